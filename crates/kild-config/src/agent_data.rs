@@ -3,13 +3,14 @@
 //! This is an intentional, documented duplication of agent data from
 //! `kild-core/src/agents/types.rs`. The alternative — moving `AgentType`
 //! to `kild-protocol` — would expand protocol scope beyond IPC types.
-//! The duplicated data is 6 agent name/command pairs, trivial to maintain.
+//! The duplicated data is 7 agent name/command pairs, trivial to maintain.
 //!
 //! Keep in sync with `crates/kild-core/src/agents/types.rs:AgentType`.
 
 /// Agent name + default command pairs.
 /// Keep in sync with kild-core/src/agents/types.rs:AgentType.
 const AGENT_DATA: &[(&str, &str)] = &[
+    ("aider", "aider"),
     ("amp", "amp"),
     ("claude", "claude"),
     ("codex", "codex"),
@@ -6,6 +6,7 @@
 use crate::agent_data;
 use crate::types::{AgentConfig, Config, HealthConfig};
 use kild_paths::KildPaths;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use tracing::warn;
 
@@ -108,6 +109,38 @@ impl HealthConfig {
         self.idle_threshold_minutes.unwrap_or(10)
     }
 
+    /// Returns the idle threshold for `agent`, falling back to the global
+    /// threshold when the agent has no valid override.
+    pub fn idle_threshold_minutes_for(&self, agent: &str) -> u64 {
+        self.idle_threshold_overrides()
+            .get(agent)
+            .copied()
+            .unwrap_or_else(|| self.idle_threshold_minutes())
+    }
+
+    /// Returns the valid per-agent idle threshold overrides.
+    ///
+    /// Entries that are not non-negative integers are skipped with a warning.
+    pub fn idle_threshold_overrides(&self) -> HashMap<String, u64> {
+        self.idle_thresholds
+            .iter()
+            .filter_map(|(agent, value)| {
+                match value.as_integer().and_then(|v| u64::try_from(v).ok()) {
+                    Some(minutes) => Some((agent.clone(), minutes)),
+                    None => {
+                        warn!(
+                            event = "core.config.idle_threshold_invalid",
+                            agent = agent.as_str(),
+                            value = %value,
+                            "Ignoring idle threshold override: expected a non-negative integer"
+                        );
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Returns the refresh interval in seconds, defaulting to 5.
     pub fn refresh_interval_secs(&self) -> u64 {
         self.refresh_interval_secs.unwrap_or(5)
@@ -117,6 +150,11 @@ impl HealthConfig {
     pub fn history_retention_days(&self) -> u64 {
         self.history_retention_days.unwrap_or(7)
     }
+
+    /// Returns the per-session health history cap, defaulting to 1000 records.
+    pub fn history_max_records(&self) -> usize {
+        self.history_max_records.unwrap_or(1000)
+    }
 }
 
 #[cfg(test)]
@@ -159,6 +197,7 @@ mod tests {
         assert_eq!(config.health.refresh_interval_secs(), 5);
         assert!(!config.health.history_enabled);
         assert_eq!(config.health.history_retention_days(), 7);
+        assert_eq!(config.health.history_max_records(), 1000);
     }
 
     #[test]
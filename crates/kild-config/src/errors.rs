@@ -12,6 +12,9 @@ pub enum ConfigError {
     #[error("Invalid configuration: {message}")]
     InvalidConfiguration { message: String },
 
+    #[error("Unknown remote alias '{alias}'. Available remotes: {available}")]
+    UnknownRemoteAlias { alias: String, available: String },
+
     #[error("IO error reading config: {source}")]
     IoError {
         #[from]
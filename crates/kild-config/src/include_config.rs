@@ -1,4 +1,8 @@
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
 use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
 
 /// Configuration for including files that override gitignore rules.
 ///
@@ -16,7 +20,7 @@ use serde::{Deserialize, Serialize};
 ///     max_file_size: Some("10MB".to_string()),
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IncludeConfig {
     /// Glob patterns to match against relative file paths.
     /// Examples: ".env*", "*.local.json", "build/artifacts/**"
@@ -51,13 +55,18 @@ impl IncludeConfig {
 
 /// A compiled glob pattern rule for matching files.
 ///
+/// A pattern prefixed with `!` is an exclude rule. Rules are evaluated in
+/// order and the last one matching a file decides whether it is copied, as in
+/// `.gitignore`. Unlike `.gitignore`, excluding a directory doesn't stop a
+/// later rule from re-including files inside it.
+///
 /// This is an internal type used by the file operations module.
 /// Users should work with `IncludeConfig` instead.
 #[derive(Debug, Clone)]
 pub struct PatternRule {
     /// Original pattern string for logging and error messages
     pub pattern: String,
-    /// Compiled glob pattern for efficient matching.
+    /// Compiled glob pattern (without the `!` prefix) for efficient matching.
     /// Private to enforce the invariant that `pattern` and `compiled` always match.
     compiled: glob::Pattern,
 }
@@ -67,7 +76,7 @@ impl PatternRule {
     ///
     /// Returns an error if the pattern is not valid glob syntax.
     pub fn new(pattern: String) -> Result<Self, glob::PatternError> {
-        let compiled = glob::Pattern::new(&pattern)?;
+        let compiled = glob::Pattern::new(pattern.strip_prefix('!').unwrap_or(&pattern))?;
         Ok(Self { pattern, compiled })
     }
 
@@ -75,6 +84,157 @@ impl PatternRule {
     pub fn compiled(&self) -> &glob::Pattern {
         &self.compiled
     }
+
+    /// Whether this rule excludes matching files instead of including them.
+    pub fn is_exclude(&self) -> bool {
+        self.pattern.starts_with('!')
+    }
+}
+
+/// Return the rule that selects a relative path, if any.
+///
+/// The last matching rule wins; a path no rule matches, or whose last match
+/// is an exclude rule, is not selected.
+fn selecting_rule<'a>(rules: &'a [PatternRule], relative_path: &str) -> Option<&'a PatternRule> {
+    rules
+        .iter()
+        .rev()
+        .find(|rule| rule.compiled().matches(relative_path))
+        .filter(|rule| !rule.is_exclude())
+}
+
+/// Literal leading directories of a pattern, e.g. `target/gen/**` gives
+/// `target` and `target/gen`.
+fn literal_parent_dirs(pattern: &str) -> Vec<String> {
+    let components: Vec<&str> = pattern.split('/').collect();
+    let mut dirs = Vec::new();
+    let mut prefix = String::new();
+    for component in &components[..components.len() - 1] {
+        if component.is_empty() || component.contains(['*', '?', '[', '{']) {
+            break;
+        }
+        if !prefix.is_empty() {
+            prefix.push('/');
+        }
+        prefix.push_str(component);
+        dirs.push(prefix.clone());
+    }
+    dirs
+}
+
+/// Find files under `source_root` selected by include rules, overriding gitignore.
+///
+/// Patterns are matched against relative paths from `source_root`. Include
+/// rules (and their literal parent directories) are registered as `ignore`
+/// overrides so gitignored paths matching them are still walked. Exclude
+/// rules are not: they only take part in the per-file last-match-wins check,
+/// so they never prune a directory a later rule re-includes from.
+///
+/// Returns an error message if the rules can't be turned into overrides.
+pub fn matching_files(source_root: &Path, rules: &[PatternRule]) -> Result<Vec<PathBuf>, String> {
+    if rules.iter().all(PatternRule::is_exclude) {
+        return Ok(Vec::new());
+    }
+
+    let mut override_builder = ignore::overrides::OverrideBuilder::new(source_root);
+    for rule in rules.iter().filter(|rule| !rule.is_exclude()) {
+        let globs = std::iter::once(rule.pattern.clone()).chain(
+            literal_parent_dirs(&rule.pattern)
+                .into_iter()
+                .map(|dir| format!("/{dir}")),
+        );
+        for glob in globs {
+            override_builder.add(&glob).map_err(|e| {
+                format!(
+                    "Failed to add override for pattern '{}': {}",
+                    rule.pattern, e
+                )
+            })?;
+        }
+    }
+    let overrides = override_builder
+        .build()
+        .map_err(|e| format!("Failed to build overrides: {}", e))?;
+
+    let walker = WalkBuilder::new(source_root)
+        .overrides(overrides)
+        .hidden(false) // Include hidden files
+        .git_ignore(true) // Still respect gitignore for non-overridden files
+        .build();
+
+    let mut matching = Vec::new();
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!(
+                    event = "core.files.walk.error",
+                    error = %e,
+                    message = "Error walking directory, skipping entry"
+                );
+                continue;
+            }
+        };
+
+        let path = entry.path();
+        if path.is_dir() {
+            continue;
+        }
+        let Ok(relative_path) = path.strip_prefix(source_root) else {
+            continue;
+        };
+
+        if let Some(rule) = selecting_rule(rules, &relative_path.to_string_lossy()) {
+            debug!(
+                event = "core.files.pattern.matched",
+                pattern = rule.pattern,
+                file = %path.display()
+            );
+            matching.push(path.to_path_buf());
+        }
+    }
+
+    debug!(
+        event = "core.files.matching.completed",
+        source_root = %source_root.display(),
+        matched_count = matching.len()
+    );
+
+    Ok(matching)
+}
+
+/// List the files that creating a kild from `root` would copy, without copying.
+///
+/// Uses the same matching as the real copy. Returns an empty list when copying
+/// is disabled, no patterns are configured, or the patterns are invalid.
+pub fn preview_matches(root: &Path, cfg: &IncludeConfig) -> Vec<PathBuf> {
+    if !cfg.enabled || cfg.patterns.is_empty() {
+        return Vec::new();
+    }
+
+    let rules = match cfg
+        .patterns
+        .iter()
+        .map(|p| PatternRule::new(p.clone()))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(rules) => rules,
+        Err(e) => {
+            warn!(event = "core.files.preview.invalid_pattern", error = %e);
+            return Vec::new();
+        }
+    };
+
+    match matching_files(root, &rules) {
+        Ok(mut files) => {
+            files.sort();
+            files
+        }
+        Err(e) => {
+            warn!(event = "core.files.preview.failed", error = %e);
+            Vec::new()
+        }
+    }
 }
 
 /// Options for copying files safely with validation.
@@ -84,6 +244,36 @@ pub struct CopyOptions {
     pub max_file_size: Option<u64>,
 }
 
+fn default_enabled() -> bool {
+    true
+}
+
+/// Returns the default include patterns.
+///
+/// These patterns provide sensible defaults for common use cases:
+/// - `.env*` - Environment files
+/// - `*.local.json` - Local config files
+/// - `.claude/**` - Claude AI context files
+/// - `.cursor/**` - Cursor AI context files
+pub fn default_include_patterns() -> Vec<String> {
+    vec![
+        ".env*".to_string(),
+        "*.local.json".to_string(),
+        ".claude/**".to_string(),
+        ".cursor/**".to_string(),
+    ]
+}
+
+impl Default for IncludeConfig {
+    fn default() -> Self {
+        Self {
+            patterns: default_include_patterns(),
+            enabled: true,
+            max_file_size: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,34 +320,183 @@ mod tests {
     fn test_pattern_rule_new_invalid() {
         assert!(PatternRule::new("[bad-pattern".to_string()).is_err());
     }
-}
 
-fn default_enabled() -> bool {
-    true
-}
+    #[test]
+    fn test_pattern_rule_exclude() {
+        let rule = PatternRule::new("!.env.production".to_string()).unwrap();
+        assert!(rule.is_exclude());
+        assert!(rule.compiled().matches(".env.production"));
+        assert!(!PatternRule::new(".env*".to_string()).unwrap().is_exclude());
+    }
 
-/// Returns the default include patterns.
-///
-/// These patterns provide sensible defaults for common use cases:
-/// - `.env*` - Environment files
-/// - `*.local.json` - Local config files
-/// - `.claude/**` - Claude AI context files
-/// - `.cursor/**` - Cursor AI context files
-pub fn default_include_patterns() -> Vec<String> {
-    vec![
-        ".env*".to_string(),
-        "*.local.json".to_string(),
-        ".claude/**".to_string(),
-        ".cursor/**".to_string(),
-    ]
-}
+    fn write_tree(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "x").unwrap();
+        }
+    }
 
-impl Default for IncludeConfig {
-    fn default() -> Self {
-        Self {
-            patterns: default_include_patterns(),
+    fn preview(root: &Path, patterns: &[&str]) -> Vec<String> {
+        let config = IncludeConfig {
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
             enabled: true,
             max_file_size: None,
-        }
+        };
+        preview_matches(root, &config)
+            .iter()
+            .map(|p| p.strip_prefix(root).unwrap().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_preview_matches_nested_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                ".env",
+                "src/main.rs",
+                "build/artifacts/app.bin",
+                "build/artifacts/deep/nested/lib.so",
+                "build/other/skip.bin",
+            ],
+        );
+
+        assert_eq!(
+            preview(dir.path(), &[".env*", "build/artifacts/**"]),
+            vec![
+                ".env",
+                "build/artifacts/app.bin",
+                "build/artifacts/deep/nested/lib.so",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preview_matches_exclude_after_include_wins() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[".claude/settings.json", ".claude/secrets/token", ".env"],
+        );
+
+        assert_eq!(
+            preview(dir.path(), &[".claude/**", "!.claude/secrets/**"]),
+            vec![".claude/settings.json"]
+        );
+    }
+
+    #[test]
+    fn test_preview_matches_include_after_exclude_wins() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[".claude/settings.json", ".claude/secrets/token"],
+        );
+
+        assert_eq!(
+            preview(dir.path(), &["!.claude/secrets/**", ".claude/**"]),
+            vec![".claude/secrets/token", ".claude/settings.json"]
+        );
+    }
+
+    #[test]
+    fn test_preview_matches_overlapping_rules() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                "config/a.local.json",
+                "config/private/b.local.json",
+                "config/private/keep.local.json",
+            ],
+        );
+
+        assert_eq!(
+            preview(
+                dir.path(),
+                &[
+                    "*.local.json",
+                    "!config/private/**",
+                    "config/private/keep.local.json",
+                ]
+            ),
+            vec!["config/a.local.json", "config/private/keep.local.json"]
+        );
+    }
+
+    #[test]
+    fn test_preview_matches_reincludes_under_excluded_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(
+            dir.path(),
+            &[
+                "Cargo.toml",
+                "target/debug/app",
+                "target/gen/api.rs",
+                "target/gen/nested/types.rs",
+            ],
+        );
+
+        assert_eq!(
+            preview(dir.path(), &["**", "!target/**", "target/gen/**"]),
+            vec![
+                "Cargo.toml",
+                "target/gen/api.rs",
+                "target/gen/nested/types.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preview_matches_rule_order_matters() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(dir.path(), &["target/debug/app", "target/gen/api.rs"]);
+
+        // Exclude last: it overrides the narrower include before it
+        assert!(preview(dir.path(), &["target/gen/**", "!target/**"]).is_empty());
+        // Include last: it re-includes part of the excluded tree
+        assert_eq!(
+            preview(dir.path(), &["!target/**", "target/gen/**"]),
+            vec!["target/gen/api.rs"]
+        );
+    }
+
+    #[test]
+    fn test_preview_matches_only_excludes_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(dir.path(), &[".env"]);
+        assert!(preview(dir.path(), &["!.env"]).is_empty());
+    }
+
+    #[test]
+    fn test_literal_parent_dirs() {
+        assert_eq!(
+            literal_parent_dirs("target/gen/**"),
+            vec!["target", "target/gen"]
+        );
+        assert_eq!(literal_parent_dirs("build/*/out/**"), vec!["build"]);
+        assert!(literal_parent_dirs(".env*").is_empty());
+        assert!(literal_parent_dirs("**/*.rs").is_empty());
+    }
+
+    #[test]
+    fn test_preview_matches_disabled_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(dir.path(), &[".env"]);
+        let config = IncludeConfig {
+            patterns: vec![".env*".to_string()],
+            enabled: false,
+            max_file_size: None,
+        };
+        assert!(preview_matches(dir.path(), &config).is_empty());
+    }
+
+    #[test]
+    fn test_preview_matches_invalid_pattern_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        write_tree(dir.path(), &[".env"]);
+        assert!(preview(dir.path(), &[".env*", "[bad"]).is_empty());
     }
 }
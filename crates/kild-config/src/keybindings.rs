@@ -5,6 +5,8 @@
 //! as `config.toml`. Parse errors warn and fall back to defaults so invalid
 //! bindings never block app startup.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
@@ -16,6 +18,8 @@ pub struct Keybindings {
     pub terminal: TerminalKeybindings,
     /// `[navigation]` section — shortcuts for kild and workspace navigation.
     pub navigation: NavigationKeybindings,
+    /// `[detail]` section — shortcuts for the kild detail view.
+    pub detail: DetailKeybindings,
 }
 
 impl Keybindings {
@@ -24,10 +28,118 @@ impl Keybindings {
         Self {
             terminal: TerminalKeybindings::merge(&base.terminal, &override_config.terminal),
             navigation: NavigationKeybindings::merge(&base.navigation, &override_config.navigation),
+            detail: DetailKeybindings::merge(&base.detail, &override_config.detail),
+        }
+    }
+
+    /// Report keys bound to more than one action.
+    ///
+    /// Bindings are compared after normalization, so `"shift+cmd+]"` and
+    /// `"cmd+shift+]"` collide. `jump_modifier` counts as binding
+    /// modifier+1 through modifier+9. Returns an empty list when every key
+    /// maps to a single action.
+    pub fn validate(&self) -> Vec<KeybindingConflict> {
+        let term = &self.terminal;
+        let nav = &self.navigation;
+        let mut bindings = vec![
+            ("terminal.focus_escape", term.focus_escape().to_string()),
+            ("terminal.copy", term.copy().to_string()),
+            ("terminal.paste", term.paste().to_string()),
+            ("navigation.next_kild", nav.next_kild().to_string()),
+            ("navigation.prev_kild", nav.prev_kild().to_string()),
+            ("navigation.toggle_view", nav.toggle_view().to_string()),
+            ("navigation.toggle_theme", nav.toggle_theme().to_string()),
+            (
+                "navigation.next_workspace",
+                nav.next_workspace().to_string(),
+            ),
+            (
+                "navigation.prev_workspace",
+                nav.prev_workspace().to_string(),
+            ),
+            ("detail.copy_branch", self.detail.copy_branch().to_string()),
+        ];
+        for digit in 1..=9 {
+            bindings.push((
+                "navigation.jump_modifier",
+                format!("{}+{}", nav.jump_modifier(), digit),
+            ));
         }
+
+        // BTreeMap keeps the report order stable across runs
+        let mut by_key: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (action, binding) in bindings {
+            let actions = by_key.entry(normalize_binding(&binding)).or_default();
+            if !actions.iter().any(|a| a == action) {
+                actions.push(action.to_string());
+            }
+        }
+
+        let conflicts: Vec<KeybindingConflict> = by_key
+            .into_iter()
+            .filter(|(_, actions)| actions.len() > 1)
+            .map(|(key, actions)| KeybindingConflict { key, actions })
+            .collect();
+
+        for conflict in &conflicts {
+            warn!(
+                event = "core.keybindings.conflict_detected",
+                key = conflict.key.as_str(),
+                actions = ?conflict.actions,
+            );
+        }
+
+        conflicts
+    }
+}
+
+/// A key bound to more than one action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeybindingConflict {
+    /// The shared key, normalized (e.g. `"cmd+shift+]"`).
+    pub key: String,
+    /// Colliding actions as `section.field` (e.g. `"navigation.next_kild"`).
+    pub actions: Vec<String>,
+}
+
+impl std::fmt::Display for KeybindingConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is bound to {}", self.key, self.actions.join(", "))
     }
 }
 
+/// Normalize a `"modifier+key"` string for comparison.
+///
+/// Lowercases, maps modifier aliases to `cmd`/`ctrl`/`alt`/`shift` and orders
+/// them canonically. Unknown modifiers are kept as written.
+fn normalize_binding(binding: &str) -> String {
+    let lowered = binding.trim().to_lowercase();
+    let mut parts: Vec<&str> = lowered.split('+').map(str::trim).collect();
+    let key = parts.pop().unwrap_or_default();
+
+    let mut modifiers: Vec<&str> = parts
+        .into_iter()
+        .map(|m| match m {
+            "control" => "ctrl",
+            "option" => "alt",
+            "super" | "win" => "cmd",
+            other => other,
+        })
+        .collect();
+    let rank = |m: &str| match m {
+        "cmd" => 0,
+        "ctrl" => 1,
+        "alt" => 2,
+        "shift" => 3,
+        _ => 4,
+    };
+    modifiers.sort_by(|a, b| rank(a).cmp(&rank(b)).then(a.cmp(b)));
+    modifiers.dedup();
+
+    modifiers.push(key);
+    modifiers.join("+")
+}
+
 /// `[terminal]` section of `keybindings.toml`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -102,6 +214,11 @@ pub struct NavigationKeybindings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub toggle_view: Option<String>,
 
+    /// Switch between the dark and light UI themes.
+    /// Default: `"cmd+shift+t"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toggle_theme: Option<String>,
+
     /// Cycle to the next workspace.
     /// Default: `"cmd+shift+]"`
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -134,6 +251,11 @@ impl NavigationKeybindings {
         self.toggle_view.as_deref().unwrap_or("cmd+d")
     }
 
+    /// Returns the toggle_theme binding, defaulting to `"cmd+shift+t"`.
+    pub fn toggle_theme(&self) -> &str {
+        self.toggle_theme.as_deref().unwrap_or("cmd+shift+t")
+    }
+
     /// Returns the next_workspace binding, defaulting to `"cmd+shift+]"`.
     pub fn next_workspace(&self) -> &str {
         self.next_workspace.as_deref().unwrap_or("cmd+shift+]")
@@ -157,6 +279,10 @@ impl NavigationKeybindings {
                 .toggle_view
                 .clone()
                 .or(base.toggle_view.clone()),
+            toggle_theme: override_config
+                .toggle_theme
+                .clone()
+                .or(base.toggle_theme.clone()),
             next_workspace: override_config
                 .next_workspace
                 .clone()
@@ -169,6 +295,33 @@ impl NavigationKeybindings {
     }
 }
 
+/// `[detail]` section of `keybindings.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DetailKeybindings {
+    /// Copy the selected kild's branch name to the clipboard.
+    /// Default: `"cmd+shift+c"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copy_branch: Option<String>,
+}
+
+impl DetailKeybindings {
+    /// Returns the copy_branch binding, defaulting to `"cmd+shift+c"`.
+    pub fn copy_branch(&self) -> &str {
+        self.copy_branch.as_deref().unwrap_or("cmd+shift+c")
+    }
+
+    /// Merge two detail keybinding configs. Override takes precedence for set fields.
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        Self {
+            copy_branch: override_config
+                .copy_branch
+                .clone()
+                .or(base.copy_branch.clone()),
+        }
+    }
+}
+
 /// Load keybindings from the user/project hierarchy.
 ///
 /// Returns `Keybindings::default()` if no files are found or on any error.
@@ -260,8 +413,10 @@ mod tests {
         assert_eq!(parsed.navigation.prev_kild(), "cmd+k");
         assert_eq!(parsed.navigation.jump_modifier(), "ctrl");
         assert_eq!(parsed.navigation.toggle_view(), "cmd+d");
+        assert_eq!(parsed.navigation.toggle_theme(), "cmd+shift+t");
         assert_eq!(parsed.navigation.next_workspace(), "cmd+shift+]");
         assert_eq!(parsed.navigation.prev_workspace(), "cmd+shift+[");
+        assert_eq!(parsed.detail.copy_branch(), "cmd+shift+c");
     }
 
     #[test]
@@ -333,6 +488,19 @@ mod tests {
         assert_eq!(kb.navigation.prev_kild(), "alt+k");
     }
 
+    #[test]
+    fn test_detail_section_loads_and_merges() {
+        let user: Keybindings = toml::from_str("[detail]\ncopy_branch = \"ctrl+b\"\n").unwrap();
+        assert_eq!(user.detail.copy_branch(), "ctrl+b");
+
+        let merged = Keybindings::merge(&user, &Keybindings::default());
+        assert_eq!(merged.detail.copy_branch(), "ctrl+b");
+
+        let project: Keybindings = toml::from_str("[detail]\ncopy_branch = \"alt+b\"\n").unwrap();
+        let merged = Keybindings::merge(&user, &project);
+        assert_eq!(merged.detail.copy_branch(), "alt+b");
+    }
+
     #[test]
     fn test_load_hierarchy_returns_defaults_when_no_files() {
         // In the test environment, no keybindings.toml exists → defaults
@@ -340,4 +508,67 @@ mod tests {
         // We only verify it doesn't panic and returns valid Keybindings
         assert_eq!(kb.navigation.jump_modifier(), "ctrl");
     }
+
+    #[test]
+    fn test_validate_defaults_have_no_conflicts() {
+        assert!(Keybindings::default().validate().is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_cross_section_conflict() {
+        let mut kb = Keybindings::default();
+        kb.navigation.next_kild = Some("cmd+c".to_string());
+
+        let conflicts = kb.validate();
+        assert_eq!(
+            conflicts,
+            vec![KeybindingConflict {
+                key: "cmd+c".to_string(),
+                actions: vec![
+                    "terminal.copy".to_string(),
+                    "navigation.next_kild".to_string()
+                ],
+            }]
+        );
+        assert_eq!(
+            conflicts[0].to_string(),
+            "cmd+c is bound to terminal.copy, navigation.next_kild"
+        );
+    }
+
+    #[test]
+    fn test_validate_normalizes_modifier_order_and_aliases() {
+        let mut kb = Keybindings::default();
+        kb.navigation.toggle_view = Some("Shift+Super+]".to_string());
+
+        let conflicts = kb.validate();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].key, "cmd+shift+]");
+        assert_eq!(
+            conflicts[0].actions,
+            ["navigation.toggle_view", "navigation.next_workspace"]
+        );
+    }
+
+    #[test]
+    fn test_validate_jump_modifier_collides_with_digit_binding() {
+        let mut kb = Keybindings::default();
+        kb.terminal.paste = Some("ctrl+3".to_string());
+
+        let conflicts = kb.validate();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].key, "ctrl+3");
+        assert_eq!(
+            conflicts[0].actions,
+            ["terminal.paste", "navigation.jump_modifier"]
+        );
+    }
+
+    #[test]
+    fn test_normalize_binding() {
+        assert_eq!(normalize_binding("cmd+j"), "cmd+j");
+        assert_eq!(normalize_binding("Control+Escape"), "ctrl+escape");
+        assert_eq!(normalize_binding("shift+alt+cmd+k"), "cmd+alt+shift+k");
+        assert_eq!(normalize_binding("option+ctrl+x"), "ctrl+alt+x");
+    }
 }
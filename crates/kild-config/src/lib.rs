@@ -13,18 +13,28 @@ mod validation;
 pub mod errors;
 pub mod include_config;
 pub mod keybindings;
+pub mod migration;
+pub mod sources;
 pub mod types;
+pub mod watcher;
 
 // Public API re-exports
 pub use errors::ConfigError;
-pub use include_config::{CopyOptions, IncludeConfig, PatternRule, default_include_patterns};
-pub use keybindings::{Keybindings, NavigationKeybindings, TerminalKeybindings};
-pub use loading::{get_agent_command, load_hierarchy, merge_configs};
+pub use include_config::{
+    CopyOptions, IncludeConfig, PatternRule, default_include_patterns, preview_matches,
+};
+pub use keybindings::{
+    DetailKeybindings, KeybindingConflict, Keybindings, NavigationKeybindings, TerminalKeybindings,
+};
+pub use loading::{get_agent_command, load_hierarchy, load_hierarchy_with_sources, merge_configs};
+pub use migration::{CURRENT_SCHEMA_VERSION, MigrationNote, migrate};
+pub use sources::{ConfigSource, ConfigSources};
 pub use types::{
     AgentConfig, AgentSettings, Config, DaemonRuntimeConfig, EditorConfig, GitConfig, HealthConfig,
-    KildConfig, TerminalConfig, UiConfig,
+    KildConfig, NotifyConfig, RemoteDaemon, TerminalConfig, UiConfig,
 };
 pub use validation::{VALID_TERMINALS, validate_config};
+pub use watcher::{ConfigWatcher, DEFAULT_RELOAD_DEBOUNCE};
 
 impl Keybindings {
     /// Load keybindings from the user/project hierarchy.
@@ -43,6 +53,14 @@ impl KildConfig {
         loading::load_hierarchy()
     }
 
+    /// Load configuration from the hierarchy, with the source of each value.
+    ///
+    /// See [`loading::load_hierarchy_with_sources`] for details.
+    pub fn load_hierarchy_with_sources() -> Result<(Self, ConfigSources), Box<dyn std::error::Error>>
+    {
+        loading::load_hierarchy_with_sources()
+    }
+
     /// Validate the configuration.
     ///
     /// See [`validation::validate_config`] for details.
@@ -12,13 +12,18 @@
 //! 4. **CLI arguments** - Command-line flags (highest priority)
 
 use crate::agent_data;
+use crate::errors::ConfigError;
 use crate::include_config::IncludeConfig;
+use crate::migration::{self, MigrationNote};
+use crate::sources::{ConfigSource, ConfigSources};
 use crate::types::{
-    AgentConfig, DaemonRuntimeConfig, GitConfig, HealthConfig, KildConfig, TerminalConfig, UiConfig,
+    AgentConfig, DaemonRuntimeConfig, GitConfig, HealthConfig, KildConfig, NotifyConfig,
+    TerminalConfig, UiConfig,
 };
 use crate::validation::validate_config;
 use std::fs;
 use std::path::Path;
+use tracing::warn;
 
 /// Check if an error is a "file not found" error.
 fn is_file_not_found(e: &(dyn std::error::Error + 'static)) -> bool {
@@ -41,47 +46,220 @@ fn is_file_not_found(e: &(dyn std::error::Error + 'static)) -> bool {
 ///
 /// Returns an error if validation fails. Missing config files are not errors.
 pub fn load_hierarchy() -> Result<KildConfig, Box<dyn std::error::Error>> {
-    let mut config = KildConfig::default();
+    load_hierarchy_with_sources().map(|(config, _)| config)
+}
 
-    // Load user config (file not found is expected, parse errors fail)
-    match load_user_config() {
-        Ok(user_config) => config = merge_configs(config, user_config),
-        Err(e) if !is_file_not_found(e.as_ref()) => return Err(e),
-        Err(_) => {} // File not found - continue with defaults
+/// Load configuration from the hierarchy, recording which file set each value.
+///
+/// Same merge as [`load_hierarchy`]; the returned [`ConfigSources`] maps every
+/// key set by a config file to the last file that set it.
+///
+/// # Errors
+///
+/// Returns an error if validation fails. Missing config files are not errors.
+pub fn load_hierarchy_with_sources()
+-> Result<(KildConfig, ConfigSources), Box<dyn std::error::Error>> {
+    let paths = kild_paths::KildPaths::resolve().map_err(|e| e.to_string())?;
+    let project_root = std::env::current_dir()?;
+
+    load_files(&[
+        ConfigSource::User(paths.user_config()),
+        ConfigSource::Project(kild_paths::KildPaths::project_config(&project_root)),
+    ])
+}
+
+/// Load, merge and validate the given config files, in order.
+///
+/// Missing files are skipped; parse and validation errors fail.
+pub(crate) fn load_files(
+    files: &[ConfigSource],
+) -> Result<(KildConfig, ConfigSources), Box<dyn std::error::Error>> {
+    let mut layers = Vec::new();
+    let mut notes = Vec::new();
+    for source in files {
+        let Some(path) = source.path() else { continue };
+        // File not found is expected, parse errors fail
+        match load_config_file(path) {
+            Ok(loaded) => {
+                for note in &loaded.notes {
+                    warn!(
+                        event = "core.config.migrated",
+                        path = %path.display(),
+                        from_version = note.from_version,
+                        note = note.message.as_str()
+                    );
+                    notes.push((source.clone(), note.clone()));
+                }
+                layers.push((source.clone(), loaded.config, loaded.table));
+            }
+            Err(e) if !is_file_not_found(e.as_ref()) => return Err(e),
+            Err(_) => {}
+        }
     }
 
-    // Load project config (file not found is expected, parse errors fail)
-    match load_project_config() {
-        Ok(project_config) => config = merge_configs(config, project_config),
-        Err(e) if !is_file_not_found(e.as_ref()) => return Err(e),
-        Err(_) => {} // File not found - continue with merged config
+    let (config, mut sources) = merge_layers(layers);
+    for (source, note) in notes {
+        sources.record_migration(source, note);
     }
 
     // Validate the final configuration
     validate_config(&config)?;
 
-    Ok(config)
+    Ok((config, sources))
 }
 
-/// Load the user configuration from ~/.kild/config.toml.
-fn load_user_config() -> Result<KildConfig, Box<dyn std::error::Error>> {
-    let paths = kild_paths::KildPaths::resolve().map_err(|e| e.to_string())?;
-    load_config_file(&paths.user_config())
+/// Merge loaded config files over the defaults, in order.
+///
+/// Each layer carries the raw table it was parsed from, so keys the file
+/// didn't set can be told apart from serde defaults.
+fn merge_layers(
+    layers: Vec<(ConfigSource, KildConfig, toml::Table)>,
+) -> (KildConfig, ConfigSources) {
+    let mut config = KildConfig::default();
+    let mut sources = ConfigSources::default();
+
+    for (source, mut layer, table) in layers {
+        // merge_configs always takes the override's agent.default; keep the
+        // current one when this file doesn't set it.
+        let sets_default = table
+            .get("agent")
+            .and_then(|agent| agent.get("default"))
+            .is_some();
+        if !sets_default {
+            layer.agent.default = config.agent.default.clone();
+        }
+
+        config = merge_configs(config, layer);
+        sources.record(&table, &source);
+    }
+
+    (config, sources)
 }
 
-/// Load the project configuration from ./.kild/config.toml.
-fn load_project_config() -> Result<KildConfig, Box<dyn std::error::Error>> {
-    let project_root = std::env::current_dir()?;
-    load_config_file(&kild_paths::KildPaths::project_config(&project_root))
+/// A config file parsed by [`load_config_file`].
+#[derive(Debug)]
+struct LoadedFile {
+    config: KildConfig,
+    /// Migrated, env-expanded TOML table the config was deserialized from.
+    table: toml::Table,
+    notes: Vec<MigrationNote>,
 }
 
 /// Load a configuration file from the given path.
-fn load_config_file(path: &Path) -> Result<KildConfig, Box<dyn std::error::Error>> {
+///
+/// Old schema layouts are migrated (see [`migration::migrate`]) and
+/// environment variables in string values are expanded (see
+/// [`interpolate_env`]) before the config is deserialized.
+fn load_config_file(path: &Path) -> Result<LoadedFile, Box<dyn std::error::Error>> {
     let content = fs::read_to_string(path)
         .map_err(|e| std::io::Error::new(e.kind(), format!("'{}': {}", path.display(), e)))?;
-    let config: KildConfig = toml::from_str(&content)
+    let mut table: toml::Table = toml::from_str(&content)
+        .map_err(|e| format!("Failed to parse config file '{}': {}", path.display(), e))?;
+    let notes = migration::migrate_table(&mut table);
+    interpolate_table(&mut table, "").map_err(|e| match e {
+        ConfigError::InvalidConfiguration { message } => ConfigError::InvalidConfiguration {
+            message: format!("{} (in '{}')", message, path.display()),
+        },
+        other => other,
+    })?;
+    let config: KildConfig = toml::Value::Table(table.clone())
+        .try_into()
         .map_err(|e| format!("Failed to parse config file '{}': {}", path.display(), e))?;
-    Ok(config)
+    Ok(LoadedFile {
+        config,
+        table,
+        notes,
+    })
+}
+
+/// Expand environment variables in every string value of `table`, in place.
+///
+/// `prefix` is the dotted path of `table`, used in error messages.
+fn interpolate_table(table: &mut toml::Table, prefix: &str) -> Result<(), ConfigError> {
+    for (key, value) in table.iter_mut() {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        interpolate_value(value, &path)?;
+    }
+    Ok(())
+}
+
+fn interpolate_value(value: &mut toml::Value, path: &str) -> Result<(), ConfigError> {
+    match value {
+        toml::Value::String(s) => *s = interpolate_env(s, path)?,
+        toml::Value::Array(items) => {
+            for item in items {
+                interpolate_value(item, path)?;
+            }
+        }
+        toml::Value::Table(inner) => interpolate_table(inner, path)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Expand `${VAR}` and `$VAR` references in a config string.
+///
+/// `$$` produces a literal `$`, as does a `$` not followed by a variable name.
+///
+/// # Errors
+///
+/// Returns `ConfigError::InvalidConfiguration` if a referenced variable is
+/// unset or a `${` is not closed. `key` names the config key in the message.
+fn interpolate_env(input: &str, key: &str) -> Result<String, ConfigError> {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+
+        let (name, remaining) = if let Some(escaped) = after.strip_prefix('$') {
+            out.push('$');
+            rest = escaped;
+            continue;
+        } else if let Some(braced) = after.strip_prefix('{') {
+            let end = braced
+                .find('}')
+                .ok_or_else(|| ConfigError::InvalidConfiguration {
+                    message: format!("Unclosed '${{' in '{}'", key),
+                })?;
+            (&braced[..end], &braced[end + 1..])
+        } else {
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            (&after[..end], &after[end..])
+        };
+
+        if !is_env_var_name(name) {
+            // Not a variable reference - keep the '$' as written
+            out.push('$');
+            rest = after;
+            continue;
+        }
+
+        let expanded = std::env::var(name).map_err(|_| ConfigError::InvalidConfiguration {
+            message: format!(
+                "Environment variable '{}' referenced by '{}' is not set",
+                name, key
+            ),
+        })?;
+        out.push_str(&expanded);
+        rest = remaining;
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn is_env_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
 
 /// Merge include pattern configurations.
@@ -119,6 +297,7 @@ fn merge_include_patterns(
 /// For collections (like agents HashMap), entries are merged with override taking precedence.
 pub fn merge_configs(base: KildConfig, override_config: KildConfig) -> KildConfig {
     KildConfig {
+        schema_version: override_config.schema_version.or(base.schema_version),
         agent: AgentConfig {
             // Always use override agent if it was explicitly set in the config file
             // We can't distinguish between explicit "claude" and default "claude" here,
@@ -152,6 +331,11 @@ pub fn merge_configs(base: KildConfig, override_config: KildConfig) -> KildConfi
                 .health
                 .idle_threshold_minutes
                 .or(base.health.idle_threshold_minutes),
+            idle_thresholds: {
+                let mut merged = base.health.idle_thresholds;
+                merged.extend(override_config.health.idle_thresholds);
+                merged
+            },
             refresh_interval_secs: override_config
                 .health
                 .refresh_interval_secs
@@ -161,6 +345,10 @@ pub fn merge_configs(base: KildConfig, override_config: KildConfig) -> KildConfi
                 .health
                 .history_retention_days
                 .or(base.health.history_retention_days),
+            history_max_records: override_config
+                .health
+                .history_max_records
+                .or(base.health.history_max_records),
         },
         git: GitConfig {
             remote: override_config.git.remote.or(base.git.remote),
@@ -170,17 +358,23 @@ pub fn merge_configs(base: KildConfig, override_config: KildConfig) -> KildConfi
                 .fetch_before_create
                 .or(base.git.fetch_before_create),
             forge: override_config.git.forge.or(base.git.forge),
+            stale_after_commits: override_config
+                .git
+                .stale_after_commits
+                .or(base.git.stale_after_commits),
         },
         editor: base.editor.merge(override_config.editor),
         daemon: DaemonRuntimeConfig::merge(&base.daemon, &override_config.daemon),
         ui: UiConfig::merge(&base.ui, &override_config.ui),
+        notify: NotifyConfig::merge(&base.notify, &override_config.notify),
     }
 }
 
 /// Get the command to run for a specific agent.
 ///
 /// Resolution order:
-/// 1. Agent-specific settings from `[agents.<name>]` section
+/// 1. Agent-specific settings from `[agents.<name>]` section (`startup_command`,
+///    then `binary` for config-defined agents)
 /// 2. Global agent config from `[agent]` section
 /// 3. Built-in default command for the agent
 ///
@@ -196,7 +390,10 @@ pub fn get_agent_command(
     let (base_command, flags) = if let Some(agent_settings) = config.agents.get(agent_name) {
         // Agent-specific settings: resolve base command, use agent-specific flags
         let base = resolve_base_command(
-            agent_settings.startup_command.as_deref(),
+            agent_settings
+                .startup_command
+                .as_deref()
+                .or(agent_settings.binary.as_deref()),
             config.agent.startup_command.as_deref(),
             agent_name,
         )?;
@@ -246,12 +443,14 @@ mod tests {
     use crate::types::AgentSettings;
     use std::env;
     use std::fs;
+    use std::path::PathBuf;
 
     /// Helper to create AgentSettings for tests
     fn make_agent_settings(startup_command: Option<&str>, flags: Option<&str>) -> AgentSettings {
         AgentSettings {
             startup_command: startup_command.map(String::from),
             flags: flags.map(String::from),
+            ..Default::default()
         }
     }
 
@@ -399,6 +598,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_agent_command_config_agent_uses_binary_without_command() {
+        let mut config = KildConfig::default();
+        config.agents.insert(
+            "myagent".to_string(),
+            AgentSettings {
+                binary: Some("myagent".to_string()),
+                flags: Some("--fast".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            get_agent_command(&config, "myagent").unwrap(),
+            "myagent --fast"
+        );
+    }
+
+    #[test]
+    fn test_get_agent_command_config_agent_command_wins_over_binary() {
+        let mut config = KildConfig::default();
+        config.agents.insert(
+            "claude".to_string(),
+            AgentSettings {
+                binary: Some("claude-beta".to_string()),
+                startup_command: Some("claude-beta --channel beta".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            get_agent_command(&config, "claude").unwrap(),
+            "claude-beta --channel beta"
+        );
+    }
+
     #[test]
     fn test_config_hierarchy_integration() {
         // Create temporary directories for testing
@@ -411,7 +646,7 @@ mod tests {
 
         // Create test directories
         fs::create_dir_all(&user_config_dir).unwrap();
-        fs::create_dir_all(&project_config_dir.join(".kild")).unwrap();
+        fs::create_dir_all(project_config_dir.join(".kild")).unwrap();
 
         // Create user config
         let user_config_content = r#"
@@ -437,7 +672,9 @@ flags = "--yolo"
         .unwrap();
 
         // Test loading user config
-        let user_config = load_config_file(&user_config_dir.join("config.toml")).unwrap();
+        let user_config = load_config_file(&user_config_dir.join("config.toml"))
+            .unwrap()
+            .config;
         assert_eq!(user_config.agent.default, "kiro");
         assert_eq!(
             user_config.agent.startup_command,
@@ -447,7 +684,9 @@ flags = "--yolo"
 
         // Test loading project config
         let project_config =
-            load_config_file(&project_config_dir.join(".kild").join("config.toml")).unwrap();
+            load_config_file(&project_config_dir.join(".kild").join("config.toml"))
+                .unwrap()
+                .config;
         assert_eq!(project_config.agent.default, "claude");
         assert_eq!(project_config.agent.flags, Some("--yolo".to_string()));
 
@@ -507,6 +746,58 @@ history_enabled = true
         assert_eq!(config.health.history_retention_days(), 7);
     }
 
+    #[test]
+    fn test_health_idle_threshold_per_agent_override() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[health]
+idle_threshold_minutes = 10
+
+[health.idle_thresholds]
+claude = 5
+codex = 15
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.health.idle_threshold_minutes_for("claude"), 5);
+        assert_eq!(config.health.idle_threshold_minutes_for("codex"), 15);
+    }
+
+    #[test]
+    fn test_health_idle_threshold_falls_back_to_global() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[health]
+idle_threshold_minutes = 20
+
+[health.idle_thresholds]
+claude = 5
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.health.idle_threshold_minutes_for("gemini"), 20);
+    }
+
+    #[test]
+    fn test_health_idle_threshold_malformed_override_ignored() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[health]
+idle_threshold_minutes = 12
+
+[health.idle_thresholds]
+claude = "five"
+codex = -3
+kiro = 7
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.health.idle_threshold_minutes_for("claude"), 12);
+        assert_eq!(config.health.idle_threshold_minutes_for("codex"), 12);
+        assert_eq!(config.health.idle_threshold_minutes_for("kiro"), 7);
+        assert_eq!(config.health.idle_threshold_overrides().len(), 1);
+    }
+
     #[test]
     fn test_health_config_merge() {
         let user_config: KildConfig = toml::from_str(
@@ -864,6 +1155,7 @@ terminal = true
             r#"
 [editor]
 default = "code"
+new_window = true
 "#,
         )
         .unwrap();
@@ -872,6 +1164,7 @@ default = "code"
         assert_eq!(merged.editor.default(), Some("code"));
         assert_eq!(merged.editor.flags(), Some("--nofork"));
         assert!(merged.editor.terminal());
+        assert!(merged.editor.new_window());
     }
 
     #[test]
@@ -949,4 +1242,183 @@ enabled = true
             "io::Error should be preserved as io::Error, not erased to String"
         );
     }
+
+    fn layer(source: ConfigSource, content: &str) -> (ConfigSource, KildConfig, toml::Table) {
+        (
+            source,
+            toml::from_str(content).unwrap(),
+            toml::from_str(content).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_merge_layers_project_overrides_user_source() {
+        let user_path = PathBuf::from("/home/me/.kild/config.toml");
+        let project_path = PathBuf::from("/repo/.kild/config.toml");
+
+        let (config, sources) = merge_layers(vec![
+            layer(
+                ConfigSource::User(user_path.clone()),
+                "[agent]\ndefault = \"kiro\"\nflags = \"-v\"\n[terminal]\npreferred = \"ghostty\"",
+            ),
+            layer(
+                ConfigSource::Project(project_path.clone()),
+                "[agent]\ndefault = \"codex\"\n[terminal]\npreferred = \"iterm2\"",
+            ),
+        ]);
+
+        assert_eq!(config.agent.default, "codex");
+        assert_eq!(config.terminal.preferred.as_deref(), Some("iterm2"));
+        assert_eq!(config.agent.flags.as_deref(), Some("-v"));
+        assert_eq!(
+            sources.source_of("agent.default"),
+            &ConfigSource::Project(project_path.clone())
+        );
+        assert_eq!(
+            sources.source_of("terminal.preferred"),
+            &ConfigSource::Project(project_path)
+        );
+        assert_eq!(
+            sources.source_of("agent.flags"),
+            &ConfigSource::User(user_path)
+        );
+        assert_eq!(sources.source_of("git.remote"), &ConfigSource::Default);
+    }
+
+    #[test]
+    fn test_merge_layers_keeps_agent_default_when_unset() {
+        let user_path = PathBuf::from("/home/me/.kild/config.toml");
+
+        let (config, sources) = merge_layers(vec![
+            layer(
+                ConfigSource::User(user_path.clone()),
+                "[agent]\ndefault = \"kiro\"",
+            ),
+            layer(
+                ConfigSource::Project(PathBuf::from("/repo/.kild/config.toml")),
+                "[agent]\nflags = \"--yolo\"",
+            ),
+        ]);
+
+        // Project file doesn't set agent.default, so the user's value stands
+        assert_eq!(config.agent.default, "kiro");
+        assert_eq!(
+            sources.source_of("agent.default"),
+            &ConfigSource::User(user_path)
+        );
+    }
+
+    #[test]
+    fn test_merge_layers_without_files_is_default() {
+        let (config, sources) = merge_layers(Vec::new());
+        assert_eq!(config.agent.default, KildConfig::default().agent.default);
+        assert_eq!(sources.iter().count(), 0);
+        assert_eq!(sources.source_of("agent.default"), &ConfigSource::Default);
+    }
+
+    #[test]
+    fn test_interpolate_env_defined_var() {
+        temp_env::with_vars([("KILD_TEST_HOME", Some("/home/me"))], || {
+            assert_eq!(
+                interpolate_env("${KILD_TEST_HOME}/bin/agent", "agent.startup_command").unwrap(),
+                "/home/me/bin/agent"
+            );
+            assert_eq!(
+                interpolate_env("$KILD_TEST_HOME/bin", "agent.startup_command").unwrap(),
+                "/home/me/bin"
+            );
+        });
+    }
+
+    #[test]
+    fn test_interpolate_env_escaped_dollar() {
+        assert_eq!(
+            interpolate_env("echo $$KILD_NOT_A_VAR costs $$5", "agent.flags").unwrap(),
+            "echo $KILD_NOT_A_VAR costs $5"
+        );
+        // A lone '$' not followed by a name is kept as written
+        assert_eq!(
+            interpolate_env("cost: $ 5$", "agent.flags").unwrap(),
+            "cost: $ 5$"
+        );
+    }
+
+    #[test]
+    fn test_interpolate_env_undefined_var_errors() {
+        temp_env::with_vars([("KILD_TEST_UNSET", None::<&str>)], || {
+            let err = interpolate_env("${KILD_TEST_UNSET}/x", "editor.default").unwrap_err();
+            assert!(matches!(err, ConfigError::InvalidConfiguration { .. }));
+            let msg = err.to_string();
+            assert!(msg.contains("KILD_TEST_UNSET"), "got: {}", msg);
+            assert!(msg.contains("editor.default"), "got: {}", msg);
+
+            assert!(interpolate_env("$KILD_TEST_UNSET", "editor.default").is_err());
+        });
+    }
+
+    #[test]
+    fn test_interpolate_env_unclosed_brace_errors() {
+        assert!(matches!(
+            interpolate_env("${HOME", "editor.default"),
+            Err(ConfigError::InvalidConfiguration { .. })
+        ));
+    }
+
+    #[test]
+    fn test_load_config_file_expands_env_vars() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(
+            &path,
+            "[agent]\nstartup_command = \"${KILD_TEST_BIN}/claude\"\n\n[editor]\ndefault = \"$KILD_TEST_BIN/nvim\"\n",
+        )
+        .unwrap();
+
+        temp_env::with_vars([("KILD_TEST_BIN", Some("/opt/bin"))], || {
+            let config = load_config_file(&path).unwrap().config;
+            assert_eq!(
+                config.agent.startup_command.as_deref(),
+                Some("/opt/bin/claude")
+            );
+            assert_eq!(config.editor.default(), Some("/opt/bin/nvim"));
+        });
+    }
+
+    #[test]
+    fn test_load_config_file_undefined_env_var_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "[agent]\nflags = \"${KILD_TEST_MISSING}\"\n").unwrap();
+
+        temp_env::with_vars([("KILD_TEST_MISSING", None::<&str>)], || {
+            let err = load_config_file(&path).unwrap_err();
+            let config_err = err.downcast_ref::<ConfigError>().unwrap();
+            assert!(matches!(
+                config_err,
+                ConfigError::InvalidConfiguration { .. }
+            ));
+            assert!(err.to_string().contains("agent.flags"));
+        });
+    }
+
+    #[test]
+    fn test_load_config_file_migrates_old_layout() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "[agents.kiro]\ncommand = \"kiro-cli chat\"\n").unwrap();
+
+        let loaded = load_config_file(&path).unwrap();
+        assert_eq!(
+            loaded.config.agents["kiro"].startup_command.as_deref(),
+            Some("kiro-cli chat")
+        );
+        assert_eq!(loaded.notes.len(), 1);
+        // Sources see the migrated key, not the old one
+        assert!(
+            loaded.table["agents"]["kiro"]
+                .get("startup_command")
+                .is_some()
+        );
+        assert!(loaded.table["agents"]["kiro"].get("command").is_none());
+    }
 }
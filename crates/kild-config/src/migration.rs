@@ -0,0 +1,218 @@
+//! Config schema versioning and migration.
+//!
+//! Config files declare the layout they were written for with a top-level
+//! `schema_version`. Files without one are version 0. Before deserializing,
+//! older layouts are rewritten in place to the current schema and each change
+//! is recorded as a [`MigrationNote`] so the CLI can tell the user what to
+//! update.
+
+use std::fmt;
+
+use crate::errors::ConfigError;
+use crate::types::KildConfig;
+
+/// Schema version written by this release of KILD.
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// One change made (or skipped) while migrating a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationNote {
+    /// Schema version the file was migrated from.
+    pub from_version: u32,
+    /// Human-readable description of the change.
+    pub message: String,
+}
+
+impl MigrationNote {
+    fn new(from_version: u32, message: String) -> Self {
+        Self {
+            from_version,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for MigrationNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Parse a config file's contents, upgrading old layouts to the current schema.
+///
+/// A `schema_version` newer than [`CURRENT_SCHEMA_VERSION`] is parsed
+/// best-effort (unknown keys are ignored) and reported as a note.
+///
+/// # Errors
+///
+/// Returns `ConfigError::ConfigParseError` if the TOML is malformed or the
+/// migrated table doesn't match the config schema.
+pub fn migrate(raw_toml: &str) -> Result<(KildConfig, Vec<MigrationNote>), ConfigError> {
+    let mut table: toml::Table =
+        toml::from_str(raw_toml).map_err(|e| ConfigError::ConfigParseError {
+            message: e.to_string(),
+        })?;
+    let notes = migrate_table(&mut table);
+    let config = toml::Value::Table(table)
+        .try_into()
+        .map_err(|e: toml::de::Error| ConfigError::ConfigParseError {
+            message: e.to_string(),
+        })?;
+    Ok((config, notes))
+}
+
+/// Upgrade a parsed config table to the current schema, in place.
+pub(crate) fn migrate_table(table: &mut toml::Table) -> Vec<MigrationNote> {
+    let version = match table.get("schema_version") {
+        None => 0,
+        Some(value) => match value.as_integer().and_then(|v| u32::try_from(v).ok()) {
+            Some(version) => version,
+            // Not a valid version; deserialization reports the error
+            None => return Vec::new(),
+        },
+    };
+
+    let mut notes = Vec::new();
+    if version > CURRENT_SCHEMA_VERSION {
+        notes.push(MigrationNote::new(
+            version,
+            format!(
+                "schema_version {} is newer than this kild supports ({}); unknown keys are ignored",
+                version, CURRENT_SCHEMA_VERSION
+            ),
+        ));
+        return notes;
+    }
+
+    if version < 1 {
+        migrate_v0(table, &mut notes);
+    }
+    notes
+}
+
+/// v0 → v1: `[agents.<name>] command` became `startup_command`, and UI
+/// shortcuts moved from `[ui]` to `keybindings.toml`.
+fn migrate_v0(table: &mut toml::Table, notes: &mut Vec<MigrationNote>) {
+    if let Some(agents) = table.get_mut("agents").and_then(toml::Value::as_table_mut) {
+        for (name, settings) in agents.iter_mut() {
+            let Some(settings) = settings.as_table_mut() else {
+                continue;
+            };
+            let Some(command) = settings.remove("command") else {
+                continue;
+            };
+            let message = if settings.contains_key("startup_command") {
+                format!("agents.{name}.command ignored: agents.{name}.startup_command is also set")
+            } else {
+                settings.insert("startup_command".to_string(), command);
+                format!("agents.{name}.command renamed to agents.{name}.startup_command")
+            };
+            notes.push(MigrationNote::new(0, message));
+        }
+    }
+
+    if let Some(ui) = table.get_mut("ui").and_then(toml::Value::as_table_mut) {
+        for (key, _) in std::mem::take(ui) {
+            notes.push(MigrationNote::new(
+                0,
+                format!("ui.{key} is no longer read from config.toml; set it in keybindings.toml"),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V0_FIXTURE: &str = r#"
+[agent]
+default = "kiro"
+
+[agents.kiro]
+command = "kiro-cli chat"
+flags = "--trust-all-tools"
+
+[ui]
+nav_modifier = "alt"
+"#;
+
+    #[test]
+    fn test_migrate_v0_renames_agent_command() {
+        let (config, notes) = migrate(V0_FIXTURE).unwrap();
+
+        let kiro = &config.agents["kiro"];
+        assert_eq!(kiro.startup_command.as_deref(), Some("kiro-cli chat"));
+        assert_eq!(kiro.flags.as_deref(), Some("--trust-all-tools"));
+        assert_eq!(config.agent.default, "kiro");
+
+        assert!(notes.iter().all(|n| n.from_version == 0));
+        assert!(
+            notes
+                .iter()
+                .any(|n| n.message == "agents.kiro.command renamed to agents.kiro.startup_command")
+        );
+    }
+
+    #[test]
+    fn test_migrate_v0_reports_dropped_ui_keys() {
+        let (_, notes) = migrate(V0_FIXTURE).unwrap();
+        assert!(
+            notes
+                .iter()
+                .any(|n| n.message.starts_with("ui.nav_modifier is no longer read"))
+        );
+    }
+
+    #[test]
+    fn test_migrate_v0_keeps_startup_command_when_both_set() {
+        let (config, notes) =
+            migrate("[agents.claude]\ncommand = \"old\"\nstartup_command = \"new\"\n").unwrap();
+
+        assert_eq!(
+            config.agents["claude"].startup_command.as_deref(),
+            Some("new")
+        );
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].message.contains("ignored"));
+    }
+
+    #[test]
+    fn test_migrate_current_version_is_untouched() {
+        let (config, notes) = migrate(&format!(
+            "schema_version = {}\n\n[agents.kiro]\nstartup_command = \"kiro-cli chat\"\n",
+            CURRENT_SCHEMA_VERSION
+        ))
+        .unwrap();
+
+        assert!(notes.is_empty());
+        assert_eq!(config.schema_version, Some(CURRENT_SCHEMA_VERSION));
+    }
+
+    #[test]
+    fn test_migrate_v0_without_old_keys_has_no_notes() {
+        let (_, notes) = migrate("[agent]\ndefault = \"claude\"\n").unwrap();
+        assert!(notes.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_future_version_warns_and_parses() {
+        let (config, notes) = migrate(
+            "schema_version = 99\nnew_section = { x = 1 }\n\n[agent]\ndefault = \"codex\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.agent.default, "codex");
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].from_version, 99);
+        assert!(notes[0].message.contains("newer"));
+    }
+
+    #[test]
+    fn test_migrate_malformed_toml_errors() {
+        assert!(matches!(
+            migrate("[agent"),
+            Err(ConfigError::ConfigParseError { .. })
+        ));
+    }
+}
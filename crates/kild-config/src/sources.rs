@@ -0,0 +1,188 @@
+//! Provenance of effective configuration values.
+//!
+//! Tracks which config file set each key so `kild config show` can attribute
+//! every effective value to the default, the user config, or the project config.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::migration::MigrationNote;
+use crate::types::KildConfig;
+
+/// Where an effective config value came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Built-in default (no config file set the key).
+    Default,
+    /// User config, `~/.kild/config.toml`.
+    User(PathBuf),
+    /// Project config, `./.kild/config.toml`.
+    Project(PathBuf),
+}
+
+impl ConfigSource {
+    /// Path of the config file, or `None` for built-in defaults.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            ConfigSource::Default => None,
+            ConfigSource::User(path) | ConfigSource::Project(path) => Some(path),
+        }
+    }
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default"),
+            ConfigSource::User(path) | ConfigSource::Project(path) => {
+                write!(f, "{}", path.display())
+            }
+        }
+    }
+}
+
+/// Source of each config key, keyed by dotted path (e.g. `agent.default`).
+///
+/// Only leaf values are tracked; arrays count as a single value. A key set by
+/// several files is attributed to the last one loaded.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSources {
+    by_key: BTreeMap<String, ConfigSource>,
+    migrations: Vec<(ConfigSource, MigrationNote)>,
+}
+
+impl ConfigSources {
+    /// Record every key set in `table` as coming from `source`.
+    pub(crate) fn record(&mut self, table: &toml::Table, source: &ConfigSource) {
+        for key in flatten(table).into_keys() {
+            self.by_key.insert(key, source.clone());
+        }
+    }
+
+    /// Source of `key`, or [`ConfigSource::Default`] if no file set it.
+    pub fn source_of(&self, key: &str) -> &ConfigSource {
+        self.by_key.get(key).unwrap_or(&ConfigSource::Default)
+    }
+
+    /// Record a schema migration applied while loading `source`.
+    pub(crate) fn record_migration(&mut self, source: ConfigSource, note: MigrationNote) {
+        self.migrations.push((source, note));
+    }
+
+    /// Schema migrations applied while loading, with the file each came from.
+    pub fn migration_notes(&self) -> impl Iterator<Item = (&ConfigSource, &MigrationNote)> {
+        self.migrations.iter().map(|(s, n)| (s, n))
+    }
+
+    /// Keys set by a config file, with their sources.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ConfigSource)> {
+        self.by_key.iter().map(|(k, s)| (k.as_str(), s))
+    }
+}
+
+/// Flatten a TOML table into dotted keys mapped to their leaf values.
+pub fn flatten(table: &toml::Table) -> BTreeMap<String, toml::Value> {
+    let mut out = BTreeMap::new();
+    flatten_into(table, "", &mut out);
+    out
+}
+
+fn flatten_into(table: &toml::Table, prefix: &str, out: &mut BTreeMap<String, toml::Value>) {
+    for (key, value) in table {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            toml::Value::Table(inner) => flatten_into(inner, &path, out),
+            leaf => {
+                out.insert(path, leaf.clone());
+            }
+        }
+    }
+}
+
+/// Every effective value of `config` that has a value, keyed by dotted path.
+///
+/// Unset optional fields are omitted.
+pub fn effective_values(
+    config: &KildConfig,
+) -> Result<BTreeMap<String, toml::Value>, toml::ser::Error> {
+    match toml::Value::try_from(config)? {
+        toml::Value::Table(table) => Ok(flatten(&table)),
+        _ => Ok(BTreeMap::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_nested_tables() {
+        let table: toml::Table = toml::from_str(
+            r#"
+            [agent]
+            default = "kiro"
+
+            [agents.claude]
+            flags = "--yolo"
+
+            [include_patterns]
+            patterns = [".env"]
+            "#,
+        )
+        .unwrap();
+
+        let flat = flatten(&table);
+        assert_eq!(
+            flat.keys().map(String::as_str).collect::<Vec<_>>(),
+            [
+                "agent.default",
+                "agents.claude.flags",
+                "include_patterns.patterns"
+            ]
+        );
+        assert_eq!(flat["agent.default"].as_str(), Some("kiro"));
+        assert!(flat["include_patterns.patterns"].is_array());
+    }
+
+    #[test]
+    fn test_sources_last_record_wins() {
+        let user = ConfigSource::User(PathBuf::from("/home/me/.kild/config.toml"));
+        let project = ConfigSource::Project(PathBuf::from("/repo/.kild/config.toml"));
+        let mut sources = ConfigSources::default();
+
+        sources.record(
+            &toml::from_str("[agent]\ndefault = \"kiro\"\nflags = \"-v\"").unwrap(),
+            &user,
+        );
+        sources.record(
+            &toml::from_str("[agent]\ndefault = \"claude\"").unwrap(),
+            &project,
+        );
+
+        assert_eq!(sources.source_of("agent.default"), &project);
+        assert_eq!(sources.source_of("agent.flags"), &user);
+        assert_eq!(sources.source_of("git.remote"), &ConfigSource::Default);
+    }
+
+    #[test]
+    fn test_config_source_display() {
+        assert_eq!(ConfigSource::Default.to_string(), "default");
+        assert_eq!(
+            ConfigSource::Project(PathBuf::from("/repo/.kild/config.toml")).to_string(),
+            "/repo/.kild/config.toml"
+        );
+    }
+
+    #[test]
+    fn test_effective_values_include_defaults() {
+        let values = effective_values(&KildConfig::default()).unwrap();
+        assert_eq!(values["agent.default"].as_str(), Some("claude"));
+        // Unset optionals are omitted rather than shown as empty
+        assert!(!values.contains_key("git.remote"));
+    }
+}
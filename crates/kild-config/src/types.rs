@@ -26,14 +26,19 @@
 //! [health]
 //! idle_threshold_minutes = 10
 //! history_enabled = true
+//!
+//! [health.idle_thresholds]
+//! claude = 5
+//! codex = 15
 //! ```
 
+use crate::errors::ConfigError;
 use crate::include_config::IncludeConfig;
 use kild_paths::KildPaths;
 use kild_protocol::ForgeType;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::debug;
 
 /// Runtime configuration for the KILD CLI.
@@ -73,6 +78,11 @@ impl Config {
 /// Project config values override user config values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KildConfig {
+    /// Config layout the file was written for. Missing means version 0;
+    /// older layouts are migrated on load (see `migration`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<u32>,
+
     /// Global agent configuration
     #[serde(default)]
     pub agent: AgentConfig,
@@ -108,11 +118,16 @@ pub struct KildConfig {
     /// UI configuration (keybindings, navigation).
     #[serde(default)]
     pub ui: UiConfig,
+
+    /// Desktop notification settings.
+    #[serde(default)]
+    pub notify: NotifyConfig,
 }
 
 impl Default for KildConfig {
     fn default() -> Self {
         Self {
+            schema_version: None,
             agent: AgentConfig::default(),
             terminal: TerminalConfig::default(),
             agents: HashMap::default(),
@@ -122,6 +137,7 @@ impl Default for KildConfig {
             editor: <EditorConfig as Default>::default(),
             daemon: DaemonRuntimeConfig::default(),
             ui: UiConfig::default(),
+            notify: NotifyConfig::default(),
         }
     }
 }
@@ -129,15 +145,56 @@ impl Default for KildConfig {
 /// UI configuration for the KILD native GUI.
 ///
 /// Keyboard shortcuts have moved to `keybindings.toml` (see `Keybindings`).
-/// This section is kept for forward extension.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
-pub struct UiConfig {}
+pub struct UiConfig {
+    /// Seconds between full reloads of the kild list.
+    /// Default: 60 when session files are watched, 5 when watching is
+    /// unavailable. `0` disables the periodic reload (file watching still
+    /// refreshes on change).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_interval_secs: Option<u64>,
+    /// Color theme: `"tallinn-night"` (dark) or `"tallinn-day"` (light).
+    /// Default: `"tallinn-night"`. Unknown names warn and use the default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+}
 
 impl UiConfig {
     /// Merge two UI configs. Override takes precedence for set fields.
-    pub fn merge(_base: &Self, _override_config: &Self) -> Self {
-        Self {}
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        Self {
+            refresh_interval_secs: override_config
+                .refresh_interval_secs
+                .or(base.refresh_interval_secs),
+            theme: override_config.theme.clone().or(base.theme.clone()),
+        }
+    }
+}
+
+/// Desktop notification configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotifyConfig {
+    /// Window in seconds during which a repeat notification for the same
+    /// agent, branch and status is suppressed. `0` disables throttling.
+    /// Default: 60
+    pub throttle_window_secs: Option<u64>,
+}
+
+impl NotifyConfig {
+    /// Repeat-notification suppression window in seconds. Default: 60.
+    pub fn throttle_window_secs(&self) -> u64 {
+        self.throttle_window_secs.unwrap_or(60)
+    }
+
+    /// Merge two notify configs. Override takes precedence for set fields.
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        Self {
+            throttle_window_secs: override_config
+                .throttle_window_secs
+                .or(base.throttle_window_secs),
+        }
     }
 }
 
@@ -169,6 +226,65 @@ pub struct DaemonRuntimeConfig {
     ///   openssl x509 -in ~/.kild/certs/daemon.crt -fingerprint -sha256 -noout
     /// Format: "sha256:<lowercase hex>" — 64 hex chars after "sha256:"
     pub remote_cert_fingerprint: Option<String>,
+
+    /// Shared secret presented to the remote daemon in the handshake.
+    /// Must match `auth_token` in the daemon host's config when it sets one.
+    pub remote_auth_token: Option<String>,
+
+    /// Client certificate (PEM) presented to a remote daemon that requires
+    /// mutual TLS (`client_ca_path` on the daemon host). Set together with
+    /// `remote_client_key_path`.
+    pub remote_client_cert_path: Option<PathBuf>,
+
+    /// Private key (PEM) for `remote_client_cert_path`.
+    pub remote_client_key_path: Option<PathBuf>,
+
+    /// Send a ping on attached streams after this many idle seconds, so
+    /// NAT/firewalls don't drop quiet TCP connections. Unset or 0 disables it.
+    pub heartbeat_interval_secs: Option<u64>,
+
+    /// Treat a stream as dead when no reply arrives this many seconds after a
+    /// heartbeat ping. Default: 10
+    pub heartbeat_timeout_secs: Option<u64>,
+
+    /// Named remote daemons for `--remote-alias <name>`.
+    ///
+    /// ```toml
+    /// [daemon.remotes]
+    /// work = "10.0.0.5:7070"
+    /// lab = { host = "lab:7432", fingerprint = "sha256:..." }
+    /// ```
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub remotes: HashMap<String, RemoteDaemon>,
+}
+
+/// A `[daemon.remotes]` entry: a bare `"host:port"` or a table that also pins
+/// the daemon's TLS certificate fingerprint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RemoteDaemon {
+    Address(String),
+    Pinned {
+        host: String,
+        fingerprint: Option<String>,
+    },
+}
+
+impl RemoteDaemon {
+    /// The `host:port` to connect to.
+    pub fn host(&self) -> &str {
+        match self {
+            RemoteDaemon::Address(host) | RemoteDaemon::Pinned { host, .. } => host,
+        }
+    }
+
+    /// The stored TLS certificate fingerprint, if the entry has one.
+    pub fn fingerprint(&self) -> Option<&str> {
+        match self {
+            RemoteDaemon::Address(_) => None,
+            RemoteDaemon::Pinned { fingerprint, .. } => fingerprint.as_deref(),
+        }
+    }
 }
 
 impl DaemonRuntimeConfig {
@@ -182,10 +298,21 @@ impl DaemonRuntimeConfig {
         self.auto_start.unwrap_or(true)
     }
 
+    /// Idle seconds before a heartbeat ping, or `None` when heartbeats are off.
+    pub fn heartbeat_interval_secs(&self) -> Option<u64> {
+        self.heartbeat_interval_secs.filter(|&secs| secs > 0)
+    }
+
+    /// Seconds to wait for a heartbeat reply. Default: 10.
+    pub fn heartbeat_timeout_secs(&self) -> u64 {
+        self.heartbeat_timeout_secs.unwrap_or(10)
+    }
+
     /// Validate the remote connection fields are consistent.
     ///
     /// Returns an error if `remote_host` is set without `remote_cert_fingerprint`.
-    /// Both must be present to establish a verified TLS connection.
+    /// Both must be present to establish a verified TLS connection. The client
+    /// certificate and key paths must likewise be set together.
     pub fn validate_remote(&self) -> Result<(), String> {
         if self.remote_host.is_some() && self.remote_cert_fingerprint.is_none() {
             return Err(
@@ -194,9 +321,28 @@ impl DaemonRuntimeConfig {
                     .to_string(),
             );
         }
+        self.remote_client_identity()?;
         Ok(())
     }
 
+    /// The client certificate and key paths for mutual TLS, if configured.
+    ///
+    /// Returns an error if only one of the two is set.
+    pub fn remote_client_identity(&self) -> Result<Option<(&Path, &Path)>, String> {
+        match (&self.remote_client_cert_path, &self.remote_client_key_path) {
+            (Some(cert), Some(key)) => Ok(Some((cert, key))),
+            (None, None) => Ok(None),
+            (Some(_), None) => Err(
+                "daemon.remote_client_cert_path is set but daemon.remote_client_key_path is missing"
+                    .to_string(),
+            ),
+            (None, Some(_)) => Err(
+                "daemon.remote_client_key_path is set but daemon.remote_client_cert_path is missing"
+                    .to_string(),
+            ),
+        }
+    }
+
     /// Merge two daemon runtime configs. Override takes precedence for set fields.
     pub fn merge(base: &Self, override_config: &Self) -> Self {
         Self {
@@ -210,8 +356,50 @@ impl DaemonRuntimeConfig {
                 .remote_cert_fingerprint
                 .clone()
                 .or(base.remote_cert_fingerprint.clone()),
+            remote_auth_token: override_config
+                .remote_auth_token
+                .clone()
+                .or(base.remote_auth_token.clone()),
+            remote_client_cert_path: override_config
+                .remote_client_cert_path
+                .clone()
+                .or(base.remote_client_cert_path.clone()),
+            remote_client_key_path: override_config
+                .remote_client_key_path
+                .clone()
+                .or(base.remote_client_key_path.clone()),
+            heartbeat_interval_secs: override_config
+                .heartbeat_interval_secs
+                .or(base.heartbeat_interval_secs),
+            heartbeat_timeout_secs: override_config
+                .heartbeat_timeout_secs
+                .or(base.heartbeat_timeout_secs),
+            remotes: {
+                let mut merged = base.remotes.clone();
+                merged.extend(override_config.remotes.clone());
+                merged
+            },
         }
     }
+
+    /// Look up a `[daemon.remotes]` entry by name.
+    ///
+    /// Returns [`ConfigError::UnknownRemoteAlias`] listing the configured names
+    /// when `alias` isn't one of them.
+    pub fn remote(&self, alias: &str) -> Result<&RemoteDaemon, ConfigError> {
+        self.remotes.get(alias).ok_or_else(|| {
+            let mut names: Vec<&str> = self.remotes.keys().map(String::as_str).collect();
+            names.sort_unstable();
+            ConfigError::UnknownRemoteAlias {
+                alias: alias.to_string(),
+                available: if names.is_empty() {
+                    "none configured; add entries under [daemon.remotes]".to_string()
+                } else {
+                    names.join(", ")
+                },
+            }
+        })
+    }
 }
 
 /// Git configuration for worktree creation.
@@ -242,6 +430,11 @@ pub struct GitConfig {
     /// When None, detect_forge() inspects the git remote URL.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub forge: Option<ForgeType>,
+
+    /// Number of commits behind the base branch after which a kild is flagged stale.
+    /// Default: 20
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stale_after_commits: Option<usize>,
 }
 
 impl GitConfig {
@@ -260,6 +453,11 @@ impl GitConfig {
         self.fetch_before_create.unwrap_or(true)
     }
 
+    /// Returns the stale threshold in commits behind base, defaulting to 20.
+    pub fn stale_after_commits(&self) -> usize {
+        self.stale_after_commits.unwrap_or(20)
+    }
+
     /// Returns the configured forge override, if any.
     pub fn forge(&self) -> Option<ForgeType> {
         self.forge
@@ -292,6 +490,12 @@ pub struct EditorConfig {
     /// Default: false
     #[serde(default, skip_serializing_if = "Option::is_none")]
     terminal: Option<bool>,
+
+    /// Whether GUI editors open the worktree in a new window instead of
+    /// reusing an existing one (e.g. `zed --new`, `code --new-window`).
+    /// Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    new_window: Option<bool>,
 }
 
 impl EditorConfig {
@@ -310,6 +514,11 @@ impl EditorConfig {
         self.terminal.unwrap_or(false)
     }
 
+    /// Returns whether to open a new editor window, defaulting to false.
+    pub fn new_window(&self) -> bool {
+        self.new_window.unwrap_or(false)
+    }
+
     /// Override the editor command (used for CLI flag override).
     pub fn set_default(&mut self, editor: String) {
         self.default = Some(editor);
@@ -342,6 +551,7 @@ impl EditorConfig {
             default: other.default.or(self.default),
             flags: other.flags.or(self.flags),
             terminal: other.terminal.or(self.terminal),
+            new_window: other.new_window.or(self.new_window),
         }
     }
 }
@@ -361,6 +571,13 @@ pub struct HealthConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub idle_threshold_minutes: Option<u64>,
 
+    /// Per-agent idle thresholds in minutes, keyed by agent name.
+    /// Agents without an entry use `idle_threshold_minutes`.
+    /// Kept as raw TOML values so a malformed entry is skipped with a
+    /// warning instead of failing the whole config load.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub idle_thresholds: HashMap<String, toml::Value>,
+
     /// Interval in seconds between health check refreshes.
     /// Default: 5 seconds.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -374,6 +591,11 @@ pub struct HealthConfig {
     /// Default: 7 days.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub history_retention_days: Option<u64>,
+
+    /// Maximum number of records kept per session in the health history.
+    /// Oldest records are dropped first. Default: 1000.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history_max_records: Option<usize>,
 }
 
 /// Global agent configuration.
@@ -409,7 +631,9 @@ pub struct TerminalConfig {
 
 /// Per-agent settings that override global agent config.
 ///
-/// Used in `[agents.<name>]` sections of the config file.
+/// Used in `[agents.<name>]` sections of the config file. Setting `binary`
+/// turns the section into a full agent definition: the agent becomes usable
+/// even if it is not compiled in, and overrides a built-in of the same name.
 ///
 /// # Example
 ///
@@ -417,16 +641,52 @@ pub struct TerminalConfig {
 /// [agents.claude]
 /// startup_command = "cc"
 /// flags = "--dangerous"
+///
+/// [agents.myagent]
+/// binary = "myagent"
+/// command = "myagent chat"
+/// process_patterns = ["myagent", "myagent-cli"]
+/// yolo_flags = "--auto-approve"
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AgentSettings {
-    /// Agent-specific startup command.
-    #[serde(default)]
+    /// Agent-specific startup command. Also accepted as `command`.
+    #[serde(default, alias = "command")]
     pub startup_command: Option<String>,
 
     /// Agent-specific flags to append to the command.
     #[serde(default)]
     pub flags: Option<String>,
+
+    /// Binary checked on PATH for availability. Marks this section as a
+    /// config-defined agent backend.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binary: Option<String>,
+
+    /// Process name patterns used to detect running instances.
+    /// Default: the binary name (or the built-in patterns when overriding).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process_patterns: Option<Vec<String>>,
+
+    /// Flags injected for `--yolo` mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yolo_flags: Option<String>,
+}
+
+impl AgentSettings {
+    /// Whether this section defines an agent backend (has a `binary`).
+    pub fn defines_backend(&self) -> bool {
+        self.binary.is_some()
+    }
+}
+
+impl KildConfig {
+    /// Whether `name` is a config-defined agent (`[agents.<name>]` with `binary`).
+    pub fn is_config_agent(&self, name: &str) -> bool {
+        self.agents
+            .get(name)
+            .is_some_and(AgentSettings::defines_backend)
+    }
 }
 
 #[cfg(test)]
@@ -441,13 +701,52 @@ mod tests {
         assert_eq!(config.agent.default, parsed.agent.default);
     }
 
+    #[test]
+    fn test_agent_settings_config_agent_from_toml() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[agents.myagent]
+binary = "myagent"
+command = "myagent chat"
+process_patterns = ["myagent", "myagent-cli"]
+yolo_flags = "--auto-approve"
+"#,
+        )
+        .unwrap();
+
+        let settings = &config.agents["myagent"];
+        assert_eq!(settings.binary.as_deref(), Some("myagent"));
+        assert_eq!(settings.startup_command.as_deref(), Some("myagent chat"));
+        assert_eq!(
+            settings.process_patterns.as_deref(),
+            Some(&["myagent".to_string(), "myagent-cli".to_string()][..])
+        );
+        assert_eq!(settings.yolo_flags.as_deref(), Some("--auto-approve"));
+        assert!(config.is_config_agent("myagent"));
+    }
+
+    #[test]
+    fn test_agent_settings_without_binary_is_not_config_agent() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[agents.kiro]
+startup_command = "kiro-cli chat"
+"#,
+        )
+        .unwrap();
+        assert!(!config.is_config_agent("kiro"));
+        assert!(!config.is_config_agent("missing"));
+    }
+
     #[test]
     fn test_health_config_serialization() {
         let config = HealthConfig {
             idle_threshold_minutes: Some(15),
+            idle_thresholds: HashMap::new(),
             refresh_interval_secs: Some(10),
             history_enabled: true,
             history_retention_days: Some(30),
+            history_max_records: None,
         };
         let toml_str = toml::to_string(&config).unwrap();
         assert!(toml_str.contains("idle_threshold_minutes = 15"));
@@ -501,6 +800,19 @@ fetch_before_create = false
         assert_eq!(config.git.remote(), "origin");
         assert_eq!(config.git.base_branch(), "main");
         assert!(config.git.fetch_before_create());
+        assert_eq!(config.git.stale_after_commits(), 20);
+    }
+
+    #[test]
+    fn test_git_config_stale_after_commits_from_toml() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[git]
+stale_after_commits = 5
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.git.stale_after_commits(), 5);
     }
 
     #[test]
@@ -547,6 +859,22 @@ terminal = true
         assert!(config.editor.terminal());
     }
 
+    #[test]
+    fn test_editor_config_new_window_from_toml() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[editor]
+default = "zed"
+new_window = true
+"#,
+        )
+        .unwrap();
+        assert!(config.editor.new_window());
+
+        let config: KildConfig = toml::from_str("").unwrap();
+        assert!(!config.editor.new_window());
+    }
+
     #[test]
     fn test_editor_config_defaults_when_missing() {
         let config: KildConfig = toml::from_str("").unwrap();
@@ -608,6 +936,7 @@ default = "code"
 [daemon]
 remote_host = "build-server:7432"
 remote_cert_fingerprint = "sha256:abc123"
+remote_auth_token = "s3cret"
 "#,
         )
         .unwrap();
@@ -619,6 +948,34 @@ remote_cert_fingerprint = "sha256:abc123"
             config.daemon.remote_cert_fingerprint,
             Some("sha256:abc123".to_string())
         );
+        assert_eq!(config.daemon.remote_auth_token, Some("s3cret".to_string()));
+    }
+
+    #[test]
+    fn test_daemon_runtime_config_client_identity_requires_both_paths() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[daemon]
+remote_client_cert_path = "/etc/kild/client.crt"
+remote_client_key_path = "/etc/kild/client.key"
+"#,
+        )
+        .unwrap();
+        let (cert, key) = config.daemon.remote_client_identity().unwrap().unwrap();
+        assert_eq!(cert, Path::new("/etc/kild/client.crt"));
+        assert_eq!(key, Path::new("/etc/kild/client.key"));
+
+        let cert_only = DaemonRuntimeConfig {
+            remote_client_cert_path: Some(PathBuf::from("/etc/kild/client.crt")),
+            ..Default::default()
+        };
+        let err = cert_only.validate_remote().unwrap_err();
+        assert!(err.contains("remote_client_key_path"));
+
+        assert_eq!(
+            DaemonRuntimeConfig::default().remote_client_identity(),
+            Ok(None)
+        );
     }
 
     #[test]
@@ -626,6 +983,36 @@ remote_cert_fingerprint = "sha256:abc123"
         let config: KildConfig = toml::from_str("").unwrap();
         assert!(config.daemon.remote_host.is_none());
         assert!(config.daemon.remote_cert_fingerprint.is_none());
+        assert!(config.daemon.remote_auth_token.is_none());
+    }
+
+    #[test]
+    fn test_daemon_runtime_config_heartbeat_defaults_off() {
+        let config: KildConfig = toml::from_str("").unwrap();
+        assert_eq!(config.daemon.heartbeat_interval_secs(), None);
+        assert_eq!(config.daemon.heartbeat_timeout_secs(), 10);
+    }
+
+    #[test]
+    fn test_daemon_runtime_config_heartbeat_from_toml_and_merge() {
+        let base: KildConfig = toml::from_str(
+            r#"
+[daemon]
+heartbeat_interval_secs = 30
+heartbeat_timeout_secs = 5
+"#,
+        )
+        .unwrap();
+        assert_eq!(base.daemon.heartbeat_interval_secs(), Some(30));
+
+        // 0 turns heartbeats off again in a higher-priority config
+        let disable = DaemonRuntimeConfig {
+            heartbeat_interval_secs: Some(0),
+            ..Default::default()
+        };
+        let merged = DaemonRuntimeConfig::merge(&base.daemon, &disable);
+        assert_eq!(merged.heartbeat_interval_secs(), None);
+        assert_eq!(merged.heartbeat_timeout_secs(), 5);
     }
 
     #[test]
@@ -648,6 +1035,75 @@ remote_cert_fingerprint = "sha256:abc123"
         );
     }
 
+    #[test]
+    fn test_daemon_remotes_resolve_alias() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[daemon.remotes]
+work = "10.0.0.5:7070"
+lab = { host = "lab:7432", fingerprint = "sha256:abcd" }
+"#,
+        )
+        .unwrap();
+
+        let work = config.daemon.remote("work").unwrap();
+        assert_eq!(work.host(), "10.0.0.5:7070");
+        assert_eq!(work.fingerprint(), None);
+
+        let lab = config.daemon.remote("lab").unwrap();
+        assert_eq!(lab.host(), "lab:7432");
+        assert_eq!(lab.fingerprint(), Some("sha256:abcd"));
+    }
+
+    #[test]
+    fn test_daemon_remotes_unknown_alias_lists_available() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[daemon.remotes]
+work = "10.0.0.5:7070"
+lab = "lab:7432"
+"#,
+        )
+        .unwrap();
+
+        let err = config.daemon.remote("home").unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownRemoteAlias { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Unknown remote alias 'home'. Available remotes: lab, work"
+        );
+
+        let err = DaemonRuntimeConfig::default().remote("home").unwrap_err();
+        assert!(err.to_string().contains("none configured"));
+    }
+
+    #[test]
+    fn test_daemon_remotes_merge_by_name() {
+        let base = DaemonRuntimeConfig {
+            remotes: HashMap::from([
+                (
+                    "work".to_string(),
+                    RemoteDaemon::Address("old:7070".to_string()),
+                ),
+                (
+                    "lab".to_string(),
+                    RemoteDaemon::Address("lab:7432".to_string()),
+                ),
+            ]),
+            ..Default::default()
+        };
+        let override_config = DaemonRuntimeConfig {
+            remotes: HashMap::from([(
+                "work".to_string(),
+                RemoteDaemon::Address("new:7070".to_string()),
+            )]),
+            ..Default::default()
+        };
+        let merged = DaemonRuntimeConfig::merge(&base, &override_config);
+        assert_eq!(merged.remote("work").unwrap().host(), "new:7070");
+        assert_eq!(merged.remote("lab").unwrap().host(), "lab:7432");
+    }
+
     // --- UiConfig tests ---
 
     #[test]
@@ -664,9 +1120,43 @@ remote_cert_fingerprint = "sha256:abc123"
         let _merged = UiConfig::merge(&base, &override_config);
     }
 
+    #[test]
+    fn test_ui_config_refresh_interval_merge() {
+        let base: UiConfig = toml::from_str("refresh_interval_secs = 30").unwrap();
+        let unset = UiConfig::default();
+        assert_eq!(
+            UiConfig::merge(&base, &unset).refresh_interval_secs,
+            Some(30)
+        );
+
+        let override_config: UiConfig = toml::from_str("refresh_interval_secs = 0").unwrap();
+        assert_eq!(
+            UiConfig::merge(&base, &override_config).refresh_interval_secs,
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_ui_config_theme_merge() {
+        let base: UiConfig = toml::from_str("theme = \"tallinn-day\"").unwrap();
+        assert_eq!(
+            UiConfig::merge(&base, &UiConfig::default())
+                .theme
+                .as_deref(),
+            Some("tallinn-day")
+        );
+
+        let override_config: UiConfig = toml::from_str("theme = \"tallinn-night\"").unwrap();
+        assert_eq!(
+            UiConfig::merge(&base, &override_config).theme.as_deref(),
+            Some("tallinn-night")
+        );
+    }
+
     #[test]
     fn test_ui_config_from_empty_toml() {
         let config: KildConfig = toml::from_str("").unwrap();
-        let _ = config.ui; // UiConfig is empty but must deserialize
+        assert!(config.ui.refresh_interval_secs.is_none());
+        assert!(config.ui.theme.is_none());
     }
 }
@@ -14,7 +14,8 @@ pub const VALID_TERMINALS: [&str; 5] = ["iterm2", "iterm", "terminal", "ghostty"
 ///
 /// # Validation Rules
 ///
-/// - Agent name must be a known agent
+/// - Agent name must be a known agent or defined in `[agents.<name>]` with `binary`
+/// - Config-defined agents must have a non-empty `binary`
 /// - Terminal preference, if set, should be a valid terminal name (warning only)
 /// - Include patterns, if configured, must be valid
 ///
@@ -23,8 +24,21 @@ pub const VALID_TERMINALS: [&str; 5] = ["iterm2", "iterm", "terminal", "ghostty"
 /// Returns `ConfigError::InvalidAgent` if the default agent is not recognized.
 /// Returns `ConfigError::InvalidConfiguration` if include patterns are invalid.
 pub fn validate_config(config: &KildConfig) -> Result<(), ConfigError> {
+    // Validate config-defined agent backends
+    for (name, settings) in &config.agents {
+        if let Some(ref binary) = settings.binary
+            && binary.trim().is_empty()
+        {
+            return Err(ConfigError::InvalidConfiguration {
+                message: format!("agents.{name}.binary must not be empty"),
+            });
+        }
+    }
+
     // Validate agent name
-    if !agent_data::is_valid_agent(&config.agent.default) {
+    if !agent_data::is_valid_agent(&config.agent.default)
+        && !config.is_config_agent(&config.agent.default)
+    {
         return Err(ConfigError::InvalidAgent {
             agent: config.agent.default.clone(),
             supported_agents: agent_data::supported_agents_string(),
@@ -59,7 +73,7 @@ pub fn validate_config(config: &KildConfig) -> Result<(), ConfigError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{AgentConfig, KildConfig};
+    use crate::types::{AgentConfig, AgentSettings, KildConfig};
 
     #[test]
     fn test_config_validation_valid_agent() {
@@ -82,13 +96,17 @@ mod tests {
 
     #[test]
     fn test_config_validation_all_valid_agents() {
-        let valid_agents = ["amp", "claude", "kiro", "gemini", "codex", "opencode"];
+        let valid_agents = [
+            "aider", "amp", "claude", "kiro", "gemini", "codex", "opencode",
+        ];
         for agent in valid_agents {
-            let mut config = KildConfig::default();
-            config.agent = AgentConfig {
-                default: agent.to_string(),
-                startup_command: None,
-                flags: None,
+            let config = KildConfig {
+                agent: AgentConfig {
+                    default: agent.to_string(),
+                    startup_command: None,
+                    flags: None,
+                },
+                ..Default::default()
             };
             assert!(
                 validate_config(&config).is_ok(),
@@ -98,6 +116,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_config_validation_accepts_config_defined_default_agent() {
+        let mut config = KildConfig::default();
+        config.agent.default = "myagent".to_string();
+        config.agents.insert(
+            "myagent".to_string(),
+            AgentSettings {
+                binary: Some("myagent".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(validate_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_config_validation_rejects_settings_only_unknown_default_agent() {
+        let mut config = KildConfig::default();
+        config.agent.default = "myagent".to_string();
+        config.agents.insert(
+            "myagent".to_string(),
+            AgentSettings {
+                startup_command: Some("myagent".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            validate_config(&config),
+            Err(ConfigError::InvalidAgent { .. })
+        ));
+    }
+
+    #[test]
+    fn test_config_validation_rejects_empty_config_agent_binary() {
+        let mut config = KildConfig::default();
+        config.agents.insert(
+            "myagent".to_string(),
+            AgentSettings {
+                binary: Some("  ".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            validate_config(&config),
+            Err(ConfigError::InvalidConfiguration { .. })
+        ));
+    }
+
     #[test]
     fn test_valid_terminals_constant() {
         assert!(VALID_TERMINALS.contains(&"iterm2"));
@@ -132,12 +197,14 @@ mod tests {
     #[test]
     fn test_config_validation_invalid_include_pattern() {
         use crate::include_config::IncludeConfig;
-        let mut config = KildConfig::default();
-        config.include_patterns = Some(IncludeConfig {
-            patterns: vec!["[invalid-glob".to_string()],
-            enabled: true,
-            max_file_size: None,
-        });
+        let config = KildConfig {
+            include_patterns: Some(IncludeConfig {
+                patterns: vec!["[invalid-glob".to_string()],
+                enabled: true,
+                max_file_size: None,
+            }),
+            ..Default::default()
+        };
         let result = validate_config(&config);
         assert!(result.is_err());
         assert!(matches!(
@@ -149,12 +216,14 @@ mod tests {
     #[test]
     fn test_config_validation_valid_include_patterns() {
         use crate::include_config::IncludeConfig;
-        let mut config = KildConfig::default();
-        config.include_patterns = Some(IncludeConfig {
-            patterns: vec![".env*".to_string(), "*.local.json".to_string()],
-            enabled: true,
-            max_file_size: None,
-        });
+        let config = KildConfig {
+            include_patterns: Some(IncludeConfig {
+                patterns: vec![".env*".to_string(), "*.local.json".to_string()],
+                enabled: true,
+                max_file_size: None,
+            }),
+            ..Default::default()
+        };
         assert!(validate_config(&config).is_ok());
     }
 }
@@ -0,0 +1,424 @@
+//! File watcher for config hot-reload.
+//!
+//! Follows the `TeamWatcher` pattern from `kild-teams/src/watcher.rs`.
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::ffi::OsString;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::time::{Duration, Instant};
+
+use crate::loading;
+use crate::sources::ConfigSource;
+use crate::types::KildConfig;
+
+/// How long the config files must be quiet before a reload.
+pub const DEFAULT_RELOAD_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches the user and project config files and reloads on change.
+///
+/// Editors often write a file several times per save, so events are
+/// debounced: the merged config is reloaded once the files have been quiet
+/// for the debounce window. Each successful reload is delivered as a
+/// [`KildConfig`] to subscribers. A reload that fails to parse or validate
+/// keeps the last good config.
+pub struct ConfigWatcher {
+    /// Underlying notify watchers. Must be kept alive.
+    _watchers: Vec<RecommendedWatcher>,
+    /// Channel receiver for file events.
+    receiver: Receiver<Result<Event, notify::Error>>,
+    /// Config files to merge on reload, in hierarchy order.
+    files: Vec<ConfigSource>,
+    /// File names of the watched config files.
+    file_names: Vec<OsString>,
+    /// Quiet period required before reloading.
+    debounce: Duration,
+    /// Time of the most recent unprocessed change.
+    pending_since: Option<Instant>,
+    /// Last successfully loaded config.
+    current: KildConfig,
+    /// Config channels handed out by `subscribe()`.
+    subscribers: Vec<Sender<KildConfig>>,
+}
+
+impl ConfigWatcher {
+    /// Create a new config watcher.
+    ///
+    /// Watches the directory containing each config file (non-recursively),
+    /// so files that don't exist yet or are replaced atomically on save are
+    /// still picked up. Directories that don't exist are skipped.
+    ///
+    /// Returns `None` if no directories can be watched.
+    pub fn new(user_config: Option<&Path>, project_config: Option<&Path>) -> Option<Self> {
+        let (tx, rx) = mpsc::channel();
+        let mut watchers = Vec::new();
+        let mut files = Vec::new();
+
+        let candidates = [
+            user_config.map(|p| ConfigSource::User(p.to_path_buf())),
+            project_config.map(|p| ConfigSource::Project(p.to_path_buf())),
+        ];
+        for source in candidates.into_iter().flatten() {
+            let Some(dir) = source.path().and_then(Path::parent) else {
+                continue;
+            };
+            if !dir.is_dir() {
+                continue;
+            }
+            match Self::create_watcher(dir, tx.clone()) {
+                Ok(w) => {
+                    tracing::info!(
+                        event = "config.watcher.watching",
+                        path = %dir.display()
+                    );
+                    watchers.push(w);
+                    files.push(source);
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        event = "config.watcher.watch_failed",
+                        path = %dir.display(),
+                        error = %e
+                    );
+                }
+            }
+        }
+
+        if watchers.is_empty() {
+            tracing::debug!(
+                event = "config.watcher.no_dirs_available",
+                "No config directories available to watch"
+            );
+            return None;
+        }
+
+        let file_names = files
+            .iter()
+            .filter_map(|f| f.path().and_then(Path::file_name))
+            .map(|n| n.to_os_string())
+            .collect();
+
+        let current = match loading::load_files(&files) {
+            Ok((config, _)) => config,
+            Err(e) => {
+                tracing::warn!(
+                    event = "config.watcher.initial_load_failed",
+                    error = %e,
+                    "Using default config until the config files are fixed"
+                );
+                KildConfig::default()
+            }
+        };
+
+        Some(Self {
+            _watchers: watchers,
+            receiver: rx,
+            files,
+            file_names,
+            debounce: DEFAULT_RELOAD_DEBOUNCE,
+            pending_since: None,
+            current,
+            subscribers: Vec::new(),
+        })
+    }
+
+    /// Create a watcher for the default config files.
+    ///
+    /// Watches `~/.kild/config.toml` and `./.kild/config.toml` relative to
+    /// the current directory.
+    pub fn new_default() -> Option<Self> {
+        let paths = kild_paths::KildPaths::resolve().ok()?;
+        let user_config = paths.user_config();
+        let project_config = std::env::current_dir()
+            .ok()
+            .map(|root| kild_paths::KildPaths::project_config(&root));
+
+        Self::new(Some(&user_config), project_config.as_deref())
+    }
+
+    /// Set the quiet period required before a reload.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// The last successfully loaded config.
+    pub fn current(&self) -> &KildConfig {
+        &self.current
+    }
+
+    /// Subscribe to config reloads.
+    ///
+    /// Configs are sent from [`poll_events`](Self::poll_events); the receiver
+    /// only sees reloads made after it subscribed.
+    pub fn subscribe(&mut self) -> Receiver<KildConfig> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.push(tx);
+        rx
+    }
+
+    /// Process pending file events (non-blocking).
+    ///
+    /// Once the config files have been quiet for the debounce window, reloads
+    /// and merges them and sends the new config to every subscriber. Returns
+    /// `true` if a new config was loaded.
+    pub fn poll_events(&mut self) -> bool {
+        if self.drain_events() {
+            self.pending_since = Some(Instant::now());
+        }
+
+        match self.pending_since {
+            Some(since) if since.elapsed() >= self.debounce => {
+                self.pending_since = None;
+                self.reload()
+            }
+            _ => false,
+        }
+    }
+
+    fn reload(&mut self) -> bool {
+        match loading::load_files(&self.files) {
+            Ok((config, _)) => {
+                tracing::info!(event = "config.watcher.reload_completed");
+                self.current = config;
+                let current = &self.current;
+                // Drop subscribers whose receiver has gone away.
+                self.subscribers
+                    .retain(|tx| tx.send(current.clone()).is_ok());
+                true
+            }
+            Err(e) => {
+                tracing::warn!(
+                    event = "config.watcher.reload_failed",
+                    error = %e,
+                    "Keeping last good config"
+                );
+                false
+            }
+        }
+    }
+
+    /// Drain all pending file events. Returns `true` if a config file changed.
+    fn drain_events(&self) -> bool {
+        let mut changed = false;
+
+        loop {
+            match self.receiver.try_recv() {
+                Ok(Ok(event)) => {
+                    if self.is_relevant_event(&event) {
+                        tracing::debug!(
+                            event = "config.watcher.event_detected",
+                            kind = ?event.kind,
+                            paths = ?event.paths
+                        );
+                        changed = true;
+                    }
+                    // Continue draining
+                }
+                Ok(Err(e)) => {
+                    tracing::warn!(event = "config.watcher.event_error", error = %e);
+                }
+                Err(TryRecvError::Empty) => return changed,
+                Err(TryRecvError::Disconnected) => {
+                    tracing::warn!(event = "config.watcher.channel_disconnected");
+                    return changed;
+                }
+            }
+        }
+    }
+
+    fn create_watcher(
+        dir: &Path,
+        tx: mpsc::Sender<Result<Event, notify::Error>>,
+    ) -> Result<RecommendedWatcher, notify::Error> {
+        let mut watcher = notify::recommended_watcher(tx)?;
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        Ok(watcher)
+    }
+
+    /// Check if an event touches one of the watched config files.
+    fn is_relevant_event(&self, event: &Event) -> bool {
+        let is_relevant_kind = matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        );
+
+        is_relevant_kind
+            && event
+                .paths
+                .iter()
+                .filter_map(|p| p.file_name())
+                .any(|name| self.file_names.iter().any(|f| f == name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind};
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    /// Time for the OS to deliver file events.
+    const EVENT_DELAY: Duration = Duration::from_millis(200);
+
+    fn user_config(dir: &TempDir) -> PathBuf {
+        dir.path().join("config.toml")
+    }
+
+    fn watcher_for(dir: &TempDir) -> ConfigWatcher {
+        ConfigWatcher::new(Some(&user_config(dir)), None)
+            .unwrap()
+            .with_debounce(Duration::ZERO)
+    }
+
+    fn make_event(kind: EventKind, path: &str) -> Event {
+        Event {
+            kind,
+            paths: vec![PathBuf::from(path)],
+            attrs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_new_with_missing_dirs() {
+        let watcher = ConfigWatcher::new(
+            Some(Path::new("/nonexistent/.kild/config.toml")),
+            Some(Path::new("/nonexistent/project/.kild/config.toml")),
+        );
+        assert!(watcher.is_none());
+    }
+
+    #[test]
+    fn test_new_loads_initial_config() {
+        let dir = TempDir::new().unwrap();
+        fs::write(user_config(&dir), "[agent]\ndefault = \"kiro\"\n").unwrap();
+
+        let watcher = watcher_for(&dir);
+        assert_eq!(watcher.current().agent.default, "kiro");
+    }
+
+    #[test]
+    fn test_relevant_event_filters_by_file_name() {
+        let dir = TempDir::new().unwrap();
+        let watcher = watcher_for(&dir);
+
+        assert!(watcher.is_relevant_event(&make_event(
+            EventKind::Create(CreateKind::File),
+            "/home/me/.kild/config.toml"
+        )));
+        assert!(!watcher.is_relevant_event(&make_event(
+            EventKind::Create(CreateKind::File),
+            "/home/me/.kild/keybindings.toml"
+        )));
+        assert!(!watcher.is_relevant_event(&make_event(
+            EventKind::Access(AccessKind::Read),
+            "/home/me/.kild/config.toml"
+        )));
+    }
+
+    #[test]
+    fn test_poll_events_yields_updated_config() {
+        let dir = TempDir::new().unwrap();
+        fs::write(user_config(&dir), "[agent]\ndefault = \"claude\"\n").unwrap();
+
+        let mut watcher = watcher_for(&dir);
+        let configs = watcher.subscribe();
+        assert!(!watcher.poll_events());
+
+        fs::write(user_config(&dir), "[agent]\ndefault = \"kiro\"\n").unwrap();
+        std::thread::sleep(EVENT_DELAY);
+
+        assert!(watcher.poll_events());
+        assert_eq!(watcher.current().agent.default, "kiro");
+        let received: Vec<_> = configs.try_iter().collect();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].agent.default, "kiro");
+
+        // Nothing changed since the last poll.
+        assert!(!watcher.poll_events());
+    }
+
+    #[test]
+    fn test_poll_events_picks_up_new_file() {
+        let dir = TempDir::new().unwrap();
+        let mut watcher = watcher_for(&dir);
+        assert_eq!(watcher.current().agent.default, "claude");
+
+        fs::write(user_config(&dir), "[agent]\ndefault = \"codex\"\n").unwrap();
+        std::thread::sleep(EVENT_DELAY);
+
+        assert!(watcher.poll_events());
+        assert_eq!(watcher.current().agent.default, "codex");
+    }
+
+    #[test]
+    fn test_parse_error_keeps_last_good_config() {
+        let dir = TempDir::new().unwrap();
+        fs::write(user_config(&dir), "[agent]\ndefault = \"kiro\"\n").unwrap();
+
+        let mut watcher = watcher_for(&dir);
+        let configs = watcher.subscribe();
+
+        fs::write(user_config(&dir), "[agent\ndefault = ").unwrap();
+        std::thread::sleep(EVENT_DELAY);
+
+        assert!(!watcher.poll_events());
+        assert_eq!(watcher.current().agent.default, "kiro");
+        assert!(configs.try_recv().is_err());
+
+        // Fixing the file recovers
+        fs::write(user_config(&dir), "[agent]\ndefault = \"amp\"\n").unwrap();
+        std::thread::sleep(EVENT_DELAY);
+
+        assert!(watcher.poll_events());
+        assert_eq!(configs.try_recv().unwrap().agent.default, "amp");
+    }
+
+    #[test]
+    fn test_debounce_coalesces_rapid_saves() {
+        let dir = TempDir::new().unwrap();
+        fs::write(user_config(&dir), "[agent]\ndefault = \"claude\"\n").unwrap();
+
+        let debounce = Duration::from_millis(400);
+        let mut watcher = watcher_for(&dir).with_debounce(debounce);
+        let configs = watcher.subscribe();
+
+        fs::write(user_config(&dir), "[agent]\ndefault = \"kiro\"\n").unwrap();
+        fs::write(user_config(&dir), "[agent]\ndefault = \"codex\"\n").unwrap();
+        std::thread::sleep(EVENT_DELAY);
+
+        // Still inside the debounce window
+        assert!(!watcher.poll_events());
+        assert_eq!(watcher.current().agent.default, "claude");
+
+        std::thread::sleep(debounce);
+        assert!(watcher.poll_events());
+
+        let received: Vec<_> = configs.try_iter().collect();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].agent.default, "codex");
+    }
+
+    #[test]
+    fn test_project_config_overrides_user_on_reload() {
+        let user_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        fs::write(user_config(&user_dir), "[agent]\ndefault = \"kiro\"\n").unwrap();
+
+        let mut watcher = ConfigWatcher::new(
+            Some(&user_config(&user_dir)),
+            Some(&user_config(&project_dir)),
+        )
+        .unwrap()
+        .with_debounce(Duration::ZERO);
+        assert_eq!(watcher.current().agent.default, "kiro");
+
+        fs::write(user_config(&project_dir), "[agent]\ndefault = \"gemini\"\n").unwrap();
+        std::thread::sleep(EVENT_DELAY);
+
+        assert!(watcher.poll_events());
+        assert_eq!(watcher.current().agent.default, "gemini");
+    }
+}
@@ -0,0 +1,166 @@
+//! Agent backend defined at runtime from `[agents.<name>]` config.
+
+use kild_config::AgentSettings;
+
+use crate::agents::traits::AgentBackend;
+
+/// An agent backend built from a config section with `binary` set.
+///
+/// Unset fields fall back to the compiled-in backend of the same name (when
+/// overriding one), otherwise to values derived from `binary`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigAgentBackend {
+    name: String,
+    display_name: String,
+    binary: String,
+    command: String,
+    process_patterns: Vec<String>,
+    yolo_flags: Option<String>,
+}
+
+impl ConfigAgentBackend {
+    /// Build a backend from `[agents.<name>]` settings.
+    ///
+    /// Returns `None` when `settings` has no `binary` — such sections only tweak
+    /// the command/flags of a built-in agent and don't define a backend.
+    pub fn from_settings(
+        name: &str,
+        settings: &AgentSettings,
+        builtin: Option<&dyn AgentBackend>,
+    ) -> Option<Self> {
+        let binary = settings.binary.clone()?;
+
+        let display_name = builtin
+            .map(|b| b.display_name().to_string())
+            .unwrap_or_else(|| name.to_string());
+
+        let command = settings
+            .startup_command
+            .clone()
+            .unwrap_or_else(|| binary.clone());
+
+        let process_patterns = match (&settings.process_patterns, builtin) {
+            (Some(patterns), _) => patterns.clone(),
+            (None, Some(b)) => b.process_patterns(),
+            (None, None) => vec![binary_basename(&binary).to_string()],
+        };
+
+        let yolo_flags = settings
+            .yolo_flags
+            .clone()
+            .or_else(|| builtin.and_then(|b| b.yolo_flags().map(str::to_string)));
+
+        Some(Self {
+            name: name.to_string(),
+            display_name,
+            binary,
+            command,
+            process_patterns,
+            yolo_flags,
+        })
+    }
+}
+
+impl AgentBackend for ConfigAgentBackend {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn binary(&self) -> &str {
+        &self.binary
+    }
+
+    fn is_available(&self) -> bool {
+        super::is_binary_available(&self.binary)
+    }
+
+    fn default_command(&self) -> &str {
+        &self.command
+    }
+
+    fn process_patterns(&self) -> Vec<String> {
+        self.process_patterns.clone()
+    }
+
+    fn yolo_flags(&self) -> Option<&str> {
+        self.yolo_flags.as_deref()
+    }
+}
+
+/// Last path component of a binary, so `/opt/bin/foo` detects as `foo`.
+fn binary_basename(binary: &str) -> &str {
+    binary.rsplit('/').next().unwrap_or(binary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::backends::ClaudeBackend;
+
+    #[test]
+    fn test_config_backend_requires_binary() {
+        let settings = AgentSettings {
+            startup_command: Some("myagent".to_string()),
+            ..Default::default()
+        };
+        assert!(ConfigAgentBackend::from_settings("myagent", &settings, None).is_none());
+    }
+
+    #[test]
+    fn test_config_backend_derives_defaults_from_binary() {
+        let settings = AgentSettings {
+            binary: Some("/opt/tools/myagent".to_string()),
+            ..Default::default()
+        };
+        let backend = ConfigAgentBackend::from_settings("myagent", &settings, None).unwrap();
+
+        assert_eq!(backend.name(), "myagent");
+        assert_eq!(backend.display_name(), "myagent");
+        assert_eq!(backend.binary(), "/opt/tools/myagent");
+        assert_eq!(backend.default_command(), "/opt/tools/myagent");
+        assert_eq!(backend.process_patterns(), vec!["myagent".to_string()]);
+        assert_eq!(backend.yolo_flags(), None);
+    }
+
+    #[test]
+    fn test_config_backend_uses_all_configured_fields() {
+        let settings = AgentSettings {
+            binary: Some("myagent".to_string()),
+            startup_command: Some("myagent chat".to_string()),
+            process_patterns: Some(vec!["myagent".to_string(), "myagent-cli".to_string()]),
+            yolo_flags: Some("--auto-approve".to_string()),
+            ..Default::default()
+        };
+        let backend = ConfigAgentBackend::from_settings("myagent", &settings, None).unwrap();
+
+        assert_eq!(backend.default_command(), "myagent chat");
+        assert_eq!(
+            backend.process_patterns(),
+            vec!["myagent".to_string(), "myagent-cli".to_string()]
+        );
+        assert_eq!(backend.yolo_flags(), Some("--auto-approve"));
+    }
+
+    #[test]
+    fn test_config_backend_override_falls_back_to_builtin_fields() {
+        let settings = AgentSettings {
+            binary: Some("claude-beta".to_string()),
+            ..Default::default()
+        };
+        let backend =
+            ConfigAgentBackend::from_settings("claude", &settings, Some(&ClaudeBackend)).unwrap();
+
+        assert_eq!(backend.display_name(), "Claude Code");
+        assert_eq!(backend.default_command(), "claude-beta");
+        assert!(
+            backend
+                .process_patterns()
+                .contains(&"claude-code".to_string())
+        );
+        assert_eq!(backend.yolo_flags(), Some("--dangerously-skip-permissions"));
+    }
+}
@@ -4,6 +4,261 @@
 //! the struct, `AgentBackend` trait impl, and uniquely named tests. Each invocation
 //! requires a `test_prefix` identifier used to produce descriptive test function
 //! names via `paste`.
+//!
+//! Agents defined entirely in config (`[agents.<name>]` with `binary`) are
+//! represented by [`ConfigAgentBackend`] in `config.rs`.
+//!
+//! Availability checks (`which`) and `--version` probes are cached per binary
+//! name for the lifetime of the process. Call [`refresh_availability`] after
+//! installing or upgrading an agent CLI.
+//!
+//! `--version` probes are bounded by [`VERSION_PROBE_TIMEOUT`]; render paths
+//! should use [`cached_binary_version`], which never blocks on a probe.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
+
+mod config;
+
+pub use config::ConfigAgentBackend;
+
+/// Process-lifetime cache of `which` results, keyed by binary name.
+static AVAILABILITY_CACHE: OnceLock<BinaryCache<bool>> = OnceLock::new();
+
+/// Process-lifetime cache of `<binary> --version` results, keyed by binary name.
+static VERSION_CACHE: OnceLock<BinaryCache<Option<String>>> = OnceLock::new();
+
+/// Binaries with a background `--version` probe currently running.
+static VERSION_PROBES_IN_FLIGHT: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+/// How long a `<binary> --version` probe may run before it is killed.
+const VERSION_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Poll interval while waiting for a `--version` probe to exit.
+const VERSION_PROBE_POLL: Duration = Duration::from_millis(20);
+
+/// Memoizes per-binary lookups so repeated calls (e.g. once per agent per UI
+/// render, or once per list row) don't hit the filesystem or spawn processes.
+struct BinaryCache<T> {
+    entries: Mutex<HashMap<String, T>>,
+}
+
+impl<T> Default for BinaryCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T: Clone> BinaryCache<T> {
+    /// Return the cached result for `binary`, running `lookup` on a miss.
+    ///
+    /// The lock is not held while `lookup` runs, so a slow PATH scan or
+    /// subprocess never blocks readers of other binaries.
+    fn get_or_check(&self, binary: &str, lookup: impl FnOnce(&str) -> T) -> T {
+        if let Some(cached) = self
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(binary)
+        {
+            return cached.clone();
+        }
+
+        let value = lookup(binary);
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(binary.to_string(), value.clone());
+        value
+    }
+
+    /// Return the cached result for `binary` without running a lookup.
+    fn peek(&self, binary: &str) -> Option<T> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(binary)
+            .cloned()
+    }
+
+    fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+/// Check whether `binary` is on PATH, using the process-lifetime cache.
+pub(crate) fn is_binary_available(binary: &str) -> bool {
+    AVAILABILITY_CACHE
+        .get_or_init(BinaryCache::default)
+        .get_or_check(binary, |b| which::which(b).is_ok())
+}
+
+/// Detect the installed version of `binary` via `<binary> --version`, cached.
+///
+/// Returns `None` if the binary is missing, exits non-zero, or prints nothing.
+pub(crate) fn detect_binary_version(binary: &str) -> Option<String> {
+    VERSION_CACHE
+        .get_or_init(BinaryCache::default)
+        .get_or_check(binary, |b| {
+            run_version_command(b)
+                .as_deref()
+                .and_then(parse_version_output)
+        })
+}
+
+/// Non-blocking variant of [`detect_binary_version`] for render paths.
+///
+/// Returns the cached version if a probe has completed. On a miss, starts the
+/// probe on a background thread and returns `None`; the next call after the
+/// probe finishes sees the result.
+pub(crate) fn cached_binary_version(binary: &str) -> Option<String> {
+    if let Some(cached) = VERSION_CACHE.get_or_init(BinaryCache::default).peek(binary) {
+        return cached;
+    }
+
+    let in_flight = VERSION_PROBES_IN_FLIGHT.get_or_init(|| Mutex::new(HashSet::new()));
+    if !in_flight
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(binary.to_string())
+    {
+        return None;
+    }
+
+    let binary = binary.to_string();
+    let spawned = std::thread::Builder::new()
+        .name("kild-version-probe".to_string())
+        .spawn({
+            let binary = binary.clone();
+            move || {
+                detect_binary_version(&binary);
+                if let Some(in_flight) = VERSION_PROBES_IN_FLIGHT.get() {
+                    in_flight
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .remove(&binary);
+                }
+            }
+        });
+    if let Err(e) = spawned {
+        tracing::debug!(
+            event = "core.agent.version_probe_failed",
+            binary = %binary,
+            error = %e
+        );
+        in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&binary);
+    }
+    None
+}
+
+/// Run `<binary> --version` and return its stdout.
+fn run_version_command(binary: &str) -> Option<String> {
+    run_version_command_with_timeout(binary, VERSION_PROBE_TIMEOUT)
+}
+
+/// Run `<binary> --version`, killing it if it hasn't exited after `timeout`.
+fn run_version_command_with_timeout(binary: &str, timeout: Duration) -> Option<String> {
+    let mut child = match Command::new(binary)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            tracing::debug!(
+                event = "core.agent.version_probe_failed",
+                binary = binary,
+                error = %e
+            );
+            return None;
+        }
+    };
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() >= timeout => {
+                tracing::warn!(
+                    event = "core.agent.version_probe_timed_out",
+                    binary = binary,
+                    timeout_ms = timeout.as_millis() as u64
+                );
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+            Ok(None) => std::thread::sleep(VERSION_PROBE_POLL),
+            Err(e) => {
+                tracing::debug!(
+                    event = "core.agent.version_probe_failed",
+                    binary = binary,
+                    error = %e
+                );
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
+    };
+
+    if !status.success() {
+        tracing::debug!(
+            event = "core.agent.version_probe_failed",
+            binary = binary,
+            status = %status
+        );
+        return None;
+    }
+
+    let mut stdout = String::new();
+    if let Some(mut pipe) = child.stdout.take()
+        && let Err(e) = pipe.read_to_string(&mut stdout)
+    {
+        tracing::debug!(
+            event = "core.agent.version_probe_failed",
+            binary = binary,
+            error = %e
+        );
+        return None;
+    }
+    Some(stdout)
+}
+
+/// Extract the version from `--version` output: the first non-empty line, trimmed.
+fn parse_version_output(output: &str) -> Option<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+/// Drop all cached availability and version results so the next check
+/// re-scans PATH and re-runs `--version`.
+///
+/// Call after the user installs, removes, or upgrades an agent CLI.
+pub fn refresh_availability() {
+    if let Some(cache) = AVAILABILITY_CACHE.get() {
+        cache.clear();
+    }
+    if let Some(cache) = VERSION_CACHE.get() {
+        cache.clear();
+    }
+}
 
 /// Shared test body for both macro arms. Generates the four tests common to all
 /// backends; the yolo-specific test is added by each arm individually.
@@ -50,19 +305,23 @@ macro_rules! define_agent_backend {
         pub struct $struct_name;
 
         impl crate::agents::traits::AgentBackend for $struct_name {
-            fn name(&self) -> &'static str {
+            fn name(&self) -> &str {
                 $name
             }
 
-            fn display_name(&self) -> &'static str {
+            fn display_name(&self) -> &str {
                 $display
             }
 
+            fn binary(&self) -> &str {
+                $binary
+            }
+
             fn is_available(&self) -> bool {
-                which::which($binary).is_ok()
+                crate::agents::backends::is_binary_available($binary)
             }
 
-            fn default_command(&self) -> &'static str {
+            fn default_command(&self) -> &str {
                 $cmd
             }
 
@@ -70,7 +329,7 @@ macro_rules! define_agent_backend {
                 vec![$($pat.to_string()),+]
             }
 
-            fn yolo_flags(&self) -> Option<&'static str> {
+            fn yolo_flags(&self) -> Option<&str> {
                 Some($yolo)
             }
         }
@@ -103,19 +362,23 @@ macro_rules! define_agent_backend {
         pub struct $struct_name;
 
         impl crate::agents::traits::AgentBackend for $struct_name {
-            fn name(&self) -> &'static str {
+            fn name(&self) -> &str {
                 $name
             }
 
-            fn display_name(&self) -> &'static str {
+            fn display_name(&self) -> &str {
                 $display
             }
 
+            fn binary(&self) -> &str {
+                $binary
+            }
+
             fn is_available(&self) -> bool {
-                which::which($binary).is_ok()
+                crate::agents::backends::is_binary_available($binary)
             }
 
-            fn default_command(&self) -> &'static str {
+            fn default_command(&self) -> &str {
                 $cmd
             }
 
@@ -141,6 +404,18 @@ macro_rules! define_agent_backend {
     };
 }
 
+mod aider {
+    define_agent_backend!(AiderBackend,
+        test_prefix: aider,
+        name: "aider",
+        display_name: "Aider",
+        binary: "aider",
+        command: "aider",
+        process_patterns: ["aider"],
+        yolo_flags: "--yes-always"
+    );
+}
+
 mod amp {
     define_agent_backend!(AmpBackend,
         test_prefix: amp,
@@ -212,9 +487,133 @@ mod opencode {
     );
 }
 
+pub use aider::AiderBackend;
 pub use amp::AmpBackend;
 pub use claude::ClaudeBackend;
 pub use codex::CodexBackend;
 pub use gemini::GeminiBackend;
 pub use kiro::KiroBackend;
 pub use opencode::OpenCodeBackend;
+
+#[cfg(test)]
+mod cache_tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn availability_cache_skips_lookup_on_second_call() {
+        let cache = BinaryCache::default();
+        let calls = Cell::new(0);
+        let lookup = |_: &str| {
+            calls.set(calls.get() + 1);
+            true
+        };
+
+        assert!(cache.get_or_check("kild-fake-agent", lookup));
+        assert!(cache.get_or_check("kild-fake-agent", lookup));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn availability_cache_keys_by_binary_name() {
+        let cache = BinaryCache::default();
+        assert!(cache.get_or_check("present", |_| true));
+        assert!(!cache.get_or_check("missing", |_| false));
+        // Cached values survive a lookup that would now disagree
+        assert!(cache.get_or_check("present", |_| false));
+        assert!(!cache.get_or_check("missing", |_| true));
+    }
+
+    #[test]
+    fn availability_cache_clear_forces_fresh_lookup() {
+        let cache = BinaryCache::default();
+        assert!(!cache.get_or_check("kild-fake-agent", |_| false));
+        cache.clear();
+        assert!(cache.get_or_check("kild-fake-agent", |_| true));
+    }
+
+    #[test]
+    fn is_binary_available_matches_which_for_missing_binary() {
+        assert!(!is_binary_available("kild-definitely-not-installed-binary"));
+    }
+
+    #[test]
+    fn version_cache_runs_command_once_per_binary() {
+        let cache: BinaryCache<Option<String>> = BinaryCache::default();
+        let calls = Cell::new(0);
+        let fake_runner = |_: &str| {
+            calls.set(calls.get() + 1);
+            parse_version_output("2.1.0 (Claude Code)\n")
+        };
+
+        assert_eq!(
+            cache.get_or_check("claude", fake_runner).as_deref(),
+            Some("2.1.0 (Claude Code)")
+        );
+        assert_eq!(
+            cache.get_or_check("claude", fake_runner).as_deref(),
+            Some("2.1.0 (Claude Code)")
+        );
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn parse_version_output_takes_first_non_empty_line() {
+        assert_eq!(
+            parse_version_output("\n  codex-cli 0.42.0  \nbuild abc\n").as_deref(),
+            Some("codex-cli 0.42.0")
+        );
+        assert_eq!(parse_version_output(""), None);
+        assert_eq!(parse_version_output("  \n\n"), None);
+    }
+
+    #[test]
+    fn detect_binary_version_missing_binary_is_none() {
+        assert_eq!(
+            detect_binary_version("kild-definitely-not-installed-binary"),
+            None
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_version_command_kills_hanging_binary() {
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("hang");
+        std::fs::write(&script, "#!/bin/sh\nsleep 30\n").unwrap();
+        std::fs::set_permissions(&script, std::os::unix::fs::PermissionsExt::from_mode(0o755))
+            .unwrap();
+
+        let start = Instant::now();
+        let version =
+            run_version_command_with_timeout(script.to_str().unwrap(), Duration::from_millis(200));
+
+        assert_eq!(version, None);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_version_command_reads_stdout_of_fast_binary() {
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("agent");
+        std::fs::write(&script, "#!/bin/sh\necho 'agent 1.2.3'\n").unwrap();
+        std::fs::set_permissions(&script, std::os::unix::fs::PermissionsExt::from_mode(0o755))
+            .unwrap();
+
+        let output = run_version_command(script.to_str().unwrap());
+
+        assert_eq!(
+            output.as_deref().and_then(parse_version_output).as_deref(),
+            Some("agent 1.2.3")
+        );
+    }
+
+    #[test]
+    fn cached_binary_version_never_blocks_on_miss() {
+        assert_eq!(
+            cached_binary_version("kild-definitely-not-installed-version-probe"),
+            None
+        );
+    }
+}
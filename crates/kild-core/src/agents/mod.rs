@@ -9,7 +9,8 @@
 //! - [`AgentType`] - Enum of all supported agent types
 //! - [`AgentError`] - Agent-specific error types
 //! - [`backends`] - Individual agent backend implementations
-//! - [`registry`] - Global registry for agent lookup
+//! - [`registry`] - Global registry for agent lookup; [`resolve_agent`] merges
+//!   config-defined agents (`[agents.<name>]` with `binary`) over built-ins
 //!
 //! # Usage
 //!
@@ -36,11 +37,13 @@ pub mod traits;
 pub mod types;
 
 // Re-export public API
+pub use backends::refresh_availability;
 pub use errors::AgentError;
 pub use registry::{
-    default_agent_name, default_agent_type, get_agent, get_agent_by_type, get_all_process_patterns,
-    get_default_command, get_inject_method, get_process_patterns, get_yolo_flags,
-    is_agent_available, is_valid_agent, supported_agents_string, valid_agent_names,
+    ResolvedAgent, cached_agent_version, default_agent_name, default_agent_type, get_agent,
+    get_agent_by_type, get_agent_version, get_all_process_patterns, get_default_command,
+    get_inject_method, get_process_patterns, get_yolo_flags, is_agent_available, is_valid_agent,
+    resolve_agent, supported_agents_string, valid_agent_names,
 };
 pub use traits::AgentBackend;
 pub use types::{AgentType, InjectMethod};
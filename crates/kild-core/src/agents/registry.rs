@@ -3,8 +3,11 @@
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
+use kild_config::KildConfig;
+
 use super::backends::{
-    AmpBackend, ClaudeBackend, CodexBackend, GeminiBackend, KiroBackend, OpenCodeBackend,
+    AiderBackend, AmpBackend, ClaudeBackend, CodexBackend, ConfigAgentBackend, GeminiBackend,
+    KiroBackend, OpenCodeBackend,
 };
 use super::traits::AgentBackend;
 use super::types::{AgentType, InjectMethod};
@@ -23,6 +26,7 @@ struct AgentRegistry {
 impl AgentRegistry {
     fn new() -> Self {
         let mut backends: HashMap<AgentType, Box<dyn AgentBackend>> = HashMap::new();
+        backends.insert(AgentType::Aider, Box::new(AiderBackend));
         backends.insert(AgentType::Amp, Box::new(AmpBackend));
         backends.insert(AgentType::Claude, Box::new(ClaudeBackend));
         backends.insert(AgentType::Kiro, Box::new(KiroBackend));
@@ -53,6 +57,38 @@ pub fn get_agent(name: &str) -> Option<&'static dyn AgentBackend> {
     REGISTRY.get(name)
 }
 
+/// An agent backend resolved against the loaded config.
+pub enum ResolvedAgent {
+    /// Compiled-in backend from the global registry.
+    BuiltIn(&'static dyn AgentBackend),
+    /// Backend defined by an `[agents.<name>]` section with `binary` set.
+    Config(ConfigAgentBackend),
+}
+
+impl ResolvedAgent {
+    pub fn backend(&self) -> &dyn AgentBackend {
+        match self {
+            ResolvedAgent::BuiltIn(backend) => *backend,
+            ResolvedAgent::Config(backend) => backend,
+        }
+    }
+}
+
+/// Resolve an agent by name, merging config-defined agents with compiled-in ones.
+///
+/// An `[agents.<name>]` section with `binary` set takes precedence over a
+/// built-in of the same name. Sections without `binary` only adjust the
+/// command/flags (see `KildConfig::get_agent_command`) and resolve to the built-in.
+pub fn resolve_agent(config: &KildConfig, name: &str) -> Option<ResolvedAgent> {
+    let builtin = get_agent(name);
+    if let Some(settings) = config.agents.get(name)
+        && let Some(backend) = ConfigAgentBackend::from_settings(name, settings, builtin)
+    {
+        return Some(ResolvedAgent::Config(backend));
+    }
+    builtin.map(ResolvedAgent::BuiltIn)
+}
+
 /// Get a reference to an agent backend by type.
 pub fn get_agent_by_type(agent_type: AgentType) -> Option<&'static dyn AgentBackend> {
     REGISTRY.get_by_type(agent_type)
@@ -116,24 +152,26 @@ pub fn supported_agents_string() -> String {
 /// Get all process patterns for an agent, including bidirectional resolution.
 ///
 /// Given a name, this:
-/// 1. Looks up patterns if `name` is a known agent name
+/// 1. Looks up patterns if `name` resolves to an agent (config-defined or built-in)
 /// 2. Looks up which agent owns `name` if it's a known process pattern
 ///
 /// Returns deduplicated combined patterns, or empty vec if no match.
-pub fn get_all_process_patterns(name: &str) -> Vec<String> {
+pub fn get_all_process_patterns(config: &KildConfig, name: &str) -> Vec<String> {
     let mut patterns = Vec::new();
 
     // Forward: name is an agent name → get its patterns
-    if let Some(agent_patterns) = get_process_patterns(name) {
-        patterns.extend(agent_patterns);
+    if let Some(agent) = resolve_agent(config, name) {
+        patterns.extend(agent.backend().process_patterns());
     }
 
     // Reverse: name is a process pattern → find owning agent's patterns
-    for agent_name in valid_agent_names() {
-        if let Some(agent_patterns) = get_process_patterns(agent_name)
-            && agent_patterns.iter().any(|p| p == name)
-        {
-            patterns.extend(agent_patterns);
+    let config_agents = config.agents.keys().map(String::as_str);
+    for agent_name in valid_agent_names().into_iter().chain(config_agents) {
+        if let Some(agent) = resolve_agent(config, agent_name) {
+            let agent_patterns = agent.backend().process_patterns();
+            if agent_patterns.iter().any(|p| p == name) {
+                patterns.extend(agent_patterns);
+            }
         }
     }
 
@@ -148,9 +186,28 @@ pub fn is_agent_available(name: &str) -> Option<bool> {
     get_agent(name).map(|backend| backend.is_available())
 }
 
+/// Get the installed CLI version for an agent by name (case-insensitive).
+///
+/// Resolves through [`resolve_agent`], so config-defined agents report the
+/// version of their own `binary`. Returns `None` for unknown agents and for
+/// agents whose CLI is not installed.
+pub fn get_agent_version(config: &KildConfig, name: &str) -> Option<String> {
+    resolve_agent(config, name).and_then(|agent| agent.backend().version())
+}
+
+/// Like [`get_agent_version`], but never blocks on a `--version` probe.
+///
+/// Returns `None` until a background probe for the agent's binary has
+/// completed. Use on UI refresh paths.
+pub fn cached_agent_version(config: &KildConfig, name: &str) -> Option<String> {
+    resolve_agent(config, name)
+        .and_then(|agent| super::backends::cached_binary_version(agent.backend().binary()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use kild_config::AgentSettings;
 
     #[test]
     fn test_get_agent_known() {
@@ -190,6 +247,7 @@ mod tests {
 
     #[test]
     fn test_is_valid_agent() {
+        assert!(is_valid_agent("aider"));
         assert!(is_valid_agent("amp"));
         assert!(is_valid_agent("claude"));
         assert!(is_valid_agent("kiro"));
@@ -208,8 +266,10 @@ mod tests {
     #[test]
     fn test_valid_agent_names() {
         let names = valid_agent_names();
-        assert_eq!(names.len(), 6);
-        for agent in ["amp", "claude", "kiro", "gemini", "codex", "opencode"] {
+        assert_eq!(names.len(), 7);
+        for agent in [
+            "aider", "amp", "claude", "kiro", "gemini", "codex", "opencode",
+        ] {
             assert!(names.contains(&agent));
         }
     }
@@ -230,6 +290,7 @@ mod tests {
             get_yolo_flags("claude"),
             Some("--dangerously-skip-permissions")
         );
+        assert_eq!(get_yolo_flags("aider"), Some("--yes-always"));
         assert_eq!(get_yolo_flags("amp"), Some("--dangerously-allow-all"));
         assert_eq!(get_yolo_flags("kiro"), Some("--trust-all-tools"));
         assert_eq!(get_yolo_flags("codex"), Some("--yolo"));
@@ -269,6 +330,7 @@ mod tests {
 
     #[test]
     fn test_get_default_command() {
+        assert_eq!(get_default_command("aider"), Some("aider"));
         assert_eq!(get_default_command("amp"), Some("amp"));
         assert_eq!(get_default_command("claude"), Some("claude"));
         assert_eq!(get_default_command("kiro"), Some("kiro-cli chat"));
@@ -306,10 +368,43 @@ mod tests {
         assert!(is_agent_available("unknown").is_none());
     }
 
+    #[test]
+    fn test_get_agent_version_unknown_agent_is_none() {
+        assert!(get_agent_version(&KildConfig::default(), "unknown").is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_agent_version_config_agent_uses_its_binary() {
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("myagent");
+        std::fs::write(&script, "#!/bin/sh\necho 'myagent 0.9.1'\n").unwrap();
+        std::fs::set_permissions(&script, std::os::unix::fs::PermissionsExt::from_mode(0o755))
+            .unwrap();
+
+        let mut config = KildConfig::default();
+        config.agents.insert(
+            "myagent".to_string(),
+            AgentSettings {
+                binary: Some(script.to_str().unwrap().to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            get_agent_version(&config, "myagent").as_deref(),
+            Some("myagent 0.9.1")
+        );
+        // Without the config section the agent is unknown
+        assert!(get_agent_version(&KildConfig::default(), "myagent").is_none());
+    }
+
     #[test]
     fn test_registry_contains_all_agents() {
         // Ensure all expected agents are registered
-        let expected_agents = ["amp", "claude", "kiro", "gemini", "codex", "opencode"];
+        let expected_agents = [
+            "aider", "amp", "claude", "kiro", "gemini", "codex", "opencode",
+        ];
         for agent in expected_agents {
             assert!(
                 is_valid_agent(agent),
@@ -322,6 +417,7 @@ mod tests {
     #[test]
     fn test_supported_agents_string() {
         let s = supported_agents_string();
+        assert!(s.contains("aider"));
         assert!(s.contains("amp"));
         assert!(s.contains("claude"));
         assert!(s.contains("kiro"));
@@ -349,21 +445,42 @@ mod tests {
 
     #[test]
     fn test_get_all_process_patterns() {
+        let config = KildConfig::default();
+
         // Forward lookup: agent name → patterns
-        let patterns = get_all_process_patterns("claude");
+        let patterns = get_all_process_patterns(&config, "claude");
         assert!(patterns.contains(&"claude".to_string()));
         assert!(patterns.contains(&"claude-code".to_string()));
 
         // Reverse lookup: process pattern → all agent patterns
-        let patterns = get_all_process_patterns("claude-code");
+        let patterns = get_all_process_patterns(&config, "claude-code");
         assert!(patterns.contains(&"claude".to_string()));
         assert!(patterns.contains(&"claude-code".to_string()));
 
         // Unknown name: empty
-        let patterns = get_all_process_patterns("unknown");
+        let patterns = get_all_process_patterns(&config, "unknown");
         assert!(patterns.is_empty());
     }
 
+    #[test]
+    fn test_get_all_process_patterns_config_agent() {
+        let mut config = KildConfig::default();
+        config.agents.insert(
+            "myagent".to_string(),
+            AgentSettings {
+                binary: Some("/opt/bin/my-agent".to_string()),
+                process_patterns: Some(vec!["my-agent".to_string(), "my-agent-worker".to_string()]),
+                ..Default::default()
+            },
+        );
+
+        let forward = get_all_process_patterns(&config, "myagent");
+        assert_eq!(forward, ["my-agent", "my-agent-worker"]);
+
+        let reverse = get_all_process_patterns(&config, "my-agent-worker");
+        assert_eq!(reverse, ["my-agent", "my-agent-worker"]);
+    }
+
     #[test]
     fn test_get_inject_method() {
         assert_eq!(get_inject_method("claude"), InjectMethod::ClaudeInbox);
@@ -372,12 +489,77 @@ mod tests {
 
         assert_eq!(get_inject_method("codex"), InjectMethod::Pty);
         assert_eq!(get_inject_method("gemini"), InjectMethod::Pty);
+        assert_eq!(get_inject_method("aider"), InjectMethod::Pty);
         assert_eq!(get_inject_method("amp"), InjectMethod::Pty);
         assert_eq!(get_inject_method("kiro"), InjectMethod::Pty);
         assert_eq!(get_inject_method("opencode"), InjectMethod::Pty);
         assert_eq!(get_inject_method("unknown"), InjectMethod::Pty);
     }
 
+    #[test]
+    fn test_resolve_agent_config_only_agent() {
+        let mut config = KildConfig::default();
+        config.agents.insert(
+            "myagent".to_string(),
+            AgentSettings {
+                binary: Some("myagent".to_string()),
+                startup_command: Some("myagent chat".to_string()),
+                yolo_flags: Some("--auto".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let resolved = resolve_agent(&config, "myagent").unwrap();
+        assert!(matches!(resolved, ResolvedAgent::Config(_)));
+        let backend = resolved.backend();
+        assert_eq!(backend.name(), "myagent");
+        assert_eq!(backend.default_command(), "myagent chat");
+        assert_eq!(backend.yolo_flags(), Some("--auto"));
+        assert_eq!(backend.process_patterns(), vec!["myagent".to_string()]);
+
+        // Not visible without the config
+        assert!(get_agent("myagent").is_none());
+    }
+
+    #[test]
+    fn test_resolve_agent_config_overrides_builtin() {
+        let mut config = KildConfig::default();
+        config.agents.insert(
+            "claude".to_string(),
+            AgentSettings {
+                binary: Some("claude-beta".to_string()),
+                yolo_flags: Some("--beta-yolo".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let resolved = resolve_agent(&config, "claude").unwrap();
+        assert!(matches!(resolved, ResolvedAgent::Config(_)));
+        assert_eq!(resolved.backend().default_command(), "claude-beta");
+        assert_eq!(resolved.backend().yolo_flags(), Some("--beta-yolo"));
+    }
+
+    #[test]
+    fn test_resolve_agent_settings_without_binary_keep_builtin() {
+        let mut config = KildConfig::default();
+        config.agents.insert(
+            "kiro".to_string(),
+            AgentSettings {
+                flags: Some("--verbose".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let resolved = resolve_agent(&config, "kiro").unwrap();
+        assert!(matches!(resolved, ResolvedAgent::BuiltIn(_)));
+        assert_eq!(resolved.backend().default_command(), "kiro-cli chat");
+    }
+
+    #[test]
+    fn test_resolve_agent_unknown() {
+        assert!(resolve_agent(&KildConfig::default(), "unknown").is_none());
+    }
+
     #[test]
     fn test_all_agent_types_have_backends() {
         // Verify every AgentType variant has a registered backend
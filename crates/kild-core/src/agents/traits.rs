@@ -4,18 +4,23 @@
 ///
 /// Each supported agent (Claude, Kiro, Gemini, etc.) implements this trait
 /// to provide agent-specific behavior like command construction and process detection.
+/// Compiled-in backends return `'static` data; config-defined backends
+/// (`ConfigAgentBackend`) borrow from their own fields.
 pub trait AgentBackend: Send + Sync {
     /// The canonical name of this agent (e.g., "claude", "kiro").
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
 
     /// The display name for this agent (e.g., "Claude Code", "Kiro CLI").
-    fn display_name(&self) -> &'static str;
+    fn display_name(&self) -> &str;
+
+    /// The executable name (or path) of this agent's CLI (e.g., "claude", "kiro-cli").
+    fn binary(&self) -> &str;
 
     /// Check if this agent's CLI is installed and available in PATH.
     fn is_available(&self) -> bool;
 
     /// Get the default command to launch this agent.
-    fn default_command(&self) -> &'static str;
+    fn default_command(&self) -> &str;
 
     /// Get process name patterns for detection.
     ///
@@ -24,11 +29,32 @@ pub trait AgentBackend: Send + Sync {
     /// as process name.
     fn process_patterns(&self) -> Vec<String>;
 
+    /// Check if a process command line belongs to this agent.
+    ///
+    /// Matches when any of [`process_patterns`](Self::process_patterns) is a
+    /// substring of the executable token (so `kiro-cli` matches
+    /// `/Users/me/.local/bin/kiro-cli-chat chat`) or a path component of a
+    /// wrapper's script argument (e.g. `node .../claude-code/cli.js`), including
+    /// commands run through a shell (`sh -c '... && exec claude'`).
+    fn matches_process(&self, cmdline: &str) -> bool {
+        self.process_patterns()
+            .iter()
+            .any(|pattern| crate::process::cmdline_matches_pattern(cmdline, pattern))
+    }
+
     /// Returns the CLI flags for "yolo mode" (full autonomy, skip all permission prompts).
     /// Returns `None` if the agent doesn't support autonomous mode.
-    fn yolo_flags(&self) -> Option<&'static str> {
+    fn yolo_flags(&self) -> Option<&str> {
         None
     }
+
+    /// Installed CLI version, from the first line of `<binary> --version`.
+    ///
+    /// Returns `None` when the binary isn't installed or the probe fails.
+    /// Results are cached per binary for the process lifetime.
+    fn version(&self) -> Option<String> {
+        crate::agents::backends::detect_binary_version(self.binary())
+    }
 }
 
 #[cfg(test)]
@@ -38,19 +64,23 @@ mod tests {
     struct MockBackend;
 
     impl AgentBackend for MockBackend {
-        fn name(&self) -> &'static str {
+        fn name(&self) -> &str {
             "mock"
         }
 
-        fn display_name(&self) -> &'static str {
+        fn display_name(&self) -> &str {
             "Mock Agent"
         }
 
+        fn binary(&self) -> &str {
+            "mock-cli"
+        }
+
         fn is_available(&self) -> bool {
             true
         }
 
-        fn default_command(&self) -> &'static str {
+        fn default_command(&self) -> &str {
             "mock-cli"
         }
 
@@ -68,4 +98,15 @@ mod tests {
         assert_eq!(backend.default_command(), "mock-cli");
         assert_eq!(backend.yolo_flags(), None);
     }
+
+    #[test]
+    fn matches_process_handles_absolute_paths_and_wrappers() {
+        let backend = MockBackend;
+        assert!(backend.matches_process("mock-cli"));
+        assert!(backend.matches_process("/Users/me/.local/bin/mock-cli-chat chat"));
+        assert!(backend.matches_process("node /usr/local/lib/node_modules/mock/cli.js --yes"));
+        assert!(backend.matches_process("sh -c exec mock-cli --yes"));
+        assert!(!backend.matches_process("vim notes-about-mocking.md"));
+        assert!(!backend.matches_process(""));
+    }
 }
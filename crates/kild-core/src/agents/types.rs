@@ -25,6 +25,7 @@ pub enum InjectMethod {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AgentType {
+    Aider,
     Amp,
     Claude,
     Kiro,
@@ -37,6 +38,7 @@ impl AgentType {
     /// Get the canonical string name for this agent type.
     pub fn as_str(&self) -> &'static str {
         match self {
+            AgentType::Aider => "aider",
             AgentType::Amp => "amp",
             AgentType::Claude => "claude",
             AgentType::Kiro => "kiro",
@@ -49,6 +51,7 @@ impl AgentType {
     /// Parse an agent type from a string (case-insensitive).
     pub fn parse(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
+            "aider" => Some(AgentType::Aider),
             "amp" => Some(AgentType::Amp),
             "claude" => Some(AgentType::Claude),
             "kiro" => Some(AgentType::Kiro),
@@ -62,6 +65,7 @@ impl AgentType {
     /// Get all supported agent types.
     pub fn all() -> &'static [AgentType] {
         &[
+            AgentType::Aider,
             AgentType::Amp,
             AgentType::Claude,
             AgentType::Kiro,
@@ -99,6 +103,7 @@ mod tests {
 
     #[test]
     fn test_agent_type_as_str() {
+        assert_eq!(AgentType::Aider.as_str(), "aider");
         assert_eq!(AgentType::Amp.as_str(), "amp");
         assert_eq!(AgentType::Claude.as_str(), "claude");
         assert_eq!(AgentType::Kiro.as_str(), "kiro");
@@ -120,7 +125,8 @@ mod tests {
     #[test]
     fn test_agent_type_all() {
         let all = AgentType::all();
-        assert_eq!(all.len(), 6);
+        assert_eq!(all.len(), 7);
+        assert!(all.contains(&AgentType::Aider));
         assert!(all.contains(&AgentType::Amp));
         assert!(all.contains(&AgentType::Claude));
         assert!(all.contains(&AgentType::Kiro));
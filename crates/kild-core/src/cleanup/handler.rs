@@ -5,6 +5,7 @@ use crate::cleanup::{errors::CleanupError, operations, types::*};
 use crate::git;
 use crate::sessions;
 use kild_config::Config;
+use kild_paths::KildPaths;
 
 pub fn scan_for_orphans() -> Result<CleanupSummary, CleanupError> {
     info!(event = "core.cleanup.scan_started");
@@ -57,12 +58,28 @@ pub fn scan_for_orphans() -> Result<CleanupSummary, CleanupError> {
         summary.add_session(session_id);
     }
 
+    // Detect shim state left behind by sessions that no longer exist
+    let orphaned_shim_dirs =
+        operations::detect_orphaned_shim_dirs(&config.paths().shim_dir(), &config.sessions_dir())
+            .map_err(|e| {
+            error!(event = "core.cleanup.scan_shim_dirs_failed", error = %e);
+            e
+        })?;
+    info!(
+        event = "core.cleanup.scan_shim_dirs_completed",
+        count = orphaned_shim_dirs.len()
+    );
+    for session_id in orphaned_shim_dirs {
+        summary.add_shim_dir(session_id);
+    }
+
     info!(
         event = "core.cleanup.scan_completed",
         total_orphaned = summary.total_cleaned,
         branches = summary.orphaned_branches.len(),
         worktrees = summary.orphaned_worktrees.len(),
-        sessions = summary.stale_sessions.len()
+        sessions = summary.stale_sessions.len(),
+        shim_dirs = summary.orphaned_shim_dirs.len()
     );
 
     Ok(summary)
@@ -121,6 +138,16 @@ pub fn cleanup_orphaned_resources(
         }
     }
 
+    // Clean up orphaned shim state
+    if !summary.orphaned_shim_dirs.is_empty() {
+        let config = Config::new();
+        for session_id in
+            cleanup_orphaned_shim_dirs_in(&config.paths().shim_dir(), &summary.orphaned_shim_dirs)
+        {
+            cleaned_summary.add_shim_dir(session_id);
+        }
+    }
+
     info!(
         event = "core.cleanup.cleanup_completed",
         total_cleaned = cleaned_summary.total_cleaned
@@ -171,6 +198,7 @@ pub fn cleanup_all_with_strategy(
     if scan_summary.stale_sessions.is_empty()
         && scan_summary.orphaned_branches.is_empty()
         && scan_summary.orphaned_worktrees.is_empty()
+        && scan_summary.orphaned_shim_dirs.is_empty()
     {
         info!(event = "core.cleanup.cleanup_all_with_strategy_no_resources");
         return Err(CleanupError::NoOrphanedResources);
@@ -306,6 +334,23 @@ pub fn scan_for_orphans_with_strategy(
             for branch in orphaned_branches {
                 summary.add_branch(branch);
             }
+
+            // Shim state without a session is orphaned the same way
+            let orphaned_shim_dirs = operations::detect_orphaned_shim_dirs(
+                &config.paths().shim_dir(),
+                &config.sessions_dir(),
+            )
+            .map_err(|e| {
+                error!(event = "core.cleanup.strategy_failed", strategy = "Orphans", error = %e);
+                CleanupError::StrategyFailed {
+                    strategy: "Orphans".to_string(),
+                    source: Box::new(e),
+                }
+            })?;
+
+            for session_id in orphaned_shim_dirs {
+                summary.add_shim_dir(session_id);
+            }
         }
     }
 
@@ -518,6 +563,61 @@ fn cleanup_orphaned_worktrees(
     Ok((cleaned_worktrees, skipped_worktrees))
 }
 
+/// Remove orphaned shim state dirs under `shim_dir`.
+///
+/// Re-checks `panes.lock` right before removal since a shim command may have
+/// started after the scan. Empty parent dirs (the project level of the
+/// session id) are pruned up to `shim_dir`. Returns the session ids removed.
+fn cleanup_orphaned_shim_dirs_in(shim_dir: &Path, session_ids: &[String]) -> Vec<String> {
+    let mut cleaned = Vec::new();
+
+    for session_id in session_ids {
+        let dir = shim_dir.join(session_id);
+        if !dir.exists() {
+            continue;
+        }
+        if operations::is_shim_lock_held(&dir) {
+            warn!(
+                event = "core.cleanup.shim_dir_skipped_locked",
+                session_id = session_id,
+                path = %dir.display(),
+            );
+            continue;
+        }
+
+        match std::fs::remove_dir_all(&dir) {
+            Ok(()) => {
+                info!(
+                    event = "core.cleanup.shim_dir_removed",
+                    session_id = session_id,
+                    path = %dir.display(),
+                );
+                cleaned.push(session_id.clone());
+            }
+            Err(e) => {
+                warn!(
+                    event = "core.cleanup.shim_dir_remove_failed",
+                    session_id = session_id,
+                    path = %dir.display(),
+                    error = %e,
+                );
+                continue;
+            }
+        }
+
+        // remove_dir only succeeds on empty dirs, so siblings are never touched
+        let mut parent = dir.parent();
+        while let Some(p) = parent {
+            if p == shim_dir || !p.starts_with(shim_dir) || std::fs::remove_dir(p).is_err() {
+                break;
+            }
+            parent = p.parent();
+        }
+    }
+
+    cleaned
+}
+
 /// Load minimal session data needed for worktree cleanup.
 ///
 /// Returns `(worktree_path, use_main_worktree, branch)` or `None` if the
@@ -526,10 +626,18 @@ fn load_session_for_cleanup(
     sessions_dir: &Path,
     session_id: &str,
 ) -> Option<(PathBuf, bool, String)> {
+    // Directories written before ids were percent-encoded use `/` → `_`
     let safe_id = session_id.replace('/', "_");
 
-    // Try new format: <sessions_dir>/<safe_id>/kild.json
-    let new_path = sessions_dir.join(&safe_id).join("kild.json");
+    // Try new format: <sessions_dir>/<encoded_id>/kild.json, then <safe_id>/kild.json
+    let encoded_path = sessions_dir
+        .join(KildPaths::encode_session_id(session_id))
+        .join("kild.json");
+    let new_path = if encoded_path.exists() {
+        encoded_path
+    } else {
+        sessions_dir.join(&safe_id).join("kild.json")
+    };
     let content = if new_path.exists() {
         match std::fs::read_to_string(&new_path) {
             Ok(c) => c,
@@ -1247,4 +1355,50 @@ mod tests {
         let result = load_session_for_cleanup(sessions_dir, "kild/test-branch");
         assert!(result.is_some());
     }
+
+    #[test]
+    fn test_cleanup_orphaned_shim_dirs_removes_only_dead_session() {
+        let kild_dir = tempfile::TempDir::new().unwrap();
+        let sessions_dir = kild_dir.path().join("sessions");
+        let shim_dir = kild_dir.path().join("shim");
+
+        let live_session = sessions_dir.join("proj_live");
+        std::fs::create_dir_all(&live_session).unwrap();
+        std::fs::write(live_session.join("kild.json"), r#"{"id": "proj/live"}"#).unwrap();
+
+        for id in ["proj/live", "proj/dead", "other/dead"] {
+            let dir = shim_dir.join(id);
+            std::fs::create_dir_all(dir.join("zdotdir")).unwrap();
+            std::fs::write(dir.join("panes.json"), r#"{"panes":{}}"#).unwrap();
+            std::fs::write(dir.join("panes.lock"), "").unwrap();
+        }
+
+        let orphaned = operations::detect_orphaned_shim_dirs(&shim_dir, &sessions_dir).unwrap();
+        assert_eq!(orphaned, vec!["other/dead", "proj/dead"]);
+
+        let cleaned = cleanup_orphaned_shim_dirs_in(&shim_dir, &orphaned);
+        assert_eq!(cleaned, orphaned);
+
+        assert!(shim_dir.join("proj/live/panes.json").exists());
+        assert!(!shim_dir.join("proj/dead").exists());
+        // Project dir emptied by the removal is pruned
+        assert!(!shim_dir.join("other").exists());
+        assert!(shim_dir.exists());
+    }
+
+    #[test]
+    fn test_cleanup_orphaned_shim_dirs_skips_locked_dir() {
+        use nix::fcntl::{Flock, FlockArg};
+
+        let kild_dir = tempfile::TempDir::new().unwrap();
+        let shim_dir = kild_dir.path().join("shim");
+        let dead = shim_dir.join("proj/dead");
+        std::fs::create_dir_all(&dead).unwrap();
+        let lock_file = std::fs::File::create(dead.join("panes.lock")).unwrap();
+        let _guard = Flock::lock(lock_file, FlockArg::LockExclusive).unwrap();
+
+        let cleaned = cleanup_orphaned_shim_dirs_in(&shim_dir, &["proj/dead".to_string()]);
+        assert!(cleaned.is_empty());
+        assert!(dead.join("panes.lock").exists());
+    }
 }
@@ -5,10 +5,13 @@
 //! - detect_sessions_older_than: Stopped sessions older than N days
 //! - detect_orphaned_branches: Git branches without corresponding sessions
 //! - detect_orphaned_worktrees: Worktrees without corresponding sessions
+//! - detect_orphaned_shim_dirs: Shim pane registries without corresponding sessions
 
 use crate::cleanup::errors::CleanupError;
 use crate::git;
 use chrono::Utc;
+use kild_paths::KildPaths;
+use nix::fcntl::{Flock, FlockArg};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use tracing::{info, warn};
@@ -270,6 +273,10 @@ pub fn detect_stale_sessions(sessions_dir: &Path) -> Result<Vec<String>, Cleanup
                 // Try to parse as JSON to validate it's a proper session file
                 match serde_json::from_str::<serde_json::Value>(&content) {
                     Ok(session) => {
+                        // Archived kilds may have had their worktree removed on purpose
+                        if session.get("status").and_then(|v| v.as_str()) == Some("archived") {
+                            continue;
+                        }
                         // Check if the worktree path exists
                         if let Some(worktree_path) =
                             session.get("worktree_path").and_then(|v| v.as_str())
@@ -318,6 +325,149 @@ pub fn detect_stale_sessions(sessions_dir: &Path) -> Result<Vec<String>, Cleanup
     Ok(stale_sessions)
 }
 
+/// Entries that mark a directory under `~/.kild/shim/` as a session's shim state.
+const SHIM_STATE_ENTRIES: &[&str] = &["panes.json", "panes.lock", "zdotdir"];
+
+/// Detect shim state directories whose session no longer exists.
+///
+/// Shim state lives at `<shim_dir>/<session_id>/`, and session ids contain a
+/// `/`, so the directory is found by walking down to the first level holding
+/// `panes.json`, `panes.lock` or `zdotdir`. Returns the session ids of dirs
+/// with no matching session file. Dirs whose `panes.lock` is currently held
+/// by another process are skipped.
+pub fn detect_orphaned_shim_dirs(
+    shim_dir: &Path,
+    sessions_dir: &Path,
+) -> Result<Vec<String>, CleanupError> {
+    if !shim_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let live_ids = collect_live_session_safe_ids(sessions_dir)?;
+
+    let mut shim_session_ids = Vec::new();
+    collect_shim_session_ids(shim_dir, shim_dir, &mut shim_session_ids)?;
+
+    let mut orphaned = Vec::new();
+    for session_id in shim_session_ids {
+        // Live sessions may still sit under the legacy `/` → `_` name
+        if live_ids.contains(&KildPaths::encode_session_id(&session_id))
+            || live_ids.contains(&session_id.replace('/', "_"))
+        {
+            continue;
+        }
+        if is_shim_lock_held(&shim_dir.join(&session_id)) {
+            info!(
+                event = "core.cleanup.shim_dir_locked",
+                session_id = session_id,
+                "Skipping orphaned shim dir: panes.lock is held by another process"
+            );
+            continue;
+        }
+        orphaned.push(session_id);
+    }
+
+    orphaned.sort();
+    Ok(orphaned)
+}
+
+/// Collect the on-disk (encoded or legacy slash-escaped) ids of every session file.
+///
+/// Uses directory and file names rather than parsing JSON, so an unreadable
+/// session still counts as live and its shim state is left alone.
+fn collect_live_session_safe_ids(sessions_dir: &Path) -> Result<HashSet<String>, CleanupError> {
+    let mut ids = HashSet::new();
+
+    if !sessions_dir.exists() {
+        return Ok(ids);
+    }
+
+    let entries =
+        std::fs::read_dir(sessions_dir).map_err(|e| CleanupError::IoError { source: e })?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| CleanupError::IoError { source: e })?;
+        let path = entry.path();
+
+        // Support both storage formats:
+        // - New (current): <sessions_dir>/<safe_id>/kild.json
+        // - Old (legacy):  <sessions_dir>/<safe_id>.json
+        let safe_id = if path.is_dir() && path.join("kild.json").exists() {
+            path.file_name()
+        } else if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+            path.file_stem()
+        } else {
+            continue;
+        };
+
+        if let Some(safe_id) = safe_id.and_then(|s| s.to_str()) {
+            ids.insert(safe_id.to_string());
+        }
+    }
+
+    Ok(ids)
+}
+
+/// Recursively collect session ids (paths relative to `shim_dir`) of shim state dirs.
+fn collect_shim_session_ids(
+    shim_dir: &Path,
+    dir: &Path,
+    ids: &mut Vec<String>,
+) -> Result<(), CleanupError> {
+    let entries = std::fs::read_dir(dir).map_err(|e| CleanupError::IoError { source: e })?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| CleanupError::IoError { source: e })?;
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let is_session_dir = SHIM_STATE_ENTRIES
+            .iter()
+            .any(|name| path.join(name).exists());
+        if !is_session_dir {
+            collect_shim_session_ids(shim_dir, &path, ids)?;
+            continue;
+        }
+
+        let Ok(relative) = path.strip_prefix(shim_dir) else {
+            continue;
+        };
+        let components: Vec<&str> = relative
+            .components()
+            .filter_map(|c| c.as_os_str().to_str())
+            .collect();
+        ids.push(components.join("/"));
+    }
+
+    Ok(())
+}
+
+/// Check whether another process holds the flock on a shim dir's `panes.lock`.
+///
+/// The tmux shim takes an exclusive flock while mutating the registry, so a
+/// held lock means a shim command is running against this dir right now. Any
+/// failure other than a missing lock file is treated as held.
+pub fn is_shim_lock_held(shim_session_dir: &Path) -> bool {
+    let lock_path = shim_session_dir.join("panes.lock");
+    let file = match std::fs::OpenOptions::new().write(true).open(&lock_path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return false,
+        Err(e) => {
+            warn!(
+                event = "core.cleanup.shim_lock_open_failed",
+                path = %lock_path.display(),
+                error = %e,
+            );
+            return true;
+        }
+    };
+
+    // The lock is released as soon as the returned guard drops
+    Flock::lock(file, FlockArg::LockExclusiveNonblock).is_err()
+}
+
 /// Detect stopped sessions whose last activity is older than `days` days.
 ///
 /// Uses `last_activity` if present, falling back to `created_at`. Only returns
@@ -491,6 +641,27 @@ mod tests {
         assert_eq!(stale_sessions[0], "stale-session");
     }
 
+    #[test]
+    fn test_detect_stale_sessions_skips_archived_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let test_path = temp_dir.path();
+
+        let nonexistent_path = test_path.join("nonexistent_worktree");
+        let session_content = serde_json::json!({
+            "id": "archived-session",
+            "worktree_path": nonexistent_path.to_str().unwrap(),
+            "branch": "archived-branch",
+            "agent": "test-agent",
+            "status": "archived"
+        });
+
+        let session_file = test_path.join("archived-session.json");
+        fs::write(session_file, session_content.to_string()).unwrap();
+
+        let stale_sessions = detect_stale_sessions(test_path).unwrap();
+        assert!(stale_sessions.is_empty());
+    }
+
     #[test]
     fn test_detect_stale_sessions_with_invalid_json() {
         let temp_dir = TempDir::new().unwrap();
@@ -779,4 +950,68 @@ mod tests {
         let results = detect_sessions_older_than(temp_dir.path(), 7).unwrap();
         assert!(results.is_empty());
     }
+
+    // --- detect_orphaned_shim_dirs tests ---
+
+    fn write_shim_state(shim_dir: &Path, session_id: &str) -> PathBuf {
+        let dir = shim_dir.join(session_id);
+        fs::create_dir_all(dir.join("zdotdir")).unwrap();
+        fs::write(dir.join("panes.json"), r#"{"panes":{}}"#).unwrap();
+        fs::write(dir.join("panes.lock"), "").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_detect_orphaned_shim_dirs_live_and_orphaned() {
+        let temp_dir = TempDir::new().unwrap();
+        let sessions_dir = temp_dir.path().join("sessions");
+        let shim_dir = temp_dir.path().join("shim");
+
+        write_session_json(&sessions_dir, "proj_live", "active", None);
+        write_shim_state(&shim_dir, "proj/live");
+        write_shim_state(&shim_dir, "proj/dead");
+
+        let orphaned = detect_orphaned_shim_dirs(&shim_dir, &sessions_dir).unwrap();
+        assert_eq!(orphaned, vec!["proj/dead"]);
+    }
+
+    #[test]
+    fn test_detect_orphaned_shim_dirs_legacy_session_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let sessions_dir = temp_dir.path().join("sessions");
+        let shim_dir = temp_dir.path().join("shim");
+
+        fs::create_dir_all(&sessions_dir).unwrap();
+        fs::write(sessions_dir.join("proj_legacy.json"), "not json").unwrap();
+        write_shim_state(&shim_dir, "proj/legacy");
+
+        let orphaned = detect_orphaned_shim_dirs(&shim_dir, &sessions_dir).unwrap();
+        assert!(
+            orphaned.is_empty(),
+            "unreadable sessions still count as live"
+        );
+    }
+
+    #[test]
+    fn test_detect_orphaned_shim_dirs_skips_held_lock() {
+        let temp_dir = TempDir::new().unwrap();
+        let sessions_dir = temp_dir.path().join("sessions");
+        let shim_dir = temp_dir.path().join("shim");
+
+        let dead = write_shim_state(&shim_dir, "proj/dead");
+        let lock_file = fs::File::create(dead.join("panes.lock")).unwrap();
+        let _guard = Flock::lock(lock_file, FlockArg::LockExclusive).unwrap();
+
+        assert!(is_shim_lock_held(&dead));
+        let orphaned = detect_orphaned_shim_dirs(&shim_dir, &sessions_dir).unwrap();
+        assert!(orphaned.is_empty());
+    }
+
+    #[test]
+    fn test_detect_orphaned_shim_dirs_missing_shim_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let orphaned =
+            detect_orphaned_shim_dirs(&temp_dir.path().join("shim"), temp_dir.path()).unwrap();
+        assert!(orphaned.is_empty());
+    }
 }
@@ -30,6 +30,8 @@ pub struct CleanupSummary {
     pub orphaned_branches: Vec<String>,
     pub orphaned_worktrees: Vec<PathBuf>,
     pub stale_sessions: Vec<String>,
+    /// Session ids whose `~/.kild/shim/<session_id>/` state was orphaned.
+    pub orphaned_shim_dirs: Vec<String>,
     pub skipped_worktrees: Vec<(PathBuf, String)>,
     pub total_cleaned: usize,
 }
@@ -56,6 +58,7 @@ impl CleanupSummary {
             orphaned_branches: Vec::new(),
             orphaned_worktrees: Vec::new(),
             stale_sessions: Vec::new(),
+            orphaned_shim_dirs: Vec::new(),
             skipped_worktrees: Vec::new(),
             total_cleaned: 0,
         }
@@ -80,6 +83,11 @@ impl CleanupSummary {
         self.stale_sessions.push(session_id);
         self.total_cleaned += 1;
     }
+
+    pub fn add_shim_dir(&mut self, session_id: String) {
+        self.orphaned_shim_dirs.push(session_id);
+        self.total_cleaned += 1;
+    }
 }
 
 impl Default for CleanupSummary {
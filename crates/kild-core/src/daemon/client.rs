@@ -24,13 +24,6 @@ use tracing::{debug, info, warn};
 /// The connection is taken from the pool (exclusive ownership) and must be
 /// returned with `return_connection()` after successful use.
 fn get_connection() -> Result<IpcConnection, DaemonClientError> {
-    // CLI --remote override takes precedence over config file.
-    if let Some((host, fingerprint)) = crate::daemon::remote_override() {
-        debug!(event = "core.daemon.tcp_connection_override", host = %host);
-        return get_tls_connection(&host, fingerprint.as_deref());
-    }
-
-    // Config file remote_host takes precedence over local Unix socket.
     let config = match kild_config::KildConfig::load_hierarchy() {
         Ok(c) => c,
         Err(e) => {
@@ -43,20 +36,36 @@ fn get_connection() -> Result<IpcConnection, DaemonClientError> {
             kild_config::KildConfig::default()
         }
     };
+    let auth_token = config.daemon.remote_auth_token.as_deref();
+    let client_identity = config
+        .daemon
+        .remote_client_identity()
+        .map_err(|message| DaemonClientError::ConnectionFailed { message });
+
+    // CLI --remote override takes precedence over config file.
+    if let Some((host, fingerprint)) = crate::daemon::remote_override() {
+        debug!(event = "core.daemon.tcp_connection_override", host = %host);
+        return get_tls_connection(&host, fingerprint.as_deref(), auth_token, client_identity?);
+    }
+
+    // Config file remote_host takes precedence over local Unix socket.
     if let Some(ref remote_host) = config.daemon.remote_host {
         debug!(event = "core.daemon.tcp_connection_config", host = %remote_host);
         return get_tls_connection(
             remote_host,
             config.daemon.remote_cert_fingerprint.as_deref(),
+            auth_token,
+            client_identity?,
         );
     }
 
     let socket_path = crate::daemon::socket_path();
-    let (conn, reused) = kild_protocol::pool::take(&socket_path)?;
+    let (mut conn, reused) = kild_protocol::pool::take(&socket_path)?;
     if reused {
         debug!(event = "core.daemon.connection_reused");
     } else {
         debug!(event = "core.daemon.connection_created");
+        handshake(&mut conn)?;
     }
     Ok(conn)
 }
@@ -66,9 +75,14 @@ fn get_connection() -> Result<IpcConnection, DaemonClientError> {
 /// No connection caching: probing a `StreamOwned<ClientConnection, TcpStream>`
 /// with a 1ms read timeout can corrupt the TLS state machine. CLI commands are
 /// one-shot; fresh connection cost is acceptable (dominated by network latency).
+///
+/// `client_identity` is the (cert, key) PEM path pair presented to daemons
+/// that require mutual TLS.
 fn get_tls_connection(
     addr: &str,
     fingerprint_str: Option<&str>,
+    auth_token: Option<&str>,
+    client_identity: Option<(&Path, &Path)>,
 ) -> Result<IpcConnection, DaemonClientError> {
     let fp_str = fingerprint_str.ok_or_else(|| DaemonClientError::ConnectionFailed {
         message: "remote_host is set but remote_cert_fingerprint is missing — \
@@ -81,7 +95,36 @@ fn get_tls_connection(
 
     let verifier = crate::daemon::tofu::TofuVerifier::new(fingerprint);
 
-    IpcConnection::connect_tls(addr, verifier).map_err(Into::into)
+    let mut conn = match client_identity {
+        Some((cert_path, key_path)) => {
+            let (cert_chain, key) = crate::daemon::tofu::load_client_identity(cert_path, key_path)
+                .map_err(|message| DaemonClientError::ConnectionFailed { message })?;
+            IpcConnection::connect_mtls(addr, verifier, cert_chain, key)?
+        }
+        None => IpcConnection::connect_tls(addr, verifier)?,
+    };
+    if let Some(token) = auth_token {
+        conn = conn.with_auth_token(token);
+    }
+    handshake(&mut conn)?;
+    Ok(conn)
+}
+
+/// Negotiate the protocol version on a fresh connection.
+fn handshake(conn: &mut IpcConnection) -> Result<(), DaemonClientError> {
+    match conn.handshake() {
+        Ok(version) => {
+            debug!(
+                event = "core.daemon.handshake_completed",
+                protocol_version = version
+            );
+            Ok(())
+        }
+        Err(e) => {
+            warn!(event = "core.daemon.handshake_failed", error = %e);
+            Err(e.into())
+        }
+    }
 }
 
 /// Return a connection to the pool for reuse by the next call.
@@ -105,6 +148,17 @@ pub struct DaemonCreateResult {
     pub daemon_session_id: String,
 }
 
+/// Runtime counters reported by the daemon.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct DaemonStats {
+    pub uptime_secs: u64,
+    pub session_count: usize,
+    pub active_ptys: usize,
+    /// PTY output bytes delivered to attached clients since the daemon started.
+    pub bytes_streamed: u64,
+    pub connected_clients: usize,
+}
+
 /// Error communicating with the daemon.
 #[derive(Debug, thiserror::Error)]
 pub enum DaemonClientError {
@@ -120,6 +174,9 @@ pub enum DaemonClientError {
     #[error("IPC protocol error: {message}")]
     ProtocolError { message: String },
 
+    #[error("{message}")]
+    VersionMismatch { message: String },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -131,12 +188,16 @@ impl KildError for DaemonClientError {
             DaemonClientError::ConnectionFailed { .. } => "DAEMON_CONNECTION_FAILED",
             DaemonClientError::DaemonError { .. } => "DAEMON_ERROR",
             DaemonClientError::ProtocolError { .. } => "DAEMON_PROTOCOL_ERROR",
+            DaemonClientError::VersionMismatch { .. } => "DAEMON_VERSION_MISMATCH",
             DaemonClientError::Io(_) => "DAEMON_IO_ERROR",
         }
     }
 
     fn is_user_error(&self) -> bool {
-        matches!(self, DaemonClientError::NotRunning { .. })
+        matches!(
+            self,
+            DaemonClientError::NotRunning { .. } | DaemonClientError::VersionMismatch { .. }
+        )
     }
 }
 
@@ -144,6 +205,9 @@ impl From<IpcError> for DaemonClientError {
     fn from(e: IpcError) -> Self {
         match e {
             IpcError::NotRunning { path } => DaemonClientError::NotRunning { path },
+            // Stale socket already removed; report plain not-running so callers
+            // like `ensure_daemon_running` start a fresh daemon.
+            IpcError::DaemonNotRunning { path, .. } => DaemonClientError::NotRunning { path },
             IpcError::ConnectionFailed(io) => DaemonClientError::ConnectionFailed {
                 message: io.to_string(),
             },
@@ -152,6 +216,9 @@ impl From<IpcError> for DaemonClientError {
             }
             IpcError::ProtocolError { message } => DaemonClientError::ProtocolError { message },
             IpcError::Io(io) => DaemonClientError::Io(io),
+            e @ IpcError::VersionMismatch { .. } => DaemonClientError::VersionMismatch {
+                message: e.to_string(),
+            },
             // IpcError::TlsConfig is available because kild-core enables kild-protocol/tcp
             IpcError::TlsConfig(msg) => DaemonClientError::ConnectionFailed { message: msg },
             other => DaemonClientError::ProtocolError {
@@ -638,6 +705,49 @@ pub fn list_daemon_sessions() -> Result<Vec<kild_protocol::DaemonSessionStatus>,
     }
 }
 
+/// Ask the daemon for its runtime counters.
+pub fn daemon_stats() -> Result<DaemonStats, DaemonClientError> {
+    debug!(event = "core.daemon.stats_started");
+
+    let request = ClientMessage::Stats {
+        id: "stats".to_string(),
+    };
+
+    let mut conn = get_connection()?;
+
+    match conn.send(&request) {
+        Ok(DaemonMessage::Stats {
+            uptime_secs,
+            session_count,
+            active_ptys,
+            bytes_streamed,
+            connected_clients,
+            ..
+        }) => {
+            return_connection(conn);
+            debug!(event = "core.daemon.stats_completed");
+            Ok(DaemonStats {
+                uptime_secs,
+                session_count,
+                active_ptys,
+                bytes_streamed,
+                connected_clients,
+            })
+        }
+        Ok(_) => Err(DaemonClientError::ProtocolError {
+            message: "Expected Stats response".to_string(),
+        }),
+        Err(IpcError::DaemonError { code, message }) => {
+            return_connection(conn);
+            Err(DaemonClientError::DaemonError { code, message })
+        }
+        Err(e) => {
+            warn!(event = "core.daemon.stats_failed", error = %e);
+            Err(e.into())
+        }
+    }
+}
+
 /// Request the daemon to shut down gracefully.
 pub fn request_shutdown() -> Result<(), DaemonClientError> {
     info!(event = "core.daemon.shutdown_started");
@@ -663,6 +773,42 @@ pub fn request_shutdown() -> Result<(), DaemonClientError> {
     }
 }
 
+/// Ask the daemon to hand off to a replacement binary.
+///
+/// The daemon stops accepting connections, waits up to `drain_timeout` for
+/// attached streams to finish, saves session metadata and exits without
+/// stopping sessions. Sessions are not preserved: the PTYs close with the
+/// old daemon, which hangs up their processes. The next daemon only lists
+/// processes that survived that as reconnected; they can be stopped but not
+/// attached.
+pub fn request_handoff(drain_timeout: Duration) -> Result<(), DaemonClientError> {
+    info!(
+        event = "core.daemon.handoff_started",
+        drain_timeout_ms = drain_timeout.as_millis() as u64,
+    );
+
+    let request = ClientMessage::Handoff {
+        id: "handoff".to_string(),
+        drain_timeout_ms: drain_timeout.as_millis() as u64,
+    };
+
+    let mut conn = get_connection()?;
+    match conn.send(&request) {
+        Ok(_) => {
+            // Don't return connection — daemon is exiting
+            info!(event = "core.daemon.handoff_completed");
+            Ok(())
+        }
+        Err(IpcError::DaemonError { code, message }) => {
+            Err(DaemonClientError::DaemonError { code, message })
+        }
+        Err(e) => {
+            warn!(event = "core.daemon.handoff_failed", error = %e);
+            Err(e.into())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -763,6 +909,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_ipc_error_stale_socket_is_not_running() {
+        // ping_daemon maps NotRunning to Ok(false), which lets
+        // ensure_daemon_running spawn a fresh daemon
+        let ipc_err = IpcError::DaemonNotRunning {
+            path: "/tmp/test.sock".to_string(),
+            pid: 4242,
+        };
+        let daemon_err: DaemonClientError = ipc_err.into();
+        assert!(
+            matches!(daemon_err, DaemonClientError::NotRunning { path } if path == "/tmp/test.sock")
+        );
+    }
+
     #[test]
     fn test_from_ipc_error_daemon_error() {
         let ipc_err = IpcError::DaemonError {
@@ -799,6 +959,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_ipc_error_version_mismatch() {
+        let ipc_err = IpcError::VersionMismatch {
+            client_version: 2,
+            daemon_version: 1,
+            min_supported: 1,
+        };
+        let daemon_err: DaemonClientError = ipc_err.into();
+        assert!(daemon_err.is_user_error());
+        assert_eq!(daemon_err.error_code(), "DAEMON_VERSION_MISMATCH");
+        assert!(daemon_err.to_string().contains("kild daemon restart"));
+    }
+
     #[test]
     fn test_from_ipc_error_io() {
         let io_err = std::io::Error::new(std::io::ErrorKind::TimedOut, "timeout");
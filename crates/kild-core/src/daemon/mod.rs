@@ -192,7 +192,81 @@ pub fn set_remote_override(host: &str, fingerprint: Option<&str>) {
     });
 }
 
+/// Set the remote override from a named `[daemon.remotes]` entry.
+///
+/// Uses the entry's stored fingerprint unless `fingerprint` (from
+/// `--remote-fingerprint`) is given. Fails with the configured alias names
+/// when `alias` is unknown.
+pub fn set_remote_override_from_alias(
+    config: &kild_config::DaemonRuntimeConfig,
+    alias: &str,
+    fingerprint: Option<&str>,
+) -> Result<(), kild_config::ConfigError> {
+    let remote = config.remote(alias)?;
+    debug!(
+        event = "core.daemon.remote_alias_resolved",
+        alias = alias,
+        host = remote.host()
+    );
+    set_remote_override(remote.host(), fingerprint.or(remote.fingerprint()));
+    Ok(())
+}
+
 /// Read the current remote override, if any.
 pub(crate) fn remote_override() -> Option<(String, Option<String>)> {
     REMOTE_OVERRIDE.with(|cell| cell.borrow().clone())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kild_config::{DaemonRuntimeConfig, RemoteDaemon};
+    use std::collections::HashMap;
+
+    fn remotes() -> DaemonRuntimeConfig {
+        DaemonRuntimeConfig {
+            remotes: HashMap::from([
+                (
+                    "work".to_string(),
+                    RemoteDaemon::Address("10.0.0.5:7070".to_string()),
+                ),
+                (
+                    "lab".to_string(),
+                    RemoteDaemon::Pinned {
+                        host: "lab:7432".to_string(),
+                        fingerprint: Some("sha256:stored".to_string()),
+                    },
+                ),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_remote_alias_sets_override_with_stored_fingerprint() {
+        set_remote_override_from_alias(&remotes(), "lab", None).unwrap();
+        assert_eq!(
+            remote_override(),
+            Some(("lab:7432".to_string(), Some("sha256:stored".to_string())))
+        );
+
+        set_remote_override_from_alias(&remotes(), "work", None).unwrap();
+        assert_eq!(remote_override(), Some(("10.0.0.5:7070".to_string(), None)));
+    }
+
+    #[test]
+    fn test_remote_alias_cli_fingerprint_wins() {
+        set_remote_override_from_alias(&remotes(), "lab", Some("sha256:flag")).unwrap();
+        assert_eq!(
+            remote_override(),
+            Some(("lab:7432".to_string(), Some("sha256:flag".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_remote_alias_unknown_leaves_override_unset() {
+        let err = set_remote_override_from_alias(&remotes(), "home", None).unwrap_err();
+        assert!(err.to_string().contains("lab, work"));
+        assert_eq!(remote_override(), None);
+    }
+}
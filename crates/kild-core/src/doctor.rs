@@ -0,0 +1,375 @@
+//! Environment diagnostics for `kild doctor`.
+//!
+//! Each check is a small function over already-gathered facts so it can be
+//! tested without a real daemon, filesystem layout, or installed agents.
+//! [`run_diagnostics`] gathers the facts and runs every check.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use kild_config::KildConfig;
+use kild_paths::KildPaths;
+use tracing::info;
+
+use crate::agents;
+use crate::daemon;
+
+/// Outcome of a single diagnostic check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticStatus {
+    Pass,
+    /// Works, but some features are unavailable or degraded.
+    Warn,
+    /// Broken; kild commands that depend on this will fail.
+    Fail,
+}
+
+/// Result of one check: what was checked, what was found, and how to fix it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticResult {
+    pub name: String,
+    pub status: DiagnosticStatus,
+    pub detail: String,
+    /// Remediation hint, set for warnings and failures.
+    pub hint: Option<String>,
+}
+
+impl DiagnosticResult {
+    fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: DiagnosticStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: impl Into<String>, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: DiagnosticStatus::Warn,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(name: impl Into<String>, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: DiagnosticStatus::Fail,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Check that the `kild-daemon` binary sits next to `kild`.
+pub fn check_daemon_binary(found: Result<PathBuf, String>) -> DiagnosticResult {
+    match found {
+        Ok(path) => DiagnosticResult::pass("daemon binary", path.display().to_string()),
+        Err(e) => DiagnosticResult::fail(
+            "daemon binary",
+            e,
+            "Build it with 'cargo build --all', or reinstall kild",
+        ),
+    }
+}
+
+/// Check the daemon socket: absent, answering, or left behind by a dead daemon.
+///
+/// `ping` is the result of pinging the daemon over the socket.
+pub fn check_daemon_socket(
+    socket: &Path,
+    socket_exists: bool,
+    ping: Result<bool, String>,
+) -> DiagnosticResult {
+    const NAME: &str = "daemon socket";
+    if !socket_exists {
+        return DiagnosticResult::warn(
+            NAME,
+            "daemon is not running",
+            "Start it with 'kild daemon start' (needed for --daemon sessions)",
+        );
+    }
+    match ping {
+        Ok(true) => {
+            DiagnosticResult::pass(NAME, format!("daemon answering at {}", socket.display()))
+        }
+        Ok(false) => DiagnosticResult::fail(
+            NAME,
+            format!("stale socket at {}: no daemon answering", socket.display()),
+            format!(
+                "Remove it with 'rm {}' and run 'kild daemon start'",
+                socket.display()
+            ),
+        ),
+        Err(e) => DiagnosticResult::fail(
+            NAME,
+            format!("could not reach daemon at {}: {}", socket.display(), e),
+            "Restart it with 'kild daemon stop && kild daemon start'",
+        ),
+    }
+}
+
+/// Check the tmux shim used by agent teams in daemon sessions.
+///
+/// The shim is symlinked into `~/.kild/bin/tmux` on first use, so a missing
+/// link is fine as long as `kild-tmux-shim` was built.
+pub fn check_shim_binary(
+    shim_link: &Path,
+    link_present: bool,
+    link_target_exists: bool,
+    sibling: Result<PathBuf, String>,
+) -> DiagnosticResult {
+    const NAME: &str = "tmux shim";
+    if link_present && link_target_exists {
+        return DiagnosticResult::pass(NAME, shim_link.display().to_string());
+    }
+    if link_present {
+        return DiagnosticResult::fail(
+            NAME,
+            format!("{} points to a missing binary", shim_link.display()),
+            format!(
+                "Remove it with 'rm {}'; it is relinked on the next daemon session",
+                shim_link.display()
+            ),
+        );
+    }
+    match sibling {
+        Ok(path) => DiagnosticResult::pass(
+            NAME,
+            format!("{} (installed on first daemon session)", path.display()),
+        ),
+        Err(e) => DiagnosticResult::warn(
+            NAME,
+            e,
+            "Build it with 'cargo build --all' to use agent teams in daemon sessions",
+        ),
+    }
+}
+
+/// Check that the Claude status hook is installed.
+pub fn check_claude_hooks(hook_path: &Path, installed: bool) -> DiagnosticResult {
+    const NAME: &str = "claude hooks";
+    if installed {
+        DiagnosticResult::pass(NAME, hook_path.display().to_string())
+    } else {
+        DiagnosticResult::warn(
+            NAME,
+            format!("{} not found", hook_path.display()),
+            "Install with 'kild init-hooks claude' for agent status reporting",
+        )
+    }
+}
+
+/// Check one configured agent.
+///
+/// `available` is `None` when the name doesn't resolve to any agent. A
+/// missing default agent fails; other missing agents only warn.
+pub fn check_agent(name: &str, is_default: bool, available: Option<bool>) -> DiagnosticResult {
+    let check = format!("agent {}", name);
+    let role = if is_default { " (default)" } else { "" };
+    match available {
+        Some(true) => DiagnosticResult::pass(check, format!("installed{role}")),
+        Some(false) => {
+            let detail = format!("not found on PATH{role}");
+            let hint =
+                format!("Install {name}, or point [agents.{name}] binary at it in your config");
+            if is_default {
+                DiagnosticResult::fail(check, detail, hint)
+            } else {
+                DiagnosticResult::warn(check, detail, hint)
+            }
+        }
+        None => DiagnosticResult::fail(
+            check,
+            format!("unknown agent{role}"),
+            format!(
+                "Use one of: {}, or define [agents.{name}] with a binary",
+                agents::valid_agent_names().join(", ")
+            ),
+        ),
+    }
+}
+
+/// Names of the agents the config refers to: the default plus every
+/// `[agents.<name>]` section, in a stable order with the default first.
+pub fn configured_agents(config: &KildConfig) -> Vec<String> {
+    let others: BTreeSet<&String> = config
+        .agents
+        .keys()
+        .filter(|name| **name != config.agent.default)
+        .collect();
+    std::iter::once(config.agent.default.clone())
+        .chain(others.into_iter().cloned())
+        .collect()
+}
+
+/// Run every diagnostic against the real environment.
+pub fn run_diagnostics(config: &KildConfig) -> Vec<DiagnosticResult> {
+    let mut results = vec![check_daemon_binary(daemon::find_sibling_binary(
+        "kild-daemon",
+    ))];
+
+    let socket = daemon::socket_path();
+    let socket_exists = socket.exists();
+    let ping = if socket_exists {
+        daemon::client::ping_daemon().map_err(|e| e.to_string())
+    } else {
+        Ok(false)
+    };
+    results.push(check_daemon_socket(&socket, socket_exists, ping));
+
+    match KildPaths::resolve() {
+        Ok(paths) => {
+            let shim = paths.tmux_shim_binary();
+            results.push(check_shim_binary(
+                &shim,
+                shim.symlink_metadata().is_ok(),
+                shim.exists(),
+                daemon::find_sibling_binary("kild-tmux-shim"),
+            ));
+            let hook = paths.claude_status_hook();
+            results.push(check_claude_hooks(&hook, hook.exists()));
+        }
+        Err(e) => results.push(DiagnosticResult::fail(
+            "kild home",
+            e.to_string(),
+            "Set HOME or KILD_HOME",
+        )),
+    }
+
+    for name in configured_agents(config) {
+        let available = agents::resolve_agent(config, &name).map(|a| a.backend().is_available());
+        let is_default = name == config.agent.default;
+        results.push(check_agent(&name, is_default, available));
+    }
+
+    info!(
+        event = "core.doctor.completed",
+        checks = results.len(),
+        failed = results
+            .iter()
+            .filter(|r| r.status == DiagnosticStatus::Fail)
+            .count()
+    );
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_agent_available() {
+        let result = check_agent("claude", true, Some(true));
+        assert_eq!(result.status, DiagnosticStatus::Pass);
+        assert_eq!(result.name, "agent claude");
+        assert!(result.hint.is_none());
+    }
+
+    #[test]
+    fn test_check_agent_missing_default_fails() {
+        let result = check_agent("claude", true, Some(false));
+        assert_eq!(result.status, DiagnosticStatus::Fail);
+        assert!(result.detail.contains("(default)"));
+        assert!(result.hint.unwrap().contains("[agents.claude]"));
+    }
+
+    #[test]
+    fn test_check_agent_missing_non_default_warns() {
+        let result = check_agent("gemini", false, Some(false));
+        assert_eq!(result.status, DiagnosticStatus::Warn);
+        assert!(result.hint.is_some());
+    }
+
+    #[test]
+    fn test_check_agent_unknown_fails_with_valid_names() {
+        let result = check_agent("nope", false, None);
+        assert_eq!(result.status, DiagnosticStatus::Fail);
+        assert!(result.detail.contains("unknown agent"));
+        assert!(result.hint.unwrap().contains("claude"));
+    }
+
+    #[test]
+    fn test_check_daemon_socket_stale() {
+        let socket = Path::new("/tmp/kild-test/daemon.sock");
+        let result = check_daemon_socket(socket, true, Ok(false));
+        assert_eq!(result.status, DiagnosticStatus::Fail);
+        assert!(result.detail.contains("stale socket"));
+        assert!(
+            result
+                .hint
+                .unwrap()
+                .contains("rm /tmp/kild-test/daemon.sock")
+        );
+    }
+
+    #[test]
+    fn test_check_daemon_socket_alive() {
+        let result = check_daemon_socket(Path::new("/tmp/d.sock"), true, Ok(true));
+        assert_eq!(result.status, DiagnosticStatus::Pass);
+    }
+
+    #[test]
+    fn test_check_daemon_socket_missing_warns() {
+        let result = check_daemon_socket(Path::new("/tmp/d.sock"), false, Ok(false));
+        assert_eq!(result.status, DiagnosticStatus::Warn);
+        assert!(result.hint.unwrap().contains("kild daemon start"));
+    }
+
+    #[test]
+    fn test_check_daemon_socket_ping_error_fails() {
+        let result = check_daemon_socket(
+            Path::new("/tmp/d.sock"),
+            true,
+            Err("connection reset".to_string()),
+        );
+        assert_eq!(result.status, DiagnosticStatus::Fail);
+        assert!(result.detail.contains("connection reset"));
+    }
+
+    #[test]
+    fn test_check_shim_dangling_link_fails() {
+        let result = check_shim_binary(
+            Path::new("/home/u/.kild/bin/tmux"),
+            true,
+            false,
+            Err("missing".to_string()),
+        );
+        assert_eq!(result.status, DiagnosticStatus::Fail);
+        assert!(result.detail.contains("missing binary"));
+    }
+
+    #[test]
+    fn test_check_shim_not_linked_but_built_passes() {
+        let result = check_shim_binary(
+            Path::new("/home/u/.kild/bin/tmux"),
+            false,
+            false,
+            Ok(PathBuf::from("/usr/local/bin/kild-tmux-shim")),
+        );
+        assert_eq!(result.status, DiagnosticStatus::Pass);
+    }
+
+    #[test]
+    fn test_check_daemon_binary_missing_fails() {
+        let result = check_daemon_binary(Err("kild-daemon binary not found".to_string()));
+        assert_eq!(result.status, DiagnosticStatus::Fail);
+        assert!(result.hint.is_some());
+    }
+
+    #[test]
+    fn test_configured_agents_default_first_and_deduplicated() {
+        let mut config = KildConfig::default();
+        config.agent.default = "codex".to_string();
+        config.agents.insert("kiro".to_string(), Default::default());
+        config
+            .agents
+            .insert("codex".to_string(), Default::default());
+        config.agents.insert("amp".to_string(), Default::default());
+
+        assert_eq!(configured_agents(&config), vec!["codex", "amp", "kiro"]);
+    }
+}
@@ -5,10 +5,19 @@ use tracing::{error, info};
 
 use crate::editor::errors::EditorError;
 use crate::editor::traits::EditorBackend;
+use crate::editor::types::EditorOpenMode;
 use kild_config::KildConfig;
 
 pub struct VSCodeBackend;
 
+/// Build the `code` invocation: flags first, then the path to open.
+fn code_command(path: &Path, flags: &[String]) -> Command {
+    let mut cmd = Command::new("code");
+    cmd.args(flags);
+    cmd.arg(path);
+    cmd
+}
+
 impl EditorBackend for VSCodeBackend {
     fn name(&self) -> &'static str {
         "code"
@@ -26,14 +35,15 @@ impl EditorBackend for VSCodeBackend {
         false
     }
 
-    fn open(&self, path: &Path, flags: &[String], _config: &KildConfig) -> Result<(), EditorError> {
-        let mut cmd = Command::new("code");
-        for flag in flags {
-            cmd.arg(flag);
+    fn open_mode_flags(&self, mode: EditorOpenMode) -> &'static [&'static str] {
+        match mode {
+            EditorOpenMode::NewWindow => &["--new-window"],
+            EditorOpenMode::Reuse => &["--reuse-window"],
         }
-        cmd.arg(path);
+    }
 
-        match cmd.spawn() {
+    fn open(&self, path: &Path, flags: &[String], _config: &KildConfig) -> Result<(), EditorError> {
+        match code_command(path, flags).spawn() {
             Ok(_) => {
                 info!(event = "core.editor.open_completed", editor = "code");
                 Ok(())
@@ -63,4 +73,30 @@ mod tests {
         assert_eq!(backend.display_name(), "VS Code");
         assert!(!backend.is_terminal_editor());
     }
+
+    fn args(cmd: &Command) -> Vec<String> {
+        cmd.get_args()
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect()
+    }
+
+    fn command_for(mode: EditorOpenMode) -> Command {
+        let flags =
+            crate::editor::registry::editor_flags(&VSCodeBackend, mode, &KildConfig::default());
+        code_command(Path::new("/tmp/wt"), &flags)
+    }
+
+    #[test]
+    fn test_vscode_command_new_window() {
+        let cmd = command_for(EditorOpenMode::NewWindow);
+        assert_eq!(cmd.get_program(), "code");
+        assert_eq!(args(&cmd), ["--new-window", "/tmp/wt"]);
+    }
+
+    #[test]
+    fn test_vscode_command_reuse() {
+        let cmd = command_for(EditorOpenMode::Reuse);
+        assert_eq!(cmd.get_program(), "code");
+        assert_eq!(args(&cmd), ["--reuse-window", "/tmp/wt"]);
+    }
 }
@@ -5,10 +5,19 @@ use tracing::{error, info};
 
 use crate::editor::errors::EditorError;
 use crate::editor::traits::EditorBackend;
+use crate::editor::types::EditorOpenMode;
 use kild_config::KildConfig;
 
 pub struct ZedBackend;
 
+/// Build the `zed` invocation: flags first, then the path to open.
+fn zed_command(path: &Path, flags: &[String]) -> Command {
+    let mut cmd = Command::new("zed");
+    cmd.args(flags);
+    cmd.arg(path);
+    cmd
+}
+
 impl EditorBackend for ZedBackend {
     fn name(&self) -> &'static str {
         "zed"
@@ -26,14 +35,15 @@ impl EditorBackend for ZedBackend {
         false
     }
 
-    fn open(&self, path: &Path, flags: &[String], _config: &KildConfig) -> Result<(), EditorError> {
-        let mut cmd = Command::new("zed");
-        for flag in flags {
-            cmd.arg(flag);
+    fn open_mode_flags(&self, mode: EditorOpenMode) -> &'static [&'static str] {
+        match mode {
+            EditorOpenMode::NewWindow => &["--new"],
+            EditorOpenMode::Reuse => &[],
         }
-        cmd.arg(path);
+    }
 
-        match cmd.spawn() {
+    fn open(&self, path: &Path, flags: &[String], _config: &KildConfig) -> Result<(), EditorError> {
+        match zed_command(path, flags).spawn() {
             Ok(_) => {
                 info!(event = "core.editor.open_completed", editor = "zed");
                 Ok(())
@@ -63,4 +73,30 @@ mod tests {
         assert_eq!(backend.display_name(), "Zed");
         assert!(!backend.is_terminal_editor());
     }
+
+    fn args(cmd: &Command) -> Vec<String> {
+        cmd.get_args()
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect()
+    }
+
+    fn command_for(mode: EditorOpenMode) -> Command {
+        let flags =
+            crate::editor::registry::editor_flags(&ZedBackend, mode, &KildConfig::default());
+        zed_command(Path::new("/tmp/wt"), &flags)
+    }
+
+    #[test]
+    fn test_zed_command_new_window() {
+        let cmd = command_for(EditorOpenMode::NewWindow);
+        assert_eq!(cmd.get_program(), "zed");
+        assert_eq!(args(&cmd), ["--new", "/tmp/wt"]);
+    }
+
+    #[test]
+    fn test_zed_command_reuse() {
+        let cmd = command_for(EditorOpenMode::Reuse);
+        assert_eq!(cmd.get_program(), "zed");
+        assert_eq!(args(&cmd), ["/tmp/wt"]);
+    }
 }
@@ -8,4 +8,4 @@ pub mod types;
 pub use errors::EditorError;
 pub use registry::{detect_editor, get_backend, open_editor};
 pub use traits::EditorBackend;
-pub use types::EditorType;
+pub use types::{EditorOpenMode, EditorType};
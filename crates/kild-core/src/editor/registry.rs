@@ -9,7 +9,7 @@ use kild_config::KildConfig;
 use super::backends::{GenericBackend, VSCodeBackend, VimBackend, ZedBackend};
 use super::errors::EditorError;
 use super::traits::EditorBackend;
-use super::types::EditorType;
+use super::types::{EditorOpenMode, EditorType};
 
 /// Global registry of all known editor backends.
 static REGISTRY: LazyLock<EditorRegistry> = LazyLock::new(EditorRegistry::new);
@@ -59,9 +59,62 @@ pub fn detect_editor() -> Result<EditorType, EditorError> {
     Err(EditorError::NoEditorFound)
 }
 
+/// Editors tried, in order, when the configured editor is not installed.
+const FALLBACK_EDITORS: [&str; 3] = ["code", "zed", "vim"];
+
+/// Candidates for a configured editor: the editor itself, then `$VISUAL`
+/// and `$EDITOR`, then [`FALLBACK_EDITORS`]. Duplicates are dropped.
+fn fallback_chain(configured: &str) -> Vec<String> {
+    let env_editors = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok().filter(|s| !s.is_empty()));
+
+    let mut chain: Vec<String> = Vec::new();
+    for name in std::iter::once(configured.to_string())
+        .chain(env_editors)
+        .chain(FALLBACK_EDITORS.iter().map(|s| s.to_string()))
+    {
+        if !chain.contains(&name) {
+            chain.push(name);
+        }
+    }
+    chain
+}
+
+/// Use the configured editor if it is installed, otherwise the first
+/// available editor from [`fallback_chain`].
+fn resolve_configured_editor(
+    configured: &str,
+    is_available: impl Fn(&str) -> bool,
+) -> Result<String, EditorError> {
+    let chain = fallback_chain(configured);
+    let Some(chosen) = chain.iter().find(|name| is_available(name)) else {
+        warn!(
+            event = "core.editor.fallback_failed",
+            configured = configured,
+            tried = ?chain
+        );
+        return Err(EditorError::EditorNotFound {
+            editor: configured.to_string(),
+        });
+    };
+
+    if chosen != configured {
+        warn!(
+            event = "core.editor.fallback_selected",
+            configured = configured,
+            editor = %chosen,
+            "Configured editor not installed — using fallback"
+        );
+    }
+    Ok(chosen.clone())
+}
+
 /// Resolve which editor to use and return `(command_name, matched_type)`.
 ///
 /// Priority: CLI override > config default > $EDITOR > detect_editor().
+/// A configured editor that isn't installed falls back through
+/// [`fallback_chain`]; a CLI override is used as given.
 /// If the resolved name matches a known EditorType (via FromStr), returns it.
 /// Otherwise returns None (the caller should use GenericBackend).
 fn resolve_editor(
@@ -76,7 +129,7 @@ fn resolve_editor(
     let editor_name = if let Some(editor) = cli_override {
         editor.to_string()
     } else if let Some(editor) = config.editor.default() {
-        editor.to_string()
+        resolve_configured_editor(editor, |name| which::which(name).is_ok())?
     } else {
         // Unix convention: VISUAL > EDITOR > OS default > PATH detection.
         // Empty strings are treated as unset so fallback continues.
@@ -222,32 +275,54 @@ fn map_macos_bundle_to_command(bundle_id: &str) -> Option<String> {
     Some(command.to_string())
 }
 
+/// Flags for one editor invocation: the backend's window flags for `mode`,
+/// then the configured `[editor] flags`. Window flags the user already
+/// configured are not repeated.
+pub(crate) fn editor_flags(
+    backend: &dyn EditorBackend,
+    mode: EditorOpenMode,
+    config: &KildConfig,
+) -> Vec<String> {
+    let configured: Vec<String> = config
+        .editor
+        .flags()
+        .map(|f| f.split_whitespace().map(String::from).collect())
+        .unwrap_or_default();
+
+    let mut flags: Vec<String> = backend
+        .open_mode_flags(mode)
+        .iter()
+        .filter(|flag| !configured.iter().any(|c| c == *flag))
+        .map(|flag| flag.to_string())
+        .collect();
+    flags.extend(configured);
+    flags
+}
+
 /// Open a path in the resolved editor.
 ///
 /// This is the primary API for both CLI and UI. It resolves which editor
 /// to use, finds or creates the appropriate backend, and opens the path.
+/// `mode` overrides `[editor] new_window` when set.
 pub fn open_editor(
     path: &Path,
     cli_override: Option<&str>,
+    mode: Option<EditorOpenMode>,
     config: &KildConfig,
 ) -> Result<(), EditorError> {
     let (editor_name, editor_type) = resolve_editor(cli_override, config)?;
 
-    // Parse flags from config
-    let flags: Vec<String> = config
-        .editor
-        .flags()
-        .map(|f| f.split_whitespace().map(String::from).collect())
-        .unwrap_or_default();
-
-    if !flags.is_empty() {
-        debug!(event = "core.editor.flags_loaded", flags = ?flags);
-    }
+    let mode = mode.unwrap_or(if config.editor.new_window() {
+        EditorOpenMode::NewWindow
+    } else {
+        EditorOpenMode::Reuse
+    });
 
     info!(
         event = "core.editor.open_started",
         editor = %editor_name,
         editor_type = ?editor_type,
+        mode = ?mode,
         path = %path.display()
     );
 
@@ -256,6 +331,10 @@ pub fn open_editor(
             let backend = get_backend(&et).ok_or_else(|| EditorError::EditorNotFound {
                 editor: editor_name.clone(),
             })?;
+            let flags = editor_flags(backend, mode, config);
+            if !flags.is_empty() {
+                debug!(event = "core.editor.flags_loaded", flags = ?flags);
+            }
             backend.open_with_command(&editor_name, path, &flags, config)
         }
         None => {
@@ -269,6 +348,10 @@ pub fn open_editor(
                 });
             }
 
+            let flags = editor_flags(&backend, mode, config);
+            if !flags.is_empty() {
+                debug!(event = "core.editor.flags_loaded", flags = ?flags);
+            }
             backend.open(path, &flags, config)
         }
     }
@@ -369,7 +452,7 @@ mod tests {
     fn test_open_editor_unknown_unavailable_returns_not_found() {
         let config = KildConfig::default();
         let path = std::env::temp_dir();
-        let result = open_editor(&path, Some("totally-fake-editor-xyz"), &config);
+        let result = open_editor(&path, Some("totally-fake-editor-xyz"), None, &config);
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(
@@ -378,6 +461,58 @@ mod tests {
         assert!(err.is_user_error());
     }
 
+    #[test]
+    fn test_resolve_editor_configured_zed_selects_zed_backend() {
+        let name = resolve_configured_editor("zed", |name| name == "zed").unwrap();
+        assert_eq!(name, "zed");
+        let editor_type = name.parse::<EditorType>().ok();
+        assert_eq!(editor_type, Some(EditorType::Zed));
+        assert_eq!(get_backend(&EditorType::Zed).unwrap().name(), "zed");
+    }
+
+    #[test]
+    fn test_editor_flags_prepends_window_flags() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[editor]
+flags = "--foreground"
+"#,
+        )
+        .unwrap();
+        let zed = get_backend(&EditorType::Zed).unwrap();
+        assert_eq!(
+            editor_flags(zed, EditorOpenMode::NewWindow, &config),
+            vec!["--new", "--foreground"]
+        );
+        assert_eq!(
+            editor_flags(zed, EditorOpenMode::Reuse, &config),
+            vec!["--foreground"]
+        );
+    }
+
+    #[test]
+    fn test_editor_flags_does_not_repeat_configured_window_flag() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[editor]
+flags = "--new-window"
+"#,
+        )
+        .unwrap();
+        let code = get_backend(&EditorType::VSCode).unwrap();
+        assert_eq!(
+            editor_flags(code, EditorOpenMode::NewWindow, &config),
+            vec!["--new-window"]
+        );
+    }
+
+    #[test]
+    fn test_editor_flags_generic_backend_ignores_mode() {
+        let config = KildConfig::default();
+        let backend = GenericBackend::new("subl".to_string(), false);
+        assert!(editor_flags(&backend, EditorOpenMode::NewWindow, &config).is_empty());
+    }
+
     #[test]
     fn test_open_editor_known_type_resolves_correctly() {
         let config = KildConfig::default();
@@ -489,4 +624,68 @@ mod tests {
             }
         });
     }
+
+    // --- configured editor fallback chain ---
+
+    #[test]
+    fn test_fallback_chain_order() {
+        with_vars([("VISUAL", None::<&str>), ("EDITOR", Some("nano"))], || {
+            assert_eq!(
+                fallback_chain("nvim"),
+                vec!["nvim", "nano", "code", "zed", "vim"]
+            );
+        });
+    }
+
+    #[test]
+    fn test_fallback_chain_drops_duplicates() {
+        with_vars([("VISUAL", Some("zed")), ("EDITOR", Some("zed"))], || {
+            assert_eq!(fallback_chain("code"), vec!["code", "zed", "vim"]);
+        });
+    }
+
+    #[test]
+    fn test_configured_editor_used_when_available() {
+        with_vars([("VISUAL", None::<&str>), ("EDITOR", None::<&str>)], || {
+            let name = resolve_configured_editor("nvim", |_| true).unwrap();
+            assert_eq!(name, "nvim");
+        });
+    }
+
+    #[test]
+    fn test_fallback_selects_first_available_editor() {
+        with_vars([("VISUAL", None::<&str>), ("EDITOR", None::<&str>)], || {
+            // nvim and code missing: zed is next in line, ahead of vim
+            let name =
+                resolve_configured_editor("nvim", |name| name == "zed" || name == "vim").unwrap();
+            assert_eq!(name, "zed");
+        });
+    }
+
+    #[test]
+    fn test_fallback_prefers_env_editor() {
+        with_vars([("VISUAL", None::<&str>), ("EDITOR", Some("nano"))], || {
+            let name = resolve_configured_editor("nvim", |name| name != "nvim").unwrap();
+            assert_eq!(name, "nano");
+        });
+    }
+
+    #[test]
+    fn test_fallback_none_available_reports_configured_editor() {
+        with_vars([("VISUAL", None::<&str>), ("EDITOR", None::<&str>)], || {
+            let err = resolve_configured_editor("nvim", |_| false).unwrap_err();
+            assert!(matches!(err, EditorError::EditorNotFound { ref editor } if editor == "nvim"));
+        });
+    }
+
+    #[test]
+    fn test_cli_override_bypasses_fallback_chain() {
+        let mut config = KildConfig::default();
+        config.editor.set_default("zed".to_string());
+        // The override is used as given, even if it isn't installed
+        let (name, editor_type) =
+            resolve_editor(Some("definitely-not-installed-xyz"), &config).unwrap();
+        assert_eq!(name, "definitely-not-installed-xyz");
+        assert!(editor_type.is_none());
+    }
 }
@@ -3,6 +3,7 @@ use std::path::Path;
 use kild_config::KildConfig;
 
 use super::errors::EditorError;
+use super::types::EditorOpenMode;
 
 /// Trait defining the interface for editor backends.
 ///
@@ -25,6 +26,14 @@ pub trait EditorBackend: Send + Sync {
     /// the editor process directly via `Command::new()`.
     fn is_terminal_editor(&self) -> bool;
 
+    /// CLI flags that select a new window or window reuse.
+    ///
+    /// Prepended to the configured flags by `open_editor()`. The default
+    /// returns none, for editors without a window switch.
+    fn open_mode_flags(&self, _mode: EditorOpenMode) -> &'static [&'static str] {
+        &[]
+    }
+
     /// Open a path in this editor.
     ///
     /// For GUI editors, spawns a new process directly.
@@ -87,6 +96,11 @@ mod tests {
         assert_eq!(backend.display_name(), "Mock Editor");
         assert!(backend.is_available());
         assert!(!backend.is_terminal_editor());
+        assert!(
+            backend
+                .open_mode_flags(EditorOpenMode::NewWindow)
+                .is_empty()
+        );
     }
 
     #[test]
@@ -23,6 +23,19 @@ impl EditorType {
     }
 }
 
+/// Whether a GUI editor opens a new window or reuses an existing one.
+///
+/// Backends map this to their own CLI flags; editors without such a switch
+/// ignore it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EditorOpenMode {
+    /// Let the editor reuse a window (its default behavior).
+    #[default]
+    Reuse,
+    /// Always open a new window.
+    NewWindow,
+}
+
 impl std::fmt::Display for EditorType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -24,6 +24,8 @@ impl KildError for kild_git::GitError {
             kild_git::GitError::WorktreeAlreadyExists { .. } => "WORKTREE_ALREADY_EXISTS",
             kild_git::GitError::WorktreeNotFound { .. } => "WORKTREE_NOT_FOUND",
             kild_git::GitError::WorktreeRemovalFailed { .. } => "WORKTREE_REMOVAL_FAILED",
+            kild_git::GitError::WorktreeMoveFailed { .. } => "WORKTREE_MOVE_FAILED",
+            kild_git::GitError::BranchRenameFailed { .. } => "GIT_BRANCH_RENAME_FAILED",
             kild_git::GitError::InvalidPath { .. } => "INVALID_PATH",
             kild_git::GitError::OperationFailed { .. } => "GIT_OPERATION_FAILED",
             kild_git::GitError::FetchFailed { .. } => "GIT_FETCH_FAILED",
@@ -35,6 +37,7 @@ impl KildError for kild_git::GitError {
             kild_git::GitError::DiffFailed { .. } => "GIT_DIFF_FAILED",
             kild_git::GitError::MergeAnalysisFailed { .. } => "GIT_MERGE_ANALYSIS_FAILED",
             kild_git::GitError::LogFailed { .. } => "GIT_LOG_FAILED",
+            kild_git::GitError::SubmoduleInitFailed { .. } => "GIT_SUBMODULE_INIT_FAILED",
             kild_git::GitError::Git2Error { .. } => "GIT2_ERROR",
             kild_git::GitError::IoError { .. } => "GIT_IO_ERROR",
         }
@@ -59,6 +62,7 @@ impl KildError for kild_config::ConfigError {
             kild_config::ConfigError::ConfigParseError { .. } => "CONFIG_PARSE_ERROR",
             kild_config::ConfigError::InvalidAgent { .. } => "INVALID_AGENT",
             kild_config::ConfigError::InvalidConfiguration { .. } => "INVALID_CONFIGURATION",
+            kild_config::ConfigError::UnknownRemoteAlias { .. } => "UNKNOWN_REMOTE_ALIAS",
             kild_config::ConfigError::IoError { .. } => "CONFIG_IO_ERROR",
         }
     }
@@ -69,6 +73,7 @@ impl KildError for kild_config::ConfigError {
             kild_config::ConfigError::ConfigParseError { .. }
                 | kild_config::ConfigError::InvalidAgent { .. }
                 | kild_config::ConfigError::InvalidConfiguration { .. }
+                | kild_config::ConfigError::UnknownRemoteAlias { .. }
         )
     }
 }
@@ -1,3 +1,7 @@
+mod stream;
+
+pub use stream::{EventLog, EventSubscription, RecordedEvent, publish};
+
 use tracing::{error, info};
 
 pub fn log_app_startup() {
@@ -0,0 +1,288 @@
+//! Cross-process event stream backed by an append-only JSONL file.
+//!
+//! Every kild process (CLI, UI) runs its own session operations, so an
+//! in-memory channel can't reach a watcher in another terminal. Instead,
+//! [`publish`] appends one JSON line per [`Event`] to `~/.kild/events.jsonl`
+//! and an [`EventSubscription`] tails that file.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::state::Event;
+use kild_config::Config;
+
+/// Once the log grows past this size, the next publish starts it afresh.
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// An [`Event`] as written to the event log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// RFC 3339 time the event was published.
+    pub timestamp: String,
+    pub event: Event,
+}
+
+/// Handle to the event log file.
+#[derive(Debug, Clone)]
+pub struct EventLog {
+    path: PathBuf,
+}
+
+impl EventLog {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// The log under the resolved kild directory (`~/.kild/events.jsonl`).
+    pub fn open_default() -> Self {
+        Self::new(Config::new().paths().events_log())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append `event` to the log.
+    pub fn publish(&self, event: &Event) -> io::Result<()> {
+        let record = RecordedEvent {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            event: event.clone(),
+        };
+        let mut line = serde_json::to_string(&record).map_err(io::Error::other)?;
+        line.push('\n');
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let oversized = fs::metadata(&self.path).is_ok_and(|m| m.len() > MAX_LOG_BYTES);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(!oversized)
+            .write(true)
+            .truncate(oversized)
+            .open(&self.path)?;
+        // One write call per line so concurrent appenders don't interleave
+        file.write_all(line.as_bytes())
+    }
+
+    /// Start following the log from its current end.
+    ///
+    /// Only events published after this call are returned by
+    /// [`EventSubscription::poll`]. The log file need not exist yet.
+    pub fn subscribe(&self) -> EventSubscription {
+        let offset = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        EventSubscription {
+            path: self.path.clone(),
+            offset,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Read the log from the beginning, including events already recorded.
+    pub fn replay(&self) -> EventSubscription {
+        EventSubscription {
+            path: self.path.clone(),
+            offset: 0,
+            pending: Vec::new(),
+        }
+    }
+}
+
+/// Cursor into the event log returned by [`EventLog::subscribe`] and [`EventLog::replay`].
+#[derive(Debug)]
+pub struct EventSubscription {
+    path: PathBuf,
+    offset: u64,
+    /// Bytes of a line whose trailing newline hasn't been written yet.
+    pending: Vec<u8>,
+}
+
+impl EventSubscription {
+    /// Return events appended since the previous poll, oldest first.
+    ///
+    /// Never blocks. Lines that fail to parse (e.g. written by a newer kild
+    /// with unknown event variants) are skipped with a warning.
+    pub fn poll(&mut self) -> io::Result<Vec<RecordedEvent>> {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            // The log was started afresh; read it from the top
+            self.offset = 0;
+            self.pending.clear();
+        }
+        if len == self.offset {
+            return Ok(Vec::new());
+        }
+
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+        self.offset += buf.len() as u64;
+        self.pending.extend_from_slice(&buf);
+
+        let Some(last_newline) = self.pending.iter().rposition(|&b| b == b'\n') else {
+            return Ok(Vec::new());
+        };
+        let complete: Vec<u8> = self.pending.drain(..=last_newline).collect();
+
+        let events = String::from_utf8_lossy(&complete)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str::<RecordedEvent>(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    warn!(
+                        event = "core.events.parse_failed",
+                        error = %e,
+                        "Skipping unreadable event log line"
+                    );
+                    None
+                }
+            })
+            .collect();
+        Ok(events)
+    }
+}
+
+/// Publish `event` to the default event log.
+///
+/// Best-effort: the operation that produced the event has already succeeded,
+/// so a failed write is logged rather than returned.
+pub fn publish(event: Event) {
+    let log = EventLog::open_default();
+    if let Err(e) = log.publish(&event) {
+        warn!(
+            event = "core.events.publish_failed",
+            path = %log.path().display(),
+            error = %e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn created(branch: &str) -> Event {
+        Event::KildCreated {
+            branch: branch.into(),
+            session_id: format!("proj/{branch}").into(),
+        }
+    }
+
+    #[test]
+    fn test_subscription_sees_only_events_after_subscribe() {
+        let temp = TempDir::new().unwrap();
+        let log = EventLog::new(temp.path().join("events.jsonl"));
+        log.publish(&created("before")).unwrap();
+
+        let mut sub = log.subscribe();
+        assert!(sub.poll().unwrap().is_empty());
+
+        log.publish(&created("after")).unwrap();
+        log.publish(&Event::KildStopped {
+            branch: "after".into(),
+        })
+        .unwrap();
+
+        let events: Vec<Event> = sub.poll().unwrap().into_iter().map(|r| r.event).collect();
+        assert_eq!(
+            events,
+            vec![
+                created("after"),
+                Event::KildStopped {
+                    branch: "after".into()
+                }
+            ]
+        );
+        assert!(sub.poll().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_replay_returns_recorded_history() {
+        let temp = TempDir::new().unwrap();
+        let log = EventLog::new(temp.path().join("events.jsonl"));
+        log.publish(&created("one")).unwrap();
+        log.publish(&created("two")).unwrap();
+
+        let events: Vec<Event> = log
+            .replay()
+            .poll()
+            .unwrap()
+            .into_iter()
+            .map(|r| r.event)
+            .collect();
+        assert_eq!(events, vec![created("one"), created("two")]);
+    }
+
+    #[test]
+    fn test_subscription_before_log_exists() {
+        let temp = TempDir::new().unwrap();
+        let log = EventLog::new(temp.path().join("nested").join("events.jsonl"));
+
+        let mut sub = log.subscribe();
+        assert!(sub.poll().unwrap().is_empty());
+
+        log.publish(&created("first")).unwrap();
+        let events = sub.poll().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event, created("first"));
+    }
+
+    #[test]
+    fn test_subscription_waits_for_complete_lines_and_skips_garbage() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("events.jsonl");
+        let log = EventLog::new(path.clone());
+        let mut sub = log.subscribe();
+
+        let line = serde_json::to_string(&RecordedEvent {
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            event: created("split"),
+        })
+        .unwrap();
+        let (head, tail) = line.split_at(10);
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .unwrap();
+        write!(file, "not json\n{head}").unwrap();
+        assert!(sub.poll().unwrap().is_empty());
+
+        writeln!(file, "{tail}").unwrap();
+        let events = sub.poll().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].timestamp, "2026-01-01T00:00:00Z");
+        assert_eq!(events[0].event, created("split"));
+    }
+
+    #[test]
+    fn test_subscription_follows_log_restart() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("events.jsonl");
+        let log = EventLog::new(path.clone());
+        log.publish(&created("old-1")).unwrap();
+        log.publish(&created("old-2")).unwrap();
+        let mut sub = log.subscribe();
+
+        // Simulate the size cap starting the log over
+        fs::write(&path, "").unwrap();
+        log.publish(&created("new")).unwrap();
+
+        let events = sub.poll().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event, created("new"));
+    }
+}
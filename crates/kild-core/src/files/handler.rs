@@ -268,4 +268,36 @@ mod tests {
         assert!(dest.join(".env").exists());
         assert!(dest.join(".env.local").exists());
     }
+
+    #[test]
+    fn test_copy_include_files_reincludes_inside_gitignored_dir() {
+        use std::process::Command;
+
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("source");
+        let dest = temp_dir.path().join("dest");
+        fs::create_dir_all(source.join("target/gen")).unwrap();
+        fs::create_dir_all(source.join("target/debug")).unwrap();
+        fs::create_dir_all(&dest).unwrap();
+
+        Command::new("git")
+            .args(["init"])
+            .current_dir(&source)
+            .output()
+            .unwrap();
+        fs::write(source.join(".gitignore"), "target/\n").unwrap();
+        fs::write(source.join("target/gen/api.rs"), "// generated\n").unwrap();
+        fs::write(source.join("target/debug/app"), "bin").unwrap();
+
+        let config = IncludeConfig {
+            patterns: vec!["!target/**".to_string(), "target/gen/**".to_string()],
+            enabled: true,
+            max_file_size: None,
+        };
+
+        let (copied, failed) = copy_include_files(&source, &dest, &config).unwrap();
+        assert_eq!((copied, failed), (1, 0));
+        assert!(dest.join("target/gen/api.rs").exists());
+        assert!(!dest.join("target/debug/app").exists());
+    }
 }
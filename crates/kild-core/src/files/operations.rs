@@ -2,11 +2,10 @@ use crate::files::{
     errors::FileError,
     types::{CopyOptions, IncludeConfig, PatternRule},
 };
-use ignore::WalkBuilder;
 use std::fs;
 use std::path::{Path, PathBuf};
 use tempfile::NamedTempFile;
-use tracing::{debug, warn};
+use tracing::debug;
 
 /// Validate glob patterns in the include config
 pub fn validate_patterns(config: &IncludeConfig) -> Result<Vec<PatternRule>, FileError> {
@@ -41,93 +40,20 @@ pub fn validate_patterns(config: &IncludeConfig) -> Result<Vec<PatternRule>, Fil
 /// - `.env*` matches .env, .env.local, .env.production
 /// - `*.local.json` matches any .local.json file in any directory
 /// - `build/artifacts/**` matches all files under build/artifacts/
+/// - `!.env.production` excludes a file matched by an earlier pattern
 ///
 /// # How It Works
 /// Patterns use glob syntax and are checked AFTER gitignore rules,
 /// effectively overriding gitignore for matching files. The `ignore`
 /// crate's override mechanism ensures gitignored files matching these
-/// patterns are still included.
+/// patterns are still included. When patterns overlap, the last matching
+/// one decides. Shares its matching with `kild_config::preview_matches`.
 pub fn find_matching_files(
     source_root: &Path,
     rules: &[PatternRule],
 ) -> Result<Vec<PathBuf>, FileError> {
-    let mut matching_files = Vec::new();
-
-    // Create override builder to ignore gitignore for our patterns
-    let mut override_builder = ignore::overrides::OverrideBuilder::new(source_root);
-
-    // Add each pattern as an override (this makes gitignore ignore them)
-    for rule in rules {
-        if let Err(e) = override_builder.add(&rule.pattern) {
-            return Err(FileError::ValidationError {
-                message: format!(
-                    "Failed to add override for pattern '{}': {}",
-                    rule.pattern, e
-                ),
-            });
-        }
-    }
-
-    let overrides = override_builder
-        .build()
-        .map_err(|e| FileError::ValidationError {
-            message: format!("Failed to build overrides: {}", e),
-        })?;
-
-    // Walk the directory with overrides
-    let walker = WalkBuilder::new(source_root)
-        .overrides(overrides)
-        .hidden(false) // Include hidden files
-        .git_ignore(true) // Still respect gitignore for non-overridden files
-        .build();
-
-    for entry in walker {
-        match entry {
-            Ok(entry) => {
-                let path = entry.path();
-
-                // Skip directories
-                if path.is_dir() {
-                    continue;
-                }
-
-                // Get relative path for pattern matching
-                let relative_path = match path.strip_prefix(source_root) {
-                    Ok(rel) => rel,
-                    Err(_) => continue,
-                };
-
-                // Check if any pattern matches
-                let path_str = relative_path.to_string_lossy();
-                for rule in rules {
-                    if rule.compiled().matches(&path_str) {
-                        matching_files.push(path.to_path_buf());
-                        debug!(
-                            event = "core.files.pattern.matched",
-                            pattern = rule.pattern,
-                            file = %path.display()
-                        );
-                        break; // Don't need to check other patterns for this file
-                    }
-                }
-            }
-            Err(e) => {
-                warn!(
-                    event = "core.files.walk.error",
-                    error = %e,
-                    message = "Error walking directory, skipping entry"
-                );
-            }
-        }
-    }
-
-    debug!(
-        event = "core.files.matching.completed",
-        source_root = %source_root.display(),
-        matched_count = matching_files.len()
-    );
-
-    Ok(matching_files)
+    kild_config::include_config::matching_files(source_root, rules)
+        .map_err(|message| FileError::ValidationError { message })
 }
 
 /// Copy a single file safely with atomic operations.
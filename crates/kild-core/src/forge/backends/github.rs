@@ -1,6 +1,5 @@
 //! GitHub forge backend implementation.
 
-use std::borrow::Cow;
 use std::path::Path;
 
 use tracing::{debug, error, info, warn};
@@ -10,24 +9,12 @@ use crate::forge::traits::ForgeBackend;
 use crate::forge::types::{
     CiStatus, MergeStrategy, PrCheckResult, PrState, PullRequest, ReviewStatus,
 };
-use crate::git::naming::{KILD_BRANCH_PREFIX, kild_branch_name};
+
+use super::normalize_branch;
 
 /// GitHub forge backend using the `gh` CLI.
 pub struct GitHubBackend;
 
-/// Ensure the branch name has the `kild/` prefix for GitHub API queries.
-///
-/// KILD pushes branches as `kild/<branch>`, so `gh pr view` needs the full ref.
-/// Callers should always pass the full ref, but this normalizes defensively to
-/// prevent "no pull requests found" errors from short branch names.
-fn normalize_branch(branch: &str) -> Cow<'_, str> {
-    if branch.starts_with(KILD_BRANCH_PREFIX) {
-        Cow::Borrowed(branch)
-    } else {
-        Cow::Owned(kild_branch_name(branch))
-    }
-}
-
 impl ForgeBackend for GitHubBackend {
     fn name(&self) -> &'static str {
         "github"
@@ -192,6 +179,56 @@ impl ForgeBackend for GitHubBackend {
         }
     }
 
+    fn supports_draft_prs(&self) -> bool {
+        true
+    }
+
+    fn create_pr(
+        &self,
+        worktree_path: &Path,
+        branch: &str,
+        draft: bool,
+        body: Option<&str>,
+    ) -> Result<String, ForgeError> {
+        let branch = normalize_branch(branch);
+        info!(
+            event = "core.forge.pr_create_started",
+            branch = %branch,
+            draft = draft,
+            worktree_path = %worktree_path.display()
+        );
+
+        let output = std::process::Command::new("gh")
+            .current_dir(worktree_path)
+            .args(gh_pr_create_args(&branch, draft, body))
+            .output()?;
+
+        if output.status.success() {
+            // gh prints the new PR URL as the last line of stdout
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let url = stdout.lines().last().unwrap_or_default().trim().to_string();
+            info!(
+                event = "core.forge.pr_create_completed",
+                branch = %branch,
+                draft = draft,
+                url = %url
+            );
+            Ok(url)
+        } else {
+            let exit_code = output.status.code().unwrap_or(-1);
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            error!(
+                event = "core.forge.pr_create_failed",
+                branch = %branch,
+                exit_code = exit_code,
+                stderr = %stderr
+            );
+            Err(ForgeError::CliError {
+                message: format!("gh pr create failed (exit {}): {}", exit_code, stderr),
+            })
+        }
+    }
+
     fn merge_pr(
         &self,
         worktree_path: &Path,
@@ -238,6 +275,21 @@ impl ForgeBackend for GitHubBackend {
     }
 }
 
+/// Build the `gh pr create` arguments for a branch.
+///
+/// `--fill` takes the title and body from the branch's commits so the command
+/// never prompts; an explicit `--body` replaces the commit-based body.
+fn gh_pr_create_args<'a>(branch: &'a str, draft: bool, body: Option<&'a str>) -> Vec<&'a str> {
+    let mut args = vec!["pr", "create", "--head", branch, "--fill"];
+    if draft {
+        args.push("--draft");
+    }
+    if let Some(body) = body {
+        args.extend(["--body", body]);
+    }
+    args
+}
+
 /// Parse the JSON output from `gh pr view` into a `PullRequest`.
 ///
 /// Expects JSON with fields: number, url, state, isDraft, statusCheckRollup, reviews.
@@ -478,6 +530,28 @@ mod tests {
         assert_eq!(backend.display_name(), "GitHub");
     }
 
+    #[test]
+    fn test_gh_pr_create_args_draft() {
+        let args = gh_pr_create_args("kild/auth", true, None);
+        assert_eq!(
+            args,
+            ["pr", "create", "--head", "kild/auth", "--fill", "--draft"]
+        );
+        assert!(GitHubBackend.supports_draft_prs());
+    }
+
+    #[test]
+    fn test_gh_pr_create_args_body() {
+        let args = gh_pr_create_args("kild/auth", false, Some("## Summary"));
+        assert!(args.ends_with(&["--body", "## Summary"]));
+    }
+
+    #[test]
+    fn test_gh_pr_create_args_ready() {
+        let args = gh_pr_create_args("kild/auth", false, None);
+        assert!(!args.contains(&"--draft"));
+    }
+
     #[test]
     fn test_normalize_branch_adds_prefix() {
         let result = normalize_branch("my-feature");
@@ -0,0 +1,530 @@
+//! GitLab forge backend implementation.
+
+use std::path::Path;
+
+use tracing::{debug, error, info, warn};
+
+use crate::forge::errors::ForgeError;
+use crate::forge::traits::ForgeBackend;
+use crate::forge::types::{
+    CiStatus, MergeStrategy, PrCheckResult, PrState, PullRequest, ReviewStatus,
+};
+
+use super::normalize_branch;
+
+/// GitLab forge backend using the `glab` CLI.
+///
+/// Works against gitlab.com and self-hosted instances — `glab` resolves the
+/// host from the repository's remote.
+pub struct GitLabBackend;
+
+/// Whether `glab` stderr means "there is no MR for this branch" rather than a failure.
+fn is_no_mr_error(stderr: &str) -> bool {
+    let stderr = stderr.to_lowercase();
+    stderr.contains("no open merge request")
+        || stderr.contains("no merge request")
+        || stderr.contains("404 not found")
+}
+
+fn cli_error(command: &str, output: &std::process::Output) -> ForgeError {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    ForgeError::CliError {
+        message: format!(
+            "{} failed (exit {}): {}",
+            command,
+            output.status.code().unwrap_or(-1),
+            stderr.trim()
+        ),
+    }
+}
+
+impl ForgeBackend for GitLabBackend {
+    fn name(&self) -> &'static str {
+        "gitlab"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "GitLab"
+    }
+
+    fn is_available(&self) -> bool {
+        which::which("glab").is_ok()
+    }
+
+    fn is_pr_merged(&self, worktree_path: &Path, branch: &str) -> Result<bool, ForgeError> {
+        let branch = normalize_branch(branch);
+        debug!(
+            event = "core.forge.pr_merge_check_started",
+            branch = %branch,
+            worktree_path = %worktree_path.display()
+        );
+
+        // `glab mr view <branch>` only resolves open MRs, so list merged ones instead
+        let output = std::process::Command::new("glab")
+            .current_dir(worktree_path)
+            .args([
+                "mr",
+                "list",
+                "--source-branch",
+                &branch,
+                "--merged",
+                "--output",
+                "json",
+            ])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(cli_error("glab mr list", &output));
+        }
+
+        let json_str = String::from_utf8_lossy(&output.stdout);
+        let merged = serde_json::from_str::<Vec<serde_json::Value>>(&json_str)
+            .map_err(|e| ForgeError::ParseError {
+                message: format!("glab mr list output: {}", e),
+            })?
+            .iter()
+            .any(|mr| mr.get("state").and_then(|s| s.as_str()) == Some("merged"));
+
+        debug!(
+            event = "core.forge.pr_merge_check_completed",
+            branch = %branch,
+            merged = merged
+        );
+        Ok(merged)
+    }
+
+    fn check_pr_exists(&self, worktree_path: &Path, branch: &str) -> PrCheckResult {
+        let branch = normalize_branch(branch);
+        debug!(
+            event = "core.forge.pr_exists_check_started",
+            branch = %branch
+        );
+
+        if !worktree_path.exists() {
+            debug!(
+                event = "core.forge.pr_exists_check_skipped",
+                reason = "worktree_missing"
+            );
+            return PrCheckResult::Unavailable;
+        }
+
+        let output = std::process::Command::new("glab")
+            .current_dir(worktree_path)
+            .args(["mr", "view", &branch, "--output", "json"])
+            .output();
+
+        match output {
+            Ok(output) if output.status.success() => PrCheckResult::Exists,
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                if is_no_mr_error(&stderr) {
+                    PrCheckResult::NotFound
+                } else {
+                    warn!(
+                        event = "core.forge.pr_exists_check_error",
+                        branch = %branch,
+                        exit_code = output.status.code(),
+                        stderr = %stderr.trim(),
+                        "glab CLI error - MR status unavailable"
+                    );
+                    PrCheckResult::Unavailable
+                }
+            }
+            Err(e) => {
+                debug!(
+                    event = "core.forge.pr_exists_check_unavailable",
+                    error = %e,
+                    "glab CLI not available"
+                );
+                PrCheckResult::Unavailable
+            }
+        }
+    }
+
+    fn fetch_pr_info(
+        &self,
+        worktree_path: &Path,
+        branch: &str,
+    ) -> Result<Option<PullRequest>, ForgeError> {
+        let branch = normalize_branch(branch);
+        debug!(
+            event = "core.forge.pr_info_fetch_started",
+            branch = %branch,
+            worktree_path = %worktree_path.display()
+        );
+
+        let output = std::process::Command::new("glab")
+            .current_dir(worktree_path)
+            .args(["mr", "view", &branch, "--output", "json"])
+            .output()?;
+
+        if output.status.success() {
+            let json_str = String::from_utf8_lossy(&output.stdout);
+            return Ok(parse_glab_mr_json(&json_str, &branch));
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if is_no_mr_error(&stderr) {
+            debug!(event = "core.forge.pr_info_fetch_no_pr", branch = %branch);
+            Ok(None)
+        } else {
+            Err(cli_error("glab mr view", &output))
+        }
+    }
+
+    fn supports_draft_prs(&self) -> bool {
+        true
+    }
+
+    fn create_pr(
+        &self,
+        worktree_path: &Path,
+        branch: &str,
+        draft: bool,
+        body: Option<&str>,
+    ) -> Result<String, ForgeError> {
+        let branch = normalize_branch(branch);
+        info!(
+            event = "core.forge.pr_create_started",
+            branch = %branch,
+            draft = draft,
+            worktree_path = %worktree_path.display()
+        );
+
+        let output = std::process::Command::new("glab")
+            .current_dir(worktree_path)
+            .args(glab_mr_create_args(&branch, draft, body))
+            .output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!(
+                event = "core.forge.pr_create_failed",
+                branch = %branch,
+                exit_code = output.status.code().unwrap_or(-1),
+                stderr = %stderr.trim()
+            );
+            return Err(cli_error("glab mr create", &output));
+        }
+
+        // glab prints the MR URL as the last line of stdout
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let url = stdout.lines().last().unwrap_or_default().trim().to_string();
+        info!(
+            event = "core.forge.pr_create_completed",
+            branch = %branch,
+            draft = draft,
+            url = %url
+        );
+        Ok(url)
+    }
+
+    fn merge_pr(
+        &self,
+        worktree_path: &Path,
+        branch: &str,
+        strategy: MergeStrategy,
+    ) -> Result<(), ForgeError> {
+        let branch = normalize_branch(branch);
+        info!(
+            event = "core.forge.merge_started",
+            branch = %branch,
+            strategy = %strategy,
+            worktree_path = %worktree_path.display()
+        );
+
+        let mut args = vec!["mr", "merge", branch.as_ref(), "--yes"];
+        args.extend(strategy.glab_flag());
+
+        let output = std::process::Command::new("glab")
+            .current_dir(worktree_path)
+            .args(&args)
+            .output()?;
+
+        if output.status.success() {
+            info!(
+                event = "core.forge.merge_completed",
+                branch = %branch,
+                strategy = %strategy
+            );
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!(
+                event = "core.forge.merge_failed",
+                branch = %branch,
+                exit_code = output.status.code().unwrap_or(-1),
+                stderr = %stderr.trim()
+            );
+            Err(cli_error("glab mr merge", &output))
+        }
+    }
+}
+
+/// Build the `glab mr create` arguments for a branch.
+///
+/// `--fill --yes` takes the title and description from the branch's commits
+/// and skips the interactive confirmation; an explicit `--description`
+/// replaces the commit-based one.
+fn glab_mr_create_args<'a>(branch: &'a str, draft: bool, body: Option<&'a str>) -> Vec<&'a str> {
+    let mut args = vec!["mr", "create", "--source-branch", branch, "--fill", "--yes"];
+    if draft {
+        args.push("--draft");
+    }
+    if let Some(body) = body {
+        args.extend(["--description", body]);
+    }
+    args
+}
+
+/// Parse the JSON output from `glab mr view --output json` into a `PullRequest`.
+///
+/// Expects the GitLab REST merge request shape: iid, web_url, state, draft,
+/// head_pipeline, detailed_merge_status. Returns `None` if JSON is malformed
+/// or required fields are missing (logged as warnings).
+fn parse_glab_mr_json(json_str: &str, branch: &str) -> Option<PullRequest> {
+    let value: serde_json::Value = match serde_json::from_str(json_str) {
+        Ok(v) => v,
+        Err(e) => {
+            warn!(
+                event = "core.forge.pr_info_parse_failed",
+                branch = branch,
+                error = %e
+            );
+            return None;
+        }
+    };
+
+    let Some(number) = value
+        .get("iid")
+        .and_then(|v| v.as_u64())
+        .and_then(|n| u32::try_from(n).ok())
+    else {
+        warn!(
+            event = "core.forge.pr_info_missing_field",
+            branch = branch,
+            field = "iid",
+        );
+        return None;
+    };
+    let Some(url) = value.get("web_url").and_then(|v| v.as_str()) else {
+        warn!(
+            event = "core.forge.pr_info_missing_field",
+            branch = branch,
+            field = "web_url",
+        );
+        return None;
+    };
+    let Some(gl_state) = value.get("state").and_then(|v| v.as_str()) else {
+        warn!(
+            event = "core.forge.pr_info_missing_field",
+            branch = branch,
+            field = "state",
+        );
+        return None;
+    };
+    // Older GitLab versions only report `work_in_progress`
+    let is_draft = value
+        .get("draft")
+        .or_else(|| value.get("work_in_progress"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let state = match gl_state {
+        "merged" => PrState::Merged,
+        "closed" | "locked" => PrState::Closed,
+        "opened" if is_draft => PrState::Draft,
+        "opened" => PrState::Open,
+        unknown => {
+            warn!(
+                event = "core.forge.pr_state_unknown",
+                branch = branch,
+                state = unknown,
+                "Unknown MR state from glab CLI — treating as Open"
+            );
+            PrState::Open
+        }
+    };
+
+    let (ci_status, ci_summary) = parse_pipeline_status(&value);
+    let (review_status, review_summary) = parse_merge_status(&value);
+
+    info!(
+        event = "core.forge.pr_info_fetch_completed",
+        branch = branch,
+        pr_number = number,
+        pr_state = %state,
+        ci_status = %ci_status,
+        review_status = %review_status
+    );
+
+    Some(PullRequest {
+        number,
+        url: url.to_string(),
+        state,
+        ci_status,
+        ci_summary,
+        review_status,
+        review_summary,
+        updated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+/// Map `head_pipeline.status` to CI status.
+///
+/// GitLab reports one status for the whole pipeline rather than per-check
+/// results, so the summary is the raw pipeline status.
+fn parse_pipeline_status(value: &serde_json::Value) -> (CiStatus, Option<String>) {
+    let Some(status) = value
+        .get("head_pipeline")
+        .or_else(|| value.get("pipeline"))
+        .and_then(|p| p.get("status"))
+        .and_then(|s| s.as_str())
+    else {
+        return (CiStatus::Unknown, None);
+    };
+
+    let ci_status = match status {
+        "success" | "skipped" => CiStatus::Passing,
+        "failed" | "canceled" => CiStatus::Failing,
+        "created"
+        | "waiting_for_resource"
+        | "preparing"
+        | "pending"
+        | "running"
+        | "manual"
+        | "scheduled" => CiStatus::Pending,
+        _ => CiStatus::Unknown,
+    };
+
+    (ci_status, Some(format!("pipeline {}", status)))
+}
+
+/// Map `detailed_merge_status` to review status.
+///
+/// The MR view payload carries no per-reviewer decisions, only whether the MR
+/// is still blocked on approvals or has changes requested.
+fn parse_merge_status(value: &serde_json::Value) -> (ReviewStatus, Option<String>) {
+    match value.get("detailed_merge_status").and_then(|s| s.as_str()) {
+        Some("requested_changes") => (
+            ReviewStatus::ChangesRequested,
+            Some("changes requested".to_string()),
+        ),
+        Some("not_approved") => (ReviewStatus::Pending, Some("approval required".to_string())),
+        Some("mergeable") => (ReviewStatus::Approved, None),
+        _ => (ReviewStatus::Unknown, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_MR_JSON: &str = r#"{
+        "id": 9001,
+        "iid": 17,
+        "project_id": 42,
+        "title": "Add auth",
+        "state": "opened",
+        "draft": false,
+        "work_in_progress": false,
+        "source_branch": "kild/auth",
+        "target_branch": "main",
+        "web_url": "https://gitlab.com/org/repo/-/merge_requests/17",
+        "detailed_merge_status": "not_approved",
+        "head_pipeline": {
+            "id": 555,
+            "status": "running",
+            "web_url": "https://gitlab.com/org/repo/-/pipelines/555"
+        }
+    }"#;
+
+    #[test]
+    fn test_gitlab_backend_name() {
+        let backend = GitLabBackend;
+        assert_eq!(backend.name(), "gitlab");
+        assert_eq!(backend.display_name(), "GitLab");
+    }
+
+    #[test]
+    fn test_glab_mr_create_args_draft() {
+        assert!(glab_mr_create_args("kild/auth", true, None).contains(&"--draft"));
+        assert!(!glab_mr_create_args("kild/auth", false, None).contains(&"--draft"));
+    }
+
+    #[test]
+    fn test_glab_mr_create_args_body() {
+        let args = glab_mr_create_args("kild/auth", false, Some("## Summary"));
+        assert!(args.ends_with(&["--description", "## Summary"]));
+    }
+
+    #[test]
+    fn test_parse_glab_mr_json_sample() {
+        let pr = parse_glab_mr_json(SAMPLE_MR_JSON, "kild/auth").unwrap();
+        assert_eq!(pr.number, 17);
+        assert_eq!(pr.url, "https://gitlab.com/org/repo/-/merge_requests/17");
+        assert_eq!(pr.state, PrState::Open);
+        assert_eq!(pr.ci_status, CiStatus::Pending);
+        assert_eq!(pr.ci_summary.as_deref(), Some("pipeline running"));
+        assert_eq!(pr.review_status, ReviewStatus::Pending);
+        assert_eq!(pr.review_summary.as_deref(), Some("approval required"));
+    }
+
+    #[test]
+    fn test_parse_glab_mr_json_states() {
+        let cases = [
+            (r#""state": "merged", "draft": false"#, PrState::Merged),
+            (r#""state": "closed", "draft": false"#, PrState::Closed),
+            (r#""state": "opened", "draft": true"#, PrState::Draft),
+            (
+                r#""state": "opened", "work_in_progress": true"#,
+                PrState::Draft,
+            ),
+        ];
+        for (fields, expected) in cases {
+            let json = format!(r#"{{"iid": 1, "web_url": "https://gl/x/1", {}}}"#, fields);
+            let pr = parse_glab_mr_json(&json, "b").unwrap();
+            assert_eq!(pr.state, expected, "for {}", fields);
+        }
+    }
+
+    #[test]
+    fn test_parse_glab_mr_json_missing_fields() {
+        assert!(parse_glab_mr_json(r#"{"iid": 1}"#, "b").is_none());
+        assert!(parse_glab_mr_json("not json", "b").is_none());
+    }
+
+    #[test]
+    fn test_parse_pipeline_status() {
+        let status = |s: &str| {
+            let value = serde_json::json!({ "head_pipeline": { "status": s } });
+            parse_pipeline_status(&value).0
+        };
+        assert_eq!(status("success"), CiStatus::Passing);
+        assert_eq!(status("failed"), CiStatus::Failing);
+        assert_eq!(status("canceled"), CiStatus::Failing);
+        assert_eq!(status("pending"), CiStatus::Pending);
+        assert_eq!(
+            parse_pipeline_status(&serde_json::json!({ "head_pipeline": null })).0,
+            CiStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn test_parse_merge_status() {
+        let status =
+            |s: &str| parse_merge_status(&serde_json::json!({ "detailed_merge_status": s })).0;
+        assert_eq!(status("requested_changes"), ReviewStatus::ChangesRequested);
+        assert_eq!(status("mergeable"), ReviewStatus::Approved);
+        assert_eq!(status("ci_still_running"), ReviewStatus::Unknown);
+    }
+
+    #[test]
+    fn test_is_no_mr_error() {
+        assert!(is_no_mr_error(
+            "no open merge request available for \"kild/auth\""
+        ));
+        assert!(is_no_mr_error("ERROR: 404 Not Found"));
+        assert!(!is_no_mr_error("error connecting to gitlab.com"));
+    }
+}
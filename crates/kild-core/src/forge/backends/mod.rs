@@ -1,3 +1,22 @@
 mod github;
+mod gitlab;
+
+use std::borrow::Cow;
+
+use crate::git::naming::{KILD_BRANCH_PREFIX, kild_branch_name};
 
 pub use github::GitHubBackend;
+pub use gitlab::GitLabBackend;
+
+/// Ensure the branch name has the `kild/` prefix for forge queries.
+///
+/// KILD pushes branches as `kild/<branch>`, so `gh pr view` / `glab mr view`
+/// need the full ref. Callers should always pass the full ref, but this
+/// normalizes defensively to prevent "not found" errors from short branch names.
+fn normalize_branch(branch: &str) -> Cow<'_, str> {
+    if branch.starts_with(KILD_BRANCH_PREFIX) {
+        Cow::Borrowed(branch)
+    } else {
+        Cow::Owned(kild_branch_name(branch))
+    }
+}
@@ -11,6 +11,9 @@ pub enum ForgeError {
     #[error("Forge CLI error: {message}")]
     CliError { message: String },
 
+    #[error("{forge} does not support draft pull requests")]
+    DraftNotSupported { forge: String },
+
     #[error("Failed to parse forge response: {message}")]
     ParseError { message: String },
 
@@ -27,6 +30,7 @@ impl KildError for ForgeError {
             ForgeError::NoForgeAvailable => "FORGE_NOT_AVAILABLE",
             ForgeError::CliNotFound { .. } => "FORGE_CLI_NOT_FOUND",
             ForgeError::CliError { .. } => "FORGE_CLI_ERROR",
+            ForgeError::DraftNotSupported { .. } => "FORGE_DRAFT_NOT_SUPPORTED",
             ForgeError::ParseError { .. } => "FORGE_PARSE_ERROR",
             ForgeError::IoError { .. } => "FORGE_IO_ERROR",
         }
@@ -35,7 +39,9 @@ impl KildError for ForgeError {
     fn is_user_error(&self) -> bool {
         matches!(
             self,
-            ForgeError::NoForgeAvailable | ForgeError::CliNotFound { .. }
+            ForgeError::NoForgeAvailable
+                | ForgeError::CliNotFound { .. }
+                | ForgeError::DraftNotSupported { .. }
         )
     }
 }
@@ -78,6 +84,19 @@ mod tests {
         assert!(!error.is_user_error());
     }
 
+    #[test]
+    fn test_draft_not_supported() {
+        let error = ForgeError::DraftNotSupported {
+            forge: "Gitea".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Gitea does not support draft pull requests"
+        );
+        assert_eq!(error.error_code(), "FORGE_DRAFT_NOT_SUPPORTED");
+        assert!(error.is_user_error());
+    }
+
     #[test]
     fn test_parse_error() {
         let error = ForgeError::ParseError {
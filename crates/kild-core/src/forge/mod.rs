@@ -1,11 +1,13 @@
 pub mod backends;
 pub mod errors;
 pub mod registry;
+pub mod template;
 pub mod traits;
 pub mod types;
 
 // Re-export public API
 pub use errors::ForgeError;
 pub use registry::{detect_forge, get_backend, get_forge_backend};
+pub use template::{compose_pr_body, find_pr_template};
 pub use traits::ForgeBackend;
 pub use types::{ForgeType, MergeReadiness};
@@ -0,0 +1,140 @@
+//! PR/MR description template discovery.
+
+use std::path::Path;
+
+use tracing::{debug, warn};
+
+/// Template locations checked in order; the first readable one wins.
+///
+/// Covers GitHub's `.github/`, GitLab's default MR template, and the repo root.
+const TEMPLATE_CANDIDATES: &[&str] = &[
+    ".github/PULL_REQUEST_TEMPLATE.md",
+    ".github/pull_request_template.md",
+    ".gitlab/merge_request_templates/Default.md",
+    ".gitlab/merge_request_templates/default.md",
+    "PULL_REQUEST_TEMPLATE.md",
+    "pull_request_template.md",
+];
+
+/// Find a PR template in the worktree and return its contents.
+///
+/// Checks `.github/`, `.gitlab/` and the worktree root, preferring
+/// `.github/PULL_REQUEST_TEMPLATE.md` when several exist. Empty templates are
+/// skipped.
+pub fn find_pr_template(worktree: &Path) -> Option<String> {
+    for candidate in TEMPLATE_CANDIDATES {
+        let path = worktree.join(candidate);
+        if !path.is_file() {
+            continue;
+        }
+        match std::fs::read_to_string(&path) {
+            Ok(contents) if !contents.trim().is_empty() => {
+                debug!(
+                    event = "core.forge.pr_template_found",
+                    path = %path.display()
+                );
+                return Some(contents);
+            }
+            Ok(_) => continue,
+            Err(e) => {
+                warn!(
+                    event = "core.forge.pr_template_read_failed",
+                    path = %path.display(),
+                    error = %e
+                );
+            }
+        }
+    }
+    None
+}
+
+/// Build a PR body from a template and the branch's commit summaries.
+///
+/// Returns `None` without a template so callers fall back to the forge CLI's
+/// own commit-based body.
+pub fn compose_pr_body(template: Option<&str>, commits: &[String]) -> Option<String> {
+    let template = template?.trim_end();
+    if commits.is_empty() {
+        return Some(template.to_string());
+    }
+
+    let summary: String = commits.iter().map(|c| format!("- {}\n", c)).collect();
+    Some(format!("{}\n\n## Commits\n\n{}", template, summary))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, contents: &str) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn test_find_pr_template_absent() {
+        let dir = TempDir::new().unwrap();
+        assert!(find_pr_template(dir.path()).is_none());
+    }
+
+    #[test]
+    fn test_find_pr_template_in_each_location() {
+        for rel in [
+            ".github/PULL_REQUEST_TEMPLATE.md",
+            ".gitlab/merge_request_templates/Default.md",
+            "PULL_REQUEST_TEMPLATE.md",
+        ] {
+            let dir = TempDir::new().unwrap();
+            write(dir.path(), rel, "## Summary\n");
+            assert_eq!(
+                find_pr_template(dir.path()).as_deref(),
+                Some("## Summary\n"),
+                "template at {}",
+                rel
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_pr_template_prefers_github() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "PULL_REQUEST_TEMPLATE.md", "root");
+        write(
+            dir.path(),
+            ".gitlab/merge_request_templates/Default.md",
+            "gitlab",
+        );
+        write(dir.path(), ".github/PULL_REQUEST_TEMPLATE.md", "github");
+
+        assert_eq!(find_pr_template(dir.path()).as_deref(), Some("github"));
+    }
+
+    #[test]
+    fn test_find_pr_template_skips_empty() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), ".github/PULL_REQUEST_TEMPLATE.md", "  \n");
+        write(dir.path(), "PULL_REQUEST_TEMPLATE.md", "root");
+
+        assert_eq!(find_pr_template(dir.path()).as_deref(), Some("root"));
+    }
+
+    #[test]
+    fn test_compose_pr_body() {
+        assert_eq!(compose_pr_body(None, &["add a".to_string()]), None);
+        assert_eq!(
+            compose_pr_body(Some("## Summary\n\n"), &[]).as_deref(),
+            Some("## Summary")
+        );
+        assert_eq!(
+            compose_pr_body(
+                Some("## Summary\n"),
+                &["add a".to_string(), "add b".to_string()]
+            )
+            .as_deref(),
+            Some("## Summary\n\n## Commits\n\n- add a\n- add b\n")
+        );
+    }
+}
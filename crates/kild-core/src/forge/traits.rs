@@ -38,6 +38,26 @@ pub trait ForgeBackend: Send + Sync {
         branch: &str,
     ) -> Result<Option<PullRequest>, ForgeError>;
 
+    /// Whether this forge can open PRs/MRs as drafts.
+    fn supports_draft_prs(&self) -> bool {
+        false
+    }
+
+    /// Open a PR/MR for the given branch and return its URL.
+    ///
+    /// The branch must already be pushed. The title is filled from the
+    /// branch's commits; the body is `body` when given, otherwise it is filled
+    /// from the commits too. Backends that cannot open drafts return
+    /// `ForgeError::DraftNotSupported` when `draft` is set rather than
+    /// silently opening a ready PR.
+    fn create_pr(
+        &self,
+        worktree_path: &Path,
+        branch: &str,
+        draft: bool,
+        body: Option<&str>,
+    ) -> Result<String, ForgeError>;
+
     /// Merge a PR using the specified strategy.
     ///
     /// Calls the forge CLI to merge the PR. The `--delete-branch` flag is NOT
@@ -88,6 +108,21 @@ mod tests {
             Ok(None)
         }
 
+        fn create_pr(
+            &self,
+            _worktree_path: &Path,
+            _branch: &str,
+            draft: bool,
+            _body: Option<&str>,
+        ) -> Result<String, ForgeError> {
+            if draft && !self.supports_draft_prs() {
+                return Err(ForgeError::DraftNotSupported {
+                    forge: self.display_name().to_string(),
+                });
+            }
+            Ok("https://example.com/pr/1".to_string())
+        }
+
         fn merge_pr(
             &self,
             _worktree_path: &Path,
@@ -114,4 +149,16 @@ mod tests {
         assert!(backend.check_pr_exists(path, "test").is_unavailable());
         assert!(backend.fetch_pr_info(path, "test").unwrap().is_none());
     }
+
+    #[test]
+    fn test_draft_pr_on_unsupported_backend_errors() {
+        let backend = MockForge;
+        let path = Path::new("/tmp");
+        assert!(!backend.supports_draft_prs());
+        assert!(backend.create_pr(path, "test", false, None).is_ok());
+        assert!(matches!(
+            backend.create_pr(path, "test", true, None),
+            Err(ForgeError::DraftNotSupported { .. })
+        ));
+    }
 }
@@ -126,6 +126,17 @@ impl MergeStrategy {
             MergeStrategy::Rebase => "--rebase",
         }
     }
+
+    /// Returns the `glab mr merge` flag for this strategy.
+    ///
+    /// `glab` merges with a merge commit by default and has no flag for it.
+    pub fn glab_flag(&self) -> Option<&'static str> {
+        match self {
+            MergeStrategy::Squash => Some("--squash"),
+            MergeStrategy::Merge => None,
+            MergeStrategy::Rebase => Some("--rebase"),
+        }
+    }
 }
 
 impl std::fmt::Display for MergeStrategy {
@@ -276,6 +287,7 @@ mod tests {
     #[test]
     fn test_forge_type_as_str() {
         assert_eq!(ForgeType::GitHub.as_str(), "github");
+        assert_eq!(ForgeType::GitLab.as_str(), "gitlab");
     }
 
     #[test]
@@ -284,6 +296,7 @@ mod tests {
         assert_eq!(ForgeType::from_str("github"), Ok(ForgeType::GitHub));
         assert_eq!(ForgeType::from_str("GITHUB"), Ok(ForgeType::GitHub));
         assert_eq!(ForgeType::from_str("GitHub"), Ok(ForgeType::GitHub));
+        assert_eq!(ForgeType::from_str("GitLab"), Ok(ForgeType::GitLab));
         assert!(ForgeType::from_str("unknown").is_err());
         assert!(ForgeType::from_str("").is_err());
     }
@@ -625,6 +638,13 @@ mod tests {
         assert_eq!(MergeStrategy::Rebase.gh_flag(), "--rebase");
     }
 
+    #[test]
+    fn test_merge_strategy_glab_flag() {
+        assert_eq!(MergeStrategy::Squash.glab_flag(), Some("--squash"));
+        assert_eq!(MergeStrategy::Merge.glab_flag(), None);
+        assert_eq!(MergeStrategy::Rebase.glab_flag(), Some("--rebase"));
+    }
+
     #[test]
     fn test_merge_strategy_from_str() {
         use std::str::FromStr;
@@ -67,6 +67,52 @@ fn add_git_worktree_with_retry(
     }
 }
 
+/// Recreate the worktree for an existing `kild/<branch>` at `worktree_path`.
+///
+/// Counterpart of `detach_worktree`: used when unarchiving a kild whose
+/// worktree was removed but whose branch was kept.
+pub fn restore_worktree(
+    repo_root: &Path,
+    worktree_path: &Path,
+    branch: &str,
+) -> Result<(), GitError> {
+    info!(
+        event = "core.git.worktree.restore_started",
+        branch = branch,
+        worktree_path = %worktree_path.display()
+    );
+
+    let repo = Repository::open(repo_root).map_err(git2_error)?;
+    let kild_branch = naming::kild_branch_name(branch);
+    let reference = repo
+        .find_branch(&kild_branch, BranchType::Local)
+        .map_err(|_| GitError::BranchNotFound {
+            branch: kild_branch.clone(),
+        })?
+        .into_reference();
+
+    if let Some(parent) = worktree_path.parent() {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+
+    let mut opts = WorktreeAddOptions::new();
+    opts.reference(Some(&reference));
+    add_git_worktree_with_retry(
+        &repo,
+        &naming::kild_worktree_admin_name(branch),
+        worktree_path,
+        &opts,
+    )?;
+
+    info!(
+        event = "core.git.worktree.restore_completed",
+        branch = kild_branch,
+        worktree_path = %worktree_path.display()
+    );
+
+    Ok(())
+}
+
 pub fn create_worktree(
     base_dir: &Path,
     project: &GitProjectState,
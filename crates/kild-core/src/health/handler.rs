@@ -1,33 +1,23 @@
-use crate::health::{errors::HealthError, operations, types::*};
+use crate::health::{errors::HealthError, operations, storage, types::*};
 use crate::process;
 use crate::process::types::ProcessMetrics;
 use crate::sessions;
 use crate::sessions::agent_status::read_agent_status;
+use kild_config::HealthConfig;
 use tracing::{info, warn};
 
 /// Get health status for all sessions in current project
 pub fn get_health_all_sessions() -> Result<HealthOutput, HealthError> {
-    // Load config and apply thresholds (warn on errors, use defaults)
-    match kild_config::KildConfig::load_hierarchy() {
-        Ok(config) => {
-            operations::set_idle_threshold_minutes(config.health.idle_threshold_minutes());
-        }
-        Err(e) => {
-            warn!(
-                event = "core.config.load_failed",
-                error = %e,
-                "Config load failed during health check, using default idle threshold"
-            );
-        }
-    }
+    let health_config = apply_health_config();
 
     info!(event = "core.health.get_all_started");
 
-    let sessions = sessions::handler::list_sessions()?;
+    let sessions = sessions::handler::list_sessions(false)?;
     let mut kild_healths = Vec::new();
 
     for session in sessions {
         let kild_health = enrich_session_with_metrics(&session);
+        record_history(&health_config, &kild_health);
         kild_healths.push(kild_health);
     }
 
@@ -47,10 +37,13 @@ pub fn get_health_all_sessions() -> Result<HealthOutput, HealthError> {
 
 /// Get health status for a specific session
 pub fn get_health_single_session(branch: &str) -> Result<KildHealth, HealthError> {
+    let health_config = apply_health_config();
+
     info!(event = "core.health.get_single_started", branch = branch);
 
     let session = sessions::handler::get_session(branch)?;
     let kild_health = enrich_session_with_metrics(&session);
+    record_history(&health_config, &kild_health);
 
     info!(
         event = "core.health.get_single_completed",
@@ -61,6 +54,43 @@ pub fn get_health_single_session(branch: &str) -> Result<KildHealth, HealthError
     Ok(kild_health)
 }
 
+/// Load config and apply idle thresholds (warn on errors, use defaults)
+fn apply_health_config() -> HealthConfig {
+    match kild_config::KildConfig::load_hierarchy() {
+        Ok(config) => {
+            operations::set_idle_threshold_minutes(config.health.idle_threshold_minutes());
+            operations::set_agent_idle_thresholds(config.health.idle_threshold_overrides());
+            config.health
+        }
+        Err(e) => {
+            warn!(
+                event = "core.config.load_failed",
+                error = %e,
+                "Config load failed during health check, using default idle threshold"
+            );
+            HealthConfig::default()
+        }
+    }
+}
+
+/// Append the session's metrics to its health history when history is enabled.
+fn record_history(health_config: &HealthConfig, kild_health: &KildHealth) {
+    if !health_config.history_enabled {
+        return;
+    }
+    if let Err(e) = storage::append_session_record(
+        &kild_health.session_id,
+        &kild_health.metrics,
+        health_config.history_max_records(),
+    ) {
+        warn!(
+            event = "core.health.session_history_save_failed",
+            session_id = %kild_health.session_id,
+            error = %e,
+        );
+    }
+}
+
 /// Helper to enrich session with process metrics
 fn enrich_session_with_metrics(session: &sessions::types::Session) -> KildHealth {
     // Find first running agent for metrics (multi-agent path)
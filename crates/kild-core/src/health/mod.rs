@@ -7,6 +7,12 @@ pub mod types;
 // Re-export commonly used types
 pub use errors::HealthError;
 pub use handler::{get_health_all_sessions, get_health_single_session};
-pub use operations::{get_idle_threshold_minutes, set_idle_threshold_minutes};
-pub use storage::{HealthSnapshot, load_history, save_snapshot};
+pub use operations::{
+    get_idle_threshold_minutes, get_idle_threshold_minutes_for, set_agent_idle_thresholds,
+    set_idle_threshold_minutes,
+};
+pub use storage::{
+    HealthSnapshot, SessionHealthRecord, get_health_history, load_history, remove_session_history,
+    save_snapshot,
+};
 pub use types::{HealthMetrics, HealthOutput, HealthStatus, KildHealth};
@@ -3,10 +3,17 @@ use crate::process::types::ProcessMetrics;
 use crate::sessions::types::Session;
 use chrono::{DateTime, Utc};
 use kild_protocol::AgentStatus;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex, PoisonError};
 
 static IDLE_THRESHOLD_MINUTES: AtomicU64 = AtomicU64::new(10);
 
+/// Per-agent idle thresholds, keyed by agent name. Agents without an entry
+/// use `IDLE_THRESHOLD_MINUTES`.
+static AGENT_IDLE_THRESHOLDS: LazyLock<Mutex<HashMap<String, u64>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 /// Set the idle threshold for health status calculation
 pub fn set_idle_threshold_minutes(minutes: u64) {
     IDLE_THRESHOLD_MINUTES.store(minutes, Ordering::Relaxed);
@@ -17,11 +24,43 @@ pub fn get_idle_threshold_minutes() -> u64 {
     IDLE_THRESHOLD_MINUTES.load(Ordering::Relaxed)
 }
 
+/// Replace the per-agent idle threshold overrides
+pub fn set_agent_idle_thresholds(overrides: HashMap<String, u64>) {
+    *AGENT_IDLE_THRESHOLDS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = overrides;
+}
+
+/// Get the idle threshold for `agent`, falling back to the global threshold
+pub fn get_idle_threshold_minutes_for(agent: &str) -> u64 {
+    AGENT_IDLE_THRESHOLDS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(agent)
+        .copied()
+        .unwrap_or_else(get_idle_threshold_minutes)
+}
+
 /// Calculate health status based on process state and activity
 pub fn calculate_health_status(
     process_running: bool,
     last_activity: Option<&str>,
     last_message_from_user: bool,
+) -> HealthStatus {
+    calculate_health_status_with_threshold(
+        process_running,
+        last_activity,
+        last_message_from_user,
+        get_idle_threshold_minutes(),
+    )
+}
+
+/// Calculate health status using an explicit idle threshold in minutes
+pub fn calculate_health_status_with_threshold(
+    process_running: bool,
+    last_activity: Option<&str>,
+    last_message_from_user: bool,
+    threshold: u64,
 ) -> HealthStatus {
     if !process_running {
         return HealthStatus::Crashed;
@@ -37,7 +76,6 @@ pub fn calculate_health_status(
 
     let now = Utc::now();
     let minutes_since_activity = (now.signed_duration_since(activity_time)).num_minutes();
-    let threshold = IDLE_THRESHOLD_MINUTES.load(Ordering::Relaxed);
 
     // Compare as i64 (threshold fits in i64, and minutes_since_activity is i64)
     if minutes_since_activity < threshold as i64 {
@@ -84,10 +122,11 @@ pub fn enrich_session_with_health(
         session.last_activity.as_deref(),
         agent_status_updated_at.as_deref(),
     );
-    let status = calculate_health_status(
+    let status = calculate_health_status_with_threshold(
         process_running,
         effective_last_activity.as_deref(),
         false, // TODO: Track last message sender in future
+        get_idle_threshold_minutes_for(&session.agent),
     );
 
     let status_icon = match status {
@@ -236,6 +275,31 @@ mod tests {
         set_idle_threshold_minutes(10);
     }
 
+    #[test]
+    fn test_idle_threshold_for_agent_uses_override_or_global() {
+        // Agent names are unique to this test so parallel tests never see them
+        set_agent_idle_thresholds(HashMap::from([("test-fast-agent".to_string(), 1000)]));
+        assert_eq!(get_idle_threshold_minutes_for("test-fast-agent"), 1000);
+        assert_eq!(
+            get_idle_threshold_minutes_for("test-unconfigured-agent"),
+            get_idle_threshold_minutes()
+        );
+        set_agent_idle_thresholds(HashMap::new());
+    }
+
+    #[test]
+    fn test_calculate_health_status_with_threshold_ignores_global() {
+        let old = (Utc::now() - chrono::Duration::minutes(200)).to_rfc3339();
+        assert_eq!(
+            calculate_health_status_with_threshold(true, Some(&old), false, 500),
+            HealthStatus::Working
+        );
+        assert_eq!(
+            calculate_health_status_with_threshold(true, Some(&old), false, 5),
+            HealthStatus::Idle
+        );
+    }
+
     // --- enrich_session_with_health tests ---
 
     #[test]
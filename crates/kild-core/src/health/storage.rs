@@ -2,13 +2,15 @@
 //!
 //! Stores health snapshots over time for trend analysis.
 
-use crate::health::types::HealthOutput;
+use crate::health::types::{HealthMetrics, HealthOutput};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 use kild_paths::KildPaths;
+use nix::fcntl::{Flock, FlockArg};
 use tracing::warn;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,6 +120,10 @@ pub fn load_history_from(
                 match entry {
                     Ok(entry) => {
                         let path = entry.path();
+                        // Per-session .jsonl records share the directory
+                        if path.extension().is_none_or(|ext| ext != "json") {
+                            continue;
+                        }
                         match fs::read_to_string(&path) {
                             Ok(content) => {
                                 match serde_json::from_str::<Vec<HealthSnapshot>>(&content) {
@@ -170,6 +176,161 @@ pub fn load_history_from(
     Ok(all_snapshots)
 }
 
+/// A single timestamped health record for one session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionHealthRecord {
+    pub timestamp: DateTime<Utc>,
+    pub metrics: HealthMetrics,
+}
+
+fn session_history_path(history_dir: &Path, session_id: &str) -> PathBuf {
+    history_dir.join(format!(
+        "{}.jsonl",
+        KildPaths::encode_session_id(session_id)
+    ))
+}
+
+/// Append a health record for `session_id`, keeping at most `max_records` lines.
+pub fn append_session_record(
+    session_id: &str,
+    metrics: &HealthMetrics,
+    max_records: usize,
+) -> Result<(), std::io::Error> {
+    let history_dir = get_history_dir()?;
+    let record = SessionHealthRecord {
+        timestamp: Utc::now(),
+        metrics: metrics.clone(),
+    };
+    append_session_record_to(&history_dir, session_id, &record, max_records)
+}
+
+pub fn append_session_record_to(
+    history_dir: &Path,
+    session_id: &str,
+    record: &SessionHealthRecord,
+    max_records: usize,
+) -> Result<(), std::io::Error> {
+    fs::create_dir_all(history_dir)?;
+    let filepath = session_history_path(history_dir, session_id);
+
+    // Appends and rotation both run under an exclusive advisory lock on a
+    // sibling `.lock` file, so a rotation can never drop a record appended
+    // between its read and its rename
+    let lock_file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(false)
+        .open(session_lock_path(&filepath))?;
+    let _lock = Flock::lock(lock_file, FlockArg::LockExclusive).map_err(|(_, e)| e)?;
+
+    let mut line = serde_json::to_string(record)?;
+    line.push('\n');
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&filepath)?
+        .write_all(line.as_bytes())?;
+
+    let content = fs::read_to_string(&filepath)?;
+    let lines: Vec<&str> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    if lines.len() <= max_records {
+        return Ok(());
+    }
+
+    // Rotate in batches: trim to 90% of the cap so the file is rewritten once
+    // every ~10% of `max_records` appends rather than on every append. The
+    // rewrite is swapped in atomically so readers never see a partial file
+    let keep = max_records - max_records / 10;
+    let excess = lines.len() - keep;
+    let mut rotated = lines[excess..].join("\n");
+    if !rotated.is_empty() {
+        rotated.push('\n');
+    }
+    let temp_path = filepath.with_extension(format!("jsonl.tmp.{}", std::process::id()));
+    fs::write(&temp_path, rotated)?;
+    fs::rename(&temp_path, &filepath).inspect_err(|_| {
+        let _ = fs::remove_file(&temp_path);
+    })
+}
+
+fn session_lock_path(history_path: &Path) -> PathBuf {
+    history_path.with_extension("jsonl.lock")
+}
+
+/// Delete the health history of `session_id`. Missing history is not an error.
+pub fn remove_session_history(session_id: &str) -> Result<(), std::io::Error> {
+    let history_dir = get_history_dir()?;
+    remove_session_history_in(&history_dir, session_id)
+}
+
+pub fn remove_session_history_in(
+    history_dir: &Path,
+    session_id: &str,
+) -> Result<(), std::io::Error> {
+    let filepath = session_history_path(history_dir, session_id);
+    let _ = fs::remove_file(session_lock_path(&filepath));
+    match fs::remove_file(filepath) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Read the health records for `session_id` recorded within the last `since`.
+///
+/// Returns records oldest first. Unparseable lines are skipped with a warning.
+pub fn get_health_history(
+    session_id: &str,
+    since: std::time::Duration,
+) -> Result<Vec<(DateTime<Utc>, HealthMetrics)>, std::io::Error> {
+    let history_dir = get_history_dir()?;
+    get_health_history_from(&history_dir, session_id, since)
+}
+
+pub fn get_health_history_from(
+    history_dir: &Path,
+    session_id: &str,
+    since: std::time::Duration,
+) -> Result<Vec<(DateTime<Utc>, HealthMetrics)>, std::io::Error> {
+    let filepath = session_history_path(history_dir, session_id);
+    let content = match fs::read_to_string(&filepath) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    // A window too large to represent means "everything"
+    let cutoff = chrono::Duration::from_std(since)
+        .ok()
+        .and_then(|window| Utc::now().checked_sub_signed(window));
+
+    let mut records: Vec<(DateTime<Utc>, HealthMetrics)> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(
+            |line| match serde_json::from_str::<SessionHealthRecord>(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    warn!(
+                        event = "core.health.session_history_parse_failed",
+                        file_path = %filepath.display(),
+                        error = %e,
+                        "Skipping unparseable health history record"
+                    );
+                    None
+                }
+            },
+        )
+        .filter(|record| cutoff.is_none_or(|cutoff| record.timestamp >= cutoff))
+        .map(|record| (record.timestamp, record.metrics))
+        .collect();
+
+    records.sort_by_key(|(timestamp, _)| *timestamp);
+    Ok(records)
+}
+
 /// Result of history cleanup operation
 #[derive(Debug)]
 pub struct CleanupResult {
@@ -490,6 +651,173 @@ mod tests {
         assert_eq!(result.failed, 0);
     }
 
+    // --- per-session history tests ---
+
+    fn make_record(minutes_ago: i64, status: HealthStatus) -> SessionHealthRecord {
+        SessionHealthRecord {
+            timestamp: Utc::now() - Duration::minutes(minutes_ago),
+            metrics: HealthMetrics {
+                cpu_usage_percent: None,
+                memory_usage_mb: None,
+                process_status: "Running".to_string(),
+                last_activity: None,
+                status,
+                status_icon: "x".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_session_history_returns_time_bounded_slice() {
+        let dir = TempDir::new().unwrap();
+        for (minutes_ago, status) in [
+            (300, HealthStatus::Working),
+            (90, HealthStatus::Idle),
+            (30, HealthStatus::Idle),
+            (5, HealthStatus::Working),
+        ] {
+            let record = make_record(minutes_ago, status);
+            append_session_record_to(dir.path(), "proj/auth", &record, 100).unwrap();
+        }
+
+        let history = get_health_history_from(
+            dir.path(),
+            "proj/auth",
+            std::time::Duration::from_secs(60 * 60),
+        )
+        .unwrap();
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].1.status, HealthStatus::Idle);
+        assert_eq!(history[1].1.status, HealthStatus::Working);
+        assert!(history[0].0 < history[1].0);
+        assert!(dir.path().join("proj%2Fauth.jsonl").exists());
+    }
+
+    #[test]
+    fn test_session_history_rotates_oldest_records() {
+        let dir = TempDir::new().unwrap();
+        for minutes_ago in [50, 40, 30, 20, 10] {
+            let record = make_record(minutes_ago, HealthStatus::Working);
+            append_session_record_to(dir.path(), "proj/auth", &record, 3).unwrap();
+        }
+
+        let content = fs::read_to_string(dir.path().join("proj%2Fauth.jsonl")).unwrap();
+        assert_eq!(content.lines().count(), 3);
+
+        let history = get_health_history_from(
+            dir.path(),
+            "proj/auth",
+            std::time::Duration::from_secs(24 * 60 * 60),
+        )
+        .unwrap();
+        assert_eq!(history.len(), 3);
+        // The 50- and 40-minute-old records were dropped
+        assert!(history[0].0 > Utc::now() - Duration::minutes(35));
+    }
+
+    #[test]
+    fn test_session_history_rotates_in_batches() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("proj%2Fauth.jsonl");
+        let record = make_record(1, HealthStatus::Working);
+        for _ in 0..21 {
+            append_session_record_to(dir.path(), "proj/auth", &record, 20).unwrap();
+        }
+        // Crossing the cap trims to 90% of it
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 18);
+
+        // Further appends grow the file again without rewriting it
+        append_session_record_to(dir.path(), "proj/auth", &record, 20).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 19);
+    }
+
+    #[test]
+    fn test_session_history_concurrent_appends_survive_rotation() {
+        let dir = TempDir::new().unwrap();
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let history_dir = dir.path().to_path_buf();
+                std::thread::spawn(move || {
+                    let record = make_record(1, HealthStatus::Working);
+                    for _ in 0..50 {
+                        append_session_record_to(&history_dir, "proj/auth", &record, 100).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Serialized appends rotate at 101 lines and every 11 appends after,
+        // so the 200th leaves exactly 90; a lost append would change the count
+        let content = fs::read_to_string(dir.path().join("proj%2Fauth.jsonl")).unwrap();
+        assert_eq!(content.lines().count(), 90);
+    }
+
+    #[test]
+    fn test_session_history_ids_differing_by_slash_and_underscore_do_not_collide() {
+        let dir = TempDir::new().unwrap();
+        append_session_record_to(
+            dir.path(),
+            "proj/auth",
+            &make_record(2, HealthStatus::Working),
+            10,
+        )
+        .unwrap();
+        append_session_record_to(
+            dir.path(),
+            "proj_auth",
+            &make_record(1, HealthStatus::Idle),
+            10,
+        )
+        .unwrap();
+
+        let window = std::time::Duration::from_secs(60 * 60);
+        let slash = get_health_history_from(dir.path(), "proj/auth", window).unwrap();
+        let underscore = get_health_history_from(dir.path(), "proj_auth", window).unwrap();
+        assert_eq!(slash.len(), 1);
+        assert_eq!(slash[0].1.status, HealthStatus::Working);
+        assert_eq!(underscore.len(), 1);
+        assert_eq!(underscore[0].1.status, HealthStatus::Idle);
+    }
+
+    #[test]
+    fn test_remove_session_history_deletes_only_that_session() {
+        let dir = TempDir::new().unwrap();
+        let record = make_record(1, HealthStatus::Working);
+        append_session_record_to(dir.path(), "proj/auth", &record, 10).unwrap();
+        append_session_record_to(dir.path(), "proj/billing", &record, 10).unwrap();
+
+        remove_session_history_in(dir.path(), "proj/auth").unwrap();
+        // Removing again is a no-op
+        remove_session_history_in(dir.path(), "proj/auth").unwrap();
+
+        assert!(!dir.path().join("proj%2Fauth.jsonl").exists());
+        assert!(dir.path().join("proj%2Fbilling.jsonl").exists());
+    }
+
+    #[test]
+    fn test_session_history_missing_file_is_empty() {
+        let dir = TempDir::new().unwrap();
+        let history =
+            get_health_history_from(dir.path(), "proj/none", std::time::Duration::from_secs(60))
+                .unwrap();
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn test_load_history_ignores_session_history_files() {
+        let dir = TempDir::new().unwrap();
+        let record = make_record(1, HealthStatus::Working);
+        append_session_record_to(dir.path(), "proj/auth", &record, 10).unwrap();
+        save_snapshot_to(dir.path(), &make_test_snapshot(1, 0, 0)).unwrap();
+
+        let loaded = load_history_from(dir.path(), 1).unwrap();
+        assert_eq!(loaded.len(), 1);
+    }
+
     // --- load_history date filtering ---
 
     #[test]
@@ -14,6 +14,7 @@
 pub mod agents;
 pub mod cleanup;
 pub mod daemon;
+pub mod doctor;
 pub mod editor;
 pub mod errors;
 pub mod escape;
@@ -47,8 +48,8 @@ pub use git::types::{
 };
 pub use kild_config::ConfigError;
 pub use kild_config::{
-    AgentConfig, AgentSettings, Config, DaemonRuntimeConfig, EditorConfig, GitConfig, HealthConfig,
-    Keybindings, KildConfig, TerminalConfig, UiConfig, VALID_TERMINALS,
+    AgentConfig, AgentSettings, Config, ConfigWatcher, DaemonRuntimeConfig, EditorConfig,
+    GitConfig, HealthConfig, Keybindings, KildConfig, TerminalConfig, UiConfig, VALID_TERMINALS,
 };
 pub use kild_config::{CopyOptions, IncludeConfig, PatternRule};
 pub use projects::{Project, ProjectError, ProjectManager, ProjectsData};
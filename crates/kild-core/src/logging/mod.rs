@@ -1,3 +1,10 @@
+use tracing::{Span, Subscriber, field::Empty};
+use tracing_subscriber::fmt::{
+    self,
+    format::{Format, Json, JsonFields},
+    writer::MakeWriter,
+};
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 
 /// Initialize logging with quiet mode control.
@@ -8,13 +15,7 @@ pub fn init_logging(quiet: bool) {
     let directive = if quiet { "kild=off" } else { "kild=info" };
 
     tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::fmt::layer()
-                .json()
-                .with_writer(std::io::stderr)
-                .with_current_span(false)
-                .with_span_list(false),
-        )
+        .with(json_layer(std::io::stderr))
         .with(
             EnvFilter::from_default_env()
                 .add_directive(directive.parse().expect("Invalid log directive")),
@@ -22,6 +23,42 @@ pub fn init_logging(quiet: bool) {
         .init();
 }
 
+/// JSON log layer writing to `writer`.
+///
+/// Each event carries the fields of its innermost span under `"span"`, so
+/// events inside a [`session_span`] can be correlated by `session_id`.
+pub(crate) fn json_layer<S, W>(writer: W) -> fmt::Layer<S, JsonFields, Format<Json>, W>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + 'static,
+{
+    fmt::layer()
+        .json()
+        .with_writer(writer)
+        .with_current_span(true)
+        .with_span_list(false)
+}
+
+/// Span wrapping one session operation (`create`, `stop`, ...).
+///
+/// `session_id` and `agent` start empty; fill them in with [`record_session`]
+/// once the session is known.
+pub(crate) fn session_span(operation: &'static str, branch: &str) -> Span {
+    tracing::info_span!(
+        "session_op",
+        operation,
+        branch,
+        session_id = Empty,
+        agent = Empty
+    )
+}
+
+/// Record the session id and agent on a [`session_span`].
+pub(crate) fn record_session(span: &Span, session_id: &str, agent: &str) {
+    span.record("session_id", session_id);
+    span.record("agent", agent);
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
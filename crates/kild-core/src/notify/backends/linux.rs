@@ -3,8 +3,34 @@
 use crate::notify::errors::NotifyError;
 use crate::notify::traits::NotificationBackend;
 
+const NOTIFY_SEND: &str = "notify-send";
+
 /// Linux notification backend via `notify-send` (libnotify).
-pub struct LinuxNotificationBackend;
+pub struct LinuxNotificationBackend {
+    /// Checks whether a command is on `PATH`. Injectable for tests.
+    command_exists: fn(&str) -> bool,
+}
+
+impl Default for LinuxNotificationBackend {
+    fn default() -> Self {
+        Self {
+            command_exists: |cmd| which::which(cmd).is_ok(),
+        }
+    }
+}
+
+impl LinuxNotificationBackend {
+    /// Create a backend that uses `command_exists` instead of a `PATH` lookup.
+    pub fn with_command_checker(command_exists: fn(&str) -> bool) -> Self {
+        Self { command_exists }
+    }
+}
+
+/// Arguments passed to `notify-send`. The `--` keeps a title or message
+/// starting with `-` from being parsed as an option.
+fn notify_send_args<'a>(title: &'a str, message: &'a str) -> [&'a str; 3] {
+    ["--", title, message]
+}
 
 impl NotificationBackend for LinuxNotificationBackend {
     fn name(&self) -> &'static str {
@@ -12,13 +38,12 @@ impl NotificationBackend for LinuxNotificationBackend {
     }
 
     fn is_available(&self) -> bool {
-        cfg!(target_os = "linux") && which::which("notify-send").is_ok()
+        (self.command_exists)(NOTIFY_SEND)
     }
 
     fn send(&self, title: &str, message: &str) -> Result<(), NotifyError> {
-        let output = std::process::Command::new("notify-send")
-            .arg(title)
-            .arg(message)
+        let output = std::process::Command::new(NOTIFY_SEND)
+            .args(notify_send_args(title, message))
             .output()
             .map_err(|e| NotifyError::SendFailed {
                 message: format!("notify-send exec failed: {}", e),
@@ -41,15 +66,34 @@ mod tests {
 
     #[test]
     fn linux_backend_name() {
-        let backend = LinuxNotificationBackend;
+        let backend = LinuxNotificationBackend::default();
         assert_eq!(backend.name(), "linux");
     }
 
     #[test]
-    fn linux_backend_availability_matches_platform() {
-        let backend = LinuxNotificationBackend;
-        if !cfg!(target_os = "linux") {
-            assert!(!backend.is_available());
-        }
+    fn linux_backend_available_when_notify_send_found() {
+        let backend = LinuxNotificationBackend::with_command_checker(|cmd| cmd == "notify-send");
+        assert!(backend.is_available());
+    }
+
+    #[test]
+    fn linux_backend_unavailable_when_notify_send_missing() {
+        let backend = LinuxNotificationBackend::with_command_checker(|_| false);
+        assert!(!backend.is_available());
+    }
+
+    #[test]
+    fn notify_send_args_pass_title_then_message() {
+        assert_eq!(
+            notify_send_args("KILD", "Agent claude in auth needs input (waiting)"),
+            ["--", "KILD", "Agent claude in auth needs input (waiting)"]
+        );
+    }
+
+    #[test]
+    fn notify_send_args_guard_leading_dash() {
+        let args = notify_send_args("-t", "--urgency=critical");
+        assert_eq!(args[0], "--");
+        assert_eq!(&args[1..], ["-t", "--urgency=critical"]);
     }
 }
@@ -5,14 +5,17 @@
 //! `Waiting` or `Error` status.
 //!
 //! Notifications are dispatched via the [`NotificationBackend`] trait,
-//! with platform-specific backends registered in [`registry`].
+//! with platform-specific backends registered in [`registry`]. Callers
+//! suppress repeats for the same session and status with [`throttle`].
 
 pub mod backends;
 pub mod errors;
 pub mod registry;
+pub mod throttle;
 pub mod traits;
 
 pub use errors::NotifyError;
+pub use throttle::NotificationLog;
 pub use traits::NotificationBackend;
 
 use kild_protocol::AgentStatus;
@@ -34,18 +37,20 @@ pub fn format_notification_message(agent: &str, branch: &str, status: AgentStatu
     format!("Agent {} in {} needs input ({})", agent, branch, status)
 }
 
-/// Send a platform-native desktop notification (best-effort).
+/// Send a platform-native desktop notification for an agent status change (best-effort).
 ///
-/// Dispatches to the first available [`NotificationBackend`] via the registry.
-/// Failures are logged at warn level but never returned as errors.
-pub fn send_notification(title: &str, message: &str) {
+/// Dispatches to the first available [`NotificationBackend`] via the
+/// registry. Failures are logged at warn level but never returned as errors.
+pub fn send_notification(agent: &str, branch: &str, status: AgentStatus) {
+    let title = "KILD";
+    let message = format_notification_message(agent, branch, status);
     info!(
         event = "core.notify.send_started",
         title = title,
-        message = message,
+        message = %message,
     );
 
-    match registry::send_via_backend(title, message) {
+    match registry::send_via_backend(title, &message) {
         Ok(true) => {
             info!(event = "core.notify.send_completed", title = title);
         }
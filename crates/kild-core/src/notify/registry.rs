@@ -4,7 +4,9 @@ use std::sync::LazyLock;
 
 use tracing::debug;
 
-use super::backends::{LinuxNotificationBackend, MacOsNotificationBackend};
+#[cfg(target_os = "linux")]
+use super::backends::LinuxNotificationBackend;
+use super::backends::MacOsNotificationBackend;
 use super::traits::NotificationBackend;
 
 /// Global registry of all supported notification backends.
@@ -20,7 +22,8 @@ impl NotificationRegistry {
         Self {
             backends: vec![
                 Box::new(MacOsNotificationBackend),
-                Box::new(LinuxNotificationBackend),
+                #[cfg(target_os = "linux")]
+                Box::new(LinuxNotificationBackend::default()),
             ],
         }
     }
@@ -64,7 +67,7 @@ mod tests {
         let registry = NotificationRegistry::new();
         let names: Vec<&str> = registry.backends.iter().map(|b| b.name()).collect();
         assert!(names.contains(&"macos"));
-        assert!(names.contains(&"linux"));
+        assert_eq!(names.contains(&"linux"), cfg!(target_os = "linux"));
     }
 
     #[test]
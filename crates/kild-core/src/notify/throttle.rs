@@ -0,0 +1,112 @@
+//! Suppression of repeat notifications for flapping agents.
+//!
+//! An agent bouncing between `Working` and `Waiting` would otherwise raise a
+//! desktop notification on every transition. Each `kild agent-status --notify`
+//! runs in its own short-lived process, so the last time each status was
+//! notified is persisted per session (the `notified` sidecar, see
+//! `sessions::persistence`). This module only holds that record and decides
+//! whether a repeat falls inside the configured window.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use kild_protocol::AgentStatus;
+use serde::{Deserialize, Serialize};
+
+/// Default suppression window, matching `NotifyConfig::throttle_window_secs()`.
+pub const DEFAULT_THROTTLE_WINDOW: Duration = Duration::from_secs(60);
+
+/// When each status was last notified for one session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NotificationLog {
+    last_sent: HashMap<AgentStatus, DateTime<Utc>>,
+}
+
+impl NotificationLog {
+    /// Returns `true` if `status` was not notified within `window` before `now`.
+    /// A zero window disables throttling.
+    pub fn should_send(&self, status: AgentStatus, now: DateTime<Utc>, window: Duration) -> bool {
+        if window.is_zero() {
+            return true;
+        }
+        let Some(sent) = self.last_sent.get(&status) else {
+            return true;
+        };
+        // A window too large to represent suppresses forever
+        chrono::Duration::from_std(window)
+            .ok()
+            .and_then(|window| sent.checked_add_signed(window))
+            .is_some_and(|expires| now >= expires)
+    }
+
+    /// Record that `status` was notified at `now`.
+    pub fn record(&mut self, status: AgentStatus, now: DateTime<Utc>) {
+        self.last_sent.insert(status, now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    #[test]
+    fn test_repeat_inside_window_is_dropped() {
+        let start = Utc::now();
+        let mut log = NotificationLog::default();
+
+        assert!(log.should_send(AgentStatus::Waiting, start, window()));
+        log.record(AgentStatus::Waiting, start);
+        assert!(!log.should_send(
+            AgentStatus::Waiting,
+            start + chrono::Duration::seconds(30),
+            window()
+        ));
+    }
+
+    #[test]
+    fn test_repeat_after_window_passes_through() {
+        let start = Utc::now();
+        let mut log = NotificationLog::default();
+        log.record(AgentStatus::Waiting, start);
+
+        assert!(log.should_send(
+            AgentStatus::Waiting,
+            start + chrono::Duration::seconds(61),
+            window()
+        ));
+    }
+
+    #[test]
+    fn test_statuses_are_independent() {
+        let start = Utc::now();
+        let mut log = NotificationLog::default();
+        log.record(AgentStatus::Waiting, start);
+
+        assert!(log.should_send(AgentStatus::Error, start, window()));
+    }
+
+    #[test]
+    fn test_zero_window_disables_throttling() {
+        let start = Utc::now();
+        let mut log = NotificationLog::default();
+        log.record(AgentStatus::Waiting, start);
+
+        assert!(log.should_send(AgentStatus::Waiting, start, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_log_roundtrips_through_json() {
+        let mut log = NotificationLog::default();
+        log.record(AgentStatus::Error, Utc::now());
+
+        let json = serde_json::to_string(&log).unwrap();
+        assert!(json.contains("\"error\""));
+        assert_eq!(serde_json::from_str::<NotificationLog>(&json).unwrap(), log);
+    }
+}
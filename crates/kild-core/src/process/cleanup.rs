@@ -1,16 +1,23 @@
 use std::path::Path;
 use tracing::debug;
 
+use crate::process::pid_file::legacy_pid_file_path;
 use crate::process::{delete_pid_file, get_pid_file_path};
 
 /// Clean up PID files by key (best-effort).
 ///
 /// Each key maps to a PID file via [`get_pid_file_path`]. Callers are responsible
 /// for resolving which keys to clean up (e.g. spawn IDs for multi-agent sessions,
-/// session IDs for legacy sessions). Failures are logged at debug level since
+/// session IDs for legacy sessions). Files left under the pre-encoding name by
+/// older binaries are removed too. Failures are logged at debug level since
 /// PID file cleanup is best-effort.
 pub(crate) fn cleanup_pid_files(pid_keys: &[String], kild_dir: &Path, operation: &str) {
     for pid_key in pid_keys {
+        let legacy_pid_file = legacy_pid_file_path(kild_dir, pid_key);
+        if legacy_pid_file.exists() {
+            let _ = delete_pid_file(&legacy_pid_file);
+        }
+
         let pid_file = get_pid_file_path(kild_dir, pid_key);
         match delete_pid_file(&pid_file) {
             Ok(()) => {
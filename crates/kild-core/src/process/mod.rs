@@ -7,8 +7,9 @@ pub mod types;
 pub(crate) use cleanup::cleanup_pid_files;
 pub use errors::ProcessError;
 pub use operations::{
-    find_process_by_name, find_processes_in_directory, get_process_info, get_process_metrics,
-    is_process_running, kill_process,
+    cmdline_matches_pattern, find_agent_process, find_process_by_name, find_process_in_directory,
+    find_processes_in_directory, get_process_info, get_process_metrics, is_process_running,
+    kill_process, kill_process_tree, prime_cpu_sampling, verify_process_identity,
 };
 pub use pid_file::{
     delete_pid_file, ensure_pid_dir, get_pid_file_path, read_pid_file_with_retry,
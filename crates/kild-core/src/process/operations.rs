@@ -1,16 +1,22 @@
 use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use sysinfo::{Pid as SysinfoPid, ProcessesToUpdate, System};
-use tracing::{debug, error};
+use std::time::{Duration, Instant};
 
+use nix::sys::signal::Signal;
+use sysinfo::{Pid as SysinfoPid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+use tracing::{debug, error, warn};
+
+use crate::agents::traits::AgentBackend;
 use crate::process::errors::ProcessError;
 use crate::process::types::{Pid, ProcessMetrics, ProcessSnapshot, ProcessStatus};
 
 // CPU usage reporting requires a System that has seen a prior snapshot; reusing
 // the same instance per thread gives sysinfo the delta it needs for a meaningful
-// percentage. Other functions (kill_process, get_process_info, find_process_by_name,
-// find_processes_in_directory) use fresh System::new() instances deliberately —
-// they are one-shot operations that must not accumulate state across calls.
+// percentage. get_process_info borrows it for the CPU value only. Other
+// functions (kill_process, find_process, find_processes_in_directory) use fresh
+// System::new() instances deliberately — they are one-shot operations that must
+// not accumulate state across calls.
 thread_local! {
     static SYSTEM: RefCell<System> = RefCell::new(System::new());
 }
@@ -81,27 +87,7 @@ pub fn kill_process(
 
     match system.process(pid_obj) {
         Some(process) => {
-            // Validate process identity to prevent PID reuse attacks
-            if let Some(name) = expected_name {
-                let actual_name = process.name().to_string_lossy().to_string();
-                if !process_name_matches(&actual_name, name) {
-                    return Err(ProcessError::PidReused {
-                        pid,
-                        expected: name.to_string(),
-                        actual: actual_name,
-                    });
-                }
-            }
-
-            if let Some(start_time) = expected_start_time
-                && process.start_time() != start_time
-            {
-                return Err(ProcessError::PidReused {
-                    pid,
-                    expected: format!("start_time={}", start_time),
-                    actual: format!("start_time={}", process.start_time()),
-                });
-            }
+            validate_process_identity(process, pid, expected_name, expected_start_time)?;
 
             if process.kill() {
                 Ok(())
@@ -116,7 +102,242 @@ pub fn kill_process(
     }
 }
 
-/// Get basic information about a process
+/// Verify that `pid` is still the process we expect, to prevent PID reuse attacks.
+///
+/// Returns `NotFound` if the process is gone and `PidReused` if its name or
+/// start time no longer match.
+pub fn verify_process_identity(
+    pid: u32,
+    expected_name: Option<&str>,
+    expected_start_time: Option<u64>,
+) -> Result<(), ProcessError> {
+    let mut system = System::new();
+    let pid_obj = SysinfoPid::from_u32(pid);
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid_obj]), true);
+
+    let process = system
+        .process(pid_obj)
+        .ok_or(ProcessError::NotFound { pid })?;
+    validate_process_identity(process, pid, expected_name, expected_start_time)
+}
+
+fn validate_process_identity(
+    process: &sysinfo::Process,
+    pid: u32,
+    expected_name: Option<&str>,
+    expected_start_time: Option<u64>,
+) -> Result<(), ProcessError> {
+    if let Some(name) = expected_name {
+        let actual_name = process.name().to_string_lossy().to_string();
+        if !process_name_matches(&actual_name, name) {
+            return Err(ProcessError::PidReused {
+                pid,
+                expected: name.to_string(),
+                actual: actual_name,
+            });
+        }
+    }
+
+    if let Some(start_time) = expected_start_time
+        && process.start_time() != start_time
+    {
+        return Err(ProcessError::PidReused {
+            pid,
+            expected: format!("start_time={}", start_time),
+            actual: format!("start_time={}", process.start_time()),
+        });
+    }
+
+    Ok(())
+}
+
+/// How long `kill_process_tree` waits after SIGTERM before sending SIGKILL.
+const KILL_TREE_GRACE_PERIOD: Duration = Duration::from_secs(3);
+
+/// How long `kill_process_tree` waits for SIGKILL to take effect.
+const KILL_TREE_SIGKILL_WAIT: Duration = Duration::from_secs(1);
+
+const KILL_TREE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Kill a process and all of its descendants.
+///
+/// Sends SIGTERM to the whole tree, waits up to a grace period for it to exit,
+/// then sends SIGKILL to any survivors. The tree is captured before signaling
+/// because children are reparented once their parent exits.
+///
+/// Does not validate process identity — call [`verify_process_identity`] first
+/// when the PID comes from persisted state.
+pub fn kill_process_tree(pid: Pid) -> Result<(), ProcessError> {
+    kill_process_tree_with_grace(pid.as_u32(), KILL_TREE_GRACE_PERIOD)
+}
+
+fn kill_process_tree_with_grace(root: u32, grace: Duration) -> Result<(), ProcessError> {
+    if live_pids(&[root]).is_empty() {
+        return Err(ProcessError::NotFound { pid: root });
+    }
+
+    let mut tree = vec![root];
+    tree.extend(descendant_pids(root, &children_by_parent()));
+    debug!(
+        event = "core.process.kill_tree_started",
+        pid = root,
+        descendants = tree.len() - 1
+    );
+
+    for &pid in &tree {
+        send_signal(pid, Signal::SIGTERM);
+    }
+    let mut remaining = wait_for_exit(&tree, grace);
+
+    if !remaining.is_empty() {
+        warn!(
+            event = "core.process.kill_tree_escalated",
+            pid = root,
+            remaining = remaining.len()
+        );
+        for &pid in &remaining {
+            send_signal(pid, Signal::SIGKILL);
+        }
+        remaining = wait_for_exit(&remaining, KILL_TREE_SIGKILL_WAIT);
+    }
+
+    if remaining.contains(&root) {
+        return Err(ProcessError::KillFailed {
+            pid: root,
+            message: "Process still running after SIGKILL".to_string(),
+        });
+    }
+    if !remaining.is_empty() {
+        warn!(
+            event = "core.process.kill_tree_survivors",
+            pid = root,
+            survivors = ?remaining
+        );
+    }
+
+    debug!(event = "core.process.kill_tree_completed", pid = root);
+    Ok(())
+}
+
+/// Poll until every PID in `pids` has exited or `timeout` elapses.
+/// Returns the PIDs still alive.
+fn wait_for_exit(pids: &[u32], timeout: Duration) -> Vec<u32> {
+    let deadline = Instant::now() + timeout;
+    let mut remaining = live_pids(pids);
+    while !remaining.is_empty() && Instant::now() < deadline {
+        std::thread::sleep(KILL_TREE_POLL_INTERVAL);
+        remaining = live_pids(&remaining);
+    }
+    remaining
+}
+
+/// PIDs from `pids` that are still running. Zombies count as exited — they
+/// hold no resources and are waiting for their parent to reap them.
+fn live_pids(pids: &[u32]) -> Vec<u32> {
+    let sys_pids: Vec<SysinfoPid> = pids.iter().map(|&p| SysinfoPid::from_u32(p)).collect();
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&sys_pids), true);
+    pids.iter()
+        .copied()
+        .filter(|&p| {
+            system
+                .process(SysinfoPid::from_u32(p))
+                .is_some_and(|process| process.status() != sysinfo::ProcessStatus::Zombie)
+        })
+        .collect()
+}
+
+/// Send `signal` to `pid`, ignoring processes that have already exited.
+fn send_signal(pid: u32, signal: Signal) {
+    let Ok(raw) = i32::try_from(pid) else {
+        return;
+    };
+    match nix::sys::signal::kill(nix::unistd::Pid::from_raw(raw), signal) {
+        Ok(()) | Err(nix::errno::Errno::ESRCH) => {}
+        Err(e) => {
+            warn!(
+                event = "core.process.signal_failed",
+                pid = pid,
+                signal = signal.as_str(),
+                error = %e
+            );
+        }
+    }
+}
+
+/// All transitive children of `root`, breadth-first.
+fn descendant_pids(root: u32, children: &HashMap<u32, Vec<u32>>) -> Vec<u32> {
+    let mut seen = HashSet::from([root]);
+    let mut queue = vec![root];
+    let mut descendants = Vec::new();
+    while let Some(pid) = queue.pop() {
+        for &child in children.get(&pid).into_iter().flatten() {
+            if seen.insert(child) {
+                descendants.push(child);
+                queue.push(child);
+            }
+        }
+    }
+    descendants
+}
+
+/// Map of parent PID to child PIDs, read from `/proc`.
+#[cfg(target_os = "linux")]
+fn children_by_parent() -> HashMap<u32, Vec<u32>> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return children;
+    };
+    for entry in entries.flatten() {
+        let Some(pid) = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        // The process may exit between read_dir and this read
+        let Ok(stat) = std::fs::read_to_string(entry.path().join("stat")) else {
+            continue;
+        };
+        if let Some(ppid) = parse_stat_ppid(&stat) {
+            children.entry(ppid).or_default().push(pid);
+        }
+    }
+    children
+}
+
+/// Map of parent PID to child PIDs, read via sysinfo.
+#[cfg(not(target_os = "linux"))]
+fn children_by_parent() -> HashMap<u32, Vec<u32>> {
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::All, true);
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (pid, process) in system.processes() {
+        if let Some(parent) = process.parent() {
+            children
+                .entry(parent.as_u32())
+                .or_default()
+                .push(pid.as_u32());
+        }
+    }
+    children
+}
+
+/// Extract the parent PID from the contents of `/proc/<pid>/stat`.
+///
+/// The command name is wrapped in parentheses and may itself contain spaces
+/// or `)`, so fields are read after the last `)`: state, then ppid.
+#[cfg(target_os = "linux")]
+fn parse_stat_ppid(stat: &str) -> Option<u32> {
+    let rest = &stat[stat.rfind(')')? + 1..];
+    rest.split_whitespace().nth(1)?.parse().ok()
+}
+
+/// Get basic information about a process, including a resource usage sample.
+///
+/// `cpu_percent` is instantaneous and comes from the per-thread sampler, so it
+/// is 0 the first time a PID is sampled on a thread (see [`prime_cpu_sampling`]).
 pub fn get_process_info(pid: u32) -> Result<ProcessSnapshot, ProcessError> {
     let mut system = System::new();
     let pid_obj = SysinfoPid::from_u32(pid);
@@ -128,11 +349,30 @@ pub fn get_process_info(pid: u32) -> Result<ProcessSnapshot, ProcessError> {
             name: process.name().to_string_lossy().to_string(),
             status: ProcessStatus::from(process.status()),
             start_time: process.start_time(),
+            cpu_percent: get_process_metrics(pid).map_or(0.0, |m| m.cpu_usage_percent),
+            memory_bytes: process.memory(),
         }),
         None => Err(ProcessError::NotFound { pid }),
     }
 }
 
+/// Take a first CPU sample of `pids` and wait long enough for the next
+/// [`get_process_info`] / [`get_process_metrics`] on this thread to report a
+/// real percentage instead of 0.
+///
+/// Blocks for `sysinfo::MINIMUM_CPU_UPDATE_INTERVAL` (once, however many PIDs).
+/// For one-shot callers such as `kild status`; long-lived callers get the
+/// delta from their previous refresh instead. Unknown PIDs are skipped.
+pub fn prime_cpu_sampling(pids: &[u32]) {
+    if pids.is_empty() {
+        return;
+    }
+    for &pid in pids {
+        let _ = get_process_metrics(pid);
+    }
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+}
+
 /// Get CPU and memory usage metrics for a process
 pub fn get_process_metrics(pid: u32) -> Result<ProcessMetrics, ProcessError> {
     let pid_obj = SysinfoPid::from_u32(pid);
@@ -226,6 +466,72 @@ fn command_matches(cmd_line: &str, cmd_pattern: &str) -> bool {
     })
 }
 
+/// Check if a command line invokes the program named by `pattern`.
+///
+/// Handles agents launched via absolute paths or versioned/wrapper binaries:
+/// 1. `pattern` is a substring of the first token (e.g. `kiro-cli` in
+///    `/Users/me/.local/bin/kiro-cli-chat chat`)
+/// 2. `pattern` is a directory or file name in the path given as the first
+///    non-flag argument, for interpreter wrappers
+///    (e.g. `node /usr/lib/node_modules/claude-code/cli.js`)
+/// 3. `pattern` matches any command of a shell `-c` script, for shell
+///    wrappers (e.g. `sh -c echo $$ > /tmp/pid && exec claude`)
+///
+/// Returns false for empty patterns or empty command lines.
+pub fn cmdline_matches_pattern(cmd_line: &str, pattern: &str) -> bool {
+    if pattern.is_empty() {
+        return false;
+    }
+
+    let mut tokens = cmd_line.split_whitespace();
+    let Some(program) = tokens.next() else {
+        return false;
+    };
+
+    if program.contains(pattern) {
+        return true;
+    }
+
+    if let Some(script) = shell_script(cmd_line) {
+        return script
+            .split(['&', '|', ';'])
+            .map(|command| command.trim().trim_start_matches("exec "))
+            .any(|command| cmdline_matches_pattern(command, pattern));
+    }
+
+    tokens
+        .find(|token| !token.starts_with('-'))
+        .filter(|script| script.contains(['/', '\\']))
+        .is_some_and(|script| script.split(['/', '\\']).any(|part| part == pattern))
+}
+
+/// Return the script of a `sh -c <script>` style command line.
+///
+/// Recognizes the common POSIX shells and fish; the `-c` flag may be combined
+/// with others (e.g. `bash -lc`). Returns `None` for any other command line.
+fn shell_script(cmd_line: &str) -> Option<&str> {
+    const SHELLS: &[&str] = &["sh", "bash", "zsh", "dash", "fish"];
+
+    let mut rest = cmd_line.trim_start();
+    let program = rest.split_whitespace().next()?;
+    if !SHELLS.contains(&extract_base_name(program)) {
+        return None;
+    }
+    rest = rest[program.len()..].trim_start();
+
+    while let Some(flag) = rest
+        .split_whitespace()
+        .next()
+        .filter(|t| t.starts_with('-'))
+    {
+        rest = rest[flag.len()..].trim_start();
+        if !flag.starts_with("--") && flag.contains('c') {
+            return Some(rest);
+        }
+    }
+    None
+}
+
 /// Find a process by name, optionally filtering by command line pattern.
 ///
 /// `additional_patterns` allows callers to provide domain-specific search patterns
@@ -235,12 +541,32 @@ pub fn find_process_by_name(
     command_pattern: Option<&str>,
     additional_patterns: Option<&[String]>,
 ) -> Result<Option<ProcessSnapshot>, ProcessError> {
-    let mut system = System::new();
-    system.refresh_processes(ProcessesToUpdate::All, true);
-
     // Try multiple search strategies
     let search_patterns = generate_search_patterns(name_pattern, additional_patterns);
 
+    find_process(command_pattern, |process_name, cmd_line| {
+        search_patterns.iter().any(|pattern| {
+            process_name.contains(pattern.as_str()) || cmdline_matches_pattern(cmd_line, pattern)
+        })
+    })
+}
+
+/// Return the first process accepted by `is_match(process_name, cmd_line)`
+/// that also satisfies `command_pattern`.
+fn find_process(
+    command_pattern: Option<&str>,
+    is_match: impl Fn(&str, &str) -> bool,
+) -> Result<Option<ProcessSnapshot>, ProcessError> {
+    let mut system = System::new();
+    // Plain refresh_processes() leaves cmd() empty; matching needs the args
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::OnlyIfNotSet)
+            .with_memory(),
+    );
+
     for (pid, process) in system.processes() {
         let process_name = process.name().to_string_lossy();
         let cmd_line = process
@@ -250,12 +576,7 @@ pub fn find_process_by_name(
             .collect::<Vec<_>>()
             .join(" ");
 
-        // Try each search pattern
-        let name_matches = search_patterns
-            .iter()
-            .any(|pattern| process_name.contains(pattern) || cmd_line.contains(pattern));
-
-        if !name_matches {
+        if !is_match(&process_name, &cmd_line) {
             continue;
         }
 
@@ -269,7 +590,7 @@ pub fn find_process_by_name(
             // so we rely on name matching only (already passed above)
             if cmd_line.is_empty() {
                 debug!(
-                    "find_process_by_name: cmd_line unavailable for PID {}, relying on name match only",
+                    "find_process: cmd_line unavailable for PID {}, relying on name match only",
                     pid
                 );
             }
@@ -280,6 +601,8 @@ pub fn find_process_by_name(
             name: process_name.to_string(),
             status: ProcessStatus::from(process.status()),
             start_time: process.start_time(),
+            cpu_percent: process.cpu_usage(),
+            memory_bytes: process.memory(),
         }));
     }
 
@@ -306,6 +629,80 @@ pub fn find_processes_in_directory(dir: &Path) -> Vec<u32> {
         .collect()
 }
 
+/// Find the newest process inside `dir` matching one of `patterns`.
+///
+/// Fallback for terminals that detach the agent from the PID-capturing wrapper.
+/// Scoping to the worktree keeps same-named agents from other kilds out.
+/// Best-effort like `find_processes_in_directory()`: returns `None` where CWD
+/// is unreadable (macOS SIP).
+pub fn find_process_in_directory(dir: &Path, patterns: &[String]) -> Option<ProcessSnapshot> {
+    if patterns.is_empty() {
+        return None;
+    }
+
+    find_newest_in_directory(dir, |process_name, cmd_line| {
+        patterns.iter().any(|pattern| {
+            process_name_matches(process_name, pattern)
+                || cmdline_matches_pattern(cmd_line, pattern)
+        })
+    })
+}
+
+/// Find the newest process of `agent` inside `dir`.
+///
+/// Like [`find_process_in_directory`], but detection goes through
+/// [`AgentBackend::matches_process`] so backends decide how their processes
+/// are recognised, including shell-wrapped launches.
+pub fn find_agent_process(agent: &dyn AgentBackend, dir: &Path) -> Option<ProcessSnapshot> {
+    let patterns = agent.process_patterns();
+    find_newest_in_directory(dir, |process_name, cmd_line| {
+        patterns
+            .iter()
+            .any(|pattern| process_name_matches(process_name, pattern))
+            || agent.matches_process(cmd_line)
+    })
+}
+
+/// Return the newest process with a CWD inside `dir` accepted by
+/// `is_match(process_name, cmd_line)`.
+fn find_newest_in_directory(
+    dir: &Path,
+    is_match: impl Fn(&str, &str) -> bool,
+) -> Option<ProcessSnapshot> {
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        false,
+        ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::OnlyIfNotSet)
+            .with_cwd(UpdateKind::OnlyIfNotSet)
+            .with_memory(),
+    );
+    system
+        .processes()
+        .values()
+        .filter(|p| p.cwd().is_some_and(|cwd| cwd.starts_with(dir)))
+        .filter(|p| {
+            let process_name = p.name().to_string_lossy();
+            let cmd_line = p
+                .cmd()
+                .iter()
+                .map(|s| s.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" ");
+            is_match(&process_name, &cmd_line)
+        })
+        .max_by_key(|p| p.start_time())
+        .map(|p| ProcessSnapshot {
+            pid: Pid::from_raw(p.pid().as_u32()),
+            name: p.name().to_string_lossy().to_string(),
+            status: ProcessStatus::from(p.status()),
+            start_time: p.start_time(),
+            cpu_percent: p.cpu_usage(),
+            memory_bytes: p.memory(),
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -361,6 +758,126 @@ mod tests {
         let _ = child.wait();
     }
 
+    #[test]
+    fn test_descendant_pids_walks_whole_tree() {
+        let children = HashMap::from([(1, vec![2, 3]), (2, vec![4]), (4, vec![5]), (9, vec![10])]);
+        let mut descendants = descendant_pids(1, &children);
+        descendants.sort();
+        assert_eq!(descendants, vec![2, 3, 4, 5]);
+        assert!(descendant_pids(3, &children).is_empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_stat_ppid_handles_parens_in_name() {
+        assert_eq!(parse_stat_ppid("42 (sleep) S 7 42 42 0 -1"), Some(7));
+        assert_eq!(parse_stat_ppid("42 (a) b) (c) R 13 42 42 0"), Some(13));
+        assert_eq!(parse_stat_ppid("garbage"), None);
+    }
+
+    #[test]
+    fn test_kill_process_tree_with_invalid_pid() {
+        let result = kill_process_tree(Pid::from_raw(999999));
+        assert!(matches!(
+            result,
+            Err(ProcessError::NotFound { pid: 999999 })
+        ));
+    }
+
+    #[test]
+    fn test_kill_process_tree_kills_parent_and_child() {
+        // The shell forks a long-running child, prints its PID, then waits on it
+        let mut parent = Command::new("sh")
+            .arg("-c")
+            .arg("sleep 30 & echo $!; wait")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn test process");
+        let parent_pid = parent.id();
+
+        let mut line = String::new();
+        std::io::BufRead::read_line(
+            &mut std::io::BufReader::new(parent.stdout.take().unwrap()),
+            &mut line,
+        )
+        .unwrap();
+        let child_pid: u32 = line.trim().parse().unwrap();
+        assert_eq!(live_pids(&[parent_pid, child_pid]).len(), 2);
+
+        kill_process_tree_with_grace(parent_pid, Duration::from_secs(2)).unwrap();
+        let _ = parent.wait();
+
+        assert!(live_pids(&[parent_pid, child_pid]).is_empty());
+    }
+
+    #[test]
+    fn test_kill_process_tree_escalates_to_sigkill() {
+        // The parent ignores SIGTERM, so only SIGKILL after the grace period stops it
+        let mut parent = Command::new("sh")
+            .arg("-c")
+            .arg("trap '' TERM; sleep 30 & echo $!; wait")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn test process");
+        let parent_pid = parent.id();
+
+        let mut line = String::new();
+        std::io::BufRead::read_line(
+            &mut std::io::BufReader::new(parent.stdout.take().unwrap()),
+            &mut line,
+        )
+        .unwrap();
+        let child_pid: u32 = line.trim().parse().unwrap();
+
+        kill_process_tree_with_grace(parent_pid, Duration::from_millis(200)).unwrap();
+        let _ = parent.wait();
+
+        assert!(live_pids(&[parent_pid, child_pid]).is_empty());
+    }
+
+    #[test]
+    fn test_prime_cpu_sampling_reports_busy_process_cpu() {
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg("while :; do :; done")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn test process");
+        let pid = child.id();
+        // sysinfo treats a process with no CPU time yet as never sampled
+        std::thread::sleep(std::time::Duration::from_millis(300));
+
+        prime_cpu_sampling(&[pid]);
+        let info = get_process_info(pid).expect("Failed to get process info");
+
+        let _ = child.kill();
+        let _ = child.wait();
+        assert!(info.cpu_percent > 0.0);
+    }
+
+    #[test]
+    fn test_get_process_info_samples_memory_of_busy_process() {
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg("while :; do :; done")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn test process");
+        let pid = child.id();
+        std::thread::sleep(std::time::Duration::from_millis(100));
+
+        let first = get_process_info(pid).expect("Failed to get process info");
+        assert!(first.memory_bytes > 0);
+        assert!(first.cpu_percent >= 0.0);
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
     #[test]
     fn test_find_process_by_name() {
         use std::process::{Command, Stdio};
@@ -462,6 +979,69 @@ mod tests {
         assert!(!command_matches("/usr/bin/vim", "kiro-cli chat"));
     }
 
+    #[test]
+    fn test_cmdline_matches_pattern_absolute_path() {
+        // The kiro-cli report: bare pattern vs absolute, suffixed binary
+        assert!(cmdline_matches_pattern(
+            "/Users/me/.local/bin/kiro-cli-chat chat",
+            "kiro-cli"
+        ));
+        assert!(cmdline_matches_pattern("/usr/local/bin/claude", "claude"));
+        assert!(cmdline_matches_pattern("kiro-cli chat", "kiro-cli"));
+
+        // Pattern only in arguments of an unrelated program does not match
+        assert!(!cmdline_matches_pattern("vim kiro-cli.md", "kiro-cli"));
+        assert!(!cmdline_matches_pattern("grep -r claude .", "claude"));
+    }
+
+    #[test]
+    fn test_cmdline_matches_pattern_wrapper_process() {
+        assert!(cmdline_matches_pattern(
+            "node /usr/local/lib/node_modules/claude-code/cli.js --verbose",
+            "claude-code"
+        ));
+        assert!(cmdline_matches_pattern(
+            "/opt/homebrew/bin/python3 -u /opt/tools/aider/main.py",
+            "aider"
+        ));
+
+        // Partial path component is not enough for wrappers
+        assert!(!cmdline_matches_pattern(
+            "node /srv/app/claude-code-docs/index.js",
+            "claude-code"
+        ));
+    }
+
+    #[test]
+    fn test_cmdline_matches_pattern_empty_inputs() {
+        assert!(!cmdline_matches_pattern("", "claude"));
+        assert!(!cmdline_matches_pattern("claude", ""));
+    }
+
+    #[test]
+    fn test_cmdline_matches_pattern_shell_wrapper() {
+        // Regression: agents launched through `sh -c` must still be detected
+        assert!(cmdline_matches_pattern("sh -c claude --verbose", "claude"));
+        assert!(cmdline_matches_pattern(
+            "/bin/sh -c echo $$ > '/tmp/kild/pids/abc.pid' && exec claude",
+            "claude"
+        ));
+        assert!(cmdline_matches_pattern(
+            "bash -lc cd /repo; exec /usr/local/bin/kiro-cli-chat chat",
+            "kiro-cli"
+        ));
+
+        // Shell wrappers around unrelated commands don't match
+        assert!(!cmdline_matches_pattern("sh -c grep -r claude .", "claude"));
+        assert!(!cmdline_matches_pattern("sh claude-notes.sh", "claude"));
+    }
+
+    #[test]
+    fn test_find_agent_process_not_found() {
+        let agent = crate::agents::backends::OpenCodeBackend;
+        assert!(find_agent_process(&agent, Path::new("/nonexistent/path/xyz")).is_none());
+    }
+
     #[test]
     fn test_command_matches_empty_patterns() {
         // Empty patterns should NOT match anything (security fix)
@@ -535,6 +1115,42 @@ mod tests {
         // No assertion on contents: macOS SIP prevents reading CWD of most processes
     }
 
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_find_process_in_directory_finds_known_process() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path().canonicalize().unwrap();
+        let mut child = Command::new("sleep")
+            .arg("30")
+            .current_dir(&dir)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn test process");
+
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        let found = find_process_in_directory(&dir, &["sleep".to_string()]);
+        let other_pattern = find_process_in_directory(&dir, &["claude".to_string()]);
+
+        let _ = child.kill();
+        let _ = child.wait();
+
+        assert_eq!(found.map(|p| p.pid.as_u32()), Some(child.id()));
+        assert!(other_pattern.is_none());
+    }
+
+    #[test]
+    fn test_find_process_in_directory_no_patterns_or_match() {
+        assert!(find_process_in_directory(&std::env::temp_dir(), &[]).is_none());
+        assert!(
+            find_process_in_directory(
+                std::path::Path::new("/nonexistent/path/xyz"),
+                &["sleep".to_string()]
+            )
+            .is_none()
+        );
+    }
+
     #[test]
     fn test_find_processes_in_directory_nonexistent() {
         let pids = find_processes_in_directory(std::path::Path::new("/nonexistent/path/xyz"));
@@ -11,15 +11,24 @@ use std::time::Duration;
 use tracing::{debug, warn};
 
 use crate::process::errors::ProcessError;
+use kild_paths::KildPaths;
 
 /// Directory name for storing PID files within the kild directory
 const PID_DIR_NAME: &str = "pids";
 
 /// Get the PID file path for a given session ID
 ///
-/// PID files are stored at `~/.kild/pids/<session_id>.pid`
+/// PID files are stored at `~/.kild/pids/<session_id>.pid`, with the id
+/// encoded by `KildPaths::encode_session_id()` so distinct ids never share a file.
 pub fn get_pid_file_path(kild_dir: &Path, session_id: &str) -> PathBuf {
-    // Sanitize session_id to be safe for filenames (replace / with -)
+    let safe_id = KildPaths::encode_session_id(session_id);
+    kild_dir.join(PID_DIR_NAME).join(format!("{}.pid", safe_id))
+}
+
+/// PID file path used before ids were encoded (`/` replaced with `-`).
+///
+/// Only used to clean up files written by older binaries.
+pub(crate) fn legacy_pid_file_path(kild_dir: &Path, session_id: &str) -> PathBuf {
     let safe_id = session_id.replace('/', "-");
     kild_dir.join(PID_DIR_NAME).join(format!("{}.pid", safe_id))
 }
@@ -188,7 +197,24 @@ mod tests {
         let path = get_pid_file_path(kild_dir, "project-id/feature-branch");
         assert_eq!(
             path,
-            PathBuf::from("/home/user/.kild/pids/project-id-feature-branch.pid")
+            PathBuf::from("/home/user/.kild/pids/project-id%2Ffeature-branch.pid")
+        );
+    }
+
+    #[test]
+    fn test_get_pid_file_path_distinct_for_colliding_ids() {
+        let kild_dir = Path::new("/home/user/.kild");
+        assert_ne!(
+            get_pid_file_path(kild_dir, "proj/a-b"),
+            get_pid_file_path(kild_dir, "proj/a/b")
+        );
+        assert_ne!(
+            get_pid_file_path(kild_dir, "proj/a-b"),
+            get_pid_file_path(kild_dir, "proj-a-b")
+        );
+        assert_eq!(
+            legacy_pid_file_path(kild_dir, "proj/a"),
+            PathBuf::from("/home/user/.kild/pids/proj-a.pid")
         );
     }
 
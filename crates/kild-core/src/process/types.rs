@@ -63,12 +63,28 @@ pub struct ProcessSnapshot {
     pub name: String,
     pub status: ProcessStatus,
     pub start_time: u64,
+    /// Instantaneous CPU usage. 0 on the first sample of a PID.
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProcessMetadata {
     pub name: String,
     pub start_time: u64,
+    /// Instantaneous CPU usage at sample time. 0 on the first sample of a PID,
+    /// since sysinfo needs two samples to compute a rate.
+    #[serde(default)]
+    pub cpu_percent: f32,
+    /// Resident memory at sample time.
+    #[serde(default)]
+    pub memory_bytes: u64,
+}
+
+impl ProcessMetadata {
+    pub fn memory_mb(&self) -> u64 {
+        self.memory_bytes / 1_024 / 1_024
+    }
 }
 
 impl From<&ProcessSnapshot> for ProcessMetadata {
@@ -76,6 +92,8 @@ impl From<&ProcessSnapshot> for ProcessMetadata {
         Self {
             name: info.name.clone(),
             start_time: info.start_time,
+            cpu_percent: info.cpu_percent,
+            memory_bytes: info.memory_bytes,
         }
     }
 }
@@ -103,10 +121,14 @@ mod tests {
             name: "claude".to_string(),
             status: ProcessStatus::Running,
             start_time: 1700000000,
+            cpu_percent: 12.5,
+            memory_bytes: 64 * 1_024 * 1_024,
         };
         let metadata: ProcessMetadata = ProcessMetadata::from(&info);
         assert_eq!(metadata.name, "claude");
         assert_eq!(metadata.start_time, 1700000000);
+        assert_eq!(metadata.cpu_percent, 12.5);
+        assert_eq!(metadata.memory_mb(), 64);
     }
 
     #[test]
@@ -116,12 +138,35 @@ mod tests {
             name: "kiro".to_string(),
             status: ProcessStatus::Sleeping,
             start_time: 1700001000,
+            cpu_percent: 0.0,
+            memory_bytes: 0,
         };
         let _metadata: ProcessMetadata = (&info).into();
         assert_eq!(info.name, "kiro");
         assert_eq!(info.pid.as_u32(), 5678);
     }
 
+    #[test]
+    fn test_process_metadata_resource_fields_roundtrip() {
+        let metadata = ProcessMetadata {
+            name: "claude".to_string(),
+            start_time: 1700000000,
+            cpu_percent: 87.5,
+            memory_bytes: 512 * 1_024 * 1_024,
+        };
+        let json = serde_json::to_string(&metadata).unwrap();
+        let parsed: ProcessMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, metadata);
+    }
+
+    #[test]
+    fn test_process_metadata_without_resource_fields_deserializes() {
+        let parsed: ProcessMetadata =
+            serde_json::from_str(r#"{"name":"claude","start_time":1700000000}"#).unwrap();
+        assert_eq!(parsed.cpu_percent, 0.0);
+        assert_eq!(parsed.memory_bytes, 0);
+    }
+
     #[test]
     fn test_pid_from_u32() {
         let pid: Pid = 42u32.into();
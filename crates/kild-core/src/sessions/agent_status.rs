@@ -1,7 +1,10 @@
-use tracing::info;
+use std::path::Path;
+use std::time::Duration;
+
+use tracing::{debug, info, warn};
 
 use crate::sessions::{errors::SessionError, persistence, types::*};
-use kild_config::Config;
+use kild_config::{Config, NotifyConfig};
 
 /// Result of a successful agent status update.
 pub struct AgentStatusResult {
@@ -13,10 +16,12 @@ pub struct AgentStatusResult {
 /// Update agent status for a session via sidecar file.
 ///
 /// Writes `updated_at` to the status sidecar; the health system reads this directly.
+/// With `notify` set, `Waiting`/`Error` raise a desktop notification unless the
+/// same status was already notified inside `notify.throttle_window_secs()`.
 pub fn update_agent_status(
     name: &str,
     status: super::types::AgentStatus,
-    notify: bool,
+    notify: Option<&NotifyConfig>,
 ) -> Result<AgentStatusResult, SessionError> {
     info!(
         event = "core.session.agent_status_update_started",
@@ -49,15 +54,15 @@ pub fn update_agent_status(
         status = %status,
     );
 
-    if crate::notify::should_notify(notify, status) {
-        info!(
-            event = "core.session.agent_status_notify_triggered",
-            branch = %session.branch,
-            status = %status,
-        );
-        let message =
-            crate::notify::format_notification_message(&session.agent, &session.branch, status);
-        crate::notify::send_notification("KILD", &message);
+    crate::events::publish(crate::state::Event::AgentStatusUpdated {
+        branch: session.branch.clone(),
+        status,
+    });
+
+    if let Some(notify_config) = notify
+        && crate::notify::should_notify(true, status)
+    {
+        notify_unless_throttled(&config.sessions_dir(), &session, status, notify_config);
     }
 
     Ok(AgentStatusResult {
@@ -67,6 +72,43 @@ pub fn update_agent_status(
     })
 }
 
+/// Send the status notification unless the session's notification log shows
+/// the same status was notified inside the throttle window.
+fn notify_unless_throttled(
+    sessions_dir: &Path,
+    session: &Session,
+    status: super::types::AgentStatus,
+    notify_config: &NotifyConfig,
+) {
+    let window = Duration::from_secs(notify_config.throttle_window_secs());
+    let now = chrono::Utc::now();
+    let mut log = persistence::read_notification_log(sessions_dir, &session.id);
+    if !log.should_send(status, now, window) {
+        debug!(
+            event = "core.session.agent_status_notify_throttled",
+            branch = %session.branch,
+            status = %status,
+        );
+        return;
+    }
+
+    info!(
+        event = "core.session.agent_status_notify_triggered",
+        branch = %session.branch,
+        status = %status,
+    );
+    crate::notify::send_notification(&session.agent, &session.branch, status);
+
+    log.record(status, now);
+    if let Err(e) = persistence::write_notification_log(sessions_dir, &session.id, &log) {
+        warn!(
+            event = "core.session.notification_log_write_failed",
+            session_id = %session.id,
+            error = %e,
+        );
+    }
+}
+
 /// Read agent status for a session from the sidecar file.
 ///
 /// Returns `None` if no status has been reported yet.
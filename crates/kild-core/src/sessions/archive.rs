@@ -0,0 +1,258 @@
+//! Archive a kild: set it aside without destroying its branch or session state.
+
+use std::path::Path;
+
+use kild_protocol::SessionId;
+use tracing::{info, warn};
+
+use crate::git;
+use crate::sessions::{errors::SessionError, persistence, types::*};
+use kild_config::Config;
+
+/// Mark a stopped kild as archived, hiding it from `kild list`.
+///
+/// With `remove_worktree`, the worktree directory is deleted to reclaim disk
+/// space; the `kild/<branch>` branch and `kild.json` are kept so
+/// [`unarchive_session`] can recreate it. Refuses to remove a worktree with
+/// uncommitted changes.
+///
+/// Returns the archived session.
+pub fn archive_session(
+    session_id: &SessionId,
+    remove_worktree: bool,
+) -> Result<Session, SessionError> {
+    let config = Config::new();
+    archive_session_in(&config.sessions_dir(), session_id, remove_worktree)
+}
+
+fn archive_session_in(
+    sessions_dir: &Path,
+    session_id: &SessionId,
+    remove_worktree: bool,
+) -> Result<Session, SessionError> {
+    info!(
+        event = "core.session.archive_started",
+        session_id = %session_id,
+        remove_worktree = remove_worktree
+    );
+
+    let mut session = load(sessions_dir, session_id)?;
+    let name = session.branch.to_string();
+
+    match session.status {
+        SessionStatus::Active => return Err(SessionError::ArchiveActive { name }),
+        SessionStatus::Archived => return Err(SessionError::AlreadyArchived { name }),
+        SessionStatus::Stopped | SessionStatus::Destroyed => {}
+    }
+
+    if remove_worktree && session.use_main_worktree {
+        warn!(
+            event = "core.session.archive_worktree_skipped",
+            session_id = %session_id,
+            "Kild runs in the main checkout; not removing it"
+        );
+    } else if remove_worktree && session.worktree_path.exists() {
+        if git::has_uncommitted_changes(&session.worktree_path) == Some(true) {
+            return Err(SessionError::ArchiveUncommittedChanges { name });
+        }
+        git::detach_worktree(&session.worktree_path)?;
+    }
+
+    session.status = SessionStatus::Archived;
+    persistence::save_session_to_file(&session, sessions_dir)?;
+
+    info!(
+        event = "core.session.archive_completed",
+        session_id = %session_id,
+        worktree_removed = !session.worktree_path.exists()
+    );
+
+    Ok(session)
+}
+
+/// Return an archived kild to the stopped state.
+///
+/// If its worktree was removed by `archive --remove-worktree`, it is recreated
+/// from the kept branch. That needs the main repository, which is found from
+/// the current directory, so the command must run inside the kild's project.
+pub fn unarchive_session(session_id: &SessionId) -> Result<Session, SessionError> {
+    let config = Config::new();
+    let sessions_dir = config.sessions_dir();
+
+    let session = load(&sessions_dir, session_id)?;
+    let repo_root = if session.worktree_path.exists() {
+        None
+    } else {
+        git::detect_project()
+            .ok()
+            .filter(|project| *project.id == *session.project_id)
+            .map(|project| project.path)
+    };
+
+    unarchive_session_in(&sessions_dir, session_id, repo_root.as_deref())
+}
+
+fn unarchive_session_in(
+    sessions_dir: &Path,
+    session_id: &SessionId,
+    repo_root: Option<&Path>,
+) -> Result<Session, SessionError> {
+    info!(
+        event = "core.session.unarchive_started",
+        session_id = %session_id
+    );
+
+    let mut session = load(sessions_dir, session_id)?;
+    let name = session.branch.to_string();
+
+    if session.status != SessionStatus::Archived {
+        return Err(SessionError::NotArchived { name });
+    }
+
+    if !session.worktree_path.exists() {
+        let repo_root =
+            repo_root.ok_or_else(|| SessionError::UnarchiveRepoNotFound { name: name.clone() })?;
+        git::restore_worktree(repo_root, &session.worktree_path, &session.branch)?;
+    }
+
+    session.status = SessionStatus::Stopped;
+    persistence::save_session_to_file(&session, sessions_dir)?;
+
+    info!(
+        event = "core.session.unarchive_completed",
+        session_id = %session_id
+    );
+
+    Ok(session)
+}
+
+fn load(sessions_dir: &Path, session_id: &SessionId) -> Result<Session, SessionError> {
+    persistence::find_session_by_id(sessions_dir, session_id)?.ok_or_else(|| {
+        SessionError::NotFound {
+            name: session_id.to_string(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    struct Fixture {
+        _temp: TempDir,
+        sessions_dir: PathBuf,
+        repo: PathBuf,
+        session: Session,
+    }
+
+    /// A real repo with a stopped kild `feature` on `kild/feature`.
+    fn setup() -> Fixture {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git::test_support::init_repo_with_commit(&repo).unwrap();
+        git::test_support::create_branch(&repo, "kild/feature").unwrap();
+
+        let worktree = temp.path().join("worktrees").join("proj").join("feature");
+        std::fs::create_dir_all(worktree.parent().unwrap()).unwrap();
+        git::test_support::create_worktree_for_branch(
+            &repo,
+            "kild-feature",
+            &worktree,
+            "kild/feature",
+        )
+        .unwrap();
+
+        let sessions_dir = temp.path().join("sessions");
+        let mut session = Session::new_for_test("feature", worktree.canonicalize().unwrap());
+        session.id = SessionId::new("proj/feature");
+        session.project_id = "proj".into();
+        session.status = SessionStatus::Stopped;
+        persistence::save_session_to_file(&session, &sessions_dir).unwrap();
+
+        Fixture {
+            _temp: temp,
+            sessions_dir,
+            repo,
+            session,
+        }
+    }
+
+    fn stored_status(fx: &Fixture) -> SessionStatus {
+        load(&fx.sessions_dir, &fx.session.id).unwrap().status
+    }
+
+    #[test]
+    fn test_archive_unarchive_round_trip_keeps_worktree() {
+        let fx = setup();
+
+        let archived = archive_session_in(&fx.sessions_dir, &fx.session.id, false).unwrap();
+        assert_eq!(archived.status, SessionStatus::Archived);
+        assert_eq!(stored_status(&fx), SessionStatus::Archived);
+        assert!(fx.session.worktree_path.exists());
+
+        let restored = unarchive_session_in(&fx.sessions_dir, &fx.session.id, None).unwrap();
+        assert_eq!(restored.status, SessionStatus::Stopped);
+        assert_eq!(stored_status(&fx), SessionStatus::Stopped);
+    }
+
+    #[test]
+    fn test_archive_remove_worktree_keeps_session_and_branch() {
+        let fx = setup();
+
+        archive_session_in(&fx.sessions_dir, &fx.session.id, true).unwrap();
+
+        assert!(!fx.session.worktree_path.exists());
+        assert_eq!(stored_status(&fx), SessionStatus::Archived);
+        assert!(
+            git::list_local_branch_names(&fx.repo)
+                .unwrap()
+                .iter()
+                .any(|b| b == "kild/feature")
+        );
+
+        // Without a repository to restore from, unarchive refuses and stays archived
+        let err = unarchive_session_in(&fx.sessions_dir, &fx.session.id, None).unwrap_err();
+        assert!(matches!(err, SessionError::UnarchiveRepoNotFound { .. }));
+        assert_eq!(stored_status(&fx), SessionStatus::Archived);
+
+        unarchive_session_in(&fx.sessions_dir, &fx.session.id, Some(&fx.repo)).unwrap();
+        assert!(fx.session.worktree_path.join(".git").exists());
+        assert_eq!(stored_status(&fx), SessionStatus::Stopped);
+    }
+
+    #[test]
+    fn test_archive_remove_worktree_refuses_uncommitted_changes() {
+        let fx = setup();
+        std::fs::write(fx.session.worktree_path.join("wip.txt"), "wip").unwrap();
+
+        let err = archive_session_in(&fx.sessions_dir, &fx.session.id, true).unwrap_err();
+
+        assert!(matches!(
+            err,
+            SessionError::ArchiveUncommittedChanges { .. }
+        ));
+        assert!(fx.session.worktree_path.join("wip.txt").exists());
+        assert_eq!(stored_status(&fx), SessionStatus::Stopped);
+    }
+
+    #[test]
+    fn test_archive_rejects_invalid_transitions() {
+        let fx = setup();
+
+        let err = unarchive_session_in(&fx.sessions_dir, &fx.session.id, None).unwrap_err();
+        assert!(matches!(err, SessionError::NotArchived { .. }));
+
+        archive_session_in(&fx.sessions_dir, &fx.session.id, false).unwrap();
+        let err = archive_session_in(&fx.sessions_dir, &fx.session.id, false).unwrap_err();
+        assert!(matches!(err, SessionError::AlreadyArchived { .. }));
+
+        let mut active = fx.session.clone();
+        active.status = SessionStatus::Active;
+        persistence::save_session_to_file(&active, &fx.sessions_dir).unwrap();
+        let err = archive_session_in(&fx.sessions_dir, &fx.session.id, false).unwrap_err();
+        assert!(matches!(err, SessionError::ArchiveActive { .. }));
+    }
+}
@@ -0,0 +1,221 @@
+//! All-or-nothing creation of several kilds at once.
+
+use kild_config::KildConfig;
+use tracing::{error, info, warn};
+
+use super::create::create_session;
+use super::destroy::destroy_session;
+use super::errors::{FleetError, SessionError};
+use super::types::{CreateSessionRequest, Session};
+
+/// Create every kild in `requests`, or none of them.
+///
+/// Sessions are created in order. If one fails, the ones already created are
+/// force-destroyed in reverse order and the error names the failing request.
+/// Rollback is best-effort: kilds that couldn't be destroyed are listed in
+/// the error so they can be cleaned up by hand.
+pub fn create_fleet(
+    requests: Vec<CreateSessionRequest>,
+    kild_config: &KildConfig,
+) -> Result<Vec<Session>, FleetError> {
+    create_fleet_with(
+        requests,
+        |request| create_session(request, kild_config),
+        |session| destroy_session(&session.branch, true),
+    )
+}
+
+fn create_fleet_with(
+    requests: Vec<CreateSessionRequest>,
+    mut create: impl FnMut(CreateSessionRequest) -> Result<Session, SessionError>,
+    mut destroy: impl FnMut(&Session) -> Result<(), SessionError>,
+) -> Result<Vec<Session>, FleetError> {
+    let total = requests.len();
+    info!(event = "core.session.fleet_create_started", count = total);
+
+    let mut created: Vec<Session> = Vec::with_capacity(total);
+    for (index, request) in requests.into_iter().enumerate() {
+        let branch = request.branch.to_string();
+        let source = match create(request) {
+            Ok(session) => {
+                created.push(session);
+                continue;
+            }
+            Err(e) => e,
+        };
+
+        error!(
+            event = "core.session.fleet_create_failed",
+            branch = branch,
+            position = index + 1,
+            total = total,
+            error = %source
+        );
+
+        let mut rolled_back = Vec::new();
+        let mut left_behind = Vec::new();
+        for session in created.iter().rev() {
+            match destroy(session) {
+                Ok(()) => rolled_back.push(session.branch.to_string()),
+                Err(e) => {
+                    warn!(
+                        event = "core.session.fleet_rollback_failed",
+                        branch = %session.branch,
+                        error = %e
+                    );
+                    left_behind.push(session.branch.to_string());
+                }
+            }
+        }
+
+        info!(
+            event = "core.session.fleet_rollback_completed",
+            rolled_back = rolled_back.len(),
+            left_behind = left_behind.len()
+        );
+
+        return Err(FleetError::CreateFailed {
+            branch,
+            position: index + 1,
+            total,
+            source: Box::new(source),
+            rolled_back,
+            left_behind,
+        });
+    }
+
+    info!(
+        event = "core.session.fleet_create_completed",
+        count = created.len()
+    );
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashSet;
+
+    use super::*;
+    use kild_protocol::AgentMode;
+
+    fn request(branch: &str) -> CreateSessionRequest {
+        CreateSessionRequest::new(branch.to_string(), AgentMode::BareShell, None)
+    }
+
+    fn fake_session(branch: &str) -> Session {
+        let mut session = Session::new_for_test(branch, std::env::temp_dir().join(branch));
+        session.agent = "shell".to_string();
+        session
+    }
+
+    /// Fake store: creation fails for branches that already exist.
+    struct FakeStore {
+        existing: RefCell<HashSet<String>>,
+        destroyed: RefCell<Vec<String>>,
+        fail_destroy: Option<&'static str>,
+    }
+
+    impl FakeStore {
+        fn new(existing: &[&str]) -> Self {
+            Self {
+                existing: RefCell::new(existing.iter().map(|b| b.to_string()).collect()),
+                destroyed: RefCell::new(Vec::new()),
+                fail_destroy: None,
+            }
+        }
+
+        fn run(&self, branches: &[&str]) -> Result<Vec<Session>, FleetError> {
+            create_fleet_with(
+                branches.iter().map(|b| request(b)).collect(),
+                |request| {
+                    let branch = request.branch.to_string();
+                    if !self.existing.borrow_mut().insert(branch.clone()) {
+                        return Err(SessionError::AlreadyExists { name: branch });
+                    }
+                    Ok(fake_session(&branch))
+                },
+                |session| {
+                    let branch = session.branch.to_string();
+                    if self.fail_destroy == Some(branch.as_str()) {
+                        return Err(SessionError::NotFound { name: branch });
+                    }
+                    self.existing.borrow_mut().remove(&branch);
+                    self.destroyed.borrow_mut().push(branch);
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn test_create_fleet_all_succeed() {
+        let store = FakeStore::new(&[]);
+        let sessions = store.run(&["a", "b", "c"]).unwrap();
+
+        let branches: Vec<_> = sessions.iter().map(|s| s.branch.to_string()).collect();
+        assert_eq!(branches, vec!["a", "b", "c"]);
+        assert!(store.destroyed.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_create_fleet_second_failure_rolls_back_first() {
+        // "b" already exists, so the second of three creations fails
+        let store = FakeStore::new(&["b"]);
+        let err = store.run(&["a", "b", "c"]).unwrap_err();
+
+        let FleetError::CreateFailed {
+            branch,
+            position,
+            total,
+            source,
+            rolled_back,
+            left_behind,
+        } = &err;
+        assert_eq!(branch, "b");
+        assert_eq!((*position, *total), (2, 3));
+        assert!(matches!(**source, SessionError::AlreadyExists { .. }));
+        assert_eq!(rolled_back, &vec!["a".to_string()]);
+        assert!(left_behind.is_empty());
+
+        // "a" was destroyed, "c" was never attempted, the pre-existing "b" is untouched
+        assert_eq!(*store.destroyed.borrow(), vec!["a".to_string()]);
+        let existing = store.existing.borrow();
+        assert!(!existing.contains("a"));
+        assert!(!existing.contains("c"));
+        assert!(existing.contains("b"));
+
+        assert!(err.to_string().contains("'b' (2 of 3)"));
+    }
+
+    #[test]
+    fn test_create_fleet_rolls_back_in_reverse_order() {
+        let store = FakeStore::new(&["c"]);
+        let err = store.run(&["a", "b", "c"]).unwrap_err();
+
+        let FleetError::CreateFailed { rolled_back, .. } = &err;
+        assert_eq!(rolled_back, &vec!["b".to_string(), "a".to_string()]);
+    }
+
+    #[test]
+    fn test_create_fleet_reports_kilds_left_behind() {
+        let mut store = FakeStore::new(&["c"]);
+        store.fail_destroy = Some("a");
+        let err = store.run(&["a", "b", "c"]).unwrap_err();
+
+        let FleetError::CreateFailed {
+            rolled_back,
+            left_behind,
+            ..
+        } = &err;
+        assert_eq!(rolled_back, &vec!["b".to_string()]);
+        assert_eq!(left_behind, &vec!["a".to_string()]);
+        assert!(err.to_string().contains("left behind: a"));
+    }
+
+    #[test]
+    fn test_create_fleet_empty_is_ok() {
+        let store = FakeStore::new(&[]);
+        assert!(store.run(&[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,427 @@
+//! Portable session bundles.
+//!
+//! A bundle carries a kild's metadata — `kild.json` plus the agent status and
+//! PR sidecars — as one JSON document so it can be moved to another machine.
+//! Worktree contents are not included; recreating the worktree is left to the
+//! user.
+
+use std::path::{Component, Path, PathBuf};
+
+use kild_config::Config;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::forge::types::PullRequest;
+use crate::sessions::validation::{validate_branch_name, validate_session_structure};
+use crate::sessions::{errors::SessionError, persistence, types::*};
+
+/// Bundle format version written by this release.
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// A kild's session files serialized as one document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionBundle {
+    pub version: u32,
+    pub session: Session,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_status: Option<AgentStatusRecord>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_info: Option<PullRequest>,
+}
+
+/// Export a session (by branch name or session id) as a bundle.
+pub fn export_session(name: &str) -> Result<SessionBundle, SessionError> {
+    let config = Config::new();
+    export_from(&config.sessions_dir(), name)
+}
+
+/// Recreate a session's files from a bundle.
+///
+/// The imported session is marked stopped with no tracked agents: process
+/// IDs from another machine mean nothing here. Its worktree path is
+/// rewritten to where KILD would create it locally, so a bundle can never
+/// point `destroy` at a directory outside the worktrees base. The path may
+/// not exist until the user recreates the worktree.
+///
+/// # Errors
+///
+/// - `ImportConflict` if a session with the same id exists and `overwrite`
+///   is false.
+/// - `AlreadyExists` if a different session in the same project already uses
+///   the bundle's branch name.
+/// - `InvalidStructure` if the bundle comes from a newer KILD, is missing
+///   required fields, has a session id that isn't a safe path, or has a
+///   worktree path no project name can be taken from.
+/// - `InvalidName` if the bundle's branch name is invalid.
+pub fn import_session(bundle: SessionBundle, overwrite: bool) -> Result<Session, SessionError> {
+    let config = Config::new();
+    import_into(&config.sessions_dir(), config.kild_dir(), bundle, overwrite)
+}
+
+fn export_from(sessions_dir: &Path, name: &str) -> Result<SessionBundle, SessionError> {
+    let session = match persistence::find_session_by_name(sessions_dir, name)? {
+        Some(session) => session,
+        None => persistence::find_session_by_id(sessions_dir, name)?.ok_or_else(|| {
+            SessionError::NotFound {
+                name: name.to_string(),
+            }
+        })?,
+    };
+
+    let agent_status = persistence::read_agent_status(sessions_dir, &session.id);
+    let pr_info = persistence::read_pr_info(sessions_dir, &session.id);
+
+    info!(
+        event = "core.session.export_completed",
+        session_id = %session.id,
+        branch = %session.branch,
+        has_agent_status = agent_status.is_some(),
+        has_pr_info = pr_info.is_some()
+    );
+
+    Ok(SessionBundle {
+        version: BUNDLE_VERSION,
+        session,
+        agent_status,
+        pr_info,
+    })
+}
+
+fn import_into(
+    sessions_dir: &Path,
+    kild_dir: &Path,
+    bundle: SessionBundle,
+    overwrite: bool,
+) -> Result<Session, SessionError> {
+    if bundle.version > BUNDLE_VERSION {
+        return Err(SessionError::InvalidStructure {
+            field: format!(
+                "bundle version {} is newer than supported ({})",
+                bundle.version, BUNDLE_VERSION
+            ),
+        });
+    }
+
+    let SessionBundle {
+        mut session,
+        agent_status,
+        pr_info,
+        ..
+    } = bundle;
+
+    // The bundle is untrusted input: its id names a directory under
+    // `sessions_dir`, so reject anything that could escape it
+    validate_session_structure(&session)?;
+    validate_branch_name(&session.branch)?;
+    if session
+        .id
+        .split('/')
+        .any(|segment| segment.is_empty() || segment == "." || segment == "..")
+    {
+        return Err(SessionError::InvalidStructure {
+            field: format!("session id '{}' is not a valid path", session.id),
+        });
+    }
+
+    let worktree_path = local_worktree_path(kild_dir, &session)?;
+
+    persistence::ensure_sessions_directory(sessions_dir)?;
+
+    let existing = persistence::find_session_by_id(sessions_dir, &session.id)?;
+    if existing.is_some() && !overwrite {
+        return Err(SessionError::ImportConflict {
+            id: session.id.to_string(),
+        });
+    }
+
+    let (sessions, _) = persistence::load_sessions_from_files(sessions_dir)?;
+    if sessions.iter().any(|s| {
+        s.id != session.id && s.project_id == session.project_id && s.branch == session.branch
+    }) {
+        return Err(SessionError::AlreadyExists {
+            name: session.branch.to_string(),
+        });
+    }
+
+    if existing.is_some() {
+        persistence::remove_session_file(sessions_dir, &session.id)?;
+    }
+
+    session.status = SessionStatus::Stopped;
+    session.clear_agents();
+    session.worktree_path = worktree_path;
+    // A main-worktree session runs in the project root, which destroy must
+    // never remove; the rewritten path is a regular kild worktree instead
+    session.use_main_worktree = false;
+
+    persistence::save_session_to_file(&session, sessions_dir)?;
+    if let Some(status) = &agent_status {
+        persistence::write_agent_status(sessions_dir, &session.id, status)?;
+    }
+    if let Some(pr) = &pr_info {
+        persistence::write_pr_info(sessions_dir, &session.id, pr)?;
+    }
+
+    info!(
+        event = "core.session.import_completed",
+        session_id = %session.id,
+        branch = %session.branch,
+        overwritten = existing.is_some(),
+        worktree_exists = session.worktree_path.exists()
+    );
+
+    Ok(session)
+}
+
+/// Where KILD would create the imported session's worktree on this machine.
+///
+/// The project name is taken from the bundle's path: the worktree's parent
+/// for regular kilds (`<base>/worktrees/<project>/<branch>`), the project root
+/// itself for main-worktree sessions.
+fn local_worktree_path(kild_dir: &Path, session: &Session) -> Result<PathBuf, SessionError> {
+    let project_dir = if session.use_main_worktree {
+        Some(session.worktree_path.as_path())
+    } else {
+        session.worktree_path.parent()
+    };
+    let project_name = project_dir
+        .and_then(|dir| dir.components().next_back())
+        .and_then(|component| match component {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .ok_or_else(|| SessionError::InvalidStructure {
+            field: format!(
+                "worktree path '{}' has no project name",
+                session.worktree_path.display()
+            ),
+        })?;
+
+    Ok(crate::git::calculate_worktree_path(
+        kild_dir,
+        project_name,
+        &session.branch,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::types::{CiStatus, PrState, ReviewStatus};
+
+    fn sample_bundle(dir: &Path) -> SessionBundle {
+        let mut session = Session::new_for_test("feature-auth", dir.join("worktree"));
+        session.note = Some("JWT work".to_string());
+        session.base_branch = Some("develop".to_string());
+        persistence::save_session_to_file(&session, dir).unwrap();
+        persistence::write_agent_status(
+            dir,
+            &session.id,
+            &AgentStatusRecord {
+                status: AgentStatus::Idle,
+                updated_at: "2026-02-05T12:00:00Z".to_string(),
+            },
+        )
+        .unwrap();
+        persistence::write_pr_info(
+            dir,
+            &session.id,
+            &PullRequest {
+                number: 42,
+                url: "https://github.com/org/repo/pull/42".to_string(),
+                state: PrState::Open,
+                ci_status: CiStatus::Passing,
+                ci_summary: None,
+                review_status: ReviewStatus::Approved,
+                review_summary: None,
+                updated_at: "2026-02-05T12:00:00Z".to_string(),
+            },
+        )
+        .unwrap();
+        export_from(dir, "feature-auth").unwrap()
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let source = tempfile::TempDir::new().unwrap();
+        let target = tempfile::TempDir::new().unwrap();
+
+        let bundle = sample_bundle(source.path());
+        assert_eq!(bundle.version, BUNDLE_VERSION);
+        assert!(bundle.agent_status.is_some());
+        assert!(bundle.pr_info.is_some());
+
+        // Through JSON, as it travels between machines
+        let json = serde_json::to_string(&bundle).unwrap();
+        let parsed: SessionBundle = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, bundle);
+
+        let imported = import_into(target.path(), target.path(), parsed, false).unwrap();
+        assert_eq!(imported.status, SessionStatus::Stopped);
+
+        let exported_again = export_from(target.path(), "feature-auth").unwrap();
+        assert_eq!(exported_again.session, imported);
+        assert_eq!(exported_again.session.id, bundle.session.id);
+        assert_eq!(exported_again.session.note.as_deref(), Some("JWT work"));
+        assert_eq!(
+            exported_again.session.base_branch.as_deref(),
+            Some("develop")
+        );
+        assert_eq!(exported_again.agent_status, bundle.agent_status);
+        assert_eq!(exported_again.pr_info, bundle.pr_info);
+    }
+
+    #[test]
+    fn test_export_by_session_id() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let bundle = sample_bundle(dir.path());
+        let by_id = export_from(dir.path(), &bundle.session.id).unwrap();
+        assert_eq!(by_id, bundle);
+    }
+
+    #[test]
+    fn test_export_missing_session_is_not_found() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(matches!(
+            export_from(dir.path(), "nope"),
+            Err(SessionError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_import_existing_id_requires_overwrite() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let bundle = sample_bundle(dir.path());
+
+        let err = import_into(dir.path(), dir.path(), bundle.clone(), false).unwrap_err();
+        assert!(matches!(err, SessionError::ImportConflict { .. }));
+
+        let mut changed = bundle;
+        changed.session.note = Some("replaced".to_string());
+        changed.pr_info = None;
+        import_into(dir.path(), dir.path(), changed, true).unwrap();
+
+        let after = export_from(dir.path(), "feature-auth").unwrap();
+        assert_eq!(after.session.note.as_deref(), Some("replaced"));
+        // Overwrite replaces the whole session, sidecars included
+        assert!(after.pr_info.is_none());
+    }
+
+    #[test]
+    fn test_import_rejects_branch_taken_by_other_session() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut bundle = sample_bundle(dir.path());
+        bundle.session.id = "test-other-id".into();
+
+        let err = import_into(dir.path(), dir.path(), bundle, true).unwrap_err();
+        assert!(matches!(err, SessionError::AlreadyExists { .. }));
+    }
+
+    #[test]
+    fn test_import_rejects_newer_bundle_version() {
+        let source = tempfile::TempDir::new().unwrap();
+        let target = tempfile::TempDir::new().unwrap();
+        let mut bundle = sample_bundle(source.path());
+        bundle.version = BUNDLE_VERSION + 1;
+
+        assert!(matches!(
+            import_into(target.path(), target.path(), bundle, false),
+            Err(SessionError::InvalidStructure { .. })
+        ));
+    }
+
+    #[test]
+    fn test_import_rejects_traversal_session_id() {
+        let source = tempfile::TempDir::new().unwrap();
+        let target = tempfile::TempDir::new().unwrap();
+        let sessions_dir = target.path().join("sessions");
+
+        for id in ["..", ".", "proj/..", "../escape"] {
+            let mut bundle = sample_bundle(source.path());
+            bundle.session.id = id.into();
+
+            assert!(
+                matches!(
+                    import_into(&sessions_dir, target.path(), bundle, true),
+                    Err(SessionError::InvalidStructure { .. })
+                ),
+                "id {id:?} should be rejected"
+            );
+        }
+        // Nothing was written next to (or instead of) the sessions directory
+        assert!(!target.path().join("kild.json").exists());
+        assert!(!sessions_dir.join("kild.json").exists());
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_branch_name() {
+        let source = tempfile::TempDir::new().unwrap();
+        let target = tempfile::TempDir::new().unwrap();
+        let mut bundle = sample_bundle(source.path());
+        bundle.session.branch = "../main".into();
+
+        assert!(matches!(
+            import_into(target.path(), target.path(), bundle, false),
+            Err(SessionError::InvalidName)
+        ));
+    }
+
+    #[test]
+    fn test_import_rejects_missing_required_fields() {
+        let source = tempfile::TempDir::new().unwrap();
+        let target = tempfile::TempDir::new().unwrap();
+        let mut bundle = sample_bundle(source.path());
+        bundle.session.project_id = "".into();
+
+        assert!(matches!(
+            import_into(target.path(), target.path(), bundle, false),
+            Err(SessionError::InvalidStructure { .. })
+        ));
+    }
+
+    #[test]
+    fn test_import_rewrites_worktree_path_outside_worktrees_base() {
+        let source = tempfile::TempDir::new().unwrap();
+        let target = tempfile::TempDir::new().unwrap();
+        let victim = tempfile::TempDir::new().unwrap();
+        let mut bundle = sample_bundle(source.path());
+        bundle.session.worktree_path = victim.path().join("important");
+        bundle.session.use_main_worktree = true;
+
+        let imported = import_into(target.path(), target.path(), bundle, false).unwrap();
+
+        assert!(!imported.use_main_worktree);
+        assert!(
+            imported
+                .worktree_path
+                .starts_with(target.path().join("worktrees")),
+            "worktree path {} escaped the worktrees base",
+            imported.worktree_path.display()
+        );
+        assert_eq!(
+            imported.worktree_path,
+            crate::git::calculate_worktree_path(target.path(), "important", "feature-auth")
+        );
+        let saved = export_from(target.path(), "feature-auth").unwrap();
+        assert_eq!(saved.session.worktree_path, imported.worktree_path);
+    }
+
+    #[test]
+    fn test_import_rejects_worktree_path_without_project_name() {
+        let source = tempfile::TempDir::new().unwrap();
+        let target = tempfile::TempDir::new().unwrap();
+
+        for path in ["/victim", "relative"] {
+            let mut bundle = sample_bundle(source.path());
+            bundle.session.worktree_path = PathBuf::from(path);
+
+            assert!(
+                matches!(
+                    import_into(target.path(), target.path(), bundle, false),
+                    Err(SessionError::InvalidStructure { .. })
+                ),
+                "worktree path {path:?} should be rejected"
+            );
+        }
+    }
+}
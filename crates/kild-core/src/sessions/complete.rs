@@ -22,10 +22,16 @@ use kild_config::Config;
 /// - If PR is already merged: delete remote branch, destroy session
 /// - If PR is not merged: just destroy session, preserve remote
 ///
+/// With `--draft`:
+/// - If no PR exists: open one as a draft, destroy session, preserve remote
+/// - Otherwise behaves like `--no-merge`
+///
 /// With `--dry-run`:
 /// - Walk through all checks and report what would happen, without mutating.
 pub fn complete_session(request: &CompleteRequest) -> Result<CompleteResult, SessionError> {
     let name = &request.name;
+    let span = crate::logging::session_span("complete", name).entered();
+
     info!(
         event = "core.session.complete_started",
         name = name,
@@ -34,6 +40,7 @@ pub fn complete_session(request: &CompleteRequest) -> Result<CompleteResult, Ses
         force = request.force,
         dry_run = request.dry_run,
         skip_ci = request.skip_ci,
+        draft = request.draft,
     );
 
     let config = Config::new();
@@ -47,10 +54,26 @@ pub fn complete_session(request: &CompleteRequest) -> Result<CompleteResult, Ses
             }
         })?;
 
+    crate::logging::record_session(&span, &session.id, &session.agent);
+
     let kild_branch = git::kild_branch_name(name);
 
-    // 2. Check uncommitted changes
+    // 2. Check uncommitted changes and stashes
     let safety_info = super::destroy::get_destroy_safety_info(name)?;
+    if safety_info.stash_count > 0
+        && !safety_info.git_status.has_uncommitted_changes
+        && !request.force
+    {
+        error!(
+            event = "core.session.complete_blocked",
+            name = name,
+            reason = "stashes"
+        );
+        return Err(SessionError::StashesPresent {
+            name: name.to_string(),
+            count: safety_info.stash_count,
+        });
+    }
     if safety_info.should_block() && !request.force {
         error!(
             event = "core.session.complete_blocked",
@@ -88,11 +111,21 @@ pub fn complete_session(request: &CompleteRequest) -> Result<CompleteResult, Ses
         })?;
 
     // 4. --no-merge path uses check_pr_exists + is_pr_merged (lightweight checks)
-    if request.no_merge {
+    if request.no_merge || request.draft {
         match forge_backend.check_pr_exists(&session.worktree_path, &kild_branch) {
             PrCheckResult::Exists => {
                 debug!(event = "core.session.complete_pr_exists", branch = name);
             }
+            PrCheckResult::NotFound if request.draft => {
+                return complete_with_draft_pr(
+                    name,
+                    &session.worktree_path,
+                    &kild_branch,
+                    forge_backend,
+                    request.force,
+                    request.dry_run,
+                );
+            }
             PrCheckResult::NotFound => {
                 error!(
                     event = "core.session.complete_no_pr",
@@ -355,6 +388,51 @@ fn complete_no_merge(
     }
 }
 
+/// --draft path when no PR exists: open a draft PR, then cleanup.
+///
+/// The remote branch is preserved since the PR still needs it.
+fn complete_with_draft_pr(
+    name: &str,
+    worktree_path: &Path,
+    kild_branch: &str,
+    forge_backend: &dyn crate::forge::ForgeBackend,
+    force: bool,
+    dry_run: bool,
+) -> Result<CompleteResult, SessionError> {
+    if dry_run {
+        return Ok(CompleteResult::DryRun {
+            steps: vec![
+                format!("Open draft PR for '{}'", kild_branch),
+                "Destroy worktree and session (remote branch preserved)".to_string(),
+            ],
+        });
+    }
+
+    let body = prefilled_pr_body(worktree_path);
+    let url = forge_backend
+        .create_pr(worktree_path, kild_branch, true, body.as_deref())
+        .map_err(|e| {
+            error!(
+                event = "core.session.complete_draft_failed",
+                name = name,
+                error = %e
+            );
+            SessionError::PrCreateFailed {
+                name: name.to_string(),
+                message: e.to_string(),
+            }
+        })?;
+
+    super::destroy::destroy_session(name, force)?;
+    info!(
+        event = "core.session.complete_completed",
+        name = name,
+        outcome = "draft_opened",
+        url = %url
+    );
+    Ok(CompleteResult::DraftOpened { url })
+}
+
 /// Attempt to delete a remote branch. Returns true on success, false on failure.
 fn try_delete_remote(worktree_path: &Path, kild_branch: &str) -> bool {
     match crate::git::cli::delete_remote_branch(worktree_path, "origin", kild_branch) {
@@ -417,6 +495,34 @@ pub fn fetch_pr_info(
         .flatten()
 }
 
+/// Open a PR via the forge backend and return its URL.
+///
+/// Fails with `ForgeError::NoForgeAvailable` if no usable forge is detected.
+pub fn create_pr(
+    worktree_path: &Path,
+    branch: &str,
+    draft: bool,
+) -> Result<String, crate::forge::ForgeError> {
+    let forge_override = load_forge_override();
+    let backend = crate::forge::get_forge_backend(worktree_path, forge_override)
+        .ok_or(crate::forge::ForgeError::NoForgeAvailable)?;
+
+    let body = prefilled_pr_body(worktree_path);
+    backend.create_pr(worktree_path, branch, draft, body.as_deref())
+}
+
+/// PR body from the worktree's PR template plus the branch's commits.
+///
+/// `None` when the repo has no template, leaving the body to the forge CLI.
+fn prefilled_pr_body(worktree_path: &Path) -> Option<String> {
+    let template = crate::forge::find_pr_template(worktree_path)?;
+    let base_branch = kild_config::KildConfig::load_hierarchy()
+        .map(|c| c.git.base_branch().to_string())
+        .unwrap_or_else(|_| "main".to_string());
+    let commits = crate::git::health::commit_summaries_since_base(worktree_path, &base_branch);
+    crate::forge::compose_pr_body(Some(&template), &commits)
+}
+
 /// Read PR info for a session from the sidecar file.
 ///
 /// Returns `None` if no PR info has been cached yet.
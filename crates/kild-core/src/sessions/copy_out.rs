@@ -0,0 +1,238 @@
+//! Copy selected files from a kild's worktree back into the main checkout.
+
+use std::path::{Component, Path, PathBuf};
+
+use kild_config::Config;
+use kild_protocol::SessionId;
+use tracing::{info, warn};
+
+use crate::files::operations::copy_file_safely;
+use crate::files::types::CopyOptions;
+use crate::sessions::{errors::SessionError, persistence};
+
+/// Copy `paths` (relative to the kild's worktree) into `dest_root`.
+///
+/// Every path is checked before anything is written: paths must be relative,
+/// stay inside the worktree, and name existing files. A destination file with
+/// uncommitted changes (or untracked in git) is never overwritten unless
+/// `force` is set, so local edits in the main checkout can't be lost silently.
+///
+/// Returns the destination paths that were written.
+pub fn copy_out(
+    session_id: &SessionId,
+    paths: &[PathBuf],
+    dest_root: &Path,
+    force: bool,
+) -> Result<Vec<PathBuf>, SessionError> {
+    let config = Config::new();
+    let session =
+        persistence::find_session_by_id(&config.sessions_dir(), session_id)?.ok_or_else(|| {
+            SessionError::NotFound {
+                name: session_id.to_string(),
+            }
+        })?;
+
+    if !session.worktree_path.exists() {
+        return Err(SessionError::WorktreeNotFound {
+            path: session.worktree_path,
+        });
+    }
+
+    copy_files_out(&session.worktree_path, paths, dest_root, force)
+}
+
+fn copy_files_out(
+    worktree: &Path,
+    paths: &[PathBuf],
+    dest_root: &Path,
+    force: bool,
+) -> Result<Vec<PathBuf>, SessionError> {
+    info!(
+        event = "core.session.copy_out_started",
+        worktree = %worktree.display(),
+        dest = %dest_root.display(),
+        files = paths.len(),
+        force = force
+    );
+
+    for path in paths {
+        validate_copy_path(worktree, path)?;
+        let dest = dest_root.join(path);
+        if !force && dest.exists() && has_local_changes(dest_root, path) {
+            warn!(
+                event = "core.session.copy_out_refused",
+                path = %path.display()
+            );
+            return Err(SessionError::CopyOutWouldOverwrite { path: path.clone() });
+        }
+    }
+
+    let options = CopyOptions {
+        max_file_size: None,
+    };
+    let mut copied = Vec::with_capacity(paths.len());
+    for path in paths {
+        let dest = dest_root.join(path);
+        copy_file_safely(&worktree.join(path), &dest, &options).map_err(|e| {
+            SessionError::CopyOutFailed {
+                path: path.clone(),
+                message: e.to_string(),
+            }
+        })?;
+        copied.push(dest);
+    }
+
+    info!(
+        event = "core.session.copy_out_completed",
+        dest = %dest_root.display(),
+        files = copied.len()
+    );
+
+    Ok(copied)
+}
+
+/// Reject absolute paths, `..` traversal, and anything that isn't a file in the worktree.
+fn validate_copy_path(worktree: &Path, path: &Path) -> Result<(), SessionError> {
+    let invalid = |message: &str| SessionError::CopyOutInvalidPath {
+        path: path.to_path_buf(),
+        message: message.to_string(),
+    };
+
+    if path.as_os_str().is_empty()
+        || !path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(invalid("must be a relative path inside the worktree"));
+    }
+    if !worktree.join(path).is_file() {
+        return Err(invalid("not a file in the kild's worktree"));
+    }
+    Ok(())
+}
+
+/// Whether `path` in the repo at `repo_root` differs from what git has committed.
+///
+/// Untracked files count as changes. Outside a git repository every existing
+/// file is treated as changed, since there is nothing to restore it from.
+fn has_local_changes(repo_root: &Path, path: &Path) -> bool {
+    let Ok(repo) = git2::Repository::open(repo_root) else {
+        return true;
+    };
+    match repo.status_file(path) {
+        Ok(status) => !status.is_empty() && !status.is_ignored(),
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    /// Main repo with `config.toml` committed, plus a separate "worktree" dir.
+    fn setup() -> (TempDir, TempDir) {
+        let repo = TempDir::new().unwrap();
+        for args in [
+            vec!["init"],
+            vec!["config", "user.email", "test@test.com"],
+            vec!["config", "user.name", "Test"],
+        ] {
+            Command::new("git")
+                .args(&args)
+                .current_dir(repo.path())
+                .output()
+                .unwrap();
+        }
+        fs::write(repo.path().join("config.toml"), "committed\n").unwrap();
+        Command::new("git")
+            .args(["add", "."])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", "initial"])
+            .current_dir(repo.path())
+            .output()
+            .unwrap();
+
+        let worktree = TempDir::new().unwrap();
+        fs::write(worktree.path().join("config.toml"), "from kild\n").unwrap();
+        fs::create_dir_all(worktree.path().join("src")).unwrap();
+        fs::write(worktree.path().join("src/new.rs"), "fn main() {}\n").unwrap();
+        (repo, worktree)
+    }
+
+    #[test]
+    fn test_copy_out_clean_copy() {
+        let (repo, worktree) = setup();
+        let paths = vec![PathBuf::from("config.toml"), PathBuf::from("src/new.rs")];
+
+        let copied = copy_files_out(worktree.path(), &paths, repo.path(), false).unwrap();
+
+        assert_eq!(
+            copied,
+            vec![
+                repo.path().join("config.toml"),
+                repo.path().join("src/new.rs")
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(repo.path().join("config.toml")).unwrap(),
+            "from kild\n"
+        );
+        assert_eq!(
+            fs::read_to_string(repo.path().join("src/new.rs")).unwrap(),
+            "fn main() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_copy_out_refuses_to_overwrite_modified_file() {
+        let (repo, worktree) = setup();
+        fs::write(repo.path().join("config.toml"), "local edit\n").unwrap();
+        let paths = vec![PathBuf::from("src/new.rs"), PathBuf::from("config.toml")];
+
+        let err = copy_files_out(worktree.path(), &paths, repo.path(), false).unwrap_err();
+
+        assert!(matches!(
+            err,
+            SessionError::CopyOutWouldOverwrite { ref path } if path == Path::new("config.toml")
+        ));
+        // Nothing is written when any path is refused
+        assert_eq!(
+            fs::read_to_string(repo.path().join("config.toml")).unwrap(),
+            "local edit\n"
+        );
+        assert!(!repo.path().join("src/new.rs").exists());
+    }
+
+    #[test]
+    fn test_copy_out_force_overwrites_modified_file() {
+        let (repo, worktree) = setup();
+        fs::write(repo.path().join("config.toml"), "local edit\n").unwrap();
+        let paths = vec![PathBuf::from("config.toml")];
+
+        copy_files_out(worktree.path(), &paths, repo.path(), true).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(repo.path().join("config.toml")).unwrap(),
+            "from kild\n"
+        );
+    }
+
+    #[test]
+    fn test_copy_out_rejects_paths_outside_worktree() {
+        let (repo, worktree) = setup();
+        for bad in ["../escape.txt", "/etc/passwd", "", "missing.txt"] {
+            let err = copy_files_out(worktree.path(), &[PathBuf::from(bad)], repo.path(), true)
+                .unwrap_err();
+            assert!(
+                matches!(err, SessionError::CopyOutInvalidPath { .. }),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+}
@@ -11,10 +11,33 @@ use super::daemon_helpers::{
     spawn_and_save_attach_window, spawn_daemon_agent, spawn_terminal_agent,
 };
 
+/// Create a new kild session.
+///
+/// With `request.from` set, the source session's agent, base branch and
+/// include patterns seed the request first (see [`apply_source_session`]);
+/// explicit request values still win.
 pub fn create_session(
     request: CreateSessionRequest,
     kild_config: &KildConfig,
 ) -> Result<Session, SessionError> {
+    let span = crate::logging::session_span("create", &request.branch).entered();
+
+    let seeded_config;
+    let (request, kild_config) =
+        match request.from.clone() {
+            Some(source_id) => {
+                let sessions_dir = Config::new().sessions_dir();
+                let source = persistence::find_session_by_id(&sessions_dir, &source_id)?
+                    .ok_or_else(|| SessionError::NotFound {
+                        name: source_id.to_string(),
+                    })?;
+                let (request, config) = apply_source_session(request, &source, kild_config);
+                seeded_config = config;
+                (request, &seeded_config)
+            }
+            None => (request, kild_config),
+        };
+
     // Determine agent name and command based on AgentMode
     let (agent, agent_command) = match &request.agent_mode {
         AgentMode::BareShell => {
@@ -38,7 +61,9 @@ pub fn create_session(
                         message: e.to_string(),
                     })?;
 
-            if let Some(false) = agents::is_agent_available(name) {
+            if let Some(false) =
+                agents::resolve_agent(kild_config, name).map(|a| a.backend().is_available())
+            {
                 warn!(
                     event = "core.session.agent_not_available",
                     agent = %name,
@@ -58,7 +83,9 @@ pub fn create_session(
                         message: e.to_string(),
                     })?;
 
-            if let Some(false) = agents::is_agent_available(&name) {
+            if let Some(false) =
+                agents::resolve_agent(kild_config, &name).map(|a| a.backend().is_available())
+            {
                 warn!(
                     event = "core.session.agent_not_available",
                     agent = %name,
@@ -91,6 +118,8 @@ pub fn create_session(
         agent_command
     };
 
+    span.record("agent", agent.as_str());
+
     info!(
         event = "core.session.create_started",
         branch = %request.branch,
@@ -126,6 +155,7 @@ pub fn create_session(
     let config = Config::new();
     let project_id: kild_protocol::ProjectId = project.id.clone().into();
     let session_id = ports::generate_session_id(&project_id, &validated.name);
+    span.record("session_id", &*session_id);
 
     // Generate task list ID for agents that support it (depends on session_id)
     let task_list_id = if agents::resume::supports_resume(&agent) {
@@ -215,6 +245,11 @@ pub fn create_session(
         )
         .map_err(|e| SessionError::GitError { source: e })?;
 
+        if request.init_submodules {
+            git::cli::init_submodules(&wt.path)
+                .map_err(|e| SessionError::GitError { source: e })?;
+        }
+
         info!(
             event = "core.session.worktree_created",
             session_id = %session_id,
@@ -309,6 +344,8 @@ pub fn create_session(
     );
 
     session.use_main_worktree = request.use_main_worktree;
+    session.base_branch = git_config.base_branch.clone();
+    session.include_patterns = kild_config.include_patterns.clone();
 
     // 7. Save session BEFORE spawning attach window so `kild attach` can find it
     persistence::save_session_to_file(&session, &config.sessions_dir())?;
@@ -344,9 +381,50 @@ pub fn create_session(
         process_name = ?session.latest_agent().map(|a| a.process_name())
     );
 
+    crate::events::publish(crate::state::Event::KildCreated {
+        branch: session.branch.clone(),
+        session_id: session.id.clone(),
+    });
+
     Ok(session)
 }
 
+/// Seed a create request from an existing session (`kild create --from`).
+///
+/// Copies the source's agent, base branch and include patterns unless the
+/// request sets them explicitly. The agent command is then resolved from
+/// config as usual; stored commands carry per-session resume arguments.
+fn apply_source_session(
+    mut request: CreateSessionRequest,
+    source: &Session,
+    kild_config: &KildConfig,
+) -> (CreateSessionRequest, KildConfig) {
+    if matches!(request.agent_mode, AgentMode::DefaultAgent) {
+        request.agent_mode = if source.agent == "shell" {
+            AgentMode::BareShell
+        } else {
+            AgentMode::Agent(source.agent.clone())
+        };
+    }
+    if request.base_branch.is_none() {
+        request.base_branch = source.base_branch.clone();
+    }
+
+    let mut config = kild_config.clone();
+    if source.include_patterns.is_some() {
+        config.include_patterns = source.include_patterns.clone();
+    }
+
+    info!(
+        event = "core.session.create_seeded",
+        source = %source.branch,
+        branch = %request.branch,
+        agent_mode = ?request.agent_mode,
+    );
+
+    (request, config)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -593,6 +671,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_session_logs_inside_session_span() {
+        use std::sync::{Arc, Mutex};
+        use tracing_subscriber::layer::SubscriberExt;
+
+        #[derive(Clone, Default)]
+        struct Captured(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Captured {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber =
+            tracing_subscriber::registry().with(crate::logging::json_layer(move || writer.clone()));
+
+        // A blank branch fails validation right after `create_started`, before any I/O
+        let request = CreateSessionRequest::new("   ".to_string(), AgentMode::BareShell, None);
+        let result = tracing::subscriber::with_default(subscriber, || {
+            create_session(request, &KildConfig::default())
+        });
+        assert!(matches!(result, Err(SessionError::InvalidName)));
+
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        let started: serde_json::Value = output
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .find(|log| log["fields"]["event"] == "core.session.create_started")
+            .expect("create_started should be logged");
+
+        let span = &started["span"];
+        assert_eq!(span["name"], "session_op");
+        assert_eq!(span["operation"], "create");
+        assert_eq!(span["branch"], "   ");
+        assert_eq!(span["agent"], "shell");
+    }
+
     #[test]
     fn test_create_session_generates_session_id_for_claude() {
         // Verify that agent_session_id generation works for resume-capable agents
@@ -835,4 +957,70 @@ mod tests {
         // Cleanup
         let _ = fs::remove_dir_all(&temp_dir);
     }
+
+    fn source_session() -> Session {
+        let mut source = Session::new_for_test("feature-a", std::path::PathBuf::from("/tmp/a"));
+        source.agent = "kiro".to_string();
+        source.base_branch = Some("develop".to_string());
+        source.include_patterns = Some(kild_config::IncludeConfig {
+            patterns: vec![".env.local".to_string(), "fixtures/**".to_string()],
+            enabled: true,
+            max_file_size: Some("5MB".to_string()),
+        });
+        source
+    }
+
+    #[test]
+    fn test_apply_source_session_clones_setup() {
+        let source = source_session();
+        let request = CreateSessionRequest::new("feature-b", AgentMode::DefaultAgent, None)
+            .with_from(Some(source.id.clone()));
+
+        let (seeded, config) = apply_source_session(request, &source, &KildConfig::default());
+
+        assert_eq!(&*seeded.branch, "feature-b");
+        assert_ne!(seeded.branch, source.branch);
+        assert!(matches!(&seeded.agent_mode, AgentMode::Agent(name) if name == "kiro"));
+        assert_eq!(seeded.base_branch.as_deref(), Some("develop"));
+        assert_eq!(config.include_patterns, source.include_patterns);
+    }
+
+    #[test]
+    fn test_apply_source_session_explicit_overrides_win() {
+        let source = source_session();
+        let request =
+            CreateSessionRequest::new("feature-b", AgentMode::Agent("claude".to_string()), None)
+                .with_base_branch(Some("main".to_string()));
+
+        let (seeded, config) = apply_source_session(request, &source, &KildConfig::default());
+
+        assert!(matches!(&seeded.agent_mode, AgentMode::Agent(name) if name == "claude"));
+        assert_eq!(seeded.base_branch.as_deref(), Some("main"));
+        // Include patterns have no per-create override, so they still carry over
+        assert_eq!(config.include_patterns, source.include_patterns);
+    }
+
+    #[test]
+    fn test_apply_source_session_shell_source_stays_bare_shell() {
+        let mut source = source_session();
+        source.agent = "shell".to_string();
+        let request = CreateSessionRequest::new("feature-b", AgentMode::DefaultAgent, None);
+
+        let (seeded, _) = apply_source_session(request, &source, &KildConfig::default());
+        assert!(matches!(seeded.agent_mode, AgentMode::BareShell));
+    }
+
+    #[test]
+    fn test_apply_source_session_legacy_source_keeps_config() {
+        // Sessions created before base_branch/include_patterns were recorded
+        let mut source = source_session();
+        source.base_branch = None;
+        source.include_patterns = None;
+        let kild_config = KildConfig::default();
+        let request = CreateSessionRequest::new("feature-b", AgentMode::DefaultAgent, None);
+
+        let (seeded, config) = apply_source_session(request, &source, &kild_config);
+        assert!(seeded.base_branch.is_none());
+        assert_eq!(config.include_patterns, kild_config.include_patterns);
+    }
 }
@@ -613,6 +613,7 @@ mod tests {
             ("gemini", "gemini"),
             ("amp", "amp"),
             ("opencode", "opencode"),
+            ("aider", "aider"),
         ] {
             let DaemonRequestParams { env_vars, .. } = build_daemon_create_request(
                 agent_cmd,
@@ -6,9 +6,10 @@
 
 use std::path::Path;
 
-use tracing::{debug, warn};
+use tracing::{debug, info, warn};
 
 use crate::agents;
+use crate::agents::AgentBackend;
 use crate::sessions::errors::SessionError;
 use crate::sessions::types::AgentProcess;
 use crate::terminal;
@@ -172,7 +173,7 @@ pub(super) fn spawn_terminal_agent(
 
     // 3. Spawn terminal window
     let base_config = Config::new();
-    let spawn_result = terminal::handler::spawn_terminal(
+    let mut spawn_result = terminal::handler::spawn_terminal(
         params.worktree_path,
         &terminal_command,
         params.kild_config,
@@ -180,6 +181,12 @@ pub(super) fn spawn_terminal_agent(
         Some(base_config.kild_dir()),
     )
     .map_err(|e| SessionError::TerminalError { source: e })?;
+    let resolved_agent = agents::resolve_agent(params.kild_config, params.agent);
+    discover_agent_pid(
+        &mut spawn_result,
+        resolved_agent.as_ref().map(|agent| agent.backend()),
+        params.worktree_path,
+    );
 
     // 4. Capture process metadata (fresh from OS for PID reuse protection)
     let (process_name, process_start_time) = capture_process_metadata(&spawn_result);
@@ -260,6 +267,42 @@ fn read_scrollback_tail(daemon_session_id: &str) -> String {
     }
 }
 
+/// Find the agent by scanning the worktree when the PID file came back empty.
+///
+/// Some terminals detach the agent from the wrapper that writes the PID file.
+/// Matching the agent against processes whose CWD is inside the worktree
+/// recovers the PID in those cases. Leaves a captured PID alone.
+fn discover_agent_pid(
+    spawn_result: &mut terminal::types::SpawnResult,
+    agent: Option<&dyn AgentBackend>,
+    worktree_path: &Path,
+) {
+    if spawn_result.process_id.is_some() {
+        return;
+    }
+
+    match agent.and_then(|agent| crate::process::find_agent_process(agent, worktree_path)) {
+        Some(found) => {
+            info!(
+                event = "core.session.agent_pid_discovered",
+                pid = found.pid.as_u32(),
+                process_name = %found.name,
+                worktree = %worktree_path.display(),
+            );
+            spawn_result.process_id = Some(found.pid.as_u32());
+            spawn_result.process_name = Some(found.name);
+            spawn_result.process_start_time = Some(found.start_time);
+        }
+        None => {
+            warn!(
+                event = "core.session.agent_pid_not_found",
+                worktree = %worktree_path.display(),
+                "No agent process found in worktree - process tracking unavailable"
+            );
+        }
+    }
+}
+
 /// Capture process metadata from a terminal spawn result.
 ///
 /// Attempts to get fresh process info from the OS for PID reuse protection.
@@ -290,3 +333,67 @@ fn capture_process_metadata(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::backends::ConfigAgentBackend;
+    use crate::terminal::types::{SpawnResult, TerminalType};
+    use kild_config::AgentSettings;
+
+    fn spawn_result(dir: &Path, process_id: Option<u32>) -> SpawnResult {
+        SpawnResult::new(
+            TerminalType::Native,
+            "sleep 30".to_string(),
+            dir.to_path_buf(),
+            process_id,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn sleep_agent() -> ConfigAgentBackend {
+        let settings = AgentSettings {
+            binary: Some("sleep".to_string()),
+            ..Default::default()
+        };
+        ConfigAgentBackend::from_settings("sleep", &settings, None).unwrap()
+    }
+
+    #[test]
+    fn test_discover_agent_pid_keeps_captured_pid() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut result = spawn_result(dir.path(), Some(4242));
+
+        discover_agent_pid(&mut result, Some(&sleep_agent()), dir.path());
+
+        assert_eq!(result.process_id, Some(4242));
+        assert!(result.process_name.is_none());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_discover_agent_pid_scans_worktree_when_pid_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path().canonicalize().unwrap();
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .current_dir(&dir)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+
+        let mut result = spawn_result(&dir, None);
+        discover_agent_pid(&mut result, Some(&sleep_agent()), &dir);
+
+        let _ = child.kill();
+        let _ = child.wait();
+
+        assert_eq!(result.process_id, Some(child.id()));
+        assert_eq!(result.process_name.as_deref(), Some("sleep"));
+        assert!(result.process_start_time.is_some());
+    }
+}
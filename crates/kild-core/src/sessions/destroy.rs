@@ -8,6 +8,52 @@ use crate::sessions::{errors::SessionError, persistence, types::*};
 use crate::terminal;
 use kild_config::Config;
 
+/// Result of stopping a terminal-managed agent before its worktree is removed.
+#[derive(Debug, PartialEq, Eq)]
+enum AgentTermination {
+    /// The process tree was signaled and has exited.
+    Terminated(u32),
+    /// The tracked PID was already gone.
+    AlreadyExited(u32),
+    /// No PID was captured at launch, so there is nothing to signal.
+    NoPid,
+}
+
+/// SIGTERM the agent's process tree, escalating to SIGKILL after the grace period.
+///
+/// Runs before worktree removal so the agent is not left holding files open in
+/// a deleted directory. Identity is verified first to guard against PID reuse.
+fn terminate_agent_process(
+    agent_proc: &AgentProcess,
+) -> Result<AgentTermination, (u32, crate::process::ProcessError)> {
+    let Some(pid) = agent_proc.process_id() else {
+        return Ok(AgentTermination::NoPid);
+    };
+
+    info!(
+        event = "core.session.destroy_kill_started",
+        pid = pid,
+        agent = agent_proc.agent()
+    );
+
+    // Kill the whole tree so agent children (node, language servers)
+    // aren't orphaned
+    let result = crate::process::verify_process_identity(
+        pid,
+        agent_proc.process_name(),
+        agent_proc.process_start_time(),
+    )
+    .and_then(|()| crate::process::kill_process_tree(crate::process::Pid::from_raw(pid)));
+
+    match result {
+        Ok(()) => Ok(AgentTermination::Terminated(pid)),
+        Err(crate::process::ProcessError::NotFound { .. }) => {
+            Ok(AgentTermination::AlreadyExited(pid))
+        }
+        Err(e) => Err((pid, e)),
+    }
+}
+
 /// Clean up Claude Code task list directory for a session.
 ///
 /// Removes `~/.claude/tasks/<task_list_id>/` if it exists. Failures are logged
@@ -53,6 +99,8 @@ pub fn cleanup_task_list(session_id: &str, task_list_id: &str, home_dir: &std::p
 /// - Process kill failures are logged but don't block destruction
 /// - Worktree is force-deleted even with uncommitted changes (work will be lost)
 pub fn destroy_session(name: &str, force: bool) -> Result<(), SessionError> {
+    let span = crate::logging::session_span("destroy", name).entered();
+
     info!(
         event = "core.session.destroy_started",
         name = name,
@@ -69,6 +117,8 @@ pub fn destroy_session(name: &str, force: bool) -> Result<(), SessionError> {
             }
         })?;
 
+    crate::logging::record_session(&span, &session.id, &session.agent);
+
     info!(
         event = "core.session.destroy_found",
         session_id = %session.id,
@@ -78,6 +128,23 @@ pub fn destroy_session(name: &str, force: bool) -> Result<(), SessionError> {
         agent_count = session.agent_count()
     );
 
+    // Stashed work is easy to lose track of once the branch is gone
+    if !force && session.worktree_path.exists() {
+        let stash_count = count_stashes(&session.worktree_path, &git::kild_branch_name(name));
+        if stash_count > 0 {
+            error!(
+                event = "core.session.destroy_blocked",
+                name = name,
+                reason = "stashes",
+                stash_count = stash_count
+            );
+            return Err(SessionError::StashesPresent {
+                name: name.to_string(),
+                count: stash_count,
+            });
+        }
+    }
+
     // 2. Close all terminal windows and kill all processes
     {
         if !session.has_agents() {
@@ -134,37 +201,28 @@ pub fn destroy_session(name: &str, force: bool) -> Result<(), SessionError> {
                     terminal::handler::close_terminal(terminal_type, Some(window_id));
                 }
 
-                let Some(pid) = agent_proc.process_id() else {
-                    continue;
-                };
-
-                info!(
-                    event = "core.session.destroy_kill_started",
-                    pid = pid,
-                    agent = agent_proc.agent()
-                );
-
-                let result = crate::process::kill_process(
-                    pid,
-                    agent_proc.process_name(),
-                    agent_proc.process_start_time(),
-                );
-
-                match result {
-                    Ok(()) => {
+                match terminate_agent_process(agent_proc) {
+                    Ok(AgentTermination::Terminated(pid)) => {
                         info!(event = "core.session.destroy_kill_completed", pid = pid);
                     }
-                    Err(crate::process::ProcessError::NotFound { .. }) => {
+                    Ok(AgentTermination::AlreadyExited(pid)) => {
                         info!(event = "core.session.destroy_kill_already_dead", pid = pid);
                     }
-                    Err(e) if force => {
+                    Ok(AgentTermination::NoPid) => {
+                        warn!(
+                            event = "core.session.destroy_no_pid",
+                            agent = agent_proc.agent(),
+                            "No PID tracked - removing worktree without stopping the agent process"
+                        );
+                    }
+                    Err((pid, e)) if force => {
                         warn!(
                             event = "core.session.destroy_kill_failed_force_continue",
                             pid = pid,
                             error = %e
                         );
                     }
-                    Err(e) => {
+                    Err((pid, e)) => {
                         kill_errors.push((pid, e.to_string()));
                     }
                 }
@@ -350,6 +408,15 @@ pub fn destroy_session(name: &str, force: bool) -> Result<(), SessionError> {
     // 7. Clean up PID files (best-effort, don't fail if missing)
     crate::process::cleanup_pid_files(&session.pid_keys(), config.kild_dir(), "destroy");
 
+    // 7b. Remove per-session health history (best-effort)
+    if let Err(e) = crate::health::remove_session_history(&session.id) {
+        warn!(
+            event = "core.session.health_history_cleanup_failed",
+            session_id = %session.id,
+            error = %e,
+        );
+    }
+
     // 8. Remove session directory (includes kild.json, status sidecar, pr sidecar)
     persistence::remove_session_file(&config.sessions_dir(), &session.id)?;
 
@@ -366,6 +433,10 @@ pub fn destroy_session(name: &str, force: bool) -> Result<(), SessionError> {
         name = name
     );
 
+    crate::events::publish(crate::state::Event::KildDestroyed {
+        branch: session.branch.clone(),
+    });
+
     Ok(())
 }
 
@@ -487,9 +558,17 @@ pub fn get_destroy_safety_info(name: &str) -> Result<DestroySafety, SessionError
         pr_status = ?pr_status
     );
 
+    // 4. Count stashes made on the kild branch (best-effort)
+    let stash_count = if session.worktree_path.exists() {
+        count_stashes(&session.worktree_path, &kild_branch)
+    } else {
+        0
+    };
+
     let safety_info = DestroySafety {
         git_status,
         pr_status,
+        stash_count,
     };
 
     info!(
@@ -497,15 +576,100 @@ pub fn get_destroy_safety_info(name: &str) -> Result<DestroySafety, SessionError
         name = name,
         should_block = safety_info.should_block(),
         has_warnings = safety_info.has_warnings(),
-        status_check_failed = safety_info.git_status.status_check_failed
+        status_check_failed = safety_info.git_status.status_check_failed,
+        stash_count = safety_info.stash_count
     );
 
     Ok(safety_info)
 }
 
+/// Count stashes on `kild_branch`, treating a failed listing as none.
+fn count_stashes(worktree_path: &std::path::Path, kild_branch: &str) -> usize {
+    crate::git::cli::count_branch_stashes(worktree_path, kild_branch)
+        .inspect_err(|e| {
+            warn!(
+                event = "core.session.stash_check_failed",
+                branch = kild_branch,
+                error = %e
+            );
+        })
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::process::{Command, Stdio};
+
+    fn agent_with_pid(pid: Option<u32>, name: Option<String>, start: Option<u64>) -> AgentProcess {
+        AgentProcess::new(
+            "claude".to_string(),
+            "proj_feature_0".to_string(),
+            pid,
+            name,
+            start,
+            None,
+            None,
+            "claude".to_string(),
+            "2024-01-01T00:00:00Z".to_string(),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_terminate_agent_process_without_pid_is_no_pid() {
+        let agent = agent_with_pid(None, None, None);
+        assert_eq!(
+            terminate_agent_process(&agent).unwrap(),
+            AgentTermination::NoPid
+        );
+    }
+
+    #[test]
+    fn test_terminate_agent_process_dead_pid_already_exited() {
+        let agent = agent_with_pid(Some(999999), Some("claude".to_string()), Some(1));
+        assert_eq!(
+            terminate_agent_process(&agent).unwrap(),
+            AgentTermination::AlreadyExited(999999)
+        );
+    }
+
+    #[test]
+    fn test_terminate_agent_process_stops_tree_before_returning() {
+        // Agent with a child, like claude spawning node
+        let mut parent = Command::new("sh")
+            .arg("-c")
+            .arg("sleep 30 & echo $!; wait")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn test process");
+        let parent_pid = parent.id();
+        let mut line = String::new();
+        std::io::BufRead::read_line(
+            &mut std::io::BufReader::new(parent.stdout.take().unwrap()),
+            &mut line,
+        )
+        .unwrap();
+        assert!(line.trim().parse::<u32>().is_ok(), "child started");
+        let info = crate::process::get_process_info(parent_pid).unwrap();
+        let agent = agent_with_pid(Some(parent_pid), Some(info.name), Some(info.start_time));
+
+        let outcome = terminate_agent_process(&agent).unwrap();
+
+        // Worktree removal runs after this returns, so the agent must already
+        // have exited, and from SIGTERM rather than SIGKILL
+        let status = parent
+            .try_wait()
+            .unwrap()
+            .expect("agent exited before return");
+        assert_eq!(outcome, AgentTermination::Terminated(parent_pid));
+        assert_eq!(
+            std::os::unix::process::ExitStatusExt::signal(&status),
+            Some(nix::sys::signal::Signal::SIGTERM as i32)
+        );
+    }
 
     #[test]
     fn test_destroy_session_not_found() {
@@ -651,6 +815,7 @@ mod tests {
                 ..Default::default()
             },
             pr_status: PrCheckResult::Exists,
+            stash_count: 0,
         };
         assert!(!info.should_block());
         assert!(!info.has_warnings());
@@ -668,6 +833,7 @@ mod tests {
                 ..Default::default()
             },
             pr_status: PrCheckResult::Exists,
+            stash_count: 0,
         };
         assert!(uncommitted.has_warnings());
 
@@ -678,6 +844,7 @@ mod tests {
                 ..Default::default()
             },
             pr_status: PrCheckResult::Exists,
+            stash_count: 0,
         };
         assert!(unpushed.has_warnings());
 
@@ -687,6 +854,7 @@ mod tests {
                 ..Default::default()
             },
             pr_status: PrCheckResult::Exists,
+            stash_count: 0,
         };
         assert!(no_remote.has_warnings());
 
@@ -696,6 +864,7 @@ mod tests {
                 ..Default::default()
             },
             pr_status: PrCheckResult::NotFound,
+            stash_count: 0,
         };
         assert!(no_pr.has_warnings());
 
@@ -706,6 +875,7 @@ mod tests {
                 ..Default::default()
             },
             pr_status: PrCheckResult::Exists,
+            stash_count: 0,
         };
         assert!(status_failed.has_warnings());
     }
@@ -723,6 +893,7 @@ mod tests {
                 ..Default::default()
             },
             pr_status: PrCheckResult::NotFound,
+            stash_count: 0,
         };
 
         let msgs = info.warning_messages();
@@ -15,9 +15,17 @@ pub enum SessionError {
     #[error("Session '{name}' not found")]
     NotFound { name: String },
 
-    #[error("Worktree not found at path: {path}")]
+    #[error("Worktree not found at path: {path} — run 'kild cleanup' to remove the stale session")]
     WorktreeNotFound { path: std::path::PathBuf },
 
+    #[error(
+        "Cannot rename '{name}' while its agent is running.\n  Stop it first: kild stop {name}"
+    )]
+    RenameActive { name: String },
+
+    #[error("Cannot rename '{name}': {message}")]
+    RenameFailed { name: String, message: String },
+
     #[error("Invalid session name: cannot be empty")]
     InvalidName,
 
@@ -68,6 +76,12 @@ pub enum SessionError {
     )]
     InvalidProcessMetadata,
 
+    #[error("Invalid tag '{tag}': must be non-empty and contain no whitespace")]
+    InvalidTag { tag: String },
+
+    #[error("Invalid filter: {message}")]
+    InvalidFilter { message: String },
+
     #[error("Invalid agent status: '{status}'. Valid: working, idle, waiting, done, error")]
     InvalidAgentStatus { status: String },
 
@@ -79,6 +93,11 @@ pub enum SessionError {
     )]
     UncommittedChanges { name: String },
 
+    #[error(
+        "Cannot destroy '{name}': {count} stash(es) on this branch.\n   Inspect first: git -C $(kild cd {name}) stash list\n   Use --force to destroy anyway (stashes will be orphaned)."
+    )]
+    StashesPresent { name: String, count: usize },
+
     #[error(
         "Cannot complete '{name}': no PR found for this branch.\n   If the work landed, push the branch and create a PR first.\n   To remove the kild without completing, use 'kild destroy {name}'."
     )]
@@ -99,6 +118,11 @@ pub enum SessionError {
     )]
     MergeFailed { name: String, message: String },
 
+    #[error(
+        "Cannot complete '{name}': opening the PR failed.\n   {message}\n   Push the branch and try again, or use 'kild destroy {name}' to discard."
+    )]
+    PrCreateFailed { name: String, message: String },
+
     #[error("Daemon error: {message}")]
     DaemonError { message: String },
 
@@ -136,6 +160,46 @@ pub enum SessionError {
         "Pane '{pane_id}' not found in session '{branch}'. Use 'kild teammates {branch}' to list panes."
     )]
     PaneNotFound { pane_id: String, branch: String },
+
+    #[error("Cannot import: session '{id}' already exists.\n  Use --overwrite to replace it.")]
+    ImportConflict { id: String },
+
+    #[error("Cannot copy '{}': {message}", path.display())]
+    CopyOutInvalidPath {
+        path: std::path::PathBuf,
+        message: String,
+    },
+
+    #[error(
+        "Refusing to overwrite '{}': it has uncommitted changes in the main repo.\n  Use --force to overwrite.",
+        path.display()
+    )]
+    CopyOutWouldOverwrite { path: std::path::PathBuf },
+
+    #[error("Failed to copy '{}': {message}", path.display())]
+    CopyOutFailed {
+        path: std::path::PathBuf,
+        message: String,
+    },
+
+    #[error("Cannot archive '{name}' while it is running. Stop it first with 'kild stop {name}'.")]
+    ArchiveActive { name: String },
+
+    #[error(
+        "Cannot remove the worktree of '{name}': it has uncommitted changes.\n   Commit or stash them first, or archive without --remove-worktree."
+    )]
+    ArchiveUncommittedChanges { name: String },
+
+    #[error("Kild '{name}' is already archived")]
+    AlreadyArchived { name: String },
+
+    #[error("Kild '{name}' is not archived")]
+    NotArchived { name: String },
+
+    #[error(
+        "Cannot restore the worktree of '{name}': run 'kild unarchive' from inside its repository."
+    )]
+    UnarchiveRepoNotFound { name: String },
 }
 
 impl KildError for SessionError {
@@ -145,6 +209,8 @@ impl KildError for SessionError {
             SessionError::AlreadyActive { .. } => "SESSION_ALREADY_ACTIVE",
             SessionError::NotFound { .. } => "SESSION_NOT_FOUND",
             SessionError::WorktreeNotFound { .. } => "WORKTREE_NOT_FOUND",
+            SessionError::RenameActive { .. } => "SESSION_RENAME_ACTIVE",
+            SessionError::RenameFailed { .. } => "SESSION_RENAME_FAILED",
             SessionError::InvalidName => "INVALID_SESSION_NAME",
             SessionError::InvalidCommand => "INVALID_COMMAND",
             SessionError::InvalidStructure { .. } => "INVALID_SESSION_STRUCTURE",
@@ -158,13 +224,17 @@ impl KildError for SessionError {
             SessionError::ProcessKillFailed { .. } => "PROCESS_KILL_FAILED",
             SessionError::ProcessAccessDenied { .. } => "PROCESS_ACCESS_DENIED",
             SessionError::InvalidProcessMetadata => "INVALID_PROCESS_METADATA",
+            SessionError::InvalidTag { .. } => "INVALID_TAG",
+            SessionError::InvalidFilter { .. } => "INVALID_FILTER",
             SessionError::InvalidAgentStatus { .. } => "INVALID_AGENT_STATUS",
             SessionError::ConfigError { .. } => "CONFIG_ERROR",
             SessionError::UncommittedChanges { .. } => "SESSION_UNCOMMITTED_CHANGES",
+            SessionError::StashesPresent { .. } => "SESSION_STASHES_PRESENT",
             SessionError::NoPrFound { .. } => "SESSION_NO_PR_FOUND",
             SessionError::PrNotOpen { .. } => "SESSION_PR_NOT_OPEN",
             SessionError::CiFailing { .. } => "SESSION_CI_FAILING",
             SessionError::MergeFailed { .. } => "SESSION_MERGE_FAILED",
+            SessionError::PrCreateFailed { .. } => "SESSION_PR_CREATE_FAILED",
             SessionError::DaemonError { .. } => "DAEMON_ERROR",
             SessionError::DaemonPtyExitedEarly { .. } => "DAEMON_PTY_EXITED_EARLY",
             SessionError::DaemonAutoStartFailed { .. } => "DAEMON_AUTO_START_FAILED",
@@ -172,7 +242,16 @@ impl KildError for SessionError {
             SessionError::ResumeNoSessionId { .. } => "RESUME_NO_SESSION_ID",
             SessionError::NoTeammates { .. } => "SESSION_NO_TEAMMATES",
             SessionError::PaneNotFound { .. } => "SESSION_PANE_NOT_FOUND",
+            SessionError::ImportConflict { .. } => "SESSION_IMPORT_CONFLICT",
             SessionError::LeaderPaneStop { .. } => "SESSION_LEADER_PANE_STOP",
+            SessionError::CopyOutInvalidPath { .. } => "SESSION_COPY_OUT_INVALID_PATH",
+            SessionError::CopyOutWouldOverwrite { .. } => "SESSION_COPY_OUT_WOULD_OVERWRITE",
+            SessionError::CopyOutFailed { .. } => "SESSION_COPY_OUT_FAILED",
+            SessionError::ArchiveActive { .. } => "SESSION_ARCHIVE_ACTIVE",
+            SessionError::ArchiveUncommittedChanges { .. } => "SESSION_ARCHIVE_UNCOMMITTED_CHANGES",
+            SessionError::AlreadyArchived { .. } => "SESSION_ALREADY_ARCHIVED",
+            SessionError::NotArchived { .. } => "SESSION_NOT_ARCHIVED",
+            SessionError::UnarchiveRepoNotFound { .. } => "SESSION_UNARCHIVE_REPO_NOT_FOUND",
         }
     }
 
@@ -186,6 +265,8 @@ impl KildError for SessionError {
                 | SessionError::AlreadyActive { .. }
                 | SessionError::NotFound { .. }
                 | SessionError::WorktreeNotFound { .. }
+                | SessionError::RenameActive { .. }
+                | SessionError::RenameFailed { .. }
                 | SessionError::InvalidName
                 | SessionError::InvalidCommand
                 | SessionError::InvalidStructure { .. }
@@ -193,22 +274,84 @@ impl KildError for SessionError {
                 | SessionError::PortRangeExhausted
                 | SessionError::PortAllocationFailed { .. }
                 | SessionError::InvalidProcessMetadata
+                | SessionError::InvalidTag { .. }
+                | SessionError::InvalidFilter { .. }
                 | SessionError::InvalidAgentStatus { .. }
                 | SessionError::ConfigError { .. }
                 | SessionError::UncommittedChanges { .. }
+                | SessionError::StashesPresent { .. }
                 | SessionError::NoPrFound { .. }
                 | SessionError::PrNotOpen { .. }
                 | SessionError::CiFailing { .. }
                 | SessionError::MergeFailed { .. }
+                | SessionError::PrCreateFailed { .. }
                 | SessionError::ResumeUnsupported { .. }
                 | SessionError::ResumeNoSessionId { .. }
                 | SessionError::NoTeammates { .. }
                 | SessionError::PaneNotFound { .. }
+                | SessionError::ImportConflict { .. }
                 | SessionError::LeaderPaneStop { .. }
+                | SessionError::CopyOutInvalidPath { .. }
+                | SessionError::CopyOutWouldOverwrite { .. }
+                | SessionError::ArchiveActive { .. }
+                | SessionError::ArchiveUncommittedChanges { .. }
+                | SessionError::AlreadyArchived { .. }
+                | SessionError::NotArchived { .. }
+                | SessionError::UnarchiveRepoNotFound { .. }
         )
     }
 }
 
+/// Error from [`create_fleet`](super::batch::create_fleet).
+#[derive(Debug, thiserror::Error)]
+pub enum FleetError {
+    #[error(
+        "Failed to create kild '{branch}' ({position} of {total}): {source}\n{}",
+        rollback_summary(.rolled_back, .left_behind)
+    )]
+    CreateFailed {
+        branch: String,
+        /// 1-based position of the failing request in the batch.
+        position: usize,
+        total: usize,
+        #[source]
+        source: Box<SessionError>,
+        /// Kilds destroyed during rollback, most recent first.
+        rolled_back: Vec<String>,
+        /// Kilds that were created but could not be destroyed.
+        left_behind: Vec<String>,
+    },
+}
+
+fn rollback_summary(rolled_back: &[String], left_behind: &[String]) -> String {
+    let mut summary = if rolled_back.is_empty() {
+        "  Rolled back: none".to_string()
+    } else {
+        format!("  Rolled back: {}", rolled_back.join(", "))
+    };
+    if !left_behind.is_empty() {
+        summary.push_str(&format!(
+            "\n  Rollback failed, left behind: {}",
+            left_behind.join(", ")
+        ));
+    }
+    summary
+}
+
+impl KildError for FleetError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            FleetError::CreateFailed { .. } => "FLEET_CREATE_FAILED",
+        }
+    }
+
+    fn is_user_error(&self) -> bool {
+        match self {
+            FleetError::CreateFailed { source, .. } => source.is_user_error(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -350,6 +493,7 @@ mod tests {
             path: std::path::PathBuf::from("/tmp/missing"),
         };
         assert!(error.to_string().contains("/tmp/missing"));
+        assert!(error.to_string().contains("kild cleanup"));
         assert_eq!(error.error_code(), "WORKTREE_NOT_FOUND");
         assert!(error.is_user_error());
     }
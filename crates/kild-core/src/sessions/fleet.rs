@@ -13,13 +13,18 @@
 /// (~/.claude/teams/honryu/) or when the brain session itself is being created.
 use std::fs;
 use std::fs::OpenOptions;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use kild_paths::KildPaths;
 use nix::fcntl::{Flock, FlockArg};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
-use tracing::warn;
+use tracing::{debug, warn};
 
+use super::errors::SessionError;
 use crate::agents::types::AgentType;
 
 /// Branch name reserved for the Honryū brain session.
@@ -530,6 +535,149 @@ fn remove_from_team_config(branch: &str, dir: &Path) {
     }
 }
 
+/// A message passed between teammates through a dropbox.
+///
+/// Each message is one JSON file under `<dropbox>/messages/`, so writers never
+/// share a file and can't clobber each other.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DropMessage {
+    /// Sender branch name.
+    pub from: String,
+    /// Recipient branch name.
+    pub to: String,
+    pub body: String,
+    /// When the message was sent. Reads are ordered by this.
+    pub ts: DateTime<Utc>,
+}
+
+impl DropMessage {
+    /// Create a message stamped with the current time.
+    pub fn new(from: impl Into<String>, to: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            body: body.into(),
+            ts: Utc::now(),
+        }
+    }
+}
+
+/// Per-process counter so two messages dropped in the same millisecond by the
+/// same process still get distinct file names.
+static DROP_SEQ: AtomicU64 = AtomicU64::new(0);
+
+fn messages_dir(project_id: &str, branch: &str) -> Result<PathBuf, SessionError> {
+    let paths = KildPaths::resolve().map_err(|e| SessionError::IoError {
+        source: std::io::Error::other(e),
+    })?;
+    Ok(paths.fleet_dropbox_dir(project_id, branch).join("messages"))
+}
+
+/// Drop a message into `branch`'s dropbox.
+///
+/// The message is written to a temp file and moved into place under a unique
+/// name, so readers never see a partial message and concurrent writers never
+/// overwrite each other.
+pub fn drop_message(project_id: &str, branch: &str, msg: DropMessage) -> Result<(), SessionError> {
+    let dir = messages_dir(project_id, branch)?;
+    let path = drop_message_in(&dir, &msg)?;
+    debug!(
+        event = "core.session.fleet.message_dropped",
+        branch = branch,
+        from = %msg.from,
+        path = %path.display(),
+    );
+    Ok(())
+}
+
+/// Read the messages in `branch`'s dropbox, oldest first.
+///
+/// With `consume`, returned messages are deleted. A message another reader
+/// consumed first is skipped, so each message is consumed at most once.
+/// Unparseable files are logged and left in place.
+pub fn read_messages(
+    project_id: &str,
+    branch: &str,
+    consume: bool,
+) -> Result<Vec<DropMessage>, SessionError> {
+    let dir = messages_dir(project_id, branch)?;
+    read_messages_in(&dir, consume)
+}
+
+fn drop_message_in(dir: &Path, msg: &DropMessage) -> Result<PathBuf, SessionError> {
+    fs::create_dir_all(dir)?;
+
+    let json = serde_json::to_vec_pretty(msg).map_err(|e| SessionError::IoError {
+        source: std::io::Error::other(e),
+    })?;
+
+    // Dot-prefixed temp files are skipped by readers until persisted
+    let mut tmp = tempfile::Builder::new().prefix(".drop-").tempfile_in(dir)?;
+    tmp.write_all(&json)?;
+
+    let name = format!(
+        "{}-{}-{}.json",
+        msg.ts.format("%Y%m%dT%H%M%S%3fZ"),
+        std::process::id(),
+        DROP_SEQ.fetch_add(1, Ordering::Relaxed),
+    );
+    let path = dir.join(name);
+    tmp.persist_noclobber(&path)
+        .map_err(|e| SessionError::IoError { source: e.error })?;
+    Ok(path)
+}
+
+fn read_messages_in(dir: &Path, consume: bool) -> Result<Vec<DropMessage>, SessionError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut found: Vec<(DropMessage, PathBuf)> = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let is_message = path.extension().is_some_and(|ext| ext == "json")
+            && !path
+                .file_name()
+                .is_some_and(|n| n.to_string_lossy().starts_with('.'));
+        if !is_message {
+            continue;
+        }
+
+        let raw = match fs::read_to_string(&path) {
+            Ok(raw) => raw,
+            // Consumed by another reader since read_dir
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        match serde_json::from_str::<DropMessage>(&raw) {
+            Ok(msg) => found.push((msg, path)),
+            Err(e) => warn!(
+                event = "core.session.fleet.message_parse_failed",
+                path = %path.display(),
+                error = %e,
+            ),
+        }
+    }
+
+    // File names break timestamp ties in write order
+    found.sort_by(|(a, a_path), (b, b_path)| a.ts.cmp(&b.ts).then_with(|| a_path.cmp(b_path)));
+
+    let mut messages = Vec::with_capacity(found.len());
+    for (msg, path) in found {
+        if consume {
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        messages.push(msg);
+    }
+    Ok(messages)
+}
+
 /// Serialize all tests that mutate CLAUDE_CONFIG_DIR — env vars are process-global.
 ///
 /// Shared across `fleet::tests` and `dropbox::tests` so neither module can
@@ -1114,4 +1262,145 @@ mod tests {
             );
         });
     }
+
+    // --- drop_message / read_messages ---
+
+    fn message_at(from: &str, body: &str, secs: i64) -> DropMessage {
+        DropMessage {
+            from: from.to_string(),
+            to: "worker".to_string(),
+            body: body.to_string(),
+            ts: DateTime::from_timestamp(1_700_000_000 + secs, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn read_messages_orders_by_timestamp() {
+        let dir = tempfile::tempdir().unwrap();
+        // Written out of order
+        drop_message_in(dir.path(), &message_at("b", "second", 2)).unwrap();
+        drop_message_in(dir.path(), &message_at("c", "third", 3)).unwrap();
+        drop_message_in(dir.path(), &message_at("a", "first", 1)).unwrap();
+
+        let bodies: Vec<_> = read_messages_in(dir.path(), false)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.body)
+            .collect();
+        assert_eq!(bodies, vec!["first", "second", "third"]);
+    }
+
+    #[test]
+    fn read_messages_same_timestamp_keeps_write_order() {
+        let dir = tempfile::tempdir().unwrap();
+        for body in ["one", "two", "three"] {
+            drop_message_in(dir.path(), &message_at("brain", body, 0)).unwrap();
+        }
+
+        let bodies: Vec<_> = read_messages_in(dir.path(), false)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.body)
+            .collect();
+        assert_eq!(bodies, vec!["one", "two", "three"]);
+    }
+
+    #[test]
+    fn read_messages_without_consume_keeps_files() {
+        let dir = tempfile::tempdir().unwrap();
+        drop_message_in(dir.path(), &message_at("brain", "hello", 0)).unwrap();
+
+        assert_eq!(read_messages_in(dir.path(), false).unwrap().len(), 1);
+        assert_eq!(read_messages_in(dir.path(), false).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn read_messages_consume_deletes_files() {
+        let dir = tempfile::tempdir().unwrap();
+        drop_message_in(dir.path(), &message_at("brain", "hello", 0)).unwrap();
+        drop_message_in(dir.path(), &message_at("brain", "again", 1)).unwrap();
+
+        let first = read_messages_in(dir.path(), true).unwrap();
+        assert_eq!(first.len(), 2);
+        assert!(read_messages_in(dir.path(), true).unwrap().is_empty());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn read_messages_missing_dir_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(
+            read_messages_in(&dir.path().join("messages"), true)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn read_messages_skips_corrupt_and_temp_files() {
+        let dir = tempfile::tempdir().unwrap();
+        drop_message_in(dir.path(), &message_at("brain", "ok", 0)).unwrap();
+        fs::write(dir.path().join("garbage.json"), "not json").unwrap();
+        fs::write(dir.path().join(".drop-partial"), "{").unwrap();
+
+        let messages = read_messages_in(dir.path(), true).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].body, "ok");
+        // Corrupt file is left for inspection
+        assert!(dir.path().join("garbage.json").exists());
+    }
+
+    #[test]
+    fn drop_message_concurrent_writers_do_not_clobber() {
+        let dir = tempfile::tempdir().unwrap();
+        let ts = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+
+        std::thread::scope(|scope| {
+            for writer in 0..8 {
+                let dir = dir.path();
+                scope.spawn(move || {
+                    for i in 0..25 {
+                        // Same timestamp everywhere to force name collisions if any
+                        let msg = DropMessage {
+                            from: format!("w{writer}"),
+                            to: "worker".to_string(),
+                            body: format!("{writer}-{i}"),
+                            ts,
+                        };
+                        drop_message_in(dir, &msg).unwrap();
+                    }
+                });
+            }
+        });
+
+        let messages = read_messages_in(dir.path(), false).unwrap();
+        assert_eq!(messages.len(), 8 * 25);
+        let unique: std::collections::HashSet<_> =
+            messages.iter().map(|m| m.body.as_str()).collect();
+        assert_eq!(unique.len(), 8 * 25);
+    }
+
+    #[test]
+    fn drop_message_round_trips_through_dropbox() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let base = tempfile::tempdir().unwrap();
+        // SAFETY: ENV_LOCK serializes env mutations across fleet + dropbox tests.
+        unsafe { std::env::set_var("KILD_HOME", base.path()) };
+
+        let msg = DropMessage::new("honryu", "feature/auth", "review the PR");
+        drop_message("proj123", "feature/auth", msg.clone()).unwrap();
+        let received = read_messages("proj123", "feature/auth", true).unwrap();
+        let after = read_messages("proj123", "feature/auth", false).unwrap();
+
+        // SAFETY: restoring env; lock still held.
+        unsafe { std::env::remove_var("KILD_HOME") };
+
+        assert_eq!(received, vec![msg]);
+        assert!(after.is_empty());
+        assert!(
+            base.path()
+                .join("fleet/proj123/feature_auth/messages")
+                .is_dir()
+        );
+    }
 }
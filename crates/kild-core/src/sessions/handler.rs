@@ -5,14 +5,24 @@
 //! lib.rs, dispatch.rs, and health/handler.rs.
 
 // Operations
+pub use super::archive::{archive_session, unarchive_session};
+pub use super::batch::create_fleet;
+pub use super::bundle::{SessionBundle, export_session, import_session};
+pub use super::copy_out::copy_out;
 pub use super::create::create_session;
-pub use super::list::{get_session, list_sessions, sync_daemon_session_status};
+pub use super::list::{
+    get_session, list_all_sessions, list_sessions, list_sessions_filtered,
+    sync_daemon_session_status,
+};
 pub use super::open::open_session;
+pub use super::rename::rename_session;
+pub use super::restart::restart_session;
 pub use super::stop::{stop_session, stop_teammate};
+pub use super::tags::{add_tag, list_sessions_by_tag, remove_tag};
 
 // Re-export from previously extracted modules
 pub use super::agent_status::{
     AgentStatusResult, find_session_by_worktree_path, read_agent_status, update_agent_status,
 };
-pub use super::complete::{complete_session, fetch_pr_info, read_pr_info};
+pub use super::complete::{complete_session, create_pr, fetch_pr_info, read_pr_info};
 pub use super::destroy::{destroy_session, get_destroy_safety_info, has_remote_configured};
@@ -2,16 +2,25 @@
 //!
 //! Provides `SessionSnapshot`, which combines a `Session` with computed
 //! process status, git status, and diff statistics. This is the enriched
-//! view of a session used by UI and CLI consumers.
+//! view of a session used by UI and CLI consumers. `diff_snapshots` compares
+//! two refreshes so consumers can update only the rows that changed.
 
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::path::Path;
 
-use crate::git::get_diff_stats;
+use kild_protocol::SessionId;
+
 use crate::git::types::DiffStats;
-use crate::process::is_process_running;
+use crate::git::{daily_commit_counts, get_diff_stats};
+use crate::process::{ProcessMetadata, get_process_info, is_process_running};
 use crate::sessions::types::{GitStatus, ProcessStatus, Session};
 use crate::terminal::is_terminal_window_open;
 
+/// Number of days covered by `SessionSnapshot::commit_activity`.
+pub const COMMIT_ACTIVITY_DAYS: usize = 14;
+
 /// Enriched session data combining a `Session` with computed status fields.
 ///
 /// Created via `SessionSnapshot::from_session()`, which runs process detection,
@@ -28,13 +37,24 @@ pub struct SessionSnapshot {
     pub process_status: ProcessStatus,
     pub git_status: GitStatus,
     pub uncommitted_diff: Option<DiffStats>,
+    /// Installed CLI version of the session's agent (cached per binary).
+    pub agent_version: Option<String>,
+    /// CPU/memory sample of the first running PID-tracked agent.
+    pub resource_usage: Option<ProcessMetadata>,
+    /// Commits per day over the last [`COMMIT_ACTIVITY_DAYS`] days, oldest first.
+    /// Empty when the worktree is missing or its history can't be read.
+    pub commit_activity: Vec<u32>,
 }
 
 impl SessionSnapshot {
     /// Create a `SessionSnapshot` by enriching a `Session` with computed status.
     ///
-    /// Runs process detection, git status check, and diff stat computation.
-    pub fn from_session(session: Session) -> Self {
+    /// Runs process detection, git status check, diff stat computation,
+    /// commit activity bucketing, and agent version lookup. The version comes
+    /// from the probe cache only, so a slow or hanging agent CLI never stalls
+    /// a UI refresh; it fills in on a later refresh once the background probe
+    /// completes. `config` resolves config-defined agents to their binary.
+    pub fn from_session(session: Session, config: &kild_config::KildConfig) -> Self {
         let process_status = determine_process_status(&session);
 
         let git_status = if session.worktree_path.exists() {
@@ -58,11 +78,31 @@ impl SessionSnapshot {
             None
         };
 
+        let commit_activity = if session.worktree_path.exists() {
+            daily_commit_counts(
+                &session.worktree_path,
+                COMMIT_ACTIVITY_DAYS,
+                chrono::Utc::now(),
+            )
+        } else {
+            Vec::new()
+        };
+
+        let agent_version = crate::agents::cached_agent_version(config, &session.agent);
+        let resource_usage = if process_status == ProcessStatus::Running {
+            sample_resource_usage(&session)
+        } else {
+            None
+        };
+
         Self {
             session,
             process_status,
             git_status,
             uncommitted_diff,
+            agent_version,
+            resource_usage,
+            commit_activity,
         }
     }
 }
@@ -161,6 +201,18 @@ pub fn determine_process_status(session: &Session) -> ProcessStatus {
     ProcessStatus::Stopped
 }
 
+/// Sample CPU/memory of the first agent whose tracked PID is still alive.
+///
+/// Daemon-managed and window-only agents have no PID here and yield `None`.
+fn sample_resource_usage(session: &Session) -> Option<ProcessMetadata> {
+    session
+        .agents()
+        .iter()
+        .filter_map(|agent_proc| agent_proc.process_id())
+        .find_map(|pid| get_process_info(pid).ok())
+        .map(|info| ProcessMetadata::from(&info))
+}
+
 /// Check if a worktree has uncommitted changes.
 ///
 /// Returns `GitStatus::Dirty` if there are uncommitted changes,
@@ -174,6 +226,96 @@ fn check_git_status(worktree_path: &Path) -> GitStatus {
     }
 }
 
+/// A per-session difference between two snapshot lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotChange {
+    Added(SessionId),
+    Removed(SessionId),
+    Changed(SessionId),
+}
+
+/// Hash a snapshot's stable fields, for cheap change detection between refreshes.
+///
+/// Volatile resource usage samples are excluded, so a CPU or memory change
+/// alone never counts as a change.
+///
+/// Only stable within a single process — don't persist the value.
+pub fn snapshot_hash(snapshot: &SessionSnapshot) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    // Session has no Hash impl (nested agent records); its JSON form covers every field.
+    match serde_json::to_vec(&snapshot.session) {
+        Ok(bytes) => bytes.hash(&mut hasher),
+        Err(_) => snapshot.session.id.hash(&mut hasher),
+    }
+    snapshot.process_status.hash(&mut hasher);
+    snapshot.git_status.hash(&mut hasher);
+    snapshot.uncommitted_diff.hash(&mut hasher);
+    snapshot.agent_version.hash(&mut hasher);
+    // resource_usage is left out: CPU and memory move on every sample, so
+    // hashing them would mark every running session changed on every refresh
+    snapshot.commit_activity.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Compare two snapshot lists by session id.
+///
+/// Returns `Added`/`Changed` in `new` order, followed by `Removed` in `old`
+/// order. Empty when nothing changed.
+pub fn diff_snapshots(old: &[SessionSnapshot], new: &[SessionSnapshot]) -> Vec<SnapshotChange> {
+    let old_hashes: HashMap<&SessionId, u64> = old
+        .iter()
+        .map(|s| (&s.session.id, snapshot_hash(s)))
+        .collect();
+    let new_ids: HashSet<&SessionId> = new.iter().map(|s| &s.session.id).collect();
+
+    let mut changes: Vec<SnapshotChange> = new
+        .iter()
+        .filter_map(|snapshot| {
+            let id = &snapshot.session.id;
+            match old_hashes.get(id) {
+                None => Some(SnapshotChange::Added(id.clone())),
+                Some(&hash) if hash != snapshot_hash(snapshot) => {
+                    Some(SnapshotChange::Changed(id.clone()))
+                }
+                Some(_) => None,
+            }
+        })
+        .collect();
+
+    changes.extend(
+        old.iter()
+            .filter(|s| !new_ids.contains(&s.session.id))
+            .map(|s| SnapshotChange::Removed(s.session.id.clone())),
+    );
+
+    changes
+}
+
+/// Test utilities for building snapshots without running detection.
+///
+/// Public so downstream crates (kild-ui) can create test fixtures.
+#[doc(hidden)]
+pub mod test_helpers {
+    use super::*;
+
+    /// A snapshot of `session` with every computed field at its "nothing
+    /// known" value: stopped, unknown git status, no diff, version or usage.
+    ///
+    /// Override fields with struct update syntax:
+    /// `SessionSnapshot { process_status: ProcessStatus::Running, ..make_test_snapshot(session) }`
+    pub fn make_test_snapshot(session: Session) -> SessionSnapshot {
+        SessionSnapshot {
+            session,
+            process_status: ProcessStatus::Stopped,
+            git_status: GitStatus::Unknown,
+            uncommitted_diff: None,
+            agent_version: None,
+            resource_usage: None,
+            commit_activity: Vec::new(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,7 +367,7 @@ mod tests {
     #[test]
     fn test_from_session_nonexistent_path() {
         let session = make_session(PathBuf::from("/tmp/nonexistent-test-path"));
-        let info = SessionSnapshot::from_session(session);
+        let info = SessionSnapshot::from_session(session, &kild_config::KildConfig::default());
         assert_eq!(info.process_status, ProcessStatus::Stopped);
         assert_eq!(info.git_status, GitStatus::Unknown);
     }
@@ -475,12 +617,89 @@ mod tests {
         std::fs::write(path.join("test.txt"), "line1\nline2\nline3\n").unwrap();
 
         let session = make_session(path.to_path_buf());
-        let info = SessionSnapshot::from_session(session);
+        let info = SessionSnapshot::from_session(session, &kild_config::KildConfig::default());
 
         assert_eq!(info.git_status, GitStatus::Dirty);
         assert!(info.uncommitted_diff.is_some());
         let stats = info.uncommitted_diff.unwrap();
         assert_eq!(stats.insertions, 2);
         assert_eq!(stats.files_changed, 1);
+
+        // The commit just made lands in today's bucket
+        assert_eq!(info.commit_activity.len(), COMMIT_ACTIVITY_DAYS);
+        assert_eq!(info.commit_activity.last(), Some(&1));
+    }
+
+    fn make_snapshot(id: &str) -> SessionSnapshot {
+        let mut session = make_session(PathBuf::from("/tmp/nonexistent"));
+        session.id = id.into();
+        SessionSnapshot {
+            git_status: GitStatus::Clean,
+            ..test_helpers::make_test_snapshot(session)
+        }
+    }
+
+    #[test]
+    fn test_snapshot_hash_is_stable_and_field_sensitive() {
+        let a = make_snapshot("a");
+        assert_eq!(snapshot_hash(&a), snapshot_hash(&a.clone()));
+
+        let mut running = a.clone();
+        running.process_status = ProcessStatus::Running;
+        assert_ne!(snapshot_hash(&a), snapshot_hash(&running));
+
+        let mut noted = a.clone();
+        noted.session.note = Some("note".to_string());
+        assert_ne!(snapshot_hash(&a), snapshot_hash(&noted));
+
+        let mut sampled = a.clone();
+        sampled.resource_usage = Some(ProcessMetadata {
+            name: "claude".to_string(),
+            start_time: 0,
+            cpu_percent: 50.0,
+            memory_bytes: 1_024,
+        });
+        assert_eq!(snapshot_hash(&a), snapshot_hash(&sampled));
+
+        let mut active = a.clone();
+        active.commit_activity = vec![0, 2, 1];
+        assert_ne!(snapshot_hash(&a), snapshot_hash(&active));
+    }
+
+    #[test]
+    fn test_diff_snapshots_no_changes_is_empty() {
+        let list = vec![make_snapshot("a"), make_snapshot("b")];
+        assert!(diff_snapshots(&list, &list.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_snapshots_added() {
+        let old = vec![make_snapshot("a")];
+        let new = vec![make_snapshot("a"), make_snapshot("b")];
+        assert_eq!(
+            diff_snapshots(&old, &new),
+            vec![SnapshotChange::Added("b".into())]
+        );
+    }
+
+    #[test]
+    fn test_diff_snapshots_removed() {
+        let old = vec![make_snapshot("a"), make_snapshot("b")];
+        let new = vec![make_snapshot("b")];
+        assert_eq!(
+            diff_snapshots(&old, &new),
+            vec![SnapshotChange::Removed("a".into())]
+        );
+    }
+
+    #[test]
+    fn test_diff_snapshots_changed() {
+        let old = vec![make_snapshot("a"), make_snapshot("b")];
+        let mut new = old.clone();
+        new[1].git_status = GitStatus::Dirty;
+        assert_eq!(
+            diff_snapshots(&old, &new),
+            vec![SnapshotChange::Changed("b".into())]
+        );
     }
 }
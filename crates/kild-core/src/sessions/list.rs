@@ -1,14 +1,38 @@
+use std::path::Path;
+
 use tracing::{error, info, warn};
 
 use crate::daemon::client::DaemonClientError;
+use crate::process::is_process_running;
 use crate::sessions::{errors::SessionError, persistence, types::*};
 use kild_config::Config;
 
-pub fn list_sessions() -> Result<Vec<Session>, SessionError> {
-    info!(event = "core.session.list_started");
+/// List sessions, hiding archived ones. See [`list_all_sessions`] to include them.
+///
+/// With `reconcile`, active sessions whose agent processes have all exited are
+/// corrected to stopped on disk (see [`reconcile_process_status`]). Callers that
+/// want the stored data untouched pass `false`.
+pub fn list_sessions(reconcile: bool) -> Result<Vec<Session>, SessionError> {
+    load_sessions(&Config::new().sessions_dir(), false, reconcile)
+}
 
-    let config = Config::new();
-    let (sessions, skipped_count) = persistence::load_sessions_from_files(&config.sessions_dir())?;
+/// List every session, including archived ones.
+pub fn list_all_sessions(reconcile: bool) -> Result<Vec<Session>, SessionError> {
+    load_sessions(&Config::new().sessions_dir(), true, reconcile)
+}
+
+fn load_sessions(
+    sessions_dir: &Path,
+    include_archived: bool,
+    reconcile: bool,
+) -> Result<Vec<Session>, SessionError> {
+    info!(
+        event = "core.session.list_started",
+        include_archived = include_archived,
+        reconcile = reconcile
+    );
+
+    let (sessions, skipped_count) = persistence::load_sessions_from_files(sessions_dir)?;
 
     if skipped_count > 0 {
         tracing::warn!(
@@ -18,6 +42,13 @@ pub fn list_sessions() -> Result<Vec<Session>, SessionError> {
         );
     }
 
+    let mut sessions = visible_sessions(sessions, include_archived);
+    if reconcile {
+        for session in &mut sessions {
+            reconcile_process_status(session, sessions_dir);
+        }
+    }
+
     info!(
         event = "core.session.list_completed",
         count = sessions.len()
@@ -26,6 +57,191 @@ pub fn list_sessions() -> Result<Vec<Session>, SessionError> {
     Ok(sessions)
 }
 
+fn visible_sessions(sessions: Vec<Session>, include_archived: bool) -> Vec<Session> {
+    if include_archived {
+        return sessions;
+    }
+    sessions
+        .into_iter()
+        .filter(|s| s.status != SessionStatus::Archived)
+        .collect()
+}
+
+/// Session field tested by a [`FilterPredicate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterField {
+    Agent,
+    /// Session status (`active`, `stopped`) or agent activity (`working`, `idle`, ...).
+    Status,
+    Branch,
+    Tag,
+    Note,
+}
+
+impl FilterField {
+    const VALID_KEYS: &'static str = "agent, status, branch, tag, note";
+
+    fn parse(key: &str) -> Option<Self> {
+        match key {
+            "agent" => Some(Self::Agent),
+            "status" => Some(Self::Status),
+            "branch" => Some(Self::Branch),
+            "tag" => Some(Self::Tag),
+            "note" => Some(Self::Note),
+            _ => None,
+        }
+    }
+}
+
+/// Comparison applied by a [`FilterPredicate`]. Both are case-insensitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    /// `key=value`
+    Equals,
+    /// `key~value` (substring)
+    Contains,
+}
+
+impl FilterOp {
+    fn test(self, actual: &str, expected: &str) -> bool {
+        match self {
+            Self::Equals => actual.eq_ignore_ascii_case(expected),
+            Self::Contains => actual.to_lowercase().contains(&expected.to_lowercase()),
+        }
+    }
+}
+
+/// A single `key=value` or `key~value` term of a [`SessionFilter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterPredicate {
+    pub field: FilterField,
+    pub op: FilterOp,
+    pub value: String,
+}
+
+impl FilterPredicate {
+    fn matches(&self, session: &Session, agent_status: Option<AgentStatus>) -> bool {
+        match self.field {
+            FilterField::Agent => self.op.test(&session.agent, &self.value),
+            FilterField::Status => {
+                let session_status = format!("{:?}", session.status).to_lowercase();
+                self.op.test(&session_status, &self.value)
+                    || agent_status.is_some_and(|s| self.op.test(&s.to_string(), &self.value))
+            }
+            FilterField::Branch => self.op.test(&session.branch, &self.value),
+            FilterField::Tag => session.tags.iter().any(|t| self.op.test(t, &self.value)),
+            FilterField::Note => session
+                .note
+                .as_deref()
+                .is_some_and(|n| self.op.test(n, &self.value)),
+        }
+    }
+}
+
+/// Session query parsed from whitespace-separated predicates, e.g.
+/// `agent=claude status=working branch~auth`.
+///
+/// All predicates must match (AND semantics). An empty filter matches everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionFilter {
+    predicates: Vec<FilterPredicate>,
+}
+
+impl SessionFilter {
+    pub fn predicates(&self) -> &[FilterPredicate] {
+        &self.predicates
+    }
+
+    /// Check a session against every predicate.
+    ///
+    /// `agent_status` is the session's sidecar activity status, consulted by `status=`.
+    pub fn matches(&self, session: &Session, agent_status: Option<AgentStatus>) -> bool {
+        self.predicates
+            .iter()
+            .all(|p| p.matches(session, agent_status))
+    }
+
+    fn needs_agent_status(&self) -> bool {
+        self.predicates
+            .iter()
+            .any(|p| p.field == FilterField::Status)
+    }
+}
+
+impl std::str::FromStr for SessionFilter {
+    type Err = SessionError;
+
+    fn from_str(expr: &str) -> Result<Self, Self::Err> {
+        let invalid = |message: String| SessionError::InvalidFilter { message };
+
+        let predicates = expr
+            .split_whitespace()
+            .map(|term| {
+                let (idx, op) = term
+                    .char_indices()
+                    .find_map(|(i, c)| match c {
+                        '=' => Some((i, FilterOp::Equals)),
+                        '~' => Some((i, FilterOp::Contains)),
+                        _ => None,
+                    })
+                    .ok_or_else(|| invalid(format!("'{term}' must be key=value or key~value")))?;
+
+                let (key, value) = (&term[..idx], &term[idx + 1..]);
+                let field = FilterField::parse(key).ok_or_else(|| {
+                    invalid(format!(
+                        "unknown key '{key}' (valid: {})",
+                        FilterField::VALID_KEYS
+                    ))
+                })?;
+                if value.is_empty() {
+                    return Err(invalid(format!("'{term}' has an empty value")));
+                }
+
+                Ok(FilterPredicate {
+                    field,
+                    op,
+                    value: value.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { predicates })
+    }
+}
+
+/// List sessions matching `filter`. Archived sessions are skipped unless `include_archived`.
+///
+/// `reconcile` behaves as in [`list_sessions`] and runs before filtering, so
+/// `status=` predicates see corrected statuses.
+pub fn list_sessions_filtered(
+    filter: &SessionFilter,
+    include_archived: bool,
+    reconcile: bool,
+) -> Result<Vec<Session>, SessionError> {
+    let sessions_dir = Config::new().sessions_dir();
+    let sessions = load_sessions(&sessions_dir, include_archived, reconcile)?;
+
+    let matched: Vec<Session> = sessions
+        .into_iter()
+        .filter(|session| {
+            let agent_status = if filter.needs_agent_status() {
+                persistence::read_agent_status(&sessions_dir, &session.id).map(|r| r.status)
+            } else {
+                None
+            };
+            filter.matches(session, agent_status)
+        })
+        .collect();
+
+    info!(
+        event = "core.session.list_filtered_completed",
+        predicates = filter.predicates().len(),
+        count = matched.len()
+    );
+
+    Ok(matched)
+}
+
 pub fn get_session(name: &str) -> Result<Session, SessionError> {
     info!(event = "core.session.get_started", name = name);
 
@@ -41,6 +257,58 @@ pub fn get_session(name: &str) -> Result<Session, SessionError> {
     Ok(session)
 }
 
+/// Correct an active terminal session to stopped when all its agent PIDs are dead.
+///
+/// Only sessions where every agent has a recorded PID are checked; daemon
+/// agents are handled by [`sync_daemon_session_status`], and agents tracked
+/// only by terminal window can't be confirmed dead. A failed process check
+/// counts as "maybe running".
+///
+/// Returns `true` if the session status was changed to `Stopped`.
+fn reconcile_process_status(session: &mut Session, sessions_dir: &Path) -> bool {
+    if session.status != SessionStatus::Active || !session.has_agents() {
+        return false;
+    }
+
+    let mut pids = Vec::with_capacity(session.agents().len());
+    for agent in session.agents() {
+        match agent.process_id() {
+            Some(pid) if agent.daemon_session_id().is_none() => pids.push(pid),
+            _ => return false,
+        }
+    }
+    if pids
+        .iter()
+        .any(|&pid| !matches!(is_process_running(pid), Ok(false)))
+    {
+        return false;
+    }
+
+    info!(
+        event = "core.session.process_status_reconciled",
+        session_id = %session.id,
+        pids = ?pids,
+        "All agent processes have exited; marking session as stopped"
+    );
+
+    session.status = SessionStatus::Stopped;
+    if let Err(e) = persistence::patch_session_json_field(
+        sessions_dir,
+        &session.id,
+        "status",
+        serde_json::json!("stopped"),
+    ) {
+        error!(
+            event = "core.session.process_status_reconcile_save_failed",
+            session_id = %session.id,
+            error = %e,
+            "Failed to persist reconciled status"
+        );
+    }
+
+    true
+}
+
 /// Sync a session's status with the daemon if it has a daemon-managed agent.
 ///
 /// When a daemon PTY exits naturally (or the daemon crashes), the kild-core session
@@ -174,6 +442,173 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
 
+    fn filter(expr: &str) -> SessionFilter {
+        expr.parse().unwrap()
+    }
+
+    fn tagged_session(branch: &str, agent: &str, status: SessionStatus) -> Session {
+        let mut session = Session::new_for_test(branch, PathBuf::from("/tmp/test"));
+        session.agent = agent.to_string();
+        session.status = status;
+        session.tags = vec!["backend".to_string()];
+        session.note = Some("Rework OAuth flow".to_string());
+        session
+    }
+
+    #[test]
+    fn test_visible_sessions_hides_archived_by_default() {
+        let sessions = vec![
+            tagged_session("running", "claude", SessionStatus::Active),
+            tagged_session("parked", "claude", SessionStatus::Archived),
+            tagged_session("paused", "claude", SessionStatus::Stopped),
+        ];
+
+        let branches = |sessions: Vec<Session>| -> Vec<String> {
+            sessions.iter().map(|s| s.branch.to_string()).collect()
+        };
+        assert_eq!(
+            branches(visible_sessions(sessions.clone(), false)),
+            vec!["running", "paused"]
+        );
+        assert_eq!(
+            branches(visible_sessions(sessions, true)),
+            vec!["running", "parked", "paused"]
+        );
+    }
+
+    #[test]
+    fn test_session_filter_equals_operator() {
+        let session = tagged_session("feature-auth", "claude", SessionStatus::Active);
+
+        assert!(filter("agent=claude").matches(&session, None));
+        assert!(filter("agent=Claude").matches(&session, None));
+        assert!(!filter("agent=codex").matches(&session, None));
+        assert!(filter("branch=feature-auth").matches(&session, None));
+        assert!(!filter("branch=auth").matches(&session, None));
+        assert!(filter("tag=backend").matches(&session, None));
+        assert!(!filter("tag=frontend").matches(&session, None));
+    }
+
+    #[test]
+    fn test_session_filter_contains_operator() {
+        let session = tagged_session("feature-auth", "claude", SessionStatus::Active);
+
+        assert!(filter("branch~auth").matches(&session, None));
+        assert!(!filter("branch~billing").matches(&session, None));
+        assert!(filter("note~oauth").matches(&session, None));
+        assert!(filter("tag~back").matches(&session, None));
+        assert!(filter("agent~cla").matches(&session, None));
+    }
+
+    #[test]
+    fn test_session_filter_status_matches_session_and_agent_status() {
+        let session = tagged_session("feature-auth", "claude", SessionStatus::Active);
+
+        assert!(filter("status=active").matches(&session, None));
+        assert!(!filter("status=stopped").matches(&session, None));
+        assert!(filter("status=working").matches(&session, Some(AgentStatus::Working)));
+        assert!(!filter("status=working").matches(&session, Some(AgentStatus::Idle)));
+        assert!(!filter("status=working").matches(&session, None));
+    }
+
+    #[test]
+    fn test_session_filter_combines_predicates_with_and() {
+        let session = tagged_session("feature-auth", "claude", SessionStatus::Active);
+        let expr = "agent=claude status=working branch~auth";
+
+        assert!(filter(expr).matches(&session, Some(AgentStatus::Working)));
+        assert!(!filter(expr).matches(&session, Some(AgentStatus::Idle)));
+        assert!(!filter("agent=claude branch~billing").matches(&session, None));
+        assert_eq!(filter(expr).predicates().len(), 3);
+    }
+
+    #[test]
+    fn test_session_filter_empty_matches_everything() {
+        let session = tagged_session("feature-auth", "claude", SessionStatus::Stopped);
+        assert!(filter("").matches(&session, None));
+    }
+
+    #[test]
+    fn test_session_filter_rejects_unknown_key() {
+        let err = "owner=me".parse::<SessionFilter>().unwrap_err();
+        assert!(matches!(err, SessionError::InvalidFilter { .. }));
+        assert!(err.to_string().contains("unknown key 'owner'"));
+    }
+
+    #[test]
+    fn test_session_filter_rejects_malformed_terms() {
+        for expr in ["agent", "agent=", "branch~"] {
+            let result = expr.parse::<SessionFilter>();
+            assert!(
+                matches!(result, Err(SessionError::InvalidFilter { .. })),
+                "'{expr}' should be rejected"
+            );
+        }
+    }
+
+    /// Save an active terminal session whose single agent has `pid`.
+    fn save_active_session_with_pid(sessions_dir: &Path, branch: &str, pid: u32) -> Session {
+        let mut session = Session::new_for_test(branch, PathBuf::from("/tmp/test"));
+        session.status = SessionStatus::Active;
+        session.add_agent(
+            AgentProcess::new(
+                "claude".to_string(),
+                format!("{branch}_0"),
+                Some(pid),
+                Some("claude".to_string()),
+                Some(0),
+                None,
+                None,
+                "claude".to_string(),
+                chrono::Utc::now().to_rfc3339(),
+                None,
+            )
+            .unwrap(),
+        );
+        persistence::save_session_to_file(&session, sessions_dir).unwrap();
+        session
+    }
+
+    /// PID of a child process that has already exited and been reaped.
+    fn dead_pid() -> u32 {
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let pid = child.id();
+        child.wait().unwrap();
+        pid
+    }
+
+    #[test]
+    fn test_list_reconcile_marks_dead_process_stopped() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let session = save_active_session_with_pid(temp.path(), "dead-agent", dead_pid());
+
+        let sessions = load_sessions(temp.path(), false, true).unwrap();
+        assert_eq!(sessions[0].status, SessionStatus::Stopped);
+
+        let stored = persistence::find_session_by_id(temp.path(), &session.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status, SessionStatus::Stopped);
+    }
+
+    #[test]
+    fn test_list_without_reconcile_returns_stored_status() {
+        let temp = tempfile::TempDir::new().unwrap();
+        save_active_session_with_pid(temp.path(), "dead-agent", dead_pid());
+
+        let sessions = load_sessions(temp.path(), false, false).unwrap();
+        assert_eq!(sessions[0].status, SessionStatus::Active);
+    }
+
+    #[test]
+    fn test_list_reconcile_keeps_live_process_active() {
+        let temp = tempfile::TempDir::new().unwrap();
+        save_active_session_with_pid(temp.path(), "live-agent", std::process::id());
+
+        let sessions = load_sessions(temp.path(), false, true).unwrap();
+        assert_eq!(sessions[0].status, SessionStatus::Active);
+    }
+
     #[test]
     fn test_sync_daemon_skips_stopped_sessions() {
         let mut session = Session::new(
@@ -1,6 +1,10 @@
 pub mod agent_status;
+pub mod archive;
 mod attach;
+pub mod batch;
+pub mod bundle;
 pub mod complete;
+pub mod copy_out;
 pub mod create;
 pub mod daemon_helpers;
 mod daemon_request;
@@ -17,21 +21,27 @@ pub mod list;
 pub mod open;
 pub mod persistence;
 pub mod ports;
+pub mod rename;
+pub mod restart;
 mod shim_cleanup;
 pub(super) mod shim_init;
 mod shim_setup;
 pub mod stop;
 pub mod store;
+pub mod tags;
 pub mod types;
 pub mod validation;
 
 // Re-export commonly used types and functions
 pub use agent_status::{find_session_by_worktree_path, read_agent_status, update_agent_status};
-pub use complete::{complete_session, fetch_pr_info, read_pr_info};
+pub use complete::{complete_session, create_pr, fetch_pr_info, read_pr_info};
 pub use destroy::{destroy_session, get_destroy_safety_info, has_remote_configured};
-pub use errors::SessionError;
+pub use errors::{FleetError, SessionError};
 pub use handler::{create_session, get_session, list_sessions, open_session, stop_session};
-pub use info::SessionSnapshot;
+pub use info::{
+    COMMIT_ACTIVITY_DAYS, SessionSnapshot, SnapshotChange, diff_snapshots, snapshot_hash,
+};
+pub use list::SessionFilter;
 pub use types::{
     AgentProcess, AgentStatus, AgentStatusRecord, CompleteRequest, CompleteResult,
     CreateSessionRequest, DestroySafety, GitStatus, ProcessStatus, Session, SessionStatus,
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use tracing::{error, info, warn};
 
 use crate::agents;
@@ -63,7 +65,34 @@ pub fn open_session(
     );
 
     let config = Config::new();
-    let kild_config = match KildConfig::load_hierarchy() {
+    let kild_config = load_kild_config();
+
+    let session = open_session_in(
+        &config.sessions_dir(),
+        &kild_config,
+        name,
+        mode,
+        runtime_mode,
+        resume,
+        yolo,
+        no_attach,
+        initial_prompt,
+        spawn_agent,
+    )?;
+
+    crate::events::publish(crate::state::Event::KildOpened {
+        branch: session.branch.clone(),
+        agent: session
+            .latest_agent()
+            .map_or_else(|| session.agent.clone(), |a| a.agent().to_string()),
+    });
+
+    Ok(session)
+}
+
+/// Load the config hierarchy, warning and falling back to defaults on failure.
+pub(super) fn load_kild_config() -> KildConfig {
+    match KildConfig::load_hierarchy() {
         Ok(config) => config,
         Err(e) => {
             // Notify user via stderr - this is a developer tool, they need to know
@@ -76,15 +105,44 @@ pub fn open_session(
             );
             KildConfig::default()
         }
-    };
+    }
+}
+
+/// Spawn an agent in a daemon PTY or an external terminal.
+pub(super) fn spawn_agent(
+    params: &AgentSpawnParams<'_>,
+    use_daemon: bool,
+) -> Result<AgentProcess, SessionError> {
+    if use_daemon {
+        spawn_daemon_agent(params)
+    } else {
+        spawn_terminal_agent(params)
+    }
+}
 
+/// Opens a new agent in a kild stored in `sessions_dir`, spawning it with `spawn`.
+///
+/// Shared by [`open_session`] and `restart_session`. The worktree must already
+/// exist; this never creates one.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn open_session_in(
+    sessions_dir: &Path,
+    kild_config: &KildConfig,
+    name: &str,
+    mode: OpenMode,
+    runtime_mode: Option<RuntimeMode>,
+    resume: bool,
+    yolo: bool,
+    no_attach: bool,
+    initial_prompt: Option<&str>,
+    spawn: impl FnOnce(&AgentSpawnParams<'_>, bool) -> Result<AgentProcess, SessionError>,
+) -> Result<Session, SessionError> {
     // 1. Find session by name (branch name)
-    let mut session =
-        persistence::find_session_by_name(&config.sessions_dir(), name)?.ok_or_else(|| {
-            SessionError::NotFound {
-                name: name.to_string(),
-            }
-        })?;
+    let mut session = persistence::find_session_by_name(sessions_dir, name)?.ok_or_else(|| {
+        SessionError::NotFound {
+            name: name.to_string(),
+        }
+    })?;
 
     info!(
         event = "core.session.open_found",
@@ -171,7 +229,9 @@ pub fn open_session(
                 info!(event = "core.session.open_agent_selected", agent = name);
 
                 // Warn if agent CLI is not available in PATH
-                if let Some(false) = agents::is_agent_available(&name) {
+                if let Some(false) =
+                    agents::resolve_agent(kild_config, &name).map(|a| a.backend().is_available())
+                {
                     warn!(
                         event = "core.session.agent_not_available",
                         agent = %name,
@@ -207,7 +267,9 @@ pub fn open_session(
                 info!(event = "core.session.open_agent_selected", agent = agent);
 
                 // Warn if agent CLI is not available in PATH
-                if let Some(false) = agents::is_agent_available(&agent) {
+                if let Some(false) =
+                    agents::resolve_agent(kild_config, &agent).map(|a| a.backend().is_available())
+                {
                     warn!(
                         event = "core.session.agent_not_available",
                         agent = %agent,
@@ -228,11 +290,13 @@ pub fn open_session(
 
     // 3b. Inject yolo flags into agent command
     let agent_command = if yolo && !is_bare_shell {
-        if let Some(yolo_flags) = agents::get_yolo_flags(&agent) {
+        let yolo_flags = agents::resolve_agent(kild_config, &agent)
+            .and_then(|a| a.backend().yolo_flags().map(str::to_string));
+        if let Some(yolo_flags) = yolo_flags {
             info!(
                 event = "core.session.yolo_flags_injected",
                 agent = %agent,
-                flags = yolo_flags
+                flags = %yolo_flags
             );
             format!("{} {}", agent_command, yolo_flags)
         } else {
@@ -309,7 +373,7 @@ pub fn open_session(
     );
 
     let (effective_runtime_mode, source) =
-        resolve_effective_runtime_mode(runtime_mode, session.runtime_mode.clone(), &kild_config);
+        resolve_effective_runtime_mode(runtime_mode, session.runtime_mode.clone(), kild_config);
 
     info!(
         event = "core.session.open_runtime_mode_resolved",
@@ -328,28 +392,22 @@ pub fn open_session(
         spawn_id: &spawn_id,
         task_list_id: new_task_list_id.as_deref(),
         project_id: &session.project_id,
-        kild_config: &kild_config,
+        kild_config,
     };
 
-    let new_agent = if use_daemon {
-        let agent_process = spawn_daemon_agent(&spawn_params)?;
-
-        // Open-only: deliver initial prompt after spawn.
-        // Fleet claude sessions skip PTY delivery — dropbox task.md + Claude inbox is more reliable.
-        if let Some(prompt) = initial_prompt {
-            deliver_initial_prompt_for_session(
-                &session.project_id,
-                &session.branch,
-                &agent,
-                agent_process.daemon_session_id(),
-                prompt,
-            );
-        }
-
-        agent_process
-    } else {
-        spawn_terminal_agent(&spawn_params)?
-    };
+    let new_agent = spawn(&spawn_params, use_daemon)?;
+
+    // Open-only: deliver initial prompt after spawn.
+    // Fleet claude sessions skip PTY delivery — dropbox task.md + Claude inbox is more reliable.
+    if use_daemon && let Some(prompt) = initial_prompt {
+        deliver_initial_prompt_for_session(
+            &session.project_id,
+            &session.branch,
+            &agent,
+            new_agent.daemon_session_id(),
+            prompt,
+        );
+    }
 
     let now = chrono::Utc::now().to_rfc3339();
     session.status = SessionStatus::Active;
@@ -379,13 +437,13 @@ pub fn open_session(
     session.runtime_mode = Some(effective_runtime_mode);
 
     // 6. Save session BEFORE spawning attach window so `kild attach` can find it
-    persistence::save_session_to_file(&session, &config.sessions_dir())?;
+    persistence::save_session_to_file(&session, sessions_dir)?;
 
     // 7. Spawn attach window (best-effort) and update session with terminal info.
     // Skipped when no_attach is set — for programmatic opens (e.g. brain reopening workers)
     // where a Ghostty window popping up is undesirable.
     if is_daemon && !no_attach {
-        spawn_and_save_attach_window(&mut session, name, &kild_config, &config.sessions_dir())?;
+        spawn_and_save_attach_window(&mut session, name, kild_config, sessions_dir)?;
     }
 
     info!(
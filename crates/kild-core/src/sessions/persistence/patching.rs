@@ -7,7 +7,7 @@ use std::fs;
 use std::path::Path;
 
 use super::session_files::{
-    cleanup_temp_file, migrate_session_if_needed, session_dir, session_file,
+    cleanup_temp_file, legacy_safe_id, migrate_session_if_needed, session_dir, session_file,
 };
 
 /// Patch a single field in a session JSON file without deserializing into Session.
@@ -21,11 +21,10 @@ pub fn patch_session_json_field(
     field: &str,
     value: serde_json::Value,
 ) -> Result<(), SessionError> {
-    let safe_id = session_id.replace('/', "_");
-    let dir = session_dir(sessions_dir, session_id);
-    if !dir.join("kild.json").exists() {
-        migrate_session_if_needed(sessions_dir, &safe_id)?;
+    if !session_file(sessions_dir, session_id).exists() {
+        migrate_session_if_needed(sessions_dir, &legacy_safe_id(session_id))?;
     }
+    let dir = session_dir(sessions_dir, session_id);
     let file = session_file(sessions_dir, session_id);
     let content = fs::read_to_string(&file).map_err(|e| SessionError::IoError { source: e })?;
     let mut json: serde_json::Value =
@@ -67,11 +66,10 @@ pub fn patch_session_json_fields(
     session_id: &str,
     fields: &[(&str, serde_json::Value)],
 ) -> Result<(), SessionError> {
-    let safe_id = session_id.replace('/', "_");
-    let dir = session_dir(sessions_dir, session_id);
-    if !dir.join("kild.json").exists() {
-        migrate_session_if_needed(sessions_dir, &safe_id)?;
+    if !session_file(sessions_dir, session_id).exists() {
+        migrate_session_if_needed(sessions_dir, &legacy_safe_id(session_id))?;
     }
+    let dir = session_dir(sessions_dir, session_id);
     let file = session_file(sessions_dir, session_id);
     let content = fs::read_to_string(&file).map_err(|e| SessionError::IoError { source: e })?;
     let mut json: serde_json::Value =
@@ -3,13 +3,68 @@
 //! Handles reading/writing session data to disk with atomic operations.
 
 use crate::sessions::{errors::SessionError, types::*};
+use kild_paths::KildPaths;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-/// Compute session directory path: `<sessions_dir>/<safe_id>/`.
+/// Compute session directory path: `<sessions_dir>/<encoded_id>/`.
+///
+/// The id is encoded with `KildPaths::encode_session_id()`. Pure: directories
+/// still under the older `/` → `_` name are moved by `load_sessions_from_files()`.
 pub(super) fn session_dir(sessions_dir: &Path, session_id: &str) -> PathBuf {
-    let safe_id = session_id.replace('/', "_");
-    sessions_dir.join(safe_id)
+    sessions_dir.join(KildPaths::encode_session_id(session_id))
+}
+
+/// Name used for session files before ids were percent-encoded.
+///
+/// Not reversible: `a/b` and `a_b` both map to `a_b`.
+pub(super) fn legacy_safe_id(session_id: &str) -> String {
+    session_id.replace('/', "_")
+}
+
+/// `<sessions_dir>/<legacy_safe_id>/` if it exists and belongs to `session_id`.
+///
+/// Since legacy names collide, the directory only counts when its
+/// `kild.json` carries this exact id.
+fn legacy_session_dir(sessions_dir: &Path, session_id: &str) -> Option<PathBuf> {
+    let legacy_dir = sessions_dir.join(legacy_safe_id(session_id));
+    if legacy_dir == session_dir(sessions_dir, session_id) || !legacy_dir.is_dir() {
+        return None;
+    }
+
+    let owner = fs::read_to_string(legacy_dir.join("kild.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|json| json.get("id")?.as_str().map(str::to_string));
+    (owner.as_deref() == Some(session_id)).then_some(legacy_dir)
+}
+
+/// Move `legacy_dir`, holding the session `session_id`, to its encoded name.
+///
+/// Best-effort: failures are logged and the session stays where it was. An
+/// existing encoded directory is never overwritten.
+fn migrate_legacy_session_dir(sessions_dir: &Path, session_id: &str, legacy_dir: &Path) {
+    let dir = session_dir(sessions_dir, session_id);
+    if dir.exists() {
+        return;
+    }
+
+    match fs::rename(legacy_dir, &dir) {
+        Ok(()) => tracing::info!(
+            event = "core.session.legacy_dir_migrated",
+            session_id = %session_id,
+            from = %legacy_dir.display(),
+            to = %dir.display(),
+        ),
+        // Another process may have migrated it first
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => tracing::warn!(
+            event = "core.session.legacy_dir_migration_failed",
+            session_id = %session_id,
+            from = %legacy_dir.display(),
+            error = %e,
+        ),
+    }
 }
 
 /// Compute session file path: `<sessions_dir>/<safe_id>/kild.json`.
@@ -170,6 +225,57 @@ pub fn save_session_to_file(session: &Session, sessions_dir: &Path) -> Result<()
     Ok(())
 }
 
+/// Move a session's directory (kild.json and sidecars) from `old_id` to
+/// `session.id`, then rewrite its identity fields in place.
+///
+/// Patches `id`, `branch`, and `worktree_path` rather than re-saving so fields
+/// unknown to this binary survive. Moves the directory back if the patch fails.
+pub fn relocate_session_files(
+    sessions_dir: &Path,
+    old_id: &str,
+    session: &Session,
+) -> Result<(), SessionError> {
+    let old_dir = session_dir(sessions_dir, old_id);
+    let new_dir = session_dir(sessions_dir, &session.id);
+    if new_dir.exists() {
+        return Err(SessionError::AlreadyExists {
+            name: session.branch.to_string(),
+        });
+    }
+
+    fs::rename(&old_dir, &new_dir).map_err(|e| SessionError::IoError { source: e })?;
+
+    let worktree_path =
+        serde_json::to_value(&session.worktree_path).map_err(|e| SessionError::IoError {
+            source: std::io::Error::new(std::io::ErrorKind::InvalidData, e),
+        })?;
+    let patched = super::patching::patch_session_json_fields(
+        sessions_dir,
+        &session.id,
+        &[
+            ("id", serde_json::json!(&*session.id)),
+            ("branch", serde_json::json!(&*session.branch)),
+            ("worktree_path", worktree_path),
+        ],
+    );
+    if let Err(e) = patched {
+        if let Err(restore_err) = fs::rename(&new_dir, &old_dir) {
+            tracing::error!(
+                event = "core.session.relocate_restore_failed",
+                from = %new_dir.display(),
+                to = %old_dir.display(),
+                error = %restore_err,
+            );
+        }
+        return Err(e);
+    }
+
+    super::index::purge_session_id_from_branch_index(sessions_dir, old_id);
+    super::index::update_branch_index(sessions_dir, &session.branch, &session.id);
+
+    Ok(())
+}
+
 pub fn load_sessions_from_files(
     sessions_dir: &Path,
 ) -> Result<(Vec<Session>, usize), SessionError> {
@@ -277,6 +383,14 @@ pub fn load_sessions_from_files(
             continue;
         }
 
+        // Move a directory still under its legacy `_` name to the encoded name
+        let session_path = session_file.parent().unwrap_or(sessions_dir);
+        if session_path.file_name().and_then(|s| s.to_str())
+            != Some(KildPaths::encode_session_id(&session.id).as_str())
+        {
+            migrate_legacy_session_dir(sessions_dir, &session.id, session_path);
+        }
+
         sessions.push(session);
     }
 
@@ -326,8 +440,35 @@ pub fn find_session_by_name(
     Ok(None)
 }
 
+/// Find a session by its ID.
+///
+/// Reads the session's `kild.json` directly, falling back to a full scan for
+/// sessions still in the old single-file layout.
+pub fn find_session_by_id(
+    sessions_dir: &Path,
+    session_id: &str,
+) -> Result<Option<Session>, SessionError> {
+    let file = session_file(sessions_dir, session_id);
+    if file.exists() {
+        let content = fs::read_to_string(&file).map_err(|e| SessionError::IoError { source: e })?;
+        if let Ok(session) = serde_json::from_str::<Session>(&content)
+            && &*session.id == session_id
+        {
+            return Ok(Some(session));
+        }
+    }
+
+    let (sessions, _) = load_sessions_from_files(sessions_dir)?;
+    Ok(sessions.into_iter().find(|s| &*s.id == session_id))
+}
+
 pub fn remove_session_file(sessions_dir: &Path, session_id: &str) -> Result<(), SessionError> {
-    let dir = session_dir(sessions_dir, session_id);
+    let mut dir = session_dir(sessions_dir, session_id);
+    if !dir.is_dir()
+        && let Some(legacy_dir) = legacy_session_dir(sessions_dir, session_id)
+    {
+        dir = legacy_dir;
+    }
 
     if dir.is_dir() {
         // Warn about unexpected files that will be removed
@@ -337,7 +478,14 @@ pub fn remove_session_file(sessions_dir: &Path, session_id: &str) -> Result<(),
                 let name = name.to_string_lossy();
                 if !matches!(
                     name.as_ref(),
-                    "kild.json" | "status" | "pr" | "kild.json.tmp" | "status.tmp" | "pr.tmp"
+                    "kild.json"
+                        | "status"
+                        | "pr"
+                        | "notified"
+                        | "kild.json.tmp"
+                        | "status.tmp"
+                        | "pr.tmp"
+                        | "notified.tmp"
                 ) {
                     tracing::warn!(
                         event = "core.session.remove_unexpected_file",
@@ -356,7 +504,7 @@ pub fn remove_session_file(sessions_dir: &Path, session_id: &str) -> Result<(),
         fs::remove_dir_all(&dir).map_err(|e| SessionError::IoError { source: e })?;
     } else {
         // Try old-format cleanup as fallback
-        let safe_id = session_id.replace('/', "_");
+        let safe_id = legacy_safe_id(session_id);
         let old_file = sessions_dir.join(format!("{safe_id}.json"));
         if old_file.is_file() {
             fs::remove_file(&old_file).map_err(|e| SessionError::IoError { source: e })?;
@@ -1,4 +1,4 @@
-//! Sidecar file operations for agent status and PR info
+//! Sidecar file operations for agent status, PR info and notification history
 //!
 //! Sidecar files are stored inside per-session directories alongside `kild.json`.
 
@@ -159,3 +159,62 @@ pub fn remove_pr_info_file(sessions_dir: &Path, session_id: &str) {
         );
     }
 }
+
+/// Read the notification log sidecar. Missing or corrupt files yield an empty log.
+pub fn read_notification_log(
+    sessions_dir: &Path,
+    session_id: &str,
+) -> crate::notify::NotificationLog {
+    let sidecar_file = session_dir(sessions_dir, session_id).join("notified");
+    let content = match fs::read_to_string(&sidecar_file) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Default::default(),
+        Err(e) => {
+            tracing::warn!(
+                event = "core.session.notification_log_read_failed",
+                session_id = %session_id,
+                error = %e,
+            );
+            return Default::default();
+        }
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        tracing::warn!(
+            event = "core.session.notification_log_parse_failed",
+            session_id = %session_id,
+            error = %e,
+        );
+        Default::default()
+    })
+}
+
+/// Write the notification log sidecar atomically.
+pub fn write_notification_log(
+    sessions_dir: &Path,
+    session_id: &str,
+    log: &crate::notify::NotificationLog,
+) -> Result<(), SessionError> {
+    let dir = session_dir(sessions_dir, session_id);
+    fs::create_dir_all(&dir).map_err(|e| {
+        tracing::warn!(
+            event = "core.session.dir_create_failed",
+            path = %dir.display(),
+            error = %e,
+        );
+        SessionError::IoError { source: e }
+    })?;
+    let sidecar_file = dir.join("notified");
+    let content = serde_json::to_string(log).map_err(|e| SessionError::IoError {
+        source: std::io::Error::new(std::io::ErrorKind::InvalidData, e),
+    })?;
+    let temp_file = dir.join("notified.tmp");
+    if let Err(e) = fs::write(&temp_file, &content) {
+        cleanup_temp_file(&temp_file, &e);
+        return Err(SessionError::IoError { source: e });
+    }
+    if let Err(e) = fs::rename(&temp_file, &sidecar_file) {
+        cleanup_temp_file(&temp_file, &e);
+        return Err(SessionError::IoError { source: e });
+    }
+    Ok(())
+}
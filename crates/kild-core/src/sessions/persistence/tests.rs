@@ -55,7 +55,7 @@ fn test_save_session_to_file() {
 
     assert!(save_session_to_file(&session, &temp_dir).is_ok());
 
-    let sess_dir = temp_dir.join("test_branch");
+    let sess_dir = temp_dir.join("test%2Fbranch");
     let sess_file = sess_dir.join("kild.json");
     assert!(sess_dir.is_dir());
     assert!(sess_file.exists());
@@ -100,7 +100,7 @@ fn test_save_session_atomic_write_temp_cleanup() {
 
     assert!(save_session_to_file(&session, &temp_dir).is_ok());
 
-    let sess_dir = temp_dir.join("test_atomic");
+    let sess_dir = temp_dir.join("test%2Fatomic");
     let temp_file = sess_dir.join("kild.json.tmp");
     assert!(
         !temp_file.exists(),
@@ -144,7 +144,7 @@ fn test_save_session_atomic_behavior() {
         None,
     );
 
-    let sess_dir = temp_dir.join("test_atomic-behavior");
+    let sess_dir = temp_dir.join("test%2Fatomic-behavior");
     std::fs::create_dir_all(&sess_dir).unwrap();
     let sess_file = sess_dir.join("kild.json");
     std::fs::write(&sess_file, "old content").unwrap();
@@ -192,7 +192,7 @@ fn test_save_session_temp_file_cleanup_on_failure() {
         None,
     );
 
-    let sess_dir = temp_dir.join("test_cleanup");
+    let sess_dir = temp_dir.join("test%2Fcleanup");
     std::fs::create_dir_all(&sess_dir).unwrap();
     let sess_file = sess_dir.join("kild.json");
     std::fs::create_dir_all(&sess_file).unwrap(); // Create as directory to force rename failure
@@ -365,7 +365,7 @@ fn test_remove_session_file() {
 
     save_session_to_file(&session, &temp_dir).unwrap();
 
-    let sess_dir = temp_dir.join("test_branch");
+    let sess_dir = temp_dir.join("test%2Fbranch");
     assert!(sess_dir.is_dir());
 
     remove_session_file(&temp_dir, &session.id).unwrap();
@@ -579,7 +579,7 @@ fn test_patch_session_json_field_preserves_unknown_fields() {
         "last_activity": "2024-01-01T00:00:00Z", "agents": [],
         "future_field": "must_survive"
     });
-    let sess_dir = temp_dir.join("proj_my-branch");
+    let sess_dir = temp_dir.join("proj%2Fmy-branch");
     std::fs::create_dir_all(&sess_dir).unwrap();
     let sess_file = sess_dir.join("kild.json");
     std::fs::write(&sess_file, serde_json::to_string_pretty(&json).unwrap()).unwrap();
@@ -640,7 +640,7 @@ fn test_patch_session_json_fields_preserves_unknown_fields() {
         "last_activity": "2024-01-01T00:00:00Z", "agents": [],
         "future_field": "must_survive"
     });
-    let sess_dir = temp_dir.join("proj_my-branch");
+    let sess_dir = temp_dir.join("proj%2Fmy-branch");
     std::fs::create_dir_all(&sess_dir).unwrap();
     let sess_file = sess_dir.join("kild.json");
     std::fs::write(&sess_file, serde_json::to_string_pretty(&json).unwrap()).unwrap();
@@ -675,7 +675,7 @@ fn test_write_and_read_agent_status() {
         updated_at: "2026-02-05T12:00:00Z".to_string(),
     };
     write_agent_status(tmp.path(), "test/branch", &info).unwrap();
-    assert!(tmp.path().join("test_branch").join("status").exists());
+    assert!(tmp.path().join("test%2Fbranch").join("status").exists());
     assert_eq!(read_agent_status(tmp.path(), "test/branch"), Some(info));
 }
 
@@ -702,7 +702,7 @@ fn test_remove_agent_status_file_exists() {
         updated_at: "2026-02-05T12:00:00Z".to_string(),
     };
     write_agent_status(tmp.path(), "test/rm", &info).unwrap();
-    let sidecar = tmp.path().join("test_rm").join("status");
+    let sidecar = tmp.path().join("test%2Frm").join("status");
     assert!(sidecar.exists());
     remove_agent_status_file(tmp.path(), "test/rm");
     assert!(!sidecar.exists());
@@ -714,6 +714,42 @@ fn test_remove_agent_status_file_missing_is_noop() {
     remove_agent_status_file(tmp.path(), "nonexistent");
 }
 
+#[test]
+fn test_write_and_read_notification_log() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let now = chrono::Utc::now();
+    let mut log = crate::notify::NotificationLog::default();
+    log.record(AgentStatus::Waiting, now);
+
+    write_notification_log(tmp.path(), "test/branch", &log).unwrap();
+
+    assert!(tmp.path().join("test%2Fbranch").join("notified").exists());
+    let read = read_notification_log(tmp.path(), "test/branch");
+    assert_eq!(read, log);
+    assert!(!read.should_send(
+        AgentStatus::Waiting,
+        now,
+        std::time::Duration::from_secs(60)
+    ));
+}
+
+#[test]
+fn test_read_notification_log_missing_or_corrupt_is_empty() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    assert_eq!(
+        read_notification_log(tmp.path(), "nonexistent"),
+        Default::default()
+    );
+
+    let sess_dir = tmp.path().join("bad_session");
+    std::fs::create_dir_all(&sess_dir).unwrap();
+    std::fs::write(sess_dir.join("notified"), "not json").unwrap();
+    assert_eq!(
+        read_notification_log(tmp.path(), "bad_session"),
+        Default::default()
+    );
+}
+
 #[test]
 fn test_write_and_read_pr_info() {
     use crate::forge::types::{CiStatus, PrState, PullRequest, ReviewStatus};
@@ -729,7 +765,7 @@ fn test_write_and_read_pr_info() {
         updated_at: "2026-02-05T12:00:00Z".to_string(),
     };
     write_pr_info(tmp.path(), "test/branch", &info).unwrap();
-    assert!(tmp.path().join("test_branch").join("pr").exists());
+    assert!(tmp.path().join("test%2Fbranch").join("pr").exists());
     assert_eq!(read_pr_info(tmp.path(), "test/branch"), Some(info));
 }
 
@@ -763,7 +799,7 @@ fn test_remove_pr_info_file_exists() {
         updated_at: "2026-02-05T12:00:00Z".to_string(),
     };
     write_pr_info(tmp.path(), "test/rm", &info).unwrap();
-    let sidecar = tmp.path().join("test_rm").join("pr");
+    let sidecar = tmp.path().join("test%2Frm").join("pr");
     assert!(sidecar.exists());
     remove_pr_info_file(tmp.path(), "test/rm");
     assert!(!sidecar.exists());
@@ -867,7 +903,7 @@ fn test_session_id_filename_mapping() {
 
     save_session_to_file(&session, sessions_dir).unwrap();
 
-    let expected_dir = sessions_dir.join("my-project_deep_nested");
+    let expected_dir = sessions_dir.join("my-project%2Fdeep%2Fnested");
     let expected_file = expected_dir.join("kild.json");
     assert!(expected_dir.is_dir());
     assert!(expected_file.exists());
@@ -978,7 +1014,7 @@ fn test_load_sessions_auto_migrates_old_format() {
     assert!(!old_file.exists());
     assert!(
         sessions_dir
-            .join("test_migrate-me")
+            .join("test%2Fmigrate-me")
             .join("kild.json")
             .exists()
     );
@@ -1092,16 +1128,73 @@ fn test_session_dir_and_session_file_helpers() {
     let base = std::path::Path::new("/tmp/sessions");
     assert_eq!(
         session_dir(base, "proj/branch"),
-        std::path::PathBuf::from("/tmp/sessions/proj_branch")
+        std::path::PathBuf::from("/tmp/sessions/proj%2Fbranch")
     );
     assert_eq!(
         session_file(base, "proj/branch"),
-        std::path::PathBuf::from("/tmp/sessions/proj_branch/kild.json")
+        std::path::PathBuf::from("/tmp/sessions/proj%2Fbranch/kild.json")
     );
     assert_eq!(
         session_dir(base, "deep/nested/id"),
-        std::path::PathBuf::from("/tmp/sessions/deep_nested_id")
+        std::path::PathBuf::from("/tmp/sessions/deep%2Fnested%2Fid")
     );
+    assert_ne!(session_dir(base, "a/b"), session_dir(base, "a_b"));
+}
+
+#[test]
+fn test_session_dir_does_not_move_legacy_dir() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let legacy = tmp.path().join("proj_branch");
+    std::fs::create_dir_all(&legacy).unwrap();
+    std::fs::write(legacy.join("kild.json"), r#"{"id":"proj/branch"}"#).unwrap();
+
+    let dir = session_dir(tmp.path(), "proj/branch");
+
+    assert_eq!(dir, tmp.path().join("proj%2Fbranch"));
+    assert!(!dir.exists());
+    assert!(legacy.join("kild.json").exists());
+}
+
+#[test]
+fn test_load_sessions_migrates_legacy_underscore_dir() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let worktree = tmp.path().join("worktree");
+    std::fs::create_dir_all(&worktree).unwrap();
+    let sessions_dir = tmp.path().join("sessions");
+
+    let mut session = Session::new_for_test("branch".to_string(), worktree);
+    session.id = "proj/branch".into();
+    save_session_to_file(&session, &sessions_dir).unwrap();
+    let dir = sessions_dir.join("proj%2Fbranch");
+    std::fs::write(dir.join("status"), "{}").unwrap();
+    let legacy = sessions_dir.join("proj_branch");
+    std::fs::rename(&dir, &legacy).unwrap();
+
+    let (sessions, skipped) = load_sessions_from_files(&sessions_dir).unwrap();
+
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(skipped, 0);
+    assert!(dir.join("kild.json").exists());
+    assert!(dir.join("status").exists());
+    assert!(!legacy.exists());
+}
+
+#[test]
+fn test_load_sessions_leaves_colliding_legacy_dir_alone() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let worktree = tmp.path().join("worktree");
+    std::fs::create_dir_all(&worktree).unwrap();
+    let sessions_dir = tmp.path().join("sessions");
+
+    // `proj_branch` here belongs to the session whose id really is `proj_branch`
+    let mut session = Session::new_for_test("branch".to_string(), worktree);
+    session.id = "proj_branch".into();
+    save_session_to_file(&session, &sessions_dir).unwrap();
+
+    load_sessions_from_files(&sessions_dir).unwrap();
+
+    assert!(sessions_dir.join("proj_branch").join("kild.json").exists());
+    assert!(!sessions_dir.join("proj%2Fbranch").exists());
 }
 
 #[test]
@@ -1211,3 +1304,29 @@ fn test_save_session_compact_json() {
         "kild.json should be compact (single line)"
     );
 }
+
+#[test]
+fn test_find_session_by_id_round_trips_create_setup() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let sessions_dir = tmp.path();
+
+    let mut session = Session::new_for_test("feature-auth", tmp.path().join("worktree"));
+    session.base_branch = Some("develop".to_string());
+    session.include_patterns = Some(kild_config::IncludeConfig {
+        patterns: vec![".env*".to_string()],
+        enabled: true,
+        max_file_size: None,
+    });
+    save_session_to_file(&session, sessions_dir).unwrap();
+
+    let found = find_session_by_id(sessions_dir, &session.id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(found, session);
+
+    assert!(
+        find_session_by_id(sessions_dir, "test-missing")
+            .unwrap()
+            .is_none()
+    );
+}
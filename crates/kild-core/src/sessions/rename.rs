@@ -0,0 +1,320 @@
+//! Rename a kild: git branch, worktree directory, and session state.
+
+use std::path::Path;
+
+use kild_paths::KildPaths;
+use kild_protocol::{BranchName, SessionId};
+use tracing::{error, info, warn};
+
+use crate::git;
+use crate::sessions::{errors::SessionError, persistence, ports, types::*};
+use kild_config::Config;
+
+/// Rename a stopped kild to `new_branch`.
+///
+/// Renames `kild/<old>` to `kild/<new>`, moves the worktree directory, moves
+/// the session directory to the new session id, and relocates the PID file and
+/// shim state. If any required step fails, the completed steps are rolled back.
+///
+/// Returns the renamed session.
+pub fn rename_session(old: &SessionId, new_branch: BranchName) -> Result<Session, SessionError> {
+    let config = Config::new();
+    rename_session_in(config.paths(), old, new_branch)
+}
+
+fn rename_session_in(
+    paths: &KildPaths,
+    old_id: &SessionId,
+    new_branch: BranchName,
+) -> Result<Session, SessionError> {
+    info!(
+        event = "core.session.rename_started",
+        session_id = %old_id,
+        new_branch = %new_branch
+    );
+
+    let sessions_dir = paths.sessions_dir();
+    let (sessions, _) = persistence::load_sessions_from_files(&sessions_dir)?;
+    let mut session = sessions
+        .iter()
+        .find(|s| s.id == *old_id)
+        .cloned()
+        .ok_or_else(|| SessionError::NotFound {
+            name: old_id.to_string(),
+        })?;
+    let old_branch = session.branch.clone();
+
+    if session.status == SessionStatus::Active {
+        return Err(SessionError::RenameActive {
+            name: old_branch.to_string(),
+        });
+    }
+    if session.use_main_worktree {
+        return Err(SessionError::RenameFailed {
+            name: old_branch.to_string(),
+            message: "kilds created with --main have no branch or worktree of their own"
+                .to_string(),
+        });
+    }
+
+    let new_branch = git::validate_branch_name(&new_branch)?;
+    if new_branch == old_branch {
+        return Err(SessionError::RenameFailed {
+            name: old_branch.to_string(),
+            message: "new name is the same as the current name".to_string(),
+        });
+    }
+
+    let new_id = ports::generate_session_id(&session.project_id, &new_branch);
+    if sessions.iter().any(|s| s.id == new_id) {
+        return Err(SessionError::AlreadyExists {
+            name: new_branch.to_string(),
+        });
+    }
+
+    let old_worktree = session.worktree_path.clone();
+    let new_worktree = old_worktree.with_file_name(git::sanitize_for_path(&new_branch));
+    let repo_root =
+        git::find_main_repo_root(&old_worktree).ok_or_else(|| SessionError::RenameFailed {
+            name: old_branch.to_string(),
+            message: format!(
+                "cannot locate the main repository for worktree {}",
+                old_worktree.display()
+            ),
+        })?;
+
+    let old_git_branch = git::kild_branch_name(&old_branch);
+    let new_git_branch = git::kild_branch_name(&new_branch);
+
+    // 1. Git branch
+    git::cli::rename_branch(&repo_root, &old_git_branch, &new_git_branch)?;
+
+    // 2. Worktree directory
+    if let Err(e) = git::cli::move_worktree(&repo_root, &old_worktree, &new_worktree) {
+        rollback_branch(&repo_root, &new_git_branch, &old_git_branch);
+        return Err(e.into());
+    }
+
+    // 3. Session directory and kild.json
+    session.id = new_id;
+    session.branch = new_branch;
+    session.worktree_path = new_worktree;
+    if let Err(e) = persistence::relocate_session_files(&sessions_dir, old_id, &session) {
+        rollback_worktree(&repo_root, &session.worktree_path, &old_worktree);
+        rollback_branch(&repo_root, &new_git_branch, &old_git_branch);
+        return Err(e);
+    }
+
+    // 4. Best-effort: leftover PID file and shim state follow the new id
+    relocate_if_exists(
+        &crate::process::get_pid_file_path(paths.kild_dir(), old_id),
+        &crate::process::get_pid_file_path(paths.kild_dir(), &session.id),
+    );
+    relocate_if_exists(
+        &paths.shim_session_dir(old_id),
+        &paths.shim_session_dir(&session.id),
+    );
+
+    info!(
+        event = "core.session.rename_completed",
+        old_session_id = %old_id,
+        session_id = %session.id,
+        branch = %session.branch
+    );
+
+    Ok(session)
+}
+
+fn rollback_branch(repo_root: &Path, current: &str, original: &str) {
+    if let Err(e) = git::cli::rename_branch(repo_root, current, original) {
+        error!(
+            event = "core.session.rename_rollback_branch_failed",
+            current = current,
+            original = original,
+            error = %e,
+        );
+    }
+}
+
+fn rollback_worktree(repo_root: &Path, current: &Path, original: &Path) {
+    if let Err(e) = git::cli::move_worktree(repo_root, current, original) {
+        error!(
+            event = "core.session.rename_rollback_worktree_failed",
+            current = %current.display(),
+            original = %original.display(),
+            error = %e,
+        );
+    }
+}
+
+fn relocate_if_exists(from: &Path, to: &Path) {
+    if !from.exists() {
+        return;
+    }
+    let result = to
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| std::fs::rename(from, to));
+    if let Err(e) = result {
+        warn!(
+            event = "core.session.rename_relocate_failed",
+            from = %from.display(),
+            to = %to.display(),
+            error = %e,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    struct Fixture {
+        _temp: TempDir,
+        paths: KildPaths,
+        repo: PathBuf,
+        session: Session,
+    }
+
+    /// A real repo with a stopped kild `old-name` on `kild/old-name`.
+    fn setup() -> Fixture {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git::test_support::init_repo_with_commit(&repo).unwrap();
+        git::test_support::create_branch(&repo, "kild/old-name").unwrap();
+
+        let worktree = temp.path().join("worktrees").join("proj").join("old-name");
+        std::fs::create_dir_all(worktree.parent().unwrap()).unwrap();
+        git::test_support::create_worktree_for_branch(
+            &repo,
+            "kild-old-name",
+            &worktree,
+            "kild/old-name",
+        )
+        .unwrap();
+
+        let paths = KildPaths::from_dir(temp.path().join("kild"));
+        let mut session = Session::new_for_test("old-name", worktree);
+        session.id = SessionId::new("proj/old-name");
+        session.project_id = "proj".into();
+        session.status = SessionStatus::Stopped;
+        persistence::save_session_to_file(&session, &paths.sessions_dir()).unwrap();
+
+        Fixture {
+            _temp: temp,
+            paths,
+            repo,
+            session,
+        }
+    }
+
+    fn branch_exists(repo: &Path, name: &str) -> bool {
+        git::list_local_branch_names(repo)
+            .unwrap()
+            .iter()
+            .any(|b| b == name)
+    }
+
+    #[test]
+    fn test_rename_session_moves_branch_worktree_and_session() {
+        let fx = setup();
+        let sessions_dir = fx.paths.sessions_dir();
+        std::fs::write(sessions_dir.join("proj%2Fold-name").join("status"), "{}").unwrap();
+
+        let renamed = rename_session_in(&fx.paths, &fx.session.id, "new-name".into()).unwrap();
+
+        assert_eq!(&*renamed.id, "proj/new-name");
+        assert_eq!(&*renamed.branch, "new-name");
+        assert!(renamed.worktree_path.ends_with("new-name"));
+        assert!(renamed.worktree_path.exists());
+        assert!(!fx.session.worktree_path.exists());
+
+        assert!(branch_exists(&fx.repo, "kild/new-name"));
+        assert!(!branch_exists(&fx.repo, "kild/old-name"));
+
+        assert!(!sessions_dir.join("proj%2Fold-name").exists());
+        assert!(sessions_dir.join("proj%2Fnew-name").join("status").exists());
+        let loaded = persistence::find_session_by_name(&sessions_dir, "new-name")
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.id, renamed.id);
+        assert_eq!(loaded.worktree_path, renamed.worktree_path);
+        assert!(
+            persistence::find_session_by_name(&sessions_dir, "old-name")
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_rename_session_rolls_back_branch_when_worktree_move_fails() {
+        let fx = setup();
+        // A locked worktree cannot be moved, so the move fails after the branch rename
+        let lock = Command::new("git")
+            .current_dir(&fx.repo)
+            .arg("worktree")
+            .arg("lock")
+            .arg(&fx.session.worktree_path)
+            .output()
+            .unwrap();
+        assert!(lock.status.success());
+
+        let result = rename_session_in(&fx.paths, &fx.session.id, "new-name".into());
+
+        assert!(matches!(result, Err(SessionError::GitError { .. })));
+        assert!(branch_exists(&fx.repo, "kild/old-name"));
+        assert!(!branch_exists(&fx.repo, "kild/new-name"));
+        assert!(fx.session.worktree_path.exists());
+        let loaded = persistence::find_session_by_name(&fx.paths.sessions_dir(), "old-name")
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.id, fx.session.id);
+    }
+
+    #[test]
+    fn test_rename_session_refuses_active_session() {
+        let fx = setup();
+        persistence::patch_session_json_field(
+            &fx.paths.sessions_dir(),
+            &fx.session.id,
+            "status",
+            serde_json::json!("Active"),
+        )
+        .unwrap();
+
+        let result = rename_session_in(&fx.paths, &fx.session.id, "new-name".into());
+
+        assert!(matches!(result, Err(SessionError::RenameActive { .. })));
+        assert!(branch_exists(&fx.repo, "kild/old-name"));
+    }
+
+    #[test]
+    fn test_rename_session_to_existing_name_leaves_state_unchanged() {
+        let fx = setup();
+        let sessions_dir = fx.paths.sessions_dir();
+        let mut taken = Session::new_for_test("taken", fx.repo.join("taken"));
+        taken.id = SessionId::new("proj/taken");
+        taken.project_id = "proj".into();
+        persistence::save_session_to_file(&taken, &sessions_dir).unwrap();
+        let kild_json = sessions_dir.join("proj%2Fold-name").join("kild.json");
+        let before = std::fs::read(&kild_json).unwrap();
+
+        let result = rename_session_in(&fx.paths, &fx.session.id, "taken".into());
+
+        assert!(matches!(result, Err(SessionError::AlreadyExists { .. })));
+        assert_eq!(std::fs::read(&kild_json).unwrap(), before);
+        assert!(branch_exists(&fx.repo, "kild/old-name"));
+        assert!(fx.session.worktree_path.exists());
+    }
+
+    #[test]
+    fn test_rename_session_unknown_id_is_not_found() {
+        let fx = setup();
+        let result = rename_session_in(&fx.paths, &SessionId::new("proj/missing"), "x".into());
+        assert!(matches!(result, Err(SessionError::NotFound { .. })));
+    }
+}
@@ -0,0 +1,237 @@
+use std::path::Path;
+
+use tracing::info;
+
+use kild_config::{Config, KildConfig};
+use kild_protocol::{OpenMode, RuntimeMode};
+
+use crate::sessions::{errors::SessionError, persistence, types::*};
+
+use super::daemon_helpers::AgentSpawnParams;
+use super::open::{load_kild_config, open_session_in, spawn_agent};
+
+/// Relaunches the default agent of a kild in its existing worktree.
+///
+/// Equivalent to `kild stop` followed by `kild open`: a running agent is stopped
+/// first, then a fresh one is spawned. The worktree is never recreated — if it
+/// was removed, returns [`SessionError::WorktreeNotFound`].
+pub fn restart_session(
+    name: &str,
+    runtime_mode: Option<RuntimeMode>,
+    no_attach: bool,
+) -> Result<Session, SessionError> {
+    info!(event = "core.session.restart_started", name = name);
+
+    let config = Config::new();
+    let kild_config = load_kild_config();
+
+    let session = restart_session_in(
+        &config.sessions_dir(),
+        &kild_config,
+        name,
+        runtime_mode,
+        no_attach,
+        super::stop::stop_session,
+        spawn_agent,
+    )?;
+
+    crate::events::publish(crate::state::Event::KildOpened {
+        branch: session.branch.clone(),
+        agent: session
+            .latest_agent()
+            .map_or_else(|| session.agent.clone(), |a| a.agent().to_string()),
+    });
+
+    Ok(session)
+}
+
+fn restart_session_in(
+    sessions_dir: &Path,
+    kild_config: &KildConfig,
+    name: &str,
+    runtime_mode: Option<RuntimeMode>,
+    no_attach: bool,
+    stop: impl FnOnce(&str) -> Result<(), SessionError>,
+    spawn: impl FnOnce(&AgentSpawnParams<'_>, bool) -> Result<AgentProcess, SessionError>,
+) -> Result<Session, SessionError> {
+    let session = persistence::find_session_by_name(sessions_dir, name)?.ok_or_else(|| {
+        SessionError::NotFound {
+            name: name.to_string(),
+        }
+    })?;
+
+    // Check before stopping so a kild with a missing worktree keeps its agent.
+    if !session.worktree_path.exists() {
+        return Err(SessionError::WorktreeNotFound {
+            path: session.worktree_path.clone(),
+        });
+    }
+
+    if session.status == SessionStatus::Active && session.has_agents() {
+        info!(
+            event = "core.session.restart_stopping",
+            branch = name,
+            agent_count = session.agent_count()
+        );
+        stop(name)?;
+    }
+
+    let session = open_session_in(
+        sessions_dir,
+        kild_config,
+        name,
+        OpenMode::DefaultAgent,
+        runtime_mode,
+        false,
+        false,
+        no_attach,
+        None,
+        spawn,
+    )?;
+
+    info!(
+        event = "core.session.restart_completed",
+        session_id = %session.id,
+        branch = name
+    );
+
+    Ok(session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::path::PathBuf;
+
+    fn fake_agent(worktree: &Path) -> AgentProcess {
+        AgentProcess::new(
+            "claude".to_string(),
+            "test-project_restart-me_0".to_string(),
+            Some(4242),
+            Some("claude".to_string()),
+            Some(1234567890),
+            None,
+            None,
+            format!("claude # {}", worktree.display()),
+            chrono::Utc::now().to_rfc3339(),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn stopped_session(sessions_dir: &Path, worktree: PathBuf) {
+        let mut session = Session::new_for_test("restart-me", worktree);
+        session.agent = "claude".to_string();
+        session.status = SessionStatus::Stopped;
+        session.runtime_mode = Some(RuntimeMode::Terminal);
+        persistence::save_session_to_file(&session, sessions_dir).unwrap();
+    }
+
+    #[test]
+    fn test_restart_reuses_worktree_and_reactivates() {
+        let tmp = tempfile::tempdir().unwrap();
+        let sessions_dir = tmp.path().join("sessions");
+        let worktree = tmp.path().join("worktree");
+        std::fs::create_dir_all(&sessions_dir).unwrap();
+        std::fs::create_dir_all(&worktree).unwrap();
+        stopped_session(&sessions_dir, worktree.clone());
+
+        let spawned_in = RefCell::new(None);
+        let session = restart_session_in(
+            &sessions_dir,
+            &KildConfig::default(),
+            "restart-me",
+            None,
+            true,
+            |_| panic!("stopped kild must not be stopped again"),
+            |params, use_daemon| {
+                assert!(!use_daemon);
+                *spawned_in.borrow_mut() = Some(params.worktree_path.to_path_buf());
+                Ok(fake_agent(params.worktree_path))
+            },
+        )
+        .unwrap();
+
+        assert_eq!(spawned_in.into_inner(), Some(worktree.clone()));
+        assert_eq!(session.worktree_path, worktree);
+
+        let reloaded = persistence::find_session_by_name(&sessions_dir, "restart-me")
+            .unwrap()
+            .unwrap();
+        assert_eq!(reloaded.status, SessionStatus::Active);
+        assert_eq!(reloaded.agent_count(), 1);
+        assert_eq!(reloaded.worktree_path, worktree);
+
+        // No worktree was created anywhere else under the kild home.
+        let entries: Vec<_> = std::fs::read_dir(tmp.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(entries.len(), 2, "unexpected entries: {:?}", entries);
+    }
+
+    #[test]
+    fn test_restart_stops_active_kild_first() {
+        let tmp = tempfile::tempdir().unwrap();
+        let sessions_dir = tmp.path().join("sessions");
+        let worktree = tmp.path().join("worktree");
+        std::fs::create_dir_all(&sessions_dir).unwrap();
+        std::fs::create_dir_all(&worktree).unwrap();
+
+        let mut session = Session::new_for_test("restart-me", worktree.clone());
+        session.agent = "claude".to_string();
+        session.runtime_mode = Some(RuntimeMode::Terminal);
+        session.add_agent(fake_agent(&worktree));
+        persistence::save_session_to_file(&session, &sessions_dir).unwrap();
+
+        let stopped = RefCell::new(false);
+        let result = restart_session_in(
+            &sessions_dir,
+            &KildConfig::default(),
+            "restart-me",
+            None,
+            true,
+            |name| {
+                // Mirror stop_session: clear agents and mark Stopped.
+                let mut s = persistence::find_session_by_name(&sessions_dir, name)?.unwrap();
+                s.clear_agents();
+                s.status = SessionStatus::Stopped;
+                persistence::save_session_to_file(&s, &sessions_dir)?;
+                *stopped.borrow_mut() = true;
+                Ok(())
+            },
+            |params, _| Ok(fake_agent(params.worktree_path)),
+        )
+        .unwrap();
+
+        assert!(stopped.into_inner());
+        assert_eq!(result.status, SessionStatus::Active);
+        assert_eq!(result.agent_count(), 1);
+    }
+
+    #[test]
+    fn test_restart_missing_worktree_fails_without_spawning() {
+        let tmp = tempfile::tempdir().unwrap();
+        let sessions_dir = tmp.path().join("sessions");
+        std::fs::create_dir_all(&sessions_dir).unwrap();
+        let worktree = tmp.path().join("gone");
+        stopped_session(&sessions_dir, worktree.clone());
+
+        let result = restart_session_in(
+            &sessions_dir,
+            &KildConfig::default(),
+            "restart-me",
+            None,
+            true,
+            |_| panic!("must not stop"),
+            |_, _| panic!("must not spawn"),
+        );
+
+        assert!(matches!(
+            result,
+            Err(SessionError::WorktreeNotFound { path }) if path == worktree
+        ));
+        assert!(!worktree.exists(), "restart must not recreate the worktree");
+    }
+}
@@ -11,6 +11,8 @@ use kild_config::Config;
 ///
 /// The worktree and session file are preserved. The kild can be reopened with `open_session()`.
 pub fn stop_session(name: &str) -> Result<(), SessionError> {
+    let span = crate::logging::session_span("stop", name).entered();
+
     info!(event = "core.session.stop_started", name = name);
 
     let config = Config::new();
@@ -23,6 +25,8 @@ pub fn stop_session(name: &str) -> Result<(), SessionError> {
             }
         })?;
 
+    crate::logging::record_session(&span, &session.id, &session.agent);
+
     info!(
         event = "core.session.stop_found",
         session_id = %session.id,
@@ -210,6 +214,10 @@ pub fn stop_session(name: &str) -> Result<(), SessionError> {
         session_id = %session.id
     );
 
+    crate::events::publish(crate::state::Event::KildStopped {
+        branch: session.branch.clone(),
+    });
+
     Ok(())
 }
 
@@ -0,0 +1,208 @@
+//! Session tags: free-form labels for grouping kilds.
+
+use std::path::Path;
+
+use tracing::info;
+
+use crate::sessions::{errors::SessionError, persistence, types::*};
+use kild_config::Config;
+
+/// Add `tag` to a session. Adding a tag the session already has is a no-op.
+///
+/// Returns the updated session.
+pub fn add_tag(name: &str, tag: &str) -> Result<Session, SessionError> {
+    let config = Config::new();
+    add_tag_in(&config.sessions_dir(), name, tag)
+}
+
+/// Remove `tag` from a session. Removing a tag the session doesn't have is a no-op.
+///
+/// Returns the updated session.
+pub fn remove_tag(name: &str, tag: &str) -> Result<Session, SessionError> {
+    let config = Config::new();
+    remove_tag_in(&config.sessions_dir(), name, tag)
+}
+
+/// List all sessions carrying `tag`.
+pub fn list_sessions_by_tag(tag: &str) -> Result<Vec<Session>, SessionError> {
+    let config = Config::new();
+    list_sessions_by_tag_in(&config.sessions_dir(), tag)
+}
+
+fn add_tag_in(sessions_dir: &Path, name: &str, tag: &str) -> Result<Session, SessionError> {
+    validate_tag(tag)?;
+    info!(
+        event = "core.session.tag_add_started",
+        name = name,
+        tag = tag
+    );
+
+    let mut session = load_session(sessions_dir, name)?;
+    if !session.tags.iter().any(|t| t == tag) {
+        session.tags.push(tag.to_string());
+        persist_tags(sessions_dir, &session)?;
+    }
+
+    info!(
+        event = "core.session.tag_add_completed",
+        session_id = %session.id,
+        tag = tag
+    );
+    Ok(session)
+}
+
+fn remove_tag_in(sessions_dir: &Path, name: &str, tag: &str) -> Result<Session, SessionError> {
+    info!(
+        event = "core.session.tag_remove_started",
+        name = name,
+        tag = tag
+    );
+
+    let mut session = load_session(sessions_dir, name)?;
+    let before = session.tags.len();
+    session.tags.retain(|t| t != tag);
+    if session.tags.len() != before {
+        persist_tags(sessions_dir, &session)?;
+    }
+
+    info!(
+        event = "core.session.tag_remove_completed",
+        session_id = %session.id,
+        tag = tag
+    );
+    Ok(session)
+}
+
+fn list_sessions_by_tag_in(sessions_dir: &Path, tag: &str) -> Result<Vec<Session>, SessionError> {
+    let (sessions, _skipped) = persistence::load_sessions_from_files(sessions_dir)?;
+    Ok(sessions
+        .into_iter()
+        .filter(|s| s.tags.iter().any(|t| t == tag))
+        .collect())
+}
+
+fn load_session(sessions_dir: &Path, name: &str) -> Result<Session, SessionError> {
+    persistence::find_session_by_name(sessions_dir, name)?.ok_or_else(|| SessionError::NotFound {
+        name: name.to_string(),
+    })
+}
+
+/// Patch only the `tags` field so fields unknown to this binary survive.
+fn persist_tags(sessions_dir: &Path, session: &Session) -> Result<(), SessionError> {
+    persistence::patch_session_json_fields(
+        sessions_dir,
+        &session.id,
+        &[("tags", serde_json::json!(session.tags))],
+    )
+}
+
+fn validate_tag(tag: &str) -> Result<(), SessionError> {
+    if tag.is_empty() || tag.chars().any(char::is_whitespace) {
+        return Err(SessionError::InvalidTag {
+            tag: tag.to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn setup(test_name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("kild_test_tags_{}", test_name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn save(sessions_dir: &Path, branch: &str) -> Session {
+        let session = Session::new_for_test(branch, sessions_dir.join(branch));
+        persistence::save_session_to_file(&session, sessions_dir).unwrap();
+        session
+    }
+
+    #[test]
+    fn test_session_without_tags_field_loads_empty() {
+        let sessions_dir = setup("old_file");
+        let session_dir = sessions_dir.join("test_old");
+        std::fs::create_dir_all(&session_dir).unwrap();
+        std::fs::write(
+            session_dir.join("kild.json"),
+            r#"{
+                "id": "test/old",
+                "project_id": "test",
+                "branch": "old",
+                "worktree_path": "/tmp/test",
+                "agent": "claude",
+                "status": "Active",
+                "created_at": "2024-01-01T00:00:00Z"
+            }"#,
+        )
+        .unwrap();
+
+        let session = load_session(&sessions_dir, "old").unwrap();
+        assert!(session.tags.is_empty());
+
+        let _ = std::fs::remove_dir_all(&sessions_dir);
+    }
+
+    #[test]
+    fn test_add_and_remove_tag_persist() {
+        let sessions_dir = setup("add_remove");
+        save(&sessions_dir, "auth");
+
+        add_tag_in(&sessions_dir, "auth", "backend").unwrap();
+        let session = add_tag_in(&sessions_dir, "auth", "backend").unwrap();
+        assert_eq!(session.tags, vec!["backend".to_string()]);
+        assert_eq!(
+            load_session(&sessions_dir, "auth").unwrap().tags,
+            vec!["backend".to_string()]
+        );
+
+        remove_tag_in(&sessions_dir, "auth", "backend").unwrap();
+        assert!(load_session(&sessions_dir, "auth").unwrap().tags.is_empty());
+
+        let _ = std::fs::remove_dir_all(&sessions_dir);
+    }
+
+    #[test]
+    fn test_list_sessions_by_tag_filters() {
+        let sessions_dir = setup("filter");
+        save(&sessions_dir, "auth");
+        save(&sessions_dir, "billing");
+        add_tag_in(&sessions_dir, "auth", "backend").unwrap();
+
+        let tagged = list_sessions_by_tag_in(&sessions_dir, "backend").unwrap();
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(&*tagged[0].branch, "auth");
+
+        let none = list_sessions_by_tag_in(&sessions_dir, "nobody-has-this").unwrap();
+        assert!(none.is_empty());
+
+        let _ = std::fs::remove_dir_all(&sessions_dir);
+    }
+
+    #[test]
+    fn test_add_tag_rejects_invalid_tags() {
+        let sessions_dir = setup("invalid");
+        save(&sessions_dir, "auth");
+
+        for tag in ["", "two words"] {
+            let result = add_tag_in(&sessions_dir, "auth", tag);
+            assert!(matches!(result, Err(SessionError::InvalidTag { .. })));
+        }
+
+        let _ = std::fs::remove_dir_all(&sessions_dir);
+    }
+
+    #[test]
+    fn test_add_tag_unknown_session_is_not_found() {
+        let sessions_dir = setup("unknown");
+        let result = add_tag_in(&sessions_dir, "missing", "backend");
+        assert!(matches!(result, Err(SessionError::NotFound { .. })));
+
+        let _ = std::fs::remove_dir_all(&sessions_dir);
+    }
+}
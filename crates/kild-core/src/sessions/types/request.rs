@@ -1,4 +1,4 @@
-use kild_protocol::{AgentMode, BranchName, RuntimeMode};
+use kild_protocol::{AgentMode, BranchName, RuntimeMode, SessionId};
 use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
@@ -36,6 +36,13 @@ pub struct CreateSessionRequest {
     /// Best-effort: session creation succeeds even if prompt delivery fails.
     /// May block up to 20s waiting for the agent's TUI to stabilize before injecting.
     pub initial_prompt: Option<String>,
+    /// Run `git submodule update --init --recursive` in the new worktree (CLI --init-submodules flag).
+    pub init_submodules: bool,
+    /// Existing session to clone the setup of (CLI --from flag).
+    ///
+    /// Its agent, base branch and include patterns seed this request before
+    /// explicit overrides apply. See [`crate::sessions::create::create_session`].
+    pub from: Option<SessionId>,
 }
 
 impl CreateSessionRequest {
@@ -51,6 +58,8 @@ impl CreateSessionRequest {
             runtime_mode: RuntimeMode::Terminal,
             use_main_worktree: false,
             initial_prompt: None,
+            init_submodules: false,
+            from: None,
         }
     }
 
@@ -72,6 +81,8 @@ impl CreateSessionRequest {
             runtime_mode: RuntimeMode::Terminal,
             use_main_worktree: false,
             initial_prompt: None,
+            init_submodules: false,
+            from: None,
         }
     }
 
@@ -104,4 +115,14 @@ impl CreateSessionRequest {
         self.initial_prompt = prompt;
         self
     }
+
+    pub fn with_init_submodules(mut self, init_submodules: bool) -> Self {
+        self.init_submodules = init_submodules;
+        self
+    }
+
+    pub fn with_from(mut self, from: Option<SessionId>) -> Self {
+        self.from = from;
+        self
+    }
 }
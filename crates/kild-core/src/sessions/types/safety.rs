@@ -17,6 +17,8 @@ pub struct DestroySafety {
     pub git_status: WorktreeStatus,
     /// PR check result for the kild's branch.
     pub pr_status: PrCheckResult,
+    /// Number of stash entries created on the kild's branch.
+    pub stash_count: usize,
 }
 
 impl DestroySafety {
@@ -25,13 +27,15 @@ impl DestroySafety {
     /// Blocks on:
     /// - Uncommitted changes (cannot be recovered)
     /// - Status check failure with conservative fallback (user should verify manually)
+    /// - Stashes on the branch (orphaned once the worktree and branch are gone)
     pub fn should_block(&self) -> bool {
-        self.git_status.has_uncommitted_changes
+        self.git_status.has_uncommitted_changes || self.stash_count > 0
     }
 
     /// Returns true if there are any warnings to show the user.
     pub fn has_warnings(&self) -> bool {
         self.git_status.has_uncommitted_changes
+            || self.stash_count > 0
             || self.git_status.unpushed_commit_count > 0
             || !self.git_status.has_remote_branch
             || self.pr_status.not_found()
@@ -43,9 +47,10 @@ impl DestroySafety {
     /// Returns a list of human-readable warning messages in severity order:
     /// 1. Status check failures (critical - user should verify manually)
     /// 2. Uncommitted changes (blocking)
-    /// 3. Unpushed commits (warning)
-    /// 4. Never pushed (warning)
-    /// 5. No PR found (advisory)
+    /// 3. Stashes (blocking)
+    /// 4. Unpushed commits (warning)
+    /// 5. Never pushed (warning)
+    /// 6. No PR found (advisory)
     pub fn warning_messages(&self) -> Vec<String> {
         let mut messages = Vec::new();
 
@@ -76,6 +81,19 @@ impl DestroySafety {
             messages.push(message);
         }
 
+        // Stashes (blocking)
+        if self.stash_count > 0 {
+            let stash_word = if self.stash_count == 1 {
+                "stash"
+            } else {
+                "stashes"
+            };
+            messages.push(format!(
+                "{} {} on this branch will be orphaned",
+                self.stash_count, stash_word
+            ));
+        }
+
         // Unpushed commits (warning only)
         if self.git_status.unpushed_commit_count > 0 {
             let count = self.git_status.unpushed_commit_count;
@@ -115,6 +133,8 @@ pub struct CompleteRequest {
     pub dry_run: bool,
     /// Skip CI status check before merging.
     pub skip_ci: bool,
+    /// Open a draft PR if none exists, then clean up without merging.
+    pub draft: bool,
 }
 
 impl CompleteRequest {
@@ -127,6 +147,7 @@ impl CompleteRequest {
             force: false,
             dry_run: false,
             skip_ci: false,
+            draft: false,
         }
     }
 }
@@ -148,6 +169,11 @@ pub enum CompleteResult {
     },
     /// --no-merge mode: PR not merged, session destroyed, remote branch preserved.
     CleanupOnly,
+    /// --draft mode: draft PR opened, session destroyed, remote branch preserved.
+    DraftOpened {
+        /// URL of the newly opened PR.
+        url: String,
+    },
     /// --dry-run: shows what would happen.
     DryRun {
         /// Steps that would be performed.
@@ -60,6 +60,13 @@ pub struct Session {
     #[serde(default)]
     pub issue: Option<u32>,
 
+    /// Free-form labels for grouping kilds (e.g. by feature area).
+    ///
+    /// Managed via `session_ops::add_tag` / `remove_tag`. Empty for sessions
+    /// created before tagging was added.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     /// Agent session ID for resume support.
     ///
     /// Generated on `kild create` and fresh `kild open` for resume-capable agents (e.g., Claude Code).
@@ -102,6 +109,20 @@ pub struct Session {
     #[serde(default)]
     pub use_main_worktree: bool,
 
+    /// Base branch the worktree was created from (config or `--base`).
+    ///
+    /// Seeds `kild create --from`. `None` for sessions created before this
+    /// field was added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_branch: Option<String>,
+
+    /// Include patterns in effect when the worktree was created.
+    ///
+    /// Seeds `kild create --from`. `None` for sessions created before this
+    /// field was added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_patterns: Option<kild_config::IncludeConfig>,
+
     /// All agent processes opened in this kild session.
     ///
     /// Populated by `kild create` (initial agent) and `kild open` (additional agents).
@@ -147,12 +168,15 @@ impl Session {
             last_activity,
             note,
             issue,
+            tags: Vec::new(),
             agents,
             agent_session_id,
             agent_session_id_history: Vec::new(),
             task_list_id,
             runtime_mode,
             use_main_worktree: false,
+            base_branch: None,
+            include_patterns: None,
         }
     }
 
@@ -269,12 +293,15 @@ impl Session {
             last_activity: None,
             note: None,
             issue: None,
+            tags: Vec::new(),
             agents: vec![],
             agent_session_id: None,
             agent_session_id_history: Vec::new(),
             task_list_id: None,
             runtime_mode: None,
             use_main_worktree: false,
+            base_branch: None,
+            include_patterns: None,
         }
     }
 }
@@ -11,6 +11,9 @@ pub enum SessionStatus {
     Stopped,
     #[serde(alias = "Destroyed")]
     Destroyed,
+    /// Set aside by `kild archive`: kept on disk but hidden from `kild list`.
+    #[serde(alias = "Archived")]
+    Archived,
 }
 
 /// Convert a daemon protocol `SessionStatus` into a core `SessionStatus`.
@@ -44,6 +47,7 @@ impl std::fmt::Display for SessionStatus {
             Self::Active => write!(f, "active"),
             Self::Stopped => write!(f, "stopped"),
             Self::Destroyed => write!(f, "destroyed"),
+            Self::Archived => write!(f, "archived"),
         }
     }
 }
@@ -62,7 +66,7 @@ pub struct AgentStatusRecord {
 ///
 /// Represents whether the agent process is currently running, stopped,
 /// or in an unknown state (detection failed).
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ProcessStatus {
     /// Process is confirmed running
@@ -76,7 +80,7 @@ pub enum ProcessStatus {
 /// Git working tree status for a kild session.
 ///
 /// Represents whether the worktree has uncommitted changes.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum GitStatus {
     /// Worktree has no uncommitted changes
     Clean,
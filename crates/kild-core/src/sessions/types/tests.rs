@@ -380,6 +380,29 @@ fn test_should_block_on_status_check_failed() {
     assert!(info.has_warnings());
 }
 
+#[test]
+fn test_should_block_on_stashes() {
+    let info = DestroySafety {
+        stash_count: 1,
+        ..Default::default()
+    };
+    assert!(info.should_block());
+    assert!(info.has_warnings());
+    assert!(
+        info.warning_messages()
+            .contains(&"1 stash on this branch will be orphaned".to_string())
+    );
+
+    let info = DestroySafety {
+        stash_count: 3,
+        ..Default::default()
+    };
+    assert!(
+        info.warning_messages()
+            .contains(&"3 stashes on this branch will be orphaned".to_string())
+    );
+}
+
 #[test]
 fn test_has_warnings_no_pr() {
     let info = DestroySafety {
@@ -400,6 +423,7 @@ fn test_has_warnings_pr_unavailable_no_warning() {
             has_remote_branch: true,
             ..Default::default()
         },
+        ..Default::default()
     };
     assert!(!info.has_warnings());
 }
@@ -525,6 +549,7 @@ fn test_warning_messages_no_warnings() {
             ..Default::default()
         },
         pr_status: PrCheckResult::Exists,
+        ..Default::default()
     };
     assert!(!info.has_warnings());
     assert!(info.warning_messages().is_empty());
@@ -982,6 +1007,10 @@ fn test_session_status_serializes_as_snake_case() {
         serde_json::to_string(&SessionStatus::Destroyed).unwrap(),
         r#""destroyed""#
     );
+    assert_eq!(
+        serde_json::to_string(&SessionStatus::Archived).unwrap(),
+        r#""archived""#
+    );
 }
 
 #[test]
@@ -1006,6 +1035,7 @@ fn test_session_status_roundtrip_new_format() {
         SessionStatus::Active,
         SessionStatus::Stopped,
         SessionStatus::Destroyed,
+        SessionStatus::Archived,
     ] {
         let json = serde_json::to_string(&status).unwrap();
         let parsed: SessionStatus = serde_json::from_str(&json).unwrap();
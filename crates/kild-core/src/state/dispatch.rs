@@ -1,10 +1,11 @@
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
-use crate::projects::{Project, load_projects, save_projects};
+use crate::projects::{Project, ProjectsData, load_projects, save_projects};
 use crate::sessions::handler as session_ops;
 use crate::sessions::types::CreateSessionRequest;
 use crate::state::errors::DispatchError;
 use crate::state::events::Event;
+use crate::state::replay::{EventRecorder, StoreState};
 use crate::state::store::Store;
 use crate::state::types::Command;
 use kild_config::KildConfig;
@@ -15,13 +16,217 @@ use kild_protocol::RuntimeMode;
 /// Holds a `KildConfig` used only by the `CreateKild` command. Other session
 /// commands (`DestroyKild`, `OpenKild`, `StopKild`, `CompleteKild`) load their
 /// own config internally via their handlers.
+///
+/// Every successful dispatch folds its events into a [`StoreState`]. With
+/// [`with_event_log`](Self::with_event_log), the events are also recorded so
+/// they can be attached to a bug report and fed to [`replay`](Self::replay).
 pub struct CoreStore {
     config: KildConfig,
+    state: StoreState,
+    event_log: Option<EventRecorder>,
 }
 
 impl CoreStore {
     pub fn new(config: KildConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            state: StoreState::default(),
+            event_log: None,
+        }
+    }
+
+    /// Record dispatched events, keeping at most the last `capacity`.
+    pub fn with_event_log(mut self, capacity: usize) -> Self {
+        self.event_log = Some(EventRecorder::new(capacity));
+        self
+    }
+
+    /// Pause or resume recording. No-op unless built with [`with_event_log`](Self::with_event_log).
+    pub fn set_event_log_enabled(&mut self, enabled: bool) {
+        if let Some(log) = &mut self.event_log {
+            log.set_enabled(enabled);
+        }
+    }
+
+    /// Recorded events, oldest first. Empty when recording is off.
+    pub fn event_log(&self) -> Vec<Event> {
+        self.event_log
+            .as_ref()
+            .map(EventRecorder::events)
+            .unwrap_or_default()
+    }
+
+    /// State as rebuilt from the events this store has produced.
+    pub fn state(&self) -> &StoreState {
+        &self.state
+    }
+
+    /// Rebuild a store from a recorded event log.
+    ///
+    /// Only the [`StoreState`] is rebuilt: no commands run, so nothing on disk
+    /// or in git is touched. The returned store does not record events.
+    pub fn replay(config: KildConfig, events: impl IntoIterator<Item = Event>) -> Self {
+        let mut store = Self::new(config);
+        let mut count = 0;
+        for event in events {
+            store.state.apply(&event);
+            count += 1;
+        }
+        info!(event = "core.state.replay_completed", event_count = count);
+        store
+    }
+
+    /// Dispatch `commands` in order as one all-or-nothing unit.
+    ///
+    /// If any command fails, the commands already applied in the batch are
+    /// reverted in reverse order and the failing command's error is returned,
+    /// leaving the store as it was before the batch. On success, returns the
+    /// events of every command in dispatch order.
+    ///
+    /// Project commands are reverted by restoring the project list, and
+    /// `CreateKild` by force-destroying the new kild. Refresh commands need no
+    /// revert. Commands that stop, destroy, complete or open a kild, or update
+    /// agent status, can't be undone, so a batch may contain at most one of
+    /// them and only as its last command; other batches are rejected with
+    /// [`DispatchError::BatchNotReversible`] before anything runs.
+    pub fn dispatch_batch(&mut self, commands: Vec<Command>) -> Result<Vec<Event>, DispatchError> {
+        info!(
+            event = "core.state.dispatch_batch_started",
+            command_count = commands.len()
+        );
+
+        if let Some(pos) = commands
+            .iter()
+            .position(|cmd| revert_kind(cmd) == RevertKind::Irreversible)
+            && pos + 1 != commands.len()
+        {
+            let err = DispatchError::BatchNotReversible(command_name(&commands[pos]).to_string());
+            error!(event = "core.state.dispatch_batch_failed", error = %err);
+            return Err(err);
+        }
+
+        let projects_snapshot = commands
+            .iter()
+            .any(|cmd| revert_kind(cmd) == RevertKind::RestoreProjects)
+            .then(load_projects);
+
+        let state_snapshot = self.state.clone();
+        let log_snapshot = self.event_log.clone();
+        let mut events = Vec::new();
+        let mut applied: Vec<(RevertKind, Vec<Event>)> = Vec::new();
+
+        for cmd in commands {
+            let kind = revert_kind(&cmd);
+            match self.dispatch(cmd) {
+                Ok(cmd_events) => {
+                    events.extend(cmd_events.iter().cloned());
+                    applied.push((kind, cmd_events));
+                }
+                Err(e) => {
+                    error!(
+                        event = "core.state.dispatch_batch_failed",
+                        applied_count = applied.len(),
+                        error = %e
+                    );
+                    self.revert_batch(applied, projects_snapshot.as_ref());
+                    self.state = state_snapshot;
+                    self.event_log = log_snapshot;
+                    return Err(e);
+                }
+            }
+        }
+
+        info!(
+            event = "core.state.dispatch_batch_completed",
+            event_count = events.len()
+        );
+        Ok(events)
+    }
+
+    /// Undo applied batch commands, newest first. Best-effort: a failed revert
+    /// is logged and the remaining reverts still run.
+    fn revert_batch(
+        &mut self,
+        applied: Vec<(RevertKind, Vec<Event>)>,
+        projects_snapshot: Option<&ProjectsData>,
+    ) {
+        let mut restore_projects = false;
+
+        for (kind, cmd_events) in applied.into_iter().rev() {
+            match kind {
+                RevertKind::DestroyCreated => {
+                    for event in cmd_events {
+                        if let Event::KildCreated { branch, .. } = event
+                            && let Err(e) = session_ops::destroy_session(&branch, true)
+                        {
+                            warn!(
+                                event = "core.state.dispatch_batch_revert_failed",
+                                branch = %branch,
+                                error = %e
+                            );
+                        }
+                    }
+                }
+                RevertKind::RestoreProjects => restore_projects = true,
+                RevertKind::Nothing | RevertKind::Irreversible => {}
+            }
+        }
+
+        if restore_projects
+            && let Some(snapshot) = projects_snapshot
+            && let Err(e) = save_projects(snapshot)
+        {
+            warn!(
+                event = "core.state.dispatch_batch_revert_failed",
+                error = %e
+            );
+        }
+
+        info!(event = "core.state.dispatch_batch_reverted");
+    }
+}
+
+/// How a successfully applied command is undone when its batch fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RevertKind {
+    /// Force-destroy the kild named in the `KildCreated` event.
+    DestroyCreated,
+    /// Write back the project list captured before the batch.
+    RestoreProjects,
+    /// Only refreshes cached state; nothing to undo.
+    Nothing,
+    /// Side effects (processes, worktrees, status files) can't be undone.
+    Irreversible,
+}
+
+fn revert_kind(cmd: &Command) -> RevertKind {
+    match cmd {
+        Command::CreateKild { .. } => RevertKind::DestroyCreated,
+        Command::AddProject { .. }
+        | Command::RemoveProject { .. }
+        | Command::SelectProject { .. } => RevertKind::RestoreProjects,
+        Command::RefreshPrStatus { .. } | Command::RefreshSessions => RevertKind::Nothing,
+        Command::DestroyKild { .. }
+        | Command::OpenKild { .. }
+        | Command::StopKild { .. }
+        | Command::CompleteKild { .. }
+        | Command::UpdateAgentStatus { .. } => RevertKind::Irreversible,
+    }
+}
+
+fn command_name(cmd: &Command) -> &'static str {
+    match cmd {
+        Command::CreateKild { .. } => "CreateKild",
+        Command::DestroyKild { .. } => "DestroyKild",
+        Command::OpenKild { .. } => "OpenKild",
+        Command::StopKild { .. } => "StopKild",
+        Command::CompleteKild { .. } => "CompleteKild",
+        Command::UpdateAgentStatus { .. } => "UpdateAgentStatus",
+        Command::RefreshPrStatus { .. } => "RefreshPrStatus",
+        Command::RefreshSessions => "RefreshSessions",
+        Command::AddProject { .. } => "AddProject",
+        Command::RemoveProject { .. } => "RemoveProject",
+        Command::SelectProject { .. } => "SelectProject",
     }
 }
 
@@ -93,7 +298,7 @@ impl Store for CoreStore {
                 Ok(vec![Event::KildCompleted { branch }])
             }
             Command::UpdateAgentStatus { branch, status } => {
-                session_ops::update_agent_status(&branch, status, false)?;
+                session_ops::update_agent_status(&branch, status, None)?;
                 Ok(vec![Event::AgentStatusUpdated { branch, status }])
             }
             Command::RefreshPrStatus { branch } => {
@@ -114,7 +319,7 @@ impl Store for CoreStore {
                 Ok(vec![Event::PrStatusRefreshed { branch }])
             }
             Command::RefreshSessions => {
-                session_ops::list_sessions()?;
+                session_ops::list_sessions(false)?;
                 Ok(vec![Event::SessionsRefreshed])
             }
             Command::AddProject { path, name } => {
@@ -181,10 +386,18 @@ impl Store for CoreStore {
         };
 
         match &result {
-            Ok(events) => info!(
-                event = "core.state.dispatch_completed",
-                event_count = events.len()
-            ),
+            Ok(events) => {
+                for event in events {
+                    self.state.apply(event);
+                }
+                if let Some(log) = &mut self.event_log {
+                    log.record(events);
+                }
+                info!(
+                    event = "core.state.dispatch_completed",
+                    event_count = events.len()
+                )
+            }
             Err(e) => error!(event = "core.state.dispatch_failed", error = %e),
         }
 
@@ -696,4 +909,235 @@ mod tests {
             Err(DispatchError::Session(SessionError::NotFound { .. }))
         ));
     }
+
+    // --- Batch dispatch ---
+
+    #[test]
+    fn test_dispatch_batch_applies_all_commands() {
+        let _lock = PROJECTS_FILE_ENV_LOCK.lock().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let projects_file = temp_dir.path().join("projects.json");
+        let _guard = ProjectsFileEnvGuard::new(&projects_file);
+
+        let repo1 = create_temp_git_repo();
+        let repo2 = create_temp_git_repo();
+        let canonical2 = repo2.path().canonicalize().unwrap();
+        let mut store = CoreStore::new(KildConfig::default());
+
+        let events = store
+            .dispatch_batch(vec![
+                Command::AddProject {
+                    path: repo1.path().to_path_buf(),
+                    name: Some("First".to_string()),
+                },
+                Command::AddProject {
+                    path: repo2.path().to_path_buf(),
+                    name: Some("Second".to_string()),
+                },
+                Command::SelectProject {
+                    path: Some(canonical2.clone()),
+                },
+            ])
+            .expect("Batch should succeed");
+
+        assert_eq!(events.len(), 3);
+        assert!(matches!(&events[0], Event::ProjectAdded { name, .. } if name == "First"));
+        assert!(matches!(&events[1], Event::ProjectAdded { name, .. } if name == "Second"));
+        assert!(matches!(
+            &events[2],
+            Event::ActiveProjectChanged { path: Some(p) } if p == &canonical2
+        ));
+
+        let loaded = load_projects();
+        assert_eq!(loaded.projects.len(), 2);
+        assert_eq!(loaded.active, Some(canonical2));
+    }
+
+    #[test]
+    fn test_dispatch_batch_failure_reverts_applied_commands() {
+        let _lock = PROJECTS_FILE_ENV_LOCK.lock().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let projects_file = temp_dir.path().join("projects.json");
+        let _guard = ProjectsFileEnvGuard::new(&projects_file);
+
+        let repo1 = create_temp_git_repo();
+        let repo2 = create_temp_git_repo();
+        let mut store = CoreStore::new(KildConfig::default());
+
+        store
+            .dispatch(Command::AddProject {
+                path: repo1.path().to_path_buf(),
+                name: Some("Existing".to_string()),
+            })
+            .unwrap();
+        let before = std::fs::read_to_string(&projects_file).unwrap();
+
+        let result = store.dispatch_batch(vec![
+            Command::AddProject {
+                path: repo2.path().to_path_buf(),
+                name: Some("New".to_string()),
+            },
+            Command::SelectProject {
+                path: Some(repo2.path().canonicalize().unwrap()),
+            },
+            Command::RemoveProject {
+                path: PathBuf::from("/does/not/exist"),
+            },
+            Command::SelectProject { path: None },
+        ]);
+
+        assert!(matches!(
+            result,
+            Err(DispatchError::Project(
+                crate::projects::ProjectError::NotFound
+            ))
+        ));
+        assert_eq!(std::fs::read_to_string(&projects_file).unwrap(), before);
+
+        let loaded = load_projects();
+        assert_eq!(loaded.projects.len(), 1);
+        assert_eq!(loaded.projects[0].name(), "Existing");
+        assert_eq!(loaded.active, Some(repo1.path().canonicalize().unwrap()));
+    }
+
+    #[test]
+    fn test_dispatch_batch_rejects_irreversible_command_before_last() {
+        let _lock = PROJECTS_FILE_ENV_LOCK.lock().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let projects_file = temp_dir.path().join("projects.json");
+        let _guard = ProjectsFileEnvGuard::new(&projects_file);
+
+        let repo = create_temp_git_repo();
+        let mut store = CoreStore::new(KildConfig::default());
+
+        let result = store.dispatch_batch(vec![
+            Command::StopKild {
+                branch: "feat".into(),
+            },
+            Command::AddProject {
+                path: repo.path().to_path_buf(),
+                name: None,
+            },
+        ]);
+
+        assert!(
+            matches!(result, Err(DispatchError::BatchNotReversible(ref cmd)) if cmd == "StopKild")
+        );
+        assert!(
+            !projects_file.exists(),
+            "Nothing should run when the batch is rejected"
+        );
+    }
+
+    // --- Event log replay ---
+
+    #[test]
+    fn test_replay_recorded_events_rebuilds_state() {
+        let _lock = PROJECTS_FILE_ENV_LOCK.lock().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let projects_file = temp_dir.path().join("projects.json");
+        let _guard = ProjectsFileEnvGuard::new(&projects_file);
+
+        let repo1 = create_temp_git_repo();
+        let repo2 = create_temp_git_repo();
+        let repo3 = create_temp_git_repo();
+        let mut store = CoreStore::new(KildConfig::default()).with_event_log(64);
+
+        for (repo, name) in [(&repo1, "One"), (&repo2, "Two"), (&repo3, "Three")] {
+            store
+                .dispatch(Command::AddProject {
+                    path: repo.path().to_path_buf(),
+                    name: Some(name.to_string()),
+                })
+                .unwrap();
+        }
+        store
+            .dispatch(Command::SelectProject {
+                path: Some(repo3.path().canonicalize().unwrap()),
+            })
+            .unwrap();
+        store
+            .dispatch(Command::RemoveProject {
+                path: repo1.path().canonicalize().unwrap(),
+            })
+            .unwrap();
+        store.dispatch(Command::RefreshSessions).unwrap();
+
+        let log = store.event_log();
+        assert_eq!(log.len(), 6);
+
+        // The log survives a round trip through a bug report attachment
+        let attached = serde_json::to_string(&log).unwrap();
+        let events: Vec<Event> = serde_json::from_str(&attached).unwrap();
+
+        let replayed = CoreStore::replay(KildConfig::default(), events);
+        assert_eq!(replayed.state(), store.state());
+        assert_eq!(
+            store.state().projects,
+            vec![
+                repo2.path().canonicalize().unwrap(),
+                repo3.path().canonicalize().unwrap()
+            ]
+        );
+        assert_eq!(
+            store.state().active_project,
+            Some(repo3.path().canonicalize().unwrap())
+        );
+
+        // Replaying the on-disk result agrees with what was persisted
+        let loaded = load_projects();
+        let persisted: Vec<PathBuf> = loaded
+            .projects
+            .iter()
+            .map(|p| p.path().to_path_buf())
+            .collect();
+        assert_eq!(replayed.state().projects, persisted);
+        assert_eq!(replayed.state().active_project, loaded.active);
+    }
+
+    #[test]
+    fn test_event_log_is_toggleable_and_bounded() {
+        let mut store = CoreStore::new(KildConfig::default());
+        store.dispatch(Command::RefreshSessions).unwrap();
+        assert!(store.event_log().is_empty(), "Recording is off by default");
+
+        let mut store = CoreStore::new(KildConfig::default()).with_event_log(2);
+        store.set_event_log_enabled(false);
+        store.dispatch(Command::RefreshSessions).unwrap();
+        assert!(store.event_log().is_empty());
+
+        store.set_event_log_enabled(true);
+        for _ in 0..3 {
+            store.dispatch(Command::RefreshSessions).unwrap();
+        }
+        assert_eq!(
+            store.event_log(),
+            vec![Event::SessionsRefreshed, Event::SessionsRefreshed]
+        );
+    }
+
+    #[test]
+    fn test_failed_batch_leaves_event_log_and_state_unchanged() {
+        let _lock = PROJECTS_FILE_ENV_LOCK.lock().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let projects_file = temp_dir.path().join("projects.json");
+        let _guard = ProjectsFileEnvGuard::new(&projects_file);
+
+        let repo = create_temp_git_repo();
+        let mut store = CoreStore::new(KildConfig::default()).with_event_log(16);
+
+        let result = store.dispatch_batch(vec![
+            Command::AddProject {
+                path: repo.path().to_path_buf(),
+                name: None,
+            },
+            Command::RemoveProject {
+                path: PathBuf::from("/does/not/exist"),
+            },
+        ]);
+
+        assert!(result.is_err());
+        assert!(store.event_log().is_empty());
+        assert_eq!(store.state(), &StoreState::default());
+    }
 }
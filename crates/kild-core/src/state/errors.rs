@@ -13,6 +13,8 @@ pub enum DispatchError {
     Config(#[from] ConfigError),
     #[error("Command not implemented: {0}")]
     NotImplemented(String),
+    #[error("{0} can't be undone, so it must be the last command in a batch")]
+    BatchNotReversible(String),
 }
 
 impl KildError for DispatchError {
@@ -22,6 +24,7 @@ impl KildError for DispatchError {
             DispatchError::Project(e) => e.error_code(),
             DispatchError::Config(e) => e.error_code(),
             DispatchError::NotImplemented(_) => "DISPATCH_NOT_IMPLEMENTED",
+            DispatchError::BatchNotReversible(_) => "DISPATCH_BATCH_NOT_REVERSIBLE",
         }
     }
 
@@ -31,6 +34,7 @@ impl KildError for DispatchError {
             DispatchError::Project(e) => e.is_user_error(),
             DispatchError::Config(e) => e.is_user_error(),
             DispatchError::NotImplemented(_) => false,
+            DispatchError::BatchNotReversible(_) => true,
         }
     }
 }
@@ -97,6 +101,17 @@ mod tests {
         assert_eq!(err.to_string(), "Command not implemented: AddProject");
     }
 
+    #[test]
+    fn test_dispatch_error_batch_not_reversible() {
+        let err = DispatchError::BatchNotReversible("StopKild".to_string());
+        assert_eq!(err.error_code(), "DISPATCH_BATCH_NOT_REVERSIBLE");
+        assert!(err.is_user_error());
+        assert_eq!(
+            err.to_string(),
+            "StopKild can't be undone, so it must be the last command in a batch"
+        );
+    }
+
     #[test]
     fn test_dispatch_error_session_delegates_error_code() {
         let err = DispatchError::Session(SessionError::AlreadyExists {
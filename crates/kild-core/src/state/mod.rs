@@ -1,11 +1,13 @@
 pub mod dispatch;
 pub mod errors;
 pub mod events;
+pub mod replay;
 pub mod store;
 pub mod types;
 
 pub use dispatch::CoreStore;
 pub use errors::DispatchError;
 pub use events::Event;
+pub use replay::{KildLifecycle, KildState, StoreState};
 pub use store::Store;
 pub use types::Command;
@@ -0,0 +1,214 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::path::PathBuf;
+
+use kild_protocol::{AgentStatus, SessionId};
+
+use super::events::Event;
+
+/// Lifecycle of a kild as seen through the event stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KildLifecycle {
+    /// Created; no agent opened or stopped since.
+    Created,
+    /// An agent was opened after creation or a stop.
+    Running,
+    Stopped,
+}
+
+/// What the event stream says about one kild.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KildState {
+    pub session_id: SessionId,
+    pub lifecycle: KildLifecycle,
+    /// Agent of the most recent `KildOpened`, if any.
+    pub agent: Option<String>,
+    pub agent_status: Option<AgentStatus>,
+}
+
+/// State rebuilt purely from [`Event`]s.
+///
+/// `CoreStore` keeps one up to date as it dispatches, and
+/// [`CoreStore::replay`](super::CoreStore::replay) rebuilds one from a
+/// recorded log, so a bug report's event log reproduces the state the UI saw.
+/// Kilds created before recording started are unknown to it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoreState {
+    /// Kilds by branch name.
+    pub kilds: BTreeMap<String, KildState>,
+    /// Project paths in the order they were added.
+    pub projects: Vec<PathBuf>,
+    pub active_project: Option<PathBuf>,
+}
+
+impl StoreState {
+    /// Fold one event into the state.
+    pub fn apply(&mut self, event: &Event) {
+        match event {
+            Event::KildCreated { branch, session_id } => {
+                self.kilds.insert(
+                    branch.to_string(),
+                    KildState {
+                        session_id: session_id.clone(),
+                        lifecycle: KildLifecycle::Created,
+                        agent: None,
+                        agent_status: None,
+                    },
+                );
+            }
+            Event::KildDestroyed { branch } | Event::KildCompleted { branch } => {
+                self.kilds.remove(&**branch);
+            }
+            Event::KildOpened { branch, agent } => {
+                if let Some(kild) = self.kilds.get_mut(&**branch) {
+                    kild.lifecycle = KildLifecycle::Running;
+                    kild.agent = Some(agent.clone());
+                }
+            }
+            Event::KildStopped { branch } => {
+                if let Some(kild) = self.kilds.get_mut(&**branch) {
+                    kild.lifecycle = KildLifecycle::Stopped;
+                }
+            }
+            Event::AgentStatusUpdated { branch, status } => {
+                if let Some(kild) = self.kilds.get_mut(&**branch) {
+                    kild.agent_status = Some(*status);
+                }
+            }
+            Event::PrStatusRefreshed { .. } | Event::SessionsRefreshed => {}
+            Event::ProjectAdded { path, .. } => {
+                // Mirrors dispatch: the first project added becomes active
+                if self.projects.is_empty() {
+                    self.active_project = Some(path.clone());
+                }
+                if !self.projects.contains(path) {
+                    self.projects.push(path.clone());
+                }
+            }
+            Event::ProjectRemoved { path } => {
+                self.projects.retain(|p| p != path);
+                if self.active_project.as_ref() == Some(path) {
+                    self.active_project = self.projects.first().cloned();
+                }
+            }
+            Event::ActiveProjectChanged { path } => {
+                self.active_project = path.clone();
+            }
+        }
+    }
+}
+
+/// Bounded, append-only record of dispatched events.
+///
+/// Holds at most `capacity` events; once full, the oldest are dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct EventRecorder {
+    capacity: usize,
+    enabled: bool,
+    events: VecDeque<Event>,
+}
+
+impl EventRecorder {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            enabled: true,
+            events: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub(crate) fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub(crate) fn record(&mut self, events: &[Event]) {
+        if !self.enabled || self.capacity == 0 {
+            return;
+        }
+        for event in events {
+            if self.events.len() == self.capacity {
+                self.events.pop_front();
+            }
+            self.events.push_back(event.clone());
+        }
+    }
+
+    pub(crate) fn events(&self) -> Vec<Event> {
+        self.events.iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn created(branch: &str) -> Event {
+        Event::KildCreated {
+            branch: branch.into(),
+            session_id: format!("proj/{branch}").into(),
+        }
+    }
+
+    #[test]
+    fn test_apply_tracks_kild_lifecycle() {
+        let mut state = StoreState::default();
+        state.apply(&created("feat"));
+        state.apply(&Event::KildOpened {
+            branch: "feat".into(),
+            agent: "claude".to_string(),
+        });
+        state.apply(&Event::AgentStatusUpdated {
+            branch: "feat".into(),
+            status: AgentStatus::Working,
+        });
+        state.apply(&Event::KildStopped {
+            branch: "feat".into(),
+        });
+
+        let kild = &state.kilds["feat"];
+        assert_eq!(kild.lifecycle, KildLifecycle::Stopped);
+        assert_eq!(kild.agent.as_deref(), Some("claude"));
+        assert_eq!(kild.agent_status, Some(AgentStatus::Working));
+
+        state.apply(&Event::KildDestroyed {
+            branch: "feat".into(),
+        });
+        assert!(state.kilds.is_empty());
+    }
+
+    #[test]
+    fn test_apply_removing_active_project_selects_first_remaining() {
+        let mut state = StoreState::default();
+        for path in ["/a", "/b"] {
+            state.apply(&Event::ProjectAdded {
+                path: PathBuf::from(path),
+                name: path.to_string(),
+            });
+        }
+        assert_eq!(state.active_project, Some(PathBuf::from("/a")));
+
+        state.apply(&Event::ProjectRemoved {
+            path: PathBuf::from("/a"),
+        });
+        assert_eq!(state.projects, vec![PathBuf::from("/b")]);
+        assert_eq!(state.active_project, Some(PathBuf::from("/b")));
+    }
+
+    #[test]
+    fn test_recorder_drops_oldest_beyond_capacity() {
+        let mut recorder = EventRecorder::new(2);
+        recorder.record(&[created("one"), created("two"), created("three")]);
+        assert_eq!(recorder.events(), vec![created("two"), created("three")]);
+    }
+
+    #[test]
+    fn test_recorder_disabled_records_nothing() {
+        let mut recorder = EventRecorder::new(8);
+        recorder.set_enabled(false);
+        recorder.record(&[created("one")]);
+        assert!(recorder.events().is_empty());
+
+        recorder.set_enabled(true);
+        recorder.record(&[created("two")]);
+        assert_eq!(recorder.events(), vec![created("two")]);
+    }
+}
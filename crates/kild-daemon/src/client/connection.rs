@@ -142,6 +142,40 @@ impl DaemonClient {
             session_id: session_id.into(),
             rows,
             cols,
+            read_only: false,
+        };
+
+        let response = self.request(&msg).await?;
+        Self::check_error(&response)?;
+        Ok(())
+    }
+
+    /// Ask for the session's write lock. Returns `false` when another
+    /// attached client holds it.
+    pub async fn request_write(&mut self, session_id: &str) -> Result<bool, DaemonError> {
+        let id = self.next_id();
+        let msg = ClientMessage::RequestWrite {
+            id,
+            session_id: session_id.into(),
+        };
+
+        let response = self.request(&msg).await?;
+        Self::check_error(&response)?;
+        match response {
+            DaemonMessage::WriteGranted { .. } => Ok(true),
+            DaemonMessage::WriteDenied { .. } => Ok(false),
+            _ => Err(DaemonError::ProtocolError(
+                "unexpected response type".to_string(),
+            )),
+        }
+    }
+
+    /// Release the session's write lock if this connection holds it.
+    pub async fn release_write(&mut self, session_id: &str) -> Result<(), DaemonError> {
+        let id = self.next_id();
+        let msg = ClientMessage::ReleaseWrite {
+            id,
+            session_id: session_id.into(),
         };
 
         let response = self.request(&msg).await?;
@@ -275,6 +309,37 @@ impl DaemonClient {
         }
     }
 
+    /// Fetch daemon runtime counters.
+    pub async fn stats(&mut self) -> Result<DaemonMessage, DaemonError> {
+        let id = self.next_id();
+        let msg = ClientMessage::Stats { id };
+
+        let response = self.request(&msg).await?;
+        Self::check_error(&response)?;
+
+        if let DaemonMessage::Stats { .. } = response {
+            Ok(response)
+        } else {
+            Err(DaemonError::ProtocolError(
+                "unexpected response type".to_string(),
+            ))
+        }
+    }
+
+    /// Ask the daemon to hand off: drain streams, save sessions, and exit
+    /// without stopping them. Closing the PTYs still hangs up their processes.
+    pub async fn handoff(&mut self, drain_timeout: std::time::Duration) -> Result<(), DaemonError> {
+        let id = self.next_id();
+        let msg = ClientMessage::Handoff {
+            id,
+            drain_timeout_ms: drain_timeout.as_millis() as u64,
+        };
+
+        let response = self.request(&msg).await?;
+        Self::check_error(&response)?;
+        Ok(())
+    }
+
     /// Request daemon shutdown.
     pub async fn shutdown(&mut self) -> Result<(), DaemonError> {
         let id = self.next_id();
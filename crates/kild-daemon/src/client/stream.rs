@@ -9,12 +9,12 @@ pub struct PtyOutputChunk {
     pub data: Vec<u8>,
 }
 
-/// Decode a `PtyOutput` daemon message into raw bytes.
+/// Decode a `PtyOutput` or `Scrollback` daemon message into raw bytes.
 ///
-/// Returns the decoded bytes, or `None` if the message is not a `PtyOutput`.
+/// Returns the decoded bytes, or `None` if the message carries no PTY output.
 pub fn decode_pty_output(msg: &DaemonMessage) -> Result<Option<PtyOutputChunk>, DaemonError> {
     match msg {
-        DaemonMessage::PtyOutput { data, .. } => {
+        DaemonMessage::PtyOutput { data, .. } | DaemonMessage::Scrollback { data, .. } => {
             let decoded = base64::engine::general_purpose::STANDARD.decode(data)?;
             Ok(Some(PtyOutputChunk { data: decoded }))
         }
@@ -37,6 +37,17 @@ mod tests {
         assert_eq!(chunk.data, b"hello world");
     }
 
+    #[test]
+    fn test_decode_scrollback() {
+        let msg = DaemonMessage::Scrollback {
+            session_id: "test".into(),
+            data: base64::engine::general_purpose::STANDARD.encode(b"earlier output"),
+        };
+
+        let chunk = decode_pty_output(&msg).unwrap().unwrap();
+        assert_eq!(chunk.data, b"earlier output");
+    }
+
     #[test]
     fn test_decode_non_pty_output() {
         let msg = DaemonMessage::Ack {
@@ -52,6 +52,15 @@ pub enum DaemonError {
 
     #[error("TLS configuration error: {0}")]
     TlsConfig(String),
+
+    #[error("session {0} is attached read-only on this connection")]
+    ReadOnlyAttach(String),
+
+    #[error("another client holds the write lock for session {0}")]
+    WriteLockHeld(String),
+
+    #[error("attach to session {0} before requesting the write lock")]
+    NotAttached(String),
 }
 
 impl KildError for DaemonError {
@@ -73,6 +82,9 @@ impl KildError for DaemonError {
             DaemonError::Base64Decode(_) => "base64_decode_error",
             DaemonError::Session(_) => "session_error",
             DaemonError::TlsConfig(_) => "tls_config_error",
+            DaemonError::ReadOnlyAttach(_)
+            | DaemonError::WriteLockHeld(_)
+            | DaemonError::NotAttached(_) => "unauthorized",
         }
     }
 
@@ -84,6 +96,9 @@ impl KildError for DaemonError {
                 | DaemonError::SessionNotRunning(_)
                 | DaemonError::ConfigInvalid(_)
                 | DaemonError::AlreadyRunning(_)
+                | DaemonError::ReadOnlyAttach(_)
+                | DaemonError::WriteLockHeld(_)
+                | DaemonError::NotAttached(_)
         )
     }
 }
@@ -138,6 +153,8 @@ mod tests {
                 DaemonError::InvalidStateTransition("test".to_string()),
                 "invalid_state_transition",
             ),
+            (DaemonError::ReadOnlyAttach("x".to_string()), "unauthorized"),
+            (DaemonError::WriteLockHeld("x".to_string()), "unauthorized"),
         ];
 
         for (err, expected_code) in cases {
@@ -34,12 +34,17 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         e
     })?;
 
-    rt.block_on(async {
+    let result = rt.block_on(async {
         kild_daemon::run_server(config).await.map_err(|e| {
             error!(event = "daemon.server_failed", error = %e);
             e
         })
-    })?;
+    });
+
+    // PTY reader threads block until their PTY closes, which after a handoff
+    // only happens once this process exits. Don't wait for them.
+    rt.shutdown_background();
 
+    result?;
     Ok(())
 }
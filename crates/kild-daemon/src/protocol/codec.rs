@@ -1,53 +1,73 @@
+use kild_protocol::Compression;
+use kild_protocol::frame::{self, DEFAULT_MAX_MESSAGE_BYTES, FrameError, LENGTH_PREFIX_BYTES};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
-use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 use crate::errors::DaemonError;
 #[cfg(test)]
 use kild_core::errors::KildError;
 
-/// Read a single JSONL message from an async buffered reader.
+/// Read a single length-prefixed message from an async reader.
 ///
-/// Returns `Ok(None)` when the stream is closed (EOF).
-/// Returns `Err` on malformed JSON or IO errors.
+/// Returns `Ok(None)` when the stream is closed (EOF) between messages.
+/// Returns `Err` on malformed JSON, truncated frames, frames over
+/// `DEFAULT_MAX_MESSAGE_BYTES`, or IO errors. Oversized frames are rejected
+/// from the length prefix alone, before any payload buffer is allocated.
+/// Compressed frames are accepted and decompressed up to the same limit.
 pub async fn read_message<R, T>(reader: &mut R) -> Result<Option<T>, DaemonError>
 where
-    R: AsyncBufRead + Unpin,
+    R: AsyncRead + Unpin,
     T: DeserializeOwned,
 {
-    let mut line = String::new();
-    let bytes_read = reader.read_line(&mut line).await?;
-    if bytes_read == 0 {
+    let mut prefix = [0u8; LENGTH_PREFIX_BYTES];
+    let filled = read_full(reader, &mut prefix).await?;
+    if filled == 0 {
         return Ok(None); // EOF
     }
+    if filled < LENGTH_PREFIX_BYTES {
+        return Err(frame_error(FrameError::Truncated {
+            expected: LENGTH_PREFIX_BYTES,
+            received: filled,
+        }));
+    }
 
-    let trimmed = line.trim();
-    if trimmed.is_empty() {
-        return Ok(None);
+    let size = frame::decode_length(prefix, DEFAULT_MAX_MESSAGE_BYTES).map_err(frame_error)?;
+    let mut body = vec![0u8; size];
+    let filled = read_full(reader, &mut body).await?;
+    if filled < size {
+        return Err(frame_error(FrameError::Truncated {
+            expected: size,
+            received: filled,
+        }));
     }
+    let payload = frame::decode_body(body, DEFAULT_MAX_MESSAGE_BYTES).map_err(frame_error)?;
 
-    let msg: T = serde_json::from_str(trimmed)
-        .map_err(|e| DaemonError::ProtocolError(format!("invalid JSON: {}: {}", e, trimmed)))?;
+    let msg: T = serde_json::from_slice(&payload).map_err(|e| {
+        DaemonError::ProtocolError(format!(
+            "invalid JSON: {}: {}",
+            e,
+            String::from_utf8_lossy(&payload)
+        ))
+    })?;
     Ok(Some(msg))
 }
 
-/// Write a single JSONL message to an async writer.
+/// Write a single length-prefixed message to an async writer.
 ///
-/// Serializes the message as compact JSON followed by a newline.
-/// Does NOT flush — callers should flush explicitly when transitioning
-/// from write phase to read phase, or when a batch of writes is complete.
+/// Serializes the message as compact JSON behind a 4-byte length prefix,
+/// uncompressed. Does NOT flush — callers should flush explicitly when
+/// transitioning from write phase to read phase, or when a batch of writes
+/// is complete.
 pub async fn write_message<W, T>(writer: &mut W, msg: &T) -> Result<(), DaemonError>
 where
     W: AsyncWrite + Unpin,
     T: Serialize,
 {
-    let json = serde_json::to_string(msg)?;
-    writer.write_all(json.as_bytes()).await?;
-    writer.write_all(b"\n").await?;
-    Ok(())
+    write_frame(writer, msg, false).await
 }
 
-/// Write a single JSONL message and flush immediately.
+/// Write a single message and flush immediately.
 ///
 /// Use for request-response messages where the peer is waiting for a response.
 /// For streaming (e.g. PTY output), prefer `write_message()` without flush.
@@ -61,6 +81,81 @@ where
     Ok(())
 }
 
+/// Write half of a client connection that remembers the compression agreed
+/// in the handshake.
+///
+/// Messages written before the handshake (or when the client offered no
+/// compression) go out as plain frames.
+pub struct MessageWriter<W> {
+    inner: W,
+    compress: bool,
+}
+
+impl<W: AsyncWrite + Unpin> MessageWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            compress: false,
+        }
+    }
+
+    /// Apply the compression picked for this connection in `HelloAck`.
+    pub fn set_compression(&mut self, compression: Option<Compression>) {
+        self.compress = compression.is_some();
+    }
+
+    /// Write one message, compressing it if agreed and large enough. No flush.
+    pub async fn write<T: Serialize>(&mut self, msg: &T) -> Result<(), DaemonError> {
+        write_frame(&mut self.inner, msg, self.compress).await
+    }
+
+    /// Write one message and flush immediately.
+    pub async fn write_flush<T: Serialize>(&mut self, msg: &T) -> Result<(), DaemonError> {
+        self.write(msg).await?;
+        self.flush().await
+    }
+
+    pub async fn flush(&mut self) -> Result<(), DaemonError> {
+        self.inner.flush().await?;
+        Ok(())
+    }
+}
+
+async fn write_frame<W, T>(writer: &mut W, msg: &T, compress: bool) -> Result<(), DaemonError>
+where
+    W: AsyncWrite + Unpin,
+    T: Serialize,
+{
+    let json = serde_json::to_vec(msg)?;
+    let bytes =
+        frame::encode_frame(&json, DEFAULT_MAX_MESSAGE_BYTES, compress).map_err(frame_error)?;
+    writer.write_all(&bytes).await?;
+    Ok(())
+}
+
+/// Fill `buf` until it is full or the reader hits EOF. Returns bytes read.
+async fn read_full<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> Result<usize, DaemonError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = reader.read(&mut buf[filled..]).await?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
+
+fn frame_error(e: FrameError) -> DaemonError {
+    match e {
+        FrameError::Io(e) => DaemonError::from(e),
+        other => DaemonError::ProtocolError(other.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,14 +204,35 @@ mod tests {
 
     #[tokio::test]
     async fn test_read_invalid_json() {
-        let buf: &[u8] = b"not json\n";
-        let mut reader = tokio::io::BufReader::new(buf);
+        let buf = frame::encode_frame(b"not json", DEFAULT_MAX_MESSAGE_BYTES, false).unwrap();
+        let mut reader = tokio::io::BufReader::new(buf.as_slice());
         let result: Result<Option<ClientMessage>, _> = read_message(&mut reader).await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert_eq!(err.error_code(), "protocol_error");
     }
 
+    #[tokio::test]
+    async fn test_read_oversized_frame_is_rejected() {
+        // A buggy client claiming a 4 GiB message must not make us allocate it
+        let buf = u32::MAX.to_be_bytes();
+        let mut reader = tokio::io::BufReader::new(buf.as_slice());
+        let result: Result<Option<ClientMessage>, _> = read_message(&mut reader).await;
+        let err = result.unwrap_err();
+        assert_eq!(err.error_code(), "protocol_error");
+        assert!(err.to_string().contains("exceeds"), "got: {}", err);
+    }
+
+    #[tokio::test]
+    async fn test_read_truncated_length_prefix() {
+        let buf: &[u8] = &[0, 0];
+        let mut reader = tokio::io::BufReader::new(buf);
+        let result: Result<Option<ClientMessage>, _> = read_message(&mut reader).await;
+        let err = result.unwrap_err();
+        assert_eq!(err.error_code(), "protocol_error");
+        assert!(err.to_string().contains("truncated"), "got: {}", err);
+    }
+
     #[tokio::test]
     async fn test_multiple_messages() {
         let msg1 = ClientMessage::DaemonStop {
@@ -175,6 +291,47 @@ mod tests {
         }
     }
 
+    fn large_output() -> DaemonMessage {
+        DaemonMessage::PtyOutput {
+            session_id: "myapp_feature-auth".into(),
+            data: "c2Nyb2xsYmFjaw==".repeat(1024),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_message_writer_compresses_large_messages_when_agreed() {
+        let mut writer = MessageWriter::new(Vec::new());
+        writer.set_compression(Some(Compression::Zstd));
+        writer.write(&large_output()).await.unwrap();
+        let buf = writer.inner;
+        assert_eq!(buf[LENGTH_PREFIX_BYTES], frame::FLAG_ZSTD);
+
+        let mut reader = tokio::io::BufReader::new(buf.as_slice());
+        let parsed: DaemonMessage = read_message(&mut reader).await.unwrap().unwrap();
+        if let DaemonMessage::PtyOutput { data, .. } = parsed {
+            assert_eq!(data.len(), 16 * 1024);
+        } else {
+            panic!("wrong variant");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_message_writer_leaves_small_or_unnegotiated_messages_plain() {
+        let mut writer = MessageWriter::new(Vec::new());
+        writer.set_compression(Some(Compression::Zstd));
+        writer
+            .write(&DaemonMessage::Ack {
+                id: "1".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(writer.inner[LENGTH_PREFIX_BYTES], 0);
+
+        let mut writer = MessageWriter::new(Vec::new());
+        writer.write(&large_output()).await.unwrap();
+        assert_eq!(writer.inner[LENGTH_PREFIX_BYTES], 0);
+    }
+
     #[tokio::test]
     async fn test_write_message_does_not_flush() {
         // write_message writes data to a BufWriter but does not flush it.
@@ -86,6 +86,20 @@ impl ManagedPty {
         Ok(())
     }
 
+    /// Query the kernel for the PTY's current window size (`TIOCGWINSZ`).
+    ///
+    /// Unlike `size()`, this reflects what the child actually sees.
+    pub fn winsize(&self) -> Result<PtySize, DaemonError> {
+        self.master
+            .lock()
+            .map_err(|e| {
+                error!(event = "daemon.pty.master_lock_failed", error = %e);
+                DaemonError::PtyError(format!("lock master: {}", e))
+            })?
+            .get_size()
+            .map_err(|e| DaemonError::PtyError(format!("get size: {}", e)))
+    }
+
     /// Get the child process ID, if available.
     pub fn child_process_id(&self) -> Option<u32> {
         self.child.process_id()
@@ -313,6 +327,25 @@ mod tests {
         assert_eq!(mgr.count(), 0);
     }
 
+    #[test]
+    fn test_resize_updates_kernel_winsize() {
+        let mut mgr = PtyManager::new();
+        let tmpdir = tempfile::tempdir().unwrap();
+        mgr.create("s1", "sleep", &["10"], tmpdir.path(), 24, 80, &[], false)
+            .unwrap();
+
+        let pty = mgr.get_mut("s1").unwrap();
+        let before = pty.winsize().unwrap();
+        assert_eq!((before.rows, before.cols), (24, 80));
+
+        pty.resize(50, 132).unwrap();
+        let after = pty.winsize().unwrap();
+        assert_eq!((after.rows, after.cols), (50, 132));
+        assert_eq!((pty.size().rows, pty.size().cols), (50, 132));
+
+        let _ = mgr.destroy("s1");
+    }
+
     #[test]
     fn test_create_with_duplicate_session_id_fails() {
         let mut mgr = PtyManager::new();
@@ -1,5 +1,5 @@
 use std::collections::VecDeque;
-use std::io::Read;
+use std::io::{Read, Write};
 use std::sync::{Arc, RwLock};
 
 use bytes::Bytes;
@@ -61,23 +61,26 @@ impl ScrollbackBuffer {
     }
 }
 
+/// Notification that a PTY reader has exited (child process ended or read error).
+pub struct PtyExitEvent {
+    pub session_id: String,
+}
+
 /// Spawn a blocking task that reads from a PTY reader and feeds output
 /// to the broadcaster.
 ///
 /// Returns a `JoinHandle` for the reader task. The task exits when the PTY
 /// reader returns EOF (child process exited) or on read error.
 ///
-/// `on_exit` is called with the session_id when the reader loop ends.
-/// Notification that a PTY reader has exited (child process ended or read error).
-pub struct PtyExitEvent {
-    pub session_id: String,
-}
-
+/// When `log` is set, every chunk is also appended to that file.
+///
+/// `exit_tx` receives a `PtyExitEvent` when the reader loop ends.
 pub fn spawn_pty_reader(
     session_id: String,
     mut reader: Box<dyn Read + Send>,
     output_tx: broadcast::Sender<Bytes>,
     scrollback: Arc<RwLock<ScrollbackBuffer>>,
+    mut log: Option<std::fs::File>,
     exit_tx: Option<tokio::sync::mpsc::UnboundedSender<PtyExitEvent>>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::task::spawn_blocking(move || {
@@ -104,6 +107,18 @@ pub fn spawn_pty_reader(
                             sb.push(&buf[..n]);
                         }
                     }
+                    // Capture output for `kild logs`. A failed write stops logging
+                    // for this session but never interrupts the PTY stream.
+                    if let Some(file) = &mut log
+                        && let Err(e) = file.write_all(&buf[..n])
+                    {
+                        warn!(
+                            event = "daemon.pty.log_write_failed",
+                            session_id = session_id,
+                            error = %e,
+                        );
+                        log = None;
+                    }
                     // broadcast::send returns Err when there are no receivers,
                     // which is normal — nobody may be attached yet. The scrollback
                     // buffer already captured the data above for replay on attach.
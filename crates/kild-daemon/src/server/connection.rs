@@ -1,14 +1,16 @@
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use base64::Engine;
 use bytes::Bytes;
-use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::io::{AsyncRead, AsyncWrite, BufReader};
 use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 
 use kild_core::errors::KildError;
 
-use crate::protocol::codec::{read_message, write_message, write_message_flush};
+use crate::errors::DaemonError;
+use crate::protocol::codec::{MessageWriter, read_message};
 use crate::protocol::messages::{ClientMessage, DaemonMessage, ErrorCode};
 use crate::session::manager::SessionManager;
 use crate::session::state::ClientId;
@@ -18,12 +20,17 @@ use crate::session::state::ClientId;
 /// Generic over `S` so it works with both Unix streams and TLS-wrapped TCP
 /// streams. The only requirement is that `S: AsyncRead + AsyncWrite + Send + Unpin + 'static`.
 ///
-/// Reads JSONL messages from the client, dispatches them to the session manager,
+/// Reads framed messages from the client, dispatches them to the session manager,
 /// and sends responses back. For `attach` requests, enters streaming mode.
+///
+/// When `auth_token` is set, the first message must be a `Hello` carrying a
+/// matching token; anything else gets an `unauthorized` error and the
+/// connection is closed.
 pub async fn handle_connection<S>(
     stream: S,
     session_manager: Arc<RwLock<SessionManager>>,
     shutdown: tokio_util::sync::CancellationToken,
+    auth_token: Option<Arc<str>>,
 ) where
     S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
 {
@@ -41,16 +48,43 @@ pub async fn handle_connection<S>(
     // (Previously used stream.into_split() which is UnixStream-specific.)
     let (reader, writer) = tokio::io::split(stream);
     let mut reader = BufReader::new(reader);
-    let writer = Arc::new(Mutex::new(writer));
+    let writer = Arc::new(Mutex::new(MessageWriter::new(writer)));
+    let mut authenticated = auth_token.is_none();
+    // Set from Hello; clients that skip the handshake are treated as pre-v8.
+    let mut client_version: Option<u32> = None;
 
     loop {
         tokio::select! {
             result = read_message::<_, ClientMessage>(&mut reader) => {
                 match result {
                     Ok(Some(msg)) => {
+                        if !authenticated {
+                            let expected = auth_token.as_deref().unwrap_or_default();
+                            if let Err(reason) = authenticate(&msg, expected) {
+                                warn!(
+                                    event = "daemon.connection.auth_rejected",
+                                    client_id = client_id,
+                                    reason = reason,
+                                );
+                                let response = DaemonMessage::Error {
+                                    id: msg.id().to_string(),
+                                    code: ErrorCode::Unauthorized,
+                                    message: reason.to_string(),
+                                };
+                                let _ = writer.lock().await.write_flush(&response).await;
+                                break;
+                            }
+                            authenticated = true;
+                        }
+
+                        if let ClientMessage::Hello { protocol_version, .. } = &msg {
+                            client_version = Some(*protocol_version);
+                        }
+
                         let response = dispatch_message(
                             msg,
                             client_id,
+                            client_version,
                             &session_manager,
                             writer.clone(),
                             &shutdown,
@@ -58,7 +92,7 @@ pub async fn handle_connection<S>(
 
                         if let Some(response) = response {
                             let mut w = writer.lock().await;
-                            if let Err(e) = write_message_flush(&mut *w, &response).await {
+                            if let Err(e) = w.write_flush(&response).await {
                                 error!(
                                     event = "daemon.connection.write_failed",
                                     client_id = client_id,
@@ -100,14 +134,67 @@ pub async fn handle_connection<S>(
     mgr.detach_client_from_all(client_id);
 }
 
+/// Check the first message on a connection that requires `expected`.
+fn authenticate(msg: &ClientMessage, expected: &str) -> Result<(), &'static str> {
+    match msg {
+        ClientMessage::Hello {
+            auth_token: Some(token),
+            ..
+        } if tokens_match(token, expected) => Ok(()),
+        ClientMessage::Hello {
+            auth_token: Some(_),
+            ..
+        } => Err("Invalid auth token"),
+        ClientMessage::Hello { .. } => Err("This listener requires an auth token"),
+        _ => Err("Send hello with an auth token before any other request"),
+    }
+}
+
+/// Compare without short-circuiting so response timing doesn't reveal how
+/// much of a guessed token was right.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// First protocol version that understands `DaemonMessage::Pong`.
+const PONG_VERSION: u32 = 8;
+
+/// Answer a `Ping`: `Pong` for clients that announced v8+, `Ack` otherwise.
+fn ping_reply(id: String, client_version: Option<u32>) -> DaemonMessage {
+    match client_version {
+        Some(v) if v >= PONG_VERSION => DaemonMessage::Pong { id },
+        _ => DaemonMessage::Ack { id },
+    }
+}
+
+/// Reject input or a resize from an attach without the write lock.
+fn input_rejection(id: String, client_id: ClientId, e: DaemonError) -> DaemonMessage {
+    warn!(
+        event = "daemon.connection.input_rejected",
+        client_id = client_id,
+        error = %e,
+    );
+    DaemonMessage::Error {
+        id,
+        code: ErrorCode::from_code(e.error_code()),
+        message: e.to_string(),
+    }
+}
+
 /// Dispatch a client message to the session manager and return a response.
 ///
 /// Returns `None` for messages that don't generate a direct response (handled inline).
 async fn dispatch_message<W>(
     msg: ClientMessage,
     client_id: ClientId,
+    client_version: Option<u32>,
     session_manager: &Arc<RwLock<SessionManager>>,
-    writer: Arc<Mutex<W>>,
+    writer: Arc<Mutex<MessageWriter<W>>>,
     shutdown: &tokio_util::sync::CancellationToken,
 ) -> Option<DaemonMessage>
 where
@@ -156,8 +243,9 @@ where
             session_id,
             rows,
             cols,
+            read_only,
         } => {
-            let (rx, scrollback, resize_failed, size_changed) = {
+            let (rx, scrollback, resize_failed, size_changed, bytes_streamed, stream_guard) = {
                 let mut mgr = session_manager.write().await;
 
                 // Read current PTY size before resize to detect dimension changes.
@@ -175,8 +263,12 @@ where
                     );
                 }
 
-                // Resize to client dimensions
-                let resize_failed = if let Err(e) = mgr.resize_pty(&session_id, rows, cols) {
+                // Resize to client dimensions. Read-only watchers and clients
+                // waiting for the write lock leave the PTY at the writer's size.
+                let resizes = !read_only && mgr.write_lock_available(&session_id, client_id);
+                let resize_failed = if !resizes {
+                    false
+                } else if let Err(e) = mgr.resize_pty(&session_id, rows, cols) {
                     warn!(
                         event = "daemon.connection.resize_failed",
                         session_id = %session_id,
@@ -192,11 +284,12 @@ where
                 // None means PTY is already gone (session stopped / removed mid-attach);
                 // treat as changed to skip garbled replay — attach_client will surface the
                 // real error below if the session is truly invalid.
-                let size_changed = old_size.is_none_or(|(r, c)| r != rows || c != cols);
+                let size_changed =
+                    old_size.is_none_or(|(r, c)| resizes && (r != rows || c != cols));
 
                 // Subscribe to broadcast BEFORE capturing scrollback to avoid
                 // losing output produced between capture and stream start.
-                let rx = match mgr.attach_client(&session_id, client_id) {
+                let rx = match mgr.attach_client(&session_id, client_id, read_only) {
                     Ok(rx) => rx,
                     Err(e) => {
                         return Some(DaemonMessage::Error {
@@ -236,7 +329,14 @@ where
                     }
                 };
 
-                (rx, scrollback, resize_failed, size_changed)
+                (
+                    rx,
+                    scrollback,
+                    resize_failed,
+                    size_changed,
+                    mgr.bytes_streamed_counter(),
+                    mgr.track_stream(),
+                )
             };
 
             // Hold the writer lock for ack + scrollback + buffered drain so
@@ -246,7 +346,7 @@ where
                 let mut w = writer.lock().await;
 
                 // Send ack (no flush — batch with scrollback)
-                if let Err(e) = write_message(&mut *w, &DaemonMessage::Ack { id }).await {
+                if let Err(e) = w.write(&DaemonMessage::Ack { id }).await {
                     warn!(
                         event = "daemon.connection.ack_write_failed",
                         session_id = %session_id,
@@ -269,7 +369,7 @@ where
                         session_id: session_id.clone(),
                         details: Some(serde_json::json!({ "message": msg })),
                     };
-                    if let Err(e) = write_message(&mut *w, &resize_warning).await {
+                    if let Err(e) = w.write(&resize_warning).await {
                         warn!(
                             event = "daemon.connection.resize_warning_write_failed",
                             session_id = %session_id,
@@ -288,7 +388,7 @@ where
                             "message": "Scrollback replay skipped: terminal dimensions changed. The agent will re-render output."
                         })),
                     };
-                    if let Err(e) = write_message(&mut *w, &skip_notice).await {
+                    if let Err(e) = w.write(&skip_notice).await {
                         warn!(
                             event = "daemon.connection.scrollback_skip_notice_write_failed",
                             session_id = %session_id,
@@ -301,11 +401,11 @@ where
                 // Send scrollback replay so attaching client has context (no flush)
                 if !scrollback.is_empty() {
                     let encoded = base64::engine::general_purpose::STANDARD.encode(&scrollback);
-                    let scrollback_msg = DaemonMessage::PtyOutput {
+                    let scrollback_msg = DaemonMessage::Scrollback {
                         session_id: session_id.clone(),
                         data: encoded,
                     };
-                    if let Err(e) = write_message(&mut *w, &scrollback_msg).await {
+                    if let Err(e) = w.write(&scrollback_msg).await {
                         warn!(
                             event = "daemon.connection.scrollback_write_failed",
                             session_id = %session_id,
@@ -335,7 +435,15 @@ where
             let shutdown_clone = shutdown.clone();
 
             tokio::spawn(async move {
-                stream_pty_output(rx, &session_id_clone, writer_clone, shutdown_clone).await;
+                let _stream_guard = stream_guard;
+                stream_pty_output(
+                    rx,
+                    &session_id_clone,
+                    writer_clone,
+                    shutdown_clone,
+                    bytes_streamed,
+                )
+                .await;
             });
 
             None // Response already sent
@@ -360,8 +468,21 @@ where
             cols,
         } => {
             let mut mgr = session_manager.write().await;
+            if let Err(e) = mgr.check_writable(&session_id, client_id) {
+                return Some(input_rejection(id, client_id, e));
+            }
             match mgr.resize_pty(&session_id, rows, cols) {
                 Ok(()) => Some(DaemonMessage::Ack { id }),
+                // Resizes race with session teardown (a pane closing while the
+                // UI relayouts); a stale one is not worth failing the client over.
+                Err(DaemonError::SessionNotFound(_)) => {
+                    warn!(
+                        event = "daemon.connection.resize_unknown_session",
+                        session_id = %session_id,
+                        client_id = client_id,
+                    );
+                    Some(DaemonMessage::Ack { id })
+                }
                 Err(e) => Some(DaemonMessage::Error {
                     id,
                     code: ErrorCode::from_code(e.error_code()),
@@ -389,6 +510,9 @@ where
             // read() is sufficient: SessionManager::write_stdin takes &self.
             // Actual write exclusion is handled by Arc<Mutex<Writer>> inside ManagedPty.
             let mgr = session_manager.read().await;
+            if let Err(e) = mgr.check_writable(&session_id, client_id) {
+                return Some(input_rejection(id, client_id, e));
+            }
             match mgr.write_stdin(&session_id, &decoded) {
                 Ok(()) => Some(DaemonMessage::Ack { id }),
                 Err(e) => Some(DaemonMessage::Error {
@@ -473,7 +597,118 @@ where
             Some(DaemonMessage::Ack { id })
         }
 
-        ClientMessage::Ping { id } => Some(DaemonMessage::Ack { id }),
+        ClientMessage::Ping { id } => Some(ping_reply(id, client_version)),
+
+        ClientMessage::Handoff {
+            id,
+            drain_timeout_ms,
+        } => {
+            info!(
+                event = "daemon.server.handoff_requested",
+                client_id = client_id,
+                drain_timeout_ms = drain_timeout_ms,
+            );
+            // Ack before starting the handoff: with nothing left to drain the
+            // daemon exits right away and could drop a reply sent afterwards.
+            let ack = DaemonMessage::Ack { id };
+            if let Err(e) = writer.lock().await.write_flush(&ack).await {
+                warn!(
+                    event = "daemon.server.handoff_ack_failed",
+                    client_id = client_id,
+                    error = %e,
+                );
+            }
+            let mut mgr = session_manager.write().await;
+            mgr.request_handoff(std::time::Duration::from_millis(drain_timeout_ms));
+            None
+        }
+
+        ClientMessage::RequestWrite { id, session_id } => {
+            let mut mgr = session_manager.write().await;
+            match mgr.request_write(&session_id, client_id) {
+                Ok(()) => Some(DaemonMessage::WriteGranted { id, session_id }),
+                Err(
+                    e @ (DaemonError::WriteLockHeld(_)
+                    | DaemonError::ReadOnlyAttach(_)
+                    | DaemonError::NotAttached(_)),
+                ) => Some(DaemonMessage::WriteDenied {
+                    id,
+                    session_id,
+                    message: e.to_string(),
+                }),
+                Err(e) => Some(DaemonMessage::Error {
+                    id,
+                    code: ErrorCode::from_code(e.error_code()),
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        ClientMessage::ReleaseWrite { id, session_id } => {
+            let mut mgr = session_manager.write().await;
+            match mgr.release_write(&session_id, client_id) {
+                Ok(()) => Some(DaemonMessage::Ack { id }),
+                Err(e) => Some(DaemonMessage::Error {
+                    id,
+                    code: ErrorCode::from_code(e.error_code()),
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        ClientMessage::Stats { id } => {
+            let mgr = session_manager.read().await;
+            Some(DaemonMessage::Stats {
+                id,
+                uptime_secs: mgr.uptime().as_secs(),
+                session_count: mgr.session_count(),
+                active_ptys: mgr.active_pty_count(),
+                bytes_streamed: mgr.bytes_streamed(),
+                connected_clients: mgr.connected_client_count(),
+            })
+        }
+
+        ClientMessage::Hello {
+            id,
+            protocol_version,
+            compression,
+            ..
+        } => {
+            // The client decides whether to proceed; the daemon just reports its range
+            if kild_protocol::version::negotiate(
+                protocol_version,
+                kild_protocol::MIN_SUPPORTED_VERSION,
+                kild_protocol::PROTOCOL_VERSION,
+                kild_protocol::MIN_SUPPORTED_VERSION,
+            )
+            .is_none()
+            {
+                warn!(
+                    event = "daemon.connection.version_mismatch",
+                    client_id = client_id,
+                    client_version = protocol_version,
+                    daemon_version = kild_protocol::PROTOCOL_VERSION,
+                );
+            } else {
+                debug!(
+                    event = "daemon.connection.hello",
+                    client_id = client_id,
+                    client_version = protocol_version,
+                );
+            }
+
+            // The HelloAck itself may go out compressed; clients decode any
+            // frame that carries the flag, whether or not they asked for it.
+            let compression = kild_protocol::frame::negotiate_compression(&compression);
+            writer.lock().await.set_compression(compression);
+
+            Some(DaemonMessage::HelloAck {
+                id,
+                protocol_version: kild_protocol::PROTOCOL_VERSION,
+                min_supported: kild_protocol::MIN_SUPPORTED_VERSION,
+                compression,
+            })
+        }
 
         other => {
             warn!(
@@ -492,11 +727,14 @@ where
 }
 
 /// Stream PTY output to a client until detach, shutdown, or channel close.
+///
+/// Adds every delivered chunk's raw size to `bytes_streamed`.
 async fn stream_pty_output<W>(
     mut rx: tokio::sync::broadcast::Receiver<Bytes>,
     session_id: &str,
-    writer: Arc<Mutex<W>>,
+    writer: Arc<Mutex<MessageWriter<W>>>,
     shutdown: tokio_util::sync::CancellationToken,
+    bytes_streamed: Arc<AtomicU64>,
 ) where
     W: AsyncWrite + Send + Unpin + 'static,
 {
@@ -513,7 +751,7 @@ async fn stream_pty_output<W>(
                             data: encoded,
                         };
                         let mut w = writer.lock().await;
-                        if let Err(e) = write_message(&mut *w, &msg).await {
+                        if let Err(e) = w.write(&msg).await {
                             debug!(
                                 event = "daemon.connection.stream_write_failed",
                                 session_id = session_id,
@@ -521,6 +759,7 @@ async fn stream_pty_output<W>(
                             );
                             break;
                         }
+                        bytes_streamed.fetch_add(data.len() as u64, Ordering::Relaxed);
                     }
                     Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
                         let msg = DaemonMessage::PtyOutputDropped {
@@ -528,7 +767,7 @@ async fn stream_pty_output<W>(
                             bytes_dropped: n as usize,
                         };
                         let mut w = writer.lock().await;
-                        if let Err(e) = write_message(&mut *w, &msg).await {
+                        if let Err(e) = w.write(&msg).await {
                             error!(
                                 event = "daemon.connection.lag_notification_failed",
                                 session_id = session_id,
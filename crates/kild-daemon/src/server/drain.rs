@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// How often `wait_for_drain` re-checks the in-flight count.
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Count of PTY output streams currently being served to clients.
+///
+/// Cloning shares the counter. Streaming tasks hold a [`StreamGuard`] for
+/// as long as they run, so the count can't leak if a task exits early.
+#[derive(Debug, Clone, Default)]
+pub struct InFlight(Arc<AtomicUsize>);
+
+impl InFlight {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a stream; it stays counted until the guard is dropped.
+    pub fn track(&self) -> StreamGuard {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        StreamGuard(self.0.clone())
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Keeps one stream counted in its [`InFlight`] until dropped.
+#[derive(Debug)]
+pub struct StreamGuard(Arc<AtomicUsize>);
+
+impl Drop for StreamGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Wait until `in_flight` reaches zero or `timeout` elapses.
+///
+/// Returns the number of streams still running when the wait ended, so 0
+/// means everything drained.
+pub async fn wait_for_drain(in_flight: &InFlight, timeout: Duration) -> usize {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let remaining = in_flight.count();
+        if remaining == 0 || Instant::now() >= deadline {
+            return remaining;
+        }
+        tokio::time::sleep(DRAIN_POLL_INTERVAL.min(deadline - Instant::now())).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard_tracks_stream_lifetime() {
+        let in_flight = InFlight::new();
+        let a = in_flight.track();
+        let b = in_flight.clone().track();
+        assert_eq!(in_flight.count(), 2);
+        drop(a);
+        assert_eq!(in_flight.count(), 1);
+        drop(b);
+        assert_eq!(in_flight.count(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_drain_returns_immediately_when_idle() {
+        let in_flight = InFlight::new();
+        let start = Instant::now();
+        assert_eq!(wait_for_drain(&in_flight, Duration::from_secs(10)).await, 0);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_drain_finishes_when_streams_end() {
+        let in_flight = InFlight::new();
+        let guards = vec![in_flight.track(), in_flight.track()];
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            drop(guards);
+        });
+
+        let start = Instant::now();
+        assert_eq!(wait_for_drain(&in_flight, Duration::from_secs(10)).await, 0);
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_drain_times_out_with_streams_left() {
+        let in_flight = InFlight::new();
+        let _stuck = in_flight.track();
+
+        let start = Instant::now();
+        assert_eq!(wait_for_drain(&in_flight, Duration::from_secs(2)).await, 1);
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_drain_zero_timeout_does_not_wait() {
+        let in_flight = InFlight::new();
+        let _stuck = in_flight.track();
+        assert_eq!(wait_for_drain(&in_flight, Duration::ZERO).await, 1);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod connection;
+pub mod drain;
 pub mod shutdown;
 
 use std::path::Path;
@@ -26,8 +27,12 @@ use crate::types::DaemonConfig;
 /// 3. Writes a bin file (binary path + mtime for staleness detection)
 /// 4. Binds a Unix socket
 /// 5. Optionally binds a TLS-wrapped TCP listener (when `bind_tcp` is configured)
-/// 6. Accepts client connections in a loop
-/// 7. Handles graceful shutdown on SIGTERM/SIGINT
+/// 6. Restores sessions saved by the previous daemon
+/// 7. Accepts client connections in a loop
+/// 8. Handles graceful shutdown on SIGTERM/SIGINT, saving session metadata
+/// 9. On a handoff request, drains attached streams and exits without
+///    stopping sessions. The PTY masters close on exit, so only processes
+///    that survive the hangup are reconciled by the replacement daemon
 pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
     let pid_path = config.pid_path.clone();
     let socket_path = config.socket_path.clone();
@@ -76,11 +81,25 @@ pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
     // Channel for PTY exit notifications from reader tasks
     let (pty_exit_tx, mut pty_exit_rx) = tokio::sync::mpsc::unbounded_channel();
 
-    let session_manager = Arc::new(RwLock::new(SessionManager::new(
-        config.clone(),
-        pty_exit_tx,
-    )));
+    let mut manager = SessionManager::new(config.clone(), pty_exit_tx);
+    match manager.reconcile_on_startup() {
+        Ok(reconnected) => info!(
+            event = "daemon.server.reconcile_completed",
+            reconnected = reconnected.len(),
+            sessions = manager.session_count(),
+        ),
+        Err(e) => warn!(
+            event = "daemon.server.reconcile_failed",
+            error = %e,
+            "Sessions from the previous daemon were not restored.",
+        ),
+    }
+    let handoff_notify = manager.handoff_notify();
+    let session_manager = Arc::new(RwLock::new(manager));
     let shutdown = CancellationToken::new();
+    // Stops the accept loops only; a handoff keeps serving open connections
+    // while they drain.
+    let accept_stop = shutdown.child_token();
 
     // Spawn signal handler
     let signal_shutdown = shutdown.clone();
@@ -119,7 +138,12 @@ pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
             .unwrap_or_else(|| kild_paths.tls_key_path());
 
         let (certs, key) = tls::load_or_generate_cert(&cert_path, &key_path)?;
-        let tls_config = tls::build_server_config(certs, key)?;
+        let client_ca = config
+            .client_ca_path
+            .as_deref()
+            .map(tls::load_client_ca)
+            .transpose()?;
+        let tls_config = tls::build_server_config(certs, key, client_ca)?;
         let acceptor = TlsAcceptor::from(tls_config);
 
         let tcp_listener = TcpListener::bind(bind_addr).await?;
@@ -127,11 +151,20 @@ pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
 
         let mgr_clone = session_manager.clone();
         let shutdown_clone = shutdown.clone();
+        let auth_token: Option<Arc<str>> = config.auth_token.as_deref().map(Arc::from);
+        if auth_token.is_none() && config.client_ca_path.is_none() {
+            warn!(
+                event = "daemon.server.tcp_auth_disabled",
+                "TCP listener has no auth_token or client CA (allow_unauthenticated_tcp); any client that trusts the certificate can connect"
+            );
+        }
         tokio::spawn(tcp_accept_loop(
             tcp_listener,
             acceptor,
             mgr_clone,
             shutdown_clone,
+            accept_stop.clone(),
+            auth_token,
         ));
     }
 
@@ -147,6 +180,7 @@ pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
                             stream,
                             mgr,
                             shutdown_token,
+                            None,
                         ));
                     }
                     Err(e) => {
@@ -166,6 +200,10 @@ pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
                     drop(output_tx);
                 }
             }
+            _ = handoff_notify.notified() => {
+                info!(event = "daemon.server.handoff_started");
+                break;
+            }
             _ = shutdown.cancelled() => {
                 info!(event = "daemon.server.shutdown_started");
                 break;
@@ -173,10 +211,38 @@ pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
         }
     }
 
-    // Graceful shutdown: stop all sessions
+    accept_stop.cancel();
+    drop(listener);
+
+    let handoff = session_manager.read().await.handoff_requested();
+    if let Some(drain_timeout) = handoff {
+        let in_flight = session_manager.read().await.active_streams();
+        let remaining = drain::wait_for_drain(&in_flight, drain_timeout).await;
+        if remaining > 0 {
+            warn!(
+                event = "daemon.server.handoff_drain_timeout",
+                remaining_streams = remaining,
+                timeout_ms = drain_timeout.as_millis() as u64,
+            );
+        }
+        shutdown.cancel();
+    }
+
+    // Graceful shutdown: save session metadata, then stop all sessions.
+    // A handoff skips the stop, but exiting still closes every PTY master,
+    // so processes that don't ignore SIGHUP end here either way.
     {
         let mut mgr = session_manager.write().await;
-        mgr.stop_all();
+        if let Err(e) = mgr.persist() {
+            warn!(
+                event = "daemon.server.persist_failed",
+                error = %e,
+                "Sessions will not be restored on the next start.",
+            );
+        }
+        if handoff.is_none() {
+            mgr.stop_all();
+        }
     }
 
     // Clean up PID, bin, and socket files
@@ -197,6 +263,8 @@ async fn tcp_accept_loop(
     acceptor: TlsAcceptor,
     session_manager: Arc<RwLock<SessionManager>>,
     shutdown: CancellationToken,
+    accept_stop: CancellationToken,
+    auth_token: Option<Arc<str>>,
 ) {
     loop {
         tokio::select! {
@@ -206,6 +274,7 @@ async fn tcp_accept_loop(
                         let acceptor = acceptor.clone();
                         let mgr = session_manager.clone();
                         let shutdown_clone = shutdown.clone();
+                        let auth_token = auth_token.clone();
                         tokio::spawn(async move {
                             match acceptor.accept(tcp_stream).await {
                                 Ok(tls_stream) => {
@@ -213,7 +282,7 @@ async fn tcp_accept_loop(
                                         event = "daemon.server.tls_connection_accepted",
                                         addr = %addr,
                                     );
-                                    connection::handle_connection(tls_stream, mgr, shutdown_clone).await;
+                                    connection::handle_connection(tls_stream, mgr, shutdown_clone, auth_token).await;
                                 }
                                 Err(e) => {
                                     warn!(
@@ -236,7 +305,7 @@ async fn tcp_accept_loop(
                     }
                 }
             }
-            _ = shutdown.cancelled() => {
+            _ = accept_stop.cancelled() => {
                 info!(event = "daemon.server.tcp_listener_shutdown");
                 break;
             }
@@ -1,4 +1,7 @@
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 use bytes::Bytes;
 use tokio::sync::broadcast;
@@ -7,6 +10,8 @@ use tracing::{debug, error, info, warn};
 use crate::errors::DaemonError;
 use crate::pty::manager::PtyManager;
 use crate::pty::output::{PtyExitEvent, spawn_pty_reader};
+use crate::server::drain::{InFlight, StreamGuard};
+use crate::session::persist::{self, PersistedSession, ProcessIdentity};
 use crate::session::state::{ClientId, DaemonSession, SessionState};
 use crate::types::{DaemonConfig, DaemonSessionStatus};
 
@@ -25,6 +30,14 @@ pub struct SessionManager {
     next_client_id: ClientId,
     /// Sender for PTY exit notifications. Passed to each PTY reader task.
     pty_exit_tx: tokio::sync::mpsc::UnboundedSender<PtyExitEvent>,
+    started_at: Instant,
+    connected_clients: usize,
+    /// Shared with streaming tasks, which count bytes without taking the lock.
+    bytes_streamed: Arc<AtomicU64>,
+    active_streams: InFlight,
+    /// Drain timeout of a pending handoff; the server loop waits on `handoff_notify`.
+    handoff: Option<Duration>,
+    handoff_notify: Arc<tokio::sync::Notify>,
 }
 
 impl SessionManager {
@@ -38,13 +51,22 @@ impl SessionManager {
             config,
             next_client_id: 1,
             pty_exit_tx,
+            started_at: Instant::now(),
+            connected_clients: 0,
+            bytes_streamed: Arc::new(AtomicU64::new(0)),
+            active_streams: InFlight::new(),
+            handoff: None,
+            handoff_notify: Arc::new(tokio::sync::Notify::new()),
         }
     }
 
-    /// Allocate a new client ID.
+    /// Allocate a client ID for a newly accepted connection.
+    ///
+    /// Counts the connection as connected until `detach_client_from_all()`.
     pub fn next_client_id(&mut self) -> ClientId {
         let id = self.next_client_id;
         self.next_client_id = self.next_client_id.wrapping_add(1);
+        self.connected_clients += 1;
         id
     }
 
@@ -121,6 +143,7 @@ impl SessionManager {
             reader,
             reader_tx,
             shared_scrollback,
+            self.open_session_log(session_id),
             Some(self.pty_exit_tx.clone()),
         );
 
@@ -139,11 +162,37 @@ impl SessionManager {
         Ok(info)
     }
 
+    /// Open (truncating) the output log for a new session.
+    ///
+    /// Logging is best-effort: on failure the session still starts, just
+    /// without captured output for `kild logs`.
+    fn open_session_log(&self, session_id: &str) -> Option<std::fs::File> {
+        let path = kild_paths::KildPaths::agent_log_file_in(&self.config.logs_dir, session_id);
+        let result = std::fs::create_dir_all(&self.config.logs_dir)
+            .and_then(|()| std::fs::File::create(&path));
+        match result {
+            Ok(file) => Some(file),
+            Err(e) => {
+                warn!(
+                    event = "daemon.session.log_open_failed",
+                    session_id = session_id,
+                    path = %path.display(),
+                    error = %e,
+                );
+                None
+            }
+        }
+    }
+
     /// Attach a client to a session. Returns a broadcast receiver for PTY output.
+    ///
+    /// Read-only clients get the same output stream; other clients may stay
+    /// attached writable alongside them.
     pub fn attach_client(
         &mut self,
         session_id: &str,
         client_id: ClientId,
+        read_only: bool,
     ) -> Result<broadcast::Receiver<Bytes>, DaemonError> {
         let session = self
             .sessions
@@ -154,7 +203,7 @@ impl SessionManager {
             return Err(DaemonError::SessionNotRunning(session_id.to_string()));
         }
 
-        session.attach_client(client_id);
+        session.attach_client(client_id, read_only);
 
         let rx = session
             .subscribe_output()
@@ -164,6 +213,7 @@ impl SessionManager {
             event = "daemon.session.client_attached",
             session_id = session_id,
             client_id = client_id,
+            read_only = read_only,
             client_count = session.client_count(),
         );
 
@@ -193,6 +243,63 @@ impl SessionManager {
         Ok(())
     }
 
+    /// Fail if `client_id` is attached to `session_id` without the write
+    /// lock, or attached read-only.
+    ///
+    /// Unknown sessions pass; the write or resize that follows reports them.
+    pub fn check_writable(&self, session_id: &str, client_id: ClientId) -> Result<(), DaemonError> {
+        match self.sessions.get(session_id) {
+            Some(session) => session.check_input(client_id),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether `client_id` holds `session_id`'s write lock or could take it now.
+    pub fn write_lock_available(&self, session_id: &str, client_id: ClientId) -> bool {
+        self.sessions
+            .get(session_id)
+            .is_some_and(|session| session.write_lock_available(client_id))
+    }
+
+    /// Give `client_id` the session's write lock if it is free.
+    pub fn request_write(
+        &mut self,
+        session_id: &str,
+        client_id: ClientId,
+    ) -> Result<(), DaemonError> {
+        let session = self
+            .sessions
+            .get_mut(session_id)
+            .ok_or_else(|| DaemonError::SessionNotFound(session_id.to_string()))?;
+        session.request_write(client_id)?;
+        debug!(
+            event = "daemon.session.write_granted",
+            session_id = session_id,
+            client_id = client_id,
+        );
+        Ok(())
+    }
+
+    /// Release the session's write lock if `client_id` holds it.
+    pub fn release_write(
+        &mut self,
+        session_id: &str,
+        client_id: ClientId,
+    ) -> Result<(), DaemonError> {
+        let session = self
+            .sessions
+            .get_mut(session_id)
+            .ok_or_else(|| DaemonError::SessionNotFound(session_id.to_string()))?;
+        if session.release_write(client_id) {
+            debug!(
+                event = "daemon.session.write_released",
+                session_id = session_id,
+                client_id = client_id,
+            );
+        }
+        Ok(())
+    }
+
     /// Resize the PTY for a session.
     pub fn resize_pty(
         &mut self,
@@ -249,6 +356,7 @@ impl SessionManager {
                 );
                 return Ok(());
             }
+            terminate_if_reconnected(session);
         } else {
             return Err(DaemonError::SessionNotFound(session_id.to_string()));
         }
@@ -313,7 +421,9 @@ impl SessionManager {
         }
 
         // Always remove the session state during destroy
-        self.sessions.remove(session_id);
+        if let Some(session) = self.sessions.remove(session_id) {
+            terminate_if_reconnected(&session);
+        }
 
         info!(
             event = "daemon.session.destroy_completed",
@@ -360,11 +470,60 @@ impl SessionManager {
         self.pty_manager.count()
     }
 
+    /// Number of open client connections.
+    pub fn connected_client_count(&self) -> usize {
+        self.connected_clients
+    }
+
+    /// Time since the manager was created, i.e. daemon uptime.
+    pub fn uptime(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Total PTY output bytes streamed to attached clients.
+    pub fn bytes_streamed(&self) -> u64 {
+        self.bytes_streamed.load(Ordering::Relaxed)
+    }
+
+    /// Counter for streaming tasks to add delivered bytes to.
+    pub fn bytes_streamed_counter(&self) -> Arc<AtomicU64> {
+        self.bytes_streamed.clone()
+    }
+
+    /// Count a PTY output stream as in flight until the guard is dropped.
+    pub fn track_stream(&self) -> StreamGuard {
+        self.active_streams.track()
+    }
+
+    /// Handle for waiting on in-flight streams without holding the lock.
+    pub fn active_streams(&self) -> InFlight {
+        self.active_streams.clone()
+    }
+
+    /// Ask the server to hand off to a new daemon, draining streams for up
+    /// to `drain_timeout` first. Sessions are left running but lose their
+    /// PTY when this daemon exits.
+    pub fn request_handoff(&mut self, drain_timeout: Duration) {
+        self.handoff = Some(drain_timeout);
+        self.handoff_notify.notify_one();
+    }
+
+    /// Drain timeout of a pending handoff, if one was requested.
+    pub fn handoff_requested(&self) -> Option<Duration> {
+        self.handoff
+    }
+
+    /// Notified once `request_handoff()` is called.
+    pub fn handoff_notify(&self) -> Arc<tokio::sync::Notify> {
+        self.handoff_notify.clone()
+    }
+
     /// Detach a client from all sessions (called on connection close).
     pub fn detach_client_from_all(&mut self, client_id: ClientId) {
         for session in self.sessions.values_mut() {
             session.detach_client(client_id);
         }
+        self.connected_clients = self.connected_clients.saturating_sub(1);
     }
 
     /// Handle a PTY exit event: transition the session to Stopped and clean up PTY.
@@ -433,6 +592,88 @@ impl SessionManager {
         self.sessions.get(session_id).map(|s| s.client_count())
     }
 
+    /// Save running sessions to `sessions_state_path` so the next daemon
+    /// can pick them up with `reconcile_on_startup()`.
+    ///
+    /// Call before `stop_all()`, which clears the pids being saved.
+    pub fn persist(&self) -> Result<(), DaemonError> {
+        let mut records: Vec<PersistedSession> = self
+            .sessions
+            .values()
+            .filter(|s| s.state() == SessionState::Running)
+            .filter_map(|s| {
+                let pty_pid = s.pty_pid()?;
+                Some(PersistedSession {
+                    id: s.id().to_string(),
+                    working_directory: s.working_directory().to_string(),
+                    command: s.command().to_string(),
+                    created_at: s.created_at().to_string(),
+                    pty_pid,
+                    pty_process: s
+                        .reconnected_process()
+                        .cloned()
+                        .or_else(|| ProcessIdentity::of(pty_pid)),
+                })
+            })
+            .collect();
+        records.sort_by(|a, b| a.id.cmp(&b.id));
+        persist::save(&self.config.sessions_state_path, &records)
+    }
+
+    /// Restore sessions saved by the previous daemon's `persist()`.
+    ///
+    /// Sessions whose process is still alive come back as reconnected: they
+    /// are listed as running and can be stopped, but have no PTY to attach
+    /// to. A process only counts as alive if its name and start time still
+    /// match the saved identity, so a reused pid is never adopted. Sessions
+    /// whose process is gone come back as stopped, so clients see them end
+    /// rather than vanish. The state file is consumed either way.
+    ///
+    /// Returns the ids of reconnected sessions.
+    pub fn reconcile_on_startup(&mut self) -> Result<Vec<String>, DaemonError> {
+        let records = persist::take(&self.config.sessions_state_path)?;
+        let mut reconnected = Vec::new();
+
+        for record in records {
+            if self.sessions.contains_key(&record.id) {
+                continue;
+            }
+
+            let mut session = DaemonSession::new(
+                record.id.clone(),
+                record.working_directory,
+                record.command,
+                record.created_at,
+                self.config.scrollback_buffer_size,
+            );
+
+            match record.pty_process {
+                Some(identity) if identity.matches(record.pty_pid) => {
+                    session.set_reconnected(record.pty_pid, identity)?;
+                    info!(
+                        event = "daemon.session.reconnected",
+                        session_id = %record.id,
+                        pid = record.pty_pid,
+                    );
+                    reconnected.push(record.id.clone());
+                }
+                identity => {
+                    session.set_stopped()?;
+                    info!(
+                        event = "daemon.session.reconcile_stopped",
+                        session_id = %record.id,
+                        pid = record.pty_pid,
+                        has_identity = identity.is_some(),
+                    );
+                }
+            }
+            self.sessions.insert(record.id, session);
+        }
+
+        reconnected.sort();
+        Ok(reconnected)
+    }
+
     /// Stop all running sessions (called during shutdown).
     pub fn stop_all(&mut self) {
         let session_ids: Vec<String> = self
@@ -465,6 +706,41 @@ impl SessionManager {
     }
 }
 
+/// Send SIGTERM to a reconnected session's process.
+///
+/// Reconnected sessions have no PTY in `PtyManager`, so destroying the PTY
+/// would leave the process running. The pid is re-checked against the saved
+/// identity first: if the process exited and its pid was reused, nothing is
+/// signalled.
+fn terminate_if_reconnected(session: &DaemonSession) {
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+
+    let Some(identity) = session.reconnected_process() else {
+        return;
+    };
+    let Some(pid) = session.pty_pid() else {
+        return;
+    };
+    if !identity.matches(pid) {
+        warn!(
+            event = "daemon.session.reconnected_terminate_skipped",
+            session_id = session.id(),
+            pid = pid,
+            reason = "process_identity_mismatch",
+        );
+        return;
+    }
+    if let Err(e) = kill(Pid::from_raw(pid as i32), Signal::SIGTERM) {
+        warn!(
+            event = "daemon.session.reconnected_terminate_failed",
+            session_id = session.id(),
+            pid = pid,
+            error = %e,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -476,7 +752,10 @@ mod tests {
         tokio::sync::mpsc::UnboundedReceiver<PtyExitEvent>,
     ) {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-        let config = DaemonConfig::default();
+        let config = DaemonConfig {
+            logs_dir: std::env::temp_dir().join("kild-daemon-test-logs"),
+            ..DaemonConfig::default()
+        };
         (SessionManager::new(config, tx), rx)
     }
 
@@ -502,6 +781,40 @@ mod tests {
         assert_eq!(info.status, kild_protocol::SessionStatus::Stopped);
     }
 
+    #[tokio::test]
+    async fn test_create_session_captures_output_to_log() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let logs_dir = tmpdir.path().join("logs");
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let config = DaemonConfig {
+            logs_dir: logs_dir.clone(),
+            ..DaemonConfig::default()
+        };
+        let mut mgr = SessionManager::new(config, tx);
+        let wd = tmpdir.path().to_str().unwrap();
+
+        mgr.create_session(
+            "s1",
+            wd,
+            "echo",
+            &["logged-output".to_string()],
+            &[],
+            24,
+            80,
+            false,
+        )
+        .unwrap();
+
+        // The reader flushes every chunk before reporting the exit
+        tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("PTY reader should exit")
+            .unwrap();
+
+        let log = std::fs::read_to_string(logs_dir.join("s1.log")).unwrap();
+        assert!(log.contains("logged-output"), "log was: {log:?}");
+    }
+
     #[test]
     fn test_handle_pty_exit_nonexistent_session_returns_none() {
         let (mut mgr, _rx) = test_manager();
@@ -540,14 +853,29 @@ mod tests {
 
         assert_eq!(mgr.client_count("s1"), Some(0));
 
-        let _rx1 = mgr.attach_client("s1", 1).unwrap();
+        let _rx1 = mgr.attach_client("s1", 1, false).unwrap();
         assert_eq!(mgr.client_count("s1"), Some(1));
 
-        let _rx2 = mgr.attach_client("s1", 2).unwrap();
+        let _rx2 = mgr.attach_client("s1", 2, false).unwrap();
         assert_eq!(mgr.client_count("s1"), Some(2));
 
-        let _rx3 = mgr.attach_client("s1", 3).unwrap();
+        // A read-only watcher coexists with writable attaches
+        let _rx3 = mgr.attach_client("s1", 3, true).unwrap();
         assert_eq!(mgr.client_count("s1"), Some(3));
+        assert!(mgr.check_writable("s1", 1).is_ok());
+        assert!(matches!(
+            mgr.check_writable("s1", 3),
+            Err(DaemonError::ReadOnlyAttach(_))
+        ));
+
+        // Client 2 waits for client 1 to release the write lock
+        assert!(matches!(
+            mgr.check_writable("s1", 2),
+            Err(DaemonError::WriteLockHeld(_))
+        ));
+        mgr.release_write("s1", 1).unwrap();
+        mgr.request_write("s1", 2).unwrap();
+        assert!(mgr.check_writable("s1", 2).is_ok());
 
         // Cleanup
         let _ = mgr.destroy_session("s1", true);
@@ -599,6 +927,51 @@ mod tests {
         assert_eq!(mgr.next_client_id(), 3);
     }
 
+    #[tokio::test]
+    async fn test_request_handoff_notifies_waiter() {
+        let (mut mgr, _rx) = test_manager();
+        assert!(mgr.handoff_requested().is_none());
+
+        let notify = mgr.handoff_notify();
+        mgr.request_handoff(Duration::from_secs(3));
+
+        // notify_one stores a permit, so a waiter that arrives late still wakes.
+        tokio::time::timeout(Duration::from_secs(1), notify.notified())
+            .await
+            .unwrap();
+        assert_eq!(mgr.handoff_requested(), Some(Duration::from_secs(3)));
+    }
+
+    #[tokio::test]
+    async fn test_stats_counters() {
+        let (mut mgr, _rx) = test_manager();
+        let tmpdir = tempfile::tempdir().unwrap();
+        let wd = tmpdir.path().to_str().unwrap();
+
+        for id in ["s1", "s2"] {
+            mgr.create_session(id, wd, "sleep", &["10".to_string()], &[], 24, 80, false)
+                .unwrap();
+        }
+        let c1 = mgr.next_client_id();
+        let _c2 = mgr.next_client_id();
+        mgr.bytes_streamed_counter()
+            .fetch_add(128, Ordering::Relaxed);
+
+        assert_eq!(mgr.session_count(), 2);
+        assert_eq!(mgr.active_pty_count(), 2);
+        assert_eq!(mgr.connected_client_count(), 2);
+        assert_eq!(mgr.bytes_streamed(), 128);
+
+        mgr.detach_client_from_all(c1);
+        mgr.stop_session("s1").unwrap();
+        assert_eq!(mgr.connected_client_count(), 1);
+        assert_eq!(mgr.session_count(), 2);
+        assert_eq!(mgr.active_pty_count(), 1);
+
+        let _ = mgr.destroy_session("s1", true);
+        let _ = mgr.destroy_session("s2", true);
+    }
+
     #[tokio::test]
     async fn test_pty_size_returns_current_dimensions() {
         let (mut mgr, _rx) = test_manager();
@@ -625,6 +998,166 @@ mod tests {
         assert_eq!(mgr.pty_size("nonexistent"), None);
     }
 
+    fn persisting_manager(
+        state_path: &std::path::Path,
+    ) -> (
+        SessionManager,
+        tokio::sync::mpsc::UnboundedReceiver<PtyExitEvent>,
+    ) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let config = DaemonConfig {
+            sessions_state_path: state_path.to_path_buf(),
+            logs_dir: std::env::temp_dir().join("kild-daemon-test-logs"),
+            ..DaemonConfig::default()
+        };
+        (SessionManager::new(config, tx), rx)
+    }
+
+    fn record(id: &str, pty_pid: u32) -> PersistedSession {
+        PersistedSession {
+            id: id.to_string(),
+            working_directory: "/tmp/wt".to_string(),
+            command: "claude".to_string(),
+            created_at: "2026-02-09T14:30:00Z".to_string(),
+            pty_pid,
+            pty_process: ProcessIdentity::of(pty_pid),
+        }
+    }
+
+    #[test]
+    fn test_reconcile_on_startup_live_and_dead_processes() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let state_path = tmpdir.path().join("sessions.json");
+
+        let mut alive = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let mut dead = std::process::Command::new("true").spawn().unwrap();
+        let dead_pid = dead.id();
+        dead.wait().unwrap();
+
+        persist::save(
+            &state_path,
+            &[record("alive", alive.id()), record("dead", dead_pid)],
+        )
+        .unwrap();
+
+        let (mut mgr, _rx) = persisting_manager(&state_path);
+        let reconnected = mgr.reconcile_on_startup().unwrap();
+        assert_eq!(reconnected, vec!["alive".to_string()]);
+
+        let info = mgr.get_session("alive").unwrap();
+        assert_eq!(info.status, kild_protocol::SessionStatus::Running);
+        assert_eq!(info.pty_pid, Some(alive.id()));
+        assert!(matches!(
+            mgr.attach_client("alive", 1, false),
+            Err(DaemonError::PtyError(_))
+        ));
+
+        let info = mgr.get_session("dead").unwrap();
+        assert_eq!(info.status, kild_protocol::SessionStatus::Stopped);
+        assert_eq!(info.pty_pid, None);
+
+        assert!(!state_path.exists(), "state file should be consumed");
+
+        // Stopping a reconnected session terminates the surviving process
+        mgr.stop_session("alive").unwrap();
+        let status = alive.wait().unwrap();
+        assert!(!status.success());
+    }
+
+    #[test]
+    fn test_reconcile_on_startup_rejects_reused_pid() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let state_path = tmpdir.path().join("sessions.json");
+
+        let mut unrelated = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let mut stale = record("stale", unrelated.id());
+        // Same pid, but not the process the previous daemon started
+        stale.pty_process.as_mut().unwrap().start_time -= 1;
+        let mut legacy = record("legacy", unrelated.id());
+        legacy.pty_process = None;
+        persist::save(&state_path, &[stale, legacy]).unwrap();
+
+        let (mut mgr, _rx) = persisting_manager(&state_path);
+        assert!(mgr.reconcile_on_startup().unwrap().is_empty());
+        for id in ["stale", "legacy"] {
+            let info = mgr.get_session(id).unwrap();
+            assert_eq!(info.status, kild_protocol::SessionStatus::Stopped);
+            assert_eq!(info.pty_pid, None);
+        }
+
+        // Stopping and destroying never signal the unrelated process
+        mgr.stop_session("stale").unwrap();
+        mgr.destroy_session("legacy", true).unwrap();
+        assert!(unrelated.try_wait().unwrap().is_none());
+
+        unrelated.kill().unwrap();
+        unrelated.wait().unwrap();
+    }
+
+    #[test]
+    fn test_terminate_if_reconnected_skips_reused_pid() {
+        let mut unrelated = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let mut identity = ProcessIdentity::of(unrelated.id()).unwrap();
+        identity.start_time -= 1;
+
+        let mut session = DaemonSession::new(
+            "s1".to_string(),
+            "/tmp".to_string(),
+            "sleep".to_string(),
+            "2026-02-09T14:30:00Z".to_string(),
+            1024,
+        );
+        session.set_reconnected(unrelated.id(), identity).unwrap();
+
+        terminate_if_reconnected(&session);
+        assert!(unrelated.try_wait().unwrap().is_none());
+
+        unrelated.kill().unwrap();
+        unrelated.wait().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_persist_then_reconcile_simulates_restart() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let wd = tmpdir.path().to_str().unwrap();
+        let state_path = tmpdir.path().join("state").join("sessions.json");
+
+        let (mut first, _rx1) = persisting_manager(&state_path);
+        first
+            .create_session("s1", wd, "sleep", &["10".to_string()], &[], 24, 80, false)
+            .unwrap();
+        let pid = first.get_session("s1").unwrap().pty_pid.unwrap();
+        first.persist().unwrap();
+
+        // New daemon instance while the first one's process is still alive
+        let (mut second, _rx2) = persisting_manager(&state_path);
+        assert_eq!(
+            second.reconcile_on_startup().unwrap(),
+            vec!["s1".to_string()]
+        );
+        assert_eq!(second.get_session("s1").unwrap().pty_pid, Some(pid));
+
+        // Cleanup
+        let _ = first.destroy_session("s1", true);
+    }
+
+    #[test]
+    fn test_reconcile_on_startup_without_state_file_is_noop() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let (mut mgr, _rx) = persisting_manager(&tmpdir.path().join("sessions.json"));
+        assert!(mgr.reconcile_on_startup().unwrap().is_empty());
+        assert_eq!(mgr.session_count(), 0);
+    }
+
     #[test]
     fn test_broadcast_capacity_calculation() {
         // capacity = max(client_buffer_size / 4096, 16)
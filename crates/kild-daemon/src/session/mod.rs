@@ -1,4 +1,5 @@
 pub mod manager;
+pub mod persist;
 pub mod state;
 
 pub use manager::SessionManager;
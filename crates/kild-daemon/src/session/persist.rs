@@ -0,0 +1,149 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::errors::DaemonError;
+
+/// Session metadata that survives a daemon restart.
+///
+/// The PTY itself can't be handed to the next daemon; this is just enough to
+/// find the process again and keep the session visible.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedSession {
+    pub id: String,
+    pub working_directory: String,
+    pub command: String,
+    pub created_at: String,
+    pub pty_pid: u32,
+    /// Identity of `pty_pid` when saved. Records without one (older files, or
+    /// a process that could not be inspected) are never reconnected.
+    #[serde(default)]
+    pub pty_process: Option<ProcessIdentity>,
+}
+
+/// Name and start time of a PTY child process.
+///
+/// A bare pid can be reused after the process exits or the machine reboots,
+/// so a pid only counts as the same process while both still match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessIdentity {
+    pub name: String,
+    pub start_time: u64,
+}
+
+impl ProcessIdentity {
+    /// Look up the identity of the running process `pid`.
+    pub fn of(pid: u32) -> Option<Self> {
+        let info = kild_core::process::get_process_info(pid).ok()?;
+        Some(Self {
+            name: info.name,
+            start_time: info.start_time,
+        })
+    }
+
+    /// Returns true if `pid` is still alive and is this process.
+    pub fn matches(&self, pid: u32) -> bool {
+        kild_core::process::verify_process_identity(pid, Some(&self.name), Some(self.start_time))
+            .is_ok()
+    }
+}
+
+/// Write `sessions` to `path`, replacing any previous file atomically.
+pub fn save(path: &Path, sessions: &[PersistedSession]) -> Result<(), DaemonError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(sessions)?;
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json)?;
+    fs::rename(&tmp, path)?;
+    debug!(
+        event = "daemon.session.persist_completed",
+        path = %path.display(),
+        count = sessions.len(),
+    );
+    Ok(())
+}
+
+/// Read and remove the file at `path`.
+///
+/// Removing it means a later crash can't replay stale records. Returns an
+/// empty list when no file exists.
+pub fn take(path: &Path) -> Result<Vec<PersistedSession>, DaemonError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    fs::remove_file(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_take_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("sessions.json");
+        let sessions = vec![PersistedSession {
+            id: "myapp_feature".to_string(),
+            working_directory: "/tmp/wt".to_string(),
+            command: "claude".to_string(),
+            created_at: "2026-02-09T14:30:00Z".to_string(),
+            pty_pid: 12345,
+            pty_process: Some(ProcessIdentity {
+                name: "claude".to_string(),
+                start_time: 1_700_000_000,
+            }),
+        }];
+
+        save(&path, &sessions).unwrap();
+        assert_eq!(take(&path).unwrap(), sessions);
+        assert!(!path.exists(), "take() should consume the file");
+    }
+
+    #[test]
+    fn test_take_record_without_identity() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sessions.json");
+        fs::write(
+            &path,
+            r#"[{"id":"s1","working_directory":"/tmp","command":"sh","created_at":"","pty_pid":1}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(take(&path).unwrap()[0].pty_process, None);
+    }
+
+    #[test]
+    fn test_process_identity_matches_only_same_start_time() {
+        let identity = ProcessIdentity::of(std::process::id()).unwrap();
+        assert!(identity.matches(std::process::id()));
+
+        let stale = ProcessIdentity {
+            start_time: identity.start_time.wrapping_sub(1),
+            ..identity
+        };
+        assert!(!stale.matches(std::process::id()));
+    }
+
+    #[test]
+    fn test_take_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(take(&dir.path().join("sessions.json")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_take_corrupt_file_errors_and_removes_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sessions.json");
+        fs::write(&path, "not json").unwrap();
+
+        assert!(matches!(take(&path), Err(DaemonError::Serde(_))));
+        assert!(!path.exists());
+    }
+}
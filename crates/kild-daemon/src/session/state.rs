@@ -7,6 +7,7 @@ use tracing::error;
 
 use crate::errors::DaemonError;
 use crate::pty::output::ScrollbackBuffer;
+use crate::session::persist::ProcessIdentity;
 use crate::types::{DaemonSessionStatus, SessionStatus};
 
 /// Unique identifier for a connected client.
@@ -48,10 +49,19 @@ pub struct DaemonSession {
     scrollback: Arc<RwLock<ScrollbackBuffer>>,
     /// Set of attached client IDs.
     attached_clients: HashSet<ClientId>,
+    /// Attached clients that only watch output and may not send input.
+    read_only_clients: HashSet<ClientId>,
+    /// Attached client holding the write lock. Other attached clients are
+    /// read-only until they request it and it is free.
+    writer: Option<ClientId>,
     /// Child process PID (only when Running).
     pty_pid: Option<u32>,
     /// Exit code of the PTY child process. Set when the process exits.
     exit_code: Option<i32>,
+    /// Recovered from a previous daemon instance: the process is alive but
+    /// this daemon holds no PTY for it, so there is no output to attach to.
+    /// Holds the identity `pty_pid` must still have before it is signalled.
+    reconnected: Option<ProcessIdentity>,
 }
 
 impl DaemonSession {
@@ -72,8 +82,11 @@ impl DaemonSession {
             output_tx: None,
             scrollback: Arc::new(RwLock::new(ScrollbackBuffer::new(scrollback_capacity))),
             attached_clients: HashSet::new(),
+            read_only_clients: HashSet::new(),
+            writer: None,
             pty_pid: None,
             exit_code: None,
+            reconnected: None,
         }
     }
 
@@ -111,6 +124,15 @@ impl DaemonSession {
         &self.working_directory
     }
 
+    pub fn is_reconnected(&self) -> bool {
+        self.reconnected.is_some()
+    }
+
+    /// Identity of a reconnected session's process, if any.
+    pub fn reconnected_process(&self) -> Option<&ProcessIdentity> {
+        self.reconnected.as_ref()
+    }
+
     pub fn has_output(&self) -> bool {
         self.output_tx.is_some()
     }
@@ -143,6 +165,30 @@ impl DaemonSession {
         Ok(())
     }
 
+    /// Transition to Running for a process that outlived the previous daemon.
+    ///
+    /// There is no PTY and no output channel; the session only tracks the
+    /// pid and its identity so it can be listed and stopped.
+    ///
+    /// # Errors
+    /// Returns `InvalidStateTransition` if the session is not in Creating state.
+    pub fn set_reconnected(
+        &mut self,
+        pty_pid: u32,
+        identity: ProcessIdentity,
+    ) -> Result<(), DaemonError> {
+        if !matches!(self.state, SessionState::Creating) {
+            return Err(DaemonError::InvalidStateTransition(format!(
+                "set_reconnected requires Creating state, got {}",
+                self.state
+            )));
+        }
+        self.state = SessionState::Running;
+        self.pty_pid = Some(pty_pid);
+        self.reconnected = Some(identity);
+        Ok(())
+    }
+
     /// Transition to Stopped state, clearing PTY resources.
     /// Idempotent: calling on an already-stopped session is a no-op.
     ///
@@ -164,17 +210,84 @@ impl DaemonSession {
         self.state = SessionState::Stopped;
         self.output_tx = None;
         self.pty_pid = None;
+        self.reconnected = None;
         Ok(())
     }
 
     /// Attach a client to this session.
-    pub fn attach_client(&mut self, client_id: ClientId) {
+    ///
+    /// A read-only client receives output but may not write stdin or resize
+    /// the PTY. Re-attaching replaces the previous mode. The first writable
+    /// client gets the write lock, so a lone attach behaves as before.
+    pub fn attach_client(&mut self, client_id: ClientId, read_only: bool) {
         self.attached_clients.insert(client_id);
+        if read_only {
+            self.read_only_clients.insert(client_id);
+            self.release_write(client_id);
+        } else {
+            self.read_only_clients.remove(&client_id);
+            self.writer.get_or_insert(client_id);
+        }
     }
 
-    /// Detach a client from this session.
+    /// Detach a client from this session, releasing its write lock.
     pub fn detach_client(&mut self, client_id: ClientId) {
         self.attached_clients.remove(&client_id);
+        self.read_only_clients.remove(&client_id);
+        self.release_write(client_id);
+    }
+
+    /// Take the write lock for an attached, writable client.
+    ///
+    /// Granted when the lock is free or already held by `client_id`.
+    pub fn request_write(&mut self, client_id: ClientId) -> Result<(), DaemonError> {
+        if !self.attached_clients.contains(&client_id) {
+            return Err(DaemonError::NotAttached(self.id.clone()));
+        }
+        if self.read_only_clients.contains(&client_id) {
+            return Err(DaemonError::ReadOnlyAttach(self.id.clone()));
+        }
+        match self.writer {
+            Some(holder) if holder != client_id => Err(DaemonError::WriteLockHeld(self.id.clone())),
+            _ => {
+                self.writer = Some(client_id);
+                Ok(())
+            }
+        }
+    }
+
+    /// Whether `client_id` holds the write lock or could take it now.
+    pub fn write_lock_available(&self, client_id: ClientId) -> bool {
+        self.writer.is_none_or(|holder| holder == client_id)
+    }
+
+    /// Release the write lock if `client_id` holds it. Returns whether it did.
+    pub fn release_write(&mut self, client_id: ClientId) -> bool {
+        if self.writer == Some(client_id) {
+            self.writer = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether `client_id` may send input or resize the PTY.
+    ///
+    /// Only attached clients are arbitrated. Connections that never attach
+    /// (`kild inject`, the UI's separate write connection) always pass.
+    pub fn check_input(&self, client_id: ClientId) -> Result<(), DaemonError> {
+        if self.read_only_clients.contains(&client_id) {
+            return Err(DaemonError::ReadOnlyAttach(self.id.clone()));
+        }
+        if self.attached_clients.contains(&client_id) && self.writer != Some(client_id) {
+            return Err(DaemonError::WriteLockHeld(self.id.clone()));
+        }
+        Ok(())
+    }
+
+    /// Whether `client_id` is attached read-only.
+    pub fn is_read_only_client(&self, client_id: ClientId) -> bool {
+        self.read_only_clients.contains(&client_id)
     }
 
     /// Number of currently attached clients.
@@ -275,19 +388,39 @@ mod tests {
         assert!(session.pty_pid().is_none());
     }
 
+    #[test]
+    fn test_set_reconnected() {
+        let identity = ProcessIdentity {
+            name: "claude".to_string(),
+            start_time: 1_700_000_000,
+        };
+        let mut session = test_session();
+        session.set_reconnected(4242, identity.clone()).unwrap();
+        assert_eq!(session.state(), SessionState::Running);
+        assert!(session.is_reconnected());
+        assert!(!session.has_output());
+        assert!(session.subscribe_output().is_none());
+        assert_eq!(session.pty_pid(), Some(4242));
+        assert_eq!(session.reconnected_process(), Some(&identity));
+
+        assert!(session.set_reconnected(4242, identity).is_err());
+        session.set_stopped().unwrap();
+        assert!(!session.is_reconnected());
+    }
+
     #[test]
     fn test_client_tracking() {
         let mut session = test_session();
         assert_eq!(session.client_count(), 0);
 
-        session.attach_client(1);
+        session.attach_client(1, false);
         assert_eq!(session.client_count(), 1);
 
-        session.attach_client(2);
+        session.attach_client(2, false);
         assert_eq!(session.client_count(), 2);
 
         // Duplicate attach is idempotent
-        session.attach_client(1);
+        session.attach_client(1, false);
         assert_eq!(session.client_count(), 2);
 
         session.detach_client(1);
@@ -297,6 +430,80 @@ mod tests {
         assert_eq!(session.client_count(), 0);
     }
 
+    #[test]
+    fn test_read_only_client_tracking() {
+        let mut session = test_session();
+        session.attach_client(1, true);
+        session.attach_client(2, false);
+        assert_eq!(session.client_count(), 2);
+        assert!(session.is_read_only_client(1));
+        assert!(!session.is_read_only_client(2));
+
+        // Re-attaching writable lifts the restriction; input still waits
+        // for the write lock held by client 2.
+        session.attach_client(1, false);
+        assert!(!session.is_read_only_client(1));
+        assert!(matches!(
+            session.check_input(1),
+            Err(DaemonError::WriteLockHeld(_))
+        ));
+        session.detach_client(2);
+        assert!(session.request_write(1).is_ok());
+        assert!(session.check_input(1).is_ok());
+
+        session.attach_client(1, true);
+        session.detach_client(1);
+        assert!(!session.is_read_only_client(1));
+    }
+
+    #[test]
+    fn test_write_lock_second_attach_denied_until_release() {
+        let mut session = test_session();
+        session.attach_client(1, false);
+        session.attach_client(2, false);
+
+        // First writable attach holds the lock
+        assert!(session.check_input(1).is_ok());
+        assert!(matches!(
+            session.check_input(2),
+            Err(DaemonError::WriteLockHeld(_))
+        ));
+        assert!(matches!(
+            session.request_write(2),
+            Err(DaemonError::WriteLockHeld(_))
+        ));
+
+        assert!(session.release_write(1));
+        session.request_write(2).unwrap();
+        assert!(session.check_input(2).is_ok());
+        assert!(session.check_input(1).is_err());
+    }
+
+    #[test]
+    fn test_write_lock_released_on_detach() {
+        let mut session = test_session();
+        session.attach_client(1, false);
+        session.attach_client(2, false);
+        session.detach_client(1);
+        session.request_write(2).unwrap();
+    }
+
+    #[test]
+    fn test_write_lock_rules_for_read_only_and_unattached() {
+        let mut session = test_session();
+        session.attach_client(1, true);
+        assert!(matches!(
+            session.request_write(1),
+            Err(DaemonError::ReadOnlyAttach(_))
+        ));
+        assert!(matches!(
+            session.request_write(9),
+            Err(DaemonError::NotAttached(_))
+        ));
+        // Unattached connections aren't arbitrated
+        assert!(session.check_input(9).is_ok());
+    }
+
     #[test]
     fn test_subscribe_output_when_running() {
         let mut session = test_session();
@@ -316,8 +523,8 @@ mod tests {
     #[test]
     fn test_daemon_session_status() {
         let mut session = test_session();
-        session.attach_client(1);
-        session.attach_client(2);
+        session.attach_client(1, false);
+        session.attach_client(2, false);
 
         let info = session.to_daemon_session_status();
         assert_eq!(&*info.id, "myapp_feature");
@@ -3,9 +3,14 @@
 //! On first daemon start with `bind_tcp` configured, generates a self-signed
 //! certificate via `rcgen` and saves it to `~/.kild/certs/`. Subsequent starts
 //! load the existing PEM files.
+//!
+//! With `client_ca_path` configured, the server also requires mutual TLS:
+//! clients must present a certificate signed by that CA.
 
 use rcgen::{CertifiedKey, generate_simple_self_signed};
+use rustls::RootCertStore;
 use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use rustls::server::WebPkiClientVerifier;
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufReader, Write};
 use std::os::unix::fs::OpenOptionsExt;
@@ -79,19 +84,65 @@ pub fn load_or_generate_cert(
     Ok((vec![cert_der], key_der))
 }
 
+/// Load the CA certificates that client certificates must chain to.
+///
+/// Fails if the file can't be read or holds no certificates, so a typo in
+/// `client_ca_path` can't silently leave the listener without client auth.
+pub fn load_client_ca(path: &Path) -> Result<RootCertStore, DaemonError> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(
+        File::open(path).map_err(DaemonError::Io)?,
+    ))
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(DaemonError::Io)?;
+
+    let mut roots = RootCertStore::empty();
+    for cert in certs {
+        roots
+            .add(cert)
+            .map_err(|e| DaemonError::TlsConfig(format!("invalid client CA cert: {}", e)))?;
+    }
+    if roots.is_empty() {
+        return Err(DaemonError::TlsConfig(format!(
+            "no certificates found in client CA file {}",
+            path.display()
+        )));
+    }
+
+    info!(
+        event = "daemon.tls.client_ca_loaded",
+        path = %path.display(),
+        count = roots.len()
+    );
+    Ok(roots)
+}
+
 /// Build a `rustls::ServerConfig` from the given cert and key.
 ///
+/// With `client_ca`, every client must present a certificate that verifies
+/// against it; the handshake fails otherwise. Without it, no client
+/// certificate is requested.
+///
 /// Uses `builder_with_provider` so the crypto provider is explicit and
 /// independent of whether `install_default()` has been called.
 pub fn build_server_config(
     certs: Vec<CertificateDer<'static>>,
     key: PrivateKeyDer<'static>,
+    client_ca: Option<RootCertStore>,
 ) -> Result<Arc<rustls::ServerConfig>, DaemonError> {
     let provider = Arc::new(rustls::crypto::ring::default_provider());
-    let config = rustls::ServerConfig::builder_with_provider(provider)
+    let builder = rustls::ServerConfig::builder_with_provider(provider.clone())
         .with_safe_default_protocol_versions()
-        .map_err(|e| DaemonError::TlsConfig(e.to_string()))?
-        .with_no_client_auth()
+        .map_err(|e| DaemonError::TlsConfig(e.to_string()))?;
+    let builder = match client_ca {
+        Some(roots) => {
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .map_err(|e| DaemonError::TlsConfig(e.to_string()))?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+    let config = builder
         .with_single_cert(certs, key)
         .map_err(|e| DaemonError::TlsConfig(e.to_string()))?;
     Ok(Arc::new(config))
@@ -149,7 +200,7 @@ mod tests {
         let key_path = dir.path().join("daemon.key");
 
         let (certs, key) = load_or_generate_cert(&cert_path, &key_path).unwrap();
-        let result = build_server_config(certs, key);
+        let result = build_server_config(certs, key, None);
         assert!(
             result.is_ok(),
             "should build server config: {:?}",
@@ -195,4 +246,167 @@ mod tests {
             "expected 'no private key' in error, got: {err}"
         );
     }
+
+    // --- Mutual TLS ---
+
+    use kild_core::daemon::tofu::{TofuVerifier, cert_fingerprint};
+    use rcgen::{
+        BasicConstraints, CertificateParams, DnType, ExtendedKeyUsagePurpose, IsCa, Issuer, KeyPair,
+    };
+    use rustls::pki_types::ServerName;
+    use rustls::{ClientConnection, ServerConnection};
+
+    type Identity = (Vec<CertificateDer<'static>>, PrivateKeyDer<'static>);
+
+    /// An in-memory CA: its cert for the server's trust store, and an issuer
+    /// for signing client certs.
+    fn test_ca(name: &str) -> (CertificateDer<'static>, Issuer<'static, KeyPair>) {
+        let key = KeyPair::generate().unwrap();
+        let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        params.distinguished_name.push(DnType::CommonName, name);
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        let cert = params.self_signed(&key).unwrap();
+        (cert.der().clone(), Issuer::new(params, key))
+    }
+
+    fn client_identity(issuer: &Issuer<'static, KeyPair>) -> Identity {
+        let key = KeyPair::generate().unwrap();
+        let mut params = CertificateParams::new(vec!["kild-client".to_string()]).unwrap();
+        params
+            .distinguished_name
+            .push(DnType::CommonName, "kild-client");
+        params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ClientAuth];
+        let cert = params.signed_by(&key, issuer).unwrap();
+        let key_der = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(key.serialize_der()));
+        (vec![cert.der().clone()], key_der)
+    }
+
+    /// Daemon server config requiring client certs signed by `ca`, plus the
+    /// pinned-fingerprint verifier a kild client would use against it.
+    fn mtls_server(ca: CertificateDer<'static>) -> (Arc<rustls::ServerConfig>, Arc<TofuVerifier>) {
+        let CertifiedKey { cert, signing_key } =
+            generate_simple_self_signed(vec!["kild-daemon".to_string()]).unwrap();
+        let verifier = TofuVerifier::new(cert_fingerprint(cert.der()));
+        let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(signing_key.serialize_der()));
+
+        let mut roots = RootCertStore::empty();
+        roots.add(ca).unwrap();
+        let config = build_server_config(vec![cert.der().clone()], key, Some(roots)).unwrap();
+        (config, verifier)
+    }
+
+    fn client_config(
+        verifier: Arc<TofuVerifier>,
+        identity: Option<Identity>,
+    ) -> rustls::ClientConfig {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let builder = rustls::ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .dangerous()
+            .with_custom_certificate_verifier(verifier);
+        match identity {
+            Some((certs, key)) => builder.with_client_auth_cert(certs, key).unwrap(),
+            None => builder.with_no_client_auth(),
+        }
+    }
+
+    /// Run a TLS handshake between the two configs over in-memory buffers.
+    fn handshake(
+        client_config: rustls::ClientConfig,
+        server_config: Arc<rustls::ServerConfig>,
+    ) -> Result<(), rustls::Error> {
+        let mut client = ClientConnection::new(
+            Arc::new(client_config),
+            ServerName::try_from("kild-daemon").unwrap(),
+        )
+        .unwrap();
+        let mut server = ServerConnection::new(server_config).unwrap();
+
+        for _ in 0..16 {
+            let mut buf = Vec::new();
+            client.write_tls(&mut buf).unwrap();
+            if !buf.is_empty() {
+                server.read_tls(&mut buf.as_slice()).unwrap();
+                server.process_new_packets()?;
+            }
+
+            let mut buf = Vec::new();
+            server.write_tls(&mut buf).unwrap();
+            if !buf.is_empty() {
+                client.read_tls(&mut buf.as_slice()).unwrap();
+                client.process_new_packets()?;
+            }
+
+            if !client.is_handshaking() && !server.is_handshaking() && !server.wants_write() {
+                return Ok(());
+            }
+        }
+        panic!("TLS handshake did not complete");
+    }
+
+    #[test]
+    fn test_mtls_accepts_client_with_cert_from_ca() {
+        let (ca_cert, ca) = test_ca("kild test CA");
+        let (server_config, verifier) = mtls_server(ca_cert);
+
+        let result = handshake(
+            client_config(verifier, Some(client_identity(&ca))),
+            server_config,
+        );
+        assert!(result.is_ok(), "valid client cert rejected: {:?}", result);
+    }
+
+    #[test]
+    fn test_mtls_rejects_client_without_cert() {
+        let (ca_cert, _ca) = test_ca("kild test CA");
+        let (server_config, verifier) = mtls_server(ca_cert);
+
+        let result = handshake(client_config(verifier, None), server_config);
+        assert!(
+            matches!(result, Err(rustls::Error::NoCertificatesPresented)),
+            "expected NoCertificatesPresented, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_mtls_rejects_client_cert_from_other_ca() {
+        let (ca_cert, _ca) = test_ca("kild test CA");
+        let (_other_cert, other_ca) = test_ca("some other CA");
+        let (server_config, verifier) = mtls_server(ca_cert);
+
+        let result = handshake(
+            client_config(verifier, Some(client_identity(&other_ca))),
+            server_config,
+        );
+        assert!(
+            matches!(result, Err(rustls::Error::InvalidCertificate(_))),
+            "expected InvalidCertificate, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_load_client_ca_rejects_file_without_certs() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("ca.pem");
+        fs::write(&path, "not a certificate\n").unwrap();
+
+        let err = load_client_ca(&path).unwrap_err().to_string();
+        assert!(err.contains("no certificates found"), "got: {err}");
+    }
+
+    #[test]
+    fn test_load_client_ca_reads_pem_bundle() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("ca.pem");
+        let key = KeyPair::generate().unwrap();
+        let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        fs::write(&path, params.self_signed(&key).unwrap().pem()).unwrap();
+
+        let roots = load_client_ca(&path).unwrap();
+        assert_eq!(roots.len(), 1);
+    }
 }
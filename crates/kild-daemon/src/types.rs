@@ -18,6 +18,17 @@ pub struct DaemonConfig {
     #[serde(default = "default_pid_path")]
     pub pid_path: PathBuf,
 
+    /// Where session metadata is saved on shutdown for reconciliation on
+    /// the next start.
+    /// Default: `~/.kild/sessions/.daemon/sessions.json`
+    #[serde(default = "default_sessions_state_path")]
+    pub sessions_state_path: PathBuf,
+
+    /// Directory for captured PTY output, one `<session_id>.log` per session.
+    /// Always `~/.kild/logs` so `kild logs` can find it; only tests override it.
+    #[serde(skip, default = "default_logs_dir")]
+    pub logs_dir: PathBuf,
+
     /// Per-session scrollback ring buffer size in bytes.
     /// Default: 262144 (256 KB)
     #[serde(default = "default_scrollback_buffer_size")]
@@ -52,6 +63,26 @@ pub struct DaemonConfig {
     /// Auto-generated at ~/.kild/certs/daemon.key if None and bind_tcp is set.
     #[serde(default)]
     pub tls_key_path: Option<PathBuf>,
+
+    /// Shared secret TCP clients must present in `Hello`.
+    /// Required when `bind_tcp` is set, unless `client_ca_path` is set or
+    /// `allow_unauthenticated_tcp` opts out. The Unix socket never requires
+    /// a token.
+    #[serde(default)]
+    pub auth_token: Option<String>,
+
+    /// PEM bundle of CA certificates for mutual TLS.
+    /// When set, TCP clients must present a certificate signed by one of
+    /// these CAs; connections without one fail the TLS handshake.
+    /// None = no client certificate required.
+    #[serde(default)]
+    pub client_ca_path: Option<PathBuf>,
+
+    /// Accept TCP clients without an `auth_token` or client certificate.
+    /// Anyone who can reach `bind_tcp` can then drive the daemon.
+    /// Default: false
+    #[serde(default)]
+    pub allow_unauthenticated_tcp: bool,
 }
 
 impl DaemonConfig {
@@ -93,6 +124,22 @@ impl DaemonConfig {
             }
             _ => {}
         }
+        if self.auth_token.as_deref().is_some_and(str::is_empty) {
+            return Err(crate::errors::DaemonError::ConfigInvalid(
+                "auth_token must not be empty".to_string(),
+            ));
+        }
+        if self.bind_tcp.is_some()
+            && self.auth_token.is_none()
+            && self.client_ca_path.is_none()
+            && !self.allow_unauthenticated_tcp
+        {
+            return Err(crate::errors::DaemonError::ConfigInvalid(
+                "bind_tcp requires auth_token or client_ca_path \
+                 (set allow_unauthenticated_tcp = true to accept any client)"
+                    .to_string(),
+            ));
+        }
         Ok(())
     }
 }
@@ -102,6 +149,8 @@ impl Default for DaemonConfig {
         Self {
             socket_path: default_socket_path(),
             pid_path: default_pid_path(),
+            sessions_state_path: default_sessions_state_path(),
+            logs_dir: default_logs_dir(),
             scrollback_buffer_size: default_scrollback_buffer_size(),
             pty_output_batch_ms: default_pty_output_batch_ms(),
             client_buffer_size: default_client_buffer_size(),
@@ -109,6 +158,9 @@ impl Default for DaemonConfig {
             bind_tcp: None,
             tls_cert_path: None,
             tls_key_path: None,
+            auth_token: None,
+            client_ca_path: None,
+            allow_unauthenticated_tcp: false,
         }
     }
 }
@@ -139,6 +191,32 @@ fn default_pid_path() -> PathBuf {
         .daemon_pid_file()
 }
 
+fn default_sessions_state_path() -> PathBuf {
+    KildPaths::resolve()
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                event = "daemon.config.sessions_state_path_fallback",
+                error = %e,
+                fallback = "/tmp/.kild",
+            );
+            KildPaths::from_dir(PathBuf::from("/tmp/.kild"))
+        })
+        .daemon_sessions_file()
+}
+
+fn default_logs_dir() -> PathBuf {
+    KildPaths::resolve()
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                event = "daemon.config.logs_dir_fallback",
+                error = %e,
+                fallback = "/tmp/.kild",
+            );
+            KildPaths::from_dir(PathBuf::from("/tmp/.kild"))
+        })
+        .logs_dir()
+}
+
 fn default_scrollback_buffer_size() -> usize {
     262_144
 }
@@ -340,6 +418,9 @@ default = "claude"
         assert!(config.bind_tcp.is_none());
         assert!(config.tls_cert_path.is_none());
         assert!(config.tls_key_path.is_none());
+        assert!(config.auth_token.is_none());
+        assert!(config.client_ca_path.is_none());
+        assert!(!config.allow_unauthenticated_tcp);
     }
 
     #[test]
@@ -376,4 +457,43 @@ bind_tcp = "0.0.0.0:7432"
         config.tls_key_path = Some(std::path::PathBuf::from("/tmp/daemon.key"));
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_validate_empty_auth_token_fails() {
+        let mut config = DaemonConfig::default();
+        config.auth_token = Some(String::new());
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("auth_token"));
+    }
+
+    #[test]
+    fn test_validate_bind_tcp_without_auth_fails() {
+        let mut config = DaemonConfig::default();
+        config.bind_tcp = Some("127.0.0.1:7432".parse().unwrap());
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("auth_token"));
+    }
+
+    #[test]
+    fn test_validate_bind_tcp_with_token_or_client_ca_ok() {
+        let mut config = DaemonConfig::default();
+        config.bind_tcp = Some("127.0.0.1:7432".parse().unwrap());
+        config.auth_token = Some("s3cret".to_string());
+        assert!(config.validate().is_ok());
+
+        config.auth_token = None;
+        config.client_ca_path = Some(std::path::PathBuf::from("/tmp/ca.pem"));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_bind_tcp_explicit_opt_out_ok() {
+        let toml = r#"
+[daemon]
+bind_tcp = "0.0.0.0:7432"
+allow_unauthenticated_tcp = true
+"#;
+        let file: ConfigFile = toml::from_str(toml).unwrap();
+        assert!(file.daemon.validate().is_ok());
+    }
 }
@@ -20,6 +20,8 @@ fn test_config(dir: &std::path::Path) -> DaemonConfig {
     DaemonConfig {
         socket_path: dir.join("daemon.sock"),
         pid_path: dir.join("daemon.pid"),
+        sessions_state_path: dir.join("sessions.json"),
+        logs_dir: dir.join("logs"),
         scrollback_buffer_size: 4096,
         pty_output_batch_ms: 4,
         client_buffer_size: 65536,
@@ -55,6 +57,95 @@ async fn test_ping_roundtrip() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_resize_unknown_session_is_ignored() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = test_config(dir.path());
+    let socket_path = config.socket_path.clone();
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = DaemonClient::connect(&socket_path).await.unwrap();
+    client.resize_pty("no-such-session", 40, 120).await.unwrap();
+
+    // Connection is still usable afterwards
+    let sessions = client.list_sessions(None).await.unwrap();
+    assert!(sessions.is_empty());
+
+    client.shutdown().await.unwrap();
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_handshake_negotiates_current_version() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = test_config(dir.path());
+    let socket_path = config.socket_path.clone();
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // IpcConnection is synchronous — run in spawn_blocking.
+    let result = tokio::task::spawn_blocking(move || {
+        let mut conn = kild_protocol::IpcConnection::connect(&socket_path)?;
+        let version = conn.handshake()?;
+        let compression = conn.compression();
+        conn.send(&kild_protocol::ClientMessage::DaemonStop {
+            id: "stop".to_string(),
+        })?;
+        Ok::<_, kild_protocol::IpcError>((version, compression))
+    })
+    .await
+    .unwrap();
+
+    let (version, compression) = result.unwrap();
+    assert_eq!(version, kild_protocol::PROTOCOL_VERSION);
+    assert_eq!(compression, Some(kild_protocol::Compression::Zstd));
+
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_ping_answered_with_pong_after_handshake() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = test_config(dir.path());
+    let socket_path = config.socket_path.clone();
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let result = tokio::task::spawn_blocking(move || {
+        let ping = kild_protocol::ClientMessage::Ping {
+            id: "hb".to_string(),
+        };
+        // Pre-v8 clients never say Hello and must keep getting Ack.
+        let mut legacy = kild_protocol::IpcConnection::connect(&socket_path)?;
+        let legacy_reply = legacy.send(&ping)?;
+
+        let mut conn = kild_protocol::IpcConnection::connect(&socket_path)?;
+        conn.handshake()?;
+        let reply = conn.send(&ping)?;
+        conn.send(&kild_protocol::ClientMessage::DaemonStop {
+            id: "stop".to_string(),
+        })?;
+        Ok::<_, kild_protocol::IpcError>((legacy_reply, reply))
+    })
+    .await
+    .unwrap();
+
+    let (legacy_reply, reply) = result.unwrap();
+    assert!(matches!(legacy_reply, DaemonMessage::Ack { .. }));
+    assert!(matches!(reply, DaemonMessage::Pong { id } if id == "hb"));
+
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok());
+}
+
 #[tokio::test]
 async fn test_create_session_and_list() {
     let dir = tempfile::tempdir().unwrap();
@@ -105,6 +196,47 @@ async fn test_create_session_and_list() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_stats_reports_sessions_and_clients() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = test_config(dir.path());
+    let socket_path = config.socket_path.clone();
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = DaemonClient::connect(&socket_path).await.unwrap();
+    let _second = DaemonClient::connect(&socket_path).await.unwrap();
+
+    for id in ["stats-a", "stats-b"] {
+        client
+            .create_session(id, "/tmp", "/bin/sh", &[], &HashMap::new(), 24, 80, false)
+            .await
+            .unwrap();
+    }
+    client.stop_session("stats-b").await.unwrap();
+
+    let stats = client.stats().await.unwrap();
+    let DaemonMessage::Stats {
+        session_count,
+        active_ptys,
+        connected_clients,
+        bytes_streamed,
+        ..
+    } = stats
+    else {
+        panic!("expected stats, got {:?}", stats);
+    };
+    assert_eq!(session_count, 2);
+    assert_eq!(active_ptys, 1);
+    assert_eq!(connected_clients, 2);
+    assert_eq!(bytes_streamed, 0, "nothing attached yet");
+
+    client.shutdown().await.unwrap();
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok());
+}
+
 #[tokio::test]
 async fn test_attach_and_read_output() {
     let dir = tempfile::tempdir().unwrap();
@@ -179,6 +311,136 @@ async fn test_attach_and_read_output() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_handoff_does_not_preserve_sessions() {
+    let dir = tempfile::tempdir().unwrap();
+    let socket_path = dir.path().join("daemon.sock");
+
+    // A real daemon process: PTY masters only close when the process exits,
+    // which an in-process server can't reproduce.
+    let mut daemon = std::process::Command::new(env!("CARGO_BIN_EXE_kild-daemon"))
+        .env("KILD_HOME", dir.path())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    while !socket_path.exists() && std::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    // A plain agent process: it does not ignore SIGHUP.
+    let mut client = DaemonClient::connect(&socket_path).await.unwrap();
+    client
+        .create_session(
+            "handoff-hup",
+            "/tmp",
+            "sleep",
+            &["30".to_string()],
+            &HashMap::new(),
+            24,
+            80,
+            false,
+        )
+        .await
+        .unwrap();
+    let pid = client
+        .get_session("handoff-hup")
+        .await
+        .unwrap()
+        .pty_pid
+        .unwrap();
+
+    client.handoff(Duration::from_millis(100)).await.unwrap();
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while daemon.try_wait().unwrap().is_none() && std::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(
+        daemon.try_wait().unwrap().is_some(),
+        "daemon should exit after the handoff"
+    );
+
+    // The old daemon's PTY closed with it, hanging up the agent
+    let alive = || nix::sys::signal::kill(nix::unistd::Pid::from_raw(pid as i32), None).is_ok();
+    let deadline = std::time::Instant::now() + Duration::from_secs(3);
+    while alive() && std::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(!alive(), "agent should not outlive the old daemon");
+
+    let config = DaemonConfig {
+        sessions_state_path: dir
+            .path()
+            .join("sessions")
+            .join(".daemon")
+            .join("sessions.json"),
+        ..test_config(dir.path())
+    };
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = DaemonClient::connect(&socket_path).await.unwrap();
+    let session = client.get_session("handoff-hup").await.unwrap();
+    assert_eq!(session.status, kild_protocol::SessionStatus::Stopped);
+
+    client.shutdown().await.unwrap();
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_handoff_drains_streams_and_reconciles_hangup_survivors() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = test_config(dir.path());
+    let socket_path = config.socket_path.clone();
+    let restart_config = config.clone();
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = DaemonClient::connect(&socket_path).await.unwrap();
+    // Ignore SIGHUP so the process outlives the PTY master closing.
+    let args = vec!["-c".to_string(), "trap '' HUP; sleep 5".to_string()];
+    client
+        .create_session(
+            "handoff-test",
+            "/tmp",
+            "/bin/sh",
+            &args,
+            &HashMap::new(),
+            24,
+            80,
+            false,
+        )
+        .await
+        .unwrap();
+    client.attach("handoff-test", 24, 80).await.unwrap();
+
+    // The attached stream never ends on its own, so the drain runs to its timeout.
+    let mut control = DaemonClient::connect(&socket_path).await.unwrap();
+    let start = std::time::Instant::now();
+    control.handoff(Duration::from_millis(500)).await.unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok(), "daemon should exit after the drain timeout");
+    assert!(start.elapsed() >= Duration::from_millis(400));
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(restart_config).await });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = DaemonClient::connect(&socket_path).await.unwrap();
+    let session = client.get_session("handoff-test").await.unwrap();
+    assert_eq!(session.status, kild_protocol::SessionStatus::Running);
+    // The survivor lost its PTY with the old daemon
+    assert!(client.attach("handoff-test", 24, 80).await.is_err());
+
+    client.destroy_session("handoff-test", true).await.unwrap();
+    client.shutdown().await.unwrap();
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok());
+}
+
 #[tokio::test]
 async fn test_session_not_found_error() {
     let dir = tempfile::tempdir().unwrap();
@@ -336,6 +598,54 @@ async fn test_multiple_clients_attach_to_session() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn test_second_attach_denied_write_until_first_releases() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = test_config(dir.path());
+    let socket_path = config.socket_path.clone();
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // `sleep` prints nothing, and resizes (unlike stdin) aren't echoed, so no
+    // output interleaves with the responses below
+    let mut first = DaemonClient::connect(&socket_path).await.unwrap();
+    first
+        .create_session(
+            "write-lock",
+            "/tmp",
+            "sleep",
+            &["30".to_string()],
+            &HashMap::new(),
+            24,
+            80,
+            false,
+        )
+        .await
+        .unwrap();
+    first.attach("write-lock", 24, 80).await.unwrap();
+
+    let mut second = DaemonClient::connect(&socket_path).await.unwrap();
+    second.attach("write-lock", 24, 80).await.unwrap();
+
+    // First attach holds the lock; the second is read-only until granted
+    first.resize_pty("write-lock", 24, 80).await.unwrap();
+    assert!(second.resize_pty("write-lock", 24, 80).await.is_err());
+    assert!(!second.request_write("write-lock").await.unwrap());
+
+    first.release_write("write-lock").await.unwrap();
+    assert!(second.request_write("write-lock").await.unwrap());
+    second.resize_pty("write-lock", 24, 80).await.unwrap();
+    assert!(first.resize_pty("write-lock", 24, 80).await.is_err());
+
+    let mut admin_client = DaemonClient::connect(&socket_path).await.unwrap();
+    admin_client.stop_session("write-lock").await.unwrap();
+    admin_client.shutdown().await.unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok());
+}
+
 #[tokio::test]
 async fn test_pty_exit_transitions_session_to_stopped() {
     let dir = tempfile::tempdir().unwrap();
@@ -697,6 +1007,105 @@ async fn test_tcp_tls_tofu_rejects_wrong_fingerprint() {
     server_handle.abort();
 }
 
+/// Start a TLS daemon that requires the token "s3cret", then handshake and
+/// ping it presenting `hello_token`.
+async fn tcp_handshake_with_token(
+    hello_token: Option<&'static str>,
+) -> Result<u32, kild_protocol::IpcError> {
+    let port = find_free_tcp_port().await;
+    let addr: std::net::SocketAddr = format!("127.0.0.1:{port}").parse().unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let cert_path = dir.path().join("daemon.crt");
+    let key_path = dir.path().join("daemon.key");
+    let certs = generate_tls_cert(&cert_path, &key_path);
+    let fingerprint = kild_core::daemon::tofu::cert_fingerprint(&certs[0]);
+
+    let mut config = test_config(dir.path());
+    config.bind_tcp = Some(addr);
+    config.tls_cert_path = Some(cert_path);
+    config.tls_key_path = Some(key_path);
+    config.auth_token = Some("s3cret".to_string());
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let addr_str = addr.to_string();
+    let result = tokio::task::spawn_blocking(move || {
+        let verifier = kild_core::daemon::tofu::TofuVerifier::new(fingerprint);
+        let mut conn = kild_protocol::IpcConnection::connect_tls(&addr_str, verifier)?;
+        if let Some(token) = hello_token {
+            conn = conn.with_auth_token(token);
+        }
+        let version = conn.handshake()?;
+        conn.send(&kild_protocol::ClientMessage::Ping {
+            id: "auth-ping".to_string(),
+        })?;
+        Ok(version)
+    })
+    .await
+    .unwrap();
+
+    server_handle.abort();
+    result
+}
+
+#[tokio::test]
+async fn test_tcp_auth_token_accepted() {
+    let result = tcp_handshake_with_token(Some("s3cret")).await;
+    assert_eq!(result.unwrap(), kild_protocol::PROTOCOL_VERSION);
+}
+
+#[tokio::test]
+async fn test_tcp_auth_token_missing_rejected() {
+    let result = tcp_handshake_with_token(None).await;
+    assert!(
+        matches!(
+            result,
+            Err(kild_protocol::IpcError::DaemonError {
+                code: kild_protocol::ErrorCode::Unauthorized,
+                ..
+            })
+        ),
+        "got {:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_tcp_auth_token_wrong_rejected() {
+    let result = tcp_handshake_with_token(Some("guess")).await;
+    assert!(
+        matches!(
+            result,
+            Err(kild_protocol::IpcError::DaemonError {
+                code: kild_protocol::ErrorCode::Unauthorized,
+                ..
+            })
+        ),
+        "got {:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_unix_socket_ignores_auth_token() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = test_config(dir.path());
+    config.auth_token = Some("s3cret".to_string());
+    let socket_path = config.socket_path.clone();
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = DaemonClient::connect(&socket_path).await.unwrap();
+    assert!(client.list_sessions(None).await.unwrap().is_empty());
+    client.shutdown().await.unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok());
+}
+
 #[tokio::test]
 async fn test_invalid_json_does_not_crash_server() {
     let dir = tempfile::tempdir().unwrap();
@@ -767,17 +1176,22 @@ async fn test_attach_skips_scrollback_when_dimensions_change() {
     // Wait for output to arrive in scrollback buffer
     tokio::time::sleep(Duration::from_millis(300)).await;
 
+    // Free the write lock so the next attach takes it and resizes the PTY
+    writer_client.release_write("scroll-test").await.unwrap();
+
     // Attach with DIFFERENT dimensions (40x120 vs 24x80)
     let mut attach_client = DaemonClient::connect(&socket_path).await.unwrap();
     attach_client.attach("scroll-test", 40, 120).await.unwrap();
 
-    // Read messages — expect a scrollback_skipped SessionEvent, NOT a PtyOutput with scrollback
+    // Read messages — expect a scrollback_skipped SessionEvent, NOT a scrollback replay
     let read_result = tokio::time::timeout(Duration::from_secs(2), async {
         let mut got_scrollback_output = false;
         let mut got_skip_notice = false;
         for _ in 0..10 {
             match attach_client.read_next().await {
-                Ok(Some(DaemonMessage::PtyOutput { data, .. })) => {
+                Ok(Some(
+                    DaemonMessage::PtyOutput { data, .. } | DaemonMessage::Scrollback { data, .. },
+                )) => {
                     // Decode base64 and check if it contains our scrollback content
                     if let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(&data) {
                         if String::from_utf8_lossy(&bytes).contains("scrollback-content") {
@@ -859,12 +1273,12 @@ async fn test_attach_replays_scrollback_when_dimensions_match() {
     let mut attach_client = DaemonClient::connect(&socket_path).await.unwrap();
     attach_client.attach("replay-test", 24, 80).await.unwrap();
 
-    // Read messages — expect PtyOutput containing our scrollback content
+    // Read messages — expect a Scrollback replay containing our content
     let read_result = tokio::time::timeout(Duration::from_secs(2), async {
         let mut got_scrollback = false;
         for _ in 0..10 {
             match attach_client.read_next().await {
-                Ok(Some(DaemonMessage::PtyOutput { data, .. })) => {
+                Ok(Some(DaemonMessage::Scrollback { data, .. })) => {
                     if let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(&data) {
                         if String::from_utf8_lossy(&bytes).contains("replay-marker") {
                             got_scrollback = true;
@@ -892,3 +1306,77 @@ async fn test_attach_replays_scrollback_when_dimensions_match() {
     let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_attach_replays_only_scrollback_tail() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = test_config(dir.path());
+    let socket_path = config.socket_path.clone();
+    let capacity = config.scrollback_buffer_size;
+
+    let server_handle = tokio::spawn(async move { kild_daemon::run_server(config).await });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let mut client = DaemonClient::connect(&socket_path).await.unwrap();
+    let working_dir = dir.path().to_string_lossy().to_string();
+    client
+        .create_session(
+            "tail-test",
+            &working_dir,
+            "/bin/sh",
+            &[],
+            &HashMap::new(),
+            24,
+            80,
+            false,
+        )
+        .await
+        .unwrap();
+
+    // Produce roughly twice the buffer size between two markers
+    let mut writer_client = DaemonClient::connect(&socket_path).await.unwrap();
+    writer_client.attach("tail-test", 24, 80).await.unwrap();
+    let script = format!(
+        "echo HEAD-MARKER; yes x | head -c {}; echo; echo TAIL-MARKER\n",
+        capacity * 2
+    );
+    writer_client
+        .write_stdin("tail-test", script.as_bytes())
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let mut attach_client = DaemonClient::connect(&socket_path).await.unwrap();
+    attach_client.attach("tail-test", 24, 80).await.unwrap();
+
+    let replay = tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            match attach_client.read_next().await {
+                Ok(Some(DaemonMessage::Scrollback { data, .. })) => {
+                    return base64::engine::general_purpose::STANDARD
+                        .decode(&data)
+                        .unwrap();
+                }
+                Ok(Some(_)) => continue,
+                other => panic!("expected Scrollback, got: {:?}", other),
+            }
+        }
+    })
+    .await
+    .expect("scrollback replay should arrive after attach");
+
+    let text = String::from_utf8_lossy(&replay);
+    assert!(replay.len() <= capacity, "replayed {} bytes", replay.len());
+    assert!(text.contains("TAIL-MARKER"), "tail missing from replay");
+    assert!(
+        !text.contains("HEAD-MARKER"),
+        "oldest bytes should be dropped"
+    );
+
+    let mut admin = DaemonClient::connect(&socket_path).await.unwrap();
+    admin.stop_session("tail-test").await.unwrap();
+    admin.shutdown().await.unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(3), server_handle).await;
+    assert!(result.is_ok());
+}
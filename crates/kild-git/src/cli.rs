@@ -4,9 +4,12 @@
 //! The git CLI inherits the user's SSH agent and credential helpers automatically,
 //! while git2 requires explicit credential callback setup.
 //!
+//! Worktree moves and branch renames also go through the CLI: git2 has no
+//! `worktree move`, and `branch -m` keeps every worktree's HEAD in sync.
+//!
 //! Each function validates arguments, logs structured events, and maps errors consistently.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use tracing::{debug, error, info, warn};
 
@@ -174,6 +177,9 @@ pub fn rebase(dir: &Path, base_branch: &str) -> Result<(), GitError> {
     let is_conflict = code == 1 && has_conflict_marker;
 
     if is_conflict {
+        // Read the conflicted paths before aborting — the abort clears them
+        let conflicted_files = unmerged_paths(dir);
+
         // Auto-abort to leave worktree clean
         let abort_result = std::process::Command::new("git")
             .current_dir(dir)
@@ -220,11 +226,13 @@ pub fn rebase(dir: &Path, base_branch: &str) -> Result<(), GitError> {
         warn!(
             event = "core.git.rebase_conflicts",
             base = base_branch,
-            path = %dir.display()
+            path = %dir.display(),
+            files = conflicted_files.len()
         );
         return Err(GitError::RebaseConflict {
             base_branch: base_branch.to_string(),
             worktree_path: dir.to_path_buf(),
+            conflicted_files,
         });
     }
 
@@ -241,6 +249,60 @@ pub fn rebase(dir: &Path, base_branch: &str) -> Result<(), GitError> {
     })
 }
 
+/// List paths with unresolved merge conflicts in `dir`.
+///
+/// Best-effort: a failed `git status` is logged and yields an empty list, since
+/// callers only use this to enrich a conflict they already detected.
+fn unmerged_paths(dir: &Path) -> Vec<PathBuf> {
+    let output = match std::process::Command::new("git")
+        .current_dir(dir)
+        .args(["status", "--porcelain=v1", "-z"])
+        .output()
+    {
+        Ok(output) if output.status.success() => output,
+        Ok(output) => {
+            warn!(
+                event = "core.git.conflict_status_failed",
+                path = %dir.display(),
+                stderr = %String::from_utf8_lossy(&output.stderr).trim()
+            );
+            return Vec::new();
+        }
+        Err(e) => {
+            warn!(
+                event = "core.git.conflict_status_failed",
+                path = %dir.display(),
+                error = %e
+            );
+            return Vec::new();
+        }
+    };
+    parse_unmerged_paths(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `git status --porcelain=v1 -z` output, keeping only unmerged entries.
+///
+/// Each entry is `XY <path>`; unmerged entries have `U` on either side or are
+/// both-added/both-deleted. Renames and copies carry their source path as an
+/// extra NUL-terminated field, which is skipped.
+fn parse_unmerged_paths(stdout: &str) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut fields = stdout.split('\0');
+    while let Some(entry) = fields.next() {
+        let Some((xy, path)) = entry.split_at_checked(2) else {
+            continue;
+        };
+        if matches!(xy.as_bytes()[0], b'R' | b'C') {
+            fields.next();
+        }
+        let unmerged = xy.contains('U') || xy == "AA" || xy == "DD";
+        if unmerged && let Some(path) = path.strip_prefix(' ') {
+            files.push(PathBuf::from(path));
+        }
+    }
+    files
+}
+
 /// Execute `git diff` in a worktree, inheriting stdio for terminal output.
 ///
 /// Uses `.status()` (not `.output()`) so diff output appears directly in the
@@ -291,6 +353,172 @@ pub fn show_diff(worktree_path: &Path, staged: bool) -> Result<(), GitError> {
     Ok(())
 }
 
+/// List files that would conflict when merging `HEAD` with `base_branch`.
+///
+/// Uses `git merge-tree --write-tree` (git 2.38+), which computes the merge
+/// in the object database only — the index and working tree are untouched.
+pub fn merge_tree_conflicts(dir: &Path, base_branch: &str) -> Result<Vec<PathBuf>, GitError> {
+    validate_git_arg(base_branch, "base branch")?;
+
+    debug!(
+        event = "core.git.merge_tree_started",
+        base = base_branch,
+        path = %dir.display()
+    );
+
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .args([
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            "--no-messages",
+            "-z",
+            base_branch,
+            "HEAD",
+        ])
+        .output()
+        .map_err(|e| GitError::OperationFailed {
+            message: format!("Failed to execute git merge-tree: {}", e),
+        })?;
+
+    // Exit 0 = clean merge, 1 = conflicts; anything else is a failure
+    match output.status.code() {
+        Some(0) | Some(1) => {}
+        code => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            warn!(
+                event = "core.git.merge_tree_failed",
+                base = base_branch,
+                exit_code = code,
+                stderr = %stderr.trim()
+            );
+            return Err(GitError::OperationFailed {
+                message: format!("git merge-tree failed: {}", stderr.trim()),
+            });
+        }
+    }
+
+    let conflicts = parse_merge_tree_conflicts(&String::from_utf8_lossy(&output.stdout));
+    debug!(
+        event = "core.git.merge_tree_completed",
+        base = base_branch,
+        conflicts = conflicts.len()
+    );
+    Ok(conflicts)
+}
+
+/// Parse `git merge-tree --write-tree --name-only -z` output.
+///
+/// The first NUL-terminated field is the tree OID; conflicted paths follow
+/// until an empty field.
+fn parse_merge_tree_conflicts(stdout: &str) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = stdout
+        .split('\0')
+        .skip(1)
+        .take_while(|field| !field.is_empty())
+        .map(PathBuf::from)
+        .collect();
+    files.dedup();
+    files
+}
+
+/// Count stash entries created on `branch`.
+///
+/// Stashes live in the shared `refs/stash`, so `git stash list` in a worktree
+/// shows every stash in the repository. Only entries whose reflog subject
+/// names `branch` ("WIP on <branch>: ..." or "On <branch>: ...") are counted.
+pub fn count_branch_stashes(dir: &Path, branch: &str) -> Result<usize, GitError> {
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(["stash", "list", "--format=%gs"])
+        .output()
+        .map_err(|e| GitError::OperationFailed {
+            message: format!("Failed to execute git stash list: {}", e),
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!(
+            event = "core.git.stash_list_failed",
+            path = %dir.display(),
+            stderr = %stderr.trim()
+        );
+        return Err(GitError::OperationFailed {
+            message: format!("git stash list failed: {}", stderr.trim()),
+        });
+    }
+
+    let count = count_stashes_on_branch(&String::from_utf8_lossy(&output.stdout), branch);
+    debug!(
+        event = "core.git.stash_list_completed",
+        branch = branch,
+        count = count
+    );
+    Ok(count)
+}
+
+/// Count `git stash list --format=%gs` lines that belong to `branch`.
+fn count_stashes_on_branch(stash_subjects: &str, branch: &str) -> usize {
+    let wip_prefix = format!("WIP on {}:", branch);
+    let msg_prefix = format!("On {}:", branch);
+    stash_subjects
+        .lines()
+        .filter(|line| line.starts_with(&wip_prefix) || line.starts_with(&msg_prefix))
+        .count()
+}
+
+/// Initialize and check out all submodules in a worktree, recursively.
+///
+/// Runs `git submodule update --init --recursive`. Uses the CLI so submodule
+/// clones inherit the user's SSH agent and credential helpers.
+pub fn init_submodules(worktree_path: &Path) -> Result<(), GitError> {
+    run_submodule_update(worktree_path, &[])
+}
+
+/// `git [-c <config>]... submodule update --init --recursive` in `worktree_path`.
+///
+/// `-c` values on the top-level command propagate to the submodule clones.
+fn run_submodule_update(worktree_path: &Path, config: &[&str]) -> Result<(), GitError> {
+    info!(
+        event = "core.git.submodule_init_started",
+        path = %worktree_path.display()
+    );
+
+    let mut command = std::process::Command::new("git");
+    command.current_dir(worktree_path);
+    for entry in config {
+        command.args(["-c", entry]);
+    }
+    let output = command
+        .args(["submodule", "update", "--init", "--recursive"])
+        .output()
+        .map_err(|e| GitError::SubmoduleInitFailed {
+            path: worktree_path.display().to_string(),
+            message: format!("Failed to execute git: {}", e),
+        })?;
+
+    if output.status.success() {
+        info!(
+            event = "core.git.submodule_init_completed",
+            path = %worktree_path.display()
+        );
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        error!(
+            event = "core.git.submodule_init_failed",
+            path = %worktree_path.display(),
+            exit_code = output.status.code(),
+            stderr = %stderr.trim()
+        );
+        Err(GitError::SubmoduleInitFailed {
+            path: worktree_path.display().to_string(),
+            message: stderr.trim().to_string(),
+        })
+    }
+}
+
 /// Get recent commits from a worktree as a formatted string.
 ///
 /// Executes `git log --oneline -n <count>` and returns the output.
@@ -330,6 +558,109 @@ pub fn get_commits(worktree_path: &Path, count: usize) -> Result<String, GitErro
     Ok(commits)
 }
 
+/// Rename a local branch, updating the HEAD of any worktree that has it checked out.
+///
+/// Executes `git branch -m <from> <to>` in `repo_path`.
+pub fn rename_branch(repo_path: &Path, from: &str, to: &str) -> Result<(), GitError> {
+    validate_git_arg(from, "branch name")?;
+    validate_git_arg(to, "branch name")?;
+
+    info!(
+        event = "core.git.branch_rename_started",
+        from = from,
+        to = to,
+        path = %repo_path.display()
+    );
+
+    let output = std::process::Command::new("git")
+        .current_dir(repo_path)
+        .args(["branch", "-m", from, to])
+        .output()
+        .map_err(|e| GitError::BranchRenameFailed {
+            from: from.to_string(),
+            to: to.to_string(),
+            message: format!("Failed to execute git: {}", e),
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!(
+            event = "core.git.branch_rename_failed",
+            from = from,
+            to = to,
+            stderr = %stderr.trim()
+        );
+        return Err(GitError::BranchRenameFailed {
+            from: from.to_string(),
+            to: to.to_string(),
+            message: stderr.trim().to_string(),
+        });
+    }
+
+    info!(
+        event = "core.git.branch_rename_completed",
+        from = from,
+        to = to
+    );
+    Ok(())
+}
+
+/// Move a linked worktree to a new directory.
+///
+/// Executes `git worktree move <from> <to>` in `repo_path`. Fails if `to`
+/// already exists — git would otherwise move the worktree *into* an existing
+/// directory.
+pub fn move_worktree(repo_path: &Path, from: &Path, to: &Path) -> Result<(), GitError> {
+    if to.exists() {
+        return Err(GitError::WorktreeMoveFailed {
+            from: from.display().to_string(),
+            to: to.display().to_string(),
+            message: "destination already exists".to_string(),
+        });
+    }
+
+    info!(
+        event = "core.git.worktree_move_started",
+        from = %from.display(),
+        to = %to.display()
+    );
+
+    let output = std::process::Command::new("git")
+        .current_dir(repo_path)
+        .arg("worktree")
+        .arg("move")
+        .arg(from)
+        .arg(to)
+        .output()
+        .map_err(|e| GitError::WorktreeMoveFailed {
+            from: from.display().to_string(),
+            to: to.display().to_string(),
+            message: format!("Failed to execute git: {}", e),
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!(
+            event = "core.git.worktree_move_failed",
+            from = %from.display(),
+            to = %to.display(),
+            stderr = %stderr.trim()
+        );
+        return Err(GitError::WorktreeMoveFailed {
+            from: from.display().to_string(),
+            to: to.display().to_string(),
+            message: stderr.trim().to_string(),
+        });
+    }
+
+    info!(
+        event = "core.git.worktree_move_completed",
+        from = %from.display(),
+        to = %to.display()
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -389,6 +720,43 @@ mod tests {
         assert!(!is_already_deleted_error(""));
     }
 
+    #[test]
+    fn test_count_stashes_on_branch() {
+        let subjects = "WIP on kild/auth: abc123 add login\n\
+                        On kild/auth: half-done refactor\n\
+                        WIP on kild/auth-v2: def456 other\n\
+                        On main: unrelated\n";
+        assert_eq!(count_stashes_on_branch(subjects, "kild/auth"), 2);
+        assert_eq!(count_stashes_on_branch(subjects, "kild/billing"), 0);
+        assert_eq!(count_stashes_on_branch("", "kild/auth"), 0);
+    }
+
+    #[test]
+    fn test_parse_merge_tree_conflicts() {
+        let clean = "4b825dc642cb6eb9a060e54bf8d69288fbee4904\0";
+        assert!(parse_merge_tree_conflicts(clean).is_empty());
+
+        let conflicted = "4b825dc642cb6eb9a060e54bf8d69288fbee4904\0src/a.rs\0src/b.rs\0\0";
+        assert_eq!(
+            parse_merge_tree_conflicts(conflicted),
+            vec![PathBuf::from("src/a.rs"), PathBuf::from("src/b.rs")]
+        );
+    }
+
+    #[test]
+    fn test_parse_unmerged_paths() {
+        let status = "UU src/a.rs\0M  src/clean.rs\0AA new.txt\0R  renamed.rs\0old.rs\0DU gone.rs\0?? untracked.txt\0";
+        assert_eq!(
+            parse_unmerged_paths(status),
+            vec![
+                PathBuf::from("src/a.rs"),
+                PathBuf::from("new.txt"),
+                PathBuf::from("gone.rs"),
+            ]
+        );
+        assert!(parse_unmerged_paths("").is_empty());
+    }
+
     // --- show_diff tests ---
 
     use std::fs;
@@ -496,4 +864,159 @@ mod tests {
         let result = get_commits(Path::new("/nonexistent/path"), 10);
         assert!(result.is_err());
     }
+
+    // --- rename_branch / move_worktree tests ---
+
+    #[test]
+    fn test_rename_branch_updates_worktree_head() {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        crate::test_support::init_repo_with_commit(&repo).unwrap();
+        crate::test_support::create_branch(&repo, "kild/old").unwrap();
+        let wt = dir.path().join("wt");
+        crate::test_support::create_worktree_for_branch(&repo, "kild-old", &wt, "kild/old")
+            .unwrap();
+
+        rename_branch(&repo, "kild/old", "kild/new").unwrap();
+
+        let wt_repo = git2::Repository::open(&wt).unwrap();
+        let head = wt_repo.head().unwrap();
+        assert_eq!(head.shorthand(), Some("kild/new"));
+    }
+
+    #[test]
+    fn test_rename_branch_missing_branch_fails() {
+        let dir = TempDir::new().unwrap();
+        crate::test_support::init_repo_with_commit(dir.path()).unwrap();
+        let result = rename_branch(dir.path(), "kild/missing", "kild/new");
+        assert!(matches!(result, Err(GitError::BranchRenameFailed { .. })));
+    }
+
+    #[test]
+    fn test_move_worktree_relocates_directory() {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        crate::test_support::init_repo_with_commit(&repo).unwrap();
+        crate::test_support::create_branch(&repo, "kild/feat").unwrap();
+        let from = dir.path().join("feat");
+        crate::test_support::create_worktree_for_branch(&repo, "kild-feat", &from, "kild/feat")
+            .unwrap();
+        let to = dir.path().join("renamed");
+
+        move_worktree(&repo, &from, &to).unwrap();
+
+        assert!(!from.exists());
+        assert!(to.join(".git").exists());
+    }
+
+    #[test]
+    fn test_move_worktree_existing_destination_fails() {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        crate::test_support::init_repo_with_commit(&repo).unwrap();
+        crate::test_support::create_branch(&repo, "kild/feat").unwrap();
+        let from = dir.path().join("feat");
+        crate::test_support::create_worktree_for_branch(&repo, "kild-feat", &from, "kild/feat")
+            .unwrap();
+        let to = dir.path().join("taken");
+        fs::create_dir_all(&to).unwrap();
+        fs::write(to.join("file.txt"), "occupied").unwrap();
+
+        let result = move_worktree(&repo, &from, &to);
+        assert!(matches!(result, Err(GitError::WorktreeMoveFailed { .. })));
+        assert!(from.exists());
+    }
+
+    #[test]
+    fn test_count_branch_stashes_zero_and_one() {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        crate::test_support::init_repo_with_commit(&repo).unwrap();
+        crate::test_support::create_branch(&repo, "kild/feat").unwrap();
+        let worktree = dir.path().join("feat");
+        crate::test_support::create_worktree_for_branch(&repo, "kild-feat", &worktree, "kild/feat")
+            .unwrap();
+
+        assert_eq!(count_branch_stashes(&worktree, "kild/feat").unwrap(), 0);
+
+        fs::write(worktree.join("wip.txt"), "work in progress").unwrap();
+        let status = ProcessCommand::new("git")
+            .args([
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@test.com",
+                "stash",
+                "push",
+                "--include-untracked",
+                "-m",
+                "wip",
+            ])
+            .current_dir(&worktree)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        assert_eq!(count_branch_stashes(&worktree, "kild/feat").unwrap(), 1);
+        // The stash is visible repo-wide but belongs to kild/feat only
+        assert_eq!(count_branch_stashes(&repo, "kild/other").unwrap(), 0);
+    }
+
+    /// Run git with a throwaway identity in `dir`, asserting success.
+    fn git_ok(dir: &Path, args: &[&str]) {
+        let status = ProcessCommand::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@test.com"])
+            .args(["-c", "protocol.file.allow=always"])
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    #[test]
+    fn test_init_submodules_checks_out_local_submodule() {
+        let dir = TempDir::new().unwrap();
+
+        let sub = dir.path().join("sub");
+        fs::create_dir_all(&sub).unwrap();
+        crate::test_support::init_repo_with_commit(&sub).unwrap();
+        fs::write(sub.join("lib.txt"), "submodule content").unwrap();
+        git_ok(&sub, &["add", "lib.txt"]);
+        git_ok(&sub, &["commit", "-m", "add lib"]);
+
+        let repo = dir.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        crate::test_support::init_repo_with_commit(&repo).unwrap();
+        git_ok(
+            &repo,
+            &["submodule", "add", sub.to_str().unwrap(), "vendor/sub"],
+        );
+        git_ok(&repo, &["commit", "-m", "add submodule"]);
+
+        crate::test_support::create_branch(&repo, "kild/feat").unwrap();
+        let worktree = dir.path().join("feat");
+        crate::test_support::create_worktree_for_branch(&repo, "kild-feat", &worktree, "kild/feat")
+            .unwrap();
+        assert!(!worktree.join("vendor/sub/lib.txt").exists());
+
+        // Local file:// clones are blocked by default since git 2.38.1
+        run_submodule_update(&worktree, &["protocol.file.allow=always"]).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(worktree.join("vendor/sub/lib.txt")).unwrap(),
+            "submodule content"
+        );
+    }
+
+    #[test]
+    fn test_init_submodules_failure_is_specific_error() {
+        let dir = TempDir::new().unwrap();
+        let result = init_submodules(dir.path());
+        assert!(matches!(result, Err(GitError::SubmoduleInitFailed { .. })));
+    }
 }
@@ -21,6 +21,20 @@ pub enum GitError {
     #[error("Failed to remove worktree at {path}: {message}")]
     WorktreeRemovalFailed { path: String, message: String },
 
+    #[error("Failed to move worktree from {from} to {to}: {message}")]
+    WorktreeMoveFailed {
+        from: String,
+        to: String,
+        message: String,
+    },
+
+    #[error("Failed to rename branch '{from}' to '{to}': {message}")]
+    BranchRenameFailed {
+        from: String,
+        to: String,
+        message: String,
+    },
+
     #[error("Invalid path: {path}: {message}")]
     InvalidPath { path: String, message: String },
 
@@ -40,6 +54,8 @@ pub enum GitError {
     RebaseConflict {
         base_branch: String,
         worktree_path: std::path::PathBuf,
+        /// Paths (relative to the worktree) that conflicted before the rebase was aborted.
+        conflicted_files: Vec<std::path::PathBuf>,
     },
 
     #[error("Rebase abort failed for '{base_branch}' at {}: {message}", worktree_path.display())]
@@ -61,6 +77,9 @@ pub enum GitError {
     #[error("Git log failed: {message}")]
     LogFailed { message: String },
 
+    #[error("Failed to initialize submodules in {path}: {message}")]
+    SubmoduleInitFailed { path: String, message: String },
+
     #[error("IO error during git operation: {source}")]
     IoError {
         #[from]
@@ -96,6 +115,7 @@ mod tests {
         let error = GitError::RebaseConflict {
             base_branch: "main".to_string(),
             worktree_path: std::path::PathBuf::from("/tmp/test-worktree"),
+            conflicted_files: vec![std::path::PathBuf::from("src/lib.rs")],
         };
         let display = error.to_string();
         assert!(display.contains("main"), "should include base_branch");
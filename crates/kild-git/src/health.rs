@@ -45,6 +45,133 @@ pub fn count_commits_since(repo: &Repository, branch_oid: Oid, base_oid: Oid) ->
     walk.count()
 }
 
+/// Summaries of the commits on HEAD that are not on `base_branch`, oldest first.
+///
+/// Returns an empty list if the worktree, HEAD or base branch cannot be
+/// resolved (logged at debug level) — callers treat it as "nothing to list".
+pub fn commit_summaries_since_base(worktree_path: &Path, base_branch: &str) -> Vec<String> {
+    let repo = match Repository::open(worktree_path) {
+        Ok(r) => r,
+        Err(e) => {
+            debug!(
+                event = "core.git.stats.repo_open_failed",
+                path = %worktree_path.display(),
+                error = %e
+            );
+            return Vec::new();
+        }
+    };
+    let Some(head_oid) = repo.head().ok().and_then(|h| h.target()) else {
+        debug!(event = "core.git.stats.head_read_failed");
+        return Vec::new();
+    };
+    let Some(base_oid) = resolve_branch_oid(&repo, base_branch)
+        .and_then(|base| find_merge_base(&repo, head_oid, base))
+    else {
+        return Vec::new();
+    };
+
+    let mut walk = match repo.revwalk() {
+        Ok(rw) => rw,
+        Err(e) => {
+            warn!(event = "core.git.stats.revwalk_init_failed", error = %e);
+            return Vec::new();
+        }
+    };
+    if let Err(e) = walk.push(head_oid) {
+        warn!(event = "core.git.stats.revwalk_push_failed", error = %e);
+        return Vec::new();
+    }
+    if let Err(e) = walk.hide(base_oid) {
+        warn!(event = "core.git.stats.revwalk_hide_failed", error = %e);
+        return Vec::new();
+    }
+    if let Err(e) = walk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE) {
+        warn!(event = "core.git.stats.revwalk_sort_failed", error = %e);
+        return Vec::new();
+    }
+
+    walk.filter_map(Result::ok)
+        .filter_map(|oid| repo.find_commit(oid).ok())
+        .filter_map(|commit| commit.summary().map(str::to_string))
+        .collect()
+}
+
+/// Daily commit counts on HEAD for the `days` UTC days ending at `now`, oldest first.
+///
+/// Commits are bucketed by author date so a rebase doesn't show up as a burst
+/// of activity. The walk stops at the first commit older than the window, so
+/// cost is bounded by recent history rather than the whole log. Returns an
+/// empty list if the worktree or HEAD cannot be read.
+pub fn daily_commit_counts(
+    worktree_path: &Path,
+    days: usize,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Vec<u32> {
+    if days == 0 {
+        return Vec::new();
+    }
+    let repo = match Repository::open(worktree_path) {
+        Ok(r) => r,
+        Err(e) => {
+            debug!(
+                event = "core.git.stats.repo_open_failed",
+                path = %worktree_path.display(),
+                error = %e
+            );
+            return Vec::new();
+        }
+    };
+    let Some(head_oid) = repo.head().ok().and_then(|h| h.target()) else {
+        debug!(event = "core.git.stats.head_read_failed");
+        return Vec::new();
+    };
+
+    let mut walk = match repo.revwalk() {
+        Ok(rw) => rw,
+        Err(e) => {
+            warn!(event = "core.git.stats.revwalk_init_failed", error = %e);
+            return Vec::new();
+        }
+    };
+    if let Err(e) = walk.push(head_oid) {
+        warn!(event = "core.git.stats.revwalk_push_failed", error = %e);
+        return Vec::new();
+    }
+    if let Err(e) = walk.set_sorting(git2::Sort::TIME) {
+        warn!(event = "core.git.stats.revwalk_sort_failed", error = %e);
+        return Vec::new();
+    }
+
+    let today = now.date_naive();
+    let first_day = today - chrono::Days::new(days as u64 - 1);
+    let window_start = first_day
+        .and_time(chrono::NaiveTime::MIN)
+        .and_utc()
+        .timestamp();
+
+    let mut counts = vec![0u32; days];
+    for commit in walk
+        .filter_map(Result::ok)
+        .filter_map(|oid| repo.find_commit(oid).ok())
+    {
+        // Sorted newest-first by committer time, which never precedes author time
+        if commit.time().seconds() < window_start {
+            break;
+        }
+        let Some(day) = chrono::DateTime::from_timestamp(commit.author().when().seconds(), 0)
+            .map(|t| t.date_naive())
+        else {
+            continue;
+        };
+        if day < first_day || day > today {
+            continue;
+        }
+        counts[(day - first_day).num_days() as usize] += 1;
+    }
+    counts
+}
+
 /// Get the last commit time on HEAD as RFC3339.
 ///
 /// Returns the commit time converted to UTC. Returns `None` if HEAD
@@ -414,6 +541,108 @@ mod tests {
         assert!(health.diff_vs_base.is_some());
     }
 
+    #[test]
+    fn test_commit_summaries_since_base() {
+        let dir = TempDir::new().unwrap();
+        init_git_repo(dir.path());
+
+        fs::write(dir.path().join("file.txt"), "initial").unwrap();
+        git_add_commit(dir.path(), "initial on main");
+        Command::new("git")
+            .args(["branch", "-M", "main"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["checkout", "-b", "kild/test-feature"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        fs::write(dir.path().join("a.txt"), "a").unwrap();
+        git_add_commit(dir.path(), "add a");
+        fs::write(dir.path().join("b.txt"), "b").unwrap();
+        git_add_commit(dir.path(), "add b");
+
+        assert_eq!(
+            commit_summaries_since_base(dir.path(), "main"),
+            vec!["add a".to_string(), "add b".to_string()]
+        );
+        assert!(commit_summaries_since_base(dir.path(), "nonexistent").is_empty());
+    }
+
+    /// Helper: commit with author and committer dates pinned to `date` (RFC3339).
+    fn git_commit_at(dir: &Path, file: &str, date: &str) {
+        fs::write(dir.join(file), date).unwrap();
+        Command::new("git")
+            .args(["add", "."])
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", file])
+            .env("GIT_AUTHOR_DATE", date)
+            .env("GIT_COMMITTER_DATE", date)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_daily_commit_counts_buckets_by_day() {
+        let dir = TempDir::new().unwrap();
+        init_git_repo(dir.path());
+
+        git_commit_at(dir.path(), "old.txt", "2026-01-20T12:00:00Z");
+        git_commit_at(dir.path(), "a.txt", "2026-02-01T09:00:00Z");
+        git_commit_at(dir.path(), "b.txt", "2026-02-01T18:30:00Z");
+        git_commit_at(dir.path(), "c.txt", "2026-02-05T23:59:00Z");
+        git_commit_at(dir.path(), "d.txt", "2026-02-14T00:01:00Z");
+
+        let now = chrono::DateTime::parse_from_rfc3339("2026-02-14T10:00:00Z")
+            .unwrap()
+            .to_utc();
+        let counts = daily_commit_counts(dir.path(), 14, now);
+
+        // Window is Feb 1..=Feb 14; the January commit falls outside it
+        let mut expected = vec![0u32; 14];
+        expected[0] = 2;
+        expected[4] = 1;
+        expected[13] = 1;
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn test_daily_commit_counts_uses_author_date() {
+        let dir = TempDir::new().unwrap();
+        init_git_repo(dir.path());
+
+        // Rebased commit: authored in the window's first day, committed today
+        fs::write(dir.path().join("rebased.txt"), "x").unwrap();
+        Command::new("git")
+            .args(["add", "."])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", "rebased"])
+            .env("GIT_AUTHOR_DATE", "2026-02-12T08:00:00Z")
+            .env("GIT_COMMITTER_DATE", "2026-02-14T08:00:00Z")
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+
+        let now = chrono::DateTime::parse_from_rfc3339("2026-02-14T10:00:00Z")
+            .unwrap()
+            .to_utc();
+        assert_eq!(daily_commit_counts(dir.path(), 3, now), vec![1, 0, 0]);
+    }
+
+    #[test]
+    fn test_daily_commit_counts_unreadable_repo_is_empty() {
+        let now = chrono::Utc::now();
+        assert!(daily_commit_counts(Path::new("/nonexistent/path"), 14, now).is_empty());
+    }
+
     #[test]
     fn test_collect_branch_health_behind_base() {
         let dir = TempDir::new().unwrap();
@@ -465,6 +694,54 @@ mod tests {
         assert_eq!(health.drift.behind, 2);
     }
 
+    #[test]
+    fn test_collect_branch_health_stale_threshold() {
+        let dir = TempDir::new().unwrap();
+        init_git_repo(dir.path());
+
+        fs::write(dir.path().join("file.txt"), "initial").unwrap();
+        git_add_commit(dir.path(), "initial on main");
+        Command::new("git")
+            .args(["branch", "-M", "main"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+
+        // Kild branch: 2 commits ahead
+        Command::new("git")
+            .args(["checkout", "-b", "kild/test-stale"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        for i in 0..2 {
+            fs::write(dir.path().join(format!("feature-{}.txt", i)), "feature").unwrap();
+            git_add_commit(dir.path(), &format!("feature {}", i));
+        }
+
+        // Base branch: 3 commits the kild doesn't have
+        Command::new("git")
+            .args(["checkout", "main"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        for i in 0..3 {
+            fs::write(dir.path().join(format!("main-{}.txt", i)), "main").unwrap();
+            git_add_commit(dir.path(), &format!("main {}", i));
+        }
+
+        let health =
+            collect_branch_health(dir.path(), "test-stale", "main", "2026-02-09T10:00:00Z")
+                .unwrap();
+        assert_eq!(health.drift.ahead, 2);
+        assert_eq!(health.drift.behind, 3);
+        assert_eq!(health.drift.base_branch, "main");
+
+        // Stale only once strictly more than `threshold` commits behind
+        assert!(health.drift.is_stale(2));
+        assert!(!health.drift.is_stale(3));
+        assert!(!health.drift.is_stale(20));
+    }
+
     #[test]
     fn test_collect_branch_health_with_conflicts() {
         let dir = TempDir::new().unwrap();
@@ -116,8 +116,8 @@ mod tests {
 
         if std::env::set_current_dir("/tmp").is_ok() {
             let result = detect_project();
-            if result.is_err() {
-                assert!(matches!(result.unwrap_err(), GitError::NotInRepository));
+            if let Err(err) = result {
+                assert!(matches!(err, GitError::NotInRepository));
             }
 
             let _ = std::env::set_current_dir(original_dir);
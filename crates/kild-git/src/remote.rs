@@ -1,6 +1,8 @@
 use std::path::Path;
 
 use crate::errors::GitError;
+use crate::naming::KILD_BRANCH_PREFIX;
+use crate::types::{FileOverlap, SyncOutcome};
 
 /// Fetch a specific branch from a remote using git CLI.
 ///
@@ -12,11 +14,62 @@ pub fn fetch_remote(repo_path: &Path, remote: &str, branch: &str) -> Result<(),
 /// Rebase a worktree onto the given base branch.
 ///
 /// Delegates to [`super::cli::rebase`] for centralized CLI handling.
-/// On conflict, auto-aborts the rebase and returns `GitError::RebaseConflict`.
+/// On conflict, auto-aborts the rebase and returns `GitError::RebaseConflict`
+/// listing the conflicted paths.
 pub fn rebase_worktree(worktree_path: &Path, base_branch: &str) -> Result<(), GitError> {
     super::cli::rebase(worktree_path, base_branch)
 }
 
+/// Rebase a worktree onto `base_branch` unless it has uncommitted changes.
+///
+/// Dirty worktrees are skipped rather than force-rebased, and a conflicting
+/// rebase is aborted and reported as [`SyncOutcome::Conflict`] so callers
+/// syncing many kilds can continue. Other git failures are returned as errors.
+pub fn sync_worktree(worktree_path: &Path, base_branch: &str) -> Result<SyncOutcome, GitError> {
+    if super::query::has_uncommitted_changes(worktree_path) == Some(true) {
+        return Ok(SyncOutcome::SkippedDirty);
+    }
+
+    match rebase_worktree(worktree_path, base_branch) {
+        Ok(()) => Ok(SyncOutcome::Synced),
+        Err(GitError::RebaseConflict {
+            conflicted_files, ..
+        }) => Ok(SyncOutcome::Conflict {
+            files: conflicted_files,
+        }),
+        Err(e) => Err(e),
+    }
+}
+
+/// Predict which files would conflict if the worktree were rebased onto `base_branch`.
+///
+/// Dry run via `git merge-tree` — neither the index nor the working tree is
+/// modified. Each overlap lists the base branch and the worktree's branch
+/// (without the `kild/` prefix). An empty list means the merge is clean.
+pub fn predict_conflicts(
+    worktree_path: &Path,
+    base_branch: &str,
+) -> Result<Vec<FileOverlap>, GitError> {
+    let files = super::cli::merge_tree_conflicts(worktree_path, base_branch)?;
+    if files.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let head = super::query::head_branch_name(worktree_path)?.unwrap_or_else(|| "HEAD".into());
+    let head = head.strip_prefix(KILD_BRANCH_PREFIX).unwrap_or(&head);
+    let mut branches = vec![base_branch.to_string(), head.to_string()];
+    branches.sort();
+    branches.dedup();
+
+    Ok(files
+        .into_iter()
+        .map(|file| FileOverlap {
+            file,
+            branches: branches.clone(),
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,6 +87,10 @@ mod tests {
     /// Test helper: Initialize a git repository with an initial commit.
     fn init_test_repo(path: &Path) {
         let repo = Repository::init(path).expect("Failed to init git repo");
+        // `sync_worktree` shells out to `git rebase`, which needs a committer identity.
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test").unwrap();
+        config.set_str("user.email", "test@test.com").unwrap();
         let sig = repo
             .signature()
             .unwrap_or_else(|_| git2::Signature::now("Test", "test@test.com").unwrap());
@@ -166,14 +223,26 @@ mod tests {
         repo.worktree("kild-test", &worktree_path, Some(&opts))
             .unwrap();
 
-        // Add conflicting file on base branch
+        // Add two conflicting files on base branch
         std::fs::write(repo_dir.join("conflict.txt"), "main version\n").unwrap();
-        add_and_commit(&repo, "conflict.txt", "main: add conflict file");
+        std::fs::write(repo_dir.join("other.txt"), "main version\n").unwrap();
+        {
+            let mut index = repo.index().unwrap();
+            index.add_path(Path::new("other.txt")).unwrap();
+            index.write().unwrap();
+        }
+        add_and_commit(&repo, "conflict.txt", "main: add conflict files");
 
-        // Add conflicting file in worktree
+        // Add the same files with different content in worktree
         let wt_repo = Repository::open(&worktree_path).unwrap();
         std::fs::write(worktree_path.join("conflict.txt"), "branch version\n").unwrap();
-        add_and_commit(&wt_repo, "conflict.txt", "branch: add conflict file");
+        std::fs::write(worktree_path.join("other.txt"), "branch version\n").unwrap();
+        {
+            let mut index = wt_repo.index().unwrap();
+            index.add_path(Path::new("other.txt")).unwrap();
+            index.write().unwrap();
+        }
+        add_and_commit(&wt_repo, "conflict.txt", "branch: add conflict files");
 
         // Canonicalize for macOS /tmp -> /private/tmp
         let canonical_wt = worktree_path.canonicalize().unwrap();
@@ -186,9 +255,14 @@ mod tests {
             GitError::RebaseConflict {
                 base_branch: err_base,
                 worktree_path: err_path,
+                conflicted_files,
             } => {
                 assert_eq!(err_base, base_branch);
                 assert_eq!(err_path, canonical_wt);
+                assert_eq!(
+                    conflicted_files,
+                    vec![PathBuf::from("conflict.txt"), PathBuf::from("other.txt")]
+                );
             }
             other => panic!("Expected RebaseConflict, got: {:?}", other),
         }
@@ -206,6 +280,167 @@ mod tests {
         let _ = std::fs::remove_dir_all(&worktree_base);
     }
 
+    /// Test helper: Repo with a `kild/test` worktree, for conflict prediction.
+    fn setup_predict_repo(name: &str) -> (PathBuf, PathBuf, PathBuf, String) {
+        let repo_dir = create_temp_test_dir(name);
+        let worktree_base = create_temp_test_dir(&format!("{}_wt", name));
+        init_test_repo(&repo_dir);
+
+        let repo = Repository::open(&repo_dir).unwrap();
+        let base_branch = default_branch_name(&repo);
+        let head_commit = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("kild/test", &head_commit, false).unwrap();
+
+        let worktree_path = worktree_base.join("test");
+        let branch_ref = repo
+            .find_branch("kild/test", BranchType::Local)
+            .unwrap()
+            .into_reference();
+        let mut opts = WorktreeAddOptions::new();
+        opts.reference(Some(&branch_ref));
+        repo.worktree("kild-test", &worktree_path, Some(&opts))
+            .unwrap();
+
+        (repo_dir, worktree_base, worktree_path, base_branch)
+    }
+
+    #[test]
+    fn test_predict_conflicts_reports_conflicting_file() {
+        let (repo_dir, worktree_base, worktree_path, base_branch) =
+            setup_predict_repo("kild_test_predict_conflict");
+
+        let repo = Repository::open(&repo_dir).unwrap();
+        std::fs::write(repo_dir.join("conflict.txt"), "main version\n").unwrap();
+        add_and_commit(&repo, "conflict.txt", "main: add conflict file");
+
+        let wt_repo = Repository::open(&worktree_path).unwrap();
+        std::fs::write(worktree_path.join("conflict.txt"), "branch version\n").unwrap();
+        add_and_commit(&wt_repo, "conflict.txt", "branch: add conflict file");
+
+        let conflicts = predict_conflicts(&worktree_path, &base_branch).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].file, PathBuf::from("conflict.txt"));
+        assert!(conflicts[0].branches.contains(&"test".to_string()));
+        assert!(conflicts[0].branches.contains(&base_branch));
+
+        // Prediction must not touch the worktree
+        let statuses = wt_repo.statuses(None).unwrap();
+        assert_eq!(statuses.len(), 0);
+        assert_eq!(
+            std::fs::read_to_string(worktree_path.join("conflict.txt")).unwrap(),
+            "branch version\n"
+        );
+
+        let _ = std::fs::remove_dir_all(&repo_dir);
+        let _ = std::fs::remove_dir_all(&worktree_base);
+    }
+
+    #[test]
+    fn test_predict_conflicts_clean_merge_is_empty() {
+        let (repo_dir, worktree_base, worktree_path, base_branch) =
+            setup_predict_repo("kild_test_predict_clean");
+
+        let repo = Repository::open(&repo_dir).unwrap();
+        std::fs::write(repo_dir.join("main.txt"), "main\n").unwrap();
+        add_and_commit(&repo, "main.txt", "main: add file");
+
+        let wt_repo = Repository::open(&worktree_path).unwrap();
+        std::fs::write(worktree_path.join("branch.txt"), "branch\n").unwrap();
+        add_and_commit(&wt_repo, "branch.txt", "branch: add file");
+
+        let conflicts = predict_conflicts(&worktree_path, &base_branch).unwrap();
+        assert!(conflicts.is_empty());
+
+        let _ = std::fs::remove_dir_all(&repo_dir);
+        let _ = std::fs::remove_dir_all(&worktree_base);
+    }
+
+    /// Test helper: Add a worktree for `kild/<name>` branched from the repo's HEAD.
+    fn add_kild_worktree(repo: &Repository, worktree_base: &Path, name: &str) -> PathBuf {
+        let head_commit = repo.head().unwrap().peel_to_commit().unwrap();
+        let branch = format!("kild/{}", name);
+        repo.branch(&branch, &head_commit, false).unwrap();
+
+        let worktree_path = worktree_base.join(name);
+        let branch_ref = repo
+            .find_branch(&branch, BranchType::Local)
+            .unwrap()
+            .into_reference();
+        let mut opts = WorktreeAddOptions::new();
+        opts.reference(Some(&branch_ref));
+        repo.worktree(&format!("kild-{}", name), &worktree_path, Some(&opts))
+            .unwrap();
+        worktree_path.canonicalize().unwrap()
+    }
+
+    #[test]
+    fn test_sync_worktree_reports_per_kild_outcomes() {
+        let repo_dir = create_temp_test_dir("kild_test_sync_outcomes");
+        let worktree_base = create_temp_test_dir("kild_test_sync_outcomes_wt");
+        init_test_repo(&repo_dir);
+
+        let repo = Repository::open(&repo_dir).unwrap();
+        let base_branch = default_branch_name(&repo);
+        let clean = add_kild_worktree(&repo, &worktree_base, "clean");
+        let dirty = add_kild_worktree(&repo, &worktree_base, "dirty");
+        let conflicting = add_kild_worktree(&repo, &worktree_base, "conflicting");
+
+        let clean_repo = Repository::open(&clean).unwrap();
+        std::fs::write(clean.join("clean.txt"), "clean\n").unwrap();
+        add_and_commit(&clean_repo, "clean.txt", "clean: add file");
+
+        std::fs::write(dirty.join("wip.txt"), "not committed\n").unwrap();
+
+        let conflicting_repo = Repository::open(&conflicting).unwrap();
+        std::fs::write(conflicting.join("shared.txt"), "branch version\n").unwrap();
+        add_and_commit(&conflicting_repo, "shared.txt", "branch: add shared file");
+
+        std::fs::write(repo_dir.join("shared.txt"), "main version\n").unwrap();
+        add_and_commit(&repo, "shared.txt", "main: add shared file");
+
+        assert_eq!(
+            sync_worktree(&clean, &base_branch).unwrap(),
+            SyncOutcome::Synced
+        );
+        assert_eq!(
+            sync_worktree(&dirty, &base_branch).unwrap(),
+            SyncOutcome::SkippedDirty
+        );
+        assert_eq!(
+            sync_worktree(&conflicting, &base_branch).unwrap(),
+            SyncOutcome::Conflict {
+                files: vec![PathBuf::from("shared.txt")]
+            }
+        );
+
+        // Clean kild now sits on top of base; the others are untouched
+        assert!(clean.join("shared.txt").exists());
+        assert!(!dirty.join("shared.txt").exists());
+        assert!(dirty.join("wip.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(conflicting.join("shared.txt")).unwrap(),
+            "branch version\n"
+        );
+        assert_eq!(conflicting_repo.statuses(None).unwrap().len(), 0);
+
+        let _ = std::fs::remove_dir_all(&repo_dir);
+        let _ = std::fs::remove_dir_all(&worktree_base);
+    }
+
+    #[test]
+    fn test_sync_worktree_propagates_non_conflict_errors() {
+        let temp_dir = create_temp_test_dir("kild_test_sync_bad_base");
+        init_test_repo(&temp_dir);
+
+        let result = sync_worktree(&temp_dir, "--upload-pack=evil");
+        assert!(matches!(
+            result.unwrap_err(),
+            GitError::OperationFailed { .. }
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
     #[test]
     fn test_rebase_worktree_rejects_dash_prefixed_branch() {
         let temp_dir = create_temp_test_dir("kild_test_rebase_dash");
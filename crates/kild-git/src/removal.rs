@@ -314,6 +314,41 @@ pub fn remove_worktree_by_path(worktree_path: &Path) -> Result<(), GitError> {
     Ok(())
 }
 
+/// Remove a worktree's directory and git registration, keeping its branch.
+///
+/// Used when archiving a kild: the branch (and all committed work) stays in
+/// the repository so the worktree can be recreated later. Uncommitted changes
+/// in the worktree are lost — callers must check first.
+pub fn detach_worktree(worktree_path: &Path) -> Result<(), GitError> {
+    assert_not_main_repo(worktree_path)?;
+
+    info!(
+        event = "core.git.worktree.detach_started",
+        worktree_path = %worktree_path.display()
+    );
+
+    let repo = find_main_repository(worktree_path)?;
+
+    if let Some(worktree) = find_worktree_by_path(&repo, worktree_path) {
+        let mut prune_options = git2::WorktreePruneOptions::new();
+        prune_options.valid(true);
+        prune_options.working_tree(true);
+        worktree
+            .prune(Some(&mut prune_options))
+            .map_err(|e| GitError::Git2Error { source: e })?;
+    }
+    if worktree_path.exists() {
+        std::fs::remove_dir_all(worktree_path).map_err(|e| GitError::IoError { source: e })?;
+    }
+
+    info!(
+        event = "core.git.worktree.detach_completed",
+        worktree_path = %worktree_path.display()
+    );
+
+    Ok(())
+}
+
 /// Force removes a git worktree, bypassing uncommitted changes check.
 ///
 /// Use with caution - uncommitted work will be lost.
@@ -514,6 +549,45 @@ mod tests {
         let _ = std::fs::remove_dir_all(&worktree_base);
     }
 
+    #[test]
+    fn test_detach_worktree_keeps_branch() {
+        let repo_dir = create_temp_test_dir("kild_test_detach_repo");
+        let worktree_base = create_temp_test_dir("kild_test_detach_wt");
+        init_test_repo(&repo_dir);
+
+        let repo = Repository::open(&repo_dir).unwrap();
+        let head_commit = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("kild/feature", &head_commit, false).unwrap();
+
+        let worktree_path = worktree_base.join("kild-feature");
+        let branch_ref = repo
+            .find_branch("kild/feature", git2::BranchType::Local)
+            .unwrap()
+            .into_reference();
+        let mut opts = WorktreeAddOptions::new();
+        opts.reference(Some(&branch_ref));
+        repo.worktree("kild-feature", &worktree_path, Some(&opts))
+            .unwrap();
+
+        let canonical_worktree_path = worktree_path.canonicalize().unwrap();
+        detach_worktree(&canonical_worktree_path).unwrap();
+
+        assert!(!worktree_path.exists());
+        let repo = Repository::open(&repo_dir).unwrap();
+        assert!(
+            repo.find_worktree("kild-feature").is_err(),
+            "worktree registration should be pruned"
+        );
+        assert!(
+            repo.find_branch("kild/feature", git2::BranchType::Local)
+                .is_ok(),
+            "kild/feature branch should survive detach"
+        );
+
+        let _ = std::fs::remove_dir_all(&repo_dir);
+        let _ = std::fs::remove_dir_all(&worktree_base);
+    }
+
     #[test]
     fn test_assert_not_main_repo_blocks_main_checkout() {
         let dir = create_temp_test_dir("kild_test_assert_main_repo");
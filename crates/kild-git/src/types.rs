@@ -7,7 +7,7 @@ use std::path::PathBuf;
 /// Context-dependent meaning:
 /// - In `GitStats.uncommitted_diff`: unstaged changes (index vs working directory).
 /// - In `GitStats.diff_vs_base` / `BranchHealth.diff_vs_base`: total branch changes (merge base vs branch tip).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
 pub struct DiffStats {
     /// Number of lines added
     pub insertions: usize,
@@ -217,6 +217,25 @@ pub struct BaseBranchDrift {
     pub base_branch: String,
 }
 
+impl BaseBranchDrift {
+    /// Whether the branch has fallen more than `threshold` commits behind its base.
+    pub fn is_stale(&self, threshold: usize) -> bool {
+        self.behind > threshold
+    }
+}
+
+/// Outcome of syncing one kild's worktree onto its base branch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncOutcome {
+    /// Rebased cleanly onto the base branch.
+    Synced,
+    /// Rebase hit conflicts in `files` and was aborted — the worktree is unchanged.
+    Conflict { files: Vec<PathBuf> },
+    /// Worktree has uncommitted changes, so it was not rebased.
+    SkippedDirty,
+}
+
 /// Result of in-memory merge conflict detection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -502,7 +521,7 @@ mod tests {
             deletions: 10,
             files_changed: 5,
         };
-        let value = serde_json::to_value(&stats).expect("DiffStats should serialize");
+        let value = serde_json::to_value(stats).expect("DiffStats should serialize");
         assert_eq!(value["insertions"], 42);
         assert_eq!(value["deletions"], 10);
         assert_eq!(value["files_changed"], 5);
@@ -2,28 +2,72 @@ use std::path::{Path, PathBuf};
 
 #[derive(Debug, thiserror::Error)]
 pub enum PathError {
-    #[error("home directory not found — set $HOME environment variable")]
+    #[error("home directory not found — set $KILD_HOME or $HOME environment variable")]
     HomeNotFound,
+
+    #[error("invalid kild scope '{scope}' — must be a single path component")]
+    InvalidScope { scope: String },
 }
 
 /// Centralized path construction for the `~/.kild/` directory layout.
 ///
 /// Single source of truth for every path under `~/.kild/`. Use `resolve()` in
 /// production code and `from_dir()` in tests.
+///
+/// The base directory is chosen by `resolve()` in this order:
+/// 1. `$KILD_HOME`
+/// 2. `$XDG_STATE_HOME/kild`
+/// 3. `~/.kild`
 #[derive(Debug, Clone)]
 pub struct KildPaths {
     kild_dir: PathBuf,
 }
 
 impl KildPaths {
-    /// Resolve paths from the user's home directory (`~/.kild`).
+    /// Resolve the base directory from the environment.
+    ///
+    /// Checks `$KILD_HOME`, then `$XDG_STATE_HOME/kild`, then falls back to
+    /// `~/.kild`. Empty values are ignored, and `$XDG_STATE_HOME` must be an
+    /// absolute path per the XDG Base Directory spec.
     pub fn resolve() -> Result<Self, PathError> {
+        if let Some(kild_home) = env_path("KILD_HOME") {
+            return Ok(Self {
+                kild_dir: kild_home,
+            });
+        }
+
+        if let Some(state_home) = env_path("XDG_STATE_HOME").filter(|p| p.is_absolute()) {
+            return Ok(Self {
+                kild_dir: state_home.join("kild"),
+            });
+        }
+
         let home = dirs::home_dir().ok_or(PathError::HomeNotFound)?;
         Ok(Self {
             kild_dir: home.join(".kild"),
         })
     }
 
+    /// Resolve paths isolated under `<kild_dir>/scopes/<scope>/`.
+    ///
+    /// Every accessor (sessions, shim, daemon socket, ...) lands inside the
+    /// scoped tree, so parallel CI jobs on one machine don't share state. An
+    /// empty scope is identical to `resolve()`.
+    pub fn resolve_scoped(scope: &str) -> Result<Self, PathError> {
+        let base = Self::resolve()?;
+        if scope.is_empty() {
+            return Ok(base);
+        }
+        if scope == "." || scope == ".." || scope.contains(['/', '\\']) {
+            return Err(PathError::InvalidScope {
+                scope: scope.to_string(),
+            });
+        }
+        Ok(Self {
+            kild_dir: base.kild_dir.join("scopes").join(scope),
+        })
+    }
+
     /// Create paths from an explicit base directory. Use in tests.
     pub fn from_dir(kild_dir: PathBuf) -> Self {
         Self { kild_dir }
@@ -64,6 +108,10 @@ impl KildPaths {
         self.kild_dir.join("health_history")
     }
 
+    pub fn logs_dir(&self) -> PathBuf {
+        self.kild_dir.join("logs")
+    }
+
     // --- Fleet paths ---
 
     pub fn fleet_dir(&self) -> PathBuf {
@@ -101,10 +149,23 @@ impl KildPaths {
         self.kild_dir.join("daemon.bin")
     }
 
+    /// Daemon session metadata saved on shutdown and reconciled on startup.
+    ///
+    /// Lives in a dot-directory under `sessions/` so session enumeration,
+    /// which only looks at directories holding a `kild.json`, skips it.
+    pub fn daemon_sessions_file(&self) -> PathBuf {
+        self.sessions_dir().join(".daemon").join("sessions.json")
+    }
+
     pub fn projects_file(&self) -> PathBuf {
         self.kild_dir.join("projects.json")
     }
 
+    /// Append-only JSONL log of kild state-change events, tailed by `kild events`.
+    pub fn events_log(&self) -> PathBuf {
+        self.kild_dir.join("events.jsonl")
+    }
+
     pub fn user_config(&self) -> PathBuf {
         self.kild_dir.join("config.toml")
     }
@@ -144,8 +205,8 @@ impl KildPaths {
     }
 
     pub fn session_dir(&self, session_id: &str) -> PathBuf {
-        let safe_id = session_id.replace('/', "_");
-        self.sessions_dir().join(safe_id)
+        self.sessions_dir()
+            .join(Self::encode_session_id(session_id))
     }
 
     pub fn session_file(&self, session_id: &str) -> PathBuf {
@@ -160,13 +221,96 @@ impl KildPaths {
         self.session_dir(session_id).join("pr")
     }
 
+    /// Captured PTY output for a daemon session (one per agent spawn).
+    pub fn agent_log_file(&self, daemon_session_id: &str) -> PathBuf {
+        Self::agent_log_file_in(&self.logs_dir(), daemon_session_id)
+    }
+
     pub fn pid_file(&self, session_id: &str) -> PathBuf {
-        let safe_id = session_id.replace('/', "-");
+        let safe_id = Self::encode_session_id(session_id);
         self.pids_dir().join(format!("{safe_id}.pid"))
     }
 
+    // --- Enumeration ---
+
+    /// On-disk names of every session directory that contains a `kild.json`.
+    ///
+    /// Names are returned as stored on disk (see `encode_session_id()`), sorted
+    /// for deterministic output. Returns an empty vec if `sessions_dir()` does not
+    /// exist yet.
+    pub fn session_ids(&self) -> std::io::Result<Vec<String>> {
+        let entries = match std::fs::read_dir(self.sessions_dir()) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut ids = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            if !path.is_dir() || !path.join("kild.json").is_file() {
+                continue;
+            }
+            if let Some(name) = entry.file_name().to_str() {
+                ids.push(name.to_string());
+            }
+        }
+        ids.sort();
+        Ok(ids)
+    }
+
     // --- Static helpers (no self) ---
 
+    /// Log file for a daemon session inside an arbitrary logs directory.
+    ///
+    /// The daemon writes logs to its configured directory, which defaults to
+    /// `logs_dir()`; this keeps the file naming in one place.
+    pub fn agent_log_file_in(logs_dir: &Path, daemon_session_id: &str) -> PathBuf {
+        let safe_id = Self::encode_session_id(daemon_session_id);
+        logs_dir.join(format!("{safe_id}.log"))
+    }
+
+    /// Encode a session id into a single filesystem-safe path component.
+    ///
+    /// Percent-encodes `%` as `%25` and `/` as `%2F`, so distinct ids always map
+    /// to distinct names (`a/b` → `a%2Fb`, `a_b` → `a_b`).
+    pub fn encode_session_id(session_id: &str) -> String {
+        let mut encoded = String::with_capacity(session_id.len());
+        for c in session_id.chars() {
+            match c {
+                '%' => encoded.push_str("%25"),
+                '/' => encoded.push_str("%2F"),
+                _ => encoded.push(c),
+            }
+        }
+        encoded
+    }
+
+    /// Reverse `encode_session_id()`.
+    ///
+    /// Returns `None` for names that `encode_session_id()` could not have
+    /// produced (a raw `/`, or a `%` not followed by `25` or `2F`), since those
+    /// cannot be mapped back to a single session id.
+    pub fn decode_session_id(encoded: &str) -> Option<String> {
+        let mut decoded = String::with_capacity(encoded.len());
+        let mut rest = encoded;
+        while let Some(idx) = rest.find(['%', '/']) {
+            decoded.push_str(&rest[..idx]);
+            let tail = &rest[idx..];
+            if tail.starts_with("%25") {
+                decoded.push('%');
+            } else if tail.starts_with("%2F") {
+                decoded.push('/');
+            } else {
+                return None;
+            }
+            rest = &tail[3..];
+        }
+        decoded.push_str(rest);
+        Some(decoded)
+    }
+
     /// Project-level config: `<project_root>/.kild/config.toml`.
     pub fn project_config(project_root: &Path) -> PathBuf {
         project_root.join(".kild").join("config.toml")
@@ -178,9 +322,17 @@ impl KildPaths {
     }
 }
 
+/// Read a path-valued environment variable, treating empty values as unset.
+fn env_path(key: &str) -> Option<PathBuf> {
+    std::env::var_os(key)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use temp_env::with_vars;
 
     fn test_paths() -> KildPaths {
         KildPaths::from_dir(PathBuf::from("/home/user/.kild"))
@@ -189,10 +341,117 @@ mod tests {
     #[test]
     fn test_resolve_returns_ok_when_home_set() {
         // HOME is set in CI and dev environments
-        let result = KildPaths::resolve();
-        assert!(result.is_ok());
-        let paths = result.unwrap();
-        assert!(paths.kild_dir().to_string_lossy().contains(".kild"));
+        with_vars(
+            [("KILD_HOME", None::<&str>), ("XDG_STATE_HOME", None)],
+            || {
+                let result = KildPaths::resolve();
+                assert!(result.is_ok());
+                let paths = result.unwrap();
+                assert!(paths.kild_dir().to_string_lossy().contains(".kild"));
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_prefers_kild_home() {
+        with_vars(
+            [
+                ("KILD_HOME", Some("/custom/kild")),
+                ("XDG_STATE_HOME", Some("/xdg/state")),
+            ],
+            || {
+                let paths = KildPaths::resolve().unwrap();
+                assert_eq!(paths.kild_dir(), Path::new("/custom/kild"));
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_uses_xdg_state_home_without_kild_home() {
+        with_vars(
+            [("KILD_HOME", None), ("XDG_STATE_HOME", Some("/xdg/state"))],
+            || {
+                let paths = KildPaths::resolve().unwrap();
+                assert_eq!(paths.kild_dir(), Path::new("/xdg/state/kild"));
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_ignores_empty_kild_home() {
+        with_vars(
+            [
+                ("KILD_HOME", Some("")),
+                ("XDG_STATE_HOME", Some("/xdg/state")),
+            ],
+            || {
+                let paths = KildPaths::resolve().unwrap();
+                assert_eq!(paths.kild_dir(), Path::new("/xdg/state/kild"));
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_ignores_relative_xdg_state_home() {
+        with_vars(
+            [
+                ("KILD_HOME", None),
+                ("XDG_STATE_HOME", Some("relative/state")),
+            ],
+            || {
+                let paths = KildPaths::resolve().unwrap();
+                let home = dirs::home_dir().unwrap();
+                assert_eq!(paths.kild_dir(), home.join(".kild"));
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_home_dot_kild() {
+        with_vars(
+            [("KILD_HOME", None::<&str>), ("XDG_STATE_HOME", None)],
+            || {
+                let paths = KildPaths::resolve().unwrap();
+                let home = dirs::home_dir().unwrap();
+                assert_eq!(paths.kild_dir(), home.join(".kild"));
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_scoped_places_daemon_socket_under_scope() {
+        with_vars([("KILD_HOME", Some("/custom/kild"))], || {
+            let paths = KildPaths::resolve_scoped("ci-job-1").unwrap();
+            assert_eq!(
+                paths.daemon_socket(),
+                PathBuf::from("/custom/kild/scopes/ci-job-1/daemon.sock")
+            );
+            assert_eq!(
+                paths.sessions_dir(),
+                PathBuf::from("/custom/kild/scopes/ci-job-1/sessions")
+            );
+        });
+    }
+
+    #[test]
+    fn test_resolve_scoped_empty_scope_matches_resolve() {
+        with_vars([("KILD_HOME", Some("/custom/kild"))], || {
+            let scoped = KildPaths::resolve_scoped("").unwrap();
+            let unscoped = KildPaths::resolve().unwrap();
+            assert_eq!(scoped.kild_dir(), unscoped.kild_dir());
+            assert_eq!(scoped.daemon_socket(), unscoped.daemon_socket());
+        });
+    }
+
+    #[test]
+    fn test_resolve_scoped_rejects_path_traversal() {
+        for scope in ["..", ".", "a/b", "a\\b"] {
+            let result = KildPaths::resolve_scoped(scope);
+            assert!(
+                matches!(result, Err(PathError::InvalidScope { .. })),
+                "scope {scope:?} should be rejected"
+            );
+        }
     }
 
     #[test]
@@ -257,6 +516,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_logs_dir() {
+        assert_eq!(
+            test_paths().logs_dir(),
+            PathBuf::from("/home/user/.kild/logs")
+        );
+    }
+
+    #[test]
+    fn test_agent_log_file() {
+        assert_eq!(
+            test_paths().agent_log_file("myapp_feature_0"),
+            PathBuf::from("/home/user/.kild/logs/myapp_feature_0.log")
+        );
+        assert_eq!(
+            test_paths().agent_log_file("myapp/feature_0"),
+            PathBuf::from("/home/user/.kild/logs/myapp%2Ffeature_0.log")
+        );
+    }
+
     #[test]
     fn test_daemon_socket() {
         assert_eq!(
@@ -297,6 +576,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_daemon_sessions_file() {
+        assert_eq!(
+            test_paths().daemon_sessions_file(),
+            PathBuf::from("/home/user/.kild/sessions/.daemon/sessions.json")
+        );
+    }
+
     #[test]
     fn test_projects_file() {
         assert_eq!(
@@ -305,6 +592,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_events_log() {
+        assert_eq!(
+            test_paths().events_log(),
+            PathBuf::from("/home/user/.kild/events.jsonl")
+        );
+    }
+
     #[test]
     fn test_user_config() {
         assert_eq!(
@@ -378,10 +673,10 @@ mod tests {
     }
 
     #[test]
-    fn test_session_dir_sanitizes_slashes() {
+    fn test_session_dir_encodes_slashes() {
         assert_eq!(
             test_paths().session_dir("project/branch"),
-            PathBuf::from("/home/user/.kild/sessions/project_branch")
+            PathBuf::from("/home/user/.kild/sessions/project%2Fbranch")
         );
     }
 
@@ -389,8 +684,17 @@ mod tests {
     fn test_session_dir_multiple_slashes() {
         assert_eq!(
             test_paths().session_dir("a/b/c"),
-            PathBuf::from("/home/user/.kild/sessions/a_b_c")
+            PathBuf::from("/home/user/.kild/sessions/a%2Fb%2Fc")
+        );
+    }
+
+    #[test]
+    fn test_session_dir_slash_and_underscore_do_not_collide() {
+        assert_ne!(
+            test_paths().session_dir("a/b"),
+            test_paths().session_dir("a_b")
         );
+        assert_ne!(test_paths().pid_file("a/b"), test_paths().pid_file("a-b"));
     }
 
     #[test]
@@ -402,10 +706,10 @@ mod tests {
     }
 
     #[test]
-    fn test_session_file_sanitizes_slashes() {
+    fn test_session_file_encodes_slashes() {
         assert_eq!(
             test_paths().session_file("project/branch"),
-            PathBuf::from("/home/user/.kild/sessions/project_branch/kild.json")
+            PathBuf::from("/home/user/.kild/sessions/project%2Fbranch/kild.json")
         );
     }
 
@@ -418,10 +722,10 @@ mod tests {
     }
 
     #[test]
-    fn test_session_status_file_sanitizes_slashes() {
+    fn test_session_status_file_encodes_slashes() {
         assert_eq!(
             test_paths().session_status_file("project/branch"),
-            PathBuf::from("/home/user/.kild/sessions/project_branch/status")
+            PathBuf::from("/home/user/.kild/sessions/project%2Fbranch/status")
         );
     }
 
@@ -434,10 +738,10 @@ mod tests {
     }
 
     #[test]
-    fn test_session_pr_file_sanitizes_slashes() {
+    fn test_session_pr_file_encodes_slashes() {
         assert_eq!(
             test_paths().session_pr_file("project/branch"),
-            PathBuf::from("/home/user/.kild/sessions/project_branch/pr")
+            PathBuf::from("/home/user/.kild/sessions/project%2Fbranch/pr")
         );
     }
 
@@ -450,10 +754,10 @@ mod tests {
     }
 
     #[test]
-    fn test_pid_file_sanitizes_slashes() {
+    fn test_pid_file_encodes_slashes() {
         assert_eq!(
             test_paths().pid_file("project/branch"),
-            PathBuf::from("/home/user/.kild/pids/project-branch.pid")
+            PathBuf::from("/home/user/.kild/pids/project%2Fbranch.pid")
         );
     }
 
@@ -461,7 +765,7 @@ mod tests {
     fn test_pid_file_multiple_slashes() {
         assert_eq!(
             test_paths().pid_file("a//b///c"),
-            PathBuf::from("/home/user/.kild/pids/a--b---c.pid")
+            PathBuf::from("/home/user/.kild/pids/a%2F%2Fb%2F%2F%2Fc.pid")
         );
     }
 
@@ -469,7 +773,7 @@ mod tests {
     fn test_pid_file_leading_trailing_slashes() {
         assert_eq!(
             test_paths().pid_file("/branch/"),
-            PathBuf::from("/home/user/.kild/pids/-branch-.pid")
+            PathBuf::from("/home/user/.kild/pids/%2Fbranch%2F.pid")
         );
     }
 
@@ -481,6 +785,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_session_ids_missing_sessions_dir_is_empty() {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths = KildPaths::from_dir(tmp.path().join("kild"));
+        assert!(paths.session_ids().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_session_ids_lists_dirs_with_kild_json() {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths = KildPaths::from_dir(tmp.path().to_path_buf());
+
+        for id in ["proj/beta", "proj/alpha"] {
+            std::fs::create_dir_all(paths.session_dir(id)).unwrap();
+            std::fs::write(paths.session_file(id), "{}").unwrap();
+        }
+        // Directory without kild.json is skipped
+        std::fs::create_dir_all(paths.session_dir("proj/empty")).unwrap();
+        // Stray file in sessions/ is skipped
+        std::fs::write(paths.sessions_dir().join("stray.json"), "{}").unwrap();
+
+        assert_eq!(
+            paths.session_ids().unwrap(),
+            vec!["proj%2Falpha".to_string(), "proj%2Fbeta".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_encode_session_id_escapes_percent_and_slash() {
+        assert_eq!(KildPaths::encode_session_id("a/b"), "a%2Fb");
+        assert_eq!(KildPaths::encode_session_id("a_b"), "a_b");
+        assert_eq!(KildPaths::encode_session_id("50%/x"), "50%25%2Fx");
+    }
+
+    #[test]
+    fn test_decode_session_id_reverses_encode() {
+        for id in [
+            "plain",
+            "a/b",
+            "a_b",
+            "a%2Fb",
+            "%",
+            "/lead/trail/",
+            "",
+            "ü/ñ",
+        ] {
+            let encoded = KildPaths::encode_session_id(id);
+            assert_eq!(
+                KildPaths::decode_session_id(&encoded).as_deref(),
+                Some(id),
+                "round-trip failed for {id:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_session_id_rejects_ambiguous_input() {
+        assert_eq!(KildPaths::decode_session_id("a/b"), None);
+        assert_eq!(KildPaths::decode_session_id("50%"), None);
+        assert_eq!(KildPaths::decode_session_id("a%2fb"), None);
+        assert_eq!(KildPaths::decode_session_id("a%20b"), None);
+    }
+
     #[test]
     fn test_path_error_message() {
         let err = PathError::HomeNotFound;
@@ -5,10 +5,14 @@ use image_compare::Algorithm;
 use tracing::{debug, info};
 
 use super::errors::DiffError;
-use super::types::{DiffRequest, DiffResult};
+use super::types::{DiffRequest, DiffResult, Rect};
 
 /// Compare two images and calculate their similarity using SSIM (Structural Similarity Index)
 ///
+/// Pixels inside `ignore_regions`, or whose channels all differ by at most
+/// `tolerance`, are treated as identical before scoring, so they affect
+/// neither the similarity nor the changed-pixel count.
+///
 /// # Errors
 ///
 /// Returns [`DiffError::ImageLoadFailed`] if either image cannot be loaded (file not found,
@@ -49,9 +53,17 @@ pub fn compare_images(request: &DiffRequest) -> Result<DiffResult, DiffError> {
         });
     }
 
+    let rgba1 = img1.to_rgba8();
+    let (rgba2, changed_pixels) = mask_equivalent_pixels(
+        &rgba1,
+        img2.to_rgba8(),
+        request.tolerance,
+        &request.ignore_regions,
+    );
+
     // Convert to grayscale for SSIM comparison
-    let gray1 = img1.to_luma8();
-    let gray2 = img2.to_luma8();
+    let gray1 = image::imageops::grayscale(&rgba1);
+    let gray2 = image::imageops::grayscale(&rgba2);
 
     // Calculate SSIM (Structural Similarity Index)
     let result = image_compare::gray_similarity_structure(&Algorithm::MSSIMSimple, &gray1, &gray2)
@@ -61,7 +73,7 @@ pub fn compare_images(request: &DiffRequest) -> Result<DiffResult, DiffError> {
 
     // Generate visual diff image if output path requested
     let diff_output_path = if let Some(ref output_path) = request.diff_output_path {
-        save_diff_image(&img1, &img2, output_path)?;
+        save_diff_image(&rgba1, &rgba2, output_path)?;
         Some(output_path.display().to_string())
     } else {
         None
@@ -74,22 +86,52 @@ pub fn compare_images(request: &DiffRequest) -> Result<DiffResult, DiffError> {
         width2,
         height2,
         request.threshold,
+        changed_pixels,
         diff_output_path,
     );
 
     info!(
         event = "core.diff.compare_completed",
         similarity = similarity,
+        changed_pixels = changed_pixels,
         is_similar = diff_result.is_similar()
     );
 
     Ok(diff_result)
 }
 
+/// Copy `img1`'s pixels into `img2` wherever they should count as equal
+///
+/// Returns the masked second image and the number of pixels that still differ.
+fn mask_equivalent_pixels(
+    img1: &image::RgbaImage,
+    mut img2: image::RgbaImage,
+    tolerance: u8,
+    ignore_regions: &[Rect],
+) -> (image::RgbaImage, u64) {
+    let mut changed = 0;
+    for (x, y, p2) in img2.enumerate_pixels_mut() {
+        let p1 = img1.get_pixel(x, y);
+        if *p1 == *p2 {
+            continue;
+        }
+        let within_tolerance =
+            p1.0.iter()
+                .zip(p2.0)
+                .all(|(a, b)| a.abs_diff(b) <= tolerance);
+        if within_tolerance || ignore_regions.iter().any(|r| r.contains(x, y)) {
+            *p2 = *p1;
+        } else {
+            changed += 1;
+        }
+    }
+    (img2, changed)
+}
+
 /// Compute per-pixel absolute differences between two images and save as PNG
 fn save_diff_image(
-    img1: &image::DynamicImage,
-    img2: &image::DynamicImage,
+    img1_rgba: &image::RgbaImage,
+    img2_rgba: &image::RgbaImage,
     output_path: &Path,
 ) -> Result<(), DiffError> {
     info!(
@@ -97,9 +139,7 @@ fn save_diff_image(
         path = %output_path.display()
     );
 
-    let img1_rgba = img1.to_rgba8();
-    let img2_rgba = img2.to_rgba8();
-    let (width, height) = img1.dimensions();
+    let (width, height) = img1_rgba.dimensions();
 
     let mut diff_img = image::RgbImage::new(width, height);
     for (x, y, p1) in img1_rgba.enumerate_pixels() {
@@ -241,6 +281,97 @@ mod tests {
         let _ = std::fs::remove_dir_all(&diff_dir);
     }
 
+    /// Create a 16x16 test PNG: mid-gray everywhere, with `patch` drawn over
+    /// the 4x4 block at (8, 8). Big enough for the SSIM window.
+    fn create_patched_image(name: &str, base: u8, patch: u8) -> PathBuf {
+        let img = image::RgbImage::from_fn(16, 16, |x, y| {
+            if (8..12).contains(&x) && (8..12).contains(&y) {
+                image::Rgb([patch, patch, patch])
+            } else {
+                image::Rgb([base, base, base])
+            }
+        });
+        let dir = std::env::temp_dir().join("kild_peek_test_diff");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join(format!("{}_{}.png", name, std::process::id()));
+        img.save(&path).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_compare_counts_changed_pixels() {
+        let img1 = create_patched_image("changed_a", 128, 128);
+        let img2 = create_patched_image("changed_b", 128, 255);
+
+        let result = compare_images(&DiffRequest::new(&img1, &img2)).unwrap();
+        assert_eq!(result.changed_pixels(), 16);
+        assert!(result.similarity() < 1.0);
+
+        let _ = std::fs::remove_file(&img1);
+        let _ = std::fs::remove_file(&img2);
+    }
+
+    #[test]
+    fn test_compare_ignores_changes_inside_ignored_region() {
+        let img1 = create_patched_image("ignored_a", 128, 0);
+        let img2 = create_patched_image("ignored_b", 128, 255);
+
+        let request = DiffRequest::new(&img1, &img2).with_ignore_region(Rect::new(8, 8, 4, 4));
+        let result = compare_images(&request).unwrap();
+
+        assert_eq!(result.changed_pixels(), 0);
+        assert!((result.similarity() - 1.0).abs() < f64::EPSILON);
+        assert!(result.is_similar());
+
+        let _ = std::fs::remove_file(&img1);
+        let _ = std::fs::remove_file(&img2);
+    }
+
+    #[test]
+    fn test_compare_ignored_region_must_cover_change() {
+        let img1 = create_patched_image("partial_a", 128, 0);
+        let img2 = create_patched_image("partial_b", 128, 255);
+
+        // Only the left half of the patch is ignored.
+        let request = DiffRequest::new(&img1, &img2).with_ignore_region(Rect::new(8, 8, 2, 4));
+        let result = compare_images(&request).unwrap();
+        assert_eq!(result.changed_pixels(), 8);
+
+        let _ = std::fs::remove_file(&img1);
+        let _ = std::fs::remove_file(&img2);
+    }
+
+    #[test]
+    fn test_compare_sub_tolerance_noise_is_equal() {
+        // Whole image shifted by 3 per channel, like antialiasing jitter.
+        let img1 = create_patched_image("noise_a", 128, 200);
+        let img2 = create_patched_image("noise_b", 131, 197);
+
+        let without = compare_images(&DiffRequest::new(&img1, &img2)).unwrap();
+        assert_eq!(without.changed_pixels(), 256);
+
+        let request = DiffRequest::new(&img1, &img2).with_tolerance(3);
+        let result = compare_images(&request).unwrap();
+        assert_eq!(result.changed_pixels(), 0);
+        assert!((result.similarity() - 1.0).abs() < f64::EPSILON);
+
+        let _ = std::fs::remove_file(&img1);
+        let _ = std::fs::remove_file(&img2);
+    }
+
+    #[test]
+    fn test_compare_above_tolerance_still_differs() {
+        let img1 = create_patched_image("over_a", 128, 128);
+        let img2 = create_patched_image("over_b", 128, 140);
+
+        let request = DiffRequest::new(&img1, &img2).with_tolerance(3);
+        let result = compare_images(&request).unwrap();
+        assert_eq!(result.changed_pixels(), 16);
+
+        let _ = std::fs::remove_file(&img1);
+        let _ = std::fs::remove_file(&img2);
+    }
+
     #[test]
     fn test_diff_output_creates_nested_parent_directories() {
         let black_pixels = [[0, 0, 0], [0, 0, 0], [0, 0, 0], [0, 0, 0]];
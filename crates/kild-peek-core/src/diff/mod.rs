@@ -4,4 +4,4 @@ mod types;
 
 pub use errors::DiffError;
 pub use handler::compare_images;
-pub use types::{DiffRequest, DiffResult};
+pub use types::{DiffRequest, DiffResult, Rect};
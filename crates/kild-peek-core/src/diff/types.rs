@@ -1,6 +1,34 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+/// Rectangular pixel region (top-left origin)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Whether the pixel at (x, y) falls inside this region
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        x >= self.x
+            && y >= self.y
+            && u64::from(x) < u64::from(self.x) + u64::from(self.width)
+            && u64::from(y) < u64::from(self.y) + u64::from(self.height)
+    }
+}
+
 /// Request to compare two images
 #[derive(Debug, Clone)]
 pub struct DiffRequest {
@@ -12,6 +40,10 @@ pub struct DiffRequest {
     pub threshold: f64,
     /// Optional path to save a visual diff image highlighting differences
     pub diff_output_path: Option<PathBuf>,
+    /// Per-channel difference at or below which pixels count as equal (default 0)
+    pub tolerance: u8,
+    /// Regions excluded from comparison (e.g. a clock widget)
+    pub ignore_regions: Vec<Rect>,
 }
 
 impl DiffRequest {
@@ -22,6 +54,8 @@ impl DiffRequest {
             image2_path: image2.into(),
             threshold: 0.95,
             diff_output_path: None,
+            tolerance: 0,
+            ignore_regions: Vec::new(),
         }
     }
 
@@ -36,6 +70,18 @@ impl DiffRequest {
         self.diff_output_path = Some(path.into());
         self
     }
+
+    /// Treat per-channel differences up to `tolerance` as equal (absorbs antialiasing noise)
+    pub fn with_tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Exclude a region from comparison
+    pub fn with_ignore_region(mut self, region: Rect) -> Self {
+        self.ignore_regions.push(region);
+        self
+    }
 }
 
 /// Result of comparing two images
@@ -48,6 +94,7 @@ pub struct DiffResult {
     width2: u32,
     height2: u32,
     threshold: f64,
+    changed_pixels: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
     diff_output_path: Option<String>,
 }
@@ -62,6 +109,7 @@ impl DiffResult {
         width2: u32,
         height2: u32,
         threshold: f64,
+        changed_pixels: u64,
         diff_output_path: Option<String>,
     ) -> Self {
         Self {
@@ -72,6 +120,7 @@ impl DiffResult {
             width2,
             height2,
             threshold,
+            changed_pixels,
             diff_output_path,
         }
     }
@@ -111,6 +160,11 @@ impl DiffResult {
         self.threshold
     }
 
+    /// Pixels that differ beyond the tolerance, outside ignored regions
+    pub fn changed_pixels(&self) -> u64 {
+        self.changed_pixels
+    }
+
     /// Get similarity as a percentage string
     pub fn similarity_percent(&self) -> String {
         format!("{:.1}%", self.similarity * 100.0)
@@ -154,29 +208,29 @@ mod tests {
 
     #[test]
     fn test_diff_result_similarity_percent() {
-        let result = DiffResult::new(0.956, 100, 100, 100, 100, 0.95, None);
+        let result = DiffResult::new(0.956, 100, 100, 100, 100, 0.95, 0, None);
         assert_eq!(result.similarity_percent(), "95.6%");
     }
 
     #[test]
     fn test_diff_result_is_similar_computed() {
         // Similarity >= threshold => is_similar = true
-        let result_pass = DiffResult::new(0.96, 100, 100, 100, 100, 0.95, None);
+        let result_pass = DiffResult::new(0.96, 100, 100, 100, 100, 0.95, 0, None);
         assert!(result_pass.is_similar());
         assert!((result_pass.similarity() - 0.96).abs() < f64::EPSILON);
 
         // Similarity exactly at threshold => is_similar = true
-        let result_exact = DiffResult::new(0.95, 100, 100, 100, 100, 0.95, None);
+        let result_exact = DiffResult::new(0.95, 100, 100, 100, 100, 0.95, 0, None);
         assert!(result_exact.is_similar());
 
         // Similarity < threshold => is_similar = false
-        let result_fail = DiffResult::new(0.94, 100, 100, 100, 100, 0.95, None);
+        let result_fail = DiffResult::new(0.94, 100, 100, 100, 100, 0.95, 0, None);
         assert!(!result_fail.is_similar());
     }
 
     #[test]
     fn test_diff_result_getters() {
-        let result = DiffResult::new(0.85, 800, 600, 800, 600, 0.80, None);
+        let result = DiffResult::new(0.85, 800, 600, 800, 600, 0.80, 0, None);
         assert_eq!(result.width1(), 800);
         assert_eq!(result.height1(), 600);
         assert_eq!(result.width2(), 800);
@@ -193,6 +247,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_diff_request_tolerance_and_ignore_regions() {
+        let request = DiffRequest::new("/a.png", "/b.png")
+            .with_tolerance(8)
+            .with_ignore_region(Rect::new(0, 0, 10, 10))
+            .with_ignore_region(Rect::new(50, 50, 5, 5));
+        assert_eq!(request.tolerance, 8);
+        assert_eq!(request.ignore_regions.len(), 2);
+
+        let default = DiffRequest::new("/a.png", "/b.png");
+        assert_eq!(default.tolerance, 0);
+        assert!(default.ignore_regions.is_empty());
+    }
+
+    #[test]
+    fn test_rect_contains() {
+        let rect = Rect::new(10, 20, 5, 5);
+        assert!(rect.contains(10, 20));
+        assert!(rect.contains(14, 24));
+        assert!(!rect.contains(15, 24));
+        assert!(!rect.contains(14, 25));
+        assert!(!rect.contains(9, 20));
+        assert!(!Rect::new(0, 0, 0, 0).contains(0, 0));
+    }
+
     #[test]
     fn test_diff_request_default_no_diff_output() {
         let request = DiffRequest::new("/a.png", "/b.png");
@@ -208,6 +287,7 @@ mod tests {
             100,
             100,
             0.80,
+            0,
             Some("/tmp/diff.png".to_string()),
         );
         assert_eq!(result.diff_output_path(), Some("/tmp/diff.png"));
@@ -215,7 +295,7 @@ mod tests {
 
     #[test]
     fn test_diff_result_without_diff_output_path() {
-        let result = DiffResult::new(0.85, 100, 100, 100, 100, 0.80, None);
+        let result = DiffResult::new(0.85, 100, 100, 100, 100, 0.80, 0, None);
         assert_eq!(result.diff_output_path(), None);
     }
 
@@ -228,6 +308,7 @@ mod tests {
             100,
             100,
             0.80,
+            0,
             Some("/tmp/diff.png".to_string()),
         );
         let json = serde_json::to_string(&result).unwrap();
@@ -237,7 +318,7 @@ mod tests {
 
     #[test]
     fn test_diff_result_json_omits_diff_output_path_when_none() {
-        let result = DiffResult::new(0.85, 100, 100, 100, 100, 0.80, None);
+        let result = DiffResult::new(0.85, 100, 100, 100, 100, 0.80, 0, None);
         let json = serde_json::to_string(&result).unwrap();
         assert!(!json.contains("diff_output_path"));
     }
@@ -1,4 +1,5 @@
 use std::ffi::c_void;
+use std::hash::{Hash, Hasher};
 use std::ptr;
 
 use accessibility_sys::{
@@ -9,11 +10,13 @@ use accessibility_sys::{
     kAXValueAttribute, kAXValueTypeCGPoint, kAXValueTypeCGSize, kAXWindowsAttribute,
 };
 use core_foundation::array::CFArray;
-use core_foundation::base::{CFType, TCFType};
+use core_foundation::base::{CFHash, CFType, TCFType};
 use core_foundation::boolean::CFBoolean;
 use core_foundation::string::CFString;
 use tracing::debug;
 
+use super::tree::ElementProvider;
+
 /// Maximum depth for recursive element tree traversal
 const MAX_TRAVERSAL_DEPTH: usize = 20;
 
@@ -131,6 +134,92 @@ pub fn query_elements(pid: i32) -> Result<Vec<RawElement>, String> {
     Ok(elements)
 }
 
+/// An AX element compared with `CFEqual` and hashed with `CFHash`
+///
+/// The AX API hands out a fresh ref each time a child list is copied, so
+/// pointer identity can't detect a node seen before; CF equality can.
+#[derive(Clone)]
+pub(crate) struct AxNode(CFType);
+
+impl PartialEq for AxNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for AxNode {}
+
+impl Hash for AxNode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // SAFETY: self.0 is a valid, retained CFTypeRef.
+        unsafe { CFHash(self.0.as_CFTypeRef()) }.hash(state);
+    }
+}
+
+impl AxNode {
+    fn as_element(&self) -> AXUIElementRef {
+        self.0.as_CFTypeRef() as AXUIElementRef
+    }
+}
+
+/// Live accessibility tree for one application, walked by [`super::tree::build_tree`]
+pub(crate) struct AxTree {
+    app_element: AXUIElementRef,
+}
+
+impl AxTree {
+    pub(crate) fn new(pid: i32) -> Result<Self, String> {
+        // SAFETY: See query_elements.
+        let app_element = unsafe { AXUIElementCreateApplication(pid) };
+        if app_element.is_null() {
+            return Err(format!("Failed to create AX element for PID {}", pid));
+        }
+        // SAFETY: app_element is a valid AXUIElementRef we just created.
+        unsafe {
+            AXUIElementSetMessagingTimeout(app_element, AX_MESSAGING_TIMEOUT);
+        }
+        Ok(Self { app_element })
+    }
+
+    /// The application's top-level windows
+    pub(crate) fn windows(&self) -> Vec<AxNode> {
+        retain_refs(get_children_refs(self.app_element, kAXWindowsAttribute))
+    }
+}
+
+impl Drop for AxTree {
+    fn drop(&mut self) {
+        // SAFETY: app_element came from AXUIElementCreateApplication (Create Rule)
+        // and is released exactly once, here.
+        unsafe {
+            core_foundation::base::CFRelease(self.app_element as *mut c_void);
+        }
+    }
+}
+
+impl ElementProvider for AxTree {
+    type Node = AxNode;
+
+    fn children(&self, node: &AxNode) -> Vec<AxNode> {
+        retain_refs(get_children_refs(node.as_element(), kAXChildrenAttribute))
+    }
+
+    fn read(&self, node: &AxNode, depth: usize) -> Option<RawElement> {
+        read_element_properties(node.as_element(), depth)
+    }
+}
+
+/// Retain each borrowed ref so it outlives the CFArray it came from
+fn retain_refs((refs, _array): (Vec<AXUIElementRef>, Option<CFArray<CFType>>)) -> Vec<AxNode> {
+    refs.into_iter()
+        // SAFETY: each ref is valid while _array is alive; wrap_under_get_rule
+        // takes our own +1 retain, released when the AxNode drops.
+        .map(|r| {
+            AxNode(unsafe { TCFType::wrap_under_get_rule(r as core_foundation::base::CFTypeRef) })
+        })
+        .collect()
+}
+
 /// Recursively collect elements from the AX tree
 fn collect_elements(element: AXUIElementRef, out: &mut Vec<RawElement>, depth: usize) {
     if depth > MAX_TRAVERSAL_DEPTH {
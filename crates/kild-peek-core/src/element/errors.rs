@@ -52,6 +52,20 @@ pub enum ElementError {
 
     #[error("Element with text '{text}' still exists after {timeout_ms}ms")]
     WaitTimeoutElementStillExists { text: String, timeout_ms: u64 },
+
+    #[error("Element with role '{role}' and text '{text}' not found after {timeout_ms}ms")]
+    WaitTimeoutRoleNotFound {
+        role: String,
+        text: String,
+        timeout_ms: u64,
+    },
+
+    #[error("Element with role '{role}' and text '{text}' still exists after {timeout_ms}ms")]
+    WaitTimeoutRoleStillExists {
+        role: String,
+        text: String,
+        timeout_ms: u64,
+    },
 }
 
 impl PeekError for ElementError {
@@ -72,8 +86,10 @@ impl PeekError for ElementError {
                 "ELEMENT_WAIT_TIMEOUT_BY_APP_AND_TITLE"
             }
             ElementError::InvalidRegex { .. } => "ELEMENT_INVALID_REGEX",
-            ElementError::WaitTimeoutElementNotFound { .. } => "ELEMENT_WAIT_TIMEOUT_NOT_FOUND",
-            ElementError::WaitTimeoutElementStillExists { .. } => {
+            ElementError::WaitTimeoutElementNotFound { .. }
+            | ElementError::WaitTimeoutRoleNotFound { .. } => "ELEMENT_WAIT_TIMEOUT_NOT_FOUND",
+            ElementError::WaitTimeoutElementStillExists { .. }
+            | ElementError::WaitTimeoutRoleStillExists { .. } => {
                 "ELEMENT_WAIT_TIMEOUT_STILL_EXISTS"
             }
         }
@@ -96,6 +112,8 @@ impl PeekError for ElementError {
                 | ElementError::InvalidRegex { .. }
                 | ElementError::WaitTimeoutElementNotFound { .. }
                 | ElementError::WaitTimeoutElementStillExists { .. }
+                | ElementError::WaitTimeoutRoleNotFound { .. }
+                | ElementError::WaitTimeoutRoleStillExists { .. }
         )
     }
 }
@@ -307,4 +325,19 @@ mod tests {
         assert_eq!(error.error_code(), "ELEMENT_WAIT_TIMEOUT_STILL_EXISTS");
         assert!(error.is_user_error());
     }
+
+    #[test]
+    fn test_wait_timeout_role_not_found_error() {
+        let error = ElementError::WaitTimeoutRoleNotFound {
+            role: "button".to_string(),
+            text: "Save".to_string(),
+            timeout_ms: 10000,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Element with role 'button' and text 'Save' not found after 10000ms"
+        );
+        assert_eq!(error.error_code(), "ELEMENT_WAIT_TIMEOUT_NOT_FOUND");
+        assert!(error.is_user_error());
+    }
 }
@@ -5,8 +5,10 @@ use tracing::{info, warn};
 
 use super::accessibility;
 use super::errors::ElementError;
+use super::tree::{self, ElementProvider};
 use super::types::{
-    ElementInfo, ElementsRequest, ElementsResult, FindMode, FindRequest, WaitRequest, WaitResult,
+    ElementInfo, ElementsRequest, ElementsResult, FindMode, FindRequest, TreeRequest, TreeResult,
+    WaitRequest, WaitResult,
 };
 use crate::interact::InteractionTarget;
 use crate::window::{
@@ -131,6 +133,66 @@ pub fn list_elements(request: &ElementsRequest) -> Result<ElementsResult, Elemen
     Ok(ElementsResult::new(elements, window.title().to_string()))
 }
 
+/// Export the accessibility hierarchy of a window as nested nodes
+///
+/// Walks at most `request.max_depth()` levels below the window. If the app's
+/// AX windows include one titled like the target, only that window is
+/// exported; otherwise all of the app's windows are.
+pub fn element_tree(request: &TreeRequest) -> Result<TreeResult, ElementError> {
+    info!(
+        event = "peek.core.element.tree_started",
+        target = ?request.target(),
+        max_depth = request.max_depth()
+    );
+
+    check_accessibility_permission()?;
+
+    let window = find_window_by_target(request.target(), request.timeout_ms())?;
+
+    if window.is_minimized() {
+        return Err(ElementError::WindowMinimized {
+            title: window.title().to_string(),
+        });
+    }
+
+    let pid = window.pid().ok_or(ElementError::NoPidAvailable)?;
+
+    let ax_tree = accessibility::AxTree::new(pid)
+        .map_err(|reason| ElementError::AccessibilityQueryFailed { reason })?;
+
+    let all_windows = ax_tree.windows();
+    let matching: Vec<_> = all_windows
+        .iter()
+        .filter(|w| {
+            ax_tree
+                .read(w, 0)
+                .is_some_and(|raw| raw.title() == Some(window.title()))
+        })
+        .cloned()
+        .collect();
+    let roots = if matching.is_empty() {
+        all_windows
+    } else {
+        matching
+    };
+
+    let nodes = tree::build_tree(
+        &ax_tree,
+        &roots,
+        request.max_depth(),
+        (window.x(), window.y()),
+    );
+    let result = TreeResult::new(nodes, window.title().to_string(), request.max_depth());
+
+    info!(
+        event = "peek.core.element.tree_completed",
+        count = result.count(),
+        window = window.title()
+    );
+
+    Ok(result)
+}
+
 /// Find a specific element by text content
 pub fn find_element(request: &FindRequest) -> Result<ElementInfo, ElementError> {
     info!(
@@ -208,53 +270,67 @@ pub fn find_element(request: &FindRequest) -> Result<ElementInfo, ElementError>
 
 const ELEMENT_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
-/// Wait for an element with the given text to appear or disappear
+/// Wait for an element matching the request to appear or disappear
 ///
 /// Polls the element tree every 100ms until:
-/// - `until_gone = false`: element matching `request.text()` is found → returns `WaitResult::appeared`
-/// - `until_gone = true`: no element matching `request.text()` is found → returns `WaitResult::gone`
+/// - `until_gone = false`: an element matching the request is found → returns `WaitResult::appeared`
+/// - `until_gone = true`: no element matching the request is found → returns `WaitResult::gone`
+///
+/// An element matches when it contains `request.text()` and, if set, has
+/// `request.role()`.
 ///
 /// If the window disappears while waiting for an element to be gone, treats
 /// it as success (element is gone because window closed).
 ///
 /// # Errors
 ///
-/// Returns `WaitTimeoutElementNotFound` or `WaitTimeoutElementStillExists` on timeout.
+/// Returns `WaitTimeoutElementNotFound` or `WaitTimeoutElementStillExists` on timeout
+/// (`WaitTimeoutRoleNotFound` / `WaitTimeoutRoleStillExists` when a role is set).
 /// Returns `AccessibilityPermissionDenied` if permission is not granted.
 pub fn wait_for_element(request: &WaitRequest) -> Result<WaitResult, ElementError> {
     info!(
         event = "peek.core.element.wait_started",
         text = request.text(),
+        role = ?request.role(),
         until_gone = request.until_gone(),
         timeout_ms = request.timeout_ms()
     );
 
     check_accessibility_permission()?;
 
+    poll_for_element(request, ELEMENT_POLL_INTERVAL, || {
+        let result = list_elements(&ElementsRequest::new(request.target().clone()))?;
+        Ok(result
+            .elements()
+            .iter()
+            .find(|e| request.matches(e))
+            .cloned())
+    })
+}
+
+/// Polling loop behind [`wait_for_element`], with the element lookup injected
+///
+/// `find` returns the first matching element, if any. Window-not-found errors
+/// mean "keep polling" when waiting for an element to appear and "gone" when
+/// waiting for it to disappear; any other error aborts the wait.
+fn poll_for_element<F>(
+    request: &WaitRequest,
+    interval: Duration,
+    mut find: F,
+) -> Result<WaitResult, ElementError>
+where
+    F: FnMut() -> Result<Option<ElementInfo>, ElementError>,
+{
     let start = Instant::now();
     let timeout = Duration::from_millis(request.timeout_ms());
 
     loop {
         if start.elapsed() >= timeout {
-            let timeout_error = if request.until_gone() {
-                ElementError::WaitTimeoutElementStillExists {
-                    text: request.text().to_string(),
-                    timeout_ms: request.timeout_ms(),
-                }
-            } else {
-                ElementError::WaitTimeoutElementNotFound {
-                    text: request.text().to_string(),
-                    timeout_ms: request.timeout_ms(),
-                }
-            };
-            return Err(timeout_error);
+            return Err(timeout_error(request));
         }
 
-        let found = match list_elements(&ElementsRequest::new(request.target().clone())) {
-            Ok(result) => result
-                .elements()
-                .iter()
-                .any(|e| e.matches_text(request.text())),
+        let found = match find() {
+            Ok(element) => element.is_some(),
             Err(ElementError::WindowNotFound { .. })
             | Err(ElementError::WindowNotFoundByApp { .. })
             | Err(ElementError::WaitTimeoutByTitle { .. })
@@ -265,7 +341,7 @@ pub fn wait_for_element(request: &WaitRequest) -> Result<WaitResult, ElementErro
                     false
                 } else {
                     // Window not yet available — keep polling
-                    thread::sleep(ELEMENT_POLL_INTERVAL);
+                    thread::sleep(interval);
                     continue;
                 }
             }
@@ -281,9 +357,8 @@ pub fn wait_for_element(request: &WaitRequest) -> Result<WaitResult, ElementErro
         };
 
         let elapsed_ms = start.elapsed().as_millis() as u64;
-        let condition_met = (!request.until_gone() && found) || (request.until_gone() && !found);
 
-        if condition_met {
+        if found != request.until_gone() {
             let result = if request.until_gone() {
                 WaitResult::gone(request.text(), elapsed_ms)
             } else {
@@ -297,7 +372,26 @@ pub fn wait_for_element(request: &WaitRequest) -> Result<WaitResult, ElementErro
             return Ok(result);
         }
 
-        thread::sleep(ELEMENT_POLL_INTERVAL);
+        thread::sleep(interval);
+    }
+}
+
+fn timeout_error(request: &WaitRequest) -> ElementError {
+    let text = request.text().to_string();
+    let timeout_ms = request.timeout_ms();
+    match (request.role(), request.until_gone()) {
+        (None, false) => ElementError::WaitTimeoutElementNotFound { text, timeout_ms },
+        (None, true) => ElementError::WaitTimeoutElementStillExists { text, timeout_ms },
+        (Some(role), false) => ElementError::WaitTimeoutRoleNotFound {
+            role: role.to_string(),
+            text,
+            timeout_ms,
+        },
+        (Some(role), true) => ElementError::WaitTimeoutRoleStillExists {
+            role: role.to_string(),
+            text,
+            timeout_ms,
+        },
     }
 }
 
@@ -596,6 +690,111 @@ mod tests {
         }
     }
 
+    fn wait_request(timeout_ms: u64) -> WaitRequest {
+        WaitRequest::new(
+            InteractionTarget::App {
+                app: "TextEdit".to_string(),
+            },
+            "Save",
+            timeout_ms,
+        )
+        .with_role("button")
+    }
+
+    fn save_button() -> ElementInfo {
+        ElementInfo::new(
+            "AXButton".to_string(),
+            Some("Save".to_string()),
+            None,
+            None,
+            10,
+            10,
+            80,
+            30,
+            true,
+            2,
+        )
+    }
+
+    const TEST_INTERVAL: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn test_poll_for_element_appears_after_misses() {
+        let mut calls = 0;
+        let result = poll_for_element(&wait_request(5000), TEST_INTERVAL, || {
+            calls += 1;
+            Ok((calls > 3).then(save_button))
+        })
+        .unwrap();
+
+        assert_eq!(calls, 4);
+        assert_eq!(result.text(), "Save");
+        assert!(!result.until_gone());
+    }
+
+    #[test]
+    fn test_poll_for_element_keeps_polling_until_window_exists() {
+        let mut calls = 0;
+        let result = poll_for_element(&wait_request(5000), TEST_INTERVAL, || {
+            calls += 1;
+            match calls {
+                1 | 2 => Err(ElementError::WindowNotFoundByApp {
+                    app: "TextEdit".to_string(),
+                }),
+                3 => Ok(None),
+                _ => Ok(Some(save_button())),
+            }
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn test_poll_for_element_times_out() {
+        let mut calls = 0;
+        let result = poll_for_element(&wait_request(20), TEST_INTERVAL, || {
+            calls += 1;
+            Ok(None)
+        });
+
+        match result {
+            Err(ElementError::WaitTimeoutRoleNotFound {
+                role,
+                text,
+                timeout_ms,
+            }) => {
+                assert_eq!(role, "button");
+                assert_eq!(text, "Save");
+                assert_eq!(timeout_ms, 20);
+            }
+            other => panic!("Expected WaitTimeoutRoleNotFound, got {:?}", other),
+        }
+        assert!(calls >= 1);
+    }
+
+    #[test]
+    fn test_poll_for_element_until_gone() {
+        let mut calls = 0;
+        let request = wait_request(5000).with_until_gone();
+        let result = poll_for_element(&request, TEST_INTERVAL, || {
+            calls += 1;
+            Ok((calls < 3).then(save_button))
+        })
+        .unwrap();
+
+        assert_eq!(calls, 3);
+        assert!(result.until_gone());
+    }
+
+    #[test]
+    fn test_poll_for_element_propagates_other_errors() {
+        let result = poll_for_element(&wait_request(5000), TEST_INTERVAL, || {
+            Err(ElementError::NoPidAvailable)
+        });
+        assert!(matches!(result, Err(ElementError::NoPidAvailable)));
+    }
+
     #[test]
     #[ignore]
     fn test_wait_for_element_until_gone_window_not_found_is_success() {
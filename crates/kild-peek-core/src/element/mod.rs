@@ -1,10 +1,12 @@
 pub(crate) mod accessibility;
 mod errors;
 pub(crate) mod handler;
+mod tree;
 mod types;
 
 pub use errors::ElementError;
-pub use handler::{find_element, list_elements, wait_for_element};
+pub use handler::{element_tree, find_element, list_elements, wait_for_element};
 pub use types::{
-    ElementInfo, ElementsRequest, ElementsResult, FindMode, FindRequest, WaitRequest, WaitResult,
+    DEFAULT_TREE_DEPTH, ElementInfo, ElementNode, ElementsRequest, ElementsResult, FindMode,
+    FindRequest, Frame, TreeRequest, TreeResult, WaitRequest, WaitResult,
 };
@@ -0,0 +1,230 @@
+//! Nested accessibility tree export.
+//!
+//! The walk is generic over [`ElementProvider`] so the depth limit and cycle
+//! guard can be tested without the Accessibility API.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use tracing::debug;
+
+use super::accessibility::RawElement;
+use super::types::{ElementNode, Frame};
+
+/// Source of accessibility nodes for [`build_tree`]
+pub(crate) trait ElementProvider {
+    /// Handle to one node. Equal handles must refer to the same UI element.
+    type Node: Eq + Hash + Clone;
+
+    fn children(&self, node: &Self::Node) -> Vec<Self::Node>;
+
+    /// Read a node's properties, or `None` if it has no role
+    fn read(&self, node: &Self::Node, depth: usize) -> Option<RawElement>;
+}
+
+/// Walk `roots` into nested nodes, at most `max_depth` levels below the roots
+///
+/// `origin` is the window's screen position; frames are made relative to it.
+/// A node reached a second time (accessibility trees can contain cycles and
+/// shared children) is skipped. Nodes whose properties can't be read are
+/// dropped and their children attached to the nearest readable ancestor.
+pub(crate) fn build_tree<P: ElementProvider>(
+    provider: &P,
+    roots: &[P::Node],
+    max_depth: usize,
+    origin: (i32, i32),
+) -> Vec<ElementNode> {
+    let mut walker = Walker {
+        provider,
+        max_depth,
+        origin,
+        visited: HashSet::new(),
+    };
+    let mut out = Vec::new();
+    for root in roots {
+        walker.walk(root, 0, &mut out);
+    }
+    out
+}
+
+struct Walker<'a, P: ElementProvider> {
+    provider: &'a P,
+    max_depth: usize,
+    origin: (i32, i32),
+    visited: HashSet<P::Node>,
+}
+
+impl<P: ElementProvider> Walker<'_, P> {
+    fn walk(&mut self, node: &P::Node, depth: usize, out: &mut Vec<ElementNode>) {
+        if !self.visited.insert(node.clone()) {
+            debug!(
+                event = "peek.core.element.tree_node_revisited",
+                depth = depth
+            );
+            return;
+        }
+
+        let mut children = Vec::new();
+        if depth < self.max_depth {
+            for child in self.provider.children(node) {
+                self.walk(&child, depth + 1, &mut children);
+            }
+        }
+
+        match self.provider.read(node, depth) {
+            Some(raw) => out.push(to_node(&raw, self.origin, children)),
+            None => out.extend(children),
+        }
+    }
+}
+
+fn to_node(raw: &RawElement, origin: (i32, i32), children: Vec<ElementNode>) -> ElementNode {
+    let frame = raw.position().map(|(abs_x, abs_y)| {
+        let (width, height) = raw.size().unwrap_or((0.0, 0.0));
+        Frame {
+            x: abs_x as i32 - origin.0,
+            y: abs_y as i32 - origin.1,
+            width: width as u32,
+            height: height as u32,
+        }
+    });
+
+    ElementNode::new(
+        raw.role().to_string(),
+        raw.title().map(String::from),
+        raw.value().map(String::from),
+        raw.description().map(String::from),
+        frame,
+        raw.enabled(),
+        children,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use super::*;
+
+    /// In-memory provider: node ids, child lists, and ids whose read fails
+    #[derive(Default)]
+    struct MockProvider {
+        children: HashMap<u32, Vec<u32>>,
+        unreadable: HashSet<u32>,
+        children_calls: RefCell<Vec<u32>>,
+    }
+
+    impl MockProvider {
+        fn with_edges(edges: &[(u32, &[u32])]) -> Self {
+            Self {
+                children: edges.iter().map(|(n, c)| (*n, c.to_vec())).collect(),
+                ..Default::default()
+            }
+        }
+    }
+
+    impl ElementProvider for MockProvider {
+        type Node = u32;
+
+        fn children(&self, node: &u32) -> Vec<u32> {
+            self.children_calls.borrow_mut().push(*node);
+            self.children.get(node).cloned().unwrap_or_default()
+        }
+
+        fn read(&self, node: &u32, depth: usize) -> Option<RawElement> {
+            if self.unreadable.contains(node) {
+                return None;
+            }
+            Some(RawElement::new(
+                format!("AXNode{node}"),
+                Some(format!("n{node}")),
+                None,
+                None,
+                Some((100.0 + f64::from(*node), 200.0)),
+                Some((10.0, 5.0)),
+                true,
+                depth,
+            ))
+        }
+    }
+
+    fn titles(nodes: &[ElementNode]) -> Vec<&str> {
+        nodes.iter().map(|n| n.title().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_build_tree_nests_children() {
+        let provider = MockProvider::with_edges(&[(1, &[2, 3]), (2, &[4])]);
+        let roots = build_tree(&provider, &[1], 10, (0, 0));
+
+        assert_eq!(titles(&roots), ["n1"]);
+        assert_eq!(titles(roots[0].children()), ["n2", "n3"]);
+        assert_eq!(titles(roots[0].children()[0].children()), ["n4"]);
+        assert!(roots[0].children()[1].children().is_empty());
+    }
+
+    #[test]
+    fn test_build_tree_respects_max_depth() {
+        // Chain 1 -> 2 -> 3 -> 4 -> 5
+        let provider = MockProvider::with_edges(&[(1, &[2]), (2, &[3]), (3, &[4]), (4, &[5])]);
+
+        let roots = build_tree(&provider, &[1], 2, (0, 0));
+        assert_eq!(roots[0].node_count(), 3);
+        let deepest = &roots[0].children()[0].children()[0];
+        assert_eq!(deepest.title(), Some("n3"));
+        assert!(deepest.children().is_empty());
+        // Children of the node at max depth are never requested
+        assert!(!provider.children_calls.borrow().contains(&3));
+
+        let window_only = build_tree(&provider, &[1], 0, (0, 0));
+        assert_eq!(window_only[0].node_count(), 1);
+    }
+
+    #[test]
+    fn test_build_tree_guards_against_cycles() {
+        // 1 -> 2 -> 3 -> 1, and 3 points at itself
+        let provider = MockProvider::with_edges(&[(1, &[2]), (2, &[3]), (3, &[1, 3])]);
+        let roots = build_tree(&provider, &[1], 50, (0, 0));
+
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].node_count(), 3);
+        assert!(roots[0].children()[0].children()[0].children().is_empty());
+    }
+
+    #[test]
+    fn test_build_tree_emits_shared_child_once() {
+        // 2 and 3 both list 4 as a child
+        let provider = MockProvider::with_edges(&[(1, &[2, 3]), (2, &[4]), (3, &[4])]);
+        let roots = build_tree(&provider, &[1], 10, (0, 0));
+
+        assert_eq!(roots[0].node_count(), 4);
+        assert_eq!(titles(roots[0].children()[0].children()), ["n4"]);
+        assert!(roots[0].children()[1].children().is_empty());
+    }
+
+    #[test]
+    fn test_build_tree_hoists_children_of_unreadable_nodes() {
+        let mut provider = MockProvider::with_edges(&[(1, &[2]), (2, &[3, 4])]);
+        provider.unreadable.insert(2);
+        let roots = build_tree(&provider, &[1], 10, (0, 0));
+
+        assert_eq!(titles(roots[0].children()), ["n3", "n4"]);
+    }
+
+    #[test]
+    fn test_build_tree_frames_are_window_relative() {
+        let provider = MockProvider::with_edges(&[(1, &[2])]);
+        let roots = build_tree(&provider, &[1], 10, (100, 150));
+
+        assert_eq!(
+            roots[0].children()[0].frame(),
+            Some(Frame {
+                x: 2,
+                y: 50,
+                width: 10,
+                height: 5,
+            })
+        );
+    }
+}
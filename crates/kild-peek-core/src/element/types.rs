@@ -90,6 +90,20 @@ impl ElementInfo {
         check(&self.title) || check(&self.value) || check(&self.description)
     }
 
+    /// Check if the role matches, ignoring case and the `AX` prefix
+    ///
+    /// `"button"`, `"Button"` and `"AXButton"` all match `AXButton`.
+    pub fn matches_role(&self, role: &str) -> bool {
+        let strip = |r: &str| {
+            let lower = r.to_lowercase();
+            match lower.strip_prefix("ax") {
+                Some(rest) if !rest.is_empty() => rest.to_string(),
+                _ => lower,
+            }
+        };
+        strip(&self.role) == strip(role)
+    }
+
     /// Check if any text field matches the given regex pattern.
     /// Case-sensitive by default; use `(?i)` prefix in the pattern for case-insensitive matching.
     pub fn matches_regex(&self, pattern: &regex::Regex) -> bool {
@@ -188,6 +202,7 @@ impl FindRequest {
 pub struct WaitRequest {
     target: InteractionTarget,
     text: String,
+    role: Option<String>,
     until_gone: bool,
     timeout_ms: u64,
 }
@@ -198,6 +213,7 @@ impl WaitRequest {
         Self {
             target,
             text: text.into(),
+            role: None,
             until_gone: false,
             timeout_ms,
         }
@@ -208,6 +224,12 @@ impl WaitRequest {
         self
     }
 
+    /// Only match elements with this role (see [`ElementInfo::matches_role`])
+    pub fn with_role(mut self, role: impl Into<String>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
     pub fn target(&self) -> &InteractionTarget {
         &self.target
     }
@@ -216,6 +238,10 @@ impl WaitRequest {
         &self.text
     }
 
+    pub fn role(&self) -> Option<&str> {
+        self.role.as_deref()
+    }
+
     pub fn until_gone(&self) -> bool {
         self.until_gone
     }
@@ -223,6 +249,15 @@ impl WaitRequest {
     pub fn timeout_ms(&self) -> u64 {
         self.timeout_ms
     }
+
+    /// Check whether an element satisfies this request
+    ///
+    /// An empty `text` matches any element, so a role alone is enough.
+    pub fn matches(&self, element: &ElementInfo) -> bool {
+        let role_ok = self.role.as_deref().is_none_or(|r| element.matches_role(r));
+        let text_ok = self.text.is_empty() || element.matches_text(&self.text);
+        role_ok && text_ok
+    }
 }
 
 /// Result of a wait operation
@@ -294,6 +329,163 @@ impl ElementsResult {
     }
 }
 
+/// Default `--max-depth` for tree export, matching the flat element listing
+pub const DEFAULT_TREE_DEPTH: usize = 20;
+
+/// Request to export a window's accessibility hierarchy
+#[derive(Debug, Clone)]
+pub struct TreeRequest {
+    target: InteractionTarget,
+    max_depth: usize,
+    timeout_ms: Option<u64>,
+}
+
+impl TreeRequest {
+    pub fn new(target: InteractionTarget) -> Self {
+        Self {
+            target,
+            max_depth: DEFAULT_TREE_DEPTH,
+            timeout_ms: None,
+        }
+    }
+
+    /// Limit how many levels below the window are walked (0 = window only)
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn with_wait(mut self, timeout_ms: u64) -> Self {
+        self.timeout_ms = Some(timeout_ms);
+        self
+    }
+
+    pub fn target(&self) -> &InteractionTarget {
+        &self.target
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn timeout_ms(&self) -> Option<u64> {
+        self.timeout_ms
+    }
+}
+
+/// Element bounds, relative to the window's top-left corner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Frame {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// One node of an exported accessibility tree
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElementNode {
+    role: String,
+    title: Option<String>,
+    value: Option<String>,
+    description: Option<String>,
+    /// `None` when the element reports no position
+    frame: Option<Frame>,
+    enabled: bool,
+    children: Vec<ElementNode>,
+}
+
+impl ElementNode {
+    pub(crate) fn new(
+        role: String,
+        title: Option<String>,
+        value: Option<String>,
+        description: Option<String>,
+        frame: Option<Frame>,
+        enabled: bool,
+        children: Vec<ElementNode>,
+    ) -> Self {
+        Self {
+            role,
+            title,
+            value,
+            description,
+            frame,
+            enabled,
+            children,
+        }
+    }
+
+    pub fn role(&self) -> &str {
+        &self.role
+    }
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+    pub fn frame(&self) -> Option<Frame> {
+        self.frame
+    }
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+    pub fn children(&self) -> &[ElementNode] {
+        &self.children
+    }
+
+    /// Number of nodes in this subtree, including this one
+    pub fn node_count(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(ElementNode::node_count)
+            .sum::<usize>()
+    }
+}
+
+/// Result of exporting a window's accessibility tree
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreeResult {
+    window: String,
+    max_depth: usize,
+    count: usize,
+    roots: Vec<ElementNode>,
+}
+
+impl TreeResult {
+    pub fn new(roots: Vec<ElementNode>, window: String, max_depth: usize) -> Self {
+        let count = roots.iter().map(ElementNode::node_count).sum();
+        Self {
+            window,
+            max_depth,
+            count,
+            roots,
+        }
+    }
+
+    pub fn window(&self) -> &str {
+        &self.window
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Total number of nodes across all roots
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn roots(&self) -> &[ElementNode] {
+        &self.roots
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -736,6 +928,125 @@ mod tests {
         assert!(req.until_gone());
     }
 
+    #[test]
+    fn test_element_info_matches_role() {
+        let elem = ElementInfo::new(
+            "AXButton".to_string(),
+            Some("Save".to_string()),
+            None,
+            None,
+            0,
+            0,
+            80,
+            30,
+            true,
+            0,
+        );
+        assert!(elem.matches_role("AXButton"));
+        assert!(elem.matches_role("button"));
+        assert!(elem.matches_role("BUTTON"));
+        assert!(!elem.matches_role("AXTextField"));
+        assert!(!elem.matches_role("ax"));
+    }
+
+    #[test]
+    fn test_wait_request_matches_role_and_text() {
+        let button = ElementInfo::new(
+            "AXButton".to_string(),
+            Some("Save".to_string()),
+            None,
+            None,
+            0,
+            0,
+            80,
+            30,
+            true,
+            0,
+        );
+        let label = ElementInfo::new(
+            "AXStaticText".to_string(),
+            None,
+            Some("Save changes?".to_string()),
+            None,
+            0,
+            0,
+            80,
+            30,
+            true,
+            0,
+        );
+        let target = InteractionTarget::App {
+            app: "TextEdit".to_string(),
+        };
+
+        let by_text = WaitRequest::new(target.clone(), "save", 1000);
+        assert!(by_text.matches(&button));
+        assert!(by_text.matches(&label));
+
+        let by_both = WaitRequest::new(target.clone(), "save", 1000).with_role("button");
+        assert_eq!(by_both.role(), Some("button"));
+        assert!(by_both.matches(&button));
+        assert!(!by_both.matches(&label));
+
+        let by_role = WaitRequest::new(target, "", 1000).with_role("statictext");
+        assert!(!by_role.matches(&button));
+        assert!(by_role.matches(&label));
+    }
+
+    #[test]
+    fn test_tree_request_defaults() {
+        let req = TreeRequest::new(InteractionTarget::App {
+            app: "Finder".to_string(),
+        });
+        assert_eq!(req.max_depth(), DEFAULT_TREE_DEPTH);
+        assert!(req.timeout_ms().is_none());
+
+        let req = req.with_max_depth(3).with_wait(5000);
+        assert_eq!(req.max_depth(), 3);
+        assert_eq!(req.timeout_ms(), Some(5000));
+    }
+
+    #[test]
+    fn test_tree_result_counts_nested_nodes() {
+        let leaf =
+            |role: &str| ElementNode::new(role.to_string(), None, None, None, None, true, vec![]);
+        let group = ElementNode::new(
+            "AXGroup".to_string(),
+            None,
+            None,
+            None,
+            Some(Frame {
+                x: 0,
+                y: 0,
+                width: 100,
+                height: 40,
+            }),
+            true,
+            vec![leaf("AXButton"), leaf("AXStaticText")],
+        );
+        let window = ElementNode::new(
+            "AXWindow".to_string(),
+            Some("Main".to_string()),
+            None,
+            None,
+            None,
+            true,
+            vec![group],
+        );
+
+        let result = TreeResult::new(vec![window], "Main".to_string(), 5);
+        assert_eq!(result.count(), 4);
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["roots"][0]["role"], "AXWindow");
+        assert_eq!(json["roots"][0]["children"][0]["frame"]["width"], 100);
+        assert_eq!(
+            json["roots"][0]["children"][0]["children"][1]["role"],
+            "AXStaticText"
+        );
+        assert!(json["roots"][0]["frame"].is_null());
+    }
+
     #[test]
     fn test_wait_result_appeared() {
         let result = WaitResult::appeared("Submit", 150);
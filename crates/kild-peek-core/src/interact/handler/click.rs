@@ -25,8 +25,8 @@ use super::helpers::{
 pub fn click(request: &ClickRequest) -> Result<InteractionResult, InteractionError> {
     info!(
         event = "peek.core.interact.click_started",
-        x = request.x(),
-        y = request.y(),
+        x = %request.x(),
+        y = %request.y(),
         modifier = ?request.modifier(),
         target = ?request.target()
     );
@@ -34,9 +34,11 @@ pub fn click(request: &ClickRequest) -> Result<InteractionResult, InteractionErr
     check_accessibility_permission()?;
 
     let window = resolve_and_focus_window(request.target(), request.timeout_ms())?;
-    validate_coordinates(request.x(), request.y(), &window)?;
+    let x = request.x().resolve(window.width());
+    let y = request.y().resolve(window.height());
+    validate_coordinates(x, y, &window)?;
 
-    let (screen_x, screen_y) = to_screen_coordinates(request.x(), request.y(), &window);
+    let (screen_x, screen_y) = to_screen_coordinates(x, y, &window);
     let point = CGPoint::new(screen_x, screen_y);
 
     debug!(
@@ -59,8 +61,8 @@ pub fn click(request: &ClickRequest) -> Result<InteractionResult, InteractionErr
     Ok(InteractionResult::success(
         action,
         serde_json::json!({
-            "x": request.x(),
-            "y": request.y(),
+            "x": x,
+            "y": y,
             "screen_x": screen_x,
             "screen_y": screen_y,
             "modifier": format!("{:?}", request.modifier()),
@@ -80,6 +82,9 @@ pub fn click(request: &ClickRequest) -> Result<InteractionResult, InteractionErr
 /// Returns error if accessibility permission is denied, window is not found or
 /// minimized, element text is not found, multiple elements match (ambiguous),
 /// or the element has no position data.
+///
+/// With the `ocr` feature, text that no accessibility element matches is
+/// looked up by OCR instead, for apps that expose no accessibility tree.
 pub fn click_text(request: &ClickTextRequest) -> Result<InteractionResult, InteractionError> {
     info!(
         event = "peek.core.interact.click_text_started",
@@ -111,6 +116,9 @@ pub fn click_text(request: &ClickTextRequest) -> Result<InteractionResult, Inter
         .map(|raw| crate::element::handler::convert_raw_to_element_info(raw, &window))
         .collect();
 
+    #[cfg(feature = "ocr")]
+    let elements = with_ocr_fallback(elements, &window, request.text());
+
     // Find matching elements
     let matches: Vec<&crate::element::ElementInfo> = elements
         .iter()
@@ -186,3 +194,34 @@ pub fn click_text(request: &ClickTextRequest) -> Result<InteractionResult, Inter
         }),
     ))
 }
+
+/// Replace `elements` with OCR results when none of them match `text`.
+///
+/// Electron apps and games often expose no accessible text; OCR finds it
+/// on screen instead. Keeps the accessibility elements if OCR fails.
+#[cfg(feature = "ocr")]
+fn with_ocr_fallback(
+    elements: Vec<crate::element::ElementInfo>,
+    window: &crate::window::WindowInfo,
+    text: &str,
+) -> Vec<crate::element::ElementInfo> {
+    if elements.iter().any(|e| e.matches_text(text)) {
+        return elements;
+    }
+    match crate::ocr::handler::recognize_window(window) {
+        Ok(recognized) => {
+            debug!(
+                event = "peek.core.interact.click_text_ocr_fallback",
+                count = recognized.len()
+            );
+            recognized
+        }
+        Err(e) => {
+            tracing::warn!(
+                event = "peek.core.interact.click_text_ocr_failed",
+                error = %e
+            );
+            elements
+        }
+    }
+}
@@ -23,18 +23,62 @@ pub enum InteractionTarget {
     AppAndWindow { app: String, title: String },
 }
 
+/// One axis of a window-relative click position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Coordinate {
+    /// Pixels from the window's left/top edge
+    Pixels(i32),
+    /// Percentage (0-100) of the window's width/height
+    Percent(f64),
+}
+
+impl Coordinate {
+    /// Resolve to pixels along an axis `extent` pixels long
+    ///
+    /// 100% maps to the last pixel inside the window rather than one past it.
+    pub fn resolve(self, extent: u32) -> i32 {
+        match self {
+            Coordinate::Pixels(px) => px,
+            Coordinate::Percent(pct) => {
+                let px = (pct / 100.0 * f64::from(extent)).round() as i32;
+                px.min(extent.saturating_sub(1) as i32)
+            }
+        }
+    }
+}
+
+impl From<i32> for Coordinate {
+    fn from(px: i32) -> Self {
+        Coordinate::Pixels(px)
+    }
+}
+
+impl std::fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Coordinate::Pixels(px) => write!(f, "{}", px),
+            Coordinate::Percent(pct) => write!(f, "{}%", pct),
+        }
+    }
+}
+
 /// Request to click at coordinates within a window
 #[derive(Debug, Clone)]
 pub struct ClickRequest {
     target: InteractionTarget,
-    x: i32,
-    y: i32,
+    x: Coordinate,
+    y: Coordinate,
     modifier: ClickModifier,
     timeout_ms: Option<u64>,
 }
 
 impl ClickRequest {
     pub fn new(target: InteractionTarget, x: i32, y: i32) -> Self {
+        Self::at(target, Coordinate::Pixels(x), Coordinate::Pixels(y))
+    }
+
+    /// Click at a position given in pixels, percentages, or a mix of both
+    pub fn at(target: InteractionTarget, x: Coordinate, y: Coordinate) -> Self {
         Self {
             target,
             x,
@@ -58,11 +102,11 @@ impl ClickRequest {
         &self.target
     }
 
-    pub fn x(&self) -> i32 {
+    pub fn x(&self) -> Coordinate {
         self.x
     }
 
-    pub fn y(&self) -> i32 {
+    pub fn y(&self) -> Coordinate {
         self.y
     }
 
@@ -422,8 +466,8 @@ mod tests {
             100,
             50,
         );
-        assert_eq!(req.x(), 100);
-        assert_eq!(req.y(), 50);
+        assert_eq!(req.x(), Coordinate::Pixels(100));
+        assert_eq!(req.y(), Coordinate::Pixels(50));
         match req.target() {
             InteractionTarget::Window { title } => assert_eq!(title, "Terminal"),
             _ => panic!("Expected Window target"),
@@ -538,6 +582,53 @@ mod tests {
         assert!(debug.contains("Test"));
     }
 
+    #[test]
+    fn test_click_request_at_mixed_coordinates() {
+        let req = ClickRequest::at(
+            InteractionTarget::App {
+                app: "KILD".to_string(),
+            },
+            Coordinate::Percent(50.0),
+            Coordinate::Pixels(100),
+        );
+        assert_eq!(req.x(), Coordinate::Percent(50.0));
+        assert_eq!(req.y(), Coordinate::Pixels(100));
+    }
+
+    #[test]
+    fn test_coordinate_resolve_against_window_frame() {
+        let window = crate::window::WindowInfo::new(
+            1,
+            "KILD".to_string(),
+            "KILD".to_string(),
+            200,
+            100,
+            800,
+            600,
+            false,
+            Some(1),
+        );
+        let (w, h) = (window.width(), window.height());
+
+        assert_eq!(Coordinate::Percent(50.0).resolve(w), 400);
+        assert_eq!(Coordinate::Percent(50.0).resolve(h), 300);
+        assert_eq!(Coordinate::Percent(0.0).resolve(w), 0);
+        assert_eq!(Coordinate::Percent(25.0).resolve(h), 150);
+        assert_eq!(Coordinate::Percent(33.3).resolve(w), 266);
+        // 100% stays inside the window
+        assert_eq!(Coordinate::Percent(100.0).resolve(w), 799);
+        assert_eq!(Coordinate::Percent(100.0).resolve(h), 599);
+        // Pixels pass through unchanged
+        assert_eq!(Coordinate::Pixels(123).resolve(w), 123);
+    }
+
+    #[test]
+    fn test_coordinate_display() {
+        assert_eq!(Coordinate::Pixels(100).to_string(), "100");
+        assert_eq!(Coordinate::Percent(50.0).to_string(), "50%");
+        assert_eq!(Coordinate::Percent(12.5).to_string(), "12.5%");
+    }
+
     #[test]
     fn test_click_request_default_timeout_none() {
         let req = ClickRequest::new(
@@ -5,6 +5,7 @@
 //! - Screenshot capture
 //! - Image comparison
 //! - UI state assertions
+//! - OCR text extraction (`ocr` feature)
 //!
 //! Designed for AI-assisted development workflows where Claude Code needs
 //! "eyes" on native UI applications.
@@ -16,6 +17,7 @@ pub mod errors;
 pub mod events;
 pub mod interact;
 pub mod logging;
+pub mod ocr;
 pub mod screenshot;
 pub mod window;
 
@@ -0,0 +1,53 @@
+use crate::errors::PeekError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum OcrError {
+    #[error("Window lookup failed: {0}")]
+    WindowLookupFailed(String),
+
+    #[error("Window is minimized: '{title}'")]
+    WindowMinimized { title: String },
+
+    #[error("Screenshot capture failed: {0}")]
+    ScreenshotFailed(String),
+
+    #[error("Text recognition failed: {0}")]
+    RecognitionFailed(String),
+}
+
+impl PeekError for OcrError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            OcrError::WindowLookupFailed(_) => "OCR_WINDOW_LOOKUP_FAILED",
+            OcrError::WindowMinimized { .. } => "OCR_WINDOW_MINIMIZED",
+            OcrError::ScreenshotFailed(_) => "OCR_SCREENSHOT_FAILED",
+            OcrError::RecognitionFailed(_) => "OCR_RECOGNITION_FAILED",
+        }
+    }
+
+    fn is_user_error(&self) -> bool {
+        matches!(
+            self,
+            OcrError::WindowLookupFailed(_) | OcrError::WindowMinimized { .. }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ocr_error_codes() {
+        let error = OcrError::WindowMinimized {
+            title: "Slack".to_string(),
+        };
+        assert_eq!(error.to_string(), "Window is minimized: 'Slack'");
+        assert_eq!(error.error_code(), "OCR_WINDOW_MINIMIZED");
+        assert!(error.is_user_error());
+
+        let error = OcrError::RecognitionFailed("no handler".to_string());
+        assert_eq!(error.error_code(), "OCR_RECOGNITION_FAILED");
+        assert!(!error.is_user_error());
+    }
+}
@@ -0,0 +1,79 @@
+use tracing::info;
+
+use super::errors::OcrError;
+use super::types::TextRequest;
+use super::vision;
+use crate::element::{ElementInfo, ElementsResult};
+use crate::interact::InteractionTarget;
+use crate::screenshot::{CaptureRequest, capture};
+use crate::window::{
+    WindowInfo, find_window_by_app, find_window_by_app_and_title,
+    find_window_by_app_and_title_with_wait, find_window_by_app_with_wait, find_window_by_title,
+    find_window_by_title_with_wait,
+};
+
+/// Recognize all text in a window via OCR
+///
+/// Returns results shaped like accessibility elements (role `OCRText`, text
+/// in `value`) with window-relative coordinates.
+pub fn recognize_text(request: &TextRequest) -> Result<ElementsResult, OcrError> {
+    info!(
+        event = "peek.core.ocr.recognize_started",
+        target = ?request.target()
+    );
+
+    let window = find_window_by_target(request.target(), request.timeout_ms())?;
+    let elements = recognize_window(&window)?;
+
+    info!(
+        event = "peek.core.ocr.recognize_completed",
+        count = elements.len(),
+        window = window.title()
+    );
+
+    Ok(ElementsResult::new(elements, window.title().to_string()))
+}
+
+/// Capture `window` and run OCR over it
+pub(crate) fn recognize_window(window: &WindowInfo) -> Result<Vec<ElementInfo>, OcrError> {
+    if window.is_minimized() {
+        return Err(OcrError::WindowMinimized {
+            title: window.title().to_string(),
+        });
+    }
+
+    let shot = capture(&CaptureRequest::window_id(window.id()))
+        .map_err(|e| OcrError::ScreenshotFailed(e.to_string()))?;
+
+    let observations = vision::recognize(shot.data()).map_err(OcrError::RecognitionFailed)?;
+
+    Ok(observations
+        .iter()
+        .map(|obs| {
+            obs.to_element_info(shot.width(), shot.height(), window.width(), window.height())
+        })
+        .collect())
+}
+
+fn find_window_by_target(
+    target: &InteractionTarget,
+    timeout_ms: Option<u64>,
+) -> Result<WindowInfo, OcrError> {
+    let result = match (target, timeout_ms) {
+        (InteractionTarget::Window { title }, Some(timeout)) => {
+            find_window_by_title_with_wait(title, timeout)
+        }
+        (InteractionTarget::Window { title }, None) => find_window_by_title(title),
+        (InteractionTarget::App { app }, Some(timeout)) => {
+            find_window_by_app_with_wait(app, timeout)
+        }
+        (InteractionTarget::App { app }, None) => find_window_by_app(app),
+        (InteractionTarget::AppAndWindow { app, title }, Some(timeout)) => {
+            find_window_by_app_and_title_with_wait(app, title, timeout)
+        }
+        (InteractionTarget::AppAndWindow { app, title }, None) => {
+            find_window_by_app_and_title(app, title)
+        }
+    };
+    result.map_err(|e| OcrError::WindowLookupFailed(e.to_string()))
+}
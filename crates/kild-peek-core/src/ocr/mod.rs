@@ -0,0 +1,17 @@
+//! OCR text extraction for windows without a usable accessibility tree.
+//!
+//! Results are returned as `ElementInfo` so they plug into the same
+//! matching and clicking code as accessibility elements. Recognition uses
+//! the macOS Vision framework and is only built with the `ocr` feature.
+
+mod errors;
+#[cfg(feature = "ocr")]
+pub(crate) mod handler;
+mod types;
+#[cfg(feature = "ocr")]
+mod vision;
+
+pub use errors::OcrError;
+#[cfg(feature = "ocr")]
+pub use handler::recognize_text;
+pub use types::{OCR_TEXT_ROLE, TextObservation, TextRequest};
@@ -0,0 +1,198 @@
+use crate::element::ElementInfo;
+use crate::interact::InteractionTarget;
+
+/// Role given to OCR results so they can be told apart from accessibility elements
+pub const OCR_TEXT_ROLE: &str = "OCRText";
+
+/// Request to recognize text in a window
+#[derive(Debug, Clone)]
+pub struct TextRequest {
+    target: InteractionTarget,
+    timeout_ms: Option<u64>,
+}
+
+impl TextRequest {
+    pub fn new(target: InteractionTarget) -> Self {
+        Self {
+            target,
+            timeout_ms: None,
+        }
+    }
+
+    pub fn with_wait(mut self, timeout_ms: u64) -> Self {
+        self.timeout_ms = Some(timeout_ms);
+        self
+    }
+
+    pub fn target(&self) -> &InteractionTarget {
+        &self.target
+    }
+
+    pub fn timeout_ms(&self) -> Option<u64> {
+        self.timeout_ms
+    }
+}
+
+/// A string recognized in a captured image
+///
+/// The bounding box is normalized (0.0-1.0) with a bottom-left origin,
+/// which is how the Vision framework reports it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextObservation {
+    text: String,
+    confidence: f32,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl TextObservation {
+    pub fn new(text: String, confidence: f32, x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            text,
+            confidence,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+
+    /// Bounding box in image pixels with a top-left origin: `(x, y, width, height)`
+    pub fn pixel_rect(&self, image_width: u32, image_height: u32) -> (u32, u32, u32, u32) {
+        let (iw, ih) = (f64::from(image_width), f64::from(image_height));
+        let px = |v: f64, max: f64| (v.clamp(0.0, 1.0) * max).round() as u32;
+        let top = 1.0 - self.y - self.height;
+        (
+            px(self.x, iw),
+            px(top, ih),
+            px(self.width, iw),
+            px(self.height, ih),
+        )
+    }
+
+    /// Convert to an [`ElementInfo`] with window-relative coordinates
+    ///
+    /// The captured image may be larger than the window in points (2x on
+    /// Retina), so pixel positions are scaled by `window / image` per axis.
+    pub fn to_element_info(
+        &self,
+        image_width: u32,
+        image_height: u32,
+        window_width: u32,
+        window_height: u32,
+    ) -> ElementInfo {
+        let (px_x, px_y, px_w, px_h) = self.pixel_rect(image_width, image_height);
+        let scale_x = f64::from(window_width) / f64::from(image_width.max(1));
+        let scale_y = f64::from(window_height) / f64::from(image_height.max(1));
+        let to_points = |v: u32, scale: f64| (f64::from(v) * scale).round();
+
+        ElementInfo::new(
+            OCR_TEXT_ROLE.to_string(),
+            None,
+            Some(self.text.clone()),
+            None,
+            to_points(px_x, scale_x) as i32,
+            to_points(px_y, scale_y) as i32,
+            to_points(px_w, scale_x) as u32,
+            to_points(px_h, scale_y) as u32,
+            true,
+            0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 200x100 fixture: white background with a black "word" block at
+    /// pixels x=40..120, y=20..40 (top-left origin).
+    fn fixture_image() -> image::RgbaImage {
+        image::RgbaImage::from_fn(200, 100, |x, y| {
+            if (40..120).contains(&x) && (20..40).contains(&y) {
+                image::Rgba([0, 0, 0, 255])
+            } else {
+                image::Rgba([255, 255, 255, 255])
+            }
+        })
+    }
+
+    /// Pixel bounds of the dark block: (x, y, width, height).
+    fn dark_bounds(image: &image::RgbaImage) -> (u32, u32, u32, u32) {
+        let dark: Vec<(u32, u32)> = image
+            .enumerate_pixels()
+            .filter(|(_, _, p)| p.0[0] < 128)
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        let min_x = dark.iter().map(|p| p.0).min().unwrap();
+        let max_x = dark.iter().map(|p| p.0).max().unwrap();
+        let min_y = dark.iter().map(|p| p.1).min().unwrap();
+        let max_y = dark.iter().map(|p| p.1).max().unwrap();
+        (min_x, min_y, max_x - min_x + 1, max_y - min_y + 1)
+    }
+
+    /// What Vision reports for the fixture block: bottom-left, normalized.
+    fn fixture_observation() -> TextObservation {
+        TextObservation::new("Submit".to_string(), 0.98, 0.2, 0.6, 0.4, 0.2)
+    }
+
+    #[test]
+    fn test_pixel_rect_flips_to_top_left_origin() {
+        let image = fixture_image();
+        let rect = fixture_observation().pixel_rect(image.width(), image.height());
+        assert_eq!(rect, dark_bounds(&image));
+        assert_eq!(rect, (40, 20, 80, 20));
+    }
+
+    #[test]
+    fn test_to_element_info_scales_retina_capture_to_window_points() {
+        // The fixture is a 2x capture of a 100x50pt window.
+        let image = fixture_image();
+        let element = fixture_observation().to_element_info(image.width(), image.height(), 100, 50);
+
+        assert_eq!(element.role(), OCR_TEXT_ROLE);
+        assert_eq!(element.value(), Some("Submit"));
+        assert_eq!(
+            (element.x(), element.y(), element.width(), element.height()),
+            (20, 10, 40, 10)
+        );
+        assert!(element.matches_text("submit"));
+    }
+
+    #[test]
+    fn test_to_element_info_same_scale() {
+        let image = fixture_image();
+        let element =
+            fixture_observation().to_element_info(image.width(), image.height(), 200, 100);
+        assert_eq!(
+            (element.x(), element.y(), element.width(), element.height()),
+            (40, 20, 80, 20)
+        );
+    }
+
+    #[test]
+    fn test_pixel_rect_clamps_out_of_range_boxes() {
+        let obs = TextObservation::new("edge".to_string(), 0.5, -0.1, 0.9, 0.5, 0.2);
+        let (x, y, _, _) = obs.pixel_rect(200, 100);
+        assert_eq!((x, y), (0, 0));
+    }
+
+    #[test]
+    fn test_text_request_with_wait() {
+        let req = TextRequest::new(InteractionTarget::App {
+            app: "Slack".to_string(),
+        })
+        .with_wait(5000);
+        assert_eq!(req.timeout_ms(), Some(5000));
+    }
+}
@@ -0,0 +1,58 @@
+//! Text recognition via the macOS Vision framework.
+
+use objc2::AnyThread;
+use objc2::rc::Retained;
+use objc2_foundation::{NSArray, NSData, NSDictionary};
+use objc2_vision::{
+    VNImageRequestHandler, VNRecognizeTextRequest, VNRequest, VNRequestTextRecognitionLevel,
+};
+
+use super::types::TextObservation;
+
+/// Run accurate text recognition over encoded image bytes (PNG or JPEG)
+pub(super) fn recognize(image_data: &[u8]) -> Result<Vec<TextObservation>, String> {
+    let data = NSData::with_bytes(image_data);
+    let options = NSDictionary::new();
+
+    // SAFETY: Vision request objects are used on this thread only, and
+    // performRequests runs synchronously before results are read.
+    unsafe {
+        let handler = VNImageRequestHandler::initWithData_options(
+            VNImageRequestHandler::alloc(),
+            &data,
+            &options,
+        );
+
+        let request = VNRecognizeTextRequest::new();
+        request.setRecognitionLevel(VNRequestTextRecognitionLevel::Accurate);
+        request.setUsesLanguageCorrection(true);
+
+        let as_request: Retained<VNRequest> =
+            Retained::into_super(Retained::into_super(request.clone()));
+        let requests = NSArray::from_retained_slice(&[as_request]);
+        handler
+            .performRequests_error(&requests)
+            .map_err(|e| e.localizedDescription().to_string())?;
+
+        let Some(results) = request.results() else {
+            return Ok(Vec::new());
+        };
+
+        let mut observations = Vec::with_capacity(results.count());
+        for observation in results.iter() {
+            let Some(best) = observation.topCandidates(1).firstObject() else {
+                continue;
+            };
+            let bbox = observation.boundingBox();
+            observations.push(TextObservation::new(
+                best.string().to_string(),
+                best.confidence(),
+                bbox.origin.x,
+                bbox.origin.y,
+                bbox.size.width,
+                bbox.size.height,
+            ));
+        }
+        Ok(observations)
+    }
+}
@@ -56,6 +56,9 @@ pub enum ScreenshotError {
         image_height: u32,
     },
 
+    #[error("Invalid image quality {quality}: must be between 1 and 100")]
+    InvalidQuality { quality: u8 },
+
     /// Directory creation failed during screenshot save.
     ///
     /// Use this for mkdir-like failures when creating parent directories.
@@ -91,6 +94,7 @@ impl PeekError for ScreenshotError {
             ScreenshotError::EncodingError(_) => "SCREENSHOT_ENCODING_ERROR",
             ScreenshotError::MonitorNotFound { .. } => "SCREENSHOT_MONITOR_NOT_FOUND",
             ScreenshotError::InvalidCropBounds { .. } => "SCREENSHOT_INVALID_CROP_BOUNDS",
+            ScreenshotError::InvalidQuality { .. } => "SCREENSHOT_INVALID_QUALITY",
             ScreenshotError::DirectoryCreationFailed { .. } => {
                 "SCREENSHOT_DIRECTORY_CREATION_FAILED"
             }
@@ -112,6 +116,7 @@ impl PeekError for ScreenshotError {
                 | ScreenshotError::MonitorNotFound { .. }
                 | ScreenshotError::DirectoryCreationFailed { .. }
                 | ScreenshotError::InvalidCropBounds { .. }
+                | ScreenshotError::InvalidQuality { .. }
         )
     }
 }
@@ -231,4 +236,15 @@ mod tests {
         assert!(msg.contains("200x100"));
         assert!(msg.contains("150x100"));
     }
+
+    #[test]
+    fn test_invalid_quality_error() {
+        let error = ScreenshotError::InvalidQuality { quality: 0 };
+        assert_eq!(
+            error.to_string(),
+            "Invalid image quality 0: must be between 1 and 100"
+        );
+        assert_eq!(error.error_code(), "SCREENSHOT_INVALID_QUALITY");
+        assert!(error.is_user_error());
+    }
 }
@@ -7,9 +7,11 @@ use image::codecs::png::PngEncoder;
 use tracing::{debug, error, info, warn};
 
 use super::errors::ScreenshotError;
+use super::stitch::{self, MonitorRect};
 use super::types::{CaptureRequest, CaptureResult, CaptureTarget, CropArea, ImageFormat};
 use crate::window::{
     WindowError, find_window_by_app, find_window_by_app_and_title, find_window_by_title,
+    list_monitors,
 };
 
 /// Capture a screenshot based on the request
@@ -17,16 +19,30 @@ pub fn capture(request: &CaptureRequest) -> Result<CaptureResult, ScreenshotErro
     info!(event = "core.screenshot.capture_started", target = ?request.target);
 
     let crop = request.crop;
+    let format = resolve_format(request)?;
 
     match &request.target {
-        CaptureTarget::Window { title } => capture_window_by_title(title, &request.format, crop),
-        CaptureTarget::WindowId { id } => capture_window_by_id(*id, &request.format, crop),
-        CaptureTarget::WindowApp { app } => capture_window_by_app(app, &request.format, crop),
+        CaptureTarget::Window { title } => capture_window_by_title(title, &format, crop),
+        CaptureTarget::WindowId { id } => capture_window_by_id(*id, &format, crop),
+        CaptureTarget::WindowApp { app } => capture_window_by_app(app, &format, crop),
         CaptureTarget::WindowAppAndTitle { app, title } => {
-            capture_window_by_app_and_title(app, title, &request.format, crop)
+            capture_window_by_app_and_title(app, title, &format, crop)
         }
-        CaptureTarget::Monitor { index } => capture_monitor(*index, &request.format, crop),
-        CaptureTarget::PrimaryMonitor => capture_primary_monitor(&request.format, crop),
+        CaptureTarget::Monitor { index } => capture_monitor(*index, &format, crop),
+        CaptureTarget::PrimaryMonitor => capture_primary_monitor(&format, crop),
+        CaptureTarget::AllMonitors => capture_all_monitors(&format, crop),
+    }
+}
+
+/// Apply the request's quality override to its format
+///
+/// Rejects qualities outside 1-100 before anything is captured, even for
+/// PNG where the value would otherwise be ignored.
+fn resolve_format(request: &CaptureRequest) -> Result<ImageFormat, ScreenshotError> {
+    match request.quality {
+        None => Ok(request.format.clone()),
+        Some(quality @ 1..=100) => Ok(request.format.clone().with_quality(quality)),
+        Some(quality) => Err(ScreenshotError::InvalidQuality { quality }),
     }
 }
 
@@ -367,6 +383,69 @@ fn capture_primary_monitor(
     encode_image(image, format, crop)
 }
 
+/// Capture every monitor and stitch them by virtual-desktop position.
+///
+/// Gaps between monitors of different sizes are left transparent. The canvas
+/// uses the densest monitor's pixel scale; lower-density captures are
+/// upscaled to match so positions stay consistent.
+fn capture_all_monitors(
+    format: &ImageFormat,
+    crop: Option<CropArea>,
+) -> Result<CaptureResult, ScreenshotError> {
+    let infos = list_monitors().map_err(map_window_error_to_screenshot_error)?;
+    let monitors = enumerate_monitors()?;
+
+    let mut rects = Vec::with_capacity(infos.len());
+    let mut images = Vec::with_capacity(infos.len());
+    for info in &infos {
+        // MonitorInfo ids are indices into xcap's monitor list.
+        let index = info.id() as usize;
+        let monitor = monitors
+            .get(index)
+            .ok_or(ScreenshotError::MonitorNotFound { index })?;
+        let image = monitor
+            .capture_image()
+            .map_err(|e| ScreenshotError::CaptureFailed(e.to_string()))?;
+        rects.push(MonitorRect::from(info));
+        images.push(image);
+    }
+
+    let scale = rects
+        .iter()
+        .zip(&images)
+        .map(|(rect, image)| f64::from(image.width()) / f64::from(rect.width))
+        .fold(1.0, f64::max);
+
+    let layout =
+        stitch::layout(&rects, scale).ok_or(ScreenshotError::MonitorNotFound { index: 0 })?;
+
+    let mut canvas = image::RgbaImage::new(layout.width, layout.height);
+    for (image, place) in images.iter().zip(&layout.placements) {
+        let (x, y) = (i64::from(place.x), i64::from(place.y));
+        if image.dimensions() == (place.width, place.height) {
+            image::imageops::overlay(&mut canvas, image, x, y);
+        } else {
+            let resized = image::imageops::resize(
+                image,
+                place.width,
+                place.height,
+                image::imageops::FilterType::Triangle,
+            );
+            image::imageops::overlay(&mut canvas, &resized, x, y);
+        }
+    }
+
+    info!(
+        event = "core.screenshot.monitors_stitched",
+        monitors = images.len(),
+        width = layout.width,
+        height = layout.height,
+        scale = scale
+    );
+
+    encode_image(canvas, format, crop)
+}
+
 /// Enumerate all monitors with consistent permission error handling
 fn enumerate_monitors() -> Result<Vec<xcap::Monitor>, ScreenshotError> {
     xcap::Monitor::all().map_err(|e| {
@@ -444,6 +523,12 @@ fn encode_image(
                 .write_image(&rgb, width, height, image::ExtendedColorType::Rgb8)
                 .map_err(|e| ScreenshotError::EncodingError(e.to_string()))?;
         }
+        ImageFormat::Webp { quality } => {
+            // image's own WebP encoder is lossless-only, so go through libwebp
+            let encoded =
+                webp::Encoder::from_rgba(&image, width, height).encode(f32::from(*quality));
+            buffer.get_mut().extend_from_slice(&encoded);
+        }
     }
 
     info!(
@@ -629,6 +714,111 @@ mod tests {
         buffer.into_inner()
     }
 
+    /// Fixture with enough detail that lossy encoders have something to drop
+    fn create_fixture_image() -> image::RgbaImage {
+        image::RgbaImage::from_fn(256, 256, |x, y| {
+            let noise = ((x * 7919 + y * 104_729) % 61) as u8;
+            image::Rgba([
+                (x as u8).wrapping_add(noise),
+                (y as u8).wrapping_sub(noise),
+                ((x ^ y) as u8).wrapping_mul(3),
+                255,
+            ])
+        })
+    }
+
+    #[test]
+    fn test_encode_jpeg_lower_quality_is_smaller() {
+        let low = encode_image(
+            create_fixture_image(),
+            &ImageFormat::Jpeg { quality: 10 },
+            None,
+        )
+        .unwrap();
+        let high = encode_image(
+            create_fixture_image(),
+            &ImageFormat::Jpeg { quality: 95 },
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(low.mime_type(), "image/jpeg");
+        assert!(
+            low.data().len() < high.data().len(),
+            "q10 ({} bytes) should be smaller than q95 ({} bytes)",
+            low.data().len(),
+            high.data().len()
+        );
+    }
+
+    #[test]
+    fn test_encode_webp_honors_quality() {
+        let low = encode_image(
+            create_fixture_image(),
+            &ImageFormat::Webp { quality: 10 },
+            None,
+        )
+        .unwrap();
+        let high = encode_image(
+            create_fixture_image(),
+            &ImageFormat::Webp { quality: 95 },
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(low.mime_type(), "image/webp");
+        assert_eq!(&low.data()[..4], b"RIFF");
+        assert_eq!(&low.data()[8..12], b"WEBP");
+        assert!(low.data().len() < high.data().len());
+        assert_eq!((low.width(), low.height()), (256, 256));
+    }
+
+    #[test]
+    fn test_resolve_format_applies_quality_to_lossy_formats() {
+        let request = CaptureRequest::window("Test")
+            .with_format(ImageFormat::Jpeg { quality: 85 })
+            .with_quality(30);
+        assert!(matches!(
+            resolve_format(&request).unwrap(),
+            ImageFormat::Jpeg { quality: 30 }
+        ));
+
+        let request = CaptureRequest::window("Test")
+            .with_format(ImageFormat::Webp { quality: 85 })
+            .with_quality(100);
+        assert!(matches!(
+            resolve_format(&request).unwrap(),
+            ImageFormat::Webp { quality: 100 }
+        ));
+
+        // PNG ignores quality
+        let request = CaptureRequest::window("Test").with_quality(1);
+        assert!(matches!(
+            resolve_format(&request).unwrap(),
+            ImageFormat::Png
+        ));
+
+        // No override keeps the format's own quality
+        let request = CaptureRequest::window("Test").with_format(ImageFormat::Jpeg { quality: 70 });
+        assert!(matches!(
+            resolve_format(&request).unwrap(),
+            ImageFormat::Jpeg { quality: 70 }
+        ));
+    }
+
+    #[test]
+    fn test_capture_rejects_out_of_range_quality() {
+        for quality in [0, 101, 255] {
+            let request = CaptureRequest::window("NONEXISTENT_WINDOW_12345_UNIQUE")
+                .with_format(ImageFormat::Jpeg { quality: 85 })
+                .with_quality(quality);
+            match capture(&request) {
+                Err(ScreenshotError::InvalidQuality { quality: q }) => assert_eq!(q, quality),
+                other => panic!("Expected InvalidQuality for {quality}, got {:?}", other),
+            }
+        }
+    }
+
     #[test]
     fn test_capture_by_app_nonexistent() {
         let request = CaptureRequest::window_app("NONEXISTENT_APP_12345_UNIQUE");
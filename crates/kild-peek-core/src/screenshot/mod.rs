@@ -1,5 +1,6 @@
 mod errors;
 mod handler;
+mod stitch;
 mod types;
 
 pub use errors::ScreenshotError;
@@ -0,0 +1,160 @@
+//! Layout math for stitching every monitor into one image.
+//!
+//! Monitors report their position in virtual-desktop coordinates, where
+//! monitors left of or above the primary have negative origins. The canvas
+//! is the bounding box of all monitors, shifted so its top-left is (0, 0).
+
+use crate::window::MonitorInfo;
+
+/// A monitor's rectangle in virtual-desktop coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MonitorRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl From<&MonitorInfo> for MonitorRect {
+    fn from(m: &MonitorInfo) -> Self {
+        Self {
+            x: m.x(),
+            y: m.y(),
+            width: m.width(),
+            height: m.height(),
+        }
+    }
+}
+
+/// Stitched canvas size and where each monitor lands on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CanvasLayout {
+    pub width: u32,
+    pub height: u32,
+    /// Pixel offset and size of each monitor, in input order.
+    pub placements: Vec<Placement>,
+}
+
+/// Where one monitor's image is drawn on the canvas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Placement {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Lay out `rects` on a single canvas, scaled by `scale` pixels per point.
+///
+/// `scale` is the capture resolution relative to monitor coordinates (2.0
+/// on a Retina display). Returns `None` when there are no monitors.
+pub(crate) fn layout(rects: &[MonitorRect], scale: f64) -> Option<CanvasLayout> {
+    let min_x = rects.iter().map(|r| i64::from(r.x)).min()?;
+    let min_y = rects.iter().map(|r| i64::from(r.y)).min()?;
+    let max_x = rects
+        .iter()
+        .map(|r| i64::from(r.x) + i64::from(r.width))
+        .max()?;
+    let max_y = rects
+        .iter()
+        .map(|r| i64::from(r.y) + i64::from(r.height))
+        .max()?;
+
+    let px = |points: i64| (points as f64 * scale).round() as u32;
+
+    let placements = rects
+        .iter()
+        .map(|r| Placement {
+            x: px(i64::from(r.x) - min_x),
+            y: px(i64::from(r.y) - min_y),
+            width: px(i64::from(r.width)),
+            height: px(i64::from(r.height)),
+        })
+        .collect();
+
+    Some(CanvasLayout {
+        width: px(max_x - min_x),
+        height: px(max_y - min_y),
+        placements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i32, y: i32, width: u32, height: u32) -> MonitorRect {
+        MonitorRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    fn place(x: u32, y: u32, width: u32, height: u32) -> Placement {
+        Placement {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_layout_empty_is_none() {
+        assert!(layout(&[], 1.0).is_none());
+    }
+
+    #[test]
+    fn test_layout_single_monitor() {
+        let l = layout(&[rect(0, 0, 1920, 1080)], 1.0).unwrap();
+        assert_eq!((l.width, l.height), (1920, 1080));
+        assert_eq!(l.placements, [place(0, 0, 1920, 1080)]);
+    }
+
+    #[test]
+    fn test_layout_side_by_side() {
+        let l = layout(&[rect(0, 0, 1920, 1080), rect(1920, 0, 2560, 1440)], 1.0).unwrap();
+        assert_eq!((l.width, l.height), (4480, 1440));
+        assert_eq!(
+            l.placements,
+            [place(0, 0, 1920, 1080), place(1920, 0, 2560, 1440)]
+        );
+    }
+
+    #[test]
+    fn test_layout_normalizes_negative_origin() {
+        // Secondary monitor to the left of and slightly above the primary.
+        let primary = MonitorInfo::new(0, "Built-in".into(), 0, 0, 1512, 982, true);
+        let left = MonitorInfo::new(1, "External".into(), -2560, -200, 2560, 1440, false);
+        let rects: Vec<MonitorRect> = [&primary, &left].into_iter().map(Into::into).collect();
+
+        let l = layout(&rects, 1.0).unwrap();
+        assert_eq!((l.width, l.height), (4072, 1440));
+        assert_eq!(
+            l.placements,
+            [place(2560, 200, 1512, 982), place(0, 0, 2560, 1440)]
+        );
+    }
+
+    #[test]
+    fn test_layout_stacked_vertically() {
+        let l = layout(&[rect(0, 0, 1920, 1080), rect(320, -1440, 2560, 1440)], 1.0).unwrap();
+        assert_eq!((l.width, l.height), (2880, 2520));
+        assert_eq!(
+            l.placements,
+            [place(0, 1440, 1920, 1080), place(320, 0, 2560, 1440)]
+        );
+    }
+
+    #[test]
+    fn test_layout_applies_scale() {
+        let l = layout(&[rect(-1512, 0, 1512, 982), rect(0, 0, 1920, 1080)], 2.0).unwrap();
+        assert_eq!((l.width, l.height), (6864, 2160));
+        assert_eq!(
+            l.placements,
+            [place(0, 0, 3024, 1964), place(3024, 0, 3840, 2160)]
+        );
+    }
+}
@@ -36,6 +36,8 @@ pub enum CaptureTarget {
     Monitor { index: usize },
     /// Capture the primary monitor
     PrimaryMonitor,
+    /// Capture every monitor, stitched into one image by desktop position
+    AllMonitors,
 }
 
 /// Image format for screenshot output
@@ -46,6 +48,21 @@ pub enum ImageFormat {
     Jpeg {
         quality: u8,
     },
+    /// Lossy WebP, usually much smaller than PNG for the same screenshot
+    Webp {
+        quality: u8,
+    },
+}
+
+impl ImageFormat {
+    /// Replace the quality of a lossy format; PNG is returned unchanged
+    pub fn with_quality(self, quality: u8) -> Self {
+        match self {
+            ImageFormat::Png => ImageFormat::Png,
+            ImageFormat::Jpeg { .. } => ImageFormat::Jpeg { quality },
+            ImageFormat::Webp { .. } => ImageFormat::Webp { quality },
+        }
+    }
 }
 
 /// Request to capture a screenshot
@@ -57,6 +74,9 @@ pub struct CaptureRequest {
     pub format: ImageFormat,
     /// Optional crop region
     pub crop: Option<CropArea>,
+    /// Quality (1-100) for lossy formats, overriding the one in `format`.
+    /// Ignored for PNG.
+    pub quality: Option<u8>,
 }
 
 impl CaptureRequest {
@@ -68,6 +88,7 @@ impl CaptureRequest {
             },
             format: ImageFormat::default(),
             crop: None,
+            quality: None,
         }
     }
 
@@ -77,6 +98,7 @@ impl CaptureRequest {
             target: CaptureTarget::WindowId { id },
             format: ImageFormat::default(),
             crop: None,
+            quality: None,
         }
     }
 
@@ -86,6 +108,7 @@ impl CaptureRequest {
             target: CaptureTarget::WindowApp { app: app.into() },
             format: ImageFormat::default(),
             crop: None,
+            quality: None,
         }
     }
 
@@ -98,6 +121,7 @@ impl CaptureRequest {
             },
             format: ImageFormat::default(),
             crop: None,
+            quality: None,
         }
     }
 
@@ -107,6 +131,7 @@ impl CaptureRequest {
             target: CaptureTarget::Monitor { index },
             format: ImageFormat::default(),
             crop: None,
+            quality: None,
         }
     }
 
@@ -116,6 +141,17 @@ impl CaptureRequest {
             target: CaptureTarget::PrimaryMonitor,
             format: ImageFormat::default(),
             crop: None,
+            quality: None,
+        }
+    }
+
+    /// Create a new capture request stitching all monitors together
+    pub fn all_monitors() -> Self {
+        Self {
+            target: CaptureTarget::AllMonitors,
+            format: ImageFormat::default(),
+            crop: None,
+            quality: None,
         }
     }
 
@@ -133,6 +169,12 @@ impl CaptureRequest {
         self
     }
 
+    /// Set the encoder quality for JPEG/WebP (validated as 1-100 at capture)
+    pub fn with_quality(mut self, quality: u8) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
     /// Set crop region to extract from captured image
     pub fn with_crop(mut self, crop: CropArea) -> Self {
         self.crop = Some(crop);
@@ -179,7 +221,7 @@ impl CaptureResult {
         &self.format
     }
 
-    /// Encoded image bytes (PNG or JPEG)
+    /// Encoded image bytes (PNG, JPEG or WebP)
     pub fn data(&self) -> &[u8] {
         &self.data
     }
@@ -194,6 +236,7 @@ impl CaptureResult {
         match self.format {
             ImageFormat::Png => "image/png",
             ImageFormat::Jpeg { .. } => "image/jpeg",
+            ImageFormat::Webp { .. } => "image/webp",
         }
     }
 
@@ -216,6 +259,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_capture_request_all_monitors() {
+        let req = CaptureRequest::all_monitors();
+        assert!(matches!(req.target, CaptureTarget::AllMonitors));
+        assert!(req.crop.is_none());
+    }
+
     #[test]
     fn test_capture_request_with_format() {
         let req = CaptureRequest::window("Test").with_format(ImageFormat::Jpeg { quality: 90 });
@@ -233,6 +283,37 @@ mod tests {
         let jpg_result =
             CaptureResult::new(100, 100, ImageFormat::Jpeg { quality: 85 }, vec![0xFF]);
         assert_eq!(jpg_result.mime_type(), "image/jpeg");
+
+        let webp_result =
+            CaptureResult::new(100, 100, ImageFormat::Webp { quality: 80 }, vec![0x52]);
+        assert_eq!(webp_result.mime_type(), "image/webp");
+    }
+
+    #[test]
+    fn test_image_format_with_quality() {
+        assert!(matches!(
+            ImageFormat::Png.with_quality(50),
+            ImageFormat::Png
+        ));
+        assert!(matches!(
+            ImageFormat::Jpeg { quality: 85 }.with_quality(50),
+            ImageFormat::Jpeg { quality: 50 }
+        ));
+        assert!(matches!(
+            ImageFormat::Webp { quality: 85 }.with_quality(50),
+            ImageFormat::Webp { quality: 50 }
+        ));
+    }
+
+    #[test]
+    fn test_capture_request_with_quality() {
+        let req = CaptureRequest::window("Test");
+        assert!(req.quality.is_none());
+
+        let req = req
+            .with_format(ImageFormat::Webp { quality: 85 })
+            .with_quality(40);
+        assert_eq!(req.quality, Some(40));
     }
 
     #[test]
@@ -34,4 +34,17 @@ pub fn subcommand() -> Command {
                 .long("diff-output")
                 .help("Save visual diff image highlighting differences"),
         )
+        .arg(
+            Arg::new("tolerance")
+                .long("tolerance")
+                .help("Per-channel difference to treat as equal (0-255, default: 0)")
+                .value_parser(clap::value_parser!(u8))
+                .default_value("0"),
+        )
+        .arg(
+            Arg::new("ignore")
+                .long("ignore")
+                .help("Region to exclude from comparison: x,y,width,height (repeatable)")
+                .action(ArgAction::Append),
+        )
 }
@@ -42,9 +42,83 @@ pub fn subcommand() -> Command {
         )
 }
 
+pub fn tree_subcommand() -> Command {
+    Command::new("tree")
+        .about("Export a window's full accessibility hierarchy as nested JSON")
+        .arg(
+            Arg::new("window")
+                .long("window")
+                .short('w')
+                .help("Target window by title"),
+        )
+        .arg(
+            Arg::new("app")
+                .long("app")
+                .short('a')
+                .help("Target window by app name"),
+        )
+        .arg(
+            Arg::new("max-depth")
+                .long("max-depth")
+                .help("Levels below the window to include (default: 20)")
+                .value_parser(clap::value_parser!(usize))
+                .default_value("20"),
+        )
+        .arg(
+            Arg::new("wait")
+                .long("wait")
+                .help("Wait for window to appear (polls until found or timeout)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .help("Timeout in milliseconds when using --wait (default: 30000)")
+                .value_parser(clap::value_parser!(u64))
+                .default_value("30000"),
+        )
+}
+
+#[cfg(feature = "ocr")]
+pub fn text_subcommand() -> Command {
+    Command::new("text")
+        .about("Recognize text in a window via OCR (for apps without an accessibility tree)")
+        .arg(
+            Arg::new("window")
+                .long("window")
+                .short('w')
+                .help("Target window by title"),
+        )
+        .arg(
+            Arg::new("app")
+                .long("app")
+                .short('a')
+                .help("Target window by app name"),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Output in JSON format")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("wait")
+                .long("wait")
+                .help("Wait for window to appear (polls until found or timeout)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .help("Timeout in milliseconds when using --wait (default: 30000)")
+                .value_parser(clap::value_parser!(u64))
+                .default_value("30000"),
+        )
+}
+
 pub fn wait_subcommand() -> Command {
     Command::new("wait")
-        .about("Wait for a UI element with given text to appear or disappear")
+        .about("Wait for a UI element to appear or disappear")
         .arg(
             Arg::new("window")
                 .long("window")
@@ -60,9 +134,15 @@ pub fn wait_subcommand() -> Command {
         .arg(
             Arg::new("text")
                 .long("text")
-                .required(true)
+                .visible_alias("title")
+                .required_unless_present("role")
                 .help("Text to wait for (case-insensitive substring match)"),
         )
+        .arg(
+            Arg::new("role")
+                .long("role")
+                .help("Element role to wait for, e.g. button or AXButton"),
+        )
         .arg(
             Arg::new("until-gone")
                 .long("until-gone")
@@ -72,8 +152,8 @@ pub fn wait_subcommand() -> Command {
         .arg(
             Arg::new("timeout")
                 .long("timeout")
-                .help("Timeout in milliseconds (default: 30000)")
-                .value_parser(clap::value_parser!(u64))
+                .help("Timeout as milliseconds or with a unit: 500ms, 10s, 2m (default: 30000)")
+                .value_parser(parse_timeout_ms)
                 .default_value("30000"),
         )
         .arg(
@@ -84,6 +164,29 @@ pub fn wait_subcommand() -> Command {
         )
 }
 
+/// Parse a timeout into milliseconds; a bare number is already milliseconds
+fn parse_timeout_ms(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (digits, unit) = s.split_at(split);
+    let value: u64 = digits
+        .parse()
+        .map_err(|_| format!("invalid timeout '{s}': expected e.g. 5000, 500ms, 10s or 2m"))?;
+    let multiplier = match unit.trim() {
+        "" | "ms" => 1,
+        "s" => 1_000,
+        "m" => 60_000,
+        other => return Err(format!("unknown timeout unit '{other}': use ms, s or m")),
+    };
+    let ms = value
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("timeout '{s}' is too large"))?;
+    if ms == 0 {
+        return Err("timeout must be greater than zero".to_string());
+    }
+    Ok(ms)
+}
+
 pub fn find_subcommand() -> Command {
     Command::new("find")
         .about("Find a UI element by text content")
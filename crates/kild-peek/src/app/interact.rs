@@ -18,7 +18,7 @@ pub fn click_subcommand() -> Command {
         .arg(
             Arg::new("at")
                 .long("at")
-                .help("Coordinates to click: x,y (relative to window top-left)")
+                .help("Coordinates to click: x,y in pixels or percent of the window (e.g. 100,50 or 50%,50%)")
                 .conflicts_with("text"),
         )
         .arg(
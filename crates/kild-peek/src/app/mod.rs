@@ -11,7 +11,7 @@ mod screenshot;
 mod tests;
 
 pub fn build_cli() -> Command {
-    Command::new("kild-peek")
+    let app = Command::new("kild-peek")
         .version(env!("CARGO_PKG_VERSION"))
         .about("Native application inspector for AI-assisted development")
         .long_about(
@@ -33,6 +33,7 @@ pub fn build_cli() -> Command {
         .subcommand(screenshot::subcommand())
         .subcommand(diff::subcommand())
         .subcommand(elements::subcommand())
+        .subcommand(elements::tree_subcommand())
         .subcommand(elements::find_subcommand())
         .subcommand(elements::wait_subcommand())
         .subcommand(interact::click_subcommand())
@@ -41,5 +42,10 @@ pub fn build_cli() -> Command {
         .subcommand(interact::drag_subcommand())
         .subcommand(interact::scroll_subcommand())
         .subcommand(interact::hover_subcommand())
-        .subcommand(assert::subcommand())
+        .subcommand(assert::subcommand());
+
+    #[cfg(feature = "ocr")]
+    let app = app.subcommand(elements::text_subcommand());
+
+    app
 }
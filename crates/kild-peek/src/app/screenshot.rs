@@ -48,13 +48,13 @@ pub fn subcommand() -> Command {
                 .long("format")
                 .short('f')
                 .help("Output format")
-                .value_parser(["png", "jpg", "jpeg"])
+                .value_parser(["png", "jpg", "jpeg", "webp"])
                 .default_value("png"),
         )
         .arg(
             Arg::new("quality")
                 .long("quality")
-                .help("JPEG quality (1-100, default: 85)")
+                .help("JPEG/WebP quality (1-100, default: 85)")
                 .value_parser(clap::value_parser!(u8))
                 .default_value("85"),
         )
@@ -100,6 +100,28 @@ fn test_cli_screenshot_format_jpeg() {
     assert_eq!(*screenshot_matches.get_one::<u8>("quality").unwrap(), 90);
 }
 
+#[test]
+fn test_cli_screenshot_format_webp() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec![
+        "kild-peek",
+        "screenshot",
+        "--format",
+        "webp",
+        "--quality",
+        "60",
+    ]);
+    assert!(matches.is_ok());
+
+    let matches = matches.unwrap();
+    let screenshot_matches = matches.subcommand_matches("screenshot").unwrap();
+    assert_eq!(
+        screenshot_matches.get_one::<String>("format").unwrap(),
+        "webp"
+    );
+    assert_eq!(*screenshot_matches.get_one::<u8>("quality").unwrap(), 60);
+}
+
 #[test]
 fn test_cli_diff() {
     let app = build_cli();
@@ -326,6 +348,41 @@ fn test_cli_diff_with_diff_output() {
     );
 }
 
+#[test]
+fn test_cli_diff_tolerance_and_ignore_regions() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec![
+        "kild-peek",
+        "diff",
+        "/path/to/a.png",
+        "/path/to/b.png",
+        "--tolerance",
+        "8",
+        "--ignore",
+        "0,0,100,20",
+        "--ignore",
+        "10,500,50,50",
+    ]);
+    assert!(matches.is_ok());
+
+    let matches = matches.unwrap();
+    let diff_matches = matches.subcommand_matches("diff").unwrap();
+    assert_eq!(*diff_matches.get_one::<u8>("tolerance").unwrap(), 8);
+    let regions: Vec<&String> = diff_matches.get_many::<String>("ignore").unwrap().collect();
+    assert_eq!(regions, ["0,0,100,20", "10,500,50,50"]);
+}
+
+#[test]
+fn test_cli_diff_tolerance_defaults_to_zero() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild-peek", "diff", "/a.png", "/b.png"])
+        .unwrap();
+    let diff_matches = matches.subcommand_matches("diff").unwrap();
+    assert_eq!(*diff_matches.get_one::<u8>("tolerance").unwrap(), 0);
+    assert!(diff_matches.get_many::<String>("ignore").is_none());
+}
+
 #[test]
 fn test_cli_assert_app() {
     let app = build_cli();
@@ -746,6 +803,29 @@ fn test_cli_elements_with_window() {
     );
 }
 
+#[cfg(feature = "ocr")]
+#[test]
+fn test_cli_text_with_app_json() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild-peek", "text", "--app", "Slack", "--json"]);
+    assert!(matches.is_ok());
+
+    let matches = matches.unwrap();
+    let text_matches = matches.subcommand_matches("text").unwrap();
+    assert_eq!(text_matches.get_one::<String>("app").unwrap(), "Slack");
+    assert!(text_matches.get_flag("json"));
+}
+
+#[cfg(not(feature = "ocr"))]
+#[test]
+fn test_cli_text_requires_ocr_feature() {
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild-peek", "text", "--app", "Slack"])
+            .is_err()
+    );
+}
+
 #[test]
 fn test_cli_elements_json() {
     let app = build_cli();
@@ -1442,6 +1522,56 @@ fn test_cli_find_regex_with_json() {
     assert!(find_matches.get_flag("json"));
 }
 
+#[test]
+fn test_cli_tree() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec![
+        "kild-peek",
+        "tree",
+        "--app",
+        "Finder",
+        "--window",
+        "Downloads",
+        "--max-depth",
+        "5",
+    ]);
+    assert!(matches.is_ok());
+
+    let matches = matches.unwrap();
+    let tree_matches = matches.subcommand_matches("tree").unwrap();
+    assert_eq!(tree_matches.get_one::<String>("app").unwrap(), "Finder");
+    assert_eq!(
+        tree_matches.get_one::<String>("window").unwrap(),
+        "Downloads"
+    );
+    assert_eq!(*tree_matches.get_one::<usize>("max-depth").unwrap(), 5);
+    assert!(!tree_matches.get_flag("wait"));
+}
+
+#[test]
+fn test_cli_tree_default_depth() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild-peek", "tree", "--app", "Finder"])
+        .unwrap();
+    let tree_matches = matches.subcommand_matches("tree").unwrap();
+    assert_eq!(*tree_matches.get_one::<usize>("max-depth").unwrap(), 20);
+}
+
+#[test]
+fn test_cli_tree_rejects_negative_depth() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec![
+        "kild-peek",
+        "tree",
+        "--app",
+        "Finder",
+        "--max-depth",
+        "-1",
+    ]);
+    assert!(matches.is_err());
+}
+
 #[test]
 fn test_cli_wait_basic() {
     let app = build_cli();
@@ -1508,6 +1638,83 @@ fn test_cli_wait_requires_text() {
     assert!(matches.is_err());
 }
 
+#[test]
+fn test_cli_wait_role_and_title() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec![
+        "kild-peek",
+        "wait",
+        "--app",
+        "TextEdit",
+        "--role",
+        "button",
+        "--title",
+        "Save",
+        "--timeout",
+        "10s",
+    ]);
+    assert!(matches.is_ok());
+    let matches = matches.unwrap();
+    let wait_matches = matches.subcommand_matches("wait").unwrap();
+    assert_eq!(wait_matches.get_one::<String>("role").unwrap(), "button");
+    assert_eq!(wait_matches.get_one::<String>("text").unwrap(), "Save");
+    assert_eq!(*wait_matches.get_one::<u64>("timeout").unwrap(), 10000);
+}
+
+#[test]
+fn test_cli_wait_role_without_text() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec![
+        "kild-peek",
+        "wait",
+        "--app",
+        "TextEdit",
+        "--role",
+        "AXSheet",
+    ]);
+    assert!(matches.is_ok());
+    let matches = matches.unwrap();
+    let wait_matches = matches.subcommand_matches("wait").unwrap();
+    assert!(wait_matches.get_one::<String>("text").is_none());
+}
+
+#[test]
+fn test_cli_wait_timeout_units() {
+    for (input, expected) in [("750", 750), ("750ms", 750), ("3s", 3000), ("2m", 120_000)] {
+        let matches = build_cli()
+            .try_get_matches_from(vec![
+                "kild-peek",
+                "wait",
+                "--text",
+                "Done",
+                "--timeout",
+                input,
+            ])
+            .unwrap();
+        let wait_matches = matches.subcommand_matches("wait").unwrap();
+        assert_eq!(
+            *wait_matches.get_one::<u64>("timeout").unwrap(),
+            expected,
+            "timeout {input}"
+        );
+    }
+}
+
+#[test]
+fn test_cli_wait_rejects_bad_timeout() {
+    for input in ["10h", "s", "0", "-5s"] {
+        let result = build_cli().try_get_matches_from(vec![
+            "kild-peek",
+            "wait",
+            "--text",
+            "Done",
+            "--timeout",
+            input,
+        ]);
+        assert!(result.is_err(), "timeout {input} should be rejected");
+    }
+}
+
 #[test]
 fn test_cli_assert_contains_text() {
     let app = build_cli();
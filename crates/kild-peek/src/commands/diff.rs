@@ -1,5 +1,5 @@
 use clap::ArgMatches;
-use kild_peek_core::diff::{DiffRequest, compare_images};
+use kild_peek_core::diff::{DiffRequest, Rect, compare_images};
 use kild_peek_core::events;
 use tracing::{error, info};
 
@@ -9,6 +9,13 @@ pub fn handle_diff_command(matches: &ArgMatches) -> Result<(), Box<dyn std::erro
     let threshold_percent = *matches.get_one::<u8>("threshold").unwrap_or(&95);
     let json_output = matches.get_flag("json");
     let diff_output = matches.get_one::<String>("diff-output");
+    let tolerance = *matches.get_one::<u8>("tolerance").unwrap_or(&0);
+    let ignore_regions = matches
+        .get_many::<String>("ignore")
+        .into_iter()
+        .flatten()
+        .map(|s| parse_region(s))
+        .collect::<Result<Vec<_>, _>>()?;
 
     let threshold = (threshold_percent as f64) / 100.0;
 
@@ -17,10 +24,17 @@ pub fn handle_diff_command(matches: &ArgMatches) -> Result<(), Box<dyn std::erro
         image1 = image1,
         image2 = image2,
         threshold = threshold,
+        tolerance = tolerance,
+        ignore_regions = ignore_regions.len(),
         diff_output = ?diff_output
     );
 
-    let mut request = DiffRequest::new(image1, image2).with_threshold(threshold);
+    let mut request = DiffRequest::new(image1, image2)
+        .with_threshold(threshold)
+        .with_tolerance(tolerance);
+    for region in ignore_regions {
+        request = request.with_ignore_region(region);
+    }
     if let Some(path) = diff_output {
         request = request.with_diff_output(path);
     }
@@ -37,6 +51,7 @@ pub fn handle_diff_command(matches: &ArgMatches) -> Result<(), Box<dyn std::erro
                 println!("Image comparison: {}", status);
                 println!("  Similarity: {}", result.similarity_percent());
                 println!("  Threshold: {}%", threshold_percent);
+                println!("  Changed pixels: {}", result.changed_pixels());
                 println!("  Image 1: {}x{}", result.width1(), result.height1());
                 println!("  Image 2: {}x{}", result.width2(), result.height2());
                 if let Some(path) = result.diff_output_path() {
@@ -47,6 +62,7 @@ pub fn handle_diff_command(matches: &ArgMatches) -> Result<(), Box<dyn std::erro
             info!(
                 event = "peek.cli.diff_completed",
                 similarity = result.similarity(),
+                changed_pixels = result.changed_pixels(),
                 is_similar = result.is_similar()
             );
 
@@ -65,3 +81,16 @@ pub fn handle_diff_command(matches: &ArgMatches) -> Result<(), Box<dyn std::erro
         }
     }
 }
+
+/// Parse an ignore region string in the format "x,y,width,height"
+fn parse_region(s: &str) -> Result<Rect, Box<dyn std::error::Error>> {
+    let parts: Vec<&str> = s.split(',').collect();
+    if parts.len() != 4 {
+        return Err("Ignore region format must be x,y,width,height".into());
+    }
+    let x: u32 = parts[0].trim().parse()?;
+    let y: u32 = parts[1].trim().parse()?;
+    let width: u32 = parts[2].trim().parse()?;
+    let height: u32 = parts[3].trim().parse()?;
+    Ok(Rect::new(x, y, width, height))
+}
@@ -1,6 +1,7 @@
 use clap::ArgMatches;
 use kild_peek_core::element::{
-    ElementsRequest, FindRequest, WaitRequest, find_element, list_elements, wait_for_element,
+    ElementsRequest, FindRequest, TreeRequest, WaitRequest, element_tree, find_element,
+    list_elements, wait_for_element,
 };
 use kild_peek_core::events;
 use tracing::{error, info};
@@ -64,39 +65,133 @@ pub fn handle_elements_command(matches: &ArgMatches) -> Result<(), Box<dyn std::
     }
 }
 
+pub fn handle_tree_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let target = parse_interaction_target(matches)?;
+    let max_depth = *matches.get_one::<usize>("max-depth").unwrap_or(&20);
+    let wait_flag = matches.get_flag("wait");
+    let timeout_ms = *matches.get_one::<u64>("timeout").unwrap_or(&30000);
+
+    info!(
+        event = "peek.cli.tree_started",
+        target = ?target,
+        max_depth = max_depth,
+        wait = wait_flag,
+        timeout_ms = timeout_ms
+    );
+
+    let mut request = TreeRequest::new(target).with_max_depth(max_depth);
+    if wait_flag {
+        request = request.with_wait(timeout_ms);
+    }
+
+    match element_tree(&request) {
+        Ok(result) => {
+            println!("{}", serde_json::to_string_pretty(&result)?);
+            info!(event = "peek.cli.tree_completed", count = result.count());
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("Tree export failed: {}", e);
+            error!(event = "peek.cli.tree_failed", error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
+
+#[cfg(feature = "ocr")]
+pub fn handle_text_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    use kild_peek_core::ocr::{TextRequest, recognize_text};
+
+    let target = parse_interaction_target(matches)?;
+    let json_output = matches.get_flag("json");
+    let wait_flag = matches.get_flag("wait");
+    let timeout_ms = *matches.get_one::<u64>("timeout").unwrap_or(&30000);
+
+    info!(
+        event = "peek.cli.text_started",
+        target = ?target,
+        wait = wait_flag,
+        timeout_ms = timeout_ms
+    );
+
+    let request = if wait_flag {
+        TextRequest::new(target).with_wait(timeout_ms)
+    } else {
+        TextRequest::new(target)
+    };
+
+    match recognize_text(&request) {
+        Ok(result) => {
+            if json_output {
+                println!("{}", serde_json::to_string_pretty(&result)?);
+            } else if result.elements().is_empty() {
+                println!("No text recognized in window \"{}\"", result.window());
+            } else {
+                println!(
+                    "Text in \"{}\" ({} found):",
+                    result.window(),
+                    result.count()
+                );
+                table::print_elements_table(result.elements());
+            }
+
+            info!(event = "peek.cli.text_completed", count = result.count());
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("Text recognition failed: {}", e);
+            error!(event = "peek.cli.text_failed", error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
+
 pub fn handle_wait_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
     let target = parse_interaction_target(matches)?;
     let text = matches
         .get_one::<String>("text")
-        .ok_or("--text is required")?;
+        .map(String::as_str)
+        .unwrap_or_default();
+    let role = matches.get_one::<String>("role");
     let until_gone = matches.get_flag("until-gone");
     let timeout_ms = *matches.get_one::<u64>("timeout").unwrap_or(&30000);
     let json_output = matches.get_flag("json");
 
     info!(
         event = "peek.cli.wait_started",
-        text = text.as_str(),
+        text = text,
+        role = ?role,
         until_gone = until_gone,
         timeout_ms = timeout_ms
     );
 
     let mut request = WaitRequest::new(target, text, timeout_ms);
+    if let Some(role) = role {
+        request = request.with_role(role);
+    }
     if until_gone {
         request = request.with_until_gone();
     }
+    let label = match role {
+        Some(role) if text.is_empty() => role.to_string(),
+        Some(role) => format!("{} \"{}\"", role, text),
+        None => format!("\"{}\"", text),
+    };
 
     match wait_for_element(&request) {
         Ok(result) => {
             if json_output {
                 println!("{}", serde_json::to_string_pretty(&result)?);
             } else if until_gone {
-                println!("Element \"{}\" is gone ({}ms)", text, result.elapsed_ms());
+                println!("Element {} is gone ({}ms)", label, result.elapsed_ms());
             } else {
-                println!("Element \"{}\" appeared ({}ms)", text, result.elapsed_ms());
+                println!("Element {} appeared ({}ms)", label, result.elapsed_ms());
             }
             info!(
                 event = "peek.cli.wait_completed",
-                text = text.as_str(),
+                text = text,
                 elapsed_ms = result.elapsed_ms()
             );
             Ok(())
@@ -7,7 +7,7 @@ use kild_peek_core::interact::{
 };
 use tracing::{error, info};
 
-use super::{parse_coordinates, parse_interaction_target};
+use super::{parse_click_point, parse_coordinates, parse_interaction_target};
 
 /// Parse click modifier from --right/--double flags and return (modifier, user-facing label)
 fn parse_click_modifier(matches: &ArgMatches) -> (ClickModifier, &'static str) {
@@ -41,19 +41,19 @@ pub fn handle_click_command(matches: &ArgMatches) -> Result<(), Box<dyn std::err
     }
 
     let at_str = at_str.unwrap();
-    let (x, y) = parse_coordinates(at_str)?;
+    let (x, y) = parse_click_point(at_str)?;
 
     info!(
         event = "peek.cli.interact.click_started",
-        x = x,
-        y = y,
+        x = %x,
+        y = %y,
         modifier = ?modifier,
         target = ?target,
         wait = wait_flag,
         timeout_ms = timeout_ms
     );
 
-    let mut request = ClickRequest::new(target, x, y).with_modifier(modifier);
+    let mut request = ClickRequest::at(target, x, y).with_modifier(modifier);
     if wait_flag {
         request = request.with_wait(timeout_ms);
     }
@@ -75,7 +75,7 @@ pub fn handle_click_command(matches: &ArgMatches) -> Result<(), Box<dyn std::err
                 }
             }
 
-            info!(event = "peek.cli.interact.click_completed", x = x, y = y, modifier = ?modifier);
+            info!(event = "peek.cli.interact.click_completed", x = %x, y = %y, modifier = ?modifier);
             Ok(())
         }
         Err(e) => {
@@ -1,6 +1,6 @@
 use clap::ArgMatches;
 use kild_peek_core::events;
-use kild_peek_core::interact::InteractionTarget;
+use kild_peek_core::interact::{Coordinate, InteractionTarget};
 use tracing::error;
 
 mod assert;
@@ -19,6 +19,7 @@ pub fn run_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error
         Some(("screenshot", sub_matches)) => screenshot::handle_screenshot_command(sub_matches),
         Some(("diff", sub_matches)) => diff::handle_diff_command(sub_matches),
         Some(("elements", sub_matches)) => elements::handle_elements_command(sub_matches),
+        Some(("tree", sub_matches)) => elements::handle_tree_command(sub_matches),
         Some(("find", sub_matches)) => elements::handle_find_command(sub_matches),
         Some(("wait", sub_matches)) => elements::handle_wait_command(sub_matches),
         Some(("click", sub_matches)) => interact::handle_click_command(sub_matches),
@@ -28,6 +29,8 @@ pub fn run_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error
         Some(("type", sub_matches)) => interact::handle_type_command(sub_matches),
         Some(("key", sub_matches)) => interact::handle_key_command(sub_matches),
         Some(("assert", sub_matches)) => assert::handle_assert_command(sub_matches),
+        #[cfg(feature = "ocr")]
+        Some(("text", sub_matches)) => elements::handle_text_command(sub_matches),
         _ => {
             error!(event = "peek.cli.command_unknown");
             Err("Unknown command".into())
@@ -81,8 +84,110 @@ pub(crate) fn parse_coordinates(at_str: &str) -> Result<(i32, i32), Box<dyn std:
     Ok((x, y))
 }
 
+/// Parse a click position where each axis is pixels (`100`) or a percentage
+/// of the window (`50%`), e.g. `50%,50%` or `50%,100`
+pub(crate) fn parse_click_point(
+    at_str: &str,
+) -> Result<(Coordinate, Coordinate), Box<dyn std::error::Error>> {
+    let parts: Vec<&str> = at_str.split(',').collect();
+    if parts.len() != 2 {
+        return Err(format!(
+            "--at format must be x,y (e.g., \"100,50\" or \"50%,50%\"), got: '{}'",
+            at_str
+        )
+        .into());
+    }
+    let x = parse_axis(parts[0].trim(), "x")?;
+    let y = parse_axis(parts[1].trim(), "y")?;
+    Ok((x, y))
+}
+
+fn parse_axis(s: &str, axis: &str) -> Result<Coordinate, Box<dyn std::error::Error>> {
+    if let Some(pct) = s.strip_suffix('%') {
+        let pct: f64 = pct.trim().parse().map_err(|e| {
+            format!(
+                "Invalid {} percentage '{}': {} (expected number followed by %)",
+                axis, s, e
+            )
+        })?;
+        if !(0.0..=100.0).contains(&pct) {
+            return Err(format!(
+                "Invalid {} percentage '{}': must be between 0% and 100%",
+                axis, s
+            )
+            .into());
+        }
+        return Ok(Coordinate::Percent(pct));
+    }
+    let px: i32 = s.parse().map_err(|e| {
+        format!(
+            "Invalid {} coordinate '{}': {} (expected integer or percentage)",
+            axis, s, e
+        )
+    })?;
+    Ok(Coordinate::Pixels(px))
+}
+
 #[cfg(test)]
 mod tests {
-    // Integration tests would go here
-    // Most command tests require actual windows/monitors
+    use super::*;
+
+    #[test]
+    fn test_parse_click_point_absolute() {
+        let (x, y) = parse_click_point("100,50").unwrap();
+        assert_eq!(x, Coordinate::Pixels(100));
+        assert_eq!(y, Coordinate::Pixels(50));
+    }
+
+    #[test]
+    fn test_parse_click_point_percentages() {
+        let (x, y) = parse_click_point("50%,50%").unwrap();
+        assert_eq!(x, Coordinate::Percent(50.0));
+        assert_eq!(y, Coordinate::Percent(50.0));
+
+        let (x, y) = parse_click_point(" 12.5% , 100% ").unwrap();
+        assert_eq!(x, Coordinate::Percent(12.5));
+        assert_eq!(y, Coordinate::Percent(100.0));
+
+        let (x, _) = parse_click_point("0%,10").unwrap();
+        assert_eq!(x, Coordinate::Percent(0.0));
+    }
+
+    #[test]
+    fn test_parse_click_point_mixed() {
+        let (x, y) = parse_click_point("50%,100").unwrap();
+        assert_eq!(x, Coordinate::Percent(50.0));
+        assert_eq!(y, Coordinate::Pixels(100));
+
+        let (x, y) = parse_click_point("20,75%").unwrap();
+        assert_eq!(x, Coordinate::Pixels(20));
+        assert_eq!(y, Coordinate::Percent(75.0));
+    }
+
+    #[test]
+    fn test_parse_click_point_rejects_out_of_range_percentages() {
+        for input in ["101%,50%", "50%,150%", "-1%,0"] {
+            let err = parse_click_point(input).unwrap_err().to_string();
+            assert!(err.contains("between 0% and 100%"), "{input}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_parse_click_point_rejects_malformed() {
+        assert!(parse_click_point("50%").is_err());
+        assert!(parse_click_point("50%,50%,1").is_err());
+        assert!(parse_click_point("abc%,1").is_err());
+        assert!(parse_click_point("%,1").is_err());
+        assert!(parse_click_point("1.5,2").is_err());
+    }
+
+    #[test]
+    fn test_parse_click_point_resolves_against_window() {
+        // 1280x720 window: center and mixed forms
+        let (x, y) = parse_click_point("50%,50%").unwrap();
+        assert_eq!((x.resolve(1280), y.resolve(720)), (640, 360));
+
+        let (x, y) = parse_click_point("25%,100").unwrap();
+        assert_eq!((x.resolve(1280), y.resolve(720)), (320, 100));
+    }
 }
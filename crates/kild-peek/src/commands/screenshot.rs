@@ -35,6 +35,7 @@ pub fn handle_screenshot_command(matches: &ArgMatches) -> Result<(), Box<dyn std
     // Determine image format
     let format = match format_str {
         "jpg" | "jpeg" => ImageFormat::Jpeg { quality },
+        "webp" => ImageFormat::Webp { quality },
         _ => ImageFormat::Png,
     };
 
@@ -61,7 +62,8 @@ pub fn handle_screenshot_command(matches: &ArgMatches) -> Result<(), Box<dyn std
         wait_flag,
         timeout_ms,
         crop,
-    )?;
+    )?
+    .with_quality(quality);
 
     match capture(&request) {
         Ok(result) => {
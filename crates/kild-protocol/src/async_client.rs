@@ -1,15 +1,25 @@
-//! Generic async JSONL client over any `futures::io::AsyncBufRead + AsyncWrite` pair.
+//! Generic async client over any `futures::io::AsyncRead + AsyncWrite` pair.
 //!
 //! Used by `kild-ui` (smol executor) and will be used by the TCP transport
 //! when #479 is implemented. The I/O transport is generic — callers supply the
 //! stream halves. Message types are fixed to `ClientMessage`/`DaemonMessage`.
+//! Messages are length-prefixed frames (see [`crate::frame`]).
+//!
+//! [`connect_with_retry`] and [`reattach_with_retry`] reconnect with
+//! exponential backoff when the daemon goes away (e.g. a stale-binary
+//! restart). The runtime-specific parts — opening a stream and sleeping — are
+//! supplied through [`Transport`].
+
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
 
-use futures::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+use futures::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use serde::Serialize;
 
+use crate::frame::{self, DEFAULT_MAX_MESSAGE_BYTES, FrameError, LENGTH_PREFIX_BYTES};
 use crate::{ClientMessage, DaemonMessage, IpcError};
 
-/// Async JSONL client, generic over any reader/writer pair.
+/// Async IPC client, generic over any reader/writer pair.
 ///
 /// `R` is typically `futures::io::BufReader<ReadHalf<T>>`.
 /// `W` is typically `WriteHalf<T>` or `T` directly.
@@ -23,7 +33,7 @@ pub struct AsyncIpcClient<R, W> {
 
 impl<R, W> AsyncIpcClient<R, W>
 where
-    R: AsyncBufRead + Unpin,
+    R: AsyncRead + Unpin,
     W: AsyncWrite + Unpin,
 {
     /// Wrap a reader/writer pair.
@@ -31,35 +41,40 @@ where
         Self { reader, writer }
     }
 
-    /// Write a JSONL message and flush, then read one JSONL response.
+    /// Write a message and flush, then read one response.
     ///
     /// The standard request-response pattern. Flush is mandatory before reading
     /// to ensure the peer receives the request.
     ///
     /// Converts `DaemonMessage::Error` responses into `IpcError::DaemonError`.
     pub async fn send(&mut self, msg: &ClientMessage) -> Result<DaemonMessage, IpcError> {
-        write_jsonl_flush(&mut self.writer, msg).await?;
-        let response = read_jsonl(&mut self.reader).await?;
+        write_message_flush(&mut self.writer, msg).await?;
+        let response =
+            read_message(&mut self.reader)
+                .await?
+                .ok_or_else(|| IpcError::ProtocolError {
+                    message: "Empty response from daemon".to_string(),
+                })?;
         if let DaemonMessage::Error { code, message, .. } = response {
             return Err(IpcError::DaemonError { code, message });
         }
         Ok(response)
     }
 
-    /// Write a JSONL message without flushing and without reading a response.
+    /// Write a message without flushing and without reading a response.
     ///
     /// For fire-and-forget writes (WriteStdin, ResizePty) where the caller does not
     /// wait for the daemon's Ack. The caller must call `flush()` before the
     /// connection is dropped, or buffered data will be silently lost.
     pub async fn write(&mut self, msg: &ClientMessage) -> Result<(), IpcError> {
-        write_jsonl(&mut self.writer, msg).await
+        write_message(&mut self.writer, msg).await
     }
 
-    /// Read one JSONL response from the stream.
+    /// Read one message from the stream.
     ///
     /// Returns `Ok(None)` on EOF. Used for streaming PtyOutput after Attach.
     pub async fn read_next(&mut self) -> Result<Option<DaemonMessage>, IpcError> {
-        read_jsonl_optional(&mut self.reader).await
+        read_message(&mut self.reader).await
     }
 
     /// Consume the client, returning (reader, writer) for use in split tasks.
@@ -68,125 +83,549 @@ where
     }
 }
 
-/// Serialize `msg` as compact JSON, write as a single line, no flush.
-pub async fn write_jsonl<W: AsyncWrite + Unpin, T: Serialize>(
+/// Upper bound on a single backoff delay unless overridden.
+const DEFAULT_MAX_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Backoff settings for [`connect_with_retry`] and [`reattach_with_retry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total connection attempts, including the first.
+    pub max_attempts: u32,
+    /// Delay after the first failure; doubles after each further failure.
+    pub base_delay: Duration,
+    /// Cap on the doubled delay.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            max_delay: DEFAULT_MAX_RETRY_DELAY,
+        }
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Delay after `failures` failed attempts (1-based).
+    ///
+    /// Uses "equal jitter": half of the capped exponential delay is fixed and
+    /// the other half is scaled by `jitter` (in `0.0..1.0`), so clients that
+    /// lost the same daemon don't all reconnect in lockstep.
+    pub fn backoff_delay(&self, failures: u32, jitter: f64) -> Duration {
+        let exponent = failures.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+        let half = delay / 2;
+        half + half.mul_f64(jitter.clamp(0.0, 1.0))
+    }
+}
+
+/// Progress of a retrying connection, reported so callers can show it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryState {
+    /// An attempt failed; attempt `attempt` of `max_attempts` starts after `delay`.
+    Reconnecting {
+        attempt: u32,
+        max_attempts: u32,
+        delay: Duration,
+    },
+    /// Attempt `attempt` succeeded.
+    Connected { attempt: u32 },
+    /// No attempt succeeded, or the last error was not worth retrying.
+    Failed { attempts: u32 },
+}
+
+/// Runtime-specific operations needed to reconnect.
+///
+/// Implemented by the UI on top of smol, and by fakes in tests.
+pub trait Transport {
+    type Reader: AsyncRead + Unpin;
+    type Writer: AsyncWrite + Unpin;
+
+    /// Open a fresh connection to the daemon.
+    fn connect(
+        &mut self,
+    ) -> impl Future<Output = Result<AsyncIpcClient<Self::Reader, Self::Writer>, IpcError>>;
+
+    /// Wait `delay` before the next attempt.
+    fn sleep(&mut self, delay: Duration) -> impl Future<Output = ()>;
+}
+
+/// Connect through `transport`, retrying transient failures with backoff.
+///
+/// Only "daemon is gone" failures are retried (socket missing, connection
+/// refused or reset). Errors the daemon reports, version mismatches and
+/// protocol errors are returned immediately. `on_state` is told about every
+/// retry and the final outcome.
+pub async fn connect_with_retry<T: Transport>(
+    transport: &mut T,
+    policy: &RetryPolicy,
+    on_state: impl FnMut(RetryState),
+) -> Result<AsyncIpcClient<T::Reader, T::Writer>, IpcError> {
+    retry_connect(transport, policy, None, on_state).await
+}
+
+/// Reconnect and resubscribe to a session's output after the stream dropped.
+///
+/// Like [`connect_with_retry`], but each attempt also sends `attach` and waits
+/// for its `Ack`, so the returned client streams the session again via
+/// [`AsyncIpcClient::read_next`]. A daemon error (e.g. the session no longer
+/// exists) ends the retries.
+pub async fn reattach_with_retry<T: Transport>(
+    transport: &mut T,
+    policy: &RetryPolicy,
+    attach: &ClientMessage,
+    on_state: impl FnMut(RetryState),
+) -> Result<AsyncIpcClient<T::Reader, T::Writer>, IpcError> {
+    retry_connect(transport, policy, Some(attach), on_state).await
+}
+
+async fn retry_connect<T: Transport>(
+    transport: &mut T,
+    policy: &RetryPolicy,
+    attach: Option<&ClientMessage>,
+    mut on_state: impl FnMut(RetryState),
+) -> Result<AsyncIpcClient<T::Reader, T::Writer>, IpcError> {
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        let error = match try_connect(transport, attach).await {
+            Ok(client) => {
+                on_state(RetryState::Connected { attempt });
+                return Ok(client);
+            }
+            Err(e) => e,
+        };
+
+        if !is_transient(&error) || attempt >= max_attempts {
+            on_state(RetryState::Failed { attempts: attempt });
+            return Err(error);
+        }
+
+        let delay = policy.backoff_delay(attempt, jitter());
+        attempt += 1;
+        on_state(RetryState::Reconnecting {
+            attempt,
+            max_attempts,
+            delay,
+        });
+        transport.sleep(delay).await;
+    }
+}
+
+async fn try_connect<T: Transport>(
+    transport: &mut T,
+    attach: Option<&ClientMessage>,
+) -> Result<AsyncIpcClient<T::Reader, T::Writer>, IpcError> {
+    let mut client = transport.connect().await?;
+    if let Some(attach) = attach {
+        match client.send(attach).await? {
+            DaemonMessage::Ack { .. } => {}
+            other => {
+                return Err(IpcError::ProtocolError {
+                    message: format!("unexpected response to attach: {other:?}"),
+                });
+            }
+        }
+    }
+    Ok(client)
+}
+
+/// Whether `e` means the daemon is unreachable rather than refusing us.
+fn is_transient(e: &IpcError) -> bool {
+    matches!(
+        e,
+        IpcError::NotRunning { .. }
+            | IpcError::DaemonNotRunning { .. }
+            | IpcError::ConnectionFailed(_)
+            | IpcError::Io(_)
+    )
+}
+
+/// A value in `0.0..1.0` for backoff jitter.
+///
+/// `RandomState` is randomly seeded per instance, which is plenty for
+/// spreading out reconnects without pulling in an RNG crate.
+fn jitter() -> f64 {
+    let bits = std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Serialize `msg` as compact JSON and write it as one frame, no flush.
+///
+/// Always sends uncompressed frames: the async client doesn't handshake, so
+/// the daemon never agreed to decode compressed ones.
+pub async fn write_message<W: AsyncWrite + Unpin, T: Serialize>(
     writer: &mut W,
     msg: &T,
 ) -> Result<(), IpcError> {
-    let json = serde_json::to_string(msg).map_err(|e| IpcError::ProtocolError {
+    let json = serde_json::to_vec(msg).map_err(|e| IpcError::ProtocolError {
         message: format!("serialization failed: {e}"),
     })?;
-    writer
-        .write_all(json.as_bytes())
-        .await
-        .map_err(IpcError::Io)?;
-    writer.write_all(b"\n").await.map_err(IpcError::Io)?;
+    let bytes = frame::encode_frame(&json, DEFAULT_MAX_MESSAGE_BYTES, false)?;
+    writer.write_all(&bytes).await.map_err(IpcError::Io)?;
     Ok(())
 }
 
-/// Serialize `msg`, write as a single line, and flush.
-pub async fn write_jsonl_flush<W: AsyncWrite + Unpin, T: Serialize>(
+/// Serialize `msg`, write it as one frame, and flush.
+pub async fn write_message_flush<W: AsyncWrite + Unpin, T: Serialize>(
     writer: &mut W,
     msg: &T,
 ) -> Result<(), IpcError> {
-    write_jsonl(writer, msg).await?;
+    write_message(writer, msg).await?;
     writer.flush().await.map_err(IpcError::Io)?;
     Ok(())
 }
 
-/// Read one JSONL line and parse as `DaemonMessage`.
+/// Read one frame and parse it as `DaemonMessage`.
 ///
-/// Returns `Err(IpcError::ProtocolError)` on EOF or empty line.
-async fn read_jsonl<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<DaemonMessage, IpcError> {
-    read_jsonl_optional(reader)
-        .await?
-        .ok_or_else(|| IpcError::ProtocolError {
-            message: "Empty response from daemon".to_string(),
-        })
-}
-
-/// Read one JSONL line. Returns `Ok(None)` on EOF (connection closed).
-/// Returns `Err(IpcError::ProtocolError)` on a blank line (protocol violation).
-async fn read_jsonl_optional<R: AsyncBufRead + Unpin>(
+/// Returns `Ok(None)` on EOF (connection closed). Frames over
+/// [`DEFAULT_MAX_MESSAGE_BYTES`] fail with `IpcError::MessageTooLarge`
+/// before the payload is read.
+pub async fn read_message<R: AsyncRead + Unpin>(
     reader: &mut R,
 ) -> Result<Option<DaemonMessage>, IpcError> {
-    let mut line = String::new();
-    let bytes_read = reader.read_line(&mut line).await.map_err(IpcError::Io)?;
-    if bytes_read == 0 {
+    let mut prefix = [0u8; LENGTH_PREFIX_BYTES];
+    let filled = read_full(reader, &mut prefix).await?;
+    if filled == 0 {
         return Ok(None);
     }
-    let trimmed = line.trim();
-    if trimmed.is_empty() {
-        return Err(IpcError::ProtocolError {
-            message: "Daemon sent empty line (protocol violation)".to_string(),
-        });
+    if filled < LENGTH_PREFIX_BYTES {
+        return Err(FrameError::Truncated {
+            expected: LENGTH_PREFIX_BYTES,
+            received: filled,
+        }
+        .into());
+    }
+
+    let size = frame::decode_length(prefix, DEFAULT_MAX_MESSAGE_BYTES)?;
+    let mut body = vec![0u8; size];
+    let filled = read_full(reader, &mut body).await?;
+    if filled < size {
+        return Err(FrameError::Truncated {
+            expected: size,
+            received: filled,
+        }
+        .into());
     }
-    serde_json::from_str(trimmed)
+    let payload = frame::decode_body(body, DEFAULT_MAX_MESSAGE_BYTES)?;
+
+    serde_json::from_slice(&payload)
         .map(Some)
         .map_err(|e| IpcError::ProtocolError {
             message: format!("Invalid JSON response: {e}"),
         })
 }
 
+/// Fill `buf` until it is full or the reader hits EOF. Returns bytes read.
+async fn read_full<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> Result<usize, IpcError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]).await {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(IpcError::Io(e)),
+        }
+    }
+    Ok(filled)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use futures::io::Cursor;
 
+    /// Fake transport: fails the first `failures` connects with `error`, then
+    /// hands out a connection whose reader replays `incoming`.
+    struct FakeTransport {
+        failures: u32,
+        error: fn() -> IpcError,
+        incoming: Vec<u8>,
+        connects: u32,
+        sleeps: Vec<Duration>,
+    }
+
+    impl FakeTransport {
+        fn failing(failures: u32) -> Self {
+            Self {
+                failures,
+                error: || IpcError::NotRunning {
+                    path: "/tmp/fake.sock".to_string(),
+                },
+                incoming: Vec::new(),
+                connects: 0,
+                sleeps: Vec::new(),
+            }
+        }
+    }
+
+    impl Transport for FakeTransport {
+        type Reader = Cursor<Vec<u8>>;
+        type Writer = Vec<u8>;
+
+        async fn connect(
+            &mut self,
+        ) -> Result<AsyncIpcClient<Self::Reader, Self::Writer>, IpcError> {
+            self.connects += 1;
+            if self.failures > 0 {
+                self.failures -= 1;
+                return Err((self.error)());
+            }
+            Ok(AsyncIpcClient::new(
+                Cursor::new(self.incoming.clone()),
+                Vec::new(),
+            ))
+        }
+
+        async fn sleep(&mut self, delay: Duration) {
+            self.sleeps.push(delay);
+        }
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new(5, Duration::from_millis(100))
+    }
+
+    fn attach() -> ClientMessage {
+        ClientMessage::Attach {
+            id: "r1".to_string(),
+            session_id: "s1".into(),
+            rows: 24,
+            cols: 80,
+            read_only: false,
+        }
+    }
+
     #[test]
-    fn test_write_jsonl_produces_newline_terminated_json() {
+    fn test_backoff_delay_doubles_and_caps() {
+        let policy = RetryPolicy::new(10, Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(1000));
+        // Full jitter lands on the capped exponential delay
+        assert_eq!(policy.backoff_delay(1, 1.0), Duration::from_millis(100));
+        assert_eq!(policy.backoff_delay(2, 1.0), Duration::from_millis(200));
+        assert_eq!(policy.backoff_delay(3, 1.0), Duration::from_millis(400));
+        assert_eq!(policy.backoff_delay(5, 1.0), Duration::from_millis(1000));
+        assert_eq!(policy.backoff_delay(40, 1.0), Duration::from_millis(1000));
+        // No jitter halves it
+        assert_eq!(policy.backoff_delay(2, 0.0), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_jitter_in_unit_range() {
+        for _ in 0..100 {
+            let j = jitter();
+            assert!((0.0..1.0).contains(&j), "jitter out of range: {j}");
+        }
+    }
+
+    #[test]
+    fn test_connect_with_retry_succeeds_after_failures() {
+        smol::block_on(async {
+            let mut transport = FakeTransport::failing(2);
+            let mut states = Vec::new();
+            let result = connect_with_retry(&mut transport, &policy(), |s| states.push(s)).await;
+
+            assert!(result.is_ok());
+            assert_eq!(transport.connects, 3);
+            assert_eq!(transport.sleeps.len(), 2);
+            assert!(transport.sleeps[0] >= Duration::from_millis(50));
+            assert!(transport.sleeps[0] <= Duration::from_millis(100));
+            assert!(transport.sleeps[1] >= Duration::from_millis(100));
+            assert!(transport.sleeps[1] <= Duration::from_millis(200));
+
+            assert_eq!(states.len(), 3);
+            assert!(matches!(
+                states[0],
+                RetryState::Reconnecting {
+                    attempt: 2,
+                    max_attempts: 5,
+                    ..
+                }
+            ));
+            assert!(matches!(
+                states[1],
+                RetryState::Reconnecting { attempt: 3, .. }
+            ));
+            assert_eq!(states[2], RetryState::Connected { attempt: 3 });
+        });
+    }
+
+    #[test]
+    fn test_connect_with_retry_gives_up_after_max_attempts() {
+        smol::block_on(async {
+            let mut transport = FakeTransport::failing(u32::MAX);
+            let mut states = Vec::new();
+            let result = connect_with_retry(&mut transport, &policy(), |s| states.push(s)).await;
+
+            assert!(matches!(result, Err(IpcError::NotRunning { .. })));
+            assert_eq!(transport.connects, 5);
+            assert_eq!(transport.sleeps.len(), 4);
+            assert_eq!(states.last(), Some(&RetryState::Failed { attempts: 5 }));
+        });
+    }
+
+    #[test]
+    fn test_connect_with_retry_does_not_retry_daemon_errors() {
+        smol::block_on(async {
+            let mut transport = FakeTransport::failing(1);
+            transport.error = || IpcError::VersionMismatch {
+                client_version: 3,
+                daemon_version: 0,
+                min_supported: 3,
+            };
+            let mut states = Vec::new();
+            let result = connect_with_retry(&mut transport, &policy(), |s| states.push(s)).await;
+
+            assert!(matches!(result, Err(IpcError::VersionMismatch { .. })));
+            assert_eq!(transport.connects, 1);
+            assert!(transport.sleeps.is_empty());
+            assert_eq!(states, vec![RetryState::Failed { attempts: 1 }]);
+        });
+    }
+
+    #[test]
+    fn test_reattach_with_retry_resubscribes_to_output() {
+        smol::block_on(async {
+            let mut transport = FakeTransport::failing(1);
+            transport.incoming = framed(r#"{"type":"ack","id":"r1"}"#);
+            transport.incoming.extend(framed(
+                r#"{"type":"pty_output","session_id":"s1","data":"aGk="}"#,
+            ));
+
+            let mut client = reattach_with_retry(&mut transport, &policy(), &attach(), |_| {})
+                .await
+                .unwrap();
+            assert_eq!(transport.connects, 2);
+
+            let next = client.read_next().await.unwrap();
+            assert!(matches!(next, Some(DaemonMessage::PtyOutput { .. })));
+
+            let (_, written) = client.into_parts();
+            let sent = String::from_utf8_lossy(&written);
+            assert!(sent.contains("\"type\":\"attach\""));
+        });
+    }
+
+    #[test]
+    fn test_reattach_with_retry_stops_on_daemon_error() {
+        smol::block_on(async {
+            let mut transport = FakeTransport::failing(0);
+            transport.incoming =
+                framed(r#"{"type":"error","id":"r1","code":"session_not_found","message":"gone"}"#);
+            let result = reattach_with_retry(&mut transport, &policy(), &attach(), |_| {}).await;
+
+            assert!(matches!(result, Err(IpcError::DaemonError { .. })));
+            assert_eq!(transport.connects, 1);
+            assert!(transport.sleeps.is_empty());
+        });
+    }
+
+    /// Frame a raw JSON string the way the daemon would.
+    fn framed(json: &str) -> Vec<u8> {
+        frame::encode_frame(json.as_bytes(), DEFAULT_MAX_MESSAGE_BYTES, false).unwrap()
+    }
+
+    #[test]
+    fn test_write_message_produces_length_prefixed_json() {
         smol::block_on(async {
             let mut buf = Vec::new();
             let msg = ClientMessage::Ping {
                 id: "t1".to_string(),
             };
-            write_jsonl(&mut buf, &msg).await.unwrap();
-            let s = std::str::from_utf8(&buf).unwrap();
-            assert!(s.ends_with('\n'));
+            write_message(&mut buf, &msg).await.unwrap();
+            let len = u32::from_be_bytes(buf[..LENGTH_PREFIX_BYTES].try_into().unwrap()) as usize;
+            assert_eq!(len, buf.len() - LENGTH_PREFIX_BYTES);
+            assert_eq!(buf[LENGTH_PREFIX_BYTES], 0, "async client never compresses");
+            let body = &buf[LENGTH_PREFIX_BYTES + frame::FLAG_BYTES..];
+            let s = std::str::from_utf8(body).unwrap();
             assert!(s.contains("\"type\":\"ping\""));
         });
     }
 
     #[test]
-    fn test_read_jsonl_parses_ack() {
+    fn test_read_message_parses_ack() {
         smol::block_on(async {
-            let data = b"{\"type\":\"ack\",\"id\":\"t1\"}\n";
-            let mut reader = futures::io::BufReader::new(Cursor::new(data.as_ref()));
-            let msg = read_jsonl(&mut reader).await.unwrap();
-            assert!(matches!(msg, DaemonMessage::Ack { .. }));
+            let data = framed(r#"{"type":"ack","id":"t1"}"#);
+            let mut reader = Cursor::new(data);
+            let msg = read_message(&mut reader).await.unwrap();
+            assert!(matches!(msg, Some(DaemonMessage::Ack { .. })));
         });
     }
 
+    #[cfg(feature = "compression")]
     #[test]
-    fn test_read_jsonl_optional_returns_none_on_eof() {
+    fn test_read_message_decompresses_large_frame() {
+        smol::block_on(async {
+            let data = "x".repeat(frame::COMPRESSION_THRESHOLD_BYTES * 2);
+            let json = format!(r#"{{"type":"pty_output","session_id":"s","data":"{data}"}}"#);
+            let bytes =
+                frame::encode_frame(json.as_bytes(), DEFAULT_MAX_MESSAGE_BYTES, true).unwrap();
+            assert_eq!(bytes[LENGTH_PREFIX_BYTES], frame::FLAG_ZSTD);
+
+            let mut reader = Cursor::new(bytes);
+            match read_message(&mut reader).await.unwrap() {
+                Some(DaemonMessage::PtyOutput { data: got, .. }) => assert_eq!(got, data),
+                other => panic!("expected PtyOutput, got: {:?}", other),
+            }
+        });
+    }
+
+    #[test]
+    fn test_read_message_returns_none_on_eof() {
         smol::block_on(async {
             let data: &[u8] = b"";
-            let mut reader = futures::io::BufReader::new(Cursor::new(data));
-            let result = read_jsonl_optional(&mut reader).await.unwrap();
+            let mut reader = Cursor::new(data);
+            let result = read_message(&mut reader).await.unwrap();
             assert!(result.is_none());
         });
     }
 
     #[test]
-    fn test_read_jsonl_optional_empty_line_is_protocol_error() {
+    fn test_read_message_truncated_prefix_is_protocol_error() {
         smol::block_on(async {
-            let data: &[u8] = b"\n";
-            let mut reader = futures::io::BufReader::new(Cursor::new(data));
-            let result = read_jsonl_optional(&mut reader).await;
+            let data: &[u8] = &[0, 0, 1];
+            let mut reader = Cursor::new(data);
+            let result = read_message(&mut reader).await;
             assert!(matches!(result, Err(IpcError::ProtocolError { .. })));
         });
     }
 
+    #[test]
+    fn test_read_message_oversized_frame_is_rejected() {
+        smol::block_on(async {
+            let data = u32::MAX.to_be_bytes();
+            let mut reader = Cursor::new(data);
+            let result = read_message(&mut reader).await;
+            assert!(matches!(result, Err(IpcError::MessageTooLarge { .. })));
+        });
+    }
+
     #[test]
     fn test_send_success_roundtrip() {
-        use std::io::{BufRead, Write};
+        use std::io::Write;
         smol::block_on(async {
-            let (client_stream, server_stream) = std::os::unix::net::UnixStream::pair().unwrap();
+            let (client_stream, mut server_stream) =
+                std::os::unix::net::UnixStream::pair().unwrap();
             let server = std::thread::spawn(move || {
-                let mut reader = std::io::BufReader::new(&server_stream);
-                let mut line = String::new();
-                reader.read_line(&mut line).unwrap();
-                writeln!(&server_stream, r#"{{"type":"ack","id":"t1"}}"#).unwrap();
+                frame::read_frame(&mut server_stream, DEFAULT_MAX_MESSAGE_BYTES)
+                    .unwrap()
+                    .unwrap();
+                server_stream
+                    .write_all(&framed(r#"{"type":"ack","id":"t1"}"#))
+                    .unwrap();
             });
             let async_stream = smol::Async::new(client_stream).unwrap();
             let (r, w) = smol::io::split(async_stream);
@@ -204,15 +643,16 @@ mod tests {
 
     #[test]
     fn test_send_error_response_converts_to_ipc_error() {
-        use std::io::{BufRead, Write};
+        use std::io::Write;
         smol::block_on(async {
-            let (client_stream, server_stream) = std::os::unix::net::UnixStream::pair().unwrap();
+            let (client_stream, mut server_stream) =
+                std::os::unix::net::UnixStream::pair().unwrap();
             let server = std::thread::spawn(move || {
-                let mut reader = std::io::BufReader::new(&server_stream);
-                let mut line = String::new();
-                reader.read_line(&mut line).unwrap();
+                frame::read_frame(&mut server_stream, DEFAULT_MAX_MESSAGE_BYTES)
+                    .unwrap()
+                    .unwrap();
                 let resp = r#"{"type":"error","id":"t1","code":"session_not_found","message":"no such session"}"#;
-                writeln!(&server_stream, "{resp}").unwrap();
+                server_stream.write_all(&framed(resp)).unwrap();
             });
             let async_stream = smol::Async::new(client_stream).unwrap();
             let (r, w) = smol::io::split(async_stream);
@@ -233,13 +673,11 @@ mod tests {
     #[test]
     fn test_read_next_streams_messages_and_returns_none_on_eof() {
         smol::block_on(async {
-            let data = concat!(
-                r#"{"type":"pty_output","session_id":"s1","data":"aGk="}"#,
-                "\n",
+            let mut data = framed(r#"{"type":"pty_output","session_id":"s1","data":"aGk="}"#);
+            data.extend(framed(
                 r#"{"type":"pty_output","session_id":"s1","data":"dGhlcmU="}"#,
-                "\n"
-            );
-            let reader = futures::io::BufReader::new(Cursor::new(data.as_bytes()));
+            ));
+            let reader = futures::io::BufReader::new(Cursor::new(data));
             let writer = Cursor::new(vec![]);
             let mut client = AsyncIpcClient::new(reader, writer);
 
@@ -1,29 +1,60 @@
-//! Shared synchronous JSONL IPC client for Unix socket and TCP+TLS transports.
+//! Shared synchronous IPC client for Unix socket and TCP+TLS transports.
 //!
 //! Provides `IpcConnection` for connecting to the KILD daemon and sending
 //! typed `ClientMessage`/`DaemonMessage` requests. Used by both `kild-core`
 //! (Unix + TCP/TLS) and `kild-tmux-shim` (Unix only) to avoid duplicating
-//! JSONL framing logic. TCP/TLS support requires the `tcp` Cargo feature.
+//! framing logic (see [`crate::frame`]). TCP/TLS support requires the `tcp`
+//! Cargo feature.
 
-use std::io::{BufRead, BufReader, Write};
+use std::io::Write;
 use std::os::unix::net::UnixStream;
 use std::path::Path;
 use std::time::Duration;
 
+use crate::frame::{self, Compression, DEFAULT_MAX_MESSAGE_BYTES, FrameError};
+use crate::version::{self, MIN_SUPPORTED_VERSION, PROTOCOL_VERSION};
 use crate::{ClientMessage, DaemonMessage, ErrorCode};
 
+/// Default read timeout for request-response calls.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// PID file the daemon writes next to its socket before binding it.
+const PID_FILE_NAME: &str = "daemon.pid";
+
+/// Read timeout for the version handshake.
+///
+/// Pre-v3 daemons read newline-delimited JSON and never answer a framed
+/// `Hello`, so a short timeout keeps the mismatch from looking like a hang.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Error from the shared IPC client layer.
 #[non_exhaustive]
 #[derive(Debug)]
 pub enum IpcError {
     /// Daemon socket does not exist or connection was refused.
     NotRunning { path: String },
+    /// Socket was left behind by a daemon that is no longer alive.
+    ///
+    /// The stale socket has been removed, so the daemon can be started again.
+    DaemonNotRunning { path: String, pid: u32 },
     /// Socket exists but connection failed for a non-`ConnectionRefused` reason.
     ConnectionFailed(std::io::Error),
     /// Daemon returned an explicit error response.
     DaemonError { code: ErrorCode, message: String },
-    /// Protocol-level error (serialization, empty response, invalid JSON).
+    /// Protocol-level error (serialization, empty response, invalid JSON,
+    /// truncated frame).
     ProtocolError { message: String },
+    /// A message exceeded the connection's `max_message_bytes`.
+    MessageTooLarge { size: usize, max: usize },
+    /// Client and daemon protocol versions are incompatible.
+    ///
+    /// `min_supported` is the oldest client version the daemon serves.
+    /// `daemon_version` is 0 when the daemon predates the handshake.
+    VersionMismatch {
+        client_version: u32,
+        daemon_version: u32,
+        min_supported: u32,
+    },
     /// Other I/O error.
     Io(std::io::Error),
     /// TLS configuration or handshake error (only when `tcp` feature is enabled).
@@ -37,11 +68,49 @@ impl std::fmt::Display for IpcError {
             IpcError::NotRunning { path } => {
                 write!(f, "Daemon is not running (socket not found at {})", path)
             }
+            IpcError::DaemonNotRunning { path, pid } => write!(
+                f,
+                "Daemon is not running (process {} exited; removed stale socket at {})",
+                pid, path
+            ),
             IpcError::ConnectionFailed(e) => write!(f, "Connection failed: {}", e),
             IpcError::DaemonError { code, message } => {
                 write!(f, "Daemon error [{}]: {}", code, message)
             }
             IpcError::ProtocolError { message } => write!(f, "Protocol error: {}", message),
+            IpcError::MessageTooLarge { size, max } => write!(
+                f,
+                "Message of {} bytes exceeds the {} byte limit",
+                size, max
+            ),
+            IpcError::VersionMismatch {
+                client_version,
+                daemon_version,
+                min_supported,
+            } => {
+                if client_version < min_supported {
+                    write!(
+                        f,
+                        "Protocol version mismatch: client speaks v{} but the daemon requires \
+                         at least v{}. Upgrade kild.",
+                        client_version, min_supported
+                    )
+                } else if *daemon_version == 0 {
+                    write!(
+                        f,
+                        "Protocol version mismatch: daemon predates v{} and did not answer the \
+                         handshake. Restart the daemon with 'kild daemon restart'.",
+                        MIN_SUPPORTED_VERSION
+                    )
+                } else {
+                    write!(
+                        f,
+                        "Protocol version mismatch: daemon speaks v{} but this client requires \
+                         at least v{}. Restart the daemon with 'kild daemon restart'.",
+                        daemon_version, MIN_SUPPORTED_VERSION
+                    )
+                }
+            }
             IpcError::Io(e) => write!(f, "IO error: {}", e),
             #[cfg(feature = "tcp")]
             IpcError::TlsConfig(msg) => write!(f, "TLS configuration error: {}", msg),
@@ -64,6 +133,56 @@ impl From<std::io::Error> for IpcError {
     }
 }
 
+impl From<FrameError> for IpcError {
+    fn from(e: FrameError) -> Self {
+        match e {
+            FrameError::TooLarge { size, max } => IpcError::MessageTooLarge { size, max },
+            FrameError::Truncated { .. }
+            | FrameError::InvalidFlags(_)
+            | FrameError::Decompress(_) => IpcError::ProtocolError {
+                message: e.to_string(),
+            },
+            FrameError::Io(e) => IpcError::Io(e),
+        }
+    }
+}
+
+/// Remove `socket_path` if the daemon that owned it is dead.
+///
+/// The daemon writes `daemon.pid` before binding its socket, so a PID file
+/// naming a dead process means nothing is listening and nothing is about to.
+/// Without a readable PID file there is nothing to cross-check and the socket
+/// is left alone. Returns the dead daemon's PID when the socket was removed.
+fn remove_stale_socket(socket_path: &Path) -> Option<u32> {
+    let pid_path = socket_path.with_file_name(PID_FILE_NAME);
+    let pid = std::fs::read_to_string(pid_path)
+        .ok()?
+        .trim()
+        .parse::<u32>()
+        .ok()?;
+    if pid == 0 || is_process_alive(pid) {
+        return None;
+    }
+    match std::fs::remove_file(socket_path) {
+        Ok(()) => Some(pid),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Some(pid),
+        Err(_) => None,
+    }
+}
+
+/// Check whether a process exists, via `kill(pid, 0)`.
+fn is_process_alive(pid: u32) -> bool {
+    use nix::sys::signal;
+    use nix::unistd::Pid;
+
+    match signal::kill(Pid::from_raw(pid as i32), None) {
+        Ok(()) => true,
+        // EPERM means the process exists but belongs to someone else
+        Err(nix::errno::Errno::EPERM) => true,
+        Err(_) => false,
+    }
+}
+
 /// Internal stream type — Unix socket or TLS-wrapped TCP socket.
 enum IpcStream {
     Unix(UnixStream),
@@ -83,13 +202,18 @@ impl Drop for TimeoutGuard<'_> {
     }
 }
 
-/// A synchronous JSONL connection to the KILD daemon.
+/// A synchronous framed connection to the KILD daemon.
 ///
 /// Supports both Unix socket (local) and TCP+TLS (remote) transports.
 /// The `tcp` Cargo feature must be enabled for TLS support.
 #[derive(Debug)]
 pub struct IpcConnection {
     stream: IpcStream,
+    max_message_bytes: usize,
+    /// Compression agreed in the handshake; `None` until then.
+    compression: Option<Compression>,
+    /// Token presented in `Hello` to daemons that require one.
+    auth_token: Option<String>,
 }
 
 impl std::fmt::Debug for IpcStream {
@@ -108,6 +232,10 @@ impl IpcConnection {
     /// Checks that the socket file exists, connects, and configures timeouts
     /// (30s read, 5s write). Returns `IpcError::NotRunning` if the socket
     /// doesn't exist or connection is refused.
+    ///
+    /// When the connection fails and the `daemon.pid` next to the socket names
+    /// a dead process, the socket is a leftover from a crashed daemon: it is
+    /// removed and `IpcError::DaemonNotRunning` is returned instead.
     pub fn connect(socket_path: &Path) -> Result<Self, IpcError> {
         if !socket_path.exists() {
             return Err(IpcError::NotRunning {
@@ -116,7 +244,12 @@ impl IpcConnection {
         }
 
         let stream = UnixStream::connect(socket_path).map_err(|e| {
-            if e.kind() == std::io::ErrorKind::ConnectionRefused {
+            if let Some(pid) = remove_stale_socket(socket_path) {
+                IpcError::DaemonNotRunning {
+                    path: socket_path.display().to_string(),
+                    pid,
+                }
+            } else if e.kind() == std::io::ErrorKind::ConnectionRefused {
                 IpcError::NotRunning {
                     path: socket_path.display().to_string(),
                 }
@@ -125,11 +258,14 @@ impl IpcConnection {
             }
         })?;
 
-        stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
         stream.set_write_timeout(Some(Duration::from_secs(5)))?;
 
         Ok(Self {
             stream: IpcStream::Unix(stream),
+            max_message_bytes: DEFAULT_MAX_MESSAGE_BYTES,
+            compression: None,
+            auth_token: None,
         })
     }
 
@@ -145,17 +281,46 @@ impl IpcConnection {
     pub fn connect_tls(
         addr: &str,
         verifier: std::sync::Arc<dyn rustls::client::danger::ServerCertVerifier>,
+    ) -> Result<Self, IpcError> {
+        Self::connect_tls_inner(addr, verifier, None)
+    }
+
+    /// Connect like [`connect_tls`](Self::connect_tls), presenting a client
+    /// certificate for daemons that require mutual TLS (`client_ca_path`).
+    #[cfg(feature = "tcp")]
+    pub fn connect_mtls(
+        addr: &str,
+        verifier: std::sync::Arc<dyn rustls::client::danger::ServerCertVerifier>,
+        cert_chain: Vec<rustls::pki_types::CertificateDer<'static>>,
+        key: rustls::pki_types::PrivateKeyDer<'static>,
+    ) -> Result<Self, IpcError> {
+        Self::connect_tls_inner(addr, verifier, Some((cert_chain, key)))
+    }
+
+    #[cfg(feature = "tcp")]
+    fn connect_tls_inner(
+        addr: &str,
+        verifier: std::sync::Arc<dyn rustls::client::danger::ServerCertVerifier>,
+        client_cert: Option<(
+            Vec<rustls::pki_types::CertificateDer<'static>>,
+            rustls::pki_types::PrivateKeyDer<'static>,
+        )>,
     ) -> Result<Self, IpcError> {
         use std::net::TcpStream;
         use std::sync::Arc;
 
         let provider = Arc::new(rustls::crypto::ring::default_provider());
-        let config = rustls::ClientConfig::builder_with_provider(provider)
+        let builder = rustls::ClientConfig::builder_with_provider(provider)
             .with_safe_default_protocol_versions()
             .map_err(|e| IpcError::TlsConfig(e.to_string()))?
             .dangerous()
-            .with_custom_certificate_verifier(verifier)
-            .with_no_client_auth();
+            .with_custom_certificate_verifier(verifier);
+        let config = match client_cert {
+            Some((cert_chain, key)) => builder
+                .with_client_auth_cert(cert_chain, key)
+                .map_err(|e| IpcError::TlsConfig(e.to_string()))?,
+            None => builder.with_no_client_auth(),
+        };
 
         let host = addr.split(':').next().unwrap_or(addr);
         let server_name = rustls::pki_types::ServerName::try_from(host.to_owned())
@@ -163,7 +328,7 @@ impl IpcConnection {
 
         let tcp_stream = TcpStream::connect(addr).map_err(IpcError::ConnectionFailed)?;
         tcp_stream
-            .set_read_timeout(Some(Duration::from_secs(30)))
+            .set_read_timeout(Some(READ_TIMEOUT))
             .map_err(IpcError::Io)?;
         tcp_stream
             .set_write_timeout(Some(Duration::from_secs(5)))
@@ -174,52 +339,68 @@ impl IpcConnection {
 
         Ok(Self {
             stream: IpcStream::Tls(Box::new(rustls::StreamOwned::new(conn, tcp_stream))),
+            max_message_bytes: DEFAULT_MAX_MESSAGE_BYTES,
+            compression: None,
+            auth_token: None,
         })
     }
 
+    /// Cap the size of messages sent and received on this connection.
+    ///
+    /// Defaults to [`DEFAULT_MAX_MESSAGE_BYTES`] (16 MiB). Oversized
+    /// responses fail with `IpcError::MessageTooLarge` before any payload
+    /// buffer is allocated.
+    pub fn with_max_message_bytes(mut self, max_message_bytes: usize) -> Self {
+        self.max_message_bytes = max_message_bytes;
+        self
+    }
+
+    /// Present `token` in the handshake.
+    ///
+    /// Needed for daemons whose TCP listener has `auth_token` configured;
+    /// must be set before [`handshake`](Self::handshake).
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+
     /// Send a typed request and read one typed response.
     ///
-    /// Serializes `request` as JSON, writes it as a single line, flushes,
-    /// then reads one line of JSON response. Converts `DaemonMessage::Error`
-    /// into `IpcError::DaemonError`.
+    /// Serializes `request` as JSON, writes it as one length-prefixed frame,
+    /// flushes, then reads one framed JSON response. Converts
+    /// `DaemonMessage::Error` into `IpcError::DaemonError`.
     pub fn send(&mut self, request: &ClientMessage) -> Result<DaemonMessage, IpcError> {
-        let msg = serde_json::to_string(request).map_err(|e| IpcError::ProtocolError {
+        let msg = serde_json::to_vec(request).map_err(|e| IpcError::ProtocolError {
             message: e.to_string(),
         })?;
+        let request_frame =
+            frame::encode_frame(&msg, self.max_message_bytes, self.compression.is_some())?;
 
-        let line = match &mut self.stream {
+        // read_frame consumes exactly one frame, so nothing is buffered past
+        // the response and the stream stays aligned for the next send().
+        let payload = match &mut self.stream {
             IpcStream::Unix(s) => {
-                writeln!(s, "{}", msg)?;
+                s.write_all(&request_frame)?;
                 s.flush()?;
-                // Transient BufReader — not stored as a field because KILD's
-                // request-response protocol expects exactly one response line per send().
-                // Storing it would risk buffering extra data from the stream.
-                let mut reader = BufReader::new(&*s);
-                let mut line = String::new();
-                reader.read_line(&mut line)?;
-                line
+                frame::read_frame(s, self.max_message_bytes)?
             }
             #[cfg(feature = "tcp")]
             IpcStream::Tls(s) => {
                 // StreamOwned implements Read + Write; needs &mut for both.
-                writeln!(s, "{}", msg)?;
+                s.write_all(&request_frame)?;
                 s.flush()?;
-                // Transient BufReader over &mut StreamOwned for the read half.
-                let mut reader = BufReader::new(s.by_ref());
-                let mut line = String::new();
-                reader.read_line(&mut line)?;
-                line
+                frame::read_frame(s, self.max_message_bytes)?
             }
         };
 
-        if line.is_empty() {
+        let Some(payload) = payload else {
             return Err(IpcError::ProtocolError {
                 message: "Empty response from daemon".to_string(),
             });
-        }
+        };
 
         let response: DaemonMessage =
-            serde_json::from_str(&line).map_err(|e| IpcError::ProtocolError {
+            serde_json::from_slice(&payload).map_err(|e| IpcError::ProtocolError {
                 message: format!("Invalid JSON response: {}", e),
             })?;
 
@@ -230,6 +411,87 @@ impl IpcConnection {
         Ok(response)
     }
 
+    /// Exchange `Hello`/`HelloAck` with the daemon and agree on a version.
+    ///
+    /// Must be the first message on a fresh connection. Returns the
+    /// negotiated protocol version, or `IpcError::VersionMismatch` when the
+    /// two sides can't talk. Daemons older than the handshake either drop
+    /// the connection or never answer; both surface here as a mismatch
+    /// with `daemon_version: 0`.
+    ///
+    /// Also offers every compression algorithm this build supports; large
+    /// messages in both directions are compressed if the daemon accepts.
+    pub fn handshake(&mut self) -> Result<u32, IpcError> {
+        let offered = frame::supported_compression();
+        let request = ClientMessage::Hello {
+            id: "hello".to_string(),
+            protocol_version: PROTOCOL_VERSION,
+            compression: offered.clone(),
+            auth_token: self.auth_token.clone(),
+        };
+
+        self.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+        let result = self.send(&request);
+        self.set_read_timeout(Some(READ_TIMEOUT))?;
+
+        let response = match result {
+            Ok(response) => response,
+            Err(IpcError::ProtocolError { .. })
+            | Err(IpcError::DaemonError {
+                code: ErrorCode::ProtocolError,
+                ..
+            }) => return Err(Self::pre_handshake_mismatch()),
+            Err(IpcError::Io(e))
+                if e.kind() == std::io::ErrorKind::WouldBlock
+                    || e.kind() == std::io::ErrorKind::TimedOut =>
+            {
+                return Err(Self::pre_handshake_mismatch());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let DaemonMessage::HelloAck {
+            protocol_version,
+            min_supported,
+            compression,
+            ..
+        } = response
+        else {
+            return Err(IpcError::ProtocolError {
+                message: format!("Expected hello_ack, got {:?}", response),
+            });
+        };
+
+        // Ignore a choice we never offered rather than sending frames the
+        // daemon can't decode.
+        self.compression = compression.filter(|c| offered.contains(c));
+
+        version::negotiate(
+            PROTOCOL_VERSION,
+            MIN_SUPPORTED_VERSION,
+            protocol_version,
+            min_supported,
+        )
+        .ok_or(IpcError::VersionMismatch {
+            client_version: PROTOCOL_VERSION,
+            daemon_version: protocol_version,
+            min_supported,
+        })
+    }
+
+    /// Compression agreed in the handshake, if any.
+    pub fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
+
+    fn pre_handshake_mismatch() -> IpcError {
+        IpcError::VersionMismatch {
+            client_version: PROTOCOL_VERSION,
+            daemon_version: 0,
+            min_supported: 0,
+        }
+    }
+
     /// Override the read timeout on the underlying socket.
     ///
     /// Callers like `ping_daemon()` use shorter timeouts than the default 30s.
@@ -241,6 +503,31 @@ impl IpcConnection {
         }
     }
 
+    /// Round-trip a `Ping` within `timeout`, then restore the read timeout.
+    ///
+    /// Stronger than [`is_alive`](Self::is_alive): also catches a daemon that
+    /// keeps the socket open but no longer answers. Always `false` for TLS
+    /// streams, which are never pooled.
+    pub fn ping(&mut self, timeout: Duration) -> bool {
+        let orig_timeout = match &self.stream {
+            IpcStream::Unix(s) => s.read_timeout().ok().flatten(),
+            #[cfg(feature = "tcp")]
+            IpcStream::Tls(_) => return false,
+        };
+        if self.set_read_timeout(Some(timeout)).is_err() {
+            return false;
+        }
+        let response = self.send(&ClientMessage::Ping {
+            id: "pool-ping".to_string(),
+        });
+        let restored = self.set_read_timeout(orig_timeout).is_ok();
+        restored
+            && matches!(
+                response,
+                Ok(DaemonMessage::Ack { .. } | DaemonMessage::Pong { .. })
+            )
+    }
+
     /// Check if the connection is still usable (peer hasn't closed).
     ///
     /// For Unix streams: temporarily sets a 1ms read timeout (restored via RAII
@@ -296,6 +583,22 @@ mod tests {
     use super::*;
     use std::os::unix::net::UnixListener;
 
+    /// Read one framed request on the mock daemon side.
+    fn read_request(stream: &mut UnixStream) -> String {
+        let payload = frame::read_frame(stream, DEFAULT_MAX_MESSAGE_BYTES)
+            .unwrap()
+            .unwrap();
+        String::from_utf8(payload).unwrap()
+    }
+
+    /// Write one framed response on the mock daemon side.
+    fn write_response(stream: &mut UnixStream, response: &str) {
+        let bytes =
+            frame::encode_frame(response.as_bytes(), DEFAULT_MAX_MESSAGE_BYTES, false).unwrap();
+        stream.write_all(&bytes).unwrap();
+        stream.flush().unwrap();
+    }
+
     #[test]
     fn test_connect_missing_socket() {
         let dir = tempfile::tempdir().unwrap();
@@ -309,6 +612,69 @@ mod tests {
         );
     }
 
+    /// PID of a process that has already exited and been reaped.
+    fn dead_pid() -> u32 {
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let pid = child.id();
+        child.wait().unwrap();
+        pid
+    }
+
+    /// Leave a socket file behind with nothing listening on it, as a crashed
+    /// daemon would.
+    fn leftover_socket(dir: &Path) -> std::path::PathBuf {
+        let sock_path = dir.join("daemon.sock");
+        drop(UnixListener::bind(&sock_path).unwrap());
+        assert!(sock_path.exists());
+        sock_path
+    }
+
+    #[test]
+    fn test_connect_removes_stale_socket_of_dead_daemon() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = leftover_socket(dir.path());
+        let pid = dead_pid();
+        std::fs::write(dir.path().join(PID_FILE_NAME), format!("{}\n", pid)).unwrap();
+
+        let err = IpcConnection::connect(&sock_path).unwrap_err();
+        match err {
+            IpcError::DaemonNotRunning { pid: got, .. } => assert_eq!(got, pid),
+            other => panic!("expected DaemonNotRunning, got: {:?}", other),
+        }
+        assert!(!sock_path.exists(), "stale socket should be removed");
+
+        // With the socket gone, the next attempt is a plain NotRunning
+        assert!(matches!(
+            IpcConnection::connect(&sock_path).unwrap_err(),
+            IpcError::NotRunning { .. }
+        ));
+    }
+
+    #[test]
+    fn test_connect_keeps_socket_when_daemon_pid_alive() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = leftover_socket(dir.path());
+        std::fs::write(
+            dir.path().join(PID_FILE_NAME),
+            format!("{}\n", std::process::id()),
+        )
+        .unwrap();
+
+        let err = IpcConnection::connect(&sock_path).unwrap_err();
+        assert!(matches!(err, IpcError::NotRunning { .. }));
+        assert!(sock_path.exists(), "socket of a live daemon must be kept");
+    }
+
+    #[test]
+    fn test_connect_keeps_socket_without_pid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = leftover_socket(dir.path());
+
+        let err = IpcConnection::connect(&sock_path).unwrap_err();
+        assert!(matches!(err, IpcError::NotRunning { .. }));
+        assert!(sock_path.exists());
+    }
+
     #[test]
     fn test_send_success() {
         let dir = tempfile::tempdir().unwrap();
@@ -317,13 +683,10 @@ mod tests {
 
         let handle = std::thread::spawn(move || {
             let (mut stream, _) = listener.accept().unwrap();
-            let mut reader = std::io::BufReader::new(&stream);
-            let mut line = String::new();
-            reader.read_line(&mut line).unwrap();
+            read_request(&mut stream);
 
             let response = r#"{"type":"ack","id":"test-123"}"#;
-            writeln!(stream, "{}", response).unwrap();
-            stream.flush().unwrap();
+            write_response(&mut stream, response);
         });
 
         let mut conn = IpcConnection::connect(&sock_path).unwrap();
@@ -344,13 +707,10 @@ mod tests {
 
         let handle = std::thread::spawn(move || {
             let (mut stream, _) = listener.accept().unwrap();
-            let mut reader = std::io::BufReader::new(&stream);
-            let mut line = String::new();
-            reader.read_line(&mut line).unwrap();
+            read_request(&mut stream);
 
             let response = r#"{"type":"error","id":"1","code":"session_not_found","message":"no such session"}"#;
-            writeln!(stream, "{}", response).unwrap();
-            stream.flush().unwrap();
+            write_response(&mut stream, response);
         });
 
         let mut conn = IpcConnection::connect(&sock_path).unwrap();
@@ -377,10 +737,8 @@ mod tests {
         let listener = UnixListener::bind(&sock_path).unwrap();
 
         let handle = std::thread::spawn(move || {
-            let (stream, _) = listener.accept().unwrap();
-            let mut reader = std::io::BufReader::new(&stream);
-            let mut line = String::new();
-            reader.read_line(&mut line).unwrap();
+            let (mut stream, _) = listener.accept().unwrap();
+            read_request(&mut stream);
             drop(stream);
         });
 
@@ -408,12 +766,9 @@ mod tests {
 
         let handle = std::thread::spawn(move || {
             let (mut stream, _) = listener.accept().unwrap();
-            let mut reader = std::io::BufReader::new(&stream);
-            let mut line = String::new();
-            reader.read_line(&mut line).unwrap();
+            read_request(&mut stream);
 
-            writeln!(stream, "not-json{{").unwrap();
-            stream.flush().unwrap();
+            write_response(&mut stream, "not-json{");
         });
 
         let mut conn = IpcConnection::connect(&sock_path).unwrap();
@@ -439,16 +794,11 @@ mod tests {
         let listener = UnixListener::bind(&sock_path).unwrap();
 
         let handle = std::thread::spawn(move || {
-            let (stream, _) = listener.accept().unwrap();
-            let mut writer = stream.try_clone().unwrap();
-            let mut reader = std::io::BufReader::new(stream);
+            let (mut stream, _) = listener.accept().unwrap();
             // Handle two sequential requests on the same connection
             for _ in 0..2 {
-                let mut line = String::new();
-                reader.read_line(&mut line).unwrap();
-                let response = r#"{"type":"ack","id":"1"}"#;
-                writeln!(writer, "{}", response).unwrap();
-                writer.flush().unwrap();
+                read_request(&mut stream);
+                write_response(&mut stream, r#"{"type":"ack","id":"1"}"#);
             }
         });
 
@@ -520,4 +870,236 @@ mod tests {
         };
         assert_eq!(after, before, "is_alive() should restore original timeout");
     }
+
+    /// Spawn a mock daemon that answers one request with `response`.
+    fn mock_daemon(
+        listener: UnixListener,
+        response: Option<String>,
+    ) -> std::thread::JoinHandle<String> {
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = read_request(&mut stream);
+            if let Some(response) = response {
+                write_response(&mut stream, &response);
+            }
+            request
+        })
+    }
+
+    #[test]
+    fn test_handshake_matched_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+        let response = format!(
+            r#"{{"type":"hello_ack","id":"hello","protocol_version":{},"min_supported":{}}}"#,
+            PROTOCOL_VERSION, MIN_SUPPORTED_VERSION
+        );
+        let handle = mock_daemon(listener, Some(response));
+
+        let mut conn = IpcConnection::connect(&sock_path).unwrap();
+        assert_eq!(conn.handshake().unwrap(), PROTOCOL_VERSION);
+        assert_eq!(conn.compression(), None, "daemon did not pick compression");
+
+        let request = handle.join().unwrap();
+        assert!(request.contains(r#""type":"hello""#));
+        assert!(request.contains(&format!(r#""protocol_version":{}"#, PROTOCOL_VERSION)));
+    }
+
+    #[test]
+    fn test_handshake_client_too_old() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+        let handle = mock_daemon(
+            listener,
+            Some(
+                r#"{"type":"hello_ack","id":"hello","protocol_version":1000,"min_supported":999}"#
+                    .to_string(),
+            ),
+        );
+
+        let mut conn = IpcConnection::connect(&sock_path).unwrap();
+        let err = conn.handshake().unwrap_err();
+        match &err {
+            IpcError::VersionMismatch {
+                client_version,
+                daemon_version,
+                min_supported,
+            } => {
+                assert_eq!(*client_version, PROTOCOL_VERSION);
+                assert_eq!(*daemon_version, 1000);
+                assert_eq!(*min_supported, 999);
+            }
+            other => panic!("expected VersionMismatch, got: {:?}", other),
+        }
+        assert!(err.to_string().contains("Upgrade kild"));
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_handshake_client_too_new() {
+        // A pre-handshake daemon can't parse `hello` and closes the connection
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+        let handle = mock_daemon(listener, None);
+
+        let mut conn = IpcConnection::connect(&sock_path).unwrap();
+        let err = conn.handshake().unwrap_err();
+        match &err {
+            IpcError::VersionMismatch { daemon_version, .. } => {
+                assert_eq!(*daemon_version, 0);
+            }
+            other => panic!("expected VersionMismatch, got: {:?}", other),
+        }
+        assert!(err.to_string().contains("kild daemon restart"));
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_send_response_at_size_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+        // Longer than the ping request so only the response hits the limit
+        let response = r#"{"type":"ack","id":"response-padded-past-the-request"}"#;
+        let handle = mock_daemon(listener, Some(response.to_string()));
+
+        let mut conn = IpcConnection::connect(&sock_path)
+            .unwrap()
+            .with_max_message_bytes(response.len());
+        let result = conn.send(&ClientMessage::Ping {
+            id: "1".to_string(),
+        });
+        assert!(
+            matches!(result, Ok(DaemonMessage::Ack { .. })),
+            "{:?}",
+            result
+        );
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_send_response_over_size_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+        let response = r#"{"type":"ack","id":"response-padded-past-the-request"}"#;
+        let handle = mock_daemon(listener, Some(response.to_string()));
+
+        let max = response.len() - 1;
+        let mut conn = IpcConnection::connect(&sock_path)
+            .unwrap()
+            .with_max_message_bytes(max);
+        match conn.send(&ClientMessage::Ping {
+            id: "1".to_string(),
+        }) {
+            Err(IpcError::MessageTooLarge { size, max: limit }) => {
+                assert_eq!(size, response.len());
+                assert_eq!(limit, max);
+            }
+            other => panic!("expected MessageTooLarge, got: {:?}", other),
+        }
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_send_request_over_size_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let _listener = UnixListener::bind(&sock_path).unwrap();
+
+        let mut conn = IpcConnection::connect(&sock_path)
+            .unwrap()
+            .with_max_message_bytes(8);
+        let result = conn.send(&ClientMessage::Ping {
+            id: "1".to_string(),
+        });
+        assert!(
+            matches!(result, Err(IpcError::MessageTooLarge { max: 8, .. })),
+            "{:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_send_truncated_length_prefix() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            read_request(&mut stream);
+            // Half a length prefix, then hang up
+            stream.write_all(&[0, 0]).unwrap();
+            stream.flush().unwrap();
+        });
+
+        let mut conn = IpcConnection::connect(&sock_path).unwrap();
+        match conn.send(&ClientMessage::Ping {
+            id: "1".to_string(),
+        }) {
+            Err(IpcError::ProtocolError { message }) => {
+                assert!(message.contains("truncated"), "got: {}", message);
+            }
+            other => panic!("expected ProtocolError, got: {:?}", other),
+        }
+
+        handle.join().unwrap();
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_handshake_enables_compression() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+        let large_id = "x".repeat(frame::COMPRESSION_THRESHOLD_BYTES * 2);
+        let expected_id = large_id.clone();
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let hello = read_request(&mut stream);
+            write_response(
+                &mut stream,
+                &format!(
+                    r#"{{"type":"hello_ack","id":"hello","protocol_version":{},"min_supported":{},"compression":"zstd"}}"#,
+                    PROTOCOL_VERSION, MIN_SUPPORTED_VERSION
+                ),
+            );
+
+            read_request(&mut stream);
+            let ack = format!(r#"{{"type":"ack","id":"{}"}}"#, large_id);
+            let bytes =
+                frame::encode_frame(ack.as_bytes(), DEFAULT_MAX_MESSAGE_BYTES, true).unwrap();
+            assert_eq!(bytes[frame::LENGTH_PREFIX_BYTES], frame::FLAG_ZSTD);
+            stream.write_all(&bytes).unwrap();
+            stream.flush().unwrap();
+            hello
+        });
+
+        let mut conn = IpcConnection::connect(&sock_path).unwrap();
+        conn.handshake().unwrap();
+        assert_eq!(conn.compression(), Some(Compression::Zstd));
+
+        match conn.send(&ClientMessage::Ping {
+            id: "1".to_string(),
+        }) {
+            Ok(DaemonMessage::Ack { id }) => assert_eq!(id, expected_id),
+            other => panic!("expected Ack, got: {:?}", other),
+        }
+
+        let hello = handle.join().unwrap();
+        assert!(
+            hello.contains(r#""compression":["zstd"]"#),
+            "got: {}",
+            hello
+        );
+    }
 }
@@ -0,0 +1,401 @@
+//! Length-prefixed message framing.
+//!
+//! Every message on the wire is a 4-byte big-endian frame length, a flag
+//! byte, and the body. The body is compact JSON, or zstd-compressed JSON
+//! when [`FLAG_ZSTD`] is set. Readers check the length against a maximum
+//! before allocating, so a corrupt or hostile prefix can't trigger a
+//! multi-gigabyte allocation, and decompression is capped at the same
+//! maximum so a small compressed body can't expand without bound.
+//!
+//! Senders only compress once the peer agreed to it in the handshake
+//! (see [`Compression`]). Readers always honour the flag byte.
+//!
+//! The helpers here are transport-agnostic: the sync client uses
+//! [`read_frame`] directly, async readers fill the prefix themselves and
+//! validate it with [`decode_length`] and [`decode_body`].
+
+use std::io::Read;
+
+use serde::{Deserialize, Serialize};
+
+/// Size of the length prefix in bytes.
+pub const LENGTH_PREFIX_BYTES: usize = 4;
+
+/// Size of the flag byte that precedes every body.
+pub const FLAG_BYTES: usize = 1;
+
+/// Default maximum payload size: 16 MiB.
+pub const DEFAULT_MAX_MESSAGE_BYTES: usize = 16 * 1024 * 1024;
+
+/// Payloads smaller than this are always sent uncompressed.
+pub const COMPRESSION_THRESHOLD_BYTES: usize = 4 * 1024;
+
+/// Flag bit: the body is zstd-compressed.
+pub const FLAG_ZSTD: u8 = 0b0000_0001;
+
+#[cfg(feature = "compression")]
+const ZSTD_LEVEL: i32 = 3;
+
+/// Compression algorithm negotiated in the `Hello`/`HelloAck` handshake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Compression {
+    Zstd,
+}
+
+/// Compression algorithms this build can encode and decode.
+pub fn supported_compression() -> Vec<Compression> {
+    if cfg!(feature = "compression") {
+        vec![Compression::Zstd]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Pick the compression to use given what the peer offered.
+pub fn negotiate_compression(offered: &[Compression]) -> Option<Compression> {
+    supported_compression()
+        .into_iter()
+        .find(|c| offered.contains(c))
+}
+
+/// Error decoding or encoding a frame.
+#[derive(Debug)]
+pub enum FrameError {
+    /// Payload length exceeds the configured maximum.
+    TooLarge { size: usize, max: usize },
+    /// Stream ended partway through a length prefix or payload.
+    Truncated { expected: usize, received: usize },
+    /// Frame has no flag byte or sets flag bits this build doesn't know.
+    InvalidFlags(Option<u8>),
+    /// Compressed body could not be decompressed within the size limit.
+    Decompress(String),
+    /// Underlying I/O error.
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for FrameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameError::TooLarge { size, max } => {
+                write!(
+                    f,
+                    "message of {} bytes exceeds the {} byte limit",
+                    size, max
+                )
+            }
+            FrameError::Truncated { expected, received } => write!(
+                f,
+                "truncated frame: expected {} bytes, received {}",
+                expected, received
+            ),
+            FrameError::InvalidFlags(None) => write!(f, "empty frame: missing flag byte"),
+            FrameError::InvalidFlags(Some(flags)) => {
+                write!(f, "unsupported frame flags: {:#04x}", flags)
+            }
+            FrameError::Decompress(msg) => write!(f, "failed to decompress frame: {}", msg),
+            FrameError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for FrameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FrameError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for FrameError {
+    fn from(e: std::io::Error) -> Self {
+        FrameError::Io(e)
+    }
+}
+
+/// Frame `payload`, rejecting payloads over `max`.
+///
+/// With `compress` set, payloads of at least [`COMPRESSION_THRESHOLD_BYTES`]
+/// are zstd-compressed when that makes them smaller. Only pass `true` after
+/// the peer agreed to compression in the handshake.
+pub fn encode_frame(payload: &[u8], max: usize, compress: bool) -> Result<Vec<u8>, FrameError> {
+    let too_large = || FrameError::TooLarge {
+        size: payload.len(),
+        max,
+    };
+    if payload.len() > max {
+        return Err(too_large());
+    }
+
+    let (flags, body) = match compress_body(payload, compress) {
+        Some(compressed) => (FLAG_ZSTD, std::borrow::Cow::Owned(compressed)),
+        None => (0, std::borrow::Cow::Borrowed(payload)),
+    };
+    let len = u32::try_from(FLAG_BYTES + body.len()).map_err(|_| too_large())?;
+
+    let mut frame = Vec::with_capacity(LENGTH_PREFIX_BYTES + FLAG_BYTES + body.len());
+    frame.extend_from_slice(&len.to_be_bytes());
+    frame.push(flags);
+    frame.extend_from_slice(&body);
+    Ok(frame)
+}
+
+/// Decode a length prefix, rejecting frames whose body could exceed `max`.
+///
+/// Returns the number of bytes that follow the prefix (flag byte included).
+pub fn decode_length(prefix: [u8; LENGTH_PREFIX_BYTES], max: usize) -> Result<usize, FrameError> {
+    let len = u32::from_be_bytes(prefix) as usize;
+    if len < FLAG_BYTES {
+        return Err(FrameError::InvalidFlags(None));
+    }
+    if len - FLAG_BYTES > max {
+        return Err(FrameError::TooLarge {
+            size: len - FLAG_BYTES,
+            max,
+        });
+    }
+    Ok(len)
+}
+
+/// Decode the bytes after a length prefix into the JSON payload.
+///
+/// Decompression stops at `max` bytes of output.
+pub fn decode_body(mut frame: Vec<u8>, max: usize) -> Result<Vec<u8>, FrameError> {
+    let Some(&flags) = frame.first() else {
+        return Err(FrameError::InvalidFlags(None));
+    };
+    match flags {
+        0 => {
+            frame.remove(0);
+            Ok(frame)
+        }
+        FLAG_ZSTD => decompress_body(&frame[FLAG_BYTES..], max),
+        other => Err(FrameError::InvalidFlags(Some(other))),
+    }
+}
+
+/// Read one frame from a blocking reader and return its decoded payload.
+///
+/// Returns `Ok(None)` on a clean EOF before any prefix byte. EOF anywhere
+/// else is `FrameError::Truncated`.
+pub fn read_frame<R: Read>(reader: &mut R, max: usize) -> Result<Option<Vec<u8>>, FrameError> {
+    let mut prefix = [0u8; LENGTH_PREFIX_BYTES];
+    let filled = read_full(reader, &mut prefix)?;
+    if filled == 0 {
+        return Ok(None);
+    }
+    if filled < LENGTH_PREFIX_BYTES {
+        return Err(FrameError::Truncated {
+            expected: LENGTH_PREFIX_BYTES,
+            received: filled,
+        });
+    }
+
+    let size = decode_length(prefix, max)?;
+    let mut frame = vec![0u8; size];
+    let filled = read_full(reader, &mut frame)?;
+    if filled < size {
+        return Err(FrameError::Truncated {
+            expected: size,
+            received: filled,
+        });
+    }
+    decode_body(frame, max).map(Some)
+}
+
+/// Fill `buf` until it is full or the reader hits EOF. Returns bytes read.
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<usize, FrameError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(FrameError::Io(e)),
+        }
+    }
+    Ok(filled)
+}
+
+/// Compress `payload` if asked to, it is over the threshold, and it shrinks.
+#[cfg(feature = "compression")]
+fn compress_body(payload: &[u8], compress: bool) -> Option<Vec<u8>> {
+    if !compress || payload.len() < COMPRESSION_THRESHOLD_BYTES {
+        return None;
+    }
+    zstd::bulk::compress(payload, ZSTD_LEVEL)
+        .ok()
+        .filter(|compressed| compressed.len() < payload.len())
+}
+
+#[cfg(not(feature = "compression"))]
+fn compress_body(_payload: &[u8], _compress: bool) -> Option<Vec<u8>> {
+    None
+}
+
+#[cfg(feature = "compression")]
+fn decompress_body(body: &[u8], max: usize) -> Result<Vec<u8>, FrameError> {
+    // bulk::decompress fails rather than growing past `max`
+    zstd::bulk::decompress(body, max).map_err(|e| FrameError::Decompress(e.to_string()))
+}
+
+#[cfg(not(feature = "compression"))]
+fn decompress_body(_body: &[u8], _max: usize) -> Result<Vec<u8>, FrameError> {
+    Err(FrameError::InvalidFlags(Some(FLAG_ZSTD)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX: usize = DEFAULT_MAX_MESSAGE_BYTES;
+
+    #[test]
+    fn test_roundtrip() {
+        let frame = encode_frame(b"{\"type\":\"ping\"}", MAX, false).unwrap();
+        assert_eq!(&frame[..LENGTH_PREFIX_BYTES], &16u32.to_be_bytes());
+        assert_eq!(frame[LENGTH_PREFIX_BYTES], 0);
+
+        let mut reader = frame.as_slice();
+        let payload = read_frame(&mut reader, MAX).unwrap();
+        assert_eq!(payload.as_deref(), Some(b"{\"type\":\"ping\"}".as_slice()));
+        assert!(read_frame(&mut reader, MAX).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_message_at_limit_is_accepted() {
+        let payload = vec![b'x'; 64];
+        let frame = encode_frame(&payload, 64, false).unwrap();
+        let decoded = read_frame(&mut frame.as_slice(), 64).unwrap().unwrap();
+        assert_eq!(decoded.len(), 64);
+    }
+
+    #[test]
+    fn test_message_over_limit_is_rejected() {
+        assert!(matches!(
+            encode_frame(&[0u8; 65], 64, false),
+            Err(FrameError::TooLarge { size: 65, max: 64 })
+        ));
+
+        let frame = encode_frame(&[0u8; 65], 1024, false).unwrap();
+        assert!(matches!(
+            read_frame(&mut frame.as_slice(), 64),
+            Err(FrameError::TooLarge { size: 65, max: 64 })
+        ));
+    }
+
+    #[test]
+    fn test_huge_prefix_does_not_allocate() {
+        // u32::MAX length with no payload — must fail before allocating
+        let frame = u32::MAX.to_be_bytes();
+        assert!(matches!(
+            read_frame(&mut frame.as_slice(), MAX),
+            Err(FrameError::TooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn test_truncated_length_prefix() {
+        let data = [0u8, 0];
+        assert!(matches!(
+            read_frame(&mut data.as_slice(), MAX),
+            Err(FrameError::Truncated {
+                expected: 4,
+                received: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_truncated_payload() {
+        let mut frame = encode_frame(b"hello", MAX, false).unwrap();
+        frame.truncate(LENGTH_PREFIX_BYTES + 3);
+        assert!(matches!(
+            read_frame(&mut frame.as_slice(), MAX),
+            Err(FrameError::Truncated {
+                expected: 6,
+                received: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_empty_frame_is_rejected() {
+        let frame = 0u32.to_be_bytes();
+        assert!(matches!(
+            read_frame(&mut frame.as_slice(), MAX),
+            Err(FrameError::InvalidFlags(None))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_flags_are_rejected() {
+        let mut frame = encode_frame(b"{}", MAX, false).unwrap();
+        frame[LENGTH_PREFIX_BYTES] = 0x80;
+        assert!(matches!(
+            read_frame(&mut frame.as_slice(), MAX),
+            Err(FrameError::InvalidFlags(Some(0x80)))
+        ));
+    }
+
+    #[test]
+    fn test_small_message_stays_uncompressed() {
+        let payload = vec![b'a'; COMPRESSION_THRESHOLD_BYTES - 1];
+        let frame = encode_frame(&payload, MAX, true).unwrap();
+        assert_eq!(frame[LENGTH_PREFIX_BYTES], 0);
+        assert_eq!(
+            frame.len(),
+            LENGTH_PREFIX_BYTES + FLAG_BYTES + payload.len()
+        );
+    }
+
+    #[test]
+    fn test_compression_not_applied_unless_negotiated() {
+        let payload = vec![b'a'; COMPRESSION_THRESHOLD_BYTES * 4];
+        let frame = encode_frame(&payload, MAX, false).unwrap();
+        assert_eq!(frame[LENGTH_PREFIX_BYTES], 0);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_large_message_roundtrips_compressed() {
+        let payload = serde_json::to_vec(&serde_json::json!({
+            "type": "pty_output",
+            "session_id": "proj/branch",
+            "data": "line of scrollback\n".repeat(2048),
+        }))
+        .unwrap();
+        assert!(payload.len() >= COMPRESSION_THRESHOLD_BYTES);
+
+        let frame = encode_frame(&payload, MAX, true).unwrap();
+        assert_eq!(frame[LENGTH_PREFIX_BYTES], FLAG_ZSTD);
+        assert!(frame.len() < payload.len() / 4);
+
+        let decoded = read_frame(&mut frame.as_slice(), MAX).unwrap().unwrap();
+        assert_eq!(decoded, payload);
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_decompression_is_bounded() {
+        // 1 MiB of zeros compresses to a few bytes; a 64 KiB reader must refuse it
+        let bomb = encode_frame(&vec![0u8; 1024 * 1024], MAX, true).unwrap();
+        assert_eq!(bomb[LENGTH_PREFIX_BYTES], FLAG_ZSTD);
+        assert!(matches!(
+            read_frame(&mut bomb.as_slice(), 64 * 1024),
+            Err(FrameError::Decompress(_))
+        ));
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_negotiate_compression() {
+        assert_eq!(
+            negotiate_compression(&[Compression::Zstd]),
+            Some(Compression::Zstd)
+        );
+        assert_eq!(negotiate_compression(&[]), None);
+    }
+}
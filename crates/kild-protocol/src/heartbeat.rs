@@ -0,0 +1,278 @@
+//! Client-side heartbeats for long-lived streams.
+//!
+//! NAT gateways and firewalls drop TCP connections that stay quiet for too
+//! long, and a dropped connection often looks idle rather than closed. A
+//! [`Heartbeat`] tracks when the stream last carried traffic: once it has been
+//! idle for `interval`, the client sends a `Ping`; if nothing arrives within
+//! `timeout` after that, the connection is considered dead. Any inbound
+//! message counts as traffic, so busy streams never send a ping.
+//!
+//! [`read_message_with_heartbeat`] drives the state machine around
+//! [`read_message`]; the timer is supplied by the caller so this crate stays
+//! runtime-agnostic.
+
+use std::io;
+use std::pin::pin;
+use std::time::{Duration, Instant};
+
+use futures::future::{Either, select};
+use futures::io::{AsyncRead, AsyncWrite};
+
+use crate::async_client::{read_message, write_message_flush};
+use crate::{ClientMessage, DaemonMessage, IpcError};
+
+/// Request ID used for heartbeat pings, so their replies can be told apart
+/// from replies to real requests.
+pub const HEARTBEAT_ID: &str = "heartbeat";
+
+/// What the stream owner should do next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeartbeatAction {
+    /// Nothing due; check again after this long unless traffic arrives first.
+    Wait(Duration),
+    /// The stream has been idle for a full interval.
+    SendPing,
+    /// A ping went unanswered for the whole timeout.
+    Dead,
+}
+
+/// Idle/ping bookkeeping for one connection. Time is passed in, never read,
+/// so the state machine can be tested without sleeping.
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    interval: Duration,
+    timeout: Duration,
+    last_activity: Instant,
+    ping_sent_at: Option<Instant>,
+}
+
+impl Heartbeat {
+    /// Start tracking a connection that was last active at `now`.
+    pub fn new(interval: Duration, timeout: Duration, now: Instant) -> Self {
+        Self {
+            interval,
+            timeout,
+            last_activity: now,
+            ping_sent_at: None,
+        }
+    }
+
+    /// A message arrived: the connection is alive and no longer idle.
+    pub fn record_activity(&mut self, now: Instant) {
+        self.last_activity = now;
+        self.ping_sent_at = None;
+    }
+
+    /// A ping was written at `now`; a reply is due within the timeout.
+    pub fn record_ping(&mut self, now: Instant) {
+        self.ping_sent_at = Some(now);
+    }
+
+    /// Decide what is due at `now`.
+    pub fn poll(&self, now: Instant) -> HeartbeatAction {
+        let (deadline, due) = match self.ping_sent_at {
+            Some(sent) => (sent + self.timeout, HeartbeatAction::Dead),
+            None => (
+                self.last_activity + self.interval,
+                HeartbeatAction::SendPing,
+            ),
+        };
+        match deadline.checked_duration_since(now) {
+            Some(wait) if !wait.is_zero() => HeartbeatAction::Wait(wait),
+            _ => due,
+        }
+    }
+}
+
+/// Read the next message, pinging the daemon while the stream is idle.
+///
+/// Pings go out on `writer`, which must be the write half of the same
+/// connection as `reader`. Their replies (`Pong`, or `Ack` from daemons
+/// before protocol v8) are consumed here and never returned. Fails with
+/// `IpcError::ConnectionFailed` (`TimedOut`) when a ping goes unanswered.
+///
+/// `sleep` builds the runtime's timer future, e.g. `smol::Timer::after`.
+/// The pending read is kept across timer wakeups, so no partially read
+/// frame is lost.
+pub async fn read_message_with_heartbeat<R, W, S, F>(
+    reader: &mut R,
+    writer: &mut W,
+    heartbeat: &mut Heartbeat,
+    mut sleep: S,
+) -> Result<Option<DaemonMessage>, IpcError>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+    S: FnMut(Duration) -> F,
+    F: Future<Output = ()>,
+{
+    loop {
+        let mut read = pin!(read_message(reader));
+        let result = loop {
+            match heartbeat.poll(Instant::now()) {
+                HeartbeatAction::Dead => {
+                    return Err(IpcError::ConnectionFailed(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "daemon did not answer heartbeat ping",
+                    )));
+                }
+                HeartbeatAction::SendPing => {
+                    let ping = ClientMessage::Ping {
+                        id: HEARTBEAT_ID.to_string(),
+                    };
+                    write_message_flush(writer, &ping).await?;
+                    heartbeat.record_ping(Instant::now());
+                }
+                HeartbeatAction::Wait(delay) => {
+                    let timer = pin!(sleep(delay));
+                    if let Either::Left((result, _)) = select(read.as_mut(), timer).await {
+                        break result;
+                    }
+                }
+            }
+        };
+
+        if let Ok(Some(_)) = &result {
+            heartbeat.record_activity(Instant::now());
+        }
+        match result {
+            Ok(Some(DaemonMessage::Pong { id } | DaemonMessage::Ack { id }))
+                if id == HEARTBEAT_ID =>
+            {
+                continue;
+            }
+            other => return other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_secs(30);
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    #[test]
+    fn test_poll_waits_until_interval_elapses() {
+        let start = Instant::now();
+        let hb = Heartbeat::new(INTERVAL, TIMEOUT, start);
+        assert_eq!(
+            hb.poll(start + Duration::from_secs(5)),
+            HeartbeatAction::Wait(Duration::from_secs(25))
+        );
+        assert_eq!(hb.poll(start + INTERVAL), HeartbeatAction::SendPing);
+    }
+
+    #[test]
+    fn test_activity_postpones_ping() {
+        let start = Instant::now();
+        let mut hb = Heartbeat::new(INTERVAL, TIMEOUT, start);
+        // Busy stream: traffic every 20s never reaches the 30s interval
+        for tick in 1..10 {
+            let now = start + Duration::from_secs(20 * tick);
+            assert!(matches!(hb.poll(now), HeartbeatAction::Wait(_)));
+            hb.record_activity(now);
+        }
+    }
+
+    #[test]
+    fn test_unanswered_ping_is_dead_after_timeout() {
+        let start = Instant::now();
+        let mut hb = Heartbeat::new(INTERVAL, TIMEOUT, start);
+        let pinged = start + INTERVAL;
+        hb.record_ping(pinged);
+        assert_eq!(
+            hb.poll(pinged + Duration::from_secs(4)),
+            HeartbeatAction::Wait(Duration::from_secs(6))
+        );
+        assert_eq!(hb.poll(pinged + TIMEOUT), HeartbeatAction::Dead);
+    }
+
+    #[test]
+    fn test_reply_after_ping_resets_to_idle_wait() {
+        let start = Instant::now();
+        let mut hb = Heartbeat::new(INTERVAL, TIMEOUT, start);
+        let pinged = start + INTERVAL;
+        hb.record_ping(pinged);
+        hb.record_activity(pinged + Duration::from_secs(1));
+        assert_eq!(
+            hb.poll(pinged + TIMEOUT),
+            HeartbeatAction::Wait(INTERVAL - TIMEOUT + Duration::from_secs(1))
+        );
+    }
+
+    /// A connection the peer silently dropped: reads never complete.
+    struct Silent;
+
+    impl AsyncRead for Silent {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            _buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            Poll::Pending
+        }
+    }
+
+    async fn timer(delay: Duration) {
+        smol::Timer::after(delay).await;
+    }
+
+    fn framed(msg: &DaemonMessage) -> Vec<u8> {
+        let json = serde_json::to_vec(msg).unwrap();
+        crate::frame::encode_frame(&json, crate::frame::DEFAULT_MAX_MESSAGE_BYTES, false).unwrap()
+    }
+
+    #[test]
+    fn test_idle_stream_sends_ping_then_fails_without_pong() {
+        smol::block_on(async {
+            let mut writer = Vec::new();
+            let mut hb = Heartbeat::new(
+                Duration::from_millis(20),
+                Duration::from_millis(20),
+                Instant::now(),
+            );
+            let result =
+                read_message_with_heartbeat(&mut Silent, &mut writer, &mut hb, timer).await;
+
+            match result {
+                Err(IpcError::ConnectionFailed(e)) => {
+                    assert_eq!(e.kind(), io::ErrorKind::TimedOut)
+                }
+                other => panic!("expected heartbeat timeout, got {other:?}"),
+            }
+            let sent = String::from_utf8_lossy(&writer);
+            assert!(sent.contains(r#""type":"ping""#));
+            assert!(sent.contains(HEARTBEAT_ID));
+        });
+    }
+
+    #[test]
+    fn test_busy_stream_never_pings_and_skips_pongs() {
+        smol::block_on(async {
+            let mut input = framed(&DaemonMessage::Pong {
+                id: HEARTBEAT_ID.to_string(),
+            });
+            input.extend(framed(&DaemonMessage::Ack {
+                id: "attach-1".to_string(),
+            }));
+            let mut reader = futures::io::Cursor::new(input);
+            let mut writer = Vec::new();
+            let mut hb = Heartbeat::new(Duration::from_secs(60), TIMEOUT, Instant::now());
+
+            let msg = read_message_with_heartbeat(&mut reader, &mut writer, &mut hb, timer)
+                .await
+                .unwrap();
+            assert!(matches!(msg, Some(DaemonMessage::Ack { id }) if id == "attach-1"));
+            let eof = read_message_with_heartbeat(&mut reader, &mut writer, &mut hb, timer)
+                .await
+                .unwrap();
+            assert!(eof.is_none());
+            assert!(writer.is_empty());
+        });
+    }
+}
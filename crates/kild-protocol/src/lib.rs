@@ -3,17 +3,25 @@ pub mod async_client;
 #[cfg(unix)]
 pub mod client;
 pub mod env_cleanup;
+pub mod frame;
+#[cfg(unix)]
+pub mod heartbeat;
 mod messages;
 #[cfg(unix)]
 pub mod pool;
 mod types;
+pub mod version;
 
 #[cfg(unix)]
-pub use async_client::AsyncIpcClient;
+pub use async_client::{AsyncIpcClient, RetryPolicy, RetryState, Transport};
 #[cfg(unix)]
 pub use client::{IpcConnection, IpcError};
+pub use frame::Compression;
+#[cfg(unix)]
+pub use heartbeat::Heartbeat;
 pub use messages::{ClientMessage, DaemonMessage, ErrorCode};
 pub use types::{
     AgentMode, AgentStatus, BranchName, DaemonSessionStatus, ForgeType, OpenMode, ProjectId,
     RuntimeMode, SessionId, SessionStatus,
 };
+pub use version::{MIN_SUPPORTED_VERSION, PROTOCOL_VERSION};
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::frame::Compression;
 use crate::types::{DaemonSessionStatus, ProjectId, SessionId};
 
 /// Error codes returned by the daemon in error responses.
@@ -27,6 +28,7 @@ pub enum ErrorCode {
     SerializationError,
     Base64DecodeError,
     SessionError,
+    Unauthorized,
     #[serde(other)]
     Unknown,
 }
@@ -57,6 +59,7 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::SerializationError => write!(f, "serialization_error"),
             ErrorCode::Base64DecodeError => write!(f, "base64_decode_error"),
             ErrorCode::SessionError => write!(f, "session_error"),
+            ErrorCode::Unauthorized => write!(f, "unauthorized"),
             ErrorCode::Unknown => write!(f, "unknown"),
         }
     }
@@ -107,6 +110,10 @@ pub enum ClientMessage {
         session_id: SessionId,
         rows: u16,
         cols: u16,
+        /// Watch output only: the daemon leaves the PTY size alone and
+        /// rejects input and resizes from this connection.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        read_only: bool,
     },
 
     #[serde(rename = "detach")]
@@ -157,6 +164,43 @@ pub enum ClientMessage {
 
     #[serde(rename = "ping")]
     Ping { id: String },
+
+    /// Hand off to a replacement daemon: stop accepting connections, wait
+    /// up to `drain_timeout_ms` for attached streams to finish, save session
+    /// metadata, and exit without stopping sessions. PTYs close with the
+    /// daemon, so only processes that survive the hangup are reconciled.
+    #[serde(rename = "handoff")]
+    Handoff { id: String, drain_timeout_ms: u64 },
+
+    /// Ask the daemon for runtime counters. Answered with `DaemonMessage::Stats`.
+    #[serde(rename = "stats")]
+    Stats { id: String },
+
+    /// Ask for the session's write lock. Only the holder among attached
+    /// clients may send input or resize; answered with `WriteGranted` or
+    /// `WriteDenied`.
+    #[serde(rename = "request_write")]
+    RequestWrite { id: String, session_id: SessionId },
+
+    /// Give up the session's write lock so another attached client can take it.
+    #[serde(rename = "release_write")]
+    ReleaseWrite { id: String, session_id: SessionId },
+
+    /// Version handshake, sent as the first message on a connection.
+    ///
+    /// See [`crate::version`] for the negotiation rules.
+    #[serde(rename = "hello")]
+    Hello {
+        id: String,
+        protocol_version: u32,
+        /// Compression algorithms the client can decode, in preference order.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        compression: Vec<Compression>,
+        /// Shared secret for daemons that require one on their TCP listener.
+        /// Ignored on the Unix socket.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        auth_token: Option<String>,
+    },
 }
 
 /// Daemon -> Client response and streaming messages.
@@ -181,6 +225,16 @@ pub enum DaemonMessage {
         data: String,
     },
 
+    /// Replay of buffered PTY output, sent once on attach before live
+    /// `pty_output` begins. No `id`.
+    #[serde(rename = "scrollback")]
+    Scrollback {
+        session_id: SessionId,
+        /// Base64-encoded tail of recent PTY output, bounded by the daemon's
+        /// `scrollback_buffer_size`.
+        data: String,
+    },
+
     /// Notification that PTY output was dropped for a slow client.
     #[serde(rename = "pty_output_dropped")]
     PtyOutputDropped {
@@ -225,6 +279,50 @@ pub enum DaemonMessage {
 
     #[serde(rename = "ack")]
     Ack { id: String },
+
+    /// Reply to `Ping` from daemons at protocol v8+. Older daemons answer
+    /// with `Ack`, so clients accept either.
+    #[serde(rename = "pong")]
+    Pong { id: String },
+
+    /// Reply to `Hello` with the daemon's version and the oldest client
+    /// version it still serves.
+    #[serde(rename = "hello_ack")]
+    HelloAck {
+        id: String,
+        protocol_version: u32,
+        min_supported: u32,
+        /// Compression the daemon picked from the client's offer. Both sides
+        /// may compress large messages once this is set.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        compression: Option<Compression>,
+    },
+
+    /// Reply to `Stats` with a snapshot of daemon counters.
+    #[serde(rename = "stats")]
+    Stats {
+        id: String,
+        uptime_secs: u64,
+        /// Sessions known to the daemon, running or stopped.
+        session_count: usize,
+        active_ptys: usize,
+        /// PTY output bytes delivered to attached clients since start.
+        bytes_streamed: u64,
+        connected_clients: usize,
+    },
+
+    /// Reply to `RequestWrite`: this connection now holds the write lock.
+    #[serde(rename = "write_granted")]
+    WriteGranted { id: String, session_id: SessionId },
+
+    /// Reply to `RequestWrite` when another client holds the write lock, or
+    /// this connection isn't attached writable.
+    #[serde(rename = "write_denied")]
+    WriteDenied {
+        id: String,
+        session_id: SessionId,
+        message: String,
+    },
 }
 
 fn default_rows() -> u16 {
@@ -250,7 +348,12 @@ impl ClientMessage {
             | ClientMessage::GetSession { id, .. }
             | ClientMessage::ReadScrollback { id, .. }
             | ClientMessage::DaemonStop { id, .. }
-            | ClientMessage::Ping { id, .. } => id,
+            | ClientMessage::Ping { id, .. }
+            | ClientMessage::Handoff { id, .. }
+            | ClientMessage::Stats { id, .. }
+            | ClientMessage::RequestWrite { id, .. }
+            | ClientMessage::ReleaseWrite { id, .. }
+            | ClientMessage::Hello { id, .. } => id,
         }
     }
 }
@@ -288,12 +391,36 @@ mod tests {
             session_id: SessionId::new("myapp_feature-auth"),
             rows: 24,
             cols: 80,
+            read_only: false,
         };
         let json = serde_json::to_string(&msg).unwrap();
+        // Writable attaches stay byte-identical for older daemons
+        assert!(!json.contains("read_only"));
         let parsed: ClientMessage = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.id(), "req-002");
     }
 
+    #[test]
+    fn test_client_message_attach_read_only_roundtrip() {
+        let msg = ClientMessage::Attach {
+            id: "req-002".to_string(),
+            session_id: SessionId::new("myapp_feature-auth"),
+            rows: 24,
+            cols: 80,
+            read_only: true,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""read_only":true"#));
+        let parsed: ClientMessage = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            ClientMessage::Attach {
+                read_only: true,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn test_client_message_write_stdin_roundtrip() {
         let msg = ClientMessage::WriteStdin {
@@ -336,6 +463,7 @@ mod tests {
                 session_id: SessionId::new("s"),
                 rows: 24,
                 cols: 80,
+                read_only: false,
             },
             ClientMessage::Detach {
                 id: "3".to_string(),
@@ -379,6 +507,27 @@ mod tests {
             ClientMessage::Ping {
                 id: "11".to_string(),
             },
+            ClientMessage::Stats {
+                id: "11b".to_string(),
+            },
+            ClientMessage::Handoff {
+                id: "11c".to_string(),
+                drain_timeout_ms: 5000,
+            },
+            ClientMessage::RequestWrite {
+                id: "11d".to_string(),
+                session_id: SessionId::new("s"),
+            },
+            ClientMessage::ReleaseWrite {
+                id: "11e".to_string(),
+                session_id: SessionId::new("s"),
+            },
+            ClientMessage::Hello {
+                id: "12".to_string(),
+                protocol_version: 2,
+                compression: vec![Compression::Zstd],
+                auth_token: Some("secret".to_string()),
+            },
         ];
 
         for msg in messages {
@@ -431,6 +580,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_daemon_message_scrollback_roundtrip() {
+        let msg = DaemonMessage::Scrollback {
+            session_id: SessionId::new("myapp_feature-auth"),
+            data: "dG90YWwgNDgK".to_string(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"scrollback"#));
+        let parsed: DaemonMessage = serde_json::from_str(&json).unwrap();
+        if let DaemonMessage::Scrollback { session_id, data } = parsed {
+            assert_eq!(&*session_id, "myapp_feature-auth");
+            assert_eq!(data, "dG90YWwgNDgK");
+        } else {
+            panic!("wrong variant");
+        }
+    }
+
     #[test]
     fn test_daemon_message_error_roundtrip() {
         let msg = DaemonMessage::Error {
@@ -457,6 +623,10 @@ mod tests {
             ErrorCode::SessionNotFound
         );
         assert_eq!(ErrorCode::from_code("pty_error"), ErrorCode::PtyError);
+        assert_eq!(
+            ErrorCode::from_code("unauthorized"),
+            ErrorCode::Unauthorized
+        );
         assert_eq!(
             ErrorCode::from_code("something_totally_new"),
             ErrorCode::Unknown
@@ -497,6 +667,112 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_daemon_message_write_denied_roundtrip() {
+        let msg = DaemonMessage::WriteDenied {
+            id: "w-1".to_string(),
+            session_id: SessionId::new("s"),
+            message: "write lock held".to_string(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"write_denied""#));
+        let parsed: DaemonMessage = serde_json::from_str(&json).unwrap();
+        assert!(
+            matches!(parsed, DaemonMessage::WriteDenied { message, .. } if message == "write lock held")
+        );
+    }
+
+    #[test]
+    fn test_daemon_message_pong_roundtrip() {
+        let msg = DaemonMessage::Pong {
+            id: "hb-1".to_string(),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert_eq!(json, r#"{"type":"pong","id":"hb-1"}"#);
+        let parsed: DaemonMessage = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, DaemonMessage::Pong { id } if id == "hb-1"));
+    }
+
+    #[test]
+    fn test_hello_without_compression_offer() {
+        let json = r#"{"type":"hello","id":"h","protocol_version":4}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            ClientMessage::Hello { compression, auth_token, .. }
+                if compression.is_empty() && auth_token.is_none()
+        ));
+
+        let ack = DaemonMessage::HelloAck {
+            id: "h".to_string(),
+            protocol_version: 4,
+            min_supported: 4,
+            compression: None,
+        };
+        let json = serde_json::to_string(&ack).unwrap();
+        assert!(!json.contains("compression"));
+    }
+
+    #[test]
+    fn test_daemon_message_hello_ack_roundtrip() {
+        let msg = DaemonMessage::HelloAck {
+            id: "hello".to_string(),
+            protocol_version: 2,
+            min_supported: 1,
+            compression: Some(Compression::Zstd),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"hello_ack"#));
+        assert!(json.contains(r#""compression":"zstd"#));
+        let parsed: DaemonMessage = serde_json::from_str(&json).unwrap();
+        if let DaemonMessage::HelloAck {
+            id,
+            protocol_version,
+            min_supported,
+            compression,
+        } = parsed
+        {
+            assert_eq!(id, "hello");
+            assert_eq!(protocol_version, 2);
+            assert_eq!(min_supported, 1);
+            assert_eq!(compression, Some(Compression::Zstd));
+        } else {
+            panic!("wrong variant");
+        }
+    }
+
+    #[test]
+    fn test_daemon_message_stats_roundtrip() {
+        let msg = DaemonMessage::Stats {
+            id: "s".to_string(),
+            uptime_secs: 42,
+            session_count: 3,
+            active_ptys: 2,
+            bytes_streamed: 1024,
+            connected_clients: 1,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"stats"#));
+        let parsed: DaemonMessage = serde_json::from_str(&json).unwrap();
+        if let DaemonMessage::Stats {
+            uptime_secs,
+            session_count,
+            active_ptys,
+            bytes_streamed,
+            connected_clients,
+            ..
+        } = parsed
+        {
+            assert_eq!(uptime_secs, 42);
+            assert_eq!(session_count, 3);
+            assert_eq!(active_ptys, 2);
+            assert_eq!(bytes_streamed, 1024);
+            assert_eq!(connected_clients, 1);
+        } else {
+            panic!("wrong variant");
+        }
+    }
+
     #[test]
     fn test_daemon_message_session_event_roundtrip() {
         let msg = DaemonMessage::SessionEvent {
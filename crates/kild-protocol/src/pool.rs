@@ -5,7 +5,9 @@
 //! like keystroke forwarding in the tmux shim.
 //!
 //! Both `kild-core` and `kild-tmux-shim` delegate to this module instead of
-//! maintaining their own connection caches.
+//! maintaining their own connection caches. [`take()`] and [`release()`] use
+//! a thread-local [`Pool`], which health-checks a cached connection with a
+//! `Ping` before handing it out and drops connections idle for too long.
 //!
 //! **Single-path invariant:** the pool does not track which socket path a
 //! cached connection belongs to. Each process is expected to call [`take()`]
@@ -14,18 +16,102 @@
 
 use std::cell::RefCell;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 use crate::{IpcConnection, IpcError};
 
+/// Cached connections idle longer than this are dropped instead of reused.
+pub const DEFAULT_MAX_IDLE: Duration = Duration::from_secs(60);
+
+/// How long a health-check `Ping` may take before the connection counts as dead.
+pub const DEFAULT_PING_TIMEOUT: Duration = Duration::from_millis(500);
+
 thread_local! {
-    static CACHED: RefCell<Option<IpcConnection>> = const { RefCell::new(None) };
+    static CACHED: RefCell<Pool> = const { RefCell::new(Pool::new()) };
+}
+
+/// Single-slot connection cache with health checks.
+#[derive(Debug)]
+pub struct Pool {
+    idle: Option<(IpcConnection, Instant)>,
+    max_idle: Duration,
+    ping_timeout: Duration,
+}
+
+impl Default for Pool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pool {
+    pub const fn new() -> Self {
+        Self {
+            idle: None,
+            max_idle: DEFAULT_MAX_IDLE,
+            ping_timeout: DEFAULT_PING_TIMEOUT,
+        }
+    }
+
+    pub fn with_max_idle(mut self, max_idle: Duration) -> Self {
+        self.max_idle = max_idle;
+        self
+    }
+
+    pub fn with_ping_timeout(mut self, ping_timeout: Duration) -> Self {
+        self.ping_timeout = ping_timeout;
+        self
+    }
+
+    /// Hand out a healthy connection, connecting to `socket_path` if needed.
+    ///
+    /// A cached connection is evicted instead of returned when it has been
+    /// idle longer than `max_idle`, its peer has closed, or it doesn't answer
+    /// a `Ping` within `ping_timeout`. Returns `(connection, reused)`.
+    pub fn checkout(&mut self, socket_path: &Path) -> Result<(IpcConnection, bool), IpcError> {
+        if let Some(conn) = self.take_healthy() {
+            return Ok((conn, true));
+        }
+        let conn = IpcConnection::connect(socket_path)?;
+        Ok((conn, false))
+    }
+
+    /// Cache `conn` for the next checkout, replacing any cached connection.
+    ///
+    /// Returns `false` (and drops `conn`) if its peer has already closed.
+    pub fn checkin(&mut self, conn: IpcConnection) -> bool {
+        if !conn.is_alive() {
+            return false;
+        }
+        self.idle = Some((conn, Instant::now()));
+        true
+    }
+
+    /// Whether no connection is cached.
+    pub fn is_empty(&self) -> bool {
+        self.idle.is_none()
+    }
+
+    fn take_healthy(&mut self) -> Option<IpcConnection> {
+        let (mut conn, idle_since) = self.idle.take()?;
+        if idle_since.elapsed() > self.max_idle {
+            return None;
+        }
+        // The cheap probe catches a closed peer without a round trip
+        if !conn.is_alive() || !conn.ping(self.ping_timeout) {
+            return None;
+        }
+        Some(conn)
+    }
 }
 
 /// Take a connection from the pool, or create a fresh one.
 ///
 /// Three possible paths:
-/// 1. A cached connection exists and is still alive — returns it (reused).
-/// 2. A cached connection exists but is dead — evicts it and connects fresh.
+/// 1. A cached connection exists and passes [`Pool::checkout`]'s health
+///    check — returns it (reused).
+/// 2. A cached connection exists but is dead, unresponsive or idle too
+///    long — evicts it and connects fresh.
 /// 3. The pool is empty — connects to `socket_path`.
 ///
 /// Returns `(connection, reused)` where `reused` is `true` for path 1 and
@@ -35,16 +121,7 @@ thread_local! {
 /// The returned connection has exclusive ownership — call [`release()`]
 /// after successful use to make it available for the next caller.
 pub fn take(socket_path: &Path) -> Result<(IpcConnection, bool), IpcError> {
-    CACHED.with(|cell| {
-        let mut cached = cell.borrow_mut();
-        if let Some(conn) = cached.take()
-            && conn.is_alive()
-        {
-            return Ok((conn, true));
-        }
-        let conn = IpcConnection::connect(socket_path)?;
-        Ok((conn, false))
-    })
+    CACHED.with(|cell| cell.borrow_mut().checkout(socket_path))
 }
 
 /// Return a connection to the pool for reuse.
@@ -54,20 +131,30 @@ pub fn take(socket_path: &Path) -> Result<(IpcConnection, bool), IpcError> {
 /// dropped due to a failed liveness check. Callers use this to emit their
 /// own tracing events.
 pub fn release(conn: IpcConnection) -> bool {
-    if !conn.is_alive() {
-        return false;
-    }
-    CACHED.with(|cell| {
-        *cell.borrow_mut() = Some(conn);
-    });
-    true
+    CACHED.with(|cell| cell.borrow_mut().checkin(conn))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::io::{BufRead, Write};
-    use std::os::unix::net::UnixListener;
+    use crate::frame;
+    use std::io::Write;
+    use std::os::unix::net::{UnixListener, UnixStream};
+
+    /// Answer `count` requests on `stream` with an `Ack`.
+    fn answer(stream: &mut UnixStream, count: usize) {
+        for _ in 0..count {
+            frame::read_frame(stream, frame::DEFAULT_MAX_MESSAGE_BYTES).unwrap();
+            let response = frame::encode_frame(
+                br#"{"type":"ack","id":"1"}"#,
+                frame::DEFAULT_MAX_MESSAGE_BYTES,
+                false,
+            )
+            .unwrap();
+            stream.write_all(&response).unwrap();
+            stream.flush().unwrap();
+        }
+    }
 
     #[test]
     fn test_take_creates_fresh_connection() {
@@ -101,16 +188,9 @@ mod tests {
 
         // Accept on server side and send a response so we can verify the connection works
         let handle = std::thread::spawn(move || {
-            let (stream, _) = listener.accept().unwrap();
-            let mut writer = stream.try_clone().unwrap();
-            let mut reader = std::io::BufReader::new(stream);
-            // Handle two requests (one per take)
-            for _ in 0..2 {
-                let mut line = String::new();
-                reader.read_line(&mut line).unwrap();
-                writeln!(writer, r#"{{"type":"ack","id":"1"}}"#).unwrap();
-                writer.flush().unwrap();
-            }
+            let (mut stream, _) = listener.accept().unwrap();
+            // Request, health-check ping on the second take, request
+            answer(&mut stream, 3);
         });
 
         let request = crate::ClientMessage::Ping {
@@ -147,7 +227,7 @@ mod tests {
         // Verify pool is empty
         CACHED.with(|cell| {
             assert!(
-                cell.borrow().is_none(),
+                cell.borrow().is_empty(),
                 "Dead connection should not be cached"
             );
         });
@@ -178,4 +258,79 @@ mod tests {
         assert!(!reused, "Stale connection should be evicted, not reused");
         assert!(conn.is_alive());
     }
+
+    #[test]
+    fn test_checkout_reuses_connection_that_answers_ping() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            answer(&mut stream, 1);
+            stream
+        });
+
+        let mut pool = Pool::new();
+        let (conn, _) = pool.checkout(&sock_path).unwrap();
+        assert!(pool.checkin(conn));
+
+        let (_conn, reused) = pool.checkout(&sock_path).unwrap();
+        assert!(reused, "Healthy connection should be reused");
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_checkout_evicts_dead_injected_connection() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+
+        let mut pool = Pool::new();
+        let conn = IpcConnection::connect(&sock_path).unwrap();
+        let (server_stream, _) = listener.accept().unwrap();
+        assert!(pool.checkin(conn));
+
+        // Daemon side goes away after the connection was pooled
+        drop(server_stream);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+
+        let (conn, reused) = pool.checkout(&sock_path).unwrap();
+        assert!(!reused, "Dead connection should be evicted, not returned");
+        assert!(conn.is_alive());
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn test_checkout_evicts_connection_that_ignores_ping() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+
+        let mut pool = Pool::new().with_ping_timeout(Duration::from_millis(50));
+        let conn = IpcConnection::connect(&sock_path).unwrap();
+        // Socket stays open but nothing ever answers: a hung daemon
+        let (_server_stream, _) = listener.accept().unwrap();
+        assert!(pool.checkin(conn));
+
+        let (_conn, reused) = pool.checkout(&sock_path).unwrap();
+        assert!(!reused, "Unresponsive connection should be evicted");
+    }
+
+    #[test]
+    fn test_checkout_evicts_connection_idle_too_long() {
+        let dir = tempfile::tempdir().unwrap();
+        let sock_path = dir.path().join("test.sock");
+        let listener = UnixListener::bind(&sock_path).unwrap();
+
+        let mut pool = Pool::new().with_max_idle(Duration::ZERO);
+        let conn = IpcConnection::connect(&sock_path).unwrap();
+        let (_server_stream, _) = listener.accept().unwrap();
+        assert!(pool.checkin(conn));
+        std::thread::sleep(std::time::Duration::from_millis(5));
+
+        // No ping is answered here, so a reuse would have failed the health check;
+        // eviction happens before any ping is sent
+        let (_conn, reused) = pool.checkout(&sock_path).unwrap();
+        assert!(!reused, "Idle connection should be evicted");
+    }
 }
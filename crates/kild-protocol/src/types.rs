@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "lowercase")]
 pub enum ForgeType {
     GitHub,
+    GitLab,
 }
 
 impl ForgeType {
@@ -16,6 +17,7 @@ impl ForgeType {
     pub fn as_str(&self) -> &'static str {
         match self {
             ForgeType::GitHub => "github",
+            ForgeType::GitLab => "gitlab",
         }
     }
 }
@@ -32,7 +34,8 @@ impl std::str::FromStr for ForgeType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "github" => Ok(ForgeType::GitHub),
-            _ => Err(format!("Unknown forge '{}'. Supported: github", s)),
+            "gitlab" => Ok(ForgeType::GitLab),
+            _ => Err(format!("Unknown forge '{}'. Supported: github, gitlab", s)),
         }
     }
 }
@@ -161,7 +164,7 @@ pub struct DaemonSessionStatus {
 /// (inferred from metrics). `AgentStatus` is explicitly reported by the agent
 /// via hooks, giving real-time insight into what the agent is doing.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AgentStatus {
     Working,
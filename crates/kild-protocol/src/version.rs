@@ -0,0 +1,159 @@
+//! IPC protocol versioning.
+//!
+//! Clients open a connection with `ClientMessage::Hello` carrying their
+//! [`PROTOCOL_VERSION`]. The daemon answers with `DaemonMessage::HelloAck`
+//! carrying its own version and the oldest client version it still serves.
+//! Both sides then agree on the lower of the two versions, or the client
+//! gives up with `IpcError::VersionMismatch`.
+
+/// Protocol version spoken by this build.
+///
+/// Bump when a message is added, removed, or changes shape, and append a
+/// row to [`COMPATIBILITY`].
+pub const PROTOCOL_VERSION: u32 = 10;
+
+/// Oldest peer version this build interoperates with.
+///
+/// Version 5 replays scrollback as `DaemonMessage::Scrollback`, which v4
+/// clients can't parse.
+pub const MIN_SUPPORTED_VERSION: u32 = 5;
+
+/// One row of the protocol compatibility table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolRevision {
+    pub version: u32,
+    /// Oldest peer version a build speaking `version` interoperates with.
+    pub min_supported: u32,
+    pub summary: &'static str,
+}
+
+/// Every protocol version, oldest first.
+pub const COMPATIBILITY: &[ProtocolRevision] = &[
+    ProtocolRevision {
+        version: 1,
+        min_supported: 1,
+        summary: "Unversioned JSONL protocol",
+    },
+    ProtocolRevision {
+        version: 2,
+        min_supported: 2,
+        summary: "Hello/HelloAck version handshake",
+    },
+    ProtocolRevision {
+        version: 3,
+        min_supported: 3,
+        summary: "Length-prefixed framing replaces newline-delimited JSON",
+    },
+    ProtocolRevision {
+        version: 4,
+        min_supported: 4,
+        summary: "Per-frame flag byte; optional zstd compression negotiated in Hello",
+    },
+    ProtocolRevision {
+        version: 5,
+        min_supported: 5,
+        summary: "Attach replays scrollback as a dedicated scrollback message",
+    },
+    ProtocolRevision {
+        version: 6,
+        min_supported: 5,
+        summary: "Stats request for daemon introspection",
+    },
+    ProtocolRevision {
+        version: 7,
+        min_supported: 5,
+        summary: "Handoff request for restarting onto a new daemon binary",
+    },
+    ProtocolRevision {
+        version: 8,
+        min_supported: 5,
+        summary: "Pong reply to Ping for connection heartbeats",
+    },
+    ProtocolRevision {
+        version: 9,
+        min_supported: 5,
+        summary: "Read-only attach for watching output without sending input",
+    },
+    ProtocolRevision {
+        version: 10,
+        min_supported: 5,
+        summary: "Write lock arbitration between attached clients",
+    },
+];
+
+/// Look up the compatibility row for a protocol version.
+pub fn revision(version: u32) -> Option<&'static ProtocolRevision> {
+    COMPATIBILITY.iter().find(|r| r.version == version)
+}
+
+/// Agree on a protocol version between a client and a daemon.
+///
+/// Returns the version both sides speak, or `None` if either side is older
+/// than the other's minimum.
+pub fn negotiate(
+    client_version: u32,
+    client_min_supported: u32,
+    daemon_version: u32,
+    daemon_min_supported: u32,
+) -> Option<u32> {
+    if client_version < daemon_min_supported || daemon_version < client_min_supported {
+        return None;
+    }
+    Some(client_version.min(daemon_version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constants_match_latest_revision() {
+        let latest = COMPATIBILITY.last().unwrap();
+        assert_eq!(latest.version, PROTOCOL_VERSION);
+        assert_eq!(latest.min_supported, MIN_SUPPORTED_VERSION);
+        assert_eq!(revision(PROTOCOL_VERSION), Some(latest));
+        assert_eq!(revision(PROTOCOL_VERSION + 1), None);
+    }
+
+    #[test]
+    fn test_compatibility_table_is_ordered() {
+        for pair in COMPATIBILITY.windows(2) {
+            assert_eq!(pair[1].version, pair[0].version + 1);
+            assert!(pair[1].min_supported >= pair[0].min_supported);
+        }
+        for r in COMPATIBILITY {
+            assert!(r.min_supported <= r.version);
+        }
+    }
+
+    #[test]
+    fn test_negotiate_matched_versions() {
+        assert_eq!(
+            negotiate(
+                PROTOCOL_VERSION,
+                MIN_SUPPORTED_VERSION,
+                PROTOCOL_VERSION,
+                MIN_SUPPORTED_VERSION
+            ),
+            Some(PROTOCOL_VERSION)
+        );
+    }
+
+    #[test]
+    fn test_negotiate_client_too_old() {
+        // Daemon v5 dropped support for anything below v4
+        assert_eq!(negotiate(3, 2, 5, 4), None);
+    }
+
+    #[test]
+    fn test_negotiate_client_too_new() {
+        // Client v5 needs at least v4; daemon only speaks v3
+        assert_eq!(negotiate(5, 4, 3, 2), None);
+    }
+
+    #[test]
+    fn test_negotiate_picks_lower_version() {
+        assert_eq!(negotiate(4, 2, 3, 2), Some(3));
+        assert_eq!(negotiate(3, 2, 4, 2), Some(3));
+    }
+}
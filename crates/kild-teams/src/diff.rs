@@ -0,0 +1,281 @@
+//! Diff successive team snapshots into [`TeamEvent`]s.
+//!
+//! Pure functions so the event sequence can be tested without a watcher.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::types::{TeamEvent, TeamMember, TeamState};
+
+/// Teams keyed by name, ordered so diffs are deterministic.
+pub type TeamSnapshot = BTreeMap<String, TeamState>;
+
+/// Build a snapshot from [`crate::scanner::scan_teams`] output.
+pub fn snapshot(teams: Vec<(String, TeamState)>) -> TeamSnapshot {
+    teams.into_iter().collect()
+}
+
+/// Compute the events that turn `previous` into `current`.
+///
+/// Teams are visited in name order. A team that vanished is paired with a
+/// new team holding the same (non-empty) set of member names and reported
+/// as [`TeamEvent::TeamRenamed`], followed by any status changes under the
+/// new name. Other vanished teams emit `TeammateRemoved` for each member
+/// and other new teams emit `TeammateAdded`. Within a team, removals come
+/// first, then additions, then status changes.
+pub fn diff_teams(previous: &TeamSnapshot, current: &TeamSnapshot) -> Vec<TeamEvent> {
+    let mut events = Vec::new();
+
+    let mut added: Vec<&str> = current
+        .keys()
+        .filter(|name| !previous.contains_key(*name))
+        .map(String::as_str)
+        .collect();
+    let removed: Vec<&str> = previous
+        .keys()
+        .filter(|name| !current.contains_key(*name))
+        .map(String::as_str)
+        .collect();
+
+    let mut renamed_to: HashMap<&str, &str> = HashMap::new();
+    for old_name in &removed {
+        let old_names = member_names(&previous[*old_name]);
+        if old_names.is_empty() {
+            continue;
+        }
+        if let Some(pos) = added
+            .iter()
+            .position(|new_name| member_names(&current[*new_name]) == old_names)
+        {
+            renamed_to.insert(*old_name, added.remove(pos));
+        }
+    }
+
+    for old_name in &removed {
+        let old = &previous[*old_name];
+        match renamed_to.get(old_name) {
+            Some(new_name) => {
+                events.push(TeamEvent::TeamRenamed {
+                    old_name: old_name.to_string(),
+                    new_name: new_name.to_string(),
+                });
+                diff_members(
+                    new_name,
+                    &old.members,
+                    &current[*new_name].members,
+                    &mut events,
+                );
+            }
+            None => diff_members(old_name, &old.members, &[], &mut events),
+        }
+    }
+
+    for (name, state) in current {
+        if let Some(old) = previous.get(name) {
+            diff_members(name, &old.members, &state.members, &mut events);
+        } else if added.contains(&name.as_str()) {
+            diff_members(name, &[], &state.members, &mut events);
+        }
+    }
+
+    events
+}
+
+fn member_names(state: &TeamState) -> BTreeSet<&str> {
+    state.members.iter().map(|m| m.name.as_str()).collect()
+}
+
+fn diff_members(
+    team_name: &str,
+    previous: &[TeamMember],
+    current: &[TeamMember],
+    events: &mut Vec<TeamEvent>,
+) {
+    let find = |members: &[TeamMember], name: &str| -> Option<usize> {
+        members.iter().position(|m| m.name == name)
+    };
+
+    for old in previous {
+        if find(current, &old.name).is_none() {
+            events.push(TeamEvent::TeammateRemoved {
+                team_name: team_name.to_string(),
+                name: old.name.clone(),
+            });
+        }
+    }
+
+    for new in current {
+        if find(previous, &new.name).is_none() {
+            events.push(TeamEvent::TeammateAdded {
+                team_name: team_name.to_string(),
+                member: new.clone(),
+            });
+        }
+    }
+
+    for new in current {
+        if let Some(i) = find(previous, &new.name)
+            && previous[i].is_active != new.is_active
+        {
+            events.push(TeamEvent::TeammateStatusChanged {
+                team_name: team_name.to_string(),
+                name: new.name.clone(),
+                is_active: new.is_active,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TeamColor;
+
+    fn member(name: &str, is_active: bool) -> TeamMember {
+        TeamMember {
+            name: name.to_string(),
+            agent_id: Some(format!("{name}@test-team")),
+            agent_type: None,
+            color: TeamColor::Blue,
+            pane_id: String::new(),
+            daemon_session_id: None,
+            is_active,
+        }
+    }
+
+    fn team(name: &str, members: &[(&str, bool)]) -> (String, TeamState) {
+        let state = TeamState {
+            team_name: name.to_string(),
+            kild_session_id: None,
+            members: members.iter().map(|(n, a)| member(n, *a)).collect(),
+        };
+        (name.to_string(), state)
+    }
+
+    fn removed(team_name: &str, name: &str) -> TeamEvent {
+        TeamEvent::TeammateRemoved {
+            team_name: team_name.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    fn added(team_name: &str, name: &str, is_active: bool) -> TeamEvent {
+        TeamEvent::TeammateAdded {
+            team_name: team_name.to_string(),
+            member: member(name, is_active),
+        }
+    }
+
+    fn status(team_name: &str, name: &str, is_active: bool) -> TeamEvent {
+        TeamEvent::TeammateStatusChanged {
+            team_name: team_name.to_string(),
+            name: name.to_string(),
+            is_active,
+        }
+    }
+
+    #[test]
+    fn test_identical_snapshots_emit_nothing() {
+        let s = snapshot(vec![team("alpha", &[("lead", true), ("dev", true)])]);
+        assert!(diff_teams(&s, &s.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_member_changes_within_team() {
+        let before = snapshot(vec![team(
+            "alpha",
+            &[("lead", true), ("dev", true), ("tester", true)],
+        )]);
+        let after = snapshot(vec![team(
+            "alpha",
+            &[("lead", true), ("dev", false), ("researcher", true)],
+        )]);
+
+        assert_eq!(
+            diff_teams(&before, &after),
+            vec![
+                removed("alpha", "tester"),
+                added("alpha", "researcher", true),
+                status("alpha", "dev", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_new_and_deleted_teams() {
+        let before = snapshot(vec![team("old", &[("a", true)])]);
+        let after = snapshot(vec![team("new", &[("b", true), ("c", false)])]);
+
+        assert_eq!(
+            diff_teams(&before, &after),
+            vec![
+                removed("old", "a"),
+                added("new", "b", true),
+                added("new", "c", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rename_keeps_members_and_reports_status_under_new_name() {
+        let before = snapshot(vec![team("draft", &[("lead", true), ("dev", true)])]);
+        let after = snapshot(vec![team("final", &[("dev", false), ("lead", true)])]);
+
+        assert_eq!(
+            diff_teams(&before, &after),
+            vec![
+                TeamEvent::TeamRenamed {
+                    old_name: "draft".to_string(),
+                    new_name: "final".to_string(),
+                },
+                status("final", "dev", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rename_requires_matching_members() {
+        let before = snapshot(vec![team("draft", &[("lead", true)])]);
+        let after = snapshot(vec![team("final", &[("lead", true), ("dev", true)])]);
+
+        assert_eq!(
+            diff_teams(&before, &after),
+            vec![
+                removed("draft", "lead"),
+                added("final", "lead", true),
+                added("final", "dev", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_teams_are_never_renames() {
+        let before = snapshot(vec![team("a", &[])]);
+        let after = snapshot(vec![team("b", &[])]);
+        assert!(diff_teams(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn test_successive_snapshots_sequence() {
+        let s0 = TeamSnapshot::new();
+        let s1 = snapshot(vec![team("alpha", &[("lead", true)])]);
+        let s2 = snapshot(vec![team("alpha", &[("lead", true), ("dev", true)])]);
+        let s3 = snapshot(vec![team("beta", &[("lead", true), ("dev", true)])]);
+
+        let events: Vec<_> = [(&s0, &s1), (&s1, &s2), (&s2, &s3)]
+            .into_iter()
+            .flat_map(|(a, b)| diff_teams(a, b))
+            .collect();
+
+        assert_eq!(
+            events,
+            vec![
+                added("alpha", "lead", true),
+                added("alpha", "dev", true),
+                TeamEvent::TeamRenamed {
+                    old_name: "alpha".to_string(),
+                    new_name: "beta".to_string(),
+                },
+            ]
+        );
+    }
+}
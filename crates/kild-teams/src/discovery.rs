@@ -9,7 +9,7 @@ use std::path::PathBuf;
 use kild_paths::KildPaths;
 
 use crate::errors::TeamsError;
-use crate::parser;
+use crate::mapper;
 use crate::types::{TeamColor, TeamMember};
 
 /// Default shim state directory: `~/.kild/shim/`.
@@ -54,10 +54,14 @@ fn pane_display_name(title: &str, pane_id: &str, is_leader: bool) -> String {
 }
 
 /// Discover teammates from a specific registry path (for testing).
+///
+/// Reads through [`mapper::read_registry`], so a registry caught mid-write
+/// is retried under its sibling `panes.lock`.
 pub fn discover_teammates_from_path(
     registry_path: &std::path::Path,
 ) -> Result<Option<Vec<TeamMember>>, TeamsError> {
-    let registry = match parser::parse_shim_registry(registry_path)? {
+    let lock_path = registry_path.with_file_name("panes.lock");
+    let registry = match mapper::read_registry(registry_path, &lock_path)? {
         Some(r) => r,
         None => return Ok(None),
     };
@@ -7,4 +7,22 @@ pub enum TeamsError {
 
     #[error("JSON parse error: {0}")]
     Json(#[from] serde_json::Error),
+
+    #[error("Invalid team name '{0}'")]
+    InvalidTeamName(String),
+
+    #[error("Team '{0}' already exists")]
+    TeamExists(String),
+
+    #[error("Duplicate team member '{0}'")]
+    DuplicateMember(String),
+
+    #[error("Unknown team color '{0}'")]
+    UnknownColor(String),
+
+    #[error("Got {colors} colors for {members} members")]
+    ColorCountMismatch { members: usize, colors: usize },
+
+    #[error("Cannot determine home directory")]
+    HomeDirUnavailable,
 }
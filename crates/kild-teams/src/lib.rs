@@ -1,10 +1,11 @@
 //! Agent team discovery and state management for KILD UI.
 //!
 //! Standalone library that understands Claude Code agent teams.
-//! Reads team configs from `~/.claude/teams/` and cross-references
+//! Reads and creates team configs in `~/.claude/teams/` and cross-references
 //! with shim pane registries at `~/.kild/shim/` to map teammates
 //! to daemon PTY sessions.
 
+pub mod diff;
 pub mod discovery;
 pub mod errors;
 pub mod mapper;
@@ -12,6 +13,7 @@ pub mod parser;
 pub mod scanner;
 pub mod types;
 pub mod watcher;
+pub mod writer;
 
 pub use errors::TeamsError;
 pub use types::*;
@@ -3,18 +3,36 @@
 //! Enriches `TeamMember` entries with `daemon_session_id` by reading
 //! the shim pane registry for a given kild session.
 
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
 
 use kild_paths::KildPaths;
+use nix::fcntl::{Flock, FlockArg};
 
 use crate::errors::TeamsError;
-use crate::parser;
+use crate::parser::{self, ShimPaneRegistry};
 use crate::types::TeamState;
 
-/// Resolve a shim pane registry path for a session.
-fn shim_registry_path(session_id: &str) -> Option<PathBuf> {
+/// How many times to try reading a registry before giving up.
+const READ_ATTEMPTS: u32 = 4;
+
+/// Delay before the first retry; doubles on each further attempt.
+const RETRY_BACKOFF: Duration = Duration::from_millis(20);
+
+/// Last registry that parsed cleanly, keyed by registry path.
+///
+/// Returned when a read keeps failing so a registry caught mid-write
+/// doesn't make every teammate lose its daemon session.
+static LAST_GOOD: LazyLock<Mutex<HashMap<PathBuf, ShimPaneRegistry>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Resolve the shim pane registry and lock file paths for a session.
+fn shim_registry_paths(session_id: &str) -> Option<(PathBuf, PathBuf)> {
     match KildPaths::resolve() {
-        Ok(p) => Some(p.shim_panes_file(session_id)),
+        Ok(p) => Some((p.shim_panes_file(session_id), p.shim_lock_file(session_id))),
         Err(e) => {
             tracing::warn!(
                 event = "teams.mapper.home_dir_unavailable",
@@ -26,27 +44,80 @@ fn shim_registry_path(session_id: &str) -> Option<PathBuf> {
     }
 }
 
-/// Enrich team state with daemon session IDs from the shim pane registry.
+/// Read a shim pane registry under its `panes.lock`, retrying with backoff.
 ///
-/// For each member, looks up their `pane_id` in the registry and copies
-/// the `daemon_session_id`. Members with no matching pane keep `None`.
-pub fn resolve_team(mut team_state: TeamState, session_id: &str) -> Result<TeamState, TeamsError> {
-    let Some(registry_path) = shim_registry_path(session_id) else {
-        return Ok(team_state);
+/// The shim truncates `panes.json` before rewriting it while holding an
+/// exclusive lock, so a read can see an empty or partial file. Each attempt
+/// takes a shared lock (without blocking) and parses; lock contention and
+/// parse errors are retried. If every attempt fails, the last registry that
+/// parsed cleanly for this path is returned instead, if there is one.
+pub fn read_registry(
+    registry_path: &Path,
+    lock_path: &Path,
+) -> Result<Option<ShimPaneRegistry>, TeamsError> {
+    let mut backoff = RETRY_BACKOFF;
+    let mut attempt = 1;
+
+    let err = loop {
+        match read_registry_once(registry_path, lock_path) {
+            Ok(Some(registry)) => {
+                if let Ok(mut cache) = LAST_GOOD.lock() {
+                    cache.insert(registry_path.to_path_buf(), registry.clone());
+                }
+                return Ok(Some(registry));
+            }
+            Ok(None) => return Ok(None),
+            Err(e) if attempt < READ_ATTEMPTS => {
+                tracing::debug!(
+                    event = "teams.mapper.registry_read_retry",
+                    path = %registry_path.display(),
+                    attempt = attempt,
+                    error = %e
+                );
+                std::thread::sleep(backoff);
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(e) => break e,
+        }
     };
 
-    let registry = match parser::parse_shim_registry(&registry_path)? {
-        Some(r) => r,
-        None => {
-            tracing::debug!(
-                event = "teams.mapper.no_shim_registry",
-                session_id = session_id,
-                path = %registry_path.display()
+    let cached = LAST_GOOD
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(registry_path).cloned());
+    match cached {
+        Some(registry) => {
+            tracing::warn!(
+                event = "teams.mapper.registry_read_failed_using_cached",
+                path = %registry_path.display(),
+                error = %err
             );
-            return Ok(team_state);
+            Ok(Some(registry))
         }
+        None => Err(err),
+    }
+}
+
+fn read_registry_once(
+    registry_path: &Path,
+    lock_path: &Path,
+) -> Result<Option<ShimPaneRegistry>, TeamsError> {
+    // A missing lock file means no shim has written this registry yet.
+    let _lock = match File::open(lock_path) {
+        Ok(file) => Some(
+            Flock::lock(file, FlockArg::LockSharedNonblock)
+                .map_err(|(_, e)| std::io::Error::from(e))?,
+        ),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e.into()),
     };
 
+    parser::parse_shim_registry(registry_path)
+}
+
+/// Copy daemon session IDs from `registry` onto matching team members.
+fn apply_registry(team_state: &mut TeamState, registry: &ShimPaneRegistry, session_id: &str) {
     team_state.kild_session_id = Some(session_id.to_string());
 
     for member in &mut team_state.members {
@@ -67,29 +138,49 @@ pub fn resolve_team(mut team_state: TeamState, session_id: &str) -> Result<TeamS
             );
         }
     }
+}
+
+/// Enrich team state with daemon session IDs from the shim pane registry.
+///
+/// For each member, looks up their `pane_id` in the registry and copies
+/// the `daemon_session_id`. Members with no matching pane keep `None`.
+/// The registry is read via [`read_registry`].
+pub fn resolve_team(mut team_state: TeamState, session_id: &str) -> Result<TeamState, TeamsError> {
+    let Some((registry_path, lock_path)) = shim_registry_paths(session_id) else {
+        return Ok(team_state);
+    };
+
+    let registry = match read_registry(&registry_path, &lock_path)? {
+        Some(r) => r,
+        None => {
+            tracing::debug!(
+                event = "teams.mapper.no_shim_registry",
+                session_id = session_id,
+                path = %registry_path.display()
+            );
+            return Ok(team_state);
+        }
+    };
 
+    apply_registry(&mut team_state, &registry, session_id);
     Ok(team_state)
 }
 
 /// Resolve team state from a registry at a custom path (for testing).
+///
+/// Locks the sibling `panes.lock`, matching the shim's layout.
 pub fn resolve_team_with_registry(
     mut team_state: TeamState,
-    registry_path: &std::path::Path,
+    registry_path: &Path,
     session_id: &str,
 ) -> Result<TeamState, TeamsError> {
-    let registry = match parser::parse_shim_registry(registry_path)? {
+    let lock_path = registry_path.with_file_name("panes.lock");
+    let registry = match read_registry(registry_path, &lock_path)? {
         Some(r) => r,
         None => return Ok(team_state),
     };
 
-    team_state.kild_session_id = Some(session_id.to_string());
-
-    for member in &mut team_state.members {
-        if let Some(pane) = registry.panes.get(&member.pane_id) {
-            member.daemon_session_id = Some(pane.daemon_session_id.clone());
-        }
-    }
-
+    apply_registry(&mut team_state, &registry, session_id);
     Ok(team_state)
 }
 
@@ -228,4 +319,82 @@ mod tests {
 
         assert!(resolved.members[0].daemon_session_id.is_none());
     }
+
+    const REGISTRY: &str = r#"{ "panes": { "%0": { "daemon_session_id": "d-leader", "title": "" }, "%1": { "daemon_session_id": "d-worker", "title": "worker" } } }"#;
+
+    #[test]
+    fn test_read_registry_retries_past_partial_write() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let registry_path = dir.path().join("panes.json");
+        let lock_path = dir.path().join("panes.lock");
+
+        // Shim is mid-write: holds the exclusive lock over a truncated file.
+        fs::write(&registry_path, &REGISTRY[..20]).unwrap();
+        let lock = Flock::lock(File::create(&lock_path).unwrap(), FlockArg::LockExclusive)
+            .map_err(|(_, e)| e)
+            .unwrap();
+
+        let writer_path = registry_path.clone();
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(30));
+            fs::write(&writer_path, REGISTRY).unwrap();
+            drop(lock);
+        });
+
+        let team = make_team(vec![
+            make_member("leader", "%0"),
+            make_member("worker", "%1"),
+        ]);
+        let resolved = resolve_team_with_registry(team, &registry_path, "sess").unwrap();
+        writer.join().unwrap();
+
+        assert_eq!(
+            resolved.members[1].daemon_session_id.as_deref(),
+            Some("d-worker")
+        );
+    }
+
+    #[test]
+    fn test_read_registry_recovers_from_truncated_file_without_lock() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let registry_path = dir.path().join("panes.json");
+        fs::write(&registry_path, "").unwrap();
+
+        let writer_path = registry_path.clone();
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(10));
+            fs::write(&writer_path, REGISTRY).unwrap();
+        });
+
+        let registry = read_registry(&registry_path, &dir.path().join("panes.lock"))
+            .unwrap()
+            .unwrap();
+        writer.join().unwrap();
+        assert_eq!(registry.panes["%0"].daemon_session_id, "d-leader");
+    }
+
+    #[test]
+    fn test_read_registry_falls_back_to_last_good() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let registry_path = dir.path().join("panes.json");
+        let lock_path = dir.path().join("panes.lock");
+
+        fs::write(&registry_path, REGISTRY).unwrap();
+        read_registry(&registry_path, &lock_path).unwrap().unwrap();
+
+        // Stays broken across every retry.
+        fs::write(&registry_path, &REGISTRY[..20]).unwrap();
+        let registry = read_registry(&registry_path, &lock_path).unwrap().unwrap();
+        assert_eq!(registry.panes["%1"].daemon_session_id, "d-worker");
+    }
+
+    #[test]
+    fn test_read_registry_persistent_failure_without_cache_errors() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let registry_path = dir.path().join("panes.json");
+        fs::write(&registry_path, "{ not json").unwrap();
+
+        let result = read_registry(&registry_path, &dir.path().join("panes.lock"));
+        assert!(matches!(result, Err(TeamsError::Json(_))));
+    }
 }
@@ -1,12 +1,12 @@
 //! Parsers for Claude Code team config and shim pane registry.
 //!
 //! Raw serde types with `#[serde(default)]` for forward compatibility
-//! with unknown/added fields.
+//! with unknown/added fields. `writer.rs` serializes the same types.
 
 use std::collections::HashMap;
 use std::path::Path;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::errors::TeamsError;
 use crate::types::{TeamColor, TeamMember, TeamState};
@@ -16,7 +16,7 @@ use crate::types::{TeamColor, TeamMember, TeamState};
 // =============================================================================
 
 /// Raw Claude Code team config (serde).
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RawTeamConfig {
     pub members: Vec<RawTeamMember>,
@@ -25,7 +25,7 @@ pub struct RawTeamConfig {
 }
 
 /// Raw Claude Code team member entry (serde).
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RawTeamMember {
     #[serde(rename = "agentId")]
@@ -54,13 +54,13 @@ pub struct RawTeamMember {
 // =============================================================================
 
 /// Minimal shim pane registry for cross-referencing.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ShimPaneRegistry {
     pub panes: HashMap<String, ShimPaneEntry>,
 }
 
 /// Minimal pane entry — only fields we need for mapping.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ShimPaneEntry {
     pub daemon_session_id: String,
     pub title: String,
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 
 /// Color assigned to a team member by Claude Code.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TeamColor {
     Red,
     Blue,
@@ -35,6 +36,21 @@ impl TeamColor {
         }
     }
 
+    /// Claude Code's string for this color, or `None` for `Unknown`.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Red => Some("red"),
+            Self::Blue => Some("blue"),
+            Self::Green => Some("green"),
+            Self::Yellow => Some("yellow"),
+            Self::Purple => Some("purple"),
+            Self::Orange => Some("orange"),
+            Self::Pink => Some("pink"),
+            Self::Cyan => Some("cyan"),
+            Self::Unknown => None,
+        }
+    }
+
     /// Parse from a tmux border_style string like "fg=blue".
     pub fn from_border_style(style: &str) -> Self {
         let color = style
@@ -46,7 +62,7 @@ impl TeamColor {
 }
 
 /// A single member of an agent team.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TeamMember {
     /// Display name (e.g., "researcher").
     pub name: String,
@@ -95,10 +111,26 @@ impl TeamState {
 }
 
 /// Events emitted when team state changes.
-#[derive(Debug, Clone)]
+///
+/// Produced by diffing successive team snapshots (see [`crate::diff`]), so
+/// a config rewrite that changes nothing emits nothing. Members are
+/// identified by name within a team.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TeamEvent {
-    /// A team was created or updated.
-    TeamUpdated { team_name: String, state: TeamState },
-    /// A team was removed (config deleted).
-    TeamRemoved { team_name: String },
+    /// A member appeared in a team (including every member of a new team).
+    TeammateAdded {
+        team_name: String,
+        member: TeamMember,
+    },
+    /// A member left a team (including every member of a deleted team).
+    TeammateRemoved { team_name: String, name: String },
+    /// A member's active flag flipped.
+    TeammateStatusChanged {
+        team_name: String,
+        name: String,
+        is_active: bool,
+    },
+    /// A team directory was renamed: one team disappeared and another with
+    /// the same member names appeared in the same snapshot.
+    TeamRenamed { old_name: String, new_name: String },
 }
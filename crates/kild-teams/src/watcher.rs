@@ -2,19 +2,38 @@
 //!
 //! Follows the `SessionWatcher` pattern from `kild-ui/src/watcher.rs`.
 
+use notify::event::CreateKind;
 use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
-use std::path::Path;
-use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+
+use crate::diff::{self, TeamSnapshot};
+use crate::scanner;
+use crate::types::TeamEvent;
 
 /// Watches team-related directories for changes.
 ///
 /// Monitors both `~/.claude/teams/` (team configs) and `~/.kild/shim/`
-/// (pane registries) for file system events.
+/// (pane registries) for file system events. Config changes are diffed
+/// against the last scan and delivered as [`TeamEvent`]s to subscribers.
 pub struct TeamWatcher {
     /// Underlying notify watchers. Must be kept alive.
     _watchers: Vec<RecommendedWatcher>,
     /// Channel receiver for file events.
     receiver: Receiver<Result<Event, notify::Error>>,
+    /// Teams directory to rescan on config changes, if it is being watched.
+    teams_dir: Option<PathBuf>,
+    /// Team state as of the last scan.
+    snapshot: TeamSnapshot,
+    /// Event channels handed out by `subscribe()`.
+    subscribers: Vec<Sender<TeamEvent>>,
+}
+
+/// Which kinds of files changed in one batch of file events.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct PendingChanges {
+    configs: bool,
+    registries: bool,
 }
 
 impl TeamWatcher {
@@ -27,6 +46,7 @@ impl TeamWatcher {
     pub fn new(teams_dir: Option<&Path>, shim_dir: Option<&Path>) -> Option<Self> {
         let (tx, rx) = mpsc::channel();
         let mut watchers = Vec::new();
+        let mut watched_teams_dir = None;
 
         if let Some(dir) = teams_dir
             && dir.is_dir()
@@ -38,6 +58,7 @@ impl TeamWatcher {
                         path = %dir.display()
                     );
                     watchers.push(w);
+                    watched_teams_dir = Some(dir.to_path_buf());
                 }
                 Err(e) => {
                     tracing::warn!(
@@ -78,9 +99,17 @@ impl TeamWatcher {
             return None;
         }
 
+        let snapshot = watched_teams_dir
+            .as_deref()
+            .map(|dir| diff::snapshot(scanner::scan_teams(dir)))
+            .unwrap_or_default();
+
         Some(Self {
             _watchers: watchers,
             receiver: rx,
+            teams_dir: watched_teams_dir,
+            snapshot,
+            subscribers: Vec::new(),
         })
     }
 
@@ -96,33 +125,82 @@ impl TeamWatcher {
         Self::new(Some(&teams_dir), Some(&shim_dir))
     }
 
+    /// Subscribe to team events.
+    ///
+    /// Events are sent from [`poll_events`](Self::poll_events); the receiver
+    /// only sees changes made after it subscribed.
+    pub fn subscribe(&mut self) -> Receiver<TeamEvent> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.push(tx);
+        rx
+    }
+
+    /// Process pending file events (non-blocking).
+    ///
+    /// On a config change, rescans the teams directory, diffs it against the
+    /// previous scan and sends the resulting events to every subscriber.
+    /// Returns `true` if team state actually changed or a pane registry was
+    /// touched, i.e. when cached team data should be refreshed.
+    pub fn poll_events(&mut self) -> bool {
+        let pending = self.drain_events();
+
+        let mut teams_changed = false;
+        if pending.configs
+            && let Some(dir) = &self.teams_dir
+        {
+            let current = diff::snapshot(scanner::scan_teams(dir));
+            let events = diff::diff_teams(&self.snapshot, &current);
+            self.snapshot = current;
+            teams_changed = !events.is_empty();
+            self.publish(events);
+        }
+
+        teams_changed || pending.registries
+    }
+
     /// Check for pending file events (non-blocking).
     ///
     /// Returns `true` if any relevant events (config.json or panes.json changes)
-    /// were detected since the last call. Drains all pending events.
+    /// were detected since the last call. Drains all pending events without
+    /// diffing them; use [`poll_events`](Self::poll_events) to emit events.
     pub fn has_pending_events(&self) -> bool {
-        let mut found_relevant = false;
+        let pending = self.drain_events();
+        pending.configs || pending.registries
+    }
+
+    fn publish(&mut self, events: Vec<TeamEvent>) {
+        for event in events {
+            tracing::debug!(event = "teams.watcher.team_event", team_event = ?event);
+            // Drop subscribers whose receiver has gone away.
+            self.subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+        }
+    }
+
+    fn drain_events(&self) -> PendingChanges {
+        let mut pending = PendingChanges::default();
 
         loop {
             match self.receiver.try_recv() {
                 Ok(Ok(event)) => {
-                    if Self::is_relevant_event(&event) && !found_relevant {
+                    let changes = Self::classify_event(&event);
+                    if changes != PendingChanges::default() {
                         tracing::debug!(
                             event = "teams.watcher.event_detected",
                             kind = ?event.kind,
                             paths = ?event.paths
                         );
-                        found_relevant = true;
                     }
+                    pending.configs |= changes.configs;
+                    pending.registries |= changes.registries;
                     // Continue draining
                 }
                 Ok(Err(e)) => {
                     tracing::warn!(event = "teams.watcher.event_error", error = %e);
                 }
-                Err(TryRecvError::Empty) => return found_relevant,
+                Err(TryRecvError::Empty) => return pending,
                 Err(TryRecvError::Disconnected) => {
                     tracing::warn!(event = "teams.watcher.channel_disconnected");
-                    return found_relevant;
+                    return pending;
                 }
             }
         }
@@ -138,29 +216,43 @@ impl TeamWatcher {
         Ok(watcher)
     }
 
-    /// Check if an event is relevant (config.json or panes.json changes).
-    fn is_relevant_event(event: &Event) -> bool {
+    /// Sort an event's paths into team config and pane registry changes.
+    fn classify_event(event: &Event) -> PendingChanges {
+        let mut changes = PendingChanges::default();
         let is_relevant_kind = matches!(
             event.kind,
             EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
         );
 
         if !is_relevant_kind {
-            return false;
+            return changes;
         }
 
-        event.paths.iter().any(|p| {
-            p.file_name()
-                .and_then(|n| n.to_str())
-                .is_some_and(|name| name == "config.json" || name == "panes.json")
-        })
+        // Files written into a new team directory before the recursive watch
+        // picks it up produce no events of their own, so rescan on new dirs.
+        if matches!(event.kind, EventKind::Create(CreateKind::Folder)) {
+            changes.configs = true;
+        }
+
+        for name in event
+            .paths
+            .iter()
+            .filter_map(|p| p.file_name().and_then(|n| n.to_str()))
+        {
+            match name {
+                "config.json" => changes.configs = true,
+                "panes.json" => changes.registries = true,
+                _ => {}
+            }
+        }
+        changes
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use notify::event::{CreateKind, ModifyKind, RemoveKind};
+    use notify::event::{ModifyKind, RemoveKind};
     use std::path::PathBuf;
 
     fn make_event(kind: EventKind, paths: Vec<PathBuf>) -> Event {
@@ -177,7 +269,10 @@ mod tests {
             EventKind::Create(CreateKind::File),
             vec![PathBuf::from("/teams/my-team/config.json")],
         );
-        assert!(TeamWatcher::is_relevant_event(&event));
+        assert_ne!(
+            TeamWatcher::classify_event(&event),
+            PendingChanges::default()
+        );
     }
 
     #[test]
@@ -186,7 +281,10 @@ mod tests {
             EventKind::Modify(ModifyKind::Data(notify::event::DataChange::Content)),
             vec![PathBuf::from("/shim/session/panes.json")],
         );
-        assert!(TeamWatcher::is_relevant_event(&event));
+        assert_ne!(
+            TeamWatcher::classify_event(&event),
+            PendingChanges::default()
+        );
     }
 
     #[test]
@@ -195,7 +293,10 @@ mod tests {
             EventKind::Create(CreateKind::File),
             vec![PathBuf::from("/teams/my-team/inboxes/agent.json")],
         );
-        assert!(!TeamWatcher::is_relevant_event(&event));
+        assert_eq!(
+            TeamWatcher::classify_event(&event),
+            PendingChanges::default()
+        );
     }
 
     #[test]
@@ -204,7 +305,10 @@ mod tests {
             EventKind::Create(CreateKind::File),
             vec![PathBuf::from("/teams/my-team/something.txt")],
         );
-        assert!(!TeamWatcher::is_relevant_event(&event));
+        assert_eq!(
+            TeamWatcher::classify_event(&event),
+            PendingChanges::default()
+        );
     }
 
     #[test]
@@ -213,7 +317,10 @@ mod tests {
             EventKind::Remove(RemoveKind::File),
             vec![PathBuf::from("/teams/old-team/config.json")],
         );
-        assert!(TeamWatcher::is_relevant_event(&event));
+        assert_ne!(
+            TeamWatcher::classify_event(&event),
+            PendingChanges::default()
+        );
     }
 
     #[test]
@@ -222,7 +329,10 @@ mod tests {
             EventKind::Access(notify::event::AccessKind::Read),
             vec![PathBuf::from("/teams/team/config.json")],
         );
-        assert!(!TeamWatcher::is_relevant_event(&event));
+        assert_eq!(
+            TeamWatcher::classify_event(&event),
+            PendingChanges::default()
+        );
     }
 
     #[test]
@@ -265,4 +375,64 @@ mod tests {
         // Drained
         assert!(!watcher.has_pending_events());
     }
+
+    #[test]
+    fn test_classify_event_separates_configs_and_registries() {
+        let event = make_event(
+            EventKind::Modify(ModifyKind::Any),
+            vec![
+                PathBuf::from("/teams/t/config.json"),
+                PathBuf::from("/shim/s/panes.json"),
+            ],
+        );
+        assert_eq!(
+            TeamWatcher::classify_event(&event),
+            PendingChanges {
+                configs: true,
+                registries: true
+            }
+        );
+    }
+
+    #[test]
+    fn test_classify_event_new_directory_rescans_configs() {
+        let event = make_event(
+            EventKind::Create(CreateKind::Folder),
+            vec![PathBuf::from("/teams/new-team")],
+        );
+        assert!(TeamWatcher::classify_event(&event).configs);
+    }
+
+    #[test]
+    fn test_poll_events_publishes_diff_to_subscribers() {
+        let dir = tempfile::TempDir::new().unwrap();
+        crate::writer::create_team_in(dir.path(), "alpha", &["lead"], &["blue"]).unwrap();
+
+        let mut watcher = TeamWatcher::new(Some(dir.path()), None).unwrap();
+        let events = watcher.subscribe();
+
+        crate::writer::create_team_in(dir.path(), "beta", &["lead", "dev"], &["red", "green"])
+            .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+
+        assert!(watcher.poll_events());
+        let names: Vec<_> = events
+            .try_iter()
+            .map(|e| match e {
+                TeamEvent::TeammateAdded { team_name, member } => (team_name, member.name),
+                other => panic!("unexpected event: {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("beta".to_string(), "lead".to_string()),
+                ("beta".to_string(), "dev".to_string())
+            ]
+        );
+
+        // Nothing changed since the last poll.
+        assert!(!watcher.poll_events());
+        assert!(events.try_recv().is_err());
+    }
 }
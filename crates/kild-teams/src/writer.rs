@@ -0,0 +1,217 @@
+//! Create Claude Code team configs on disk.
+//!
+//! Writes `~/.claude/teams/<team>/config.json` in the format `parser.rs`
+//! reads, so teams can be set up from the UI instead of by hand.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::errors::TeamsError;
+use crate::parser::{RawTeamConfig, RawTeamMember};
+use crate::scanner;
+use crate::types::TeamColor;
+
+/// Create a team in the default teams directory (`~/.claude/teams/`).
+///
+/// See [`create_team_in`] for the layout and validation rules.
+pub fn create_team(name: &str, members: &[&str], colors: &[&str]) -> Result<(), TeamsError> {
+    let teams_dir = scanner::default_teams_dir().ok_or(TeamsError::HomeDirUnavailable)?;
+    create_team_in(&teams_dir, name, members, colors)?;
+    Ok(())
+}
+
+/// Create a team config under `teams_dir` and return its path.
+///
+/// `members[i]` gets `colors[i]`. The first member is the leader; teammates
+/// get pane IDs `%1`, `%2`, ... in order, matching what the shim assigns
+/// when they are spawned. Member names must be unique and every color must
+/// map to a known [`TeamColor`]. Fails rather than overwrite an existing team.
+pub fn create_team_in(
+    teams_dir: &Path,
+    name: &str,
+    members: &[&str],
+    colors: &[&str],
+) -> Result<PathBuf, TeamsError> {
+    validate_team_name(name)?;
+
+    if members.len() != colors.len() {
+        return Err(TeamsError::ColorCountMismatch {
+            members: members.len(),
+            colors: colors.len(),
+        });
+    }
+
+    let mut seen = HashSet::new();
+    for member in members {
+        if !seen.insert(*member) {
+            return Err(TeamsError::DuplicateMember(member.to_string()));
+        }
+    }
+
+    let joined_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    let raw_members = members
+        .iter()
+        .zip(colors)
+        .enumerate()
+        .map(|(i, (member, color))| {
+            let color = TeamColor::parse(color)
+                .as_str()
+                .ok_or_else(|| TeamsError::UnknownColor(color.to_string()))?;
+            Ok(RawTeamMember {
+                agent_id: format!("{member}@{name}"),
+                name: member.to_string(),
+                color: color.to_string(),
+                joined_at,
+                tmux_pane_id: if i == 0 {
+                    String::new()
+                } else {
+                    format!("%{i}")
+                },
+                backend_type: "tmux".to_string(),
+                ..Default::default()
+            })
+        })
+        .collect::<Result<Vec<_>, TeamsError>>()?;
+
+    let config = RawTeamConfig {
+        members: raw_members,
+        hidden_pane_ids: Vec::new(),
+    };
+
+    let team_dir = teams_dir.join(name);
+    let config_path = team_dir.join("config.json");
+    if config_path.exists() {
+        return Err(TeamsError::TeamExists(name.to_string()));
+    }
+    std::fs::create_dir_all(&team_dir)?;
+
+    // Write then rename so the watcher never picks up a half-written file.
+    let tmp_path = team_dir.join("config.json.tmp");
+    std::fs::write(&tmp_path, serde_json::to_string_pretty(&config)?)?;
+    std::fs::rename(&tmp_path, &config_path)?;
+
+    tracing::info!(
+        event = "teams.writer.team_created",
+        team = name,
+        members = members.len(),
+        path = %config_path.display()
+    );
+
+    Ok(config_path)
+}
+
+/// Team names become a directory under the teams dir, so they must be a
+/// single plain path component.
+fn validate_team_name(name: &str) -> Result<(), TeamsError> {
+    let invalid = name.is_empty()
+        || name.starts_with('.')
+        || name.contains(['/', '\\'])
+        || name.chars().any(char::is_control);
+    if invalid {
+        return Err(TeamsError::InvalidTeamName(name.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_team_config;
+
+    #[test]
+    fn test_create_team_roundtrips_through_parser() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = create_team_in(
+            dir.path(),
+            "my-team",
+            &["lead", "researcher", "tester"],
+            &["blue", "magenta", "green"],
+        )
+        .unwrap();
+        assert_eq!(path, dir.path().join("my-team").join("config.json"));
+
+        let team = parse_team_config(&path).unwrap().unwrap();
+        assert_eq!(team.team_name, "my-team");
+        assert_eq!(team.members.len(), 3);
+        assert_eq!(team.leader().unwrap().name, "lead");
+
+        let researcher = &team.members[1];
+        assert_eq!(researcher.name, "researcher");
+        assert_eq!(researcher.agent_id.as_deref(), Some("researcher@my-team"));
+        assert_eq!(researcher.color, TeamColor::Purple);
+        assert_eq!(researcher.pane_id, "%1");
+
+        let teammates: Vec<_> = team.teammates().map(|m| m.name.as_str()).collect();
+        assert_eq!(teammates, ["researcher", "tester"]);
+    }
+
+    #[test]
+    fn test_created_team_is_found_by_scanner() {
+        let dir = tempfile::TempDir::new().unwrap();
+        create_team_in(dir.path(), "alpha", &["lead"], &["red"]).unwrap();
+
+        let teams = scanner::scan_teams(dir.path());
+        assert_eq!(teams.len(), 1);
+        assert_eq!(teams[0].0, "alpha");
+    }
+
+    #[test]
+    fn test_create_team_rejects_duplicate_members() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let err = create_team_in(dir.path(), "t", &["a", "b", "a"], &["red", "blue", "cyan"])
+            .unwrap_err();
+        assert!(matches!(err, TeamsError::DuplicateMember(ref m) if m == "a"));
+        assert!(!dir.path().join("t").exists());
+    }
+
+    #[test]
+    fn test_create_team_rejects_unknown_color() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let err = create_team_in(dir.path(), "t", &["a", "b"], &["red", "chartreuse"]).unwrap_err();
+        assert!(matches!(err, TeamsError::UnknownColor(ref c) if c == "chartreuse"));
+        assert!(!dir.path().join("t").exists());
+    }
+
+    #[test]
+    fn test_create_team_rejects_color_count_mismatch() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let err = create_team_in(dir.path(), "t", &["a", "b"], &["red"]).unwrap_err();
+        assert!(matches!(
+            err,
+            TeamsError::ColorCountMismatch {
+                members: 2,
+                colors: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn test_create_team_does_not_overwrite() {
+        let dir = tempfile::TempDir::new().unwrap();
+        create_team_in(dir.path(), "t", &["a"], &["red"]).unwrap();
+        let err = create_team_in(dir.path(), "t", &["b"], &["blue"]).unwrap_err();
+        assert!(matches!(err, TeamsError::TeamExists(_)));
+
+        let team = parse_team_config(&dir.path().join("t").join("config.json"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(team.members[0].name, "a");
+    }
+
+    #[test]
+    fn test_create_team_rejects_invalid_names() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for name in ["", ".hidden", "..", "a/b", "a\\b"] {
+            let err = create_team_in(dir.path(), name, &["a"], &["red"]).unwrap_err();
+            assert!(
+                matches!(err, TeamsError::InvalidTeamName(_)),
+                "{name:?} should be rejected"
+            );
+        }
+    }
+}
@@ -9,7 +9,7 @@ use tracing::{debug, error};
 use crate::errors::ShimError;
 use crate::ipc;
 use crate::parser::*;
-use crate::state::{self, PaneEntry, PaneRegistry, SessionEntry, WindowEntry};
+use crate::state::{self, PaneEntry, PaneRegistry, SessionEntry, SplitDirection, WindowEntry};
 
 pub fn execute(cmd: TmuxCommand<'_>) -> Result<i32, ShimError> {
     match cmd {
@@ -20,6 +20,7 @@ pub fn execute(cmd: TmuxCommand<'_>) -> Result<i32, ShimError> {
         TmuxCommand::KillPane(args) => handle_kill_pane(args),
         TmuxCommand::DisplayMessage(args) => handle_display_message(args),
         TmuxCommand::SelectPane(args) => handle_select_pane(args),
+        TmuxCommand::LastPane(args) => handle_last_pane(args),
         TmuxCommand::SetOption(args) => handle_set_option(args),
         TmuxCommand::SelectLayout(args) => handle_select_layout(args),
         TmuxCommand::ResizePane(args) => handle_resize_pane(args),
@@ -139,20 +140,28 @@ fn resolve_pane_status(daemon_session_id: &str) -> (String, String, String) {
     (pane_dead_str.to_string(), pane_pid, pane_dead_status)
 }
 
-fn expand_format(
-    format: &str,
-    pane_id: &str,
-    session_name: &str,
-    window_index: &str,
-    window_name: &str,
-    pane_title: &str,
-) -> String {
+/// Values substituted into tmux format strings. Unset fields expand to "".
+#[derive(Debug, Default)]
+struct FormatVars<'a> {
+    pane_id: &'a str,
+    session_name: &'a str,
+    window_index: &'a str,
+    window_name: &'a str,
+    pane_title: &'a str,
+    pane_current_path: &'a str,
+}
+
+/// Expand the `#{...}` variables the shim knows about. Unknown variables are
+/// left in place, matching tmux's leniency.
+fn expand_format(format: &str, vars: &FormatVars<'_>) -> String {
     format
-        .replace("#{pane_id}", pane_id)
-        .replace("#{session_name}", session_name)
-        .replace("#{window_index}", window_index)
-        .replace("#{window_name}", window_name)
-        .replace("#{pane_title}", pane_title)
+        .replace("#{pane_id}", vars.pane_id)
+        .replace("#{session_name}", vars.session_name)
+        .replace("#{window_id}", &format!("@{}", vars.window_index))
+        .replace("#{window_index}", vars.window_index)
+        .replace("#{window_name}", vars.window_name)
+        .replace("#{pane_title}", vars.pane_title)
+        .replace("#{pane_current_path}", vars.pane_current_path)
 }
 
 /// Expand format string with daemon-aware pane status variables.
@@ -162,11 +171,7 @@ fn expand_format(
 /// when the format string actually contains these variables.
 fn expand_format_with_status(
     format: &str,
-    pane_id: &str,
-    session_name: &str,
-    window_index: &str,
-    window_name: &str,
-    pane_title: &str,
+    vars: &FormatVars<'_>,
     daemon_session_id: &str,
 ) -> String {
     // Check the original format string before substitution to avoid spurious
@@ -175,14 +180,7 @@ fn expand_format_with_status(
         || format.contains("#{pane_pid}")
         || format.contains("#{pane_dead_status}");
 
-    let mut result = expand_format(
-        format,
-        pane_id,
-        session_name,
-        window_index,
-        window_name,
-        pane_title,
-    );
+    let mut result = expand_format(format, vars);
 
     if needs_status {
         let (pane_dead, pane_pid, pane_dead_status) = resolve_pane_status(daemon_session_id);
@@ -205,6 +203,7 @@ fn expand_format_with_status(
 fn create_pty_pane(
     registry: &mut PaneRegistry,
     window_id: &str,
+    split_from: Option<(&str, SplitDirection)>,
     shell_command_parts: &[&str],
 ) -> Result<String, ShimError> {
     let sid = session_id()?;
@@ -258,7 +257,7 @@ fn create_pty_pane(
         use_login_shell,
     )?;
 
-    registry.panes.insert(
+    registry.add_pane(
         pane_id.clone(),
         PaneEntry {
             daemon_session_id,
@@ -266,14 +265,12 @@ fn create_pty_pane(
             border_style: String::new(),
             window_id: window_id.to_string(),
             hidden: false,
+            parent: split_from.map(|(parent, _)| parent.to_string()),
+            split: split_from.map(|(_, direction)| direction),
+            cwd: Some(cwd),
         },
     );
 
-    // Add pane to the window's pane list
-    if let Some(window) = registry.windows.get_mut(window_id) {
-        window.pane_ids.push(pane_id.clone());
-    }
-
     debug!(
         event = "shim.split_window.create_pty_completed",
         pane_id = pane_id,
@@ -297,28 +294,55 @@ fn handle_split_window(args: SplitWindowArgs<'_>) -> Result<i32, ShimError> {
     );
 
     let sid = session_id()?;
-    let mut registry = state::load(&sid)?;
-
-    // Determine which window the target pane belongs to
     let parent_pane_id = resolve_pane_id(args.target);
-    let window_id = registry
-        .panes
-        .get(&parent_pane_id)
-        .map(|p| p.window_id.clone())
-        .unwrap_or_else(|| "0".to_string());
-
-    let pane_id = create_pty_pane(&mut registry, &window_id, &args.command)?;
-    state::save(&sid, &registry)?;
+    let direction = if args.horizontal {
+        SplitDirection::Horizontal
+    } else {
+        SplitDirection::Vertical
+    };
 
-    if args.print_info {
-        let fmt = args.format.unwrap_or("#{pane_id}");
-        let session_name = &registry.session_name;
-        let window_name = registry
-            .windows
-            .get(&window_id)
-            .map(|w| w.name.as_str())
-            .unwrap_or("main");
-        let output = expand_format(fmt, &pane_id, session_name, &window_id, window_name, "");
+    // Hold the lock across pane creation so concurrent splits can't
+    // overwrite each other's registry entries.
+    let (pane_id, info) = state::update(&sid, |registry| {
+        // Determine which window the target pane belongs to
+        let (window_id, split_from) = match registry.panes.get(&parent_pane_id) {
+            Some(parent) => (
+                parent.window_id.clone(),
+                Some((parent_pane_id.as_str(), direction)),
+            ),
+            None => ("0".to_string(), None),
+        };
+
+        let pane_id = create_pty_pane(registry, &window_id, split_from, &args.command)?;
+
+        let info = args.print_info.then(|| {
+            let fmt = args.format.unwrap_or("#{pane_id}");
+            let window_name = registry
+                .windows
+                .get(&window_id)
+                .map(|w| w.name.as_str())
+                .unwrap_or("main");
+            let pane_current_path = registry
+                .panes
+                .get(&pane_id)
+                .and_then(|pane| pane.cwd.as_deref())
+                .unwrap_or("");
+            expand_format(
+                fmt,
+                &FormatVars {
+                    pane_id: &pane_id,
+                    session_name: &registry.session_name,
+                    window_index: &window_id,
+                    window_name,
+                    pane_current_path,
+                    ..Default::default()
+                },
+            )
+        });
+        Ok((pane_id, info))
+    })?;
+
+    if let Some(output) = info {
         println!("{}", output);
     }
 
@@ -425,11 +449,14 @@ fn handle_list_panes(args: ListPanesArgs<'_>) -> Result<i32, ShimError> {
             .unwrap_or("");
         let output = expand_format_with_status(
             fmt,
-            pane_id,
-            session_name,
-            &pane.window_id,
-            window_name,
-            &pane.title,
+            &FormatVars {
+                pane_id,
+                session_name,
+                window_index: &pane.window_id,
+                window_name,
+                pane_title: &pane.title,
+                pane_current_path: pane.cwd.as_deref().unwrap_or(""),
+            },
             &pane.daemon_session_id,
         );
         println!("{}", output);
@@ -497,61 +524,73 @@ fn handle_kill_pane(args: KillPaneArgs<'_>) -> Result<i32, ShimError> {
 }
 
 fn handle_display_message(args: DisplayMsgArgs<'_>) -> Result<i32, ShimError> {
-    debug!(event = "shim.display_message_started", format = ?args.format);
+    debug!(event = "shim.display_message_started", target = ?args.target, format = ?args.format);
 
     let fmt = args.format.unwrap_or("");
-    let pane_id = current_pane_id();
-
-    let needs_registry = fmt.contains("#{session_name}")
-        || fmt.contains("#{window_index}")
-        || fmt.contains("#{window_name}")
-        || fmt.contains("#{pane_title}")
-        || fmt.contains("#{pane_dead}")
-        || fmt.contains("#{pane_pid}")
-        || fmt.contains("#{pane_dead_status}");
+    let pane_id = resolve_pane_id(args.target);
+    let is_own_pane = pane_id == current_pane_id();
+
+    // An explicit target is always looked up so unknown panes fail like tmux
+    let needs_registry = args.target.is_some()
+        || [
+            "#{session_name}",
+            "#{window_id}",
+            "#{window_index}",
+            "#{window_name}",
+            "#{pane_title}",
+            "#{pane_current_path}",
+            "#{pane_dead}",
+            "#{pane_pid}",
+            "#{pane_dead_status}",
+        ]
+        .iter()
+        .any(|var| fmt.contains(var));
 
     // For simple format strings, expand directly without loading state
     match fmt {
-        "#{pane_id}" => {
+        "#{pane_id}" if !needs_registry => {
             println!("{}", pane_id);
         }
         _ if needs_registry => {
             let sid = session_id()?;
             let registry = state::load_shared(&sid)?;
-            let session_name = &registry.session_name;
             let pane_entry = registry.panes.get(&pane_id);
+            if pane_entry.is_none() && !is_own_pane {
+                return Err(ShimError::state(format!("can't find pane: {}", pane_id)));
+            }
             let window_id = pane_entry.map(|p| p.window_id.as_str()).unwrap_or("0");
             let window_name = registry
                 .windows
                 .get(window_id)
                 .map(|w| w.name.as_str())
                 .unwrap_or("main");
-            let pane_title = pane_entry.map(|p| p.title.as_str()).unwrap_or("");
+            // The caller runs inside its own pane, so its cwd is the pane's
+            // live working directory; other panes report where they started.
+            let live_cwd = is_own_pane
+                .then(|| env::current_dir().ok())
+                .flatten()
+                .map(|p| p.to_string_lossy().to_string());
+            let pane_current_path = live_cwd
+                .as_deref()
+                .or_else(|| pane_entry.and_then(|p| p.cwd.as_deref()))
+                .unwrap_or("");
+            let vars = FormatVars {
+                pane_id: &pane_id,
+                session_name: &registry.session_name,
+                window_index: window_id,
+                window_name,
+                pane_title: pane_entry.map(|p| p.title.as_str()).unwrap_or(""),
+                pane_current_path,
+            };
             // Only query the daemon when the pane is in the registry.
             // The leader pane (%0) is not registered, so its daemon_session_id
             // would be empty — querying with an empty ID would misreport it as dead.
             let daemon_session_id = pane_entry
                 .map(|p| p.daemon_session_id.as_str())
                 .filter(|id| !id.is_empty());
-            let output = if let Some(daemon_session_id) = daemon_session_id {
-                expand_format_with_status(
-                    fmt,
-                    &pane_id,
-                    session_name,
-                    window_id,
-                    window_name,
-                    pane_title,
-                    daemon_session_id,
-                )
-            } else {
-                expand_format(
-                    fmt,
-                    &pane_id,
-                    session_name,
-                    window_id,
-                    window_name,
-                    pane_title,
-                )
+            let output = match daemon_session_id {
+                Some(daemon_session_id) => expand_format_with_status(fmt, &vars, daemon_session_id),
+                None => expand_format(fmt, &vars),
             };
             println!("{}", output);
         }
@@ -565,28 +604,106 @@ fn handle_display_message(args: DisplayMsgArgs<'_>) -> Result<i32, ShimError> {
     Ok(0)
 }
 
+/// Resolve a tmux target-window (`%N`, `session`, `session:window`, or
+/// `session:window.%N`) to a window id, defaulting to the current pane's window.
+fn resolve_window_id(registry: &PaneRegistry, target: Option<&str>) -> Result<String, ShimError> {
+    if let Some(t) = target
+        && !t.starts_with('%')
+    {
+        let window = match t.split_once(':') {
+            Some((_, window)) => window.split('.').next().unwrap_or(window),
+            None => registry
+                .sessions
+                .get(t)
+                .and_then(|session| session.windows.first())
+                .map_or(t, String::as_str),
+        };
+        return if registry.windows.contains_key(window) {
+            Ok(window.to_string())
+        } else {
+            Err(ShimError::state(format!("can't find window: {}", t)))
+        };
+    }
+
+    let pane_id = resolve_pane_id(target);
+    registry
+        .panes
+        .get(&pane_id)
+        .map(|pane| pane.window_id.clone())
+        .ok_or_else(|| ShimError::state(format!("can't find pane: {}", pane_id)))
+}
+
+/// Neighbour of `from` for `select-pane -L/-R/-U/-D`.
+///
+/// The shim keeps no layout geometry, so visible panes are treated as laid
+/// out in window order: left/up steps back, right/down steps forward, and
+/// both wrap around like tmux.
+fn neighbour_pane(registry: &PaneRegistry, from: &str, direction: PaneDirection) -> Option<String> {
+    let window = registry.windows.get(&registry.panes.get(from)?.window_id)?;
+    let visible: Vec<&String> = window
+        .pane_ids
+        .iter()
+        .filter(|id| registry.panes.get(*id).is_some_and(|pane| !pane.hidden))
+        .collect();
+    let pos = visible.iter().position(|id| *id == from)?;
+    let n = visible.len();
+    let next = match direction {
+        PaneDirection::Left | PaneDirection::Up => (pos + n - 1) % n,
+        PaneDirection::Right | PaneDirection::Down => (pos + 1) % n,
+    };
+    Some(visible[next].clone())
+}
+
 fn handle_select_pane(args: SelectPaneArgs<'_>) -> Result<i32, ShimError> {
     debug!(event = "shim.select_pane_started", target = ?args.target);
 
-    // Only need state if we have style or title to store
-    if args.style.is_some() || args.title.is_some() {
-        let sid = session_id()?;
-        let mut registry = state::load(&sid)?;
-        let pane_id = resolve_pane_id(args.target);
+    let sid = session_id()?;
+    let pane_id = resolve_pane_id(args.target);
 
-        if let Some(pane) = registry.panes.get_mut(&pane_id) {
+    let active = state::update(&sid, |registry| {
+        let pane = registry
+            .panes
+            .get_mut(&pane_id)
+            .ok_or_else(|| ShimError::state(format!("can't find pane: {}", pane_id)))?;
+
+        // Like tmux, -P and -T only restyle the pane; focus stays put
+        if args.style.is_some() || args.title.is_some() {
             if let Some(style) = args.style {
                 pane.border_style = style.to_string();
             }
             if let Some(title) = args.title {
                 pane.title = title.to_string();
             }
-            state::save(&sid, &registry)?;
+            return Ok(None);
         }
-    }
 
-    // Focus is a no-op in the shim
-    debug!(event = "shim.select_pane_completed");
+        if args.last {
+            let window_id = pane.window_id.clone();
+            return registry.last_pane(&window_id).map(Some);
+        }
+
+        let selected = args
+            .direction
+            .and_then(|direction| neighbour_pane(registry, &pane_id, direction))
+            .unwrap_or_else(|| pane_id.clone());
+        registry.select_pane(&selected)?;
+        Ok(Some(selected))
+    })?;
+
+    debug!(event = "shim.select_pane_completed", active = ?active);
+    Ok(0)
+}
+
+fn handle_last_pane(args: LastPaneArgs<'_>) -> Result<i32, ShimError> {
+    debug!(event = "shim.last_pane_started", target = ?args.target);
+
+    let sid = session_id()?;
+    let active = state::update(&sid, |registry| {
+        let window_id = resolve_window_id(registry, args.target)?;
+        registry.last_pane(&window_id)
+    })?;
+
+    debug!(event = "shim.last_pane_completed", active = active);
     Ok(0)
 }
 
@@ -671,11 +788,13 @@ fn handle_new_session(args: NewSessionArgs<'_>) -> Result<i32, ShimError> {
         WindowEntry {
             name: window_name,
             pane_ids: vec![],
+            active_pane: None,
+            last_pane: None,
         },
     );
 
     // Create initial pane in the new window
-    let pane_id = create_pty_pane(&mut registry, &window_id, &[])?;
+    let pane_id = create_pty_pane(&mut registry, &window_id, None, &[])?;
 
     // Register session
     registry.sessions.insert(
@@ -690,7 +809,16 @@ fn handle_new_session(args: NewSessionArgs<'_>) -> Result<i32, ShimError> {
 
     if args.print_info {
         let fmt = args.format.unwrap_or("#{pane_id}");
-        let output = expand_format(fmt, &pane_id, &session_name, "0", "main", "");
+        let output = expand_format(
+            fmt,
+            &FormatVars {
+                pane_id: &pane_id,
+                session_name: &session_name,
+                window_index: "0",
+                window_name: "main",
+                ..Default::default()
+            },
+        );
         println!("{}", output);
     }
 
@@ -718,10 +846,12 @@ fn handle_new_window(args: NewWindowArgs<'_>) -> Result<i32, ShimError> {
         WindowEntry {
             name: window_name.clone(),
             pane_ids: vec![],
+            active_pane: None,
+            last_pane: None,
         },
     );
 
-    let pane_id = create_pty_pane(&mut registry, &window_id, &[])?;
+    let pane_id = create_pty_pane(&mut registry, &window_id, None, &[])?;
 
     // Add window to the target session (or default session)
     let session_key = args
@@ -738,7 +868,16 @@ fn handle_new_window(args: NewWindowArgs<'_>) -> Result<i32, ShimError> {
 
     if args.print_info {
         let fmt = args.format.unwrap_or("#{pane_id}");
-        let output = expand_format(fmt, &pane_id, &session_key, &window_id, &window_name, "");
+        let output = expand_format(
+            fmt,
+            &FormatVars {
+                pane_id: &pane_id,
+                session_name: &session_key,
+                window_index: &window_id,
+                window_name: &window_name,
+                ..Default::default()
+            },
+        );
         println!("{}", output);
     }
 
@@ -774,7 +913,15 @@ fn handle_list_windows(args: ListWindowsArgs<'_>) -> Result<i32, ShimError> {
         {
             continue;
         }
-        let output = expand_format(fmt, "", session_name, window_id, &window.name, "");
+        let output = expand_format(
+            fmt,
+            &FormatVars {
+                session_name,
+                window_index: window_id,
+                window_name: &window.name,
+                ..Default::default()
+            },
+        );
         println!("{}", output);
     }
 
@@ -1019,4 +1166,171 @@ mod tests {
         assert_eq!(lines.len(), 2);
         assert_eq!(lines[1], "world");
     }
+
+    // -- select-pane navigation tests --
+
+    fn registry_with_panes(pane_ids: &[&str]) -> PaneRegistry {
+        let panes = pane_ids
+            .iter()
+            .map(|id| {
+                (
+                    id.to_string(),
+                    PaneEntry {
+                        daemon_session_id: format!("d-{}", id),
+                        title: String::new(),
+                        border_style: String::new(),
+                        window_id: "0".to_string(),
+                        hidden: false,
+                        parent: None,
+                        split: None,
+                        cwd: None,
+                    },
+                )
+            })
+            .collect();
+        let windows = HashMap::from([(
+            "0".to_string(),
+            WindowEntry {
+                name: "main".to_string(),
+                pane_ids: pane_ids.iter().map(ToString::to_string).collect(),
+                active_pane: None,
+                last_pane: None,
+            },
+        )]);
+        let sessions = HashMap::from([(
+            "kild_0".to_string(),
+            SessionEntry {
+                name: "kild_0".to_string(),
+                windows: vec!["0".to_string()],
+            },
+        )]);
+        PaneRegistry {
+            next_pane_id: pane_ids.len() as u32,
+            session_name: "kild_0".to_string(),
+            panes,
+            windows,
+            sessions,
+        }
+    }
+
+    #[test]
+    fn test_neighbour_pane_steps_through_window_order() {
+        let registry = registry_with_panes(&["%0", "%1", "%2"]);
+        assert_eq!(
+            neighbour_pane(&registry, "%1", PaneDirection::Right).as_deref(),
+            Some("%2")
+        );
+        assert_eq!(
+            neighbour_pane(&registry, "%1", PaneDirection::Down).as_deref(),
+            Some("%2")
+        );
+        assert_eq!(
+            neighbour_pane(&registry, "%1", PaneDirection::Left).as_deref(),
+            Some("%0")
+        );
+        assert_eq!(
+            neighbour_pane(&registry, "%1", PaneDirection::Up).as_deref(),
+            Some("%0")
+        );
+    }
+
+    #[test]
+    fn test_neighbour_pane_wraps_and_skips_hidden() {
+        let mut registry = registry_with_panes(&["%0", "%1", "%2"]);
+        assert_eq!(
+            neighbour_pane(&registry, "%2", PaneDirection::Right).as_deref(),
+            Some("%0")
+        );
+
+        registry.panes.get_mut("%1").unwrap().hidden = true;
+        assert_eq!(
+            neighbour_pane(&registry, "%0", PaneDirection::Right).as_deref(),
+            Some("%2")
+        );
+    }
+
+    #[test]
+    fn test_select_pane_by_direction_updates_active() {
+        let mut registry = registry_with_panes(&["%0", "%1"]);
+        let next = neighbour_pane(&registry, "%0", PaneDirection::Right).unwrap();
+        registry.select_pane(&next).unwrap();
+        assert_eq!(registry.active_pane("0"), Some("%1"));
+        assert_eq!(registry.windows["0"].last_pane.as_deref(), Some("%0"));
+    }
+
+    #[test]
+    fn test_resolve_window_id_targets() {
+        let registry = registry_with_panes(&["%0", "%1"]);
+        assert_eq!(resolve_window_id(&registry, Some("%1")).unwrap(), "0");
+        assert_eq!(resolve_window_id(&registry, Some("kild_0")).unwrap(), "0");
+        assert_eq!(resolve_window_id(&registry, Some("kild_0:0")).unwrap(), "0");
+        assert_eq!(
+            resolve_window_id(&registry, Some("kild_0:0.%1")).unwrap(),
+            "0"
+        );
+        assert!(resolve_window_id(&registry, Some("kild_0:7")).is_err());
+        assert!(resolve_window_id(&registry, Some("%9")).is_err());
+    }
+
+    // -- format expansion tests --
+
+    fn sample_vars() -> FormatVars<'static> {
+        FormatVars {
+            pane_id: "%3",
+            session_name: "kild_0",
+            window_index: "1",
+            window_name: "workers",
+            pane_title: "researcher",
+            pane_current_path: "/work/repo",
+        }
+    }
+
+    #[test]
+    fn test_expand_format_pane_id() {
+        assert_eq!(expand_format("#{pane_id}", &sample_vars()), "%3");
+    }
+
+    #[test]
+    fn test_expand_format_window_id() {
+        assert_eq!(expand_format("#{window_id}", &sample_vars()), "@1");
+        assert_eq!(expand_format("#{window_index}", &sample_vars()), "1");
+    }
+
+    #[test]
+    fn test_expand_format_session_name() {
+        assert_eq!(expand_format("#{session_name}", &sample_vars()), "kild_0");
+    }
+
+    #[test]
+    fn test_expand_format_pane_current_path() {
+        assert_eq!(
+            expand_format("#{pane_current_path}", &sample_vars()),
+            "/work/repo"
+        );
+    }
+
+    #[test]
+    fn test_expand_format_combined() {
+        assert_eq!(
+            expand_format("#{session_name}:#{window_id}.#{pane_id}", &sample_vars()),
+            "kild_0:@1.%3"
+        );
+    }
+
+    #[test]
+    fn test_expand_format_unknown_token_passes_through() {
+        assert_eq!(
+            expand_format("#{pane_id} #{client_tty} #{pane_width}", &sample_vars()),
+            "%3 #{client_tty} #{pane_width}"
+        );
+    }
+
+    #[test]
+    fn test_expand_format_unset_vars_are_empty() {
+        let vars = FormatVars {
+            pane_id: "%0",
+            ..Default::default()
+        };
+        assert_eq!(expand_format("[#{pane_current_path}]", &vars), "[]");
+    }
 }
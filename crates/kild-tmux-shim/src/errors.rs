@@ -21,7 +21,8 @@ pub enum ShimError {
 impl From<kild_protocol::IpcError> for ShimError {
     fn from(e: kild_protocol::IpcError) -> Self {
         match e {
-            kild_protocol::IpcError::NotRunning { .. } => ShimError::DaemonNotRunning,
+            kild_protocol::IpcError::NotRunning { .. }
+            | kild_protocol::IpcError::DaemonNotRunning { .. } => ShimError::DaemonNotRunning,
             other => ShimError::IpcError {
                 message: other.to_string(),
             },
@@ -29,6 +30,26 @@ impl From<kild_protocol::IpcError> for ShimError {
     }
 }
 
+impl ShimError {
+    pub fn parse(msg: impl fmt::Display) -> Self {
+        Self::ParseError {
+            message: msg.to_string(),
+        }
+    }
+
+    pub fn state(msg: impl fmt::Display) -> Self {
+        Self::StateError {
+            message: msg.to_string(),
+        }
+    }
+
+    pub fn ipc(msg: impl fmt::Display) -> Self {
+        Self::IpcError {
+            message: msg.to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,7 +66,7 @@ mod tests {
 
     #[test]
     fn test_from_ipc_error_connection_failed() {
-        let io_err = std::io::Error::new(std::io::ErrorKind::Other, "failed");
+        let io_err = std::io::Error::other("failed");
         let ipc_err = IpcError::ConnectionFailed(io_err);
         let shim_err: ShimError = ipc_err.into();
         assert!(
@@ -69,23 +90,3 @@ mod tests {
         );
     }
 }
-
-impl ShimError {
-    pub fn parse(msg: impl fmt::Display) -> Self {
-        Self::ParseError {
-            message: msg.to_string(),
-        }
-    }
-
-    pub fn state(msg: impl fmt::Display) -> Self {
-        Self::StateError {
-            message: msg.to_string(),
-        }
-    }
-
-    pub fn ipc(msg: impl fmt::Display) -> Self {
-        Self::IpcError {
-            message: msg.to_string(),
-        }
-    }
-}
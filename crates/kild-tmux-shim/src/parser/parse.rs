@@ -7,10 +7,11 @@ use super::*;
 pub fn parse(args: &[String]) -> Result<TmuxCommand<'_>, ShimError> {
     let mut iter = args.iter().peekable();
 
-    // Strip global -L <socket> flag
+    // Strip global -L <socket> flag. Global flags precede the subcommand, so
+    // a later -L (e.g. `select-pane -L`) belongs to the subcommand.
     let mut filtered: Vec<&str> = Vec::new();
     while let Some(arg) = iter.next() {
-        if arg == "-L" {
+        if filtered.is_empty() && arg == "-L" {
             // Consume the socket name arg too
             iter.next();
             continue;
@@ -38,6 +39,7 @@ pub fn parse(args: &[String]) -> Result<TmuxCommand<'_>, ShimError> {
         "kill-pane" | "killp" => parse_kill_pane(&remaining),
         "display-message" | "display" => parse_display_message(&remaining),
         "select-pane" | "selectp" => parse_select_pane(&remaining),
+        "last-pane" | "lastp" => parse_last_pane(&remaining),
         "set-option" | "set" => parse_set_option(&remaining),
         "select-layout" | "selectl" => parse_select_layout(&remaining),
         "resize-pane" | "resizep" => parse_resize_pane(&remaining),
@@ -184,6 +186,8 @@ fn parse_select_pane<'a>(args: &[&'a str]) -> Result<TmuxCommand<'a>, ShimError>
     let mut target = None;
     let mut style = None;
     let mut title = None;
+    let mut direction = None;
+    let mut last = false;
     let mut i = 0;
 
     while i < args.len() {
@@ -191,6 +195,11 @@ fn parse_select_pane<'a>(args: &[&'a str]) -> Result<TmuxCommand<'a>, ShimError>
             "-t" => target = Some(take_value(args, &mut i)?),
             "-P" => style = Some(take_value(args, &mut i)?),
             "-T" => title = Some(take_value(args, &mut i)?),
+            "-L" => direction = Some(PaneDirection::Left),
+            "-R" => direction = Some(PaneDirection::Right),
+            "-U" => direction = Some(PaneDirection::Up),
+            "-D" => direction = Some(PaneDirection::Down),
+            "-l" => last = true,
             _ => {}
         }
         i += 1;
@@ -200,9 +209,25 @@ fn parse_select_pane<'a>(args: &[&'a str]) -> Result<TmuxCommand<'a>, ShimError>
         target,
         style,
         title,
+        direction,
+        last,
     }))
 }
 
+fn parse_last_pane<'a>(args: &[&'a str]) -> Result<TmuxCommand<'a>, ShimError> {
+    let mut target = None;
+    let mut i = 0;
+
+    while i < args.len() {
+        if args[i] == "-t" {
+            target = Some(take_value(args, &mut i)?);
+        }
+        i += 1;
+    }
+
+    Ok(TmuxCommand::LastPane(LastPaneArgs { target }))
+}
+
 fn parse_set_option<'a>(args: &[&'a str]) -> Result<TmuxCommand<'a>, ShimError> {
     let mut scope = OptionScope::Session;
     let mut target = None;
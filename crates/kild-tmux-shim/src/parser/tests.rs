@@ -480,6 +480,59 @@ fn test_alias_selectp() {
     assert!(matches!(cmd, TmuxCommand::SelectPane(_)));
 }
 
+#[test]
+fn test_select_pane_direction_flags() {
+    for (flag, expected) in [
+        ("-L", PaneDirection::Left),
+        ("-R", PaneDirection::Right),
+        ("-U", PaneDirection::Up),
+        ("-D", PaneDirection::Down),
+    ] {
+        let a = args(&format!("select-pane -t %1 {}", flag));
+        let cmd = parse(&a).unwrap();
+        if let TmuxCommand::SelectPane(sp) = cmd {
+            assert_eq!(sp.target, Some("%1"));
+            assert_eq!(sp.direction, Some(expected));
+            assert!(!sp.last);
+        } else {
+            panic!("expected SelectPane");
+        }
+    }
+}
+
+#[test]
+fn test_select_pane_last_flag() {
+    let a = args("select-pane -l");
+    let cmd = parse(&a).unwrap();
+    if let TmuxCommand::SelectPane(sp) = cmd {
+        assert!(sp.last);
+        assert!(sp.direction.is_none());
+    } else {
+        panic!("expected SelectPane");
+    }
+}
+
+#[test]
+fn test_last_pane_with_target() {
+    let a = args("last-pane -t kild_0:0");
+    let cmd = parse(&a).unwrap();
+    if let TmuxCommand::LastPane(lp) = cmd {
+        assert_eq!(lp.target, Some("kild_0:0"));
+    } else {
+        panic!("expected LastPane");
+    }
+}
+
+#[test]
+fn test_alias_lastp() {
+    let a = args("lastp");
+    let cmd = parse(&a).unwrap();
+    assert!(matches!(
+        cmd,
+        TmuxCommand::LastPane(LastPaneArgs { target: None })
+    ));
+}
+
 // --- set-option scopes ---
 
 #[test]
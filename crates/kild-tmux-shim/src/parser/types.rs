@@ -7,6 +7,7 @@ pub enum TmuxCommand<'a> {
     KillPane(KillPaneArgs<'a>),
     DisplayMessage(DisplayMsgArgs<'a>),
     SelectPane(SelectPaneArgs<'a>),
+    LastPane(LastPaneArgs<'a>),
     SetOption(SetOptionArgs<'a>),
     SelectLayout(SelectLayoutArgs<'a>),
     ResizePane(ResizePaneArgs<'a>),
@@ -62,6 +63,23 @@ pub struct SelectPaneArgs<'a> {
     pub target: Option<&'a str>,
     pub style: Option<&'a str>,
     pub title: Option<&'a str>,
+    /// `-L`/`-R`/`-U`/`-D`: select the neighbour of the target instead.
+    pub direction: Option<PaneDirection>,
+    /// `-l`: behave like `last-pane`.
+    pub last: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+#[derive(Debug)]
+pub struct LastPaneArgs<'a> {
+    pub target: Option<&'a str>,
 }
 
 #[derive(Debug)]
@@ -4,8 +4,9 @@
 //!
 //! - **Reads**: Use `load_shared()` to acquire a shared (read-only) lock.
 //!   Multiple readers can hold shared locks concurrently.
-//! - **Writes**: Use `load()` to acquire an exclusive lock, mutate the registry,
-//!   then call `save()` (which re-acquires the exclusive lock).
+//! - **Writes**: Use `update()` to read, mutate, and write the registry under
+//!   one exclusive lock. `load()` followed by `save()` releases the lock in
+//!   between, so a concurrent writer's changes can be lost.
 //!
 //! Locks are automatically released when the `Flock` handle is dropped (RAII).
 
@@ -61,11 +62,74 @@ impl PaneRegistry {
         Ok(())
     }
 
+    /// Register a pane and add it to its window.
+    ///
+    /// A pane split from `entry.parent` is placed right after its parent in
+    /// the window's pane order, like tmux; other panes go at the end.
+    pub fn add_pane(&mut self, pane_id: String, entry: PaneEntry) {
+        if let Some(window) = self.windows.get_mut(&entry.window_id) {
+            let position = entry
+                .parent
+                .as_ref()
+                .and_then(|parent| window.pane_ids.iter().position(|id| id == parent))
+                .map_or(window.pane_ids.len(), |i| i + 1);
+            window.pane_ids.insert(position, pane_id.clone());
+        }
+        self.panes.insert(pane_id, entry);
+    }
+
+    /// Focused pane of a window: the last one selected, else its first pane.
+    pub fn active_pane(&self, window_id: &str) -> Option<&str> {
+        let window = self.windows.get(window_id)?;
+        window
+            .active_pane
+            .as_deref()
+            .or_else(|| window.pane_ids.first().map(String::as_str))
+    }
+
+    /// Focus a pane, remembering the previously focused pane of its window
+    /// for `last-pane`. Selecting the already active pane changes nothing.
+    pub fn select_pane(&mut self, pane_id: &str) -> Result<(), ShimError> {
+        let window_id = self
+            .panes
+            .get(pane_id)
+            .map(|pane| pane.window_id.clone())
+            .ok_or_else(|| ShimError::state(format!("can't find pane: {}", pane_id)))?;
+        let previous = self.active_pane(&window_id).map(str::to_string);
+        if previous.as_deref() == Some(pane_id) {
+            return Ok(());
+        }
+        if let Some(window) = self.windows.get_mut(&window_id) {
+            window.last_pane = previous;
+            window.active_pane = Some(pane_id.to_string());
+        }
+        Ok(())
+    }
+
+    /// Focus the window's previously focused pane, returning its id.
+    /// Calling this twice toggles back to where it started.
+    pub fn last_pane(&mut self, window_id: &str) -> Result<String, ShimError> {
+        let last = self
+            .windows
+            .get(window_id)
+            .and_then(|window| window.last_pane.clone())
+            .filter(|pane_id| self.panes.contains_key(pane_id))
+            .ok_or_else(|| ShimError::state("no last pane"))?;
+        self.select_pane(&last)?;
+        Ok(last)
+    }
+
     /// Remove a pane and clean up its window reference.
     pub fn remove_pane(&mut self, pane_id: &str) -> Option<PaneEntry> {
         if let Some(pane) = self.panes.remove(pane_id) {
             if let Some(window) = self.windows.get_mut(&pane.window_id) {
                 window.pane_ids.retain(|id| id != pane_id);
+                if window.active_pane.as_deref() == Some(pane_id) {
+                    window.active_pane = window.last_pane.take();
+                }
+                if window.last_pane.as_deref() == Some(pane_id) {
+                    window.last_pane = None;
+                }
             }
             Some(pane)
         } else {
@@ -81,12 +145,36 @@ pub struct PaneEntry {
     pub border_style: String,
     pub window_id: String,
     pub hidden: bool,
+    /// Pane this one was split from (`None` for a window's first pane).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// Direction of the split that created this pane.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub split: Option<SplitDirection>,
+    /// Working directory the pane was started in (`#{pane_current_path}`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+}
+
+/// How a pane was split from its parent: `-h` places panes side by side,
+/// `-v` (the tmux default) stacks them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitDirection {
+    Horizontal,
+    Vertical,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WindowEntry {
     pub name: String,
     pub pane_ids: Vec<String>,
+    /// Focused pane, set by `select-pane`. `None` means the first pane.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_pane: Option<String>,
+    /// Previously focused pane, the target of `last-pane`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_pane: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -153,12 +241,8 @@ fn acquire_lock(session_id: &str, mode: LockMode) -> Result<Flock<fs::File>, Shi
     })
 }
 
-/// Load the registry with a shared (read-only) lock.
-/// Multiple readers can hold shared locks concurrently.
-pub fn load_shared(session_id: &str) -> Result<PaneRegistry, ShimError> {
+fn read_registry(session_id: &str) -> Result<PaneRegistry, ShimError> {
     let data_path = panes_path(session_id)?;
-    let _lock = acquire_lock(session_id, LockMode::Shared)?;
-
     let content = fs::read_to_string(&data_path).map_err(|e| ShimError::StateError {
         message: format!("failed to read {}: {}", data_path.display(), e),
     })?;
@@ -173,29 +257,8 @@ pub fn load_shared(session_id: &str) -> Result<PaneRegistry, ShimError> {
     Ok(registry)
 }
 
-/// Load the registry with an exclusive (write) lock.
-pub fn load(session_id: &str) -> Result<PaneRegistry, ShimError> {
-    let data_path = panes_path(session_id)?;
-    let _lock = acquire_lock(session_id, LockMode::Exclusive)?;
-
-    let content = fs::read_to_string(&data_path).map_err(|e| ShimError::StateError {
-        message: format!("failed to read {}: {}", data_path.display(), e),
-    })?;
-
-    let registry: PaneRegistry =
-        serde_json::from_str(&content).map_err(|e| ShimError::StateError {
-            message: format!("failed to parse pane registry: {}", e),
-        })?;
-
-    registry.validate()?;
-
-    Ok(registry)
-}
-
-pub fn save(session_id: &str, registry: &PaneRegistry) -> Result<(), ShimError> {
+fn write_registry(session_id: &str, registry: &PaneRegistry) -> Result<(), ShimError> {
     let data_path = panes_path(session_id)?;
-    let _lock = acquire_lock(session_id, LockMode::Exclusive)?;
-
     let content = serde_json::to_string_pretty(registry).map_err(|e| ShimError::StateError {
         message: format!("failed to serialize pane registry: {}", e),
     })?;
@@ -209,6 +272,39 @@ pub fn save(session_id: &str, registry: &PaneRegistry) -> Result<(), ShimError>
     Ok(())
 }
 
+/// Load the registry with a shared (read-only) lock.
+/// Multiple readers can hold shared locks concurrently.
+pub fn load_shared(session_id: &str) -> Result<PaneRegistry, ShimError> {
+    let _lock = acquire_lock(session_id, LockMode::Shared)?;
+    read_registry(session_id)
+}
+
+/// Load the registry with an exclusive (write) lock.
+pub fn load(session_id: &str) -> Result<PaneRegistry, ShimError> {
+    let _lock = acquire_lock(session_id, LockMode::Exclusive)?;
+    read_registry(session_id)
+}
+
+pub fn save(session_id: &str, registry: &PaneRegistry) -> Result<(), ShimError> {
+    let _lock = acquire_lock(session_id, LockMode::Exclusive)?;
+    write_registry(session_id, registry)
+}
+
+/// Read, modify, and write the registry while holding one exclusive lock.
+///
+/// The registry is only written back if `f` succeeds, so a failed command
+/// leaves no half-registered pane behind.
+pub fn update<T>(
+    session_id: &str,
+    f: impl FnOnce(&mut PaneRegistry) -> Result<T, ShimError>,
+) -> Result<T, ShimError> {
+    let _lock = acquire_lock(session_id, LockMode::Exclusive)?;
+    let mut registry = read_registry(session_id)?;
+    let value = f(&mut registry)?;
+    write_registry(session_id, &registry)?;
+    Ok(value)
+}
+
 pub fn allocate_pane_id(registry: &mut PaneRegistry) -> String {
     let id = format!("%{}", registry.next_pane_id);
     registry.next_pane_id += 1;
@@ -232,6 +328,9 @@ pub fn init_registry(session_id: &str, daemon_session_id: &str) -> Result<(), Sh
             border_style: String::new(),
             window_id: "0".to_string(),
             hidden: false,
+            parent: None,
+            split: None,
+            cwd: None,
         },
     );
 
@@ -241,6 +340,8 @@ pub fn init_registry(session_id: &str, daemon_session_id: &str) -> Result<(), Sh
         WindowEntry {
             name: "main".to_string(),
             pane_ids: vec!["%0".to_string()],
+            active_pane: None,
+            last_pane: None,
         },
     );
 
@@ -320,6 +421,9 @@ mod tests {
                 border_style: String::new(),
                 window_id: "0".to_string(),
                 hidden: false,
+                parent: None,
+                split: None,
+                cwd: None,
             },
         );
         panes.insert(
@@ -330,6 +434,9 @@ mod tests {
                 border_style: "fg=blue".to_string(),
                 window_id: "0".to_string(),
                 hidden: false,
+                parent: None,
+                split: None,
+                cwd: None,
             },
         );
 
@@ -339,6 +446,8 @@ mod tests {
             WindowEntry {
                 name: "main".to_string(),
                 pane_ids: vec!["%0".to_string(), "%1".to_string()],
+                active_pane: None,
+                last_pane: None,
             },
         );
 
@@ -470,6 +579,9 @@ mod tests {
                 border_style: String::new(),
                 window_id: "0".to_string(),
                 hidden: false,
+                parent: None,
+                split: None,
+                cwd: None,
             },
         );
 
@@ -479,6 +591,8 @@ mod tests {
             WindowEntry {
                 name: "main".to_string(),
                 pane_ids: vec!["%0".to_string()],
+                active_pane: None,
+                last_pane: None,
             },
         );
 
@@ -504,6 +618,9 @@ mod tests {
                 border_style: String::new(),
                 window_id: "999".to_string(),
                 hidden: false,
+                parent: None,
+                split: None,
+                cwd: None,
             },
         );
 
@@ -527,6 +644,8 @@ mod tests {
             WindowEntry {
                 name: "main".to_string(),
                 pane_ids: vec!["%99".to_string()],
+                active_pane: None,
+                last_pane: None,
             },
         );
 
@@ -553,6 +672,9 @@ mod tests {
                 border_style: String::new(),
                 window_id: "0".to_string(),
                 hidden: false,
+                parent: None,
+                split: None,
+                cwd: None,
             },
         );
         panes.insert(
@@ -563,6 +685,9 @@ mod tests {
                 border_style: String::new(),
                 window_id: "0".to_string(),
                 hidden: false,
+                parent: None,
+                split: None,
+                cwd: None,
             },
         );
 
@@ -572,6 +697,8 @@ mod tests {
             WindowEntry {
                 name: "main".to_string(),
                 pane_ids: vec!["%0".to_string(), "%1".to_string()],
+                active_pane: None,
+                last_pane: None,
             },
         );
 
@@ -689,4 +816,228 @@ mod tests {
         let dir = state_dir(&test_id).unwrap();
         fs::remove_dir_all(&dir).ok();
     }
+
+    fn split_entry(daemon_session_id: &str, parent: &str, split: SplitDirection) -> PaneEntry {
+        PaneEntry {
+            daemon_session_id: daemon_session_id.to_string(),
+            title: String::new(),
+            border_style: String::new(),
+            window_id: "0".to_string(),
+            hidden: false,
+            parent: Some(parent.to_string()),
+            split: Some(split),
+            cwd: None,
+        }
+    }
+
+    #[test]
+    fn test_split_pane_registers_child_of_target() {
+        let test_id = format!("test-{}", uuid::Uuid::new_v4());
+        init_registry(&test_id, "daemon-abc-123").unwrap();
+
+        let pane_id = update(&test_id, |registry| {
+            let pane_id = allocate_pane_id(registry);
+            registry.add_pane(
+                pane_id.clone(),
+                split_entry("d-1", "%0", SplitDirection::Horizontal),
+            );
+            Ok(pane_id)
+        })
+        .unwrap();
+        assert_eq!(pane_id, "%1");
+
+        let registry = load_shared(&test_id).unwrap();
+        assert_eq!(registry.panes.len(), 2);
+        assert_eq!(registry.next_pane_id, 2);
+        assert!(registry.panes["%0"].parent.is_none());
+        assert_eq!(registry.panes["%1"].parent.as_deref(), Some("%0"));
+        assert_eq!(registry.panes["%1"].split, Some(SplitDirection::Horizontal));
+        assert_eq!(registry.panes["%1"].window_id, "0");
+        assert_eq!(registry.windows["0"].pane_ids, vec!["%0", "%1"]);
+
+        let dir = state_dir(&test_id).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_split_pane_inserted_after_parent() {
+        let test_id = format!("test-{}", uuid::Uuid::new_v4());
+        init_registry(&test_id, "daemon-abc-123").unwrap();
+
+        for daemon_session_id in ["d-1", "d-2"] {
+            update(&test_id, |registry| {
+                let pane_id = allocate_pane_id(registry);
+                registry.add_pane(
+                    pane_id,
+                    split_entry(daemon_session_id, "%0", SplitDirection::Vertical),
+                );
+                Ok(())
+            })
+            .unwrap();
+        }
+
+        // Like tmux, the newest split sits directly after the pane it split
+        let registry = load_shared(&test_id).unwrap();
+        assert_eq!(registry.windows["0"].pane_ids, vec!["%0", "%2", "%1"]);
+        assert_eq!(registry.panes["%2"].parent.as_deref(), Some("%0"));
+        assert_eq!(registry.panes["%2"].split, Some(SplitDirection::Vertical));
+
+        let dir = state_dir(&test_id).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_update_concurrent_splits_keep_every_pane() {
+        use std::thread;
+
+        let test_id = format!("test-{}", uuid::Uuid::new_v4());
+        init_registry(&test_id, "daemon-abc-123").unwrap();
+
+        let handles: Vec<_> = (0..5)
+            .map(|i| {
+                let id = test_id.clone();
+                thread::spawn(move || {
+                    update(&id, |registry| {
+                        let pane_id = allocate_pane_id(registry);
+                        registry.add_pane(
+                            pane_id,
+                            split_entry(&format!("d-{i}"), "%0", SplitDirection::Vertical),
+                        );
+                        Ok(())
+                    })
+                    .unwrap();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let registry = load_shared(&test_id).unwrap();
+        assert_eq!(registry.panes.len(), 6);
+        assert_eq!(registry.windows["0"].pane_ids.len(), 6);
+        assert_eq!(registry.next_pane_id, 6);
+
+        let dir = state_dir(&test_id).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_update_error_leaves_registry_unchanged() {
+        let test_id = format!("test-{}", uuid::Uuid::new_v4());
+        init_registry(&test_id, "daemon-abc-123").unwrap();
+
+        let result: Result<(), ShimError> = update(&test_id, |registry| {
+            allocate_pane_id(registry);
+            Err(ShimError::state("daemon unavailable"))
+        });
+        assert!(result.is_err());
+
+        let registry = load_shared(&test_id).unwrap();
+        assert_eq!(registry.next_pane_id, 1);
+        assert_eq!(registry.panes.len(), 1);
+
+        let dir = state_dir(&test_id).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_pane_entry_without_split_fields_deserializes() {
+        let json = r#"{"daemon_session_id":"d-1","title":"","border_style":"","window_id":"0","hidden":false}"#;
+        let entry: PaneEntry = serde_json::from_str(json).unwrap();
+        assert!(entry.parent.is_none());
+        assert!(entry.split.is_none());
+    }
+
+    #[test]
+    fn test_select_pane_by_id_sets_active() {
+        let test_id = format!("test-{}", uuid::Uuid::new_v4());
+        init_registry(&test_id, "daemon-abc-123").unwrap();
+
+        update(&test_id, |registry| {
+            let pane_id = allocate_pane_id(registry);
+            registry.add_pane(
+                pane_id,
+                split_entry("d-1", "%0", SplitDirection::Horizontal),
+            );
+            registry.select_pane("%1")
+        })
+        .unwrap();
+
+        let registry = load_shared(&test_id).unwrap();
+        assert_eq!(registry.active_pane("0"), Some("%1"));
+        assert_eq!(registry.windows["0"].active_pane.as_deref(), Some("%1"));
+        assert_eq!(registry.windows["0"].last_pane.as_deref(), Some("%0"));
+
+        let dir = state_dir(&test_id).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_active_pane_defaults_to_first_pane() {
+        let test_id = format!("test-{}", uuid::Uuid::new_v4());
+        init_registry(&test_id, "daemon-abc-123").unwrap();
+
+        let registry = load_shared(&test_id).unwrap();
+        assert_eq!(registry.active_pane("0"), Some("%0"));
+        assert!(registry.windows["0"].active_pane.is_none());
+
+        let dir = state_dir(&test_id).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_select_unknown_pane_fails() {
+        let test_id = format!("test-{}", uuid::Uuid::new_v4());
+        init_registry(&test_id, "daemon-abc-123").unwrap();
+
+        let mut registry = load_shared(&test_id).unwrap();
+        let err = registry.select_pane("%99").unwrap_err();
+        assert!(err.to_string().contains("can't find pane: %99"));
+        assert_eq!(registry.active_pane("0"), Some("%0"));
+
+        let dir = state_dir(&test_id).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_last_pane_toggles_back_and_forth() {
+        let test_id = format!("test-{}", uuid::Uuid::new_v4());
+        init_registry(&test_id, "daemon-abc-123").unwrap();
+
+        let mut registry = load_shared(&test_id).unwrap();
+        let pane_id = allocate_pane_id(&mut registry);
+        registry.add_pane(pane_id, split_entry("d-1", "%0", SplitDirection::Vertical));
+
+        // Nothing selected yet, so there is nowhere to go back to
+        assert!(registry.last_pane("0").is_err());
+
+        registry.select_pane("%1").unwrap();
+        assert_eq!(registry.last_pane("0").unwrap(), "%0");
+        assert_eq!(registry.active_pane("0"), Some("%0"));
+        assert_eq!(registry.last_pane("0").unwrap(), "%1");
+        assert_eq!(registry.active_pane("0"), Some("%1"));
+
+        let dir = state_dir(&test_id).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_remove_active_pane_falls_back_to_last() {
+        let test_id = format!("test-{}", uuid::Uuid::new_v4());
+        init_registry(&test_id, "daemon-abc-123").unwrap();
+
+        let mut registry = load_shared(&test_id).unwrap();
+        let pane_id = allocate_pane_id(&mut registry);
+        registry.add_pane(pane_id, split_entry("d-1", "%0", SplitDirection::Vertical));
+        registry.select_pane("%1").unwrap();
+
+        registry.remove_pane("%1");
+        assert_eq!(registry.active_pane("0"), Some("%0"));
+        assert!(registry.windows["0"].last_pane.is_none());
+        assert!(registry.last_pane("0").is_err());
+
+        let dir = state_dir(&test_id).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
 }
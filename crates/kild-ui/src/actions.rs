@@ -8,6 +8,9 @@ use std::path::PathBuf;
 use kild_core::{Command, CoreStore, Event, KildConfig, OpenMode, Store, session_ops};
 
 use kild_core::SessionSnapshot;
+use kild_core::errors::KildError;
+
+use crate::state::sessions::{SessionLoad, SessionLoadError};
 
 /// Load config and create a CoreStore instance.
 ///
@@ -82,21 +85,30 @@ pub fn create_kild(
 /// Refresh the list of sessions from disk.
 ///
 /// Returns `(displays, error)` where `error` is `Some` if session loading failed.
-pub fn refresh_sessions() -> (Vec<SessionSnapshot>, Option<String>) {
+pub fn refresh_sessions() -> SessionLoad {
     tracing::info!(event = "ui.refresh_sessions.started");
 
-    match session_ops::list_sessions() {
+    match session_ops::list_sessions(false) {
         Ok(sessions) => {
+            let config = KildConfig::load_hierarchy().unwrap_or_default();
             let displays = sessions
                 .into_iter()
-                .map(SessionSnapshot::from_session)
+                .map(|session| SessionSnapshot::from_session(session, &config))
                 .collect();
             tracing::info!(event = "ui.refresh_sessions.completed");
             (displays, None)
         }
         Err(e) => {
-            tracing::error!(event = "ui.refresh_sessions.failed", error = %e);
-            (Vec::new(), Some(e.to_string()))
+            tracing::error!(
+                event = "ui.refresh_sessions.failed",
+                error = %e,
+                code = e.error_code()
+            );
+            let error = SessionLoadError {
+                code: e.error_code(),
+                message: e.to_string(),
+            };
+            (Vec::new(), Some(error))
         }
     }
 }
@@ -216,8 +228,9 @@ pub fn dispatch_set_active_project(path: Option<PathBuf>) -> Result<Vec<Event>,
 
 #[cfg(test)]
 mod tests {
+    use kild_core::sessions::info::test_helpers::make_test_snapshot;
     use kild_core::sessions::types::SessionStatus;
-    use kild_core::{GitStatus, ProcessStatus, Session, SessionSnapshot};
+    use kild_core::{ProcessStatus, Session, SessionSnapshot};
     use std::path::PathBuf;
 
     /// Get branches of all stopped kilds (for testing filtering logic).
@@ -262,10 +275,8 @@ mod tests {
 
     fn make_display(id: &str, branch: &str, process_status: ProcessStatus) -> SessionSnapshot {
         SessionSnapshot {
-            session: make_session(id, branch),
             process_status,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
+            ..make_test_snapshot(make_session(id, branch))
         }
     }
 
@@ -7,6 +7,10 @@
 //! - `stop_session_async()` — stop a running daemon session
 //! - `connect_for_attach()` — two-connection attach for streaming PTY output
 //! - `send_write_stdin()` / `send_resize()` / `send_detach()` — write operations
+//! - `reattach_reader()` / `reconnect_writer()` — reconnect with backoff after
+//!   the daemon connection drops (e.g. daemon restart)
+//! - `AttachStream::read_next()` — read streamed output, sending heartbeat
+//!   pings while idle when `daemon.heartbeat_interval_secs` is set
 //!
 //! Transport routing: when `remote_host` is set in config (or via KILD remote
 //! override), connections use TCP+TLS instead of the local Unix socket. Both
@@ -16,14 +20,15 @@ use std::os::unix::net::UnixStream;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 use base64::Engine;
 use futures::io::BufReader;
 use futures_rustls::TlsConnector;
 use kild_config::KildConfig;
 use kild_protocol::{
-    AsyncIpcClient, ClientMessage, DaemonMessage, DaemonSessionStatus, ErrorCode, IpcError,
-    SessionId, SessionStatus,
+    AsyncIpcClient, ClientMessage, DaemonMessage, DaemonSessionStatus, ErrorCode, Heartbeat,
+    IpcError, RetryPolicy, RetryState, SessionId, SessionStatus, Transport,
 };
 use smol::Async;
 use smol::io::split;
@@ -69,7 +74,7 @@ pub enum DaemonClientError {
 impl From<IpcError> for DaemonClientError {
     fn from(e: IpcError) -> Self {
         match e {
-            IpcError::NotRunning { path } => {
+            IpcError::NotRunning { path } | IpcError::DaemonNotRunning { path, .. } => {
                 DaemonClientError::Connect(std::io::Error::new(std::io::ErrorKind::NotFound, path))
             }
             IpcError::ConnectionFailed(io) => DaemonClientError::Connect(io),
@@ -88,9 +93,9 @@ impl From<IpcError> for DaemonClientError {
 /// Unifies Unix and TCP/TLS halves under a single type so both transport paths
 /// share one `AsyncIpcClient` type. One allocation per connection — acceptable
 /// for IPC.
-type DynReader = Box<dyn futures::io::AsyncBufRead + Send + Unpin>;
+pub(crate) type DynReader = Box<dyn futures::io::AsyncBufRead + Send + Unpin>;
 /// Type-erased writer: boxes any `AsyncWrite + Send + Unpin`.
-type DynWriter = Box<dyn futures::io::AsyncWrite + Send + Unpin>;
+pub(crate) type DynWriter = Box<dyn futures::io::AsyncWrite + Send + Unpin>;
 /// Unified async IPC client for both Unix socket and TCP/TLS connections.
 type ErasedUiClient = AsyncIpcClient<DynReader, DynWriter>;
 
@@ -118,9 +123,18 @@ async fn connect() -> Result<ErasedUiClient, DaemonClientError> {
 /// Uses `futures_rustls` (not tokio-rustls) because the rest of the UI async
 /// path uses the `futures::io` trait family. Box-pins the TLS stream so
 /// `futures::io::split()` halves satisfy `'static`.
+///
+/// When `auth_token` is set, sends it in a `Hello` before returning so the
+/// daemon accepts the requests that follow. `client_identity` is the client
+/// certificate chain and key presented to daemons that require mutual TLS.
 async fn connect_tcp(
     addr: &str,
     fingerprint: [u8; 32],
+    auth_token: Option<&str>,
+    client_identity: Option<(
+        Vec<rustls::pki_types::CertificateDer<'static>>,
+        rustls::pki_types::PrivateKeyDer<'static>,
+    )>,
 ) -> Result<ErasedUiClient, DaemonClientError> {
     let stream = smol::net::TcpStream::connect(addr)
         .await
@@ -128,12 +142,17 @@ async fn connect_tcp(
 
     let provider = Arc::new(rustls::crypto::ring::default_provider());
     let verifier = kild_core::daemon::tofu::TofuVerifier::new(fingerprint);
-    let config = rustls::ClientConfig::builder_with_provider(provider)
+    let builder = rustls::ClientConfig::builder_with_provider(provider)
         .with_safe_default_protocol_versions()
         .map_err(|e| DaemonClientError::Protocol(e.to_string()))?
         .dangerous()
-        .with_custom_certificate_verifier(verifier)
-        .with_no_client_auth();
+        .with_custom_certificate_verifier(verifier);
+    let config = match client_identity {
+        Some((cert_chain, key)) => builder
+            .with_client_auth_cert(cert_chain, key)
+            .map_err(|e| DaemonClientError::Protocol(e.to_string()))?,
+        None => builder.with_no_client_auth(),
+    };
 
     let connector = TlsConnector::from(Arc::new(config));
     let host = addr.split(':').next().unwrap_or(addr);
@@ -150,24 +169,31 @@ async fn connect_tcp(
     let pinned: Pin<Box<futures_rustls::client::TlsStream<smol::net::TcpStream>>> =
         Box::pin(tls_stream);
     let (r, w) = split(pinned);
-    Ok(AsyncIpcClient::new(
+    let mut client = AsyncIpcClient::new(
         Box::new(BufReader::new(r)) as DynReader,
         Box::new(w) as DynWriter,
-    ))
+    );
+
+    if let Some(token) = auth_token {
+        // No compression offer: the async client always writes plain frames.
+        let hello = ClientMessage::Hello {
+            id: next_request_id(),
+            protocol_version: kild_protocol::PROTOCOL_VERSION,
+            compression: Vec::new(),
+            auth_token: Some(token.to_string()),
+        };
+        match client.send(&hello).await? {
+            DaemonMessage::HelloAck { .. } => {}
+            other => return Err(DaemonClientError::UnexpectedResponse(other)),
+        }
+    }
+
+    Ok(client)
 }
 
-/// Connect to the daemon using Unix socket or TCP/TLS based on config.
-///
-/// Routing priority (highest wins):
-/// 1. `remote_host` in `~/.kild/config.toml`
-/// 2. Local Unix socket (default)
-///
-/// Note: the `--remote` CLI override is not checked here because kild-ui is a
-/// separate binary where `set_remote_override()` is never called. The UI reads
-/// remote config exclusively from the config file.
-async fn connect_for_config() -> Result<ErasedUiClient, DaemonClientError> {
-    // Check config file
-    let config = match KildConfig::load_hierarchy() {
+/// Load the config hierarchy, falling back to defaults on error.
+fn load_config() -> KildConfig {
+    match KildConfig::load_hierarchy() {
         Ok(c) => c,
         Err(e) => {
             warn!(
@@ -178,7 +204,20 @@ async fn connect_for_config() -> Result<ErasedUiClient, DaemonClientError> {
             );
             KildConfig::default()
         }
-    };
+    }
+}
+
+/// Connect to the daemon using Unix socket or TCP/TLS based on config.
+///
+/// Routing priority (highest wins):
+/// 1. `remote_host` in `~/.kild/config.toml`
+/// 2. Local Unix socket (default)
+///
+/// Note: the `--remote` CLI override is not checked here because kild-ui is a
+/// separate binary where `set_remote_override()` is never called. The UI reads
+/// remote config exclusively from the config file.
+async fn connect_for_config() -> Result<ErasedUiClient, DaemonClientError> {
+    let config = load_config();
     if let Some(ref remote_host) = config.daemon.remote_host {
         let fp_str = config
             .daemon
@@ -191,7 +230,22 @@ async fn connect_for_config() -> Result<ErasedUiClient, DaemonClientError> {
             })?;
         let fingerprint = kild_core::daemon::tofu::parse_fingerprint(fp_str)
             .map_err(DaemonClientError::Protocol)?;
-        return connect_tcp(remote_host, fingerprint).await;
+        let client_identity = config
+            .daemon
+            .remote_client_identity()
+            .map_err(DaemonClientError::Protocol)?
+            .map(|(cert_path, key_path)| {
+                kild_core::daemon::tofu::load_client_identity(cert_path, key_path)
+            })
+            .transpose()
+            .map_err(DaemonClientError::Protocol)?;
+        return connect_tcp(
+            remote_host,
+            fingerprint,
+            config.daemon.remote_auth_token.as_deref(),
+            client_identity,
+        )
+        .await;
     }
 
     connect().await
@@ -226,7 +280,7 @@ pub async fn ping_daemon_async() -> Result<bool, DaemonClientError> {
     let response = client.send(&request).await?;
 
     match response {
-        DaemonMessage::Ack { .. } => {
+        DaemonMessage::Ack { .. } | DaemonMessage::Pong { .. } => {
             info!(event = "ui.daemon.ping_completed", result = "ack");
             Ok(true)
         }
@@ -396,15 +450,65 @@ pub async fn create_session_async(
     }
 }
 
+/// Streaming side of an attach: PtyOutput arrives on `reader`.
+///
+/// Keeps the same connection's write half so idle streams can send heartbeat
+/// pings; nothing else is written to it.
+pub struct AttachStream {
+    reader: DynReader,
+    writer: DynWriter,
+    heartbeat: Option<Heartbeat>,
+}
+
+impl AttachStream {
+    fn new(client: ErasedUiClient) -> Self {
+        let (reader, writer) = client.into_parts();
+        let config = load_config();
+        let heartbeat = config.daemon.heartbeat_interval_secs().map(|interval| {
+            Heartbeat::new(
+                Duration::from_secs(interval),
+                Duration::from_secs(config.daemon.heartbeat_timeout_secs()),
+                Instant::now(),
+            )
+        });
+        Self {
+            reader,
+            writer,
+            heartbeat,
+        }
+    }
+
+    /// Read the next streamed message. Returns `Ok(None)` on EOF.
+    ///
+    /// With heartbeats on, an unanswered ping fails with
+    /// `IpcError::ConnectionFailed` so the caller can reattach.
+    pub async fn read_next(&mut self) -> Result<Option<DaemonMessage>, IpcError> {
+        match &mut self.heartbeat {
+            Some(heartbeat) => {
+                kild_protocol::heartbeat::read_message_with_heartbeat(
+                    &mut self.reader,
+                    &mut self.writer,
+                    heartbeat,
+                    |delay| async move {
+                        smol::Timer::after(delay).await;
+                    },
+                )
+                .await
+            }
+            None => kild_protocol::async_client::read_message(&mut self.reader).await,
+        }
+    }
+}
+
 /// Two-connection handle for attached daemon session.
 ///
-/// - `reader`: receives streaming PtyOutput messages after Attach
+/// - `stream`: receives streaming PtyOutput messages after Attach
 /// - `writer`: sends WriteStdin, ResizePty, Detach commands
 ///
 /// Fields are private to enforce invariants established during construction
-/// (reader is attached, session_id matches the attached session).
+/// (stream is attached, session_id matches the attached session).
 pub struct DaemonConnection {
-    reader: DynReader,
+    stream: AttachStream,
     writer: DynWriter,
     session_id: String,
 }
@@ -417,8 +521,8 @@ impl DaemonConnection {
     }
 
     /// Consume the connection, returning its parts for use in reader/writer tasks.
-    pub fn into_parts(self) -> (DynReader, DynWriter, String) {
-        (self.reader, self.writer, self.session_id)
+    pub fn into_parts(self) -> (AttachStream, DynWriter, String) {
+        (self.stream, self.writer, self.session_id)
     }
 }
 
@@ -443,6 +547,7 @@ pub async fn connect_for_attach(
         session_id: SessionId::from(session_id),
         rows,
         cols,
+        read_only: false,
     };
     let ack = read_client.send(&attach_request).await?;
     match ack {
@@ -457,11 +562,12 @@ pub async fn connect_for_attach(
         }
     }
 
-    // Extract the reader half; discard the unused writer half of the read connection.
-    let (reader, _) = read_client.into_parts();
+    // Its writer half only carries heartbeat pings.
+    let stream = AttachStream::new(read_client);
 
     // Connection 2: writer — held open for WriteStdin/ResizePty/Detach.
-    // No protocol handshake is sent on this connection: the daemon dispatches
+    // No version handshake is sent on this connection (only the auth Hello
+    // when a remote daemon requires a token): the daemon dispatches
     // WriteStdin/ResizePty/Detach by session_id from each message's payload,
     // not by connection-level attachment state.
     let write_client = connect_for_config().await?;
@@ -473,12 +579,93 @@ pub async fn connect_for_attach(
     );
 
     Ok(DaemonConnection {
-        reader,
+        stream,
         writer,
         session_id: session_id.to_string(),
     })
 }
 
+/// Backoff for reconnecting dropped streams: 6 attempts from 250ms, doubling
+/// up to 5s — roughly 10s, enough to ride out a stale-binary daemon restart.
+fn reconnect_policy() -> RetryPolicy {
+    RetryPolicy::new(6, Duration::from_millis(250))
+}
+
+/// Reconnect transport: config-routed connections with smol timers.
+struct UiTransport;
+
+impl Transport for UiTransport {
+    type Reader = DynReader;
+    type Writer = DynWriter;
+
+    async fn connect(&mut self) -> Result<ErasedUiClient, IpcError> {
+        connect_for_config().await.map_err(|e| match e {
+            DaemonClientError::Connect(io) => IpcError::ConnectionFailed(io),
+            DaemonClientError::Io(io) => IpcError::Io(io),
+            DaemonClientError::DaemonError { code, message } => {
+                IpcError::DaemonError { code, message }
+            }
+            other => IpcError::ProtocolError {
+                message: other.to_string(),
+            },
+        })
+    }
+
+    async fn sleep(&mut self, delay: Duration) {
+        smol::Timer::after(delay).await;
+    }
+}
+
+/// Reopen the streaming side of an attach after its connection dropped.
+///
+/// Reconnects with backoff and resubscribes to `session_id`'s output.
+/// `on_state` reports each retry so the terminal can show "reconnecting".
+pub async fn reattach_reader(
+    session_id: &str,
+    rows: u16,
+    cols: u16,
+    on_state: impl FnMut(RetryState),
+) -> Result<AttachStream, DaemonClientError> {
+    info!(
+        event = "ui.daemon.reattach_started",
+        session_id = session_id
+    );
+    let attach = ClientMessage::Attach {
+        id: next_request_id(),
+        session_id: SessionId::from(session_id),
+        rows,
+        cols,
+        read_only: false,
+    };
+    let client = kild_protocol::async_client::reattach_with_retry(
+        &mut UiTransport,
+        &reconnect_policy(),
+        &attach,
+        on_state,
+    )
+    .await?;
+    info!(
+        event = "ui.daemon.reattach_completed",
+        session_id = session_id
+    );
+    Ok(AttachStream::new(client))
+}
+
+/// Reopen the write connection after it dropped, with backoff.
+pub async fn reconnect_writer(
+    on_state: impl FnMut(RetryState),
+) -> Result<DynWriter, DaemonClientError> {
+    let client = kild_protocol::async_client::connect_with_retry(
+        &mut UiTransport,
+        &reconnect_policy(),
+        on_state,
+    )
+    .await?;
+    info!(event = "ui.daemon.writer_reconnected");
+    let (_, writer) = client.into_parts();
+    Ok(writer)
+}
+
 /// Send WriteStdin IPC message (base64-encoded data).
 pub async fn send_write_stdin(
     writer: &mut DynWriter,
@@ -491,7 +678,7 @@ pub async fn send_write_stdin(
         session_id: SessionId::from(session_id),
         data: encoded,
     };
-    kild_protocol::async_client::write_jsonl(writer, &msg)
+    kild_protocol::async_client::write_message(writer, &msg)
         .await
         .map_err(Into::into)
 }
@@ -509,7 +696,7 @@ pub async fn send_resize(
         rows,
         cols,
     };
-    kild_protocol::async_client::write_jsonl(writer, &msg)
+    kild_protocol::async_client::write_message(writer, &msg)
         .await
         .map_err(Into::into)
 }
@@ -526,7 +713,7 @@ pub async fn send_detach(
         id: next_request_id(),
         session_id: SessionId::from(session_id),
     };
-    kild_protocol::async_client::write_jsonl_flush(writer, &msg)
+    kild_protocol::async_client::write_message_flush(writer, &msg)
         .await
         .map_err(Into::into)
 }
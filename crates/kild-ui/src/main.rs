@@ -32,8 +32,19 @@ fn main() {
         // Initialize gpui-component (must be first)
         gpui_component::init(cx);
 
-        // Apply Tallinn Night theme
-        theme_bridge::apply_tallinn_night_theme(cx);
+        // Apply the configured theme (`[ui] theme`, Tallinn Night by default)
+        let theme_name = match kild_config::KildConfig::load_hierarchy() {
+            Ok(config) => theme::ThemeName::from_config(config.ui.theme.as_deref()),
+            Err(e) => {
+                tracing::warn!(
+                    event = "ui.theme.config_load_failed",
+                    error = %e,
+                    "Using default theme"
+                );
+                theme::ThemeName::default()
+            }
+        };
+        theme_bridge::apply_theme(theme_name, cx);
 
         let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
         cx.open_window(
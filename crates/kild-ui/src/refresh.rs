@@ -2,9 +2,10 @@
 //!
 //! Provides auto-refresh functionality with a hybrid approach:
 //! - File watcher (notify) for instant updates when CLI modifies session files
-//! - Slow poll fallback for edge cases like direct process termination
+//! - Periodic reload (`[ui] refresh_interval_secs`) for edge cases like direct
+//!   process termination, paused while a dialog is open
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Fallback poll interval - file watcher handles most updates.
 /// This catches process crashes, external changes, missed events.
@@ -14,6 +15,250 @@ pub const POLL_INTERVAL: Duration = Duration::from_secs(60);
 /// when multiple files change at once (e.g., bulk operations).
 pub const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(100);
 
+/// Longest a steady stream of file events can hold off a refresh.
+///
+/// Without a cap, an agent rewriting its status file faster than
+/// `DEBOUNCE_INTERVAL` would keep the list from ever updating.
+pub const DEBOUNCE_MAX_WAIT: Duration = Duration::from_secs(1);
+
 /// Fast poll interval used when file watching is unavailable.
 /// Falls back to previous behavior if watcher fails to initialize.
 pub const FAST_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often the refresh task wakes to check whether a reload is due.
+///
+/// Kept short so a reload deferred by an open dialog runs soon after it closes.
+pub const SCHEDULE_TICK: Duration = Duration::from_secs(1);
+
+/// Resolve the periodic reload interval from `[ui] refresh_interval_secs`.
+///
+/// Unset falls back to `POLL_INTERVAL` with a watcher and `FAST_POLL_INTERVAL`
+/// without one. `Some(0)` disables the timer and returns `None`.
+pub fn refresh_interval(configured_secs: Option<u64>, has_watcher: bool) -> Option<Duration> {
+    match configured_secs {
+        Some(0) => None,
+        Some(secs) => Some(Duration::from_secs(secs)),
+        None if has_watcher => Some(POLL_INTERVAL),
+        None => Some(FAST_POLL_INTERVAL),
+    }
+}
+
+/// Tracks when the next periodic reload is due.
+///
+/// Time is passed in rather than read so the scheduling rules are testable.
+#[derive(Debug, Clone)]
+pub struct RefreshSchedule {
+    interval: Option<Duration>,
+    last_refresh: Instant,
+}
+
+impl RefreshSchedule {
+    pub fn new(interval: Option<Duration>, now: Instant) -> Self {
+        Self {
+            interval,
+            last_refresh: now,
+        }
+    }
+
+    /// Returns true when the interval has elapsed and no dialog is open.
+    ///
+    /// A reload that comes due while a dialog is open stays due, so it runs on
+    /// the first tick after the dialog closes.
+    pub fn is_due(&self, now: Instant, dialog_open: bool) -> bool {
+        let Some(interval) = self.interval else {
+            return false;
+        };
+        !dialog_open && now.saturating_duration_since(self.last_refresh) >= interval
+    }
+
+    /// Record that a reload happened, restarting the interval.
+    pub fn mark_refreshed(&mut self, now: Instant) {
+        self.last_refresh = now;
+    }
+}
+
+/// Coalesces bursts of file watcher events into a single refresh.
+///
+/// Fires once events have been quiet for the debounce interval, or once the
+/// oldest pending event is `max_wait` old, whichever comes first.
+#[derive(Debug, Clone)]
+pub struct EventDebounce {
+    quiet: Duration,
+    max_wait: Duration,
+    first_event: Option<Instant>,
+    last_event: Option<Instant>,
+}
+
+impl EventDebounce {
+    pub fn new(quiet: Duration, max_wait: Duration) -> Self {
+        Self {
+            quiet,
+            max_wait,
+            first_event: None,
+            last_event: None,
+        }
+    }
+
+    /// Record a relevant file event.
+    pub fn record(&mut self, now: Instant) {
+        self.first_event.get_or_insert(now);
+        self.last_event = Some(now);
+    }
+
+    /// Returns true if events are waiting to be applied.
+    pub fn is_pending(&self) -> bool {
+        self.last_event.is_some()
+    }
+
+    /// Returns true (and clears the pending state) when a refresh should run.
+    pub fn take_ready(&mut self, now: Instant) -> bool {
+        let (Some(first), Some(last)) = (self.first_event, self.last_event) else {
+            return false;
+        };
+        let quiet = now.saturating_duration_since(last) >= self.quiet;
+        let overdue = now.saturating_duration_since(first) >= self.max_wait;
+        if quiet || overdue {
+            self.first_event = None;
+            self.last_event = None;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for EventDebounce {
+    fn default() -> Self {
+        Self::new(DEBOUNCE_INTERVAL, DEBOUNCE_MAX_WAIT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refresh_interval_defaults_follow_watcher() {
+        assert_eq!(refresh_interval(None, true), Some(POLL_INTERVAL));
+        assert_eq!(refresh_interval(None, false), Some(FAST_POLL_INTERVAL));
+    }
+
+    #[test]
+    fn test_refresh_interval_configured_overrides_default() {
+        assert_eq!(
+            refresh_interval(Some(10), true),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            refresh_interval(Some(10), false),
+            Some(Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn test_refresh_interval_zero_disables() {
+        assert_eq!(refresh_interval(Some(0), true), None);
+        assert_eq!(refresh_interval(Some(0), false), None);
+    }
+
+    #[test]
+    fn test_schedule_due_after_interval() {
+        let start = Instant::now();
+        let schedule = RefreshSchedule::new(Some(Duration::from_secs(10)), start);
+
+        assert!(!schedule.is_due(start, false));
+        assert!(!schedule.is_due(start + Duration::from_secs(9), false));
+        assert!(schedule.is_due(start + Duration::from_secs(10), false));
+    }
+
+    #[test]
+    fn test_schedule_mark_refreshed_restarts_interval() {
+        let start = Instant::now();
+        let mut schedule = RefreshSchedule::new(Some(Duration::from_secs(10)), start);
+
+        let refreshed_at = start + Duration::from_secs(12);
+        schedule.mark_refreshed(refreshed_at);
+
+        assert!(!schedule.is_due(refreshed_at + Duration::from_secs(5), false));
+        assert!(schedule.is_due(refreshed_at + Duration::from_secs(10), false));
+    }
+
+    #[test]
+    fn test_schedule_suppressed_while_dialog_open() {
+        let start = Instant::now();
+        let schedule = RefreshSchedule::new(Some(Duration::from_secs(10)), start);
+        let later = start + Duration::from_secs(30);
+
+        assert!(!schedule.is_due(later, true));
+        // Still due once the dialog closes
+        assert!(schedule.is_due(later, false));
+    }
+
+    #[test]
+    fn test_schedule_disabled_never_due() {
+        let start = Instant::now();
+        let schedule = RefreshSchedule::new(None, start);
+
+        assert!(!schedule.is_due(start + Duration::from_secs(3600), false));
+    }
+
+    #[test]
+    fn test_debounce_idle_never_ready() {
+        let mut debounce = EventDebounce::default();
+        let now = Instant::now();
+
+        assert!(!debounce.is_pending());
+        assert!(!debounce.take_ready(now + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_debounce_coalesces_rapid_events() {
+        let quiet = Duration::from_millis(100);
+        let mut debounce = EventDebounce::new(quiet, Duration::from_secs(1));
+        let start = Instant::now();
+
+        // Five events 20ms apart: still inside the quiet window after each one
+        for i in 0..5 {
+            let at = start + Duration::from_millis(20 * i);
+            debounce.record(at);
+            assert!(!debounce.take_ready(at + Duration::from_millis(10)));
+        }
+
+        let last = start + Duration::from_millis(80);
+        assert!(debounce.take_ready(last + quiet));
+        // One refresh for the whole burst
+        assert!(!debounce.is_pending());
+        assert!(!debounce.take_ready(last + quiet * 2));
+    }
+
+    #[test]
+    fn test_debounce_max_wait_caps_continuous_events() {
+        let quiet = Duration::from_millis(100);
+        let max_wait = Duration::from_millis(500);
+        let mut debounce = EventDebounce::new(quiet, max_wait);
+        let start = Instant::now();
+
+        let mut fired_at = None;
+        for i in 0..20 {
+            let at = start + Duration::from_millis(50 * i);
+            debounce.record(at);
+            if debounce.take_ready(at) {
+                fired_at = Some(at);
+                break;
+            }
+        }
+
+        assert_eq!(fired_at, Some(start + max_wait));
+    }
+
+    #[test]
+    fn test_debounce_stays_pending_until_taken() {
+        let mut debounce = EventDebounce::default();
+        let start = Instant::now();
+        debounce.record(start);
+
+        // Caller skipped polling (e.g. dialog open); the event is not lost
+        assert!(debounce.is_pending());
+        assert!(debounce.take_ready(start + Duration::from_secs(30)));
+    }
+}
@@ -4,8 +4,10 @@ use kild_core::projects::{Project, ProjectManager};
 use crate::state::dialog::DialogState;
 use crate::state::errors::{OperationError, OperationErrors};
 use crate::state::loading::LoadingState;
+use crate::state::notice::StatusNotice;
+use crate::state::search::SidebarSearch;
 use crate::state::selection::SelectionState;
-use crate::state::sessions::SessionStore;
+use crate::state::sessions::{SessionLoad, SessionLoadError, SessionStore};
 
 /// Main application state.
 ///
@@ -33,6 +35,12 @@ pub struct AppState {
 
     /// In-progress operation tracking (prevents double-dispatch).
     pub(super) loading: LoadingState,
+
+    /// Sidebar search query (narrows the visible list, not the selection).
+    pub(super) search: SidebarSearch,
+
+    /// Transient status bar confirmation, if one was shown recently.
+    pub(super) notice: Option<StatusNotice>,
 }
 
 impl AppState {
@@ -65,6 +73,8 @@ impl AppState {
             projects,
             startup_errors,
             loading: LoadingState::new(),
+            search: SidebarSearch::default(),
+            notice: None,
         }
     }
 
@@ -73,20 +83,31 @@ impl AppState {
         self.sessions.refresh();
     }
 
-    /// Update only the process status of existing kilds without reloading from disk.
+    /// Apply a session load produced off the main thread.
     ///
-    /// This is faster than refresh_sessions() for status polling because it:
-    /// - Doesn't reload session files from disk (unless count mismatch detected)
-    /// - Only checks if tracked processes are still running
-    /// - Preserves the existing kild list structure
-    ///
-    /// If the session count on disk differs from the in-memory count (indicating
-    /// external create/destroy operations), triggers a full refresh instead.
+    /// Used by the periodic and watcher refreshes, which run
+    /// `actions::refresh_sessions()` on the background executor. Returns true
+    /// if the kild list changed; unchanged lists are left in place.
+    pub fn apply_session_load(&mut self, load: SessionLoad) -> bool {
+        let (displays, load_error) = load;
+        self.sessions.apply_refresh(displays, load_error)
+    }
+
+    /// Retry loading sessions after a load error.
     ///
-    /// Note: This does NOT update git status or diff stats. Use `refresh_sessions()`
-    /// for a full refresh that includes git information.
-    pub fn update_statuses_only(&mut self) {
-        self.sessions.update_statuses_only();
+    /// Always hits the loader, so the retry button keeps working after
+    /// repeated failures. Returns true if the list or error changed.
+    pub fn retry_load_sessions(&mut self) -> bool {
+        self.retry_load_sessions_with(crate::actions::refresh_sessions)
+    }
+
+    /// Retry loading sessions with the given loader.
+    pub fn retry_load_sessions_with(&mut self, load: impl FnOnce() -> SessionLoad) -> bool {
+        tracing::info!(
+            event = "ui.sessions.retry_started",
+            previous_error = self.sessions.load_error().map(|e| e.code)
+        );
+        self.sessions.reload_with(load)
     }
 
     /// Apply core events to update application state.
@@ -243,6 +264,32 @@ impl AppState {
             .filtered_by_project(self.active_project_id().as_deref())
     }
 
+    /// Get displays shown in the sidebar: the active project's kilds, narrowed
+    /// by the search query while search mode is open.
+    pub fn sidebar_displays(&self) -> Vec<&SessionSnapshot> {
+        self.search.apply(self.filtered_displays())
+    }
+
+    /// Enter sidebar search mode.
+    pub fn open_search(&mut self) {
+        self.search.open();
+    }
+
+    /// Update the sidebar search query.
+    pub fn set_search_query(&mut self, query: &str) {
+        self.search.set_query(query);
+    }
+
+    /// Leave sidebar search mode, restoring the full list.
+    pub fn clear_search(&mut self) {
+        self.search.clear();
+    }
+
+    /// Check if sidebar search mode is open.
+    pub fn is_searching(&self) -> bool {
+        self.search.is_active()
+    }
+
     /// Count kilds for a specific project (by project path).
     pub fn kild_count_for_project(&self, project_path: &std::path::Path) -> usize {
         let project_id = kild_core::projects::generate_project_id(project_path);
@@ -279,11 +326,38 @@ impl AppState {
         }
     }
 
+    /// Branch name to copy for the copy-branch shortcut.
+    ///
+    /// Returns `None` (and shows nothing) when no kild is selected. Otherwise
+    /// shows a status bar notice confirming the copy.
+    pub fn copy_selected_branch(&mut self) -> Option<String> {
+        let branch = self.selected_kild()?.session.branch.to_string();
+        self.show_notice(format!("Copied {}", branch));
+        Some(branch)
+    }
+
     /// Clear selection (e.g., when kild is destroyed).
     pub fn clear_selection(&mut self) {
         self.selection.clear();
     }
 
+    // =========================================================================
+    // Status notice facade methods
+    // =========================================================================
+
+    /// Show a transient confirmation in the status bar.
+    pub fn show_notice(&mut self, message: String) {
+        self.notice = Some(StatusNotice::new(message, std::time::Instant::now()));
+    }
+
+    /// Get the status bar notice, if it hasn't expired yet.
+    pub fn active_notice(&self) -> Option<&str> {
+        self.notice
+            .as_ref()
+            .filter(|n| n.is_visible(std::time::Instant::now()))
+            .map(StatusNotice::message)
+    }
+
     // =========================================================================
     // Dialog facade methods
     // =========================================================================
@@ -441,8 +515,7 @@ impl AppState {
     }
 
     /// Get the load error from the last refresh attempt, if any.
-    #[allow(dead_code)]
-    pub fn load_error(&self) -> Option<&str> {
+    pub fn load_error(&self) -> Option<&SessionLoadError> {
         self.sessions.load_error()
     }
 
@@ -467,6 +540,8 @@ impl AppState {
             projects: ProjectManager::new(),
             startup_errors: Vec::new(),
             loading: LoadingState::new(),
+            search: SidebarSearch::default(),
+            notice: None,
         }
     }
 
@@ -1,4 +1,5 @@
 use super::*;
+use kild_core::sessions::info::test_helpers::make_test_snapshot;
 use kild_core::sessions::types::SessionStatus;
 use kild_core::{BranchName, Event, GitStatus, ProcessStatus, Session, SessionSnapshot};
 use std::path::PathBuf;
@@ -123,18 +124,8 @@ fn test_filtered_displays_no_active_project() {
 
     let mut state = AppState::test_new();
     state.sessions.set_displays(vec![
-        SessionSnapshot {
-            session: make_session("1", "project-a"),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
-        SessionSnapshot {
-            session: make_session("2", "project-b"),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
+        make_test_snapshot(make_session("1", "project-a")),
+        make_test_snapshot(make_session("2", "project-b")),
     ]);
 
     // No active project - should return all
@@ -173,23 +164,11 @@ fn test_filtered_displays_with_active_project() {
 
     let mut state = AppState::test_new();
     state.sessions.set_displays(vec![
+        make_test_snapshot(make_session("1", &project_id_a)),
+        make_test_snapshot(make_session("2", &project_id_b)),
         SessionSnapshot {
-            session: make_session("1", &project_id_a),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
-        SessionSnapshot {
-            session: make_session("2", &project_id_b),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
-        SessionSnapshot {
-            session: make_session("3", &project_id_a),
             process_status: ProcessStatus::Running,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
+            ..make_test_snapshot(make_session("3", &project_id_a))
         },
     ]);
 
@@ -235,12 +214,12 @@ fn test_filtered_displays_returns_empty_when_no_matching_project() {
     };
 
     let mut state = AppState::test_new();
-    state.sessions.set_displays(vec![SessionSnapshot {
-        session: make_session("1", "other-project-hash"),
-        process_status: ProcessStatus::Stopped,
-        git_status: GitStatus::Unknown,
-        uncommitted_diff: None,
-    }]);
+    state
+        .sessions
+        .set_displays(vec![make_test_snapshot(make_session(
+            "1",
+            "other-project-hash",
+        ))]);
 
     // Active project set to a different path - should return empty
     let project = kild_core::projects::types::test_helpers::make_test_project(
@@ -280,12 +259,9 @@ fn test_selected_kild_returns_none_when_kild_removed_after_refresh() {
     };
 
     let mut state = AppState::test_new();
-    state.sessions.set_displays(vec![SessionSnapshot {
-        session: make_session("test-id"),
-        process_status: ProcessStatus::Stopped,
-        git_status: GitStatus::Unknown,
-        uncommitted_diff: None,
-    }]);
+    state
+        .sessions
+        .set_displays(vec![make_test_snapshot(make_session("test-id"))]);
     state.selection.select("test-id".to_string());
 
     // Verify selection works initially
@@ -327,12 +303,9 @@ fn test_selected_kild_persists_after_refresh_when_kild_still_exists() {
     };
 
     let mut state = AppState::test_new();
-    state.sessions.set_displays(vec![SessionSnapshot {
-        session: make_session("test-id"),
-        process_status: ProcessStatus::Stopped,
-        git_status: GitStatus::Unknown,
-        uncommitted_diff: None,
-    }]);
+    state
+        .sessions
+        .set_displays(vec![make_test_snapshot(make_session("test-id"))]);
     state.selection.select("test-id".to_string());
 
     // Verify initial selection
@@ -340,10 +313,9 @@ fn test_selected_kild_persists_after_refresh_when_kild_still_exists() {
 
     // Simulate refresh that keeps the same kild (new display list with same ID)
     state.sessions.set_displays(vec![SessionSnapshot {
-        session: make_session("test-id"),
         process_status: ProcessStatus::Running, // Status may change
         git_status: GitStatus::Dirty,           // Git status may change
-        uncommitted_diff: None,
+        ..make_test_snapshot(make_session("test-id"))
     }]);
 
     // Selection should persist
@@ -393,18 +365,8 @@ fn test_destroy_should_clear_selection_when_selected_kild_destroyed() {
 
     let mut state = AppState::test_new();
     state.sessions.set_displays(vec![
-        SessionSnapshot {
-            session: make_session("id-1", "branch-1"),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
-        SessionSnapshot {
-            session: make_session("id-2", "branch-2"),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
+        make_test_snapshot(make_session("id-1", "branch-1")),
+        make_test_snapshot(make_session("id-2", "branch-2")),
     ]);
     state.selection.select("id-1".to_string());
 
@@ -455,18 +417,8 @@ fn test_destroy_preserves_selection_when_different_kild_destroyed() {
 
     let mut state = AppState::test_new();
     state.sessions.set_displays(vec![
-        SessionSnapshot {
-            session: make_session("id-1", "branch-1"),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
-        SessionSnapshot {
-            session: make_session("id-2", "branch-2"),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
+        make_test_snapshot(make_session("id-1", "branch-1")),
+        make_test_snapshot(make_session("id-2", "branch-2")),
     ]);
     state.selection.select("id-1".to_string());
 
@@ -543,12 +495,11 @@ fn test_apply_kild_created_closes_dialog_and_refreshes() {
 #[test]
 fn test_apply_kild_destroyed_clears_selection_when_selected() {
     let mut state = AppState::test_new();
-    state.sessions.set_displays(vec![SessionSnapshot {
-        session: make_session_for_event_test("id-1", "branch-1"),
-        process_status: ProcessStatus::Stopped,
-        git_status: GitStatus::Unknown,
-        uncommitted_diff: None,
-    }]);
+    state
+        .sessions
+        .set_displays(vec![make_test_snapshot(make_session_for_event_test(
+            "id-1", "branch-1",
+        ))]);
     state.selection.select("id-1".to_string());
     state.set_dialog(DialogState::open_confirm("branch-1".to_string(), None));
 
@@ -564,18 +515,8 @@ fn test_apply_kild_destroyed_clears_selection_when_selected() {
 fn test_apply_kild_destroyed_preserves_selection_when_other() {
     let mut state = AppState::test_new();
     state.sessions.set_displays(vec![
-        SessionSnapshot {
-            session: make_session_for_event_test("id-1", "branch-1"),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
-        SessionSnapshot {
-            session: make_session_for_event_test("id-2", "branch-2"),
-            process_status: ProcessStatus::Stopped,
-            git_status: GitStatus::Unknown,
-            uncommitted_diff: None,
-        },
+        make_test_snapshot(make_session_for_event_test("id-1", "branch-1")),
+        make_test_snapshot(make_session_for_event_test("id-2", "branch-2")),
     ]);
     state.selection.select("id-1".to_string());
 
@@ -591,12 +532,11 @@ fn test_apply_kild_destroyed_preserves_selection_when_other() {
 #[test]
 fn test_apply_kild_opened_preserves_selection_and_dialog() {
     let mut state = AppState::test_new();
-    state.sessions.set_displays(vec![SessionSnapshot {
-        session: make_session_for_event_test("id-1", "branch-1"),
-        process_status: ProcessStatus::Stopped,
-        git_status: GitStatus::Unknown,
-        uncommitted_diff: None,
-    }]);
+    state
+        .sessions
+        .set_displays(vec![make_test_snapshot(make_session_for_event_test(
+            "id-1", "branch-1",
+        ))]);
     state.selection.select("id-1".to_string());
     state.set_dialog(DialogState::open_create());
 
@@ -614,10 +554,8 @@ fn test_apply_kild_opened_preserves_selection_and_dialog() {
 fn test_apply_kild_stopped_preserves_selection_and_dialog() {
     let mut state = AppState::test_new();
     state.sessions.set_displays(vec![SessionSnapshot {
-        session: make_session_for_event_test("id-1", "branch-1"),
         process_status: ProcessStatus::Running,
-        git_status: GitStatus::Unknown,
-        uncommitted_diff: None,
+        ..make_test_snapshot(make_session_for_event_test("id-1", "branch-1"))
     }]);
     state.selection.select("id-1".to_string());
     state.set_dialog(DialogState::open_create());
@@ -634,12 +572,11 @@ fn test_apply_kild_stopped_preserves_selection_and_dialog() {
 #[test]
 fn test_apply_kild_completed_clears_selection_when_selected() {
     let mut state = AppState::test_new();
-    state.sessions.set_displays(vec![SessionSnapshot {
-        session: make_session_for_event_test("id-1", "branch-1"),
-        process_status: ProcessStatus::Stopped,
-        git_status: GitStatus::Unknown,
-        uncommitted_diff: None,
-    }]);
+    state
+        .sessions
+        .set_displays(vec![make_test_snapshot(make_session_for_event_test(
+            "id-1", "branch-1",
+        ))]);
     state.selection.select("id-1".to_string());
 
     state.apply_events(&[Event::KildCompleted {
@@ -864,3 +801,190 @@ fn test_error_persists_through_loading_lifecycle() {
     state.clear_loading("branch-1");
     assert!(state.get_error("branch-1").is_some());
 }
+
+#[test]
+fn test_open_dialog_suppresses_scheduled_refresh() {
+    use crate::refresh::RefreshSchedule;
+    use std::time::{Duration, Instant};
+
+    let start = Instant::now();
+    let schedule = RefreshSchedule::new(Some(Duration::from_secs(5)), start);
+    let due = start + Duration::from_secs(5);
+
+    let mut state = AppState::test_new();
+    assert!(schedule.is_due(due, state.dialog().is_open()));
+
+    state.set_dialog(DialogState::open_create());
+    assert!(!schedule.is_due(due, state.dialog().is_open()));
+
+    state.close_dialog();
+    assert!(schedule.is_due(due, state.dialog().is_open()));
+}
+
+#[test]
+fn test_sidebar_search_filters_and_escape_restores_full_list() {
+    let make_display = |branch: &str, agent: &str| {
+        make_test_snapshot(Session::new(
+            format!("id-{}", branch).into(),
+            "project".into(),
+            BranchName::new(branch.to_string()),
+            PathBuf::from("/tmp/test"),
+            agent.to_string(),
+            SessionStatus::Active,
+            "2024-01-01T00:00:00Z".to_string(),
+            0,
+            0,
+            0,
+            None,
+            None,
+            None,
+            vec![],
+            None,
+            None,
+            None,
+        ))
+    };
+
+    let mut state = AppState::test_new();
+    state.sessions.set_displays(vec![
+        make_display("feature-auth", "claude"),
+        make_display("fix-billing", "codex"),
+    ]);
+    state.select_kild("id-fix-billing".to_string());
+
+    state.open_search();
+    state.set_search_query("auth");
+    let visible: Vec<_> = state
+        .sidebar_displays()
+        .iter()
+        .map(|d| d.session.branch.to_string())
+        .collect();
+    assert_eq!(visible, vec!["feature-auth"]);
+    // Filtering hides the selected kild but doesn't deselect it
+    assert_eq!(state.selected_id(), Some("id-fix-billing"));
+
+    state.clear_search();
+
+    assert!(!state.is_searching());
+    assert_eq!(state.sidebar_displays().len(), 2);
+    assert_eq!(state.selected_id(), Some("id-fix-billing"));
+}
+
+#[test]
+fn test_copy_selected_branch_returns_branch_and_shows_notice() {
+    let mut state = AppState::test_new();
+    state
+        .sessions
+        .set_displays(vec![make_test_snapshot(Session::new(
+            "proj/feature-auth".into(),
+            "proj".into(),
+            BranchName::new("feature-auth".to_string()),
+            PathBuf::from("/tmp/test"),
+            "claude".to_string(),
+            SessionStatus::Active,
+            "2024-01-01T00:00:00Z".to_string(),
+            0,
+            0,
+            0,
+            None,
+            None,
+            None,
+            vec![],
+            None,
+            None,
+            None,
+        ))]);
+    state.select_kild("proj/feature-auth".to_string());
+
+    assert_eq!(
+        state.copy_selected_branch().as_deref(),
+        Some("feature-auth")
+    );
+    assert_eq!(state.active_notice(), Some("Copied feature-auth"));
+}
+
+#[test]
+fn test_copy_selected_branch_without_selection_is_noop() {
+    let mut state = AppState::test_new();
+
+    assert!(state.copy_selected_branch().is_none());
+    assert!(state.active_notice().is_none());
+
+    // Stale selection (kild no longer listed) behaves the same
+    state.select_kild("gone".to_string());
+    assert!(state.copy_selected_branch().is_none());
+    assert!(state.active_notice().is_none());
+}
+
+// --- retry_load_sessions tests ---
+
+fn load_failure() -> crate::state::sessions::SessionLoad {
+    (
+        Vec::new(),
+        Some(crate::state::sessions::SessionLoadError {
+            code: "IO_ERROR",
+            message: "IO operation failed: permission denied".to_string(),
+        }),
+    )
+}
+
+#[test]
+fn test_retry_load_sessions_invokes_loader_on_every_failure() {
+    let mut state = AppState::test_new();
+    let mut calls = 0;
+
+    assert!(state.retry_load_sessions_with(|| {
+        calls += 1;
+        load_failure()
+    }));
+    assert_eq!(state.load_error().map(|e| e.code), Some("IO_ERROR"));
+
+    // Repeated failures leave state unchanged but still hit the loader
+    for _ in 0..2 {
+        assert!(!state.retry_load_sessions_with(|| {
+            calls += 1;
+            load_failure()
+        }));
+    }
+    assert_eq!(calls, 3);
+    assert!(state.load_error().is_some());
+}
+
+#[test]
+fn test_retry_load_sessions_success_clears_error() {
+    let mut state = AppState::test_new();
+    state.retry_load_sessions_with(load_failure);
+
+    let changed = state.retry_load_sessions_with(|| {
+        let snapshot = make_test_snapshot(make_session_for_event_test(
+            "proj/feature-auth",
+            "feature-auth",
+        ));
+        (vec![snapshot], None)
+    });
+
+    assert!(changed);
+    assert!(state.load_error().is_none());
+    assert_eq!(state.displays().len(), 1);
+}
+
+// --- apply_session_load tests ---
+
+#[test]
+fn test_apply_session_load_reports_change_only_once() {
+    let mut state = AppState::test_new();
+    let load = || {
+        let snapshot = make_test_snapshot(make_session_for_event_test(
+            "proj/feature-auth",
+            "feature-auth",
+        ));
+        (vec![snapshot], None)
+    };
+
+    assert!(state.apply_session_load(load()));
+    assert_eq!(state.displays().len(), 1);
+
+    // Same load again (e.g. next background tick) leaves the list in place
+    assert!(!state.apply_session_load(load()));
+    assert_eq!(state.displays().len(), 1);
+}
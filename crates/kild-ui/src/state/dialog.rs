@@ -29,6 +29,11 @@ pub enum DialogState {
 }
 
 impl DialogState {
+    /// Returns true if any dialog is open.
+    pub fn is_open(&self) -> bool {
+        !matches!(self, DialogState::None)
+    }
+
     /// Returns true if the create dialog is open.
     pub fn is_create(&self) -> bool {
         matches!(self, DialogState::Create { .. })
@@ -172,6 +177,11 @@ mod tests {
         assert!(!none.is_create());
         assert!(!none.is_confirm());
         assert!(!none.is_add_project());
+
+        assert!(create.is_open());
+        assert!(confirm.is_open());
+        assert!(add_project.is_open());
+        assert!(!none.is_open());
     }
 
     #[test]
@@ -8,6 +8,8 @@ pub mod app_state;
 pub mod dialog;
 pub mod errors;
 pub mod loading;
+pub mod notice;
+pub mod search;
 pub mod selection;
 pub mod sessions;
 
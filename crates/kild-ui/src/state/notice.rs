@@ -0,0 +1,42 @@
+use std::time::{Duration, Instant};
+
+/// How long a status bar notice stays visible.
+pub const NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+/// A short-lived confirmation shown in the status bar (e.g. "Copied ...").
+#[derive(Clone, Debug)]
+pub struct StatusNotice {
+    message: String,
+    shown_at: Instant,
+}
+
+impl StatusNotice {
+    pub fn new(message: String, shown_at: Instant) -> Self {
+        Self { message, shown_at }
+    }
+
+    /// The notice text.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns true until `NOTICE_DURATION` has passed since it was shown.
+    pub fn is_visible(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.shown_at) < NOTICE_DURATION
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notice_expires_after_duration() {
+        let shown_at = Instant::now();
+        let notice = StatusNotice::new("Copied".to_string(), shown_at);
+
+        assert!(notice.is_visible(shown_at));
+        assert!(notice.is_visible(shown_at + NOTICE_DURATION - Duration::from_millis(1)));
+        assert!(!notice.is_visible(shown_at + NOTICE_DURATION));
+    }
+}
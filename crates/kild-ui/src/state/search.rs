@@ -0,0 +1,154 @@
+use kild_core::SessionSnapshot;
+
+/// Sidebar search state.
+///
+/// `None` means search mode is closed and the full list is shown. An open
+/// search with an empty query also shows everything, so the list only narrows
+/// once the user starts typing.
+#[derive(Clone, Debug, Default)]
+pub struct SidebarSearch {
+    query: Option<String>,
+}
+
+impl SidebarSearch {
+    /// Enter search mode with an empty query.
+    pub fn open(&mut self) {
+        self.query.get_or_insert_with(String::new);
+    }
+
+    /// Leave search mode and drop the query.
+    pub fn clear(&mut self) {
+        self.query = None;
+    }
+
+    /// Returns true while search mode is open.
+    pub fn is_active(&self) -> bool {
+        self.query.is_some()
+    }
+
+    /// Replace the query. Ignored when search mode is closed.
+    pub fn set_query(&mut self, query: &str) {
+        if let Some(current) = &mut self.query {
+            current.clear();
+            current.push_str(query);
+        }
+    }
+
+    /// Keep only displays that match the current query.
+    pub fn apply<'a>(&self, displays: Vec<&'a SessionSnapshot>) -> Vec<&'a SessionSnapshot> {
+        match self.query.as_deref().map(str::trim) {
+            Some(query) if !query.is_empty() => displays
+                .into_iter()
+                .filter(|d| matches_query(d, query))
+                .collect(),
+            _ => displays,
+        }
+    }
+}
+
+/// Case-insensitive substring match on branch name or agent.
+pub fn matches_query(display: &SessionSnapshot, query: &str) -> bool {
+    let query = query.to_lowercase();
+    display.session.branch.to_lowercase().contains(&query)
+        || display.session.agent.to_lowercase().contains(&query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kild_core::Session;
+    use kild_core::sessions::info::test_helpers::make_test_snapshot;
+    use kild_core::sessions::types::SessionStatus;
+    use std::path::PathBuf;
+
+    fn snapshot(branch: &str, agent: &str) -> SessionSnapshot {
+        make_test_snapshot(Session::new(
+            format!("project/{}", branch).into(),
+            "project".into(),
+            branch.into(),
+            PathBuf::from("/tmp/test"),
+            agent.to_string(),
+            SessionStatus::Active,
+            "2024-01-01T00:00:00Z".to_string(),
+            0,
+            0,
+            0,
+            None,
+            None,
+            None,
+            vec![],
+            None,
+            None,
+            None,
+        ))
+    }
+
+    #[test]
+    fn test_matches_query_branch_substring() {
+        let display = snapshot("feature-auth", "claude");
+        assert!(matches_query(&display, "auth"));
+        assert!(matches_query(&display, "feature-auth"));
+        assert!(!matches_query(&display, "billing"));
+    }
+
+    #[test]
+    fn test_matches_query_agent() {
+        let display = snapshot("feature-auth", "codex");
+        assert!(matches_query(&display, "codex"));
+        assert!(matches_query(&display, "dex"));
+    }
+
+    #[test]
+    fn test_matches_query_case_insensitive() {
+        let display = snapshot("Feature-Auth", "Claude");
+        assert!(matches_query(&display, "AUTH"));
+        assert!(matches_query(&display, "claude"));
+    }
+
+    #[test]
+    fn test_apply_filters_only_when_query_non_empty() {
+        let a = snapshot("feature-auth", "claude");
+        let b = snapshot("fix-billing", "codex");
+        let mut search = SidebarSearch::default();
+
+        assert_eq!(search.apply(vec![&a, &b]).len(), 2);
+
+        search.open();
+        assert_eq!(search.apply(vec![&a, &b]).len(), 2, "empty query shows all");
+
+        search.set_query("bill");
+        let filtered = search.apply(vec![&a, &b]);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(&*filtered[0].session.branch, "fix-billing");
+
+        search.set_query("   ");
+        assert_eq!(search.apply(vec![&a, &b]).len(), 2, "blank query shows all");
+    }
+
+    #[test]
+    fn test_set_query_ignored_when_closed() {
+        let a = snapshot("feature-auth", "claude");
+        let b = snapshot("fix-billing", "codex");
+        let mut search = SidebarSearch::default();
+        search.set_query("auth");
+        assert!(!search.is_active());
+
+        search.open();
+        assert_eq!(search.apply(vec![&a, &b]).len(), 2, "query was not kept");
+    }
+
+    #[test]
+    fn test_clear_restores_full_list() {
+        let a = snapshot("feature-auth", "claude");
+        let b = snapshot("fix-billing", "codex");
+        let mut search = SidebarSearch::default();
+        search.open();
+        search.set_query("auth");
+        assert_eq!(search.apply(vec![&a, &b]).len(), 1);
+
+        search.clear();
+
+        assert!(!search.is_active());
+        assert_eq!(search.apply(vec![&a, &b]).len(), 2);
+    }
+}
@@ -1,5 +1,24 @@
 use kild_core::SessionSnapshot;
 
+/// Error from the last attempt to load sessions from disk.
+///
+/// Keeps the `SessionError` code alongside the message so the UI can show
+/// which failure occurred, not just its description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionLoadError {
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl std::fmt::Display for SessionLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
+}
+
+/// Result of one session load: the displays plus any load error.
+pub type SessionLoad = (Vec<SessionSnapshot>, Option<SessionLoadError>);
+
 /// Encapsulates session display data with refresh tracking.
 ///
 /// Provides a clean API for managing kild displays, filtering by project,
@@ -11,7 +30,7 @@ pub struct SessionStore {
     /// List of kild displays (private to enforce invariants).
     displays: Vec<SessionSnapshot>,
     /// Error from last refresh attempt, if any.
-    load_error: Option<String>,
+    load_error: Option<SessionLoadError>,
     /// Timestamp of last successful status refresh.
     last_refresh: std::time::Instant,
 }
@@ -29,7 +48,7 @@ impl SessionStore {
 
     /// Create a session store with provided data (for testing).
     #[cfg(test)]
-    pub fn from_data(displays: Vec<SessionSnapshot>, load_error: Option<String>) -> Self {
+    pub fn from_data(displays: Vec<SessionSnapshot>, load_error: Option<SessionLoadError>) -> Self {
         Self {
             displays,
             load_error,
@@ -57,46 +76,47 @@ impl SessionStore {
         self.last_refresh = std::time::Instant::now();
     }
 
-    /// Update only the process status of existing kilds without reloading from disk.
-    ///
-    /// This is faster than `refresh()` for status polling because it:
-    /// - Doesn't reload session files from disk (unless count mismatch detected)
-    /// - Only checks if tracked processes are still running
-    /// - Preserves the existing kild list structure
+    /// Reload sessions using the given loader, keeping the current list if
+    /// nothing changed.
     ///
-    /// If the session count on disk differs from the in-memory count (indicating
-    /// external create/destroy operations), triggers a full refresh instead.
+    /// The loader is invoked on every call. Returns true when the kild list or
+    /// load error differs from before, so callers can skip re-rendering
+    /// unchanged rows.
+    pub fn reload_with(&mut self, load: impl FnOnce() -> SessionLoad) -> bool {
+        let (displays, load_error) = load();
+        self.apply_refresh(displays, load_error)
+    }
+
+    /// Replace displays only when they differ from the current list.
     ///
-    /// Note: This does NOT update git status or diff stats. Use `refresh()`
-    /// for a full refresh that includes git information.
-    pub fn update_statuses_only(&mut self) {
-        // Check if session count changed (external create/destroy).
-        let disk_count = kild_core::sessions::store::count_session_files();
-
-        if let Some(count) = disk_count {
-            if count != self.displays.len() {
-                tracing::info!(
-                    event = "ui.auto_refresh.session_count_mismatch",
-                    disk_count = count,
-                    memory_count = self.displays.len(),
-                    action = "triggering full refresh"
-                );
-                self.refresh();
-                return;
-            }
-        } else {
-            tracing::debug!(
-                event = "ui.auto_refresh.count_check_skipped",
-                reason = "cannot read sessions directory"
-            );
-        }
+    /// Always updates `last_refresh`. Returns true if anything changed.
+    pub fn apply_refresh(
+        &mut self,
+        displays: Vec<SessionSnapshot>,
+        load_error: Option<SessionLoadError>,
+    ) -> bool {
+        self.last_refresh = std::time::Instant::now();
 
-        // No count change (or count unavailable) - just update process statuses
-        for kild_display in &mut self.displays {
-            kild_display.process_status =
-                kild_core::sessions::info::determine_process_status(&kild_display.session);
+        let reordered = displays.len() == self.displays.len()
+            && displays
+                .iter()
+                .zip(&self.displays)
+                .any(|(new, old)| new.session.id != old.session.id);
+        let changed = reordered
+            || load_error != self.load_error
+            || !kild_core::sessions::diff_snapshots(&self.displays, &displays).is_empty();
+
+        if changed {
+            self.displays = displays;
+            self.load_error = load_error;
+        } else {
+            // Usage samples aren't part of the change hash; keep them current
+            // without forcing a re-render
+            for (old, new) in self.displays.iter_mut().zip(displays) {
+                old.resource_usage = new.resource_usage;
+            }
         }
-        self.last_refresh = std::time::Instant::now();
+        changed
     }
 
     /// Get all displays.
@@ -120,9 +140,8 @@ impl SessionStore {
     }
 
     /// Get the load error from the last refresh attempt, if any.
-    #[allow(dead_code)]
-    pub fn load_error(&self) -> Option<&str> {
-        self.load_error.as_deref()
+    pub fn load_error(&self) -> Option<&SessionLoadError> {
+        self.load_error.as_ref()
     }
 
     /// Get the timestamp of the last successful refresh.
@@ -160,8 +179,9 @@ impl Default for SessionStore {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use kild_core::sessions::info::test_helpers::make_test_snapshot;
     use kild_core::sessions::types::SessionStatus;
-    use kild_core::{GitStatus, ProcessStatus, Session};
+    use kild_core::{GitStatus, KildConfig, ProcessStatus, Session};
     use std::path::PathBuf;
 
     #[test]
@@ -186,7 +206,7 @@ mod tests {
             None,
         );
 
-        let display = SessionSnapshot::from_session(session);
+        let display = SessionSnapshot::from_session(session, &KildConfig::default());
         assert_eq!(display.process_status, ProcessStatus::Stopped);
         // Non-existent path should result in Unknown git status
         assert_eq!(display.git_status, GitStatus::Unknown);
@@ -231,7 +251,7 @@ mod tests {
             None,
         );
 
-        let display = SessionSnapshot::from_session(session);
+        let display = SessionSnapshot::from_session(session, &KildConfig::default());
         // With window detection fallback, should attempt to check window
         // In test environment without Ghostty running, will fall back to Stopped
         assert!(
@@ -300,7 +320,7 @@ mod tests {
             None,
         );
 
-        let display = SessionSnapshot::from_session(session);
+        let display = SessionSnapshot::from_session(session, &KildConfig::default());
 
         assert_eq!(display.git_status, GitStatus::Dirty);
         assert!(
@@ -313,174 +333,111 @@ mod tests {
         assert!(stats.has_changes());
     }
 
-    #[test]
-    fn test_update_statuses_only_updates_last_refresh() {
-        let initial_time = std::time::Instant::now();
-        let mut store = SessionStore::from_data(Vec::new(), None);
+    fn snapshot(id: &str, process_status: ProcessStatus) -> SessionSnapshot {
+        SessionSnapshot {
+            process_status,
+            ..make_test_snapshot(Session::new(
+                id.into(),
+                "test-project".into(),
+                format!("branch-{}", id).into(),
+                PathBuf::from("/tmp/test"),
+                "claude".to_string(),
+                SessionStatus::Active,
+                "2024-01-01T00:00:00Z".to_string(),
+                0,
+                0,
+                0,
+                None,
+                None,
+                None,
+                vec![],
+                None,
+                None,
+                None,
+            ))
+        }
+    }
 
-        // Small delay to ensure time difference
+    #[test]
+    fn test_apply_refresh_unchanged_reports_no_change() {
+        let mut store = SessionStore::from_data(
+            vec![
+                snapshot("a", ProcessStatus::Stopped),
+                snapshot("b", ProcessStatus::Stopped),
+            ],
+            None,
+        );
+        let before = store.last_refresh();
         std::thread::sleep(std::time::Duration::from_millis(10));
 
-        store.update_statuses_only();
+        let changed = store.apply_refresh(
+            vec![
+                snapshot("a", ProcessStatus::Stopped),
+                snapshot("b", ProcessStatus::Stopped),
+            ],
+            None,
+        );
 
-        // last_refresh should be updated to a later time
-        assert!(store.last_refresh() > initial_time);
+        assert!(!changed);
+        assert!(store.last_refresh() > before);
     }
 
     #[test]
-    fn test_update_statuses_only_updates_process_status() {
-        use kild_core::sessions::types::AgentProcess;
+    fn test_apply_refresh_detects_status_change() {
+        let mut store = SessionStore::from_data(vec![snapshot("a", ProcessStatus::Stopped)], None);
 
-        let make_agent_with_pid = |pid: Option<u32>| -> Vec<AgentProcess> {
-            match pid {
-                Some(p) => vec![
-                    AgentProcess::new(
-                        "claude".to_string(),
-                        String::new(),
-                        Some(p),
-                        Some("test-process".to_string()),
-                        Some(1234567890),
-                        None,
-                        None,
-                        String::new(),
-                        "2024-01-01T00:00:00Z".to_string(),
-                        None,
-                    )
-                    .unwrap(),
-                ],
-                None => vec![],
-            }
-        };
+        let changed = store.apply_refresh(vec![snapshot("a", ProcessStatus::Running)], None);
 
-        // Create a session with a PID that doesn't exist (should become Stopped)
-        let session_with_dead_pid = Session::new(
-            "test-dead".into(),
-            "test-project".into(),
-            "dead-branch".into(),
-            PathBuf::from("/tmp/test"),
-            "claude".to_string(),
-            SessionStatus::Active,
-            "2024-01-01T00:00:00Z".to_string(),
-            0,
-            0,
-            0,
-            None,
-            None,
-            None,
-            make_agent_with_pid(Some(999999)), // Non-existent PID
-            None,
-            None,
-            None,
-        );
+        assert!(changed);
+        assert_eq!(store.displays()[0].process_status, ProcessStatus::Running);
+    }
 
-        // Create a session with our own PID (should be Running)
-        let session_with_live_pid = Session::new(
-            "test-live".into(),
-            "test-project".into(),
-            "live-branch".into(),
-            PathBuf::from("/tmp/test"),
-            "claude".to_string(),
-            SessionStatus::Active,
-            "2024-01-01T00:00:00Z".to_string(),
-            0,
-            0,
-            0,
-            None,
-            None,
-            None,
-            make_agent_with_pid(Some(std::process::id())), // Current process PID
-            None,
-            None,
-            None,
-        );
+    #[test]
+    fn test_apply_refresh_detects_added_removed_and_reordered() {
+        let mut store = SessionStore::from_data(vec![snapshot("a", ProcessStatus::Stopped)], None);
 
-        // Create a session with no PID (should remain Stopped)
-        let session_no_pid = Session::new(
-            "test-no-pid".into(),
-            "test-project".into(),
-            "no-pid-branch".into(),
-            PathBuf::from("/tmp/test"),
-            "claude".to_string(),
-            SessionStatus::Active,
-            "2024-01-01T00:00:00Z".to_string(),
-            0,
-            0,
-            0,
-            None,
-            None,
-            None,
-            make_agent_with_pid(None),
-            None,
+        assert!(store.apply_refresh(
+            vec![
+                snapshot("a", ProcessStatus::Stopped),
+                snapshot("b", ProcessStatus::Stopped),
+            ],
             None,
+        ));
+        assert_eq!(store.total_count(), 2);
+
+        assert!(store.apply_refresh(
+            vec![
+                snapshot("b", ProcessStatus::Stopped),
+                snapshot("a", ProcessStatus::Stopped),
+            ],
             None,
-        );
+        ));
+        assert_eq!(&*store.displays()[0].session.id, "b");
 
-        let mut store = SessionStore::from_data(Vec::new(), None);
-        store.set_displays(vec![
-            SessionSnapshot {
-                session: session_with_dead_pid,
-                process_status: ProcessStatus::Running, // Start as Running (incorrect)
-                git_status: GitStatus::Unknown,
-                uncommitted_diff: None,
-            },
-            SessionSnapshot {
-                session: session_with_live_pid,
-                process_status: ProcessStatus::Stopped, // Start as Stopped (incorrect)
-                git_status: GitStatus::Unknown,
-                uncommitted_diff: None,
-            },
-            SessionSnapshot {
-                session: session_no_pid,
-                process_status: ProcessStatus::Stopped, // Start as Stopped (correct)
-                git_status: GitStatus::Unknown,
-                uncommitted_diff: None,
-            },
-        ]);
-
-        let original_ids: Vec<_> = store
-            .displays()
-            .iter()
-            .map(|d| d.session.id.clone())
-            .collect();
-        store.update_statuses_only();
-
-        // Note: update_statuses_only() may trigger a full refresh if the session count
-        // on disk differs from the in-memory count (see issue #103 fix). In that case,
-        // the displays will be replaced with whatever is on disk.
-        //
-        // Check session IDs (not just count) to detect a refresh: the count can stay
-        // the same when disk sessions happen to equal the in-memory count, but the IDs
-        // will differ because our synthetic test IDs won't match real session files.
-        let new_ids: Vec<_> = store
-            .displays()
-            .iter()
-            .map(|d| d.session.id.clone())
-            .collect();
-        if original_ids != new_ids {
-            // Refresh was triggered - this is expected behavior when running tests
-            // in an environment with actual session files.
-            return;
+        assert!(store.apply_refresh(vec![snapshot("b", ProcessStatus::Stopped)], None));
+        assert_eq!(store.total_count(), 1);
+    }
+
+    fn disk_error() -> SessionLoadError {
+        SessionLoadError {
+            code: "IO_ERROR",
+            message: "disk error".to_string(),
         }
+    }
 
-        // Non-existent PID should be marked Stopped
-        assert_eq!(
-            store.displays()[0].process_status,
-            ProcessStatus::Stopped,
-            "Non-existent PID should be marked Stopped"
-        );
+    #[test]
+    fn test_apply_refresh_detects_load_error_change() {
+        let mut store = SessionStore::from_data(Vec::new(), None);
 
-        // Current process PID should be marked Running
-        assert_eq!(
-            store.displays()[1].process_status,
-            ProcessStatus::Running,
-            "Current process PID should be marked Running"
-        );
+        assert!(store.apply_refresh(Vec::new(), Some(disk_error())));
+        assert_eq!(store.load_error(), Some(&disk_error()));
+        assert!(!store.apply_refresh(Vec::new(), Some(disk_error())));
+        assert!(store.apply_refresh(Vec::new(), None));
+        assert!(store.load_error().is_none());
+    }
 
-        // No PID should remain Stopped (not checked, so unchanged)
-        assert_eq!(
-            store.displays()[2].process_status,
-            ProcessStatus::Stopped,
-            "Session with no PID should remain Stopped"
-        );
+    #[test]
+    fn test_load_error_display_includes_code() {
+        assert_eq!(disk_error().to_string(), "disk error (IO_ERROR)");
     }
 }
@@ -12,17 +12,71 @@ use crate::theme;
 use gpui::Rgba;
 use kild_teams::TeamColor;
 
-/// Map Claude Code team colors to the Tallinn Night palette.
+/// Map Claude Code team colors to the active theme's palette.
+///
+/// Every color keeps at least 3:1 contrast against the sidebar background in
+/// both Tallinn Night and Tallinn Day.
 pub fn team_color_to_rgba(color: &TeamColor) -> Rgba {
     match color {
         TeamColor::Red => theme::ember(),
         TeamColor::Blue => theme::ice(),
         TeamColor::Green => theme::aurora(),
-        TeamColor::Yellow => gpui::rgb(0xE5C07B),
-        TeamColor::Purple => gpui::rgb(0xC678DD),
+        TeamColor::Yellow => theme::team_yellow(),
+        TeamColor::Purple => theme::team_purple(),
         TeamColor::Orange => theme::copper(),
-        TeamColor::Pink => gpui::rgb(0xFF7EB6),
-        TeamColor::Cyan => gpui::rgb(0x56B6C2),
+        TeamColor::Pink => theme::team_pink(),
+        TeamColor::Cyan => theme::team_cyan(),
         TeamColor::Unknown => theme::text_muted(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// WCAG relative luminance of an sRGB color.
+    fn luminance(color: Rgba) -> f32 {
+        let channel = |c: f32| {
+            if c <= 0.039_28 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b)
+    }
+
+    fn contrast(a: Rgba, b: Rgba) -> f32 {
+        let (la, lb) = (luminance(a), luminance(b));
+        (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+    }
+
+    #[test]
+    fn test_team_colors_legible_on_every_theme() {
+        let colors = [
+            TeamColor::Red,
+            TeamColor::Blue,
+            TeamColor::Green,
+            TeamColor::Yellow,
+            TeamColor::Purple,
+            TeamColor::Orange,
+            TeamColor::Pink,
+            TeamColor::Cyan,
+            TeamColor::Unknown,
+        ];
+        for name in [theme::ThemeName::TallinnNight, theme::ThemeName::TallinnDay] {
+            theme::set_active_theme(name);
+            for color in &colors {
+                let ratio = contrast(team_color_to_rgba(color), theme::obsidian());
+                assert!(
+                    ratio >= 3.0,
+                    "{:?} on {} has contrast {:.2}",
+                    color,
+                    name.display_name(),
+                    ratio
+                );
+            }
+        }
+        theme::set_active_theme(theme::ThemeName::default());
+    }
+}
@@ -4,8 +4,9 @@
 //! for the sidebar and main view to discover teammates.
 
 use std::collections::HashMap;
+use std::sync::mpsc::Receiver;
 
-use kild_teams::{TeamMember, TeamState, TeamWatcher};
+use kild_teams::{TeamEvent, TeamMember, TeamState, TeamWatcher};
 
 /// Manages team state for the UI, providing cached team data
 /// and file-watching for live updates.
@@ -14,13 +15,16 @@ pub struct TeamManager {
     team_states: HashMap<String, TeamState>,
     /// File watcher for team config and shim registry changes.
     watcher: Option<TeamWatcher>,
+    /// Team events diffed by the watcher.
+    events: Option<Receiver<TeamEvent>>,
     /// Mapping from team_name → kild session_id (for cross-referencing).
     team_to_session: HashMap<String, String>,
 }
 
 impl TeamManager {
     pub fn new() -> Self {
-        let watcher = TeamWatcher::new_default();
+        let mut watcher = TeamWatcher::new_default();
+        let events = watcher.as_mut().map(TeamWatcher::subscribe);
         if watcher.is_some() {
             tracing::info!(event = "ui.teams.watcher_created");
         } else {
@@ -30,6 +34,7 @@ impl TeamManager {
         Self {
             team_states: HashMap::new(),
             watcher,
+            events,
             team_to_session: HashMap::new(),
         }
     }
@@ -140,10 +145,17 @@ impl TeamManager {
             .unwrap_or_default()
     }
 
-    /// Check if the watcher has pending events.
-    pub fn has_pending_events(&self) -> bool {
-        self.watcher
-            .as_ref()
-            .is_some_and(|w| w.has_pending_events())
+    /// Check if the watcher saw a change that needs a refresh.
+    ///
+    /// Config rewrites that leave team state unchanged produce no events
+    /// and don't trigger a refresh.
+    pub fn has_pending_events(&mut self) -> bool {
+        let changed = self.watcher.as_mut().is_some_and(|w| w.poll_events());
+        if let Some(events) = &self.events {
+            for event in events.try_iter() {
+                tracing::info!(event = "ui.teams.team_event", team_event = ?event);
+            }
+        }
+        changed
     }
 }
@@ -12,7 +12,7 @@ use alacritty_terminal::vte::ansi::Processor;
 use base64::Engine;
 use futures::channel::mpsc::UnboundedReceiver;
 use gpui::Task;
-use kild_protocol::DaemonMessage;
+use kild_protocol::{DaemonMessage, RetryState};
 use portable_pty::{Child, CommandBuilder, MasterPty, PtySize, native_pty_system};
 
 use super::errors::TerminalError;
@@ -190,6 +190,58 @@ fn set_error_state_if_none(error_state: &Arc<Mutex<Option<String>>>, msg: String
     }
 }
 
+/// Show daemon reconnect progress in `error_state`, clearing it on success.
+fn report_retry_state(error_state: &Arc<Mutex<Option<String>>>, state: RetryState) {
+    match state {
+        RetryState::Reconnecting {
+            attempt,
+            max_attempts,
+            ..
+        } => set_error_state(
+            error_state,
+            format!("Reconnecting to daemon (attempt {attempt}/{max_attempts})..."),
+        ),
+        RetryState::Connected { attempt } if attempt > 1 => match error_state.lock() {
+            Ok(mut err) => *err = None,
+            Err(e) => {
+                tracing::error!(event = "ui.terminal.error_state_lock_poisoned", error = %e);
+            }
+        },
+        RetryState::Connected { .. } | RetryState::Failed { .. } => {}
+    }
+}
+
+/// Reattach a dropped daemon stream at the terminal's current size.
+///
+/// Returns `None` once retries are exhausted, with the failure recorded in
+/// `error_state`.
+async fn reattach_daemon_reader(
+    session_id: &str,
+    size: &Arc<Mutex<(u16, u16)>>,
+    error_state: &Arc<Mutex<Option<String>>>,
+) -> Option<daemon_client::AttachStream> {
+    let (rows, cols) = size
+        .lock()
+        .map(|s| *s)
+        .unwrap_or((DEFAULT_ROWS, DEFAULT_COLS));
+    match daemon_client::reattach_reader(session_id, rows, cols, |state| {
+        report_retry_state(error_state, state)
+    })
+    .await
+    {
+        Ok(stream) => Some(stream),
+        Err(e) => {
+            tracing::error!(
+                event = "ui.terminal.daemon_reattach_failed",
+                session_id = session_id,
+                error = %e
+            );
+            set_error_state(error_state, format!("Lost connection to daemon: {e}"));
+            None
+        }
+    }
+}
+
 /// Core terminal state wrapping alacritty_terminal's Term with PTY lifecycle.
 ///
 /// Manages:
@@ -426,9 +478,10 @@ impl Terminal {
         // Destructure connection into reader/writer halves
         let (reader, writer, _conn_session_id) = conn.into_parts();
 
-        // Spawn IPC reader task: reads JSONL from daemon, base64 decodes, feeds byte channel
+        // Spawn IPC reader task: reads framed messages from daemon, base64 decodes, feeds byte channel
         let reader_exited = exited.clone();
         let reader_error = error_state.clone();
+        let reader_size = current_size.clone();
         let mut reader = reader;
         let reader_session_id = session_id.clone();
         let pty_reader_task = cx.background_executor().spawn(async move {
@@ -436,85 +489,108 @@ impl Terminal {
                 event = "ui.terminal.daemon_reader_started",
                 session_id = reader_session_id
             );
-            let mut line = String::new();
+            // A reattach replays scrollback the terminal already shows
+            let mut reattached = false;
             loop {
-                line.clear();
-                match smol::io::AsyncBufReadExt::read_line(&mut reader, &mut line).await {
-                    Ok(0) => {
+                match reader.read_next().await {
+                    Ok(None) => {
                         tracing::info!(event = "ui.terminal.daemon_reader_eof");
-                        break;
-                    }
-                    Ok(_) => {
-                        let trimmed = line.trim();
-                        if trimmed.is_empty() {
-                            continue;
-                        }
-                        match serde_json::from_str::<DaemonMessage>(trimmed) {
-                            Ok(DaemonMessage::PtyOutput { data, .. }) => {
-                                match base64::engine::general_purpose::STANDARD.decode(&data) {
-                                    Ok(decoded) => {
-                                        if byte_tx.unbounded_send(decoded).is_err() {
-                                            tracing::warn!(
-                                                event = "ui.terminal.daemon_byte_channel_closed",
-                                                session_id = reader_session_id,
-                                                "Batch loop likely exited — stopping daemon reader"
-                                            );
-                                            break;
-                                        }
-                                    }
-                                    Err(e) => {
-                                        tracing::error!(
-                                            event = "ui.terminal.daemon_base64_decode_failed",
-                                            error = %e,
-                                        );
-                                        set_error_state_if_none(
-                                            &reader_error,
-                                            format!("Terminal data corrupted (base64 decode): {e}"),
-                                        );
-                                        break;
-                                    }
-                                }
+                        match reattach_daemon_reader(
+                            &reader_session_id,
+                            &reader_size,
+                            &reader_error,
+                        )
+                        .await
+                        {
+                            Some(new_reader) => {
+                                reader = new_reader;
+                                reattached = true;
                             }
-                            Ok(DaemonMessage::PtyOutputDropped { bytes_dropped, .. }) => {
+                            None => break,
+                        }
+                    }
+                    Ok(Some(DaemonMessage::Scrollback { .. })) if reattached => {
+                        tracing::debug!(event = "ui.terminal.daemon_scrollback_skipped");
+                    }
+                    Ok(Some(
+                        DaemonMessage::PtyOutput { data, .. }
+                        | DaemonMessage::Scrollback { data, .. },
+                    )) => match base64::engine::general_purpose::STANDARD.decode(&data) {
+                        Ok(decoded) => {
+                            if byte_tx.unbounded_send(decoded).is_err() {
                                 tracing::warn!(
-                                    event = "ui.terminal.daemon_output_dropped",
-                                    bytes_dropped = bytes_dropped
-                                );
-                            }
-                            Ok(DaemonMessage::SessionEvent { event: ref ev, .. })
-                                if ev == "stopped" =>
-                            {
-                                tracing::info!(
-                                    event = "ui.terminal.daemon_session_stopped",
-                                    session_id = reader_session_id
-                                );
-                                break;
-                            }
-                            Ok(other) => {
-                                tracing::debug!(
-                                    event = "ui.terminal.daemon_message_ignored",
-                                    message = ?other
-                                );
-                            }
-                            Err(e) => {
-                                tracing::error!(
-                                    event = "ui.terminal.daemon_parse_failed",
-                                    error = %e,
-                                    line = trimmed
-                                );
-                                set_error_state_if_none(
-                                    &reader_error,
-                                    format!("Daemon protocol error: {e}"),
+                                    event = "ui.terminal.daemon_byte_channel_closed",
+                                    session_id = reader_session_id,
+                                    "Batch loop likely exited — stopping daemon reader"
                                 );
                                 break;
                             }
                         }
+                        Err(e) => {
+                            tracing::error!(
+                                event = "ui.terminal.daemon_base64_decode_failed",
+                                error = %e,
+                            );
+                            set_error_state_if_none(
+                                &reader_error,
+                                format!("Terminal data corrupted (base64 decode): {e}"),
+                            );
+                            break;
+                        }
+                    },
+                    Ok(Some(DaemonMessage::PtyOutputDropped { bytes_dropped, .. })) => {
+                        tracing::warn!(
+                            event = "ui.terminal.daemon_output_dropped",
+                            bytes_dropped = bytes_dropped
+                        );
                     }
-                    Err(e) => {
+                    Ok(Some(DaemonMessage::SessionEvent { event: ref ev, .. }))
+                        if ev == "stopped" =>
+                    {
+                        tracing::info!(
+                            event = "ui.terminal.daemon_session_stopped",
+                            session_id = reader_session_id
+                        );
+                        break;
+                    }
+                    Ok(Some(other)) => {
+                        tracing::debug!(
+                            event = "ui.terminal.daemon_message_ignored",
+                            message = ?other
+                        );
+                    }
+                    // Io: the connection broke; ConnectionFailed: a heartbeat went unanswered
+                    Err(
+                        e @ (kild_protocol::IpcError::Io(_)
+                        | kild_protocol::IpcError::ConnectionFailed(_)),
+                    ) => {
                         tracing::error!(
                             event = "ui.terminal.daemon_reader_failed",
                             error = %e
                         );
+                        match reattach_daemon_reader(
+                            &reader_session_id,
+                            &reader_size,
+                            &reader_error,
+                        )
+                        .await
+                        {
+                            Some(new_reader) => {
+                                reader = new_reader;
+                                reattached = true;
+                            }
+                            None => break,
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!(
+                            event = "ui.terminal.daemon_parse_failed",
+                            error = %e,
+                        );
+                        set_error_state_if_none(
+                            &reader_error,
+                            format!("Daemon protocol error: {e}"),
+                        );
                         break;
                     }
                 }
@@ -537,10 +613,33 @@ impl Terminal {
             while let Some(cmd) = cmd_rx.next().await {
                 match cmd {
                     DaemonWriteCommand::Stdin(data) => {
-                        if let Err(e) =
+                        let mut result =
                             daemon_client::send_write_stdin(&mut writer, &writer_session_id, &data)
-                                .await
-                        {
+                                .await;
+                        if let Err(e) = &result {
+                            // The connection dropped: reconnect and resend once
+                            tracing::warn!(
+                                event = "ui.terminal.daemon_write_retrying",
+                                error = %e,
+                            );
+                            result = match daemon_client::reconnect_writer(|state| {
+                                report_retry_state(&writer_error, state)
+                            })
+                            .await
+                            {
+                                Ok(new_writer) => {
+                                    writer = new_writer;
+                                    daemon_client::send_write_stdin(
+                                        &mut writer,
+                                        &writer_session_id,
+                                        &data,
+                                    )
+                                    .await
+                                }
+                                Err(e) => Err(e),
+                            };
+                        }
+                        if let Err(e) = result {
                             tracing::error!(
                                 event = "ui.terminal.daemon_write_failed",
                                 error = %e,
@@ -936,6 +1035,33 @@ impl Drop for Terminal {
 mod tests {
     use super::*;
 
+    #[test]
+    fn retry_state_shows_reconnecting_then_clears() {
+        let error_state = Arc::new(Mutex::new(None));
+        report_retry_state(
+            &error_state,
+            RetryState::Reconnecting {
+                attempt: 2,
+                max_attempts: 6,
+                delay: std::time::Duration::from_millis(250),
+            },
+        );
+        assert_eq!(
+            error_state.lock().unwrap().as_deref(),
+            Some("Reconnecting to daemon (attempt 2/6)...")
+        );
+
+        report_retry_state(&error_state, RetryState::Connected { attempt: 2 });
+        assert!(error_state.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn retry_state_first_attempt_keeps_existing_error() {
+        let error_state = Arc::new(Mutex::new(Some("Daemon write failed".to_string())));
+        report_retry_state(&error_state, RetryState::Connected { attempt: 1 });
+        assert!(error_state.lock().unwrap().is_some());
+    }
+
     #[test]
     fn resolve_cwd_none_returns_home() {
         let result = resolve_working_dir(None).unwrap();
@@ -1,7 +1,9 @@
 //! Theme constants for KILD UI.
 //!
-//! Color palette based on the "Tallinn Night" brand system.
-//! All colors are GPUI Rgba values for direct use in styling.
+//! Color palettes based on the "Tallinn Night" brand system, with a light
+//! "Tallinn Day" variant. Color functions read the active theme, so switching
+//! it at runtime re-colors the next render. All colors are GPUI Rgba values
+//! for direct use in styling.
 //!
 //! # Usage
 //!
@@ -19,107 +21,315 @@
 // defined than to remove and re-add later.
 #![allow(dead_code)]
 
+use std::cell::Cell;
+
 use gpui::Rgba;
 
 // =============================================================================
-// COLOR PALETTE - Tallinn Night (Dark Theme)
+// THEMES
+// =============================================================================
+
+/// A built-in UI theme, selected by `[ui] theme` in config.toml.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeName {
+    /// Dark theme (default).
+    #[default]
+    TallinnNight,
+    /// Light theme.
+    TallinnDay,
+}
+
+impl ThemeName {
+    /// Resolve the configured theme name. Unset or unknown names fall back
+    /// to Tallinn Night; unknown names also warn.
+    pub fn from_config(name: Option<&str>) -> Self {
+        match name.map(|n| n.trim().to_lowercase()).as_deref() {
+            None | Some("tallinn-night") | Some("dark") => Self::TallinnNight,
+            Some("tallinn-day") | Some("light") => Self::TallinnDay,
+            Some(other) => {
+                tracing::warn!(
+                    event = "ui.theme.unknown_name",
+                    value = other,
+                    valid_values = "tallinn-night, tallinn-day, dark, light",
+                    "Unknown theme, using 'tallinn-night'"
+                );
+                Self::TallinnNight
+            }
+        }
+    }
+
+    /// The other theme (dark ↔ light).
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::TallinnNight => Self::TallinnDay,
+            Self::TallinnDay => Self::TallinnNight,
+        }
+    }
+
+    /// Display name, e.g. `"Tallinn Night"`.
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::TallinnNight => "Tallinn Night",
+            Self::TallinnDay => "Tallinn Day",
+        }
+    }
+
+    pub fn is_dark(self) -> bool {
+        matches!(self, Self::TallinnNight)
+    }
+
+    /// The color palette for this theme.
+    pub fn palette(self) -> &'static Palette {
+        match self {
+            Self::TallinnNight => &TALLINN_NIGHT,
+            Self::TallinnDay => &TALLINN_DAY,
+        }
+    }
+}
+
+/// Brand colors for one theme, as `0xRRGGBB` (`overlay` is `0xRRGGBBAA`).
+///
+/// Field names match the accessor functions below. Terminal ANSI colors are
+/// not part of the palette: the embedded terminal keeps its dark scheme in
+/// every theme so program output stays readable.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Palette {
+    pub void: u32,
+    pub obsidian: u32,
+    pub surface: u32,
+    pub elevated: u32,
+    pub border_subtle: u32,
+    pub border: u32,
+    pub border_strong: u32,
+    pub text_muted: u32,
+    pub text_subtle: u32,
+    pub text: u32,
+    pub text_bright: u32,
+    pub text_white: u32,
+    pub ice: u32,
+    pub ice_dim: u32,
+    pub ice_bright: u32,
+    pub aurora: u32,
+    pub aurora_dim: u32,
+    pub copper: u32,
+    pub copper_dim: u32,
+    pub ember: u32,
+    pub ember_dim: u32,
+    pub kiri: u32,
+    pub kiri_dim: u32,
+    pub blade: u32,
+    pub blade_bright: u32,
+    pub overlay: u32,
+    /// Agent team colors without a brand equivalent (see `team_color_to_rgba`).
+    pub team_yellow: u32,
+    pub team_purple: u32,
+    pub team_pink: u32,
+    pub team_cyan: u32,
+}
+
+/// Tallinn Night — the dark brand palette.
+pub static TALLINN_NIGHT: Palette = Palette {
+    void: 0x08090A,
+    obsidian: 0x0E1012,
+    surface: 0x151719,
+    elevated: 0x1C1F22,
+    border_subtle: 0x1F2328,
+    border: 0x2D3139,
+    border_strong: 0x3D434D,
+    text_muted: 0x5C6370,
+    text_subtle: 0x848D9C,
+    text: 0xB8C0CC,
+    text_bright: 0xE8ECF0,
+    text_white: 0xF8FAFC,
+    ice: 0x7CB4C8,
+    ice_dim: 0x5E9AB2,
+    ice_bright: 0x98CCE0,
+    aurora: 0x6B8F5E,
+    aurora_dim: 0x556F4A,
+    copper: 0xC49A5C,
+    copper_dim: 0xA07D45,
+    ember: 0xB87060,
+    ember_dim: 0x9A584A,
+    kiri: 0xA088B0,
+    kiri_dim: 0x866B92,
+    blade: 0x64748B,
+    blade_bright: 0x94A3B8,
+    overlay: 0x08090ACC,
+    team_yellow: 0xE5C07B,
+    team_purple: 0xC678DD,
+    team_pink: 0xFF7EB6,
+    team_cyan: 0x56B6C2,
+};
+
+/// Tallinn Day — light counterpart. Accents are darkened so text and status
+/// dots keep at least 3:1 contrast against the light surfaces.
+pub static TALLINN_DAY: Palette = Palette {
+    void: 0xE6E9ED,
+    obsidian: 0xEEF0F3,
+    surface: 0xF7F8FA,
+    elevated: 0xFFFFFF,
+    border_subtle: 0xDDE1E6,
+    border: 0xC9CFD6,
+    border_strong: 0xAEB6C0,
+    text_muted: 0x6B7380,
+    text_subtle: 0x525A66,
+    text: 0x2B313A,
+    text_bright: 0x161A20,
+    text_white: 0x0B0E12,
+    ice: 0x2F7A96,
+    ice_dim: 0x25657D,
+    ice_bright: 0x3D8FAD,
+    aurora: 0x4A7340,
+    aurora_dim: 0x3A5C32,
+    copper: 0x9A6A22,
+    copper_dim: 0x7D5518,
+    ember: 0xA8503E,
+    ember_dim: 0x8A3F30,
+    kiri: 0x7A5C8E,
+    kiri_dim: 0x634A75,
+    blade: 0x64748B,
+    blade_bright: 0x475569,
+    overlay: 0x1B1F2466,
+    team_yellow: 0x8A6D0F,
+    team_purple: 0x8E44AD,
+    team_pink: 0xB83B76,
+    team_cyan: 0x1F7A85,
+};
+
+thread_local! {
+    // GPUI renders on the main thread only, so a thread-local is the whole
+    // app's active theme (and keeps tests isolated from each other).
+    static ACTIVE_THEME: Cell<ThemeName> = const { Cell::new(ThemeName::TallinnNight) };
+}
+
+/// The theme the accessor functions currently read from.
+pub fn active_theme() -> ThemeName {
+    ACTIVE_THEME.with(Cell::get)
+}
+
+/// Switch the active palette. Call `theme_bridge::apply_theme` instead from
+/// UI code so gpui-component widgets switch too.
+pub fn set_active_theme(theme: ThemeName) {
+    ACTIVE_THEME.with(|active| active.set(theme));
+}
+
+fn palette() -> &'static Palette {
+    active_theme().palette()
+}
+
+// =============================================================================
+// COLOR PALETTE - resolved against the active theme
 // =============================================================================
 
-// Base surfaces (darkest to lightest)
+// Base surfaces (darkest to lightest in Tallinn Night)
 // - void: deepest background, app edges, behind everything
 // - obsidian: sidebars, panels
 // - surface: cards, content areas
 // - elevated: modals, dropdowns, floating elements
 pub fn void() -> Rgba {
-    gpui::rgb(0x08090A)
+    gpui::rgb(palette().void)
 }
 pub fn obsidian() -> Rgba {
-    gpui::rgb(0x0E1012)
+    gpui::rgb(palette().obsidian)
 }
 pub fn surface() -> Rgba {
-    gpui::rgb(0x151719)
+    gpui::rgb(palette().surface)
 }
 pub fn elevated() -> Rgba {
-    gpui::rgb(0x1C1F22)
+    gpui::rgb(palette().elevated)
 }
 
 // Borders (subtle to strong)
 pub fn border_subtle() -> Rgba {
-    gpui::rgb(0x1F2328)
+    gpui::rgb(palette().border_subtle)
 }
 pub fn border() -> Rgba {
-    gpui::rgb(0x2D3139)
+    gpui::rgb(palette().border)
 }
 pub fn border_strong() -> Rgba {
-    gpui::rgb(0x3D434D)
+    gpui::rgb(palette().border_strong)
 }
 
-// Text (muted to brightest)
+// Text (muted to strongest)
 pub fn text_muted() -> Rgba {
-    gpui::rgb(0x5C6370)
+    gpui::rgb(palette().text_muted)
 }
 pub fn text_subtle() -> Rgba {
-    gpui::rgb(0x848D9C)
+    gpui::rgb(palette().text_subtle)
 }
 pub fn text() -> Rgba {
-    gpui::rgb(0xB8C0CC)
+    gpui::rgb(palette().text)
 }
 pub fn text_bright() -> Rgba {
-    gpui::rgb(0xE8ECF0)
+    gpui::rgb(palette().text_bright)
 }
 pub fn text_white() -> Rgba {
-    gpui::rgb(0xF8FAFC)
+    gpui::rgb(palette().text_white)
 }
 
 // Primary accent - Ice (frosted steel-blue, for primary actions, focus states)
 pub fn ice() -> Rgba {
-    gpui::rgb(0x7CB4C8)
+    gpui::rgb(palette().ice)
 }
 pub fn ice_dim() -> Rgba {
-    gpui::rgb(0x5E9AB2)
+    gpui::rgb(palette().ice_dim)
 }
 pub fn ice_bright() -> Rgba {
-    gpui::rgb(0x98CCE0)
+    gpui::rgb(palette().ice_bright)
 }
 
 // Status - Aurora (olive green, active/running/success)
 pub fn aurora() -> Rgba {
-    gpui::rgb(0x6B8F5E)
+    gpui::rgb(palette().aurora)
 }
 pub fn aurora_dim() -> Rgba {
-    gpui::rgb(0x556F4A)
+    gpui::rgb(palette().aurora_dim)
 }
 
 // Status - Copper (warm ochre, stopped/warning/idle)
 pub fn copper() -> Rgba {
-    gpui::rgb(0xC49A5C)
+    gpui::rgb(palette().copper)
 }
 pub fn copper_dim() -> Rgba {
-    gpui::rgb(0xA07D45)
+    gpui::rgb(palette().copper_dim)
 }
 
 // Status - Ember (terracotta, error/crashed/danger)
 pub fn ember() -> Rgba {
-    gpui::rgb(0xB87060)
+    gpui::rgb(palette().ember)
 }
 pub fn ember_dim() -> Rgba {
-    gpui::rgb(0x9A584A)
+    gpui::rgb(palette().ember_dim)
 }
 
 // Agent indicator - Kiri (twilight mauve, for AI activity)
 pub fn kiri() -> Rgba {
-    gpui::rgb(0xA088B0)
+    gpui::rgb(palette().kiri)
 }
 pub fn kiri_dim() -> Rgba {
-    gpui::rgb(0x866B92)
+    gpui::rgb(palette().kiri_dim)
 }
 
 // Secondary accent - Blade (for secondary actions)
 pub fn blade() -> Rgba {
-    gpui::rgb(0x64748B)
+    gpui::rgb(palette().blade)
 }
 pub fn blade_bright() -> Rgba {
-    gpui::rgb(0x94A3B8)
+    gpui::rgb(palette().blade_bright)
+}
+
+// Agent team colors without a brand equivalent
+pub fn team_yellow() -> Rgba {
+    gpui::rgb(palette().team_yellow)
+}
+pub fn team_purple() -> Rgba {
+    gpui::rgb(palette().team_purple)
+}
+pub fn team_pink() -> Rgba {
+    gpui::rgb(palette().team_pink)
+}
+pub fn team_cyan() -> Rgba {
+    gpui::rgb(palette().team_cyan)
 }
 
 // =============================================================================
@@ -222,13 +432,14 @@ pub const TEXT_BADGE: f32 = 9.0;
 // OVERLAY
 // =============================================================================
 
-/// Semi-transparent overlay for modals (Void at 80% opacity)
+/// Semi-transparent overlay for modals (Void at 80% opacity in Tallinn Night)
 pub fn overlay() -> Rgba {
-    gpui::rgba(0x08090ACC)
+    gpui::rgba(palette().overlay)
 }
 
 // =============================================================================
 // TERMINAL COLORS — ANSI 16 mapped to Tallinn Night (vivid for readability)
+// The terminal keeps this dark scheme regardless of the active theme.
 // =============================================================================
 
 // Standard ANSI colors (0-7)
@@ -283,16 +494,84 @@ pub fn ansi_bright_white() -> Rgba {
     gpui::rgb(0xF8FAFC)
 } // Text White
 
-// Terminal special colors
+// Terminal special colors (fixed to Tallinn Night in every theme)
 pub fn terminal_foreground() -> Rgba {
-    text()
+    gpui::rgb(TALLINN_NIGHT.text)
 } // #B8C0CC
 pub fn terminal_background() -> Rgba {
-    void()
+    gpui::rgb(TALLINN_NIGHT.void)
 } // #08090A
 pub fn terminal_cursor() -> Rgba {
     gpui::rgb(0x38BDF8)
 } // Ice (vivid)
 pub fn terminal_selection() -> Rgba {
-    with_alpha(ice(), 0.30)
+    with_alpha(gpui::rgb(TALLINN_NIGHT.ice), 0.30)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_from_config() {
+        assert_eq!(ThemeName::from_config(None), ThemeName::TallinnNight);
+        assert_eq!(
+            ThemeName::from_config(Some("tallinn-night")),
+            ThemeName::TallinnNight
+        );
+        assert_eq!(
+            ThemeName::from_config(Some("tallinn-day")),
+            ThemeName::TallinnDay
+        );
+        assert_eq!(ThemeName::from_config(Some("Light")), ThemeName::TallinnDay);
+        assert_eq!(
+            ThemeName::from_config(Some("dark")),
+            ThemeName::TallinnNight
+        );
+    }
+
+    #[test]
+    fn test_theme_from_config_unknown_falls_back_to_dark() {
+        assert_eq!(
+            ThemeName::from_config(Some("solarized")),
+            ThemeName::TallinnNight
+        );
+    }
+
+    #[test]
+    fn test_toggled_swaps_theme() {
+        assert_eq!(ThemeName::TallinnNight.toggled(), ThemeName::TallinnDay);
+        assert_eq!(ThemeName::TallinnDay.toggled(), ThemeName::TallinnNight);
+    }
+
+    #[test]
+    fn test_toggling_swaps_active_palette() {
+        set_active_theme(ThemeName::TallinnNight);
+        assert_eq!(palette(), &TALLINN_NIGHT);
+        assert_eq!(surface(), gpui::rgb(TALLINN_NIGHT.surface));
+
+        set_active_theme(active_theme().toggled());
+        assert_eq!(active_theme(), ThemeName::TallinnDay);
+        assert_eq!(palette(), &TALLINN_DAY);
+        assert_eq!(surface(), gpui::rgb(TALLINN_DAY.surface));
+
+        set_active_theme(active_theme().toggled());
+        assert_eq!(palette(), &TALLINN_NIGHT);
+    }
+
+    #[test]
+    fn test_light_theme_text_is_darker_than_surface() {
+        let day = ThemeName::TallinnDay.palette();
+        assert!(!ThemeName::TallinnDay.is_dark());
+        assert!(day.text < day.surface);
+        assert!(TALLINN_NIGHT.text > TALLINN_NIGHT.surface);
+    }
+
+    #[test]
+    fn test_terminal_colors_ignore_active_theme() {
+        set_active_theme(ThemeName::TallinnDay);
+        assert_eq!(terminal_background(), gpui::rgb(TALLINN_NIGHT.void));
+        assert_eq!(terminal_foreground(), gpui::rgb(TALLINN_NIGHT.text));
+        set_active_theme(ThemeName::TallinnNight);
+    }
 }
@@ -1,18 +1,31 @@
-//! Bridge between KILD's Tallinn Night theme and gpui-component's theme system.
+//! Bridge between KILD's themes and gpui-component's theme system.
 //!
 //! Maps our brand colors to gpui-component theme tokens so that
-//! library components (Button, Dialog, Input) render with Tallinn Night colors.
+//! library components (Button, Dialog, Input) render with the active
+//! KILD theme (Tallinn Night or Tallinn Day).
 
 use std::rc::Rc;
 
 use gpui::{App, SharedString};
 use gpui_component::theme::{Theme, ThemeConfig, ThemeConfigColors, ThemeMode};
 
-/// Apply the Tallinn Night dark theme to gpui-component's global theme.
-pub fn apply_tallinn_night_theme(cx: &mut App) {
+use crate::theme::{self, ThemeName};
+
+/// Make `name` the active theme for both KILD's palette and gpui-component.
+///
+/// Safe to call at runtime: open windows are refreshed so every view
+/// re-renders with the new colors.
+pub fn apply_theme(name: ThemeName, cx: &mut App) {
+    theme::set_active_theme(name);
+
+    let (mode, colors) = if name.is_dark() {
+        (ThemeMode::Dark, tallinn_night_colors())
+    } else {
+        (ThemeMode::Light, tallinn_day_colors())
+    };
     let config = Rc::new(ThemeConfig {
-        name: SharedString::from("Tallinn Night"),
-        mode: ThemeMode::Dark,
+        name: SharedString::from(name.display_name()),
+        mode,
         is_default: true,
         font_family: Some("Inter".into()),
         mono_font_family: Some("JetBrains Mono".into()),
@@ -21,11 +34,14 @@ pub fn apply_tallinn_night_theme(cx: &mut App) {
         radius: Some(6),
         radius_lg: Some(8),
         shadow: Some(true),
-        colors: tallinn_night_colors(),
+        colors,
         highlight: None,
     });
 
     Theme::global_mut(cx).apply_config(&config);
+    cx.refresh_windows();
+
+    tracing::info!(event = "ui.theme.applied", theme = name.display_name());
 }
 
 fn tallinn_night_colors() -> ThemeConfigColors {
@@ -115,13 +131,102 @@ fn tallinn_night_colors() -> ThemeConfigColors {
         "drag.border": "#7CB4C8",
         "drop_target.background": "#7CB4C822"
     }"##;
+    parse_colors(json, ThemeName::TallinnNight)
+}
+
+fn tallinn_day_colors() -> ThemeConfigColors {
+    // Same token set as Tallinn Night, using the Tallinn Day palette.
+    let json = r##"{
+        "background": "#EEF0F3",
+        "foreground": "#2B313A",
+        "border": "#C9CFD6",
+        "input.border": "#C9CFD6",
+        "accent.background": "#FFFFFF",
+        "accent.foreground": "#161A20",
+        "primary.background": "#2F7A96",
+        "primary.hover.background": "#3D8FAD",
+        "primary.active.background": "#25657D",
+        "primary.foreground": "#FFFFFF",
+        "secondary.background": "#FFFFFF",
+        "secondary.hover.background": "#DDE1E6",
+        "secondary.active.background": "#F7F8FA",
+        "secondary.foreground": "#2B313A",
+        "success.background": "#4A7340",
+        "success.hover.background": "#5A8650",
+        "success.active.background": "#3A5C32",
+        "success.foreground": "#FFFFFF",
+        "danger.background": "#A8503E",
+        "danger.hover.background": "#BC6452",
+        "danger.active.background": "#8A3F30",
+        "danger.foreground": "#FFFFFF",
+        "warning.background": "#9A6A22",
+        "warning.hover.background": "#AE7E34",
+        "warning.active.background": "#7D5518",
+        "warning.foreground": "#FFFFFF",
+        "info.background": "#25657D",
+        "info.hover.background": "#2F7A96",
+        "info.active.background": "#1D5266",
+        "info.foreground": "#FFFFFF",
+        "muted.background": "#DDE1E6",
+        "muted.foreground": "#6B7380",
+        "ring": "#2F7A96",
+        "overlay": "#1B1F2466",
+        "popover.background": "#FFFFFF",
+        "popover.foreground": "#2B313A",
+        "sidebar.background": "#EEF0F3",
+        "sidebar.foreground": "#2B313A",
+        "sidebar.accent.background": "#FFFFFF",
+        "sidebar.accent.foreground": "#161A20",
+        "sidebar.primary.background": "#2F7A96",
+        "sidebar.primary.foreground": "#FFFFFF",
+        "sidebar.border": "#DDE1E6",
+        "list.background": "#EEF0F3",
+        "list.hover.background": "#FFFFFF",
+        "list.active.background": "#2F7A9633",
+        "list.active.border": "#2F7A96",
+        "list.even.background": "#F7F8FA",
+        "list.head.background": "#EEF0F3",
+        "tab.background": "#EEF0F3",
+        "tab.active.background": "#F7F8FA",
+        "tab.active.foreground": "#161A20",
+        "tab.foreground": "#6B7380",
+        "tab_bar.background": "#E6E9ED",
+        "table.background": "#EEF0F3",
+        "table.hover.background": "#FFFFFF",
+        "table.active.background": "#2F7A9633",
+        "table.active.border": "#2F7A96",
+        "table.even.background": "#F7F8FA",
+        "table.head.background": "#E6E9ED",
+        "table.head.foreground": "#525A66",
+        "table.row.border": "#DDE1E6",
+        "scrollbar.background": "#EEF0F3",
+        "scrollbar.thumb.background": "#C9CFD6",
+        "scrollbar.thumb.hover.background": "#AEB6C0",
+        "selection.background": "#2F7A9633",
+        "caret": "#2F7A96",
+        "title_bar.background": "#E6E9ED",
+        "title_bar.border": "#DDE1E6",
+        "window.border": "#DDE1E6",
+        "link": "#2F7A96",
+        "link.hover": "#3D8FAD",
+        "link.active": "#25657D",
+        "skeleton.background": "#DDE1E6",
+        "progress.bar.background": "#2F7A96",
+        "drag.border": "#2F7A96",
+        "drop_target.background": "#2F7A9622"
+    }"##;
+    parse_colors(json, ThemeName::TallinnDay)
+}
+
+fn parse_colors(json: &str, name: ThemeName) -> ThemeConfigColors {
     match serde_json::from_str(json) {
         Ok(colors) => colors,
         Err(e) => {
             tracing::error!(
                 event = "ui.theme.deserialization_failed",
+                theme = name.display_name(),
                 error = %e,
-                "Tallinn Night theme JSON is invalid — falling back to library defaults. \
+                "Theme JSON is invalid — falling back to library defaults. \
                  This is a bug: the JSON schema may be incompatible with this gpui-component version."
             );
             ThemeConfigColors::default()
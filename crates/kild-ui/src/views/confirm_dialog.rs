@@ -190,7 +190,7 @@ pub fn render_confirm_dialog(
 
 /// Render the safety warnings box.
 ///
-/// Uses red styling for blocking warnings (uncommitted changes),
+/// Uses red styling for blocking warnings (uncommitted changes, stashes),
 /// amber styling for non-blocking warnings (unpushed commits, etc.).
 fn render_safety_warnings(info: &DestroySafety) -> impl IntoElement {
     let warnings = info.warning_messages();
@@ -9,7 +9,7 @@ use gpui::{
 use crate::components::{Status, StatusIndicator};
 use crate::state::AppState;
 use crate::theme;
-use crate::views::helpers::format_relative_time;
+use crate::views::helpers::{format_relative_time, summarize};
 use crate::views::main_view::MainView;
 use crate::views::terminal_tabs::TerminalTabs;
 use kild_core::ProcessStatus;
@@ -38,14 +38,7 @@ pub fn render_dashboard(
             .into_any_element();
     }
 
-    let running_count = displays
-        .iter()
-        .filter(|d| d.process_status == ProcessStatus::Running)
-        .count();
-    let stopped_count = displays
-        .iter()
-        .filter(|d| d.process_status == ProcessStatus::Stopped)
-        .count();
+    let summary = summarize(displays.iter().copied());
     let total_terminals: usize = displays
         .iter()
         .map(|d| {
@@ -85,7 +78,7 @@ pub fn render_dashboard(
                         .child(
                             div()
                                 .text_color(theme::text())
-                                .child(format!("{} active", running_count)),
+                                .child(format!("{} active", summary.running)),
                         ),
                 )
                 .child(
@@ -97,7 +90,7 @@ pub fn render_dashboard(
                         .child(
                             div()
                                 .text_color(theme::text())
-                                .child(format!("{} stopped", stopped_count)),
+                                .child(format!("{} stopped", summary.stopped)),
                         ),
                 )
                 .child(
@@ -1,6 +1,7 @@
 //! Shared view helpers.
 
 use chrono::{DateTime, Utc};
+use kild_core::{ProcessStatus, SessionSnapshot};
 
 /// Format RFC3339 timestamp as relative time (e.g., "5m ago", "2h ago").
 pub fn format_relative_time(timestamp: &str) -> String {
@@ -31,9 +32,136 @@ pub fn format_relative_time(timestamp: &str) -> String {
     }
 }
 
+/// Kild counts by process status.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StatusSummary {
+    pub running: usize,
+    pub stopped: usize,
+    pub unknown: usize,
+}
+
+impl StatusSummary {
+    /// Total number of kilds counted.
+    pub fn total(&self) -> usize {
+        self.running + self.stopped + self.unknown
+    }
+
+    /// Header text like "3 running · 1 stopped · 1 unknown".
+    ///
+    /// Zero counts are left out; an empty summary reads "0 kilds".
+    pub fn label(&self) -> String {
+        let parts: Vec<String> = [
+            (self.running, "running"),
+            (self.stopped, "stopped"),
+            (self.unknown, "unknown"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, name)| format!("{} {}", count, name))
+        .collect();
+
+        if parts.is_empty() {
+            "0 kilds".to_string()
+        } else {
+            parts.join(" · ")
+        }
+    }
+}
+
+/// Count kilds by `ProcessStatus`.
+pub fn summarize<'a>(displays: impl IntoIterator<Item = &'a SessionSnapshot>) -> StatusSummary {
+    let mut summary = StatusSummary::default();
+    for display in displays {
+        match display.process_status {
+            ProcessStatus::Running => summary.running += 1,
+            ProcessStatus::Stopped => summary.stopped += 1,
+            ProcessStatus::Unknown => summary.unknown += 1,
+        }
+    }
+    summary
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use kild_core::Session;
+    use kild_core::sessions::info::test_helpers::make_test_snapshot;
+    use kild_core::sessions::types::SessionStatus;
+    use std::path::PathBuf;
+
+    fn snapshot(id: &str, process_status: ProcessStatus) -> SessionSnapshot {
+        SessionSnapshot {
+            process_status,
+            ..make_test_snapshot(Session::new(
+                id.into(),
+                "project".into(),
+                id.into(),
+                PathBuf::from("/tmp/test"),
+                "claude".to_string(),
+                SessionStatus::Active,
+                "2024-01-01T00:00:00Z".to_string(),
+                0,
+                0,
+                0,
+                None,
+                None,
+                None,
+                vec![],
+                None,
+                None,
+                None,
+            ))
+        }
+    }
+
+    #[test]
+    fn test_summarize_mixed_statuses() {
+        let displays = vec![
+            snapshot("a", ProcessStatus::Running),
+            snapshot("b", ProcessStatus::Running),
+            snapshot("c", ProcessStatus::Running),
+            snapshot("d", ProcessStatus::Stopped),
+            snapshot("e", ProcessStatus::Unknown),
+        ];
+
+        let summary = summarize(&displays);
+
+        assert_eq!(
+            summary,
+            StatusSummary {
+                running: 3,
+                stopped: 1,
+                unknown: 1,
+            }
+        );
+        assert_eq!(summary.total(), 5);
+        assert_eq!(summary.label(), "3 running · 1 stopped · 1 unknown");
+    }
+
+    #[test]
+    fn test_summarize_all_running() {
+        let displays = vec![
+            snapshot("a", ProcessStatus::Running),
+            snapshot("b", ProcessStatus::Running),
+        ];
+
+        let summary = summarize(&displays);
+
+        assert_eq!(summary.running, 2);
+        assert_eq!(summary.stopped, 0);
+        assert_eq!(summary.unknown, 0);
+        assert_eq!(summary.label(), "2 running");
+    }
+
+    #[test]
+    fn test_summarize_empty_list_is_zero() {
+        let displays: Vec<SessionSnapshot> = Vec::new();
+        let summary = summarize(&displays);
+
+        assert_eq!(summary, StatusSummary::default());
+        assert_eq!(summary.total(), 0);
+        assert_eq!(summary.label(), "0 kilds");
+    }
 
     #[test]
     fn test_format_relative_time_invalid_timestamp() {
@@ -180,15 +180,23 @@ pub(crate) struct UiNavigationKeybindings {
     pub(crate) prev_kild: ParsedKeybinding,
     pub(crate) jump_modifier: ParsedJumpModifier,
     pub(crate) toggle_view: ParsedKeybinding,
+    pub(crate) toggle_theme: ParsedKeybinding,
     pub(crate) next_workspace: ParsedKeybinding,
     pub(crate) prev_workspace: ParsedKeybinding,
 }
 
+/// Parsed detail view keybindings.
+#[derive(Clone)]
+pub(crate) struct UiDetailKeybindings {
+    pub(crate) copy_branch: ParsedKeybinding,
+}
+
 /// All parsed UI keybindings, ready for use in `on_key_down` handlers.
 #[derive(Clone)]
 pub(crate) struct UiKeybindings {
     pub(crate) terminal: UiTerminalKeybindings,
     pub(crate) navigation: UiNavigationKeybindings,
+    pub(crate) detail: UiDetailKeybindings,
 }
 
 impl UiKeybindings {
@@ -219,9 +227,13 @@ impl UiKeybindings {
                 prev_kild: parse_or_default(nav.prev_kild(), "cmd+k"),
                 jump_modifier: ParsedJumpModifier::from_str(nav.jump_modifier()),
                 toggle_view: parse_or_default(nav.toggle_view(), "cmd+d"),
+                toggle_theme: parse_or_default(nav.toggle_theme(), "cmd+shift+t"),
                 next_workspace: parse_or_default(nav.next_workspace(), "cmd+shift+]"),
                 prev_workspace: parse_or_default(nav.prev_workspace(), "cmd+shift+["),
             },
+            detail: UiDetailKeybindings {
+                copy_branch: parse_or_default(keybindings.detail.copy_branch(), "cmd+shift+c"),
+            },
         }
     }
 
@@ -252,6 +264,7 @@ impl UiKeybindings {
         self.navigation.next_kild.matches(keystroke)
             || self.navigation.prev_kild.matches(keystroke)
             || self.navigation.toggle_view.matches(keystroke)
+            || self.navigation.toggle_theme.matches(keystroke)
             || self.navigation.next_workspace.matches(keystroke)
             || self.navigation.prev_workspace.matches(keystroke)
             || self.terminal.focus_escape.matches(keystroke)
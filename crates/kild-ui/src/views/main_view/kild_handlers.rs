@@ -263,6 +263,30 @@ impl MainView {
         cx.write_to_clipboard(gpui::ClipboardItem::new_string(path_str));
     }
 
+    /// Copy the selected kild's branch name to the clipboard (detail view shortcut).
+    ///
+    /// No-op when nothing is selected. Shows a status bar notice on success
+    /// and re-renders once it expires so the notice disappears.
+    pub(super) fn on_copy_branch(&mut self, cx: &mut Context<Self>) {
+        let Some(branch) = self.state.copy_selected_branch() else {
+            tracing::debug!(event = "ui.copy_branch.no_selection");
+            return;
+        };
+        tracing::info!(event = "ui.copy_branch.completed", branch = %branch);
+        cx.write_to_clipboard(gpui::ClipboardItem::new_string(branch));
+        cx.notify();
+
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            cx.background_executor()
+                .timer(crate::state::notice::NOTICE_DURATION)
+                .await;
+            if let Err(e) = this.update(cx, |_, cx| cx.notify()) {
+                tracing::debug!(event = "ui.copy_branch.view_dropped", error = ?e);
+            }
+        })
+        .detach();
+    }
+
     /// Handle click on the Open Editor button in a kild row.
     ///
     /// Opens the worktree in the user's preferred editor ($EDITOR or zed).
@@ -354,4 +378,12 @@ impl MainView {
         tracing::info!(event = "ui.errors.dismissed");
         self.mutate_state(cx, |s| s.dismiss_errors());
     }
+
+    /// Handle click on the Retry button in the sidebar load-error state.
+    pub(crate) fn on_retry_load_sessions(&mut self, cx: &mut Context<Self>) {
+        if self.state.retry_load_sessions() {
+            self.prune_terminal_cache();
+        }
+        cx.notify();
+    }
 }
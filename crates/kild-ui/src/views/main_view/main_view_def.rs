@@ -40,6 +40,8 @@ pub struct MainView {
         usize,
         gpui::Entity<gpui_component::input::InputState>,
     )>,
+    /// Sidebar search field. Set while search mode is open (`/`).
+    pub(super) search_input: Option<gpui::Entity<gpui_component::input::InputState>>,
     /// Whether the daemon is available. None = unknown/not checked.
     pub(super) daemon_available: Option<bool>,
     /// Whether the "+" terminal create menu is open.
@@ -55,6 +57,8 @@ pub struct MainView {
     pub(super) active_workspace: usize,
     /// Parsed keybindings from `~/.kild/keybindings.toml` (or defaults).
     pub(super) keybindings: UiKeybindings,
+    /// Keys bound to more than one action, shown in the status bar.
+    pub(super) keybinding_conflicts: Vec<kild_config::KeybindingConflict>,
     /// Agent team manager (owns watcher + cached team state).
     pub(super) team_manager: crate::teams::TeamManager,
     /// Handle to the team watcher task. Must be stored to prevent cancellation.
@@ -83,28 +87,74 @@ impl MainView {
         let watcher = SessionWatcher::new(&sessions_dir);
         let has_watcher = watcher.is_some();
 
-        // Determine poll interval based on watcher availability
-        let poll_interval = if has_watcher {
-            crate::refresh::POLL_INTERVAL // 60s with watcher
-        } else {
-            crate::refresh::FAST_POLL_INTERVAL // 5s fallback
+        // Periodic reload interval: `[ui] refresh_interval_secs`, else 60s with
+        // watcher / 5s without. Config errors fall back to the defaults.
+        let ui_config = match kild_config::KildConfig::load_hierarchy() {
+            Ok(cfg) => cfg.ui,
+            Err(e) => {
+                tracing::warn!(
+                    event = "ui.auto_refresh.config_load_failed",
+                    error = %e,
+                    "Using default refresh interval"
+                );
+                kild_config::UiConfig::default()
+            }
         };
+        let refresh_interval =
+            crate::refresh::refresh_interval(ui_config.refresh_interval_secs, has_watcher);
 
-        // Slow poll task (60s with watcher, 5s without)
+        // Periodic reload task. Wakes every SCHEDULE_TICK and reloads once the
+        // interval has elapsed, deferring while a dialog is open. The disk + git
+        // load runs on the background executor; only the diff is applied on the
+        // main thread.
         let refresh_task = cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let Some(interval) = refresh_interval else {
+                tracing::debug!(event = "ui.auto_refresh.skipped", reason = "disabled");
+                return;
+            };
+
             tracing::debug!(
                 event = "ui.auto_refresh.started",
-                interval_secs = poll_interval.as_secs()
+                interval_secs = interval.as_secs()
             );
+            let mut schedule =
+                crate::refresh::RefreshSchedule::new(Some(interval), std::time::Instant::now());
 
             loop {
-                cx.background_executor().timer(poll_interval).await;
+                cx.background_executor()
+                    .timer(crate::refresh::SCHEDULE_TICK)
+                    .await;
+
+                let now = std::time::Instant::now();
+                let due = match this.update(cx, |view, _cx| {
+                    schedule.is_due(now, view.state.dialog().is_open())
+                }) {
+                    Ok(due) => due,
+                    Err(e) => {
+                        tracing::debug!(
+                            event = "ui.auto_refresh.stopped",
+                            reason = "view_dropped",
+                            error = ?e
+                        );
+                        break;
+                    }
+                };
+                if !due {
+                    continue;
+                }
+
+                let load = cx
+                    .background_executor()
+                    .spawn(async { crate::actions::refresh_sessions() })
+                    .await;
 
                 if let Err(e) = this.update(cx, |view, cx| {
-                    tracing::debug!(event = "ui.auto_refresh.tick");
-                    view.state.update_statuses_only();
-                    view.prune_terminal_cache();
-                    cx.notify();
+                    let changed = view.state.apply_session_load(load);
+                    tracing::debug!(event = "ui.auto_refresh.tick", changed = changed);
+                    if changed {
+                        view.prune_terminal_cache();
+                        cx.notify();
+                    }
                 }) {
                     tracing::debug!(
                         event = "ui.auto_refresh.stopped",
@@ -113,6 +163,7 @@ impl MainView {
                     );
                     break;
                 }
+                schedule.mark_refreshed(now);
             }
         });
 
@@ -124,9 +175,7 @@ impl MainView {
             };
 
             tracing::debug!(event = "ui.watcher_task.started");
-            let mut last_refresh = std::time::Instant::now();
-            // Track if events were detected but debounced - ensures we refresh after debounce expires
-            let mut pending_refresh = false;
+            let mut debounce = crate::refresh::EventDebounce::default();
 
             loop {
                 // Check for events every 50ms (cheap - just channel poll)
@@ -134,19 +183,40 @@ impl MainView {
                     .timer(std::time::Duration::from_millis(50))
                     .await;
 
-                if let Err(e) = this.update(cx, |view, cx| {
+                let ready = match this.update(cx, |view, _cx| {
+                    let now = std::time::Instant::now();
                     // Check for new events (this drains the queue)
                     if watcher.has_pending_events() {
-                        pending_refresh = true;
+                        debounce.record(now);
+                    }
+
+                    // Events stay pending while a dialog is open and apply once it closes.
+                    !view.state.dialog().is_open() && debounce.take_ready(now)
+                }) {
+                    Ok(ready) => ready,
+                    Err(e) => {
+                        tracing::debug!(
+                            event = "ui.watcher_task.stopped",
+                            reason = "view_dropped",
+                            error = ?e
+                        );
+                        break;
                     }
+                };
+                if !ready {
+                    continue;
+                }
+
+                let load = cx
+                    .background_executor()
+                    .spawn(async { crate::actions::refresh_sessions() })
+                    .await;
 
-                    // Refresh if we have pending events AND debounce period has passed
-                    if pending_refresh && last_refresh.elapsed() > crate::refresh::DEBOUNCE_INTERVAL
-                    {
-                        tracing::info!(event = "ui.watcher.refresh_triggered");
-                        view.refresh_and_prune();
-                        last_refresh = std::time::Instant::now();
-                        pending_refresh = false;
+                if let Err(e) = this.update(cx, |view, cx| {
+                    let changed = view.state.apply_session_load(load);
+                    tracing::info!(event = "ui.watcher.refresh_triggered", changed = changed);
+                    if changed {
+                        view.prune_terminal_cache();
                         cx.notify();
                     }
                 }) {
@@ -232,6 +302,7 @@ impl MainView {
         // Load keybindings from hierarchy (~/.kild/keybindings.toml → ./.kild/keybindings.toml)
         let raw = kild_core::Keybindings::load_hierarchy();
         let keybindings = UiKeybindings::from_config(&raw);
+        let keybinding_conflicts = raw.validate();
 
         let mut view = Self {
             state: AppState::new(),
@@ -247,6 +318,7 @@ impl MainView {
             terminal_tabs: std::collections::HashMap::new(),
             active_terminal_id: None,
             renaming_tab: None,
+            search_input: None,
             daemon_available: None,
             show_add_menu: false,
             daemon_starting: false,
@@ -254,6 +326,7 @@ impl MainView {
             workspaces: vec![super::super::pane_grid::PaneGrid::new()],
             active_workspace: 0,
             keybindings,
+            keybinding_conflicts,
             team_manager: crate::teams::TeamManager::new(),
             _team_watcher_task: team_watcher_task,
         };
@@ -321,9 +394,4 @@ impl MainView {
             .and_then(|id| self.terminal_tabs.get(id))
             .and_then(|tabs| tabs.active_view())
     }
-
-    pub(super) fn refresh_and_prune(&mut self) {
-        self.state.refresh_sessions();
-        self.prune_terminal_cache();
-    }
 }
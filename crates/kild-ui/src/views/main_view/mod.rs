@@ -12,6 +12,7 @@ mod pane_grid_handlers;
 mod path_utils;
 mod project_handlers;
 mod rendering;
+mod sidebar_search;
 mod tab_rename;
 mod terminal_handlers;
 mod types;
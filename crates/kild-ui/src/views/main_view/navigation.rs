@@ -101,6 +101,14 @@ impl MainView {
         }
     }
 
+    /// Switch between the dark and light themes without restarting.
+    pub(super) fn toggle_theme(&mut self, cx: &mut Context<Self>) {
+        let next = crate::theme::active_theme().toggled();
+        tracing::debug!(event = "ui.theme.toggled", theme = next.display_name());
+        crate::theme_bridge::apply_theme(next, cx);
+        cx.notify();
+    }
+
     /// Toggle between Control and Dashboard views.
     pub(super) fn toggle_view(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         self.active_view = match self.active_view {
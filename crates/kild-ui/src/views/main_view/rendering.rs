@@ -218,6 +218,17 @@ impl MainView {
             return;
         }
 
+        // Sidebar search mode: Escape clears, Enter jumps to the first match,
+        // all other keys go to Input
+        if self.search_input.is_some() {
+            if key_str == "escape" {
+                self.clear_sidebar_search(window, cx);
+            } else if key_str == "enter" {
+                self.commit_sidebar_search(window, cx);
+            }
+            return;
+        }
+
         // focus_escape binding: move focus from terminal to sidebar (terminal stays rendered)
         if self
             .keybindings
@@ -383,6 +394,28 @@ impl MainView {
             return;
         }
 
+        if self
+            .keybindings
+            .navigation
+            .toggle_theme
+            .matches(&event.keystroke)
+        {
+            self.toggle_theme(cx);
+            return;
+        }
+
+        // copy_branch binding (Detail view): copy the selected branch name
+        if self.active_view == ActiveView::Detail
+            && self
+                .keybindings
+                .detail
+                .copy_branch
+                .matches(&event.keystroke)
+        {
+            self.on_copy_branch(cx);
+            return;
+        }
+
         // Escape in Detail view: back to Dashboard
         if key_str == "escape" && self.active_view == ActiveView::Detail {
             self.active_view = ActiveView::Dashboard;
@@ -403,7 +436,12 @@ impl MainView {
         }
 
         match self.state.dialog() {
-            DialogState::None => {}
+            DialogState::None => {
+                let modifiers = &event.keystroke.modifiers;
+                if key_str == "/" && !(modifiers.control || modifiers.alt || modifiers.platform) {
+                    self.open_sidebar_search(window, cx);
+                }
+            }
             DialogState::Confirm { .. } => {
                 if key_str == "escape" {
                     self.on_confirm_cancel(cx);
@@ -432,6 +470,8 @@ impl Focusable for MainView {
 
 impl Render for MainView {
     fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.sync_sidebar_search(cx);
+
         div()
             .track_focus(&self.focus_handle)
             .on_key_down(cx.listener(Self::on_key_down))
@@ -508,6 +548,7 @@ impl Render for MainView {
                                     // Sidebar (200px, kild navigation)
                                     .child(sidebar::render_sidebar(
                                         &self.state,
+                                        self.search_input.as_ref(),
                                         &self.terminal_tabs,
                                         self.active_pane_grid(),
                                         &self.team_manager,
@@ -531,6 +572,7 @@ impl Render for MainView {
                                 &self.state,
                                 self.active_view,
                                 &self.keybindings,
+                                &self.keybinding_conflicts,
                                 cx,
                             )),
                     ),
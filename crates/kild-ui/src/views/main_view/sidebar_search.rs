@@ -0,0 +1,54 @@
+//! Sidebar search handlers for MainView.
+//!
+//! `/` opens a search field above the kild list; typing narrows the list by
+//! branch or agent. Escape clears the search, Enter jumps to the first match.
+
+use gpui::{Context, Focusable, Window, prelude::*};
+use gpui_component::input::InputState;
+
+use super::main_view_def::MainView;
+use super::types::FocusRegion;
+
+impl MainView {
+    pub(super) fn open_sidebar_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        tracing::debug!(event = "ui.sidebar_search.opened");
+        self.state.open_search();
+        let input = cx.new(|cx| InputState::new(window, cx).placeholder("Filter kilds"));
+        let handle = input.read(cx).focus_handle(cx).clone();
+        self.search_input = Some(input);
+        window.focus(&handle);
+        cx.notify();
+    }
+
+    /// Copy the search field's text into state so the sidebar filters on it.
+    pub(super) fn sync_sidebar_search(&mut self, cx: &mut Context<Self>) {
+        if let Some(input) = &self.search_input {
+            let query = input.read(cx).value().to_string();
+            self.state.set_search_query(&query);
+        }
+    }
+
+    /// Close search mode and show the full list again. Selection is untouched.
+    pub(super) fn clear_sidebar_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        tracing::debug!(event = "ui.sidebar_search.cleared");
+        self.search_input = None;
+        self.state.clear_search();
+        self.focus_region = FocusRegion::Dashboard;
+        window.focus(&self.focus_handle);
+        cx.notify();
+    }
+
+    /// Select the first visible match, then close search mode.
+    pub(super) fn commit_sidebar_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.sync_sidebar_search(cx);
+        let first = self
+            .state
+            .sidebar_displays()
+            .first()
+            .map(|d| d.session.id.to_string());
+        self.clear_sidebar_search(window, cx);
+        if let Some(id) = first {
+            self.on_kild_select(&id, window, cx);
+        }
+    }
+}
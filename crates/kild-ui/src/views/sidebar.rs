@@ -8,11 +8,14 @@ use std::collections::HashMap;
 
 use crate::components::{Status, StatusIndicator};
 use crate::state::AppState;
+use crate::state::sessions::SessionLoadError;
 use crate::theme;
-use crate::views::helpers::format_relative_time;
+use crate::views::helpers::{format_relative_time, summarize};
 use crate::views::main_view::MainView;
 use crate::views::terminal_tabs::TerminalTabs;
 use gpui::Rgba;
+use gpui_component::button::Button;
+use gpui_component::input::{Input, InputState};
 use kild_core::ProcessStatus;
 
 /// Width of the sidebar in pixels.
@@ -25,6 +28,7 @@ const SELECTED_PADDING_ADJUSTMENT: f32 = 2.0;
 /// Render the navigation sidebar with kilds grouped by status.
 pub fn render_sidebar(
     state: &AppState,
+    search_input: Option<&gpui::Entity<InputState>>,
     terminal_tabs: &HashMap<String, TerminalTabs>,
     pane_grid: &super::pane_grid::PaneGrid,
     team_manager: &crate::teams::TeamManager,
@@ -35,7 +39,9 @@ pub fn render_sidebar(
         .map(|p| p.name().to_string())
         .unwrap_or_else(|| "All Projects".to_string());
 
-    let filtered = state.filtered_displays();
+    // Header counts cover the whole project, not just search matches
+    let status_summary = summarize(state.filtered_displays());
+    let filtered = state.sidebar_displays();
     let selected_id = state.selected_id().map(|s| s.to_string());
 
     let mut active_kilds = Vec::new();
@@ -50,7 +56,6 @@ pub fn render_sidebar(
 
     let active_count = active_kilds.len();
     let stopped_count = stopped_kilds.len();
-    let total_count = active_count + stopped_count;
 
     div()
         .w(px(SIDEBAR_WIDTH))
@@ -84,13 +89,20 @@ pub fn render_sidebar(
                         .text_size(px(theme::TEXT_XS))
                         .text_color(theme::text_muted())
                         .flex_shrink_0()
-                        .child(format!(
-                            "{} kild{}",
-                            total_count,
-                            if total_count == 1 { "" } else { "s" }
-                        )),
+                        .child(status_summary.label()),
                 ),
         )
+        // Search field (`/` to open, Escape to clear)
+        .when_some(search_input, |this, input| {
+            this.child(
+                div()
+                    .px(px(theme::SPACE_2))
+                    .py(px(theme::SPACE_1))
+                    .border_b_1()
+                    .border_color(theme::border_subtle())
+                    .child(Input::new(input)),
+            )
+        })
         // Scrollable kild list
         .child(
             div()
@@ -106,6 +118,9 @@ pub fn render_sidebar(
                         let is_selected = selected_id.as_deref() == Some(&*session_id);
                         let session_id_for_click = session_id.to_string();
                         let time_meta = format_relative_time(&display.session.created_at);
+                        let resource_meta = display.resource_usage.as_ref().map(|usage| {
+                            format!("{:.0}% {}M", usage.cpu_percent, usage.memory_mb())
+                        });
 
                         let tabs_for_session = terminal_tabs.get(&*session_id);
                         let tab_items = render_terminal_items(
@@ -128,6 +143,7 @@ pub fn render_sidebar(
                                     &branch,
                                     Status::Active,
                                     is_selected,
+                                    resource_meta.as_deref(),
                                     &time_meta,
                                     teammate_count,
                                     cx.listener(move |view, _, window, cx| {
@@ -190,6 +206,7 @@ pub fn render_sidebar(
                                         &branch,
                                         status,
                                         is_selected,
+                                        None, // stopped kilds have no process to sample
                                         &time_meta,
                                         0, // no badge for stopped kilds
                                         cx.listener(move |view, _, window, cx| {
@@ -207,9 +224,15 @@ pub fn render_sidebar(
                         stopped_elements
                     })
                 })
+                // Load error: show the code and message with a retry button
+                .when_some(state.load_error().cloned(), |this, error| {
+                    this.child(render_load_error(&error, cx))
+                })
                 // Empty state
                 .when(
-                    active_kilds.is_empty() && stopped_kilds.is_empty(),
+                    active_kilds.is_empty()
+                        && stopped_kilds.is_empty()
+                        && state.load_error().is_none(),
                     |this| {
                         this.child(
                             div()
@@ -217,7 +240,11 @@ pub fn render_sidebar(
                                 .py(px(theme::SPACE_6))
                                 .text_size(px(theme::TEXT_SM))
                                 .text_color(theme::text_subtle())
-                                .child("No kilds"),
+                                .child(if state.is_searching() {
+                                    "No matching kilds"
+                                } else {
+                                    "No kilds"
+                                }),
                         )
                     },
                 ),
@@ -248,6 +275,40 @@ pub fn render_sidebar(
         )
 }
 
+/// Heading for the sidebar load-error state, naming the `SessionError` code.
+fn load_error_heading(error: &SessionLoadError) -> String {
+    format!("Failed to load kilds ({})", error.code)
+}
+
+fn render_load_error(error: &SessionLoadError, cx: &mut Context<MainView>) -> impl IntoElement {
+    div()
+        .px(px(theme::SPACE_4))
+        .py(px(theme::SPACE_4))
+        .flex()
+        .flex_col()
+        .gap(px(theme::SPACE_2))
+        .child(
+            div()
+                .text_size(px(theme::TEXT_SM))
+                .font_weight(FontWeight::MEDIUM)
+                .text_color(theme::ember())
+                .child(load_error_heading(error)),
+        )
+        .child(
+            div()
+                .text_size(px(theme::TEXT_XS))
+                .text_color(theme::text_subtle())
+                .child(error.message.clone()),
+        )
+        .child(
+            Button::new("sidebar-retry-load")
+                .label("Retry")
+                .on_click(cx.listener(|view, _, _, cx| {
+                    view.on_retry_load_sessions(cx);
+                })),
+        )
+}
+
 fn render_section_header(title: &str, count: usize, count_color: Rgba) -> impl IntoElement {
     div()
         .px(px(theme::SPACE_3))
@@ -271,12 +332,14 @@ fn render_section_header(title: &str, count: usize, count_color: Rgba) -> impl I
         )
 }
 
-/// Render a clean kild row with status dot, branch name, and time meta.
+/// Render a clean kild row with status dot, branch name, resource usage, and time meta.
+#[allow(clippy::too_many_arguments)]
 fn render_kild_row(
     id: impl Into<gpui::ElementId>,
     branch: &str,
     status: Status,
     is_selected: bool,
+    resource_meta: Option<&str>,
     time_meta: &str,
     teammate_count: usize,
     on_click: impl Fn(&gpui::MouseUpEvent, &mut gpui::Window, &mut gpui::App) + 'static,
@@ -315,6 +378,16 @@ fn render_kild_row(
                 .min_w(px(0.0))
                 .child(branch.to_string()),
         )
+        // CPU / memory sample (only when the agent process is running)
+        .when_some(resource_meta, |row, meta| {
+            row.child(
+                div()
+                    .flex_shrink_0()
+                    .text_size(px(theme::TEXT_XXS))
+                    .text_color(theme::text_subtle())
+                    .child(meta.to_string()),
+            )
+        })
         // Time meta
         .child(
             div()
@@ -520,3 +593,21 @@ fn render_add_terminal_button(
         )
         .child("+ terminal")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_error_heading_shows_error_code() {
+        let error = SessionLoadError {
+            code: "INVALID_SESSION_STRUCTURE",
+            message: "Invalid session structure: missing field".to_string(),
+        };
+
+        assert_eq!(
+            load_error_heading(&error),
+            "Failed to load kilds (INVALID_SESSION_STRUCTURE)"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! Status bar component — thin footer spanning sidebar + main area.
 //!
-//! Shows contextual alerts on the left (dirty worktrees, operation errors)
+//! Shows contextual alerts on the left (dirty worktrees, operation errors,
+//! keybinding conflicts), replaced briefly by confirmations like "Copied ...",
 //! and view-aware keyboard shortcut hints on the right.
 
 use gpui::{
@@ -12,6 +13,7 @@ use crate::state::AppState;
 use crate::theme;
 use crate::views::main_view::keybindings::UiKeybindings;
 use crate::views::main_view::{ActiveView, MainView};
+use kild_config::KeybindingConflict;
 use kild_core::{GitStatus, ProcessStatus};
 
 /// Maximum number of alerts shown before truncation.
@@ -28,6 +30,7 @@ pub fn render_status_bar(
     state: &AppState,
     active_view: ActiveView,
     keybindings: &UiKeybindings,
+    keybinding_conflicts: &[KeybindingConflict],
     cx: &mut Context<MainView>,
 ) -> AnyElement {
     div()
@@ -39,17 +42,24 @@ pub fn render_status_bar(
         .bg(theme::obsidian())
         .border_t_1()
         .border_color(theme::border_subtle())
-        .child(render_alerts(state, cx))
+        .child(match state.active_notice() {
+            Some(notice) => render_notice(notice).into_any_element(),
+            None => render_alerts(state, keybinding_conflicts, cx).into_any_element(),
+        })
         .child(render_keyboard_hints(active_view, keybindings, cx))
         .into_any_element()
 }
 
 /// Compute and render alerts from session state.
 ///
-/// Shows operation errors (ember dot) and dirty stopped kilds (copper dot).
-/// Truncates to MAX_ALERTS with "+N more" overflow.
-fn render_alerts(state: &AppState, _cx: &mut Context<MainView>) -> impl IntoElement {
-    let alerts = compute_alerts(state);
+/// Shows operation errors (ember dot), keybinding conflicts and dirty stopped
+/// kilds (copper dot). Truncates to MAX_ALERTS with "+N more" overflow.
+fn render_alerts(
+    state: &AppState,
+    keybinding_conflicts: &[KeybindingConflict],
+    _cx: &mut Context<MainView>,
+) -> impl IntoElement {
+    let alerts = compute_alerts(state, keybinding_conflicts);
     let overflow = alerts.len().saturating_sub(MAX_ALERTS);
     let visible: Vec<&Alert> = alerts.iter().take(MAX_ALERTS).collect();
 
@@ -68,6 +78,16 @@ fn render_alerts(state: &AppState, _cx: &mut Context<MainView>) -> impl IntoElem
         })
 }
 
+/// Render a transient confirmation (e.g. "Copied feature-auth").
+///
+/// Takes the alerts' place while visible; alerts return once it expires.
+fn render_notice(message: &str) -> impl IntoElement {
+    div()
+        .text_size(px(10.0))
+        .text_color(theme::aurora())
+        .child(message.to_string())
+}
+
 /// Render a single alert item: dot + message.
 fn render_alert_item(alert: &Alert) -> impl IntoElement {
     let dot_color = if alert.is_error {
@@ -101,8 +121,9 @@ fn render_alert_item(alert: &Alert) -> impl IntoElement {
 ///
 /// Priority ordering (highest first):
 /// 1. Operation errors (ember dot) — from `state.get_error()`
-/// 2. Dirty stopped kilds (copper dot) — running kilds are expected to be dirty
-fn compute_alerts(state: &AppState) -> Vec<Alert> {
+/// 2. Keybinding conflicts (copper dot) — from `Keybindings::validate()`
+/// 3. Dirty stopped kilds (copper dot) — running kilds are expected to be dirty
+fn compute_alerts(state: &AppState, keybinding_conflicts: &[KeybindingConflict]) -> Vec<Alert> {
     let mut alerts = Vec::new();
     let displays = state.filtered_displays();
 
@@ -116,6 +137,8 @@ fn compute_alerts(state: &AppState) -> Vec<Alert> {
         }
     }
 
+    alerts.extend(keybinding_conflict_alerts(keybinding_conflicts));
+
     // Dirty stopped kilds
     for display in &displays {
         if display.process_status == ProcessStatus::Stopped
@@ -131,6 +154,15 @@ fn compute_alerts(state: &AppState) -> Vec<Alert> {
     alerts
 }
 
+/// One alert per keybinding conflict, e.g. "Keybinding conflict: cmd+j is
+/// bound to navigation.next_kild, terminal.copy".
+fn keybinding_conflict_alerts(conflicts: &[KeybindingConflict]) -> impl Iterator<Item = Alert> {
+    conflicts.iter().map(|conflict| Alert {
+        message: format!("Keybinding conflict: {}", conflict),
+        is_error: false,
+    })
+}
+
 /// Render view-aware keyboard shortcut hints.
 fn render_keyboard_hints(
     active_view: ActiveView,
@@ -184,6 +216,7 @@ fn keyboard_hints_for_view(
         ],
         ActiveView::Detail => vec![
             ("escape".to_string(), "back"),
+            (keybindings.detail.copy_branch.hint_str(), "copy branch"),
             (keybindings.navigation.toggle_view.hint_str(), "control"),
         ],
     }
@@ -195,6 +228,7 @@ mod tests {
     use crate::views::main_view::keybindings::UiKeybindings;
     use kild_core::Session;
     use kild_core::sessions::SessionSnapshot;
+    use kild_core::sessions::info::test_helpers::make_test_snapshot;
 
     fn default_kb() -> UiKeybindings {
         UiKeybindings::default_bindings()
@@ -291,9 +325,11 @@ mod tests {
     #[test]
     fn test_keyboard_hints_detail_view() {
         let hints = keyboard_hints_for_view(ActiveView::Detail, &default_kb());
-        assert_eq!(hints.len(), 2);
+        assert_eq!(hints.len(), 3);
         assert_eq!(hints[0].0, "escape");
         assert_eq!(hints[0].1, "back");
+        assert_eq!(hints[1].0, "cmd-shift-c");
+        assert_eq!(hints[1].1, "copy branch");
     }
 
     #[test]
@@ -335,6 +371,31 @@ mod tests {
         assert!(!alerts[1].is_error, "dirty alert should come second");
     }
 
+    #[test]
+    fn test_keybinding_conflict_alerts() {
+        let conflicts = [KeybindingConflict {
+            key: "cmd+c".to_string(),
+            actions: vec![
+                "terminal.copy".to_string(),
+                "navigation.next_kild".to_string(),
+            ],
+        }];
+
+        let alerts: Vec<Alert> = keybinding_conflict_alerts(&conflicts).collect();
+        assert_eq!(alerts.len(), 1);
+        assert!(!alerts[0].is_error);
+        assert_eq!(
+            alerts[0].message,
+            "Keybinding conflict: cmd+c is bound to terminal.copy, navigation.next_kild"
+        );
+    }
+
+    #[test]
+    fn test_keybinding_conflict_alerts_clean_set() {
+        let conflicts = kild_core::Keybindings::default().validate();
+        assert_eq!(keybinding_conflict_alerts(&conflicts).count(), 0);
+    }
+
     #[test]
     fn test_compute_alerts_running_dirty_kild_no_alert() {
         let info = make_info("active-work", ProcessStatus::Running, GitStatus::Dirty);
@@ -392,10 +453,9 @@ mod tests {
         });
         let session: Session = serde_json::from_value(json).unwrap();
         SessionSnapshot {
-            session,
             process_status,
             git_status,
-            uncommitted_diff: None,
+            ..make_test_snapshot(session)
         }
     }
 }
@@ -111,9 +111,14 @@ impl SessionWatcher {
         }
 
         // Session files: kild.json, status sidecar, pr sidecar
-        // Also support old-format .json and .status files during migration
+        // Also support old-format .json and .status files during migration.
+        // branch_index.json is a lookup cache written alongside kild.json, so
+        // it never needs a refresh of its own.
         event.paths.iter().any(|p| {
             let file_name = p.file_name().and_then(|f| f.to_str());
+            if file_name == Some("branch_index.json") {
+                return false;
+            }
             matches!(file_name, Some("kild.json" | "status" | "pr"))
                 || p.extension()
                     .and_then(|ext| ext.to_str())
@@ -295,6 +300,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_relevant_event_ignores_branch_index() {
+        let event = make_event(
+            EventKind::Modify(ModifyKind::Data(notify::event::DataChange::Content)),
+            vec![PathBuf::from("/sessions/branch_index.json")],
+        );
+        assert!(
+            !SessionWatcher::is_relevant_event(&event),
+            "Should ignore branch_index.json cache writes"
+        );
+    }
+
     // --- Integration tests for SessionWatcher::new ---
 
     #[test]
@@ -307,6 +324,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_failed_watch_registration_falls_back_to_fast_polling() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        // Watching a path that doesn't exist fails at registration time
+        let missing = temp_dir.path().join("sessions");
+        let watcher = SessionWatcher::new(&missing);
+        assert!(watcher.is_none());
+
+        assert_eq!(
+            crate::refresh::refresh_interval(None, watcher.is_some()),
+            Some(crate::refresh::FAST_POLL_INTERVAL),
+            "Without a watcher the list should poll at the fast interval"
+        );
+    }
+
     #[test]
     fn test_session_watcher_new_succeeds_for_existing_directory() {
         let temp_dir = tempfile::TempDir::new().unwrap();
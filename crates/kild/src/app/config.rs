@@ -0,0 +1,23 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn config_command() -> Command {
+    Command::new("config")
+        .about("Inspect KILD configuration")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("show")
+                .about("Show the effective config and which file set each value")
+                .arg(
+                    Arg::new("key").long("key").short('k').help(
+                        "Only show this dotted key, or keys under it (e.g. agent.default, git)",
+                    ),
+                )
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .help("Output as JSON")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+}
@@ -16,7 +16,10 @@ pub fn daemon_command() -> Command {
                 ),
         )
         .subcommand(Command::new("stop").about("Stop the running KILD daemon"))
-        .subcommand(Command::new("restart").about("Restart the KILD daemon (stop + start)"))
+        .subcommand(Command::new("restart").about(
+            "Restart the KILD daemon (stop + start). Daemon sessions are not preserved: \
+             their terminals close and agents are hung up",
+        ))
         .subcommand(
             Command::new("status").about("Show daemon status").arg(
                 Arg::new("json")
@@ -25,6 +28,16 @@ pub fn daemon_command() -> Command {
                     .action(ArgAction::SetTrue),
             ),
         )
+        .subcommand(
+            Command::new("stats")
+                .about("Show daemon runtime counters")
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .help("Output as JSON")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
 }
 
 pub fn inject_command() -> Command {
@@ -71,4 +84,10 @@ pub fn attach_command() -> Command {
                 .help("Attach to a specific teammate pane (e.g. %1, %2)")
                 .value_name("PANE_ID"),
         )
+        .arg(
+            Arg::new("read-only")
+                .long("read-only")
+                .help("Watch output without sending keystrokes or resizing the session")
+                .action(ArgAction::SetTrue),
+        )
 }
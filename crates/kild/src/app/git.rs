@@ -18,7 +18,16 @@ pub fn diff_command() -> Command {
         .arg(
             Arg::new("stat")
                 .long("stat")
+                .visible_alias("stat-only")
                 .help("Show unstaged diffstat summary instead of full diff")
+                .conflicts_with("staged")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Output diffstat as JSON (requires --stat)")
+                .requires("stat")
                 .action(ArgAction::SetTrue),
         )
 }
@@ -27,6 +27,14 @@ pub fn root_command() -> Command {
                 .help("Connect to a remote daemon instead of the local socket")
                 .global(true),
         )
+        .arg(
+            Arg::new("remote-alias")
+                .long("remote-alias")
+                .value_name("NAME")
+                .help("Connect to a remote daemon named in [daemon.remotes] config")
+                .conflicts_with("remote")
+                .global(true),
+        )
         .arg(
             Arg::new("remote-fingerprint")
                 .long("remote-fingerprint")
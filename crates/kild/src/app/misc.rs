@@ -199,6 +199,11 @@ pub fn health_command() -> Command {
         )
 }
 
+pub fn doctor_command() -> Command {
+    Command::new("doctor")
+        .about("Check the kild installation: daemon, tmux shim, hooks, and agents")
+}
+
 pub fn completions_command() -> Command {
     Command::new("completions")
         .about("Generate shell completion scripts")
@@ -244,6 +249,12 @@ pub fn code_command() -> Command {
                 .short('e')
                 .help("Editor to use (overrides config, $EDITOR, and default 'zed')"),
         )
+        .arg(
+            Arg::new("new-window")
+                .long("new-window")
+                .help("Open in a new editor window instead of reusing one (overrides config)")
+                .action(ArgAction::SetTrue),
+        )
 }
 
 pub fn focus_command() -> Command {
@@ -296,4 +307,17 @@ pub fn pr_command() -> Command {
                 .help("Force refresh PR data from GitHub")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("create")
+                .long("create")
+                .help("Open a PR for the kild's branch if none exists")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("draft")
+                .long("draft")
+                .help("Open the PR as a draft (requires --create)")
+                .action(ArgAction::SetTrue)
+                .requires("create"),
+        )
 }
@@ -1,3 +1,4 @@
+mod config;
 mod daemon;
 mod git;
 mod global;
@@ -20,7 +21,14 @@ pub fn build_cli() -> Command {
         .subcommand(session::complete_command())
         .subcommand(session::open_command())
         .subcommand(session::stop_command())
+        .subcommand(session::restart_command())
+        .subcommand(session::rename_command())
+        .subcommand(session::copy_out_command())
+        .subcommand(session::archive_command())
+        .subcommand(session::unarchive_command())
         .subcommand(session::teammates_command())
+        .subcommand(session::export_command())
+        .subcommand(session::import_command())
         .subcommand(misc::code_command())
         .subcommand(misc::focus_command())
         .subcommand(misc::hide_command())
@@ -28,6 +36,8 @@ pub fn build_cli() -> Command {
         .subcommand(git::commits_command())
         .subcommand(misc::pr_command())
         .subcommand(query::status_command())
+        .subcommand(query::logs_command())
+        .subcommand(query::events_command())
         .subcommand(query::agent_status_command())
         .subcommand(git::rebase_command())
         .subcommand(git::sync_command())
@@ -37,10 +47,12 @@ pub fn build_cli() -> Command {
         .subcommand(misc::prime_command())
         .subcommand(misc::overlaps_command())
         .subcommand(misc::health_command())
+        .subcommand(misc::doctor_command())
         .subcommand(daemon::daemon_command())
         .subcommand(daemon::attach_command())
         .subcommand(daemon::inject_command())
         .subcommand(misc::completions_command())
         .subcommand(misc::init_hooks_command())
         .subcommand(project::project_command())
+        .subcommand(config::config_command())
 }
@@ -9,6 +9,19 @@ pub fn list_command() -> Command {
                 .help("Output in JSON format")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("filter")
+                .long("filter")
+                .short('f')
+                .value_name("EXPR")
+                .help("Only show kilds matching all predicates, e.g. 'agent=claude status=working branch~auth' (keys: agent, status, branch, tag, note)"),
+        )
+        .arg(
+            Arg::new("archived")
+                .long("archived")
+                .help("Include archived kilds")
+                .action(ArgAction::SetTrue),
+        )
 }
 
 pub fn cd_command() -> Command {
@@ -20,6 +33,48 @@ pub fn cd_command() -> Command {
                 .required(true)
                 .index(1),
         )
+        .arg(
+            Arg::new("print-path")
+                .long("print-path")
+                .help("Print only the worktree path, for cd \"$(kild cd <branch> --print-path)\"")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+pub fn logs_command() -> Command {
+    Command::new("logs")
+        .about("Print an agent's captured output (daemon sessions only)")
+        .arg(
+            Arg::new("branch")
+                .help("Branch name of the kild")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("follow")
+                .long("follow")
+                .short('f')
+                .help("Keep streaming new output until interrupted")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+pub fn events_command() -> Command {
+    Command::new("events")
+        .about("Show kild lifecycle events (created, stopped, destroyed, agent status)")
+        .arg(
+            Arg::new("follow")
+                .long("follow")
+                .short('f')
+                .help("Stream new events as they happen until interrupted")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Output one JSON object per line")
+                .action(ArgAction::SetTrue),
+        )
 }
 
 pub fn status_command() -> Command {
@@ -13,8 +13,7 @@ pub fn create_command() -> Command {
             Arg::new("agent")
                 .long("agent")
                 .short('a')
-                .help("AI agent to launch (overrides config)")
-                .value_parser(["amp", "claude", "kiro", "gemini", "codex", "opencode"]),
+                .help("AI agent to launch: a built-in or an [agents.<name>] config agent (overrides config)"),
         )
         .arg(
             Arg::new("terminal")
@@ -47,6 +46,17 @@ pub fn create_command() -> Command {
                 .help("GitHub issue number to link to this kild, e.g. --issue 123 (shown in list/status, used by wave planner)")
                 .value_parser(clap::value_parser!(u32).range(1..)),
         )
+        .arg(
+            Arg::new("from")
+                .long("from")
+                .help("Copy agent, base branch and include patterns from an existing kild (explicit flags still win)"),
+        )
+        .arg(
+            Arg::new("dry-run-includes")
+                .long("dry-run-includes")
+                .help("List the files include patterns would copy into the worktree, then exit without creating")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("base")
                 .long("base")
@@ -59,6 +69,13 @@ pub fn create_command() -> Command {
                 .help("Skip fetching from remote before creating worktree")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("init-submodules")
+                .long("init-submodules")
+                .help("Initialize git submodules in the new worktree")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("main"),
+        )
         .arg(
             Arg::new("yolo")
                 .long("yolo")
@@ -118,8 +135,7 @@ pub fn open_command() -> Command {
             Arg::new("agent")
                 .long("agent")
                 .short('a')
-                .help("Agent to launch (default: kild's original agent)")
-                .value_parser(["amp", "claude", "kiro", "gemini", "codex", "opencode"]),
+                .help("Agent to launch: a built-in or an [agents.<name>] config agent (default: kild's original agent)"),
         )
         .arg(
             Arg::new("no-agent")
@@ -181,6 +197,37 @@ pub fn open_command() -> Command {
         )
 }
 
+pub fn restart_command() -> Command {
+    Command::new("restart")
+        .about("Restart the agent in a kild, reusing its existing worktree (stop + open)")
+        .arg(
+            Arg::new("branch")
+                .help("Branch name or kild identifier")
+                .index(1)
+                .required(true),
+        )
+        .arg(
+            Arg::new("daemon")
+                .long("daemon")
+                .help("Launch agent in daemon-owned PTY (overrides config)")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("no-daemon"),
+        )
+        .arg(
+            Arg::new("no-daemon")
+                .long("no-daemon")
+                .help("Launch agent in external terminal window (overrides config)")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("daemon"),
+        )
+        .arg(
+            Arg::new("no-attach")
+                .long("no-attach")
+                .help("Skip opening a terminal viewing window")
+                .action(ArgAction::SetTrue),
+        )
+}
+
 pub fn stop_command() -> Command {
     Command::new("stop")
         .about("Stop agent(s) in a kild without destroying the worktree")
@@ -215,6 +262,77 @@ pub fn stop_command() -> Command {
         )
 }
 
+pub fn rename_command() -> Command {
+    Command::new("rename")
+        .about("Rename a stopped kild: git branch, worktree, and session")
+        .arg(
+            Arg::new("old")
+                .help("Current branch name of the kild")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("new")
+                .help("New branch name")
+                .required(true)
+                .index(2),
+        )
+}
+
+pub fn copy_out_command() -> Command {
+    Command::new("copy-out")
+        .about("Copy files from a kild's worktree into the main repository")
+        .arg(
+            Arg::new("branch")
+                .help("Branch name of the kild")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("paths")
+                .help("Files to copy, relative to the worktree root")
+                .required(true)
+                .num_args(1..)
+                .value_parser(clap::value_parser!(std::path::PathBuf))
+                .index(2),
+        )
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .short('f')
+                .help("Overwrite files with uncommitted changes in the main repository")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+pub fn archive_command() -> Command {
+    Command::new("archive")
+        .about("Archive a stopped kild: hide it from 'kild list' but keep its branch and state")
+        .arg(
+            Arg::new("branch")
+                .help("Branch name of the kild to archive")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("remove-worktree")
+                .long("remove-worktree")
+                .help("Also delete the worktree directory (the branch is kept; refuses if there are uncommitted changes)")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+pub fn unarchive_command() -> Command {
+    Command::new("unarchive")
+        .about("Restore an archived kild, recreating its worktree if it was removed")
+        .arg(
+            Arg::new("branch")
+                .help("Branch name of the archived kild")
+                .required(true)
+                .index(1),
+        )
+}
+
 pub fn teammates_command() -> Command {
     Command::new("teammates")
         .about("List agent teammate panes within a daemon kild session")
@@ -257,6 +375,41 @@ pub fn destroy_command() -> Command {
         )
 }
 
+pub fn export_command() -> Command {
+    Command::new("export")
+        .about("Export a kild's session metadata as a portable JSON bundle (worktree contents not included)")
+        .arg(
+            Arg::new("branch")
+                .help("Branch name or session id of the kild to export")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .help("Write the bundle to this file instead of stdout")
+                .value_name("FILE"),
+        )
+}
+
+pub fn import_command() -> Command {
+    Command::new("import")
+        .about("Recreate a kild's session from a bundle made by `kild export`")
+        .arg(
+            Arg::new("file")
+                .help("Bundle file to import, or - for stdin")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("overwrite")
+                .long("overwrite")
+                .help("Replace an existing session with the same id")
+                .action(ArgAction::SetTrue),
+        )
+}
+
 pub fn complete_command() -> Command {
     Command::new("complete")
         .about("Complete a kild: merge PR, clean up remote branch, destroy session")
@@ -268,6 +421,7 @@ pub fn complete_command() -> Command {
             4. Delete remote branch\n\
             5. Destroy worktree and session\n\n\
             Use --no-merge for legacy behavior (cleanup only, requires PR already merged).\n\
+            Use --draft to open a draft PR for early CI instead of merging.\n\
             Use --dry-run to preview what would happen without making changes.",
         )
         .arg(
@@ -308,4 +462,11 @@ pub fn complete_command() -> Command {
                 .help("Skip CI status check before merging")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("draft")
+                .long("draft")
+                .help("Open a draft PR if none exists, then clean up without merging")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("no-merge"),
+        )
 }
@@ -22,6 +22,17 @@ fn test_cli_create_command() {
     assert_eq!(create_matches.get_one::<String>("agent").unwrap(), "kiro");
 }
 
+#[test]
+fn test_cli_daemon_stats_command() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "daemon", "stats", "--json"])
+        .unwrap();
+    let daemon_matches = matches.subcommand_matches("daemon").unwrap();
+    let stats_matches = daemon_matches.subcommand_matches("stats").unwrap();
+    assert!(stats_matches.get_flag("json"));
+}
+
 #[test]
 fn test_cli_list_command() {
     let app = build_cli();
@@ -43,6 +54,21 @@ fn test_cli_list_json_flag() {
     assert!(list_matches.get_flag("json"));
 }
 
+#[test]
+fn test_cli_list_filter_arg() {
+    let app = build_cli();
+    let matches =
+        app.try_get_matches_from(vec!["kild", "list", "--filter", "agent=claude branch~auth"]);
+    assert!(matches.is_ok());
+
+    let matches = matches.unwrap();
+    let list_matches = matches.subcommand_matches("list").unwrap();
+    assert_eq!(
+        list_matches.get_one::<String>("filter").map(String::as_str),
+        Some("agent=claude branch~auth")
+    );
+}
+
 #[test]
 fn test_cli_status_json_flag() {
     let app = build_cli();
@@ -85,11 +111,19 @@ fn test_cli_default_agent() {
 }
 
 #[test]
-fn test_cli_invalid_agent() {
+fn test_cli_config_defined_agent_parses() {
+    // Agents can come from [agents.<name>] config, so clap accepts any name;
+    // the create/open handlers validate it against the loaded config.
     let app = build_cli();
     let matches =
-        app.try_get_matches_from(vec!["kild", "create", "test-branch", "--agent", "invalid"]);
-    assert!(matches.is_err());
+        app.try_get_matches_from(vec!["kild", "create", "test-branch", "--agent", "myagent"]);
+    assert!(matches.is_ok());
+    let matches = matches.unwrap();
+    let create_matches = matches.subcommand_matches("create").unwrap();
+    assert_eq!(
+        create_matches.get_one::<String>("agent").unwrap(),
+        "myagent"
+    );
 }
 
 #[test]
@@ -138,6 +172,21 @@ fn test_cli_health_default_interval() {
     assert_eq!(*health_matches.get_one::<u64>("interval").unwrap(), 5);
 }
 
+#[test]
+fn test_cli_doctor_command() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "doctor"]);
+    assert!(matches.is_ok());
+    assert!(matches.unwrap().subcommand_matches("doctor").is_some());
+}
+
+#[test]
+fn test_cli_doctor_rejects_arguments() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "doctor", "extra"]);
+    assert!(matches.is_err());
+}
+
 #[test]
 fn test_cli_create_with_note() {
     let app = build_cli();
@@ -347,6 +396,20 @@ fn test_cli_verbose_flag_with_destroy_force() {
     );
 }
 
+#[test]
+fn test_cli_cd_print_path() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "cd", "test-branch", "--print-path"])
+        .unwrap();
+    let cd_matches = matches.subcommand_matches("cd").unwrap();
+    assert!(cd_matches.get_flag("print-path"));
+    assert_eq!(
+        cd_matches.get_one::<String>("branch").unwrap(),
+        "test-branch"
+    );
+}
+
 #[test]
 fn test_cli_cd_command() {
     let app = build_cli();
@@ -397,6 +460,23 @@ fn test_cli_code_command_with_editor() {
     assert_eq!(code_matches.get_one::<String>("editor").unwrap(), "vim");
 }
 
+#[test]
+fn test_cli_code_command_new_window() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "code", "test-branch", "--new-window"])
+        .unwrap();
+    let code_matches = matches.subcommand_matches("code").unwrap();
+    assert!(code_matches.get_flag("new-window"));
+
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "code", "test-branch"])
+        .unwrap();
+    let code_matches = matches.subcommand_matches("code").unwrap();
+    assert!(!code_matches.get_flag("new-window"));
+}
+
 #[test]
 fn test_cli_focus_command() {
     let app = build_cli();
@@ -512,6 +592,43 @@ fn test_cli_diff_with_stat_flag() {
     assert!(!diff_matches.get_flag("staged"));
 }
 
+#[test]
+fn test_cli_diff_stat_only_json() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "diff", "test-branch", "--stat-only", "--json"])
+        .unwrap();
+    let diff_matches = matches.subcommand_matches("diff").unwrap();
+    assert!(diff_matches.get_flag("stat"));
+    assert!(diff_matches.get_flag("json"));
+}
+
+#[test]
+fn test_cli_diff_json_requires_stat() {
+    let app = build_cli();
+    let result = app.try_get_matches_from(vec!["kild", "diff", "test-branch", "--json"]);
+    assert_eq!(
+        result.unwrap_err().kind(),
+        clap::error::ErrorKind::MissingRequiredArgument
+    );
+}
+
+#[test]
+fn test_cli_diff_stat_only_conflicts_with_staged_patch() {
+    let app = build_cli();
+    let result = app.try_get_matches_from(vec![
+        "kild",
+        "diff",
+        "test-branch",
+        "--stat-only",
+        "--staged",
+    ]);
+    assert_eq!(
+        result.unwrap_err().kind(),
+        clap::error::ErrorKind::ArgumentConflict
+    );
+}
+
 #[test]
 fn test_cli_commits_command() {
     let app = build_cli();
@@ -646,6 +763,28 @@ fn test_cli_open_with_branch_no_all() {
     );
 }
 
+#[test]
+fn test_cli_restart_with_branch() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "restart", "my-branch", "--no-attach"]);
+    assert!(matches.is_ok());
+
+    let matches = matches.unwrap();
+    let restart_matches = matches.subcommand_matches("restart").unwrap();
+    assert_eq!(
+        restart_matches.get_one::<String>("branch").unwrap(),
+        "my-branch"
+    );
+    assert!(restart_matches.get_flag("no-attach"));
+}
+
+#[test]
+fn test_cli_restart_requires_branch() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "restart"]);
+    assert!(matches.is_err());
+}
+
 #[test]
 fn test_cli_open_no_agent_flag() {
     let app = build_cli();
@@ -845,6 +984,29 @@ fn test_cli_complete_accepts_no_merge_and_dry_run() {
     assert!(complete_matches.get_flag("dry-run"));
 }
 
+#[test]
+fn test_cli_complete_accepts_draft() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "complete", "test-branch", "--draft"])
+        .unwrap();
+    let complete_matches = matches.subcommand_matches("complete").unwrap();
+    assert!(complete_matches.get_flag("draft"));
+}
+
+#[test]
+fn test_cli_complete_draft_conflicts_with_no_merge() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec![
+        "kild",
+        "complete",
+        "test-branch",
+        "--draft",
+        "--no-merge",
+    ]);
+    assert!(matches.is_err());
+}
+
 #[test]
 fn test_cli_complete_rejects_invalid_merge_strategy() {
     let app = build_cli();
@@ -921,6 +1083,16 @@ fn test_cli_create_no_fetch_default_false() {
     assert!(create_matches.get_one::<String>("base").is_none());
 }
 
+#[test]
+fn test_cli_create_with_init_submodules() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "create", "feature-auth", "--init-submodules"])
+        .unwrap();
+    let create_matches = matches.subcommand_matches("create").unwrap();
+    assert!(create_matches.get_flag("init-submodules"));
+}
+
 // --- pr command tests ---
 
 #[test]
@@ -961,6 +1133,24 @@ fn test_cli_pr_with_refresh_flag() {
     assert!(pr_matches.get_flag("refresh"));
 }
 
+#[test]
+fn test_cli_pr_create_draft() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "pr", "test-branch", "--create", "--draft"])
+        .unwrap();
+    let pr_matches = matches.subcommand_matches("pr").unwrap();
+    assert!(pr_matches.get_flag("create"));
+    assert!(pr_matches.get_flag("draft"));
+}
+
+#[test]
+fn test_cli_pr_draft_requires_create() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "pr", "test-branch", "--draft"]);
+    assert!(matches.is_err());
+}
+
 #[test]
 fn test_cli_pr_requires_branch() {
     let app = build_cli();
@@ -1831,6 +2021,17 @@ fn test_cli_attach_without_pane() {
         .unwrap();
     let sub = matches.subcommand_matches("attach").unwrap();
     assert!(sub.get_one::<String>("pane").is_none());
+    assert!(!sub.get_flag("read-only"));
+}
+
+#[test]
+fn test_cli_attach_read_only() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "attach", "my-branch", "--read-only"])
+        .unwrap();
+    let sub = matches.subcommand_matches("attach").unwrap();
+    assert!(sub.get_flag("read-only"));
 }
 
 // --- inject command tests ---
@@ -2076,3 +2277,296 @@ fn test_cli_inbox_status_conflicts_with_json() {
         app.try_get_matches_from(vec!["kild", "inbox", "test-branch", "--status", "--json"]);
     assert!(matches.is_err());
 }
+
+// --- config command tests ---
+
+#[test]
+fn test_cli_config_show_command() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "config", "show"])
+        .unwrap();
+    let config_matches = matches.subcommand_matches("config").unwrap();
+    let show_matches = config_matches.subcommand_matches("show").unwrap();
+    assert!(show_matches.get_one::<String>("key").is_none());
+    assert!(!show_matches.get_flag("json"));
+}
+
+#[test]
+fn test_cli_config_show_with_key_and_json() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec![
+            "kild",
+            "config",
+            "show",
+            "--key",
+            "agent.default",
+            "--json",
+        ])
+        .unwrap();
+    let config_matches = matches.subcommand_matches("config").unwrap();
+    let show_matches = config_matches.subcommand_matches("show").unwrap();
+    assert_eq!(
+        show_matches.get_one::<String>("key").unwrap(),
+        "agent.default"
+    );
+    assert!(show_matches.get_flag("json"));
+}
+
+#[test]
+fn test_cli_config_requires_subcommand() {
+    let app = build_cli();
+    let result = app.try_get_matches_from(vec!["kild", "config"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cli_create_with_from() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec![
+            "kild",
+            "create",
+            "feature-b",
+            "--from",
+            "feature-a",
+            "--base",
+            "develop",
+        ])
+        .unwrap();
+    let create_matches = matches.subcommand_matches("create").unwrap();
+    assert_eq!(
+        create_matches.get_one::<String>("branch").unwrap(),
+        "feature-b"
+    );
+    assert_eq!(
+        create_matches.get_one::<String>("from").unwrap(),
+        "feature-a"
+    );
+    assert_eq!(create_matches.get_one::<String>("base").unwrap(), "develop");
+}
+
+#[test]
+fn test_cli_create_without_from() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "create", "feature-b"])
+        .unwrap();
+    let create_matches = matches.subcommand_matches("create").unwrap();
+    assert!(create_matches.get_one::<String>("from").is_none());
+}
+
+#[test]
+fn test_cli_create_dry_run_includes() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "create", "feature-b", "--dry-run-includes"])
+        .unwrap();
+    let create_matches = matches.subcommand_matches("create").unwrap();
+    assert!(create_matches.get_flag("dry-run-includes"));
+}
+
+#[test]
+fn test_cli_export_with_output() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "export", "feature-auth", "-o", "auth.json"])
+        .unwrap();
+    let export_matches = matches.subcommand_matches("export").unwrap();
+    assert_eq!(
+        export_matches.get_one::<String>("branch").unwrap(),
+        "feature-auth"
+    );
+    assert_eq!(
+        export_matches.get_one::<String>("output").unwrap(),
+        "auth.json"
+    );
+}
+
+#[test]
+fn test_cli_export_requires_branch() {
+    let app = build_cli();
+    assert!(app.try_get_matches_from(vec!["kild", "export"]).is_err());
+}
+
+#[test]
+fn test_cli_import_overwrite() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "import", "auth.json", "--overwrite"])
+        .unwrap();
+    let import_matches = matches.subcommand_matches("import").unwrap();
+    assert_eq!(
+        import_matches.get_one::<String>("file").unwrap(),
+        "auth.json"
+    );
+    assert!(import_matches.get_flag("overwrite"));
+
+    let matches = build_cli()
+        .try_get_matches_from(vec!["kild", "import", "-"])
+        .unwrap();
+    assert!(
+        !matches
+            .subcommand_matches("import")
+            .unwrap()
+            .get_flag("overwrite")
+    );
+}
+
+#[test]
+fn test_cli_events_follow_json() {
+    let matches = build_cli()
+        .try_get_matches_from(vec!["kild", "events", "--follow", "--json"])
+        .unwrap();
+    let sub = matches.subcommand_matches("events").unwrap();
+    assert!(sub.get_flag("follow"));
+    assert!(sub.get_flag("json"));
+
+    let matches = build_cli()
+        .try_get_matches_from(vec!["kild", "events"])
+        .unwrap();
+    let sub = matches.subcommand_matches("events").unwrap();
+    assert!(!sub.get_flag("follow"));
+    assert!(!sub.get_flag("json"));
+}
+
+#[test]
+fn test_cli_logs_follow() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "logs", "test-branch", "-f"])
+        .unwrap();
+    let logs_matches = matches.subcommand_matches("logs").unwrap();
+    assert!(logs_matches.get_flag("follow"));
+    assert_eq!(
+        logs_matches.get_one::<String>("branch").unwrap(),
+        "test-branch"
+    );
+
+    let matches = build_cli()
+        .try_get_matches_from(vec!["kild", "logs", "test-branch"])
+        .unwrap();
+    assert!(
+        !matches
+            .subcommand_matches("logs")
+            .unwrap()
+            .get_flag("follow")
+    );
+}
+
+#[test]
+fn test_cli_rename_command() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "rename", "old-name", "new-name"])
+        .unwrap();
+    let rename_matches = matches.subcommand_matches("rename").unwrap();
+    assert_eq!(rename_matches.get_one::<String>("old").unwrap(), "old-name");
+    assert_eq!(rename_matches.get_one::<String>("new").unwrap(), "new-name");
+}
+
+#[test]
+fn test_cli_copy_out_command() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec![
+            "kild",
+            "copy-out",
+            "my-branch",
+            ".env.example",
+            "src/lib.rs",
+            "--force",
+        ])
+        .unwrap();
+    let sub = matches.subcommand_matches("copy-out").unwrap();
+    assert_eq!(sub.get_one::<String>("branch").unwrap(), "my-branch");
+    let paths: Vec<&std::path::PathBuf> = sub.get_many("paths").unwrap().collect();
+    assert_eq!(
+        paths,
+        vec![
+            &std::path::PathBuf::from(".env.example"),
+            &std::path::PathBuf::from("src/lib.rs")
+        ]
+    );
+    assert!(sub.get_flag("force"));
+}
+
+#[test]
+fn test_cli_copy_out_requires_paths() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "copy-out", "my-branch"]);
+    assert!(matches.is_err());
+}
+
+#[test]
+fn test_cli_archive_command() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "archive", "my-branch", "--remove-worktree"])
+        .unwrap();
+    let sub = matches.subcommand_matches("archive").unwrap();
+    assert_eq!(sub.get_one::<String>("branch").unwrap(), "my-branch");
+    assert!(sub.get_flag("remove-worktree"));
+
+    let matches = build_cli()
+        .try_get_matches_from(vec!["kild", "archive", "my-branch"])
+        .unwrap();
+    let sub = matches.subcommand_matches("archive").unwrap();
+    assert!(!sub.get_flag("remove-worktree"));
+}
+
+#[test]
+fn test_cli_unarchive_command() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "unarchive", "my-branch"])
+        .unwrap();
+    let sub = matches.subcommand_matches("unarchive").unwrap();
+    assert_eq!(sub.get_one::<String>("branch").unwrap(), "my-branch");
+}
+
+#[test]
+fn test_cli_list_archived_flag() {
+    let matches = build_cli()
+        .try_get_matches_from(vec!["kild", "list", "--archived"])
+        .unwrap();
+    let sub = matches.subcommand_matches("list").unwrap();
+    assert!(sub.get_flag("archived"));
+
+    let matches = build_cli()
+        .try_get_matches_from(vec!["kild", "list"])
+        .unwrap();
+    let sub = matches.subcommand_matches("list").unwrap();
+    assert!(!sub.get_flag("archived"));
+}
+
+#[test]
+fn test_cli_rename_requires_new_name() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "rename", "old-name"]);
+    assert!(matches.is_err());
+}
+
+#[test]
+fn test_cli_remote_alias_global_flag() {
+    let matches = build_cli()
+        .try_get_matches_from(vec!["kild", "list", "--remote-alias", "work"])
+        .unwrap();
+    assert_eq!(
+        matches
+            .get_one::<String>("remote-alias")
+            .map(String::as_str),
+        Some("work")
+    );
+
+    let result = build_cli().try_get_matches_from(vec![
+        "kild",
+        "list",
+        "--remote-alias",
+        "work",
+        "--remote",
+        "10.0.0.5:7070",
+    ]);
+    assert!(result.is_err(), "--remote-alias conflicts with --remote");
+}
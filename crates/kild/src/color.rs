@@ -125,7 +125,7 @@ pub fn muted(text: &str) -> String {
 pub fn status(status_str: &str) -> String {
     match status_str {
         "active" => aurora(status_str),
-        "stopped" => muted(status_str),
+        "stopped" | "archived" => muted(status_str),
         "destroyed" => ember(status_str),
         _ => status_str.to_string(),
     }
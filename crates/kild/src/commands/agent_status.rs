@@ -49,7 +49,8 @@ pub(crate) fn handle_agent_status_command(
 
     info!(event = "cli.agent_status_started", branch = %branch, status = %status);
 
-    match session_ops::update_agent_status(&branch, status, notify) {
+    let notify_config = notify.then(|| super::helpers::load_config_with_warning().notify);
+    match session_ops::update_agent_status(&branch, status, notify_config.as_ref()) {
         Ok(result) => {
             if json_output {
                 let response = super::json_types::AgentStatusResponse {
@@ -0,0 +1,82 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::session_ops;
+
+use super::helpers;
+use crate::color;
+
+pub(crate) fn handle_archive_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let remove_worktree = matches.get_flag("remove-worktree");
+
+    info!(
+        event = "cli.archive_started",
+        branch = branch,
+        remove_worktree = remove_worktree
+    );
+
+    let session = helpers::require_session(branch, "cli.archive_failed")?;
+
+    match session_ops::archive_session(&session.id, remove_worktree) {
+        Ok(archived) => {
+            println!("{}", color::aurora("Kild archived."));
+            println!("  {} {}", color::muted("Branch:"), color::ice(branch));
+            if archived.worktree_path.exists() {
+                println!(
+                    "  {} {}",
+                    color::muted("Worktree:"),
+                    helpers::shorten_home_path(&archived.worktree_path)
+                );
+            } else {
+                println!("  {} removed (branch kept)", color::muted("Worktree:"));
+            }
+            println!("  {} kild unarchive {}", color::muted("Restore:"), branch);
+            info!(event = "cli.archive_completed", branch = branch);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{} {}", color::error("Could not archive:"), e);
+            error!(event = "cli.archive_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
+
+pub(crate) fn handle_unarchive_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+
+    info!(event = "cli.unarchive_started", branch = branch);
+
+    let session = helpers::require_session(branch, "cli.unarchive_failed")?;
+
+    match session_ops::unarchive_session(&session.id) {
+        Ok(restored) => {
+            println!("{}", color::aurora("Kild restored."));
+            println!("  {} {}", color::muted("Branch:"), color::ice(branch));
+            println!(
+                "  {} {}",
+                color::muted("Worktree:"),
+                helpers::shorten_home_path(&restored.worktree_path)
+            );
+            info!(event = "cli.unarchive_completed", branch = branch);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{} {}", color::error("Could not unarchive:"), e);
+            error!(event = "cli.unarchive_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
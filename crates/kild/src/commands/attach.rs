@@ -2,9 +2,10 @@ use std::io::{Read, Write};
 use std::os::unix::net::UnixStream;
 
 use clap::ArgMatches;
+use kild_protocol::frame::{self, DEFAULT_MAX_MESSAGE_BYTES};
 use nix::sys::signal::{SigSet, Signal};
 use nix::sys::termios;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use super::helpers;
 
@@ -14,8 +15,13 @@ pub(crate) fn handle_attach_command(
     let branch = matches
         .get_one::<String>("branch")
         .ok_or("Branch argument is required")?;
+    let read_only = matches.get_flag("read-only");
 
-    info!(event = "cli.attach_started", branch = branch);
+    info!(
+        event = "cli.attach_started",
+        branch = branch,
+        read_only = read_only
+    );
 
     // 1. Look up session to get daemon_session_id
     let mut session = helpers::require_session(branch, "cli.attach_failed")?;
@@ -55,7 +61,9 @@ pub(crate) fn handle_attach_command(
     //    spawn a new attach window instead of connecting from the current terminal.
     //    Skip when --pane is specified — pane attach always uses direct connection
     //    since spawn_attach_window connects to the leader, not the teammate pane.
+    //    Skip for --read-only too: the spawned window would attach writable.
     let is_headless = matches.get_one::<String>("pane").is_none()
+        && !read_only
         && session.runtime_mode == Some(kild_core::RuntimeMode::Daemon)
         && session
             .latest_agent()
@@ -108,7 +116,7 @@ pub(crate) fn handle_attach_command(
     );
 
     // 3. Connect to daemon and attach from the current terminal
-    if let Err(e) = attach_to_daemon_session(&daemon_session_id, branch) {
+    if let Err(e) = attach_to_daemon_session(&daemon_session_id, branch, read_only) {
         eprintln!("{}", e);
         error!(event = "cli.attach_failed", branch = branch, error = %e);
         return Err(e);
@@ -176,6 +184,7 @@ fn pane_daemon_session_id(
 fn attach_to_daemon_session(
     daemon_session_id: &str,
     branch: &str,
+    read_only: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let socket_path = kild_core::daemon::socket_path();
     let mut stream = UnixStream::connect(&socket_path).map_err(|e| {
@@ -190,22 +199,16 @@ fn attach_to_daemon_session(
     let (cols, rows) = terminal_size();
 
     // Send attach request
-    let attach_msg = serde_json::json!({
-        "id": "attach-1",
-        "type": "attach",
-        "session_id": daemon_session_id,
-        "cols": cols,
-        "rows": rows,
-    });
-    writeln!(stream, "{}", serde_json::to_string(&attach_msg)?)?;
-    stream.flush()?;
+    write_frame(
+        &mut stream,
+        &attach_request(daemon_session_id, rows, cols, read_only),
+    )?;
 
     // Read ack response
     let mut reader = std::io::BufReader::new(stream.try_clone()?);
-    let mut line = String::new();
-    std::io::BufRead::read_line(&mut reader, &mut line)?;
-
-    let ack: serde_json::Value = serde_json::from_str(line.trim())?;
+    let ack_bytes = frame::read_frame(&mut reader, DEFAULT_MAX_MESSAGE_BYTES)?
+        .ok_or("Daemon closed the connection before acknowledging attach")?;
+    let ack: serde_json::Value = serde_json::from_slice(&ack_bytes)?;
     if ack.get("type").and_then(|t| t.as_str()) == Some("error") {
         let msg = match ack.get("message").and_then(|m| m.as_str()) {
             Some(m) => m.to_string(),
@@ -231,17 +234,26 @@ fn attach_to_daemon_session(
     let session_id_owned = daemon_session_id.to_string();
     let mut write_stream = stream.try_clone()?;
     let stdin_handle = std::thread::spawn(move || {
-        forward_stdin_to_daemon(&mut write_stream, &session_id_owned);
+        forward_stdin_to_daemon(&mut write_stream, &session_id_owned, read_only);
     });
 
     // Spawn SIGWINCH handler thread to relay terminal resizes to the daemon.
     // Thread exits when its socket write fails (daemon disconnected). We don't join()
     // because it blocks on sigwait() — on normal exit the OS cleans up the thread.
-    let sigwinch_session_id = daemon_session_id.to_string();
-    let mut sigwinch_stream = stream.try_clone()?;
-    let sigwinch_handle = std::thread::spawn(move || {
-        handle_sigwinch(&sigwinch_set, &mut sigwinch_stream, &sigwinch_session_id);
-    });
+    // Read-only attaches never resize: the writable client owns the PTY size.
+    let sigwinch_handle = if read_only {
+        None
+    } else {
+        let sigwinch_session_id = daemon_session_id.to_string();
+        let mut sigwinch_stream = stream.try_clone()?;
+        Some(std::thread::spawn(move || {
+            handle_sigwinch(&sigwinch_set, &mut sigwinch_stream, &sigwinch_session_id);
+        }))
+    };
+
+    if read_only {
+        eprintln!("[kild] Read-only: keystrokes are not sent. Ctrl+C to detach.\r");
+    }
 
     // Main thread: read daemon output, write to stdout
     // Re-use the BufReader directly so we don't lose buffered data
@@ -259,6 +271,57 @@ fn attach_to_daemon_session(
     result
 }
 
+/// Build the attach request. `read_only` asks for an output-only
+/// subscription; it is omitted otherwise so older daemons see the same message.
+fn attach_request(
+    daemon_session_id: &str,
+    rows: u16,
+    cols: u16,
+    read_only: bool,
+) -> serde_json::Value {
+    let mut msg = serde_json::json!({
+        "id": "attach-1",
+        "type": "attach",
+        "session_id": daemon_session_id,
+        "cols": cols,
+        "rows": rows,
+    });
+    if read_only {
+        msg["read_only"] = serde_json::Value::Bool(true);
+    }
+    msg
+}
+
+/// Build the write_stdin message for `data`, or `None` when read-only
+/// attaches drop the input instead of sending it.
+fn stdin_message(session_id: &str, data: &[u8], read_only: bool) -> Option<serde_json::Value> {
+    use base64::Engine;
+
+    if read_only {
+        return None;
+    }
+    let encoded = base64::engine::general_purpose::STANDARD.encode(data);
+    Some(serde_json::json!({
+        "id": format!("write-{}", data.len()),
+        "type": "write_stdin",
+        "session_id": session_id,
+        "data": encoded,
+    }))
+}
+
+/// Serialize `msg` and write it to the daemon as one length-prefixed frame.
+fn write_frame(
+    stream: &mut UnixStream,
+    msg: &serde_json::Value,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let payload = serde_json::to_vec(msg)?;
+    // attach never handshakes, so the daemon won't expect compressed frames
+    let bytes = frame::encode_frame(&payload, DEFAULT_MAX_MESSAGE_BYTES, false)?;
+    stream.write_all(&bytes)?;
+    stream.flush()?;
+    Ok(())
+}
+
 fn terminal_size() -> (u16, u16) {
     use nix::libc;
     unsafe {
@@ -303,9 +366,8 @@ impl Drop for RawModeGuard {
 /// Forwards stdin bytes to the daemon over IPC, base64-encoded.
 /// Ctrl+C (0x03) detaches from the session without killing it.
 /// The shell stays alive in the daemon — reattach with `kild attach`.
-fn forward_stdin_to_daemon(stream: &mut UnixStream, session_id: &str) {
-    use base64::Engine;
-
+/// In read-only mode stdin is still drained, but nothing is sent.
+fn forward_stdin_to_daemon(stream: &mut UnixStream, session_id: &str, read_only: bool) {
     let stdin = std::io::stdin();
     let mut buf = [0u8; 4096];
 
@@ -320,31 +382,15 @@ fn forward_stdin_to_daemon(stream: &mut UnixStream, session_id: &str) {
             }
         };
 
-        let encoded = base64::engine::general_purpose::STANDARD.encode(&buf[..n]);
-        let input_msg = serde_json::json!({
-            "id": format!("write-{}", n),
-            "type": "write_stdin",
-            "session_id": session_id,
-            "data": encoded,
-        });
-        let serialized = match serde_json::to_string(&input_msg) {
-            Ok(s) => s,
-            Err(e) => {
-                error!(event = "cli.attach.stdin_serialize_failed", error = %e, session_id = %session_id);
-                eprintln!("\r\nInput encoding failed. Detaching.");
-                break;
-            }
+        let Some(input_msg) = stdin_message(session_id, &buf[..n], read_only) else {
+            debug!(event = "cli.attach.stdin_dropped", bytes = n);
+            continue;
         };
-        if let Err(e) = writeln!(stream, "{}", serialized) {
+        if let Err(e) = write_frame(stream, &input_msg) {
             error!(event = "cli.attach.stdin_write_failed", error = %e, session_id = %session_id);
             eprintln!("\r\nConnection to daemon lost. Detaching.");
             break;
         }
-        if let Err(e) = stream.flush() {
-            error!(event = "cli.attach.stdin_flush_failed", error = %e);
-            eprintln!("\r\nConnection to daemon lost. Detaching.");
-            break;
-        }
     }
 }
 
@@ -362,18 +408,7 @@ fn handle_sigwinch(sigset: &SigSet, stream: &mut UnixStream, session_id: &str) {
                     "cols": cols,
                     "rows": rows,
                 });
-                let serialized = match serde_json::to_string(&resize_msg) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        error!(event = "cli.attach.resize_serialize_failed", error = %e);
-                        continue;
-                    }
-                };
-                if let Err(e) = writeln!(stream, "{}", serialized) {
-                    warn!(event = "cli.attach.resize_send_failed", error = %e);
-                    break;
-                }
-                if let Err(e) = stream.flush() {
+                if let Err(e) = write_frame(stream, &resize_msg) {
                     warn!(event = "cli.attach.resize_send_failed", error = %e);
                     break;
                 }
@@ -392,22 +427,13 @@ fn forward_daemon_to_stdout_buffered(
 ) -> Result<(), Box<dyn std::error::Error>> {
     use base64::Engine;
 
-    let mut line = String::new();
     let mut stdout = std::io::stdout();
+    // Keystrokes rejected because another attach holds the write lock
+    let mut input_blocked_shown = false;
 
-    loop {
-        line.clear();
-        let n = std::io::BufRead::read_line(&mut reader, &mut line)?;
-        if n == 0 {
-            break; // EOF
-        }
-
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
-
-        let msg: serde_json::Value = match serde_json::from_str(trimmed) {
+    // Loop ends on EOF
+    while let Some(payload) = frame::read_frame(&mut reader, DEFAULT_MAX_MESSAGE_BYTES)? {
+        let msg: serde_json::Value = match serde_json::from_slice(&payload) {
             Ok(v) => v,
             Err(e) => {
                 error!(event = "cli.attach.parse_failed", error = %e);
@@ -419,7 +445,7 @@ fn forward_daemon_to_stdout_buffered(
         };
 
         match msg.get("type").and_then(|t| t.as_str()) {
-            Some("pty_output") => {
+            Some("pty_output" | "scrollback") => {
                 if let Some(data) = msg.get("data").and_then(|d| d.as_str())
                     && let Ok(decoded) = base64::engine::general_purpose::STANDARD.decode(data)
                 {
@@ -473,6 +499,16 @@ fn forward_daemon_to_stdout_buffered(
                     }
                 }
             }
+            Some("error") => {
+                let code = msg.get("code").and_then(|c| c.as_str());
+                if code == Some("unauthorized") && !input_blocked_shown {
+                    input_blocked_shown = true;
+                    warn!(event = "cli.attach.input_rejected", response = %msg);
+                    eprintln!(
+                        "\r\n[kild] Another client is attached with the write lock. Keystrokes are ignored until it detaches.\r"
+                    );
+                }
+            }
             _ => {
                 // Ignore other messages (ack, etc.)
             }
@@ -481,3 +517,46 @@ fn forward_daemon_to_stdout_buffered(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_only_drops_stdin() {
+        assert!(stdin_message("sess-1", b"rm -rf /\r", true).is_none());
+    }
+
+    #[test]
+    fn test_writable_forwards_stdin_base64() {
+        let msg = stdin_message("sess-1", b"ls\r", false).unwrap();
+        assert_eq!(msg["type"], "write_stdin");
+        assert_eq!(msg["session_id"], "sess-1");
+        assert_eq!(msg["data"], "bHMN");
+    }
+
+    #[test]
+    fn test_attach_request_read_only_is_output_only() {
+        let msg = attach_request("sess-1", 24, 80, true);
+        assert_eq!(msg["type"], "attach");
+        assert_eq!(msg["read_only"], true);
+
+        // The daemon parses it as an output-only subscription
+        let parsed: kild_protocol::ClientMessage = serde_json::from_value(msg).unwrap();
+        assert!(matches!(
+            parsed,
+            kild_protocol::ClientMessage::Attach {
+                read_only: true,
+                rows: 24,
+                cols: 80,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_attach_request_writable_omits_read_only() {
+        let msg = attach_request("sess-1", 24, 80, false);
+        assert!(msg.get("read_only").is_none());
+    }
+}
@@ -1,3 +1,6 @@
+use std::io::Write;
+use std::path::Path;
+
 use clap::ArgMatches;
 use tracing::{error, info};
 
@@ -16,13 +19,16 @@ pub(crate) fn handle_cd_command(matches: &ArgMatches) -> Result<(), Box<dyn std:
         return Err("Invalid branch name".into());
     }
 
-    info!(event = "cli.cd_started", branch = branch);
+    let print_path = matches.get_flag("print-path");
+    info!(
+        event = "cli.cd_started",
+        branch = branch,
+        print_path = print_path
+    );
 
     let session = helpers::require_session(branch, "cli.cd_failed")?;
 
-    // Print only the path - no formatting, no leading text
-    // This enables shell integration: cd "$(kild cd branch)"
-    println!("{}", session.worktree_path.display());
+    write_path(&mut std::io::stdout().lock(), &session.worktree_path)?;
 
     info!(
         event = "cli.cd_completed",
@@ -32,3 +38,21 @@ pub(crate) fn handle_cd_command(matches: &ArgMatches) -> Result<(), Box<dyn std:
 
     Ok(())
 }
+
+/// Print only the path - no formatting, no leading text.
+/// This enables shell integration: cd "$(kild cd branch --print-path)"
+fn write_path(out: &mut impl Write, path: &Path) -> std::io::Result<()> {
+    writeln!(out, "{}", path.display())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_path_is_path_and_newline_only() {
+        let mut out = Vec::new();
+        write_path(&mut out, Path::new("/home/me/.kild/worktrees/proj/feat")).unwrap();
+        assert_eq!(out, b"/home/me/.kild/worktrees/proj/feat\n");
+    }
+}
@@ -53,6 +53,16 @@ pub(crate) fn handle_cleanup_command(
                     }
                 }
 
+                if !summary.orphaned_shim_dirs.is_empty() {
+                    println!(
+                        "  Shim registries removed: {}",
+                        summary.orphaned_shim_dirs.len()
+                    );
+                    for session in &summary.orphaned_shim_dirs {
+                        println!("    - {}", session);
+                    }
+                }
+
                 println!("  Total: {} resources cleaned", summary.total_cleaned);
             } else {
                 println!("  No orphaned resources found.");
@@ -1,7 +1,7 @@
 use clap::ArgMatches;
 use tracing::{error, info};
 
-use kild_core::editor::EditorError;
+use kild_core::editor::{EditorError, EditorOpenMode};
 
 use super::helpers::{self, load_config_with_warning, shorten_home_path};
 use crate::color;
@@ -11,11 +11,16 @@ pub(crate) fn handle_code_command(matches: &ArgMatches) -> Result<(), Box<dyn st
         .get_one::<String>("branch")
         .ok_or("Branch argument is required")?;
     let editor_override = matches.get_one::<String>("editor").cloned();
+    // Without the flag, `[editor] new_window` decides
+    let open_mode = matches
+        .get_flag("new-window")
+        .then_some(EditorOpenMode::NewWindow);
 
     info!(
         event = "cli.code_started",
         branch = branch,
-        editor_override = ?editor_override
+        editor_override = ?editor_override,
+        open_mode = ?open_mode
     );
 
     // 1. Load config
@@ -28,6 +33,7 @@ pub(crate) fn handle_code_command(matches: &ArgMatches) -> Result<(), Box<dyn st
     match kild_core::editor::open_editor(
         &session.worktree_path,
         editor_override.as_deref(),
+        open_mode,
         &config,
     ) {
         Ok(()) => {
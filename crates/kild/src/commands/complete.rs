@@ -36,6 +36,7 @@ pub(crate) fn handle_complete_command(
         force: matches.get_flag("force"),
         dry_run: matches.get_flag("dry-run"),
         skip_ci: matches.get_flag("skip-ci"),
+        draft: matches.get_flag("draft"),
     };
 
     info!(
@@ -46,6 +47,7 @@ pub(crate) fn handle_complete_command(
         force = request.force,
         dry_run = request.dry_run,
         skip_ci = request.skip_ci,
+        draft = request.draft,
     );
 
     match session_ops::complete_session(&request) {
@@ -75,6 +77,10 @@ pub(crate) fn handle_complete_command(
                     println!("Completed '{}'. Session destroyed.", branch);
                     println!("  PR not merged — remote branch preserved.");
                 }
+                CompleteResult::DraftOpened { ref url } => {
+                    println!("Opened draft PR for '{}': {}", branch, url);
+                    println!("  Session destroyed — remote branch preserved.");
+                }
                 CompleteResult::DryRun { ref steps } => {
                     println!("Dry run for '{}':", branch);
                     for (i, step) in steps.iter().enumerate() {
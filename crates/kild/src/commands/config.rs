@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_config::{ConfigSources, KildConfig, sources};
+
+use crate::color;
+
+pub(crate) fn handle_config_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match matches.subcommand() {
+        Some(("show", sub)) => handle_config_show(sub),
+        _ => Err("Unknown config subcommand".into()),
+    }
+}
+
+fn handle_config_show(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let key = matches.get_one::<String>("key").map(String::as_str);
+    let json_output = matches.get_flag("json");
+
+    info!(
+        event = "cli.config.show_started",
+        key = key,
+        json_output = json_output
+    );
+
+    let (config, config_sources) = match KildConfig::load_hierarchy_with_sources() {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            error!(event = "cli.config.show_failed", error = %e);
+            eprintln!("{}", color::error(&e.to_string()));
+            return Err(e);
+        }
+    };
+
+    for (source, note) in config_sources.migration_notes() {
+        eprintln!(
+            "{}",
+            color::warning(&format!(
+                "{}: {} (update the file to silence this)",
+                source, note
+            ))
+        );
+    }
+
+    let values = filter_by_key(sources::effective_values(&config)?, key);
+    if let Some(key) = key
+        && values.is_empty()
+    {
+        let msg = format!("No config value set for '{}'", key);
+        error!(event = "cli.config.show_failed", error = msg.as_str());
+        eprintln!("{}", color::error(&msg));
+        return Err(msg.into());
+    }
+
+    if json_output {
+        let entries: Vec<serde_json::Value> = values
+            .iter()
+            .map(|(key, value)| {
+                serde_json::json!({
+                    "key": key,
+                    "value": value,
+                    "source": config_sources.source_of(key).to_string(),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+    } else {
+        print_values(&values, &config_sources);
+    }
+
+    info!(event = "cli.config.show_completed", count = values.len());
+
+    Ok(())
+}
+
+/// Keep `key` itself and any keys nested under it. `None` keeps everything.
+fn filter_by_key<V>(values: BTreeMap<String, V>, key: Option<&str>) -> BTreeMap<String, V> {
+    let Some(key) = key else {
+        return values;
+    };
+    let prefix = format!("{key}.");
+    values
+        .into_iter()
+        .filter(|(k, _)| k == key || k.starts_with(&prefix))
+        .collect()
+}
+
+fn print_values<V: std::fmt::Display>(values: &BTreeMap<String, V>, sources: &ConfigSources) {
+    let width = values.keys().map(String::len).max().unwrap_or(0);
+    for (key, value) in values {
+        println!(
+            "{} = {}  {}",
+            color::ice(&format!("{key:<width$}")),
+            value,
+            color::muted(&format!("({})", sources.source_of(key)))
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> BTreeMap<String, u32> {
+        [
+            "agent.default",
+            "agent.flags",
+            "agents.claude.flags",
+            "git.remote",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, k)| (k.to_string(), i as u32))
+        .collect()
+    }
+
+    #[test]
+    fn test_filter_by_key_none_keeps_all() {
+        assert_eq!(filter_by_key(values(), None).len(), 4);
+    }
+
+    #[test]
+    fn test_filter_by_key_exact() {
+        let filtered = filter_by_key(values(), Some("agent.default"));
+        assert_eq!(filtered.keys().collect::<Vec<_>>(), ["agent.default"]);
+    }
+
+    #[test]
+    fn test_filter_by_key_section_prefix() {
+        // "agent" must not match "agents.*"
+        let filtered = filter_by_key(values(), Some("agent"));
+        assert_eq!(
+            filtered.keys().collect::<Vec<_>>(),
+            ["agent.default", "agent.flags"]
+        );
+    }
+
+    #[test]
+    fn test_filter_by_key_unknown_is_empty() {
+        assert!(filter_by_key(values(), Some("nope")).is_empty());
+    }
+}
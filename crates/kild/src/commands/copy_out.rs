@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::session_ops;
+
+use super::helpers;
+use crate::color;
+
+pub(crate) fn handle_copy_out_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let paths: Vec<PathBuf> = matches
+        .get_many::<PathBuf>("paths")
+        .ok_or("At least one path is required")?
+        .cloned()
+        .collect();
+    let force = matches.get_flag("force");
+
+    info!(
+        event = "cli.copy_out_started",
+        branch = branch,
+        files = paths.len(),
+        force = force
+    );
+
+    let session = helpers::require_session(branch, "cli.copy_out_failed")?;
+
+    let Some(repo_root) = kild_core::git::find_main_repo_root(&session.worktree_path) else {
+        let msg = format!(
+            "Cannot locate the main repository for worktree {}",
+            helpers::shorten_home_path(&session.worktree_path)
+        );
+        eprintln!("{} {}", color::error("Could not copy:"), msg);
+        error!(
+            event = "cli.copy_out_failed",
+            branch = branch,
+            error = msg.as_str()
+        );
+        return Err(msg.into());
+    };
+
+    match session_ops::copy_out(&session.id, &paths, &repo_root, force) {
+        Ok(copied) => {
+            for dest in &copied {
+                println!(
+                    "{} {}",
+                    color::aurora("Copied"),
+                    helpers::shorten_home_path(dest)
+                );
+            }
+            info!(
+                event = "cli.copy_out_completed",
+                branch = branch,
+                files = copied.len()
+            );
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{} {}", color::error("Could not copy:"), e);
+            error!(event = "cli.copy_out_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
@@ -1,14 +1,68 @@
 use clap::ArgMatches;
 use tracing::{error, info, warn};
 
+use kild_config::KildConfig;
 use kild_core::CreateSessionRequest;
+use kild_core::Session;
 use kild_core::events;
 use kild_core::session_ops;
 use kild_core::sessions::fleet;
 
-use super::helpers::{load_config_with_warning, resolve_runtime_mode, shorten_home_path};
+use super::helpers::{
+    load_config_with_warning, resolve_runtime_mode, shorten_home_path, validate_agent_arg,
+};
 use crate::color;
 
+/// List the files `kild create` would copy via include patterns.
+///
+/// Mirrors create: include patterns come from `--from` when the source kild
+/// recorded them, otherwise from the loaded config.
+fn print_include_preview(
+    branch: &str,
+    from_session: Option<&Session>,
+    config: &KildConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let project = match kild_core::git::detect_project() {
+        Ok(project) => project,
+        Err(e) => {
+            eprintln!("{}", color::error(&e.to_string()));
+            error!(event = "cli.create_failed", branch = branch, error = %e);
+            return Err(e.into());
+        }
+    };
+
+    let include = from_session
+        .and_then(|s| s.include_patterns.as_ref())
+        .or(config.include_patterns.as_ref());
+    let files = include
+        .map(|cfg| kild_config::preview_matches(&project.path, cfg))
+        .unwrap_or_default();
+
+    info!(
+        event = "cli.create.include_preview_completed",
+        branch = branch,
+        file_count = files.len()
+    );
+
+    if files.is_empty() {
+        println!("{}", color::muted("No files match the include patterns."));
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        color::aurora(&format!(
+            "{} file(s) would be copied into the new worktree:",
+            files.len()
+        ))
+    );
+    for file in &files {
+        let relative = file.strip_prefix(&project.path).unwrap_or(file);
+        println!("  {}", relative.display());
+    }
+    Ok(())
+}
+
 pub(crate) fn handle_create_command(
     matches: &ArgMatches,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -20,10 +74,34 @@ pub(crate) fn handle_create_command(
     let mut config = load_config_with_warning();
     let no_agent = matches.get_flag("no-agent");
 
+    // Resolve --from up front so --yolo sees the agent it will inherit
+    let from_session = match matches.get_one::<String>("from") {
+        Some(source) => match session_ops::get_session(source) {
+            Ok(session) => Some(session),
+            Err(e) => {
+                eprintln!("{}", color::error(&e.to_string()));
+                error!(
+                    event = "cli.create_failed",
+                    branch = branch,
+                    from = source.as_str(),
+                    error = %e
+                );
+                events::log_app_error(&e);
+                return Err(e.into());
+            }
+        },
+        None => None,
+    };
+
+    if matches.get_flag("dry-run-includes") {
+        return print_include_preview(branch, from_session.as_ref(), &config);
+    }
+
     // Determine agent mode from CLI flags
     let agent_mode = if no_agent {
         kild_core::AgentMode::BareShell
     } else if let Some(agent) = matches.get_one::<String>("agent").cloned() {
+        validate_agent_arg(&config, &agent)?;
         config.agent.default = agent.clone();
         kild_core::AgentMode::Agent(agent)
     } else {
@@ -46,16 +124,19 @@ pub(crate) fn handle_create_command(
             let agent_name = matches
                 .get_one::<String>("agent")
                 .map(|s| s.as_str())
+                .or(from_session.as_ref().map(|s| s.agent.as_str()))
                 .unwrap_or(&config.agent.default);
-            if let Some(yolo) = kild_core::agents::get_yolo_flags(agent_name) {
+            let yolo_flags = kild_core::agents::resolve_agent(&config, agent_name)
+                .and_then(|a| a.backend().yolo_flags().map(str::to_string));
+            if let Some(yolo) = yolo_flags {
                 info!(
                     event = "cli.create.yolo_flags_resolved",
                     agent = agent_name,
-                    flags = yolo
+                    flags = %yolo
                 );
                 config.agent.flags = Some(match config.agent.flags {
                     Some(existing) => format!("{} {}", yolo, existing),
-                    None => yolo.to_string(),
+                    None => yolo,
                 });
             } else {
                 warn!(
@@ -80,6 +161,7 @@ pub(crate) fn handle_create_command(
 
     let base_branch = matches.get_one::<String>("base").cloned();
     let no_fetch = matches.get_flag("no-fetch");
+    let init_submodules = matches.get_flag("init-submodules");
 
     let daemon_flag = matches.get_flag("daemon");
     let no_daemon_flag = matches.get_flag("no-daemon");
@@ -96,7 +178,9 @@ pub(crate) fn handle_create_command(
         .with_no_fetch(no_fetch)
         .with_runtime_mode(runtime_mode)
         .with_main_worktree(use_main)
-        .with_initial_prompt(initial_prompt);
+        .with_initial_prompt(initial_prompt)
+        .with_init_submodules(init_submodules)
+        .with_from(from_session.map(|s| s.id));
 
     match session_ops::create_session(request, &config) {
         Ok(session) => {
@@ -1,6 +1,14 @@
+use std::time::Duration;
+
 use clap::ArgMatches;
 use tracing::{debug, error, info, warn};
 
+/// How long the old daemon waits for attached streams before exiting.
+const HANDOFF_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long to wait for the old daemon's PID file to disappear.
+const STOP_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub(crate) fn handle_daemon_command(
     matches: &ArgMatches,
 ) -> Result<(), Box<dyn std::error::Error>> {
@@ -9,6 +17,7 @@ pub(crate) fn handle_daemon_command(
         Some(("stop", _)) => handle_daemon_stop(),
         Some(("restart", _)) => handle_daemon_restart(),
         Some(("status", sub)) => handle_daemon_status(sub),
+        Some(("stats", sub)) => handle_daemon_stats(sub),
         _ => Err("Unknown daemon subcommand".into()),
     }
 }
@@ -68,19 +77,45 @@ fn handle_daemon_restart() -> Result<(), Box<dyn std::error::Error>> {
 
     // Stop (best-effort — if not running, that's fine)
     if kild_core::daemon::client::ping_daemon().unwrap_or(false) {
-        match kild_core::daemon::client::request_shutdown() {
+        let (result, stop_timeout) = if kild_core::daemon::is_daemon_stale() {
+            // Stale binary: let attached clients drain before the old daemon
+            // exits. Its PTYs still close, so sessions end; only processes
+            // that ignore SIGHUP come back, as reconnected and unattachable.
+            match kild_core::daemon::client::request_handoff(HANDOFF_DRAIN_TIMEOUT) {
+                Ok(()) => (Ok(()), HANDOFF_DRAIN_TIMEOUT + STOP_TIMEOUT),
+                Err(e @ kild_core::daemon::client::DaemonClientError::NotRunning { .. }) => {
+                    (Err(e), STOP_TIMEOUT)
+                }
+                Err(e) => {
+                    // Daemons before protocol v7 drop the connection on an
+                    // unknown request; fall back to a plain stop.
+                    warn!(event = "cli.daemon.restart_handoff_failed", error = %e);
+                    (kild_core::daemon::client::request_shutdown(), STOP_TIMEOUT)
+                }
+            }
+        } else {
+            (kild_core::daemon::client::request_shutdown(), STOP_TIMEOUT)
+        };
+
+        match result {
             Ok(()) => {
                 let pid_file = kild_core::daemon::pid_file_path();
-                let timeout = std::time::Duration::from_secs(5);
                 let start = std::time::Instant::now();
                 while pid_file.exists() {
-                    if start.elapsed() > timeout {
+                    if start.elapsed() > stop_timeout {
                         error!(event = "cli.daemon.restart_stop_timeout");
-                        return Err("Old daemon did not stop within 5s".into());
+                        return Err(format!(
+                            "Old daemon did not stop within {}s",
+                            stop_timeout.as_secs()
+                        )
+                        .into());
                     }
                     std::thread::sleep(std::time::Duration::from_millis(100));
                 }
                 println!("Daemon stopped");
+                println!(
+                    "Daemon sessions were not preserved. Reopen kilds with `kild open <branch>`."
+                );
             }
             Err(kild_core::daemon::client::DaemonClientError::NotRunning { .. }) => {}
             Err(e) => {
@@ -247,6 +282,36 @@ fn handle_daemon_status(matches: &ArgMatches) -> Result<(), Box<dyn std::error::
     Ok(())
 }
 
+fn handle_daemon_stats(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let json = matches.get_flag("json");
+    info!(event = "cli.daemon.stats_started");
+
+    let stats = match kild_core::daemon::client::daemon_stats() {
+        Ok(stats) => stats,
+        Err(kild_core::daemon::client::DaemonClientError::NotRunning { .. }) => {
+            println!("Daemon is not running");
+            return Ok(());
+        }
+        Err(e) => {
+            error!(event = "cli.daemon.stats_failed", error = %e);
+            return Err(e.into());
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+    } else {
+        println!("Uptime:            {}s", stats.uptime_secs);
+        println!("Sessions:          {}", stats.session_count);
+        println!("Active PTYs:       {}", stats.active_ptys);
+        println!("Connected clients: {}", stats.connected_clients);
+        println!("Bytes streamed:    {}", stats.bytes_streamed);
+    }
+
+    info!(event = "cli.daemon.stats_completed");
+    Ok(())
+}
+
 fn read_daemon_pid() -> Result<u32, Box<dyn std::error::Error>> {
     let pid_file = kild_core::daemon::pid_file_path();
     let content = std::fs::read_to_string(&pid_file)
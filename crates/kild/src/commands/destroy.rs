@@ -3,6 +3,7 @@ use tracing::{error, info, warn};
 
 use kild_core::events;
 use kild_core::session_ops;
+use kild_core::{DestroySafety, Session};
 
 use super::helpers::{
     FailedOperation, format_count, format_partial_failure_error, is_confirmation_accepted,
@@ -78,7 +79,7 @@ pub(crate) fn handle_destroy_command(
         }
 
         // Block on uncommitted changes
-        if safety_info.should_block() {
+        if safety_info.git_status.has_uncommitted_changes {
             eprintln!();
             eprintln!(
                 "{} '{}': uncommitted changes.",
@@ -109,6 +110,33 @@ pub(crate) fn handle_destroy_command(
 
             return Err("Uncommitted changes detected. Use --force to override.".into());
         }
+
+        // Block on stashes made on the kild branch
+        if safety_info.stash_count > 0 {
+            eprintln!();
+            eprintln!(
+                "{} '{}': stashed changes.",
+                color::error("Cannot destroy"),
+                branch
+            );
+            eprintln!(
+                "  {} git -C $(kild cd {}) stash list",
+                color::hint("Inspect first:"),
+                branch
+            );
+            eprintln!(
+                "  {}",
+                color::hint("Use --force to destroy anyway (stashes will be orphaned).")
+            );
+
+            error!(
+                event = "cli.destroy_blocked",
+                branch = branch,
+                reason = "stashes"
+            );
+
+            return Err("Stashed changes detected. Use --force to override.".into());
+        }
     }
 
     match session_ops::destroy_session(branch, force) {
@@ -144,7 +172,7 @@ fn handle_destroy_all(force: bool) -> Result<(), Box<dyn std::error::Error>> {
 
     let self_branch = super::helpers::resolve_self_branch();
 
-    let mut sessions = session_ops::list_sessions()?;
+    let mut sessions = session_ops::list_sessions(false)?;
 
     // Filter out the calling session to prevent self-destruction
     let skipped_self = if let Some(ref self_br) = self_branch {
@@ -182,13 +210,31 @@ fn handle_destroy_all(force: bool) -> Result<(), Box<dyn std::error::Error>> {
         );
     }
 
+    // Per-session safety checks, same as single destroy (skipped with --force)
+    let (sessions, skipped) = split_blocked(sessions, force, |session| {
+        session_ops::get_destroy_safety_info(&session.branch).ok()
+    });
+    for (branch, reason) in &skipped {
+        warn!(
+            event = "cli.destroy_blocked",
+            branch = branch.as_str(),
+            reason = reason.as_str()
+        );
+    }
+
     // Confirmation prompt unless --force is specified
-    if !force {
+    if !force && !sessions.is_empty() {
         use std::io::{self, Write};
 
+        let skipped_note = if skipped.is_empty() {
+            String::new()
+        } else {
+            format!(" ({} skipped as unsafe)", skipped.len())
+        };
         print!(
-            "Destroy all {}? Worktrees and sessions will be removed. [y/N] ",
-            format_count(sessions.len())
+            "Destroy all {}{}? Worktrees and sessions will be removed. [y/N] ",
+            format_count(sessions.len()),
+            skipped_note
         );
         io::stdout().flush()?;
 
@@ -248,17 +294,152 @@ fn handle_destroy_all(force: bool) -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    // Report sessions left alone by safety checks
+    if !skipped.is_empty() {
+        eprintln!(
+            "{}",
+            color::warning(&format!(
+                "Skipped {} (unsafe to destroy):",
+                format_count(skipped.len())
+            ))
+        );
+        for (branch, reason) in &skipped {
+            eprintln!("  {}: {}", color::ice(branch), reason);
+        }
+        eprintln!(
+            "  {}",
+            color::hint("Use --all --force to destroy them anyway (changes will be lost).")
+        );
+    }
+
     info!(
         event = "cli.destroy_all_completed",
         destroyed = destroyed.len(),
-        failed = errors.len()
+        failed = errors.len(),
+        skipped = skipped.len()
     );
 
-    // Return error if any failures (for exit code)
-    if !errors.is_empty() {
-        let total_count = destroyed.len() + errors.len();
-        return Err(format_partial_failure_error("destroy", errors.len(), total_count).into());
+    // Return error if any failures or skips (for exit code)
+    let not_destroyed = errors.len() + skipped.len();
+    if not_destroyed > 0 {
+        let total_count = destroyed.len() + not_destroyed;
+        return Err(format_partial_failure_error("destroy", not_destroyed, total_count).into());
     }
 
     Ok(())
 }
+
+/// Separate sessions whose destroy safety check blocks from the rest.
+///
+/// With `force` nothing is checked or skipped. A session whose safety info
+/// can't be read is not skipped, matching single-branch destroy.
+fn split_blocked(
+    sessions: Vec<Session>,
+    force: bool,
+    safety_of: impl Fn(&Session) -> Option<DestroySafety>,
+) -> (Vec<Session>, Vec<FailedOperation>) {
+    if force {
+        return (sessions, Vec::new());
+    }
+
+    let mut allowed = Vec::with_capacity(sessions.len());
+    let mut skipped = Vec::new();
+    for session in sessions {
+        match safety_of(&session) {
+            Some(safety) if safety.should_block() => {
+                let reason = safety.warning_messages().join("; ");
+                skipped.push((session.branch.to_string(), reason));
+            }
+            _ => allowed.push(session),
+        }
+    }
+    (allowed, skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kild_core::SessionStatus;
+
+    fn session(branch: &str) -> Session {
+        Session::new(
+            format!("proj_{branch}").into(),
+            "proj".into(),
+            branch.into(),
+            std::env::temp_dir().join(branch),
+            "claude".to_string(),
+            SessionStatus::Active,
+            "2026-01-01T00:00:00Z".to_string(),
+            3000,
+            3009,
+            10,
+            None,
+            None,
+            None,
+            vec![],
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Fake safety: "dirty-*" has uncommitted changes, "stash-*" has stashes,
+    /// "unknown-*" can't be checked, everything else is clean.
+    fn fake_safety(session: &Session) -> Option<DestroySafety> {
+        let mut safety = DestroySafety::default();
+        safety.git_status.has_remote_branch = true;
+        if session.branch.starts_with("dirty-") {
+            safety.git_status.has_uncommitted_changes = true;
+        } else if session.branch.starts_with("stash-") {
+            safety.stash_count = 2;
+        } else if session.branch.starts_with("unknown-") {
+            return None;
+        }
+        Some(safety)
+    }
+
+    fn branches(sessions: &[Session]) -> Vec<&str> {
+        sessions.iter().map(|s| &*s.branch).collect()
+    }
+
+    fn mixed_sessions() -> Vec<Session> {
+        ["clean-a", "dirty-b", "stash-c", "unknown-d", "clean-e"]
+            .into_iter()
+            .map(session)
+            .collect()
+    }
+
+    #[test]
+    fn test_split_blocked_all_skips_unsafe_sessions() {
+        let (allowed, skipped) = split_blocked(mixed_sessions(), false, fake_safety);
+
+        assert_eq!(branches(&allowed), vec!["clean-a", "unknown-d", "clean-e"]);
+        let skipped_branches: Vec<_> = skipped.iter().map(|(b, _)| b.as_str()).collect();
+        assert_eq!(skipped_branches, vec!["dirty-b", "stash-c"]);
+        assert!(skipped[0].1.contains("Uncommitted changes"));
+        assert!(skipped[1].1.contains("stash"));
+    }
+
+    #[test]
+    fn test_split_blocked_all_force_keeps_every_session() {
+        let (allowed, skipped) = split_blocked(mixed_sessions(), true, |_| {
+            panic!("safety checks must not run with --force")
+        });
+
+        assert_eq!(
+            branches(&allowed),
+            vec!["clean-a", "dirty-b", "stash-c", "unknown-d", "clean-e"]
+        );
+        assert!(skipped.is_empty());
+    }
+
+    #[test]
+    fn test_split_blocked_warnings_alone_do_not_skip() {
+        // Never pushed is a warning, not a blocker
+        let (allowed, skipped) = split_blocked(vec![session("clean-a")], false, |_| {
+            Some(DestroySafety::default())
+        });
+        assert_eq!(allowed.len(), 1);
+        assert!(skipped.is_empty());
+    }
+}
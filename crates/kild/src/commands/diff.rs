@@ -1,6 +1,7 @@
 use clap::ArgMatches;
 use tracing::{error, info};
 
+use kild_core::errors::KildError;
 use kild_core::events;
 use kild_core::git::get_diff_stats;
 
@@ -13,24 +14,38 @@ pub(crate) fn handle_diff_command(matches: &ArgMatches) -> Result<(), Box<dyn st
         .ok_or("Branch argument is required")?;
     let staged = matches.get_flag("staged");
     let stat = matches.get_flag("stat");
+    let json_output = matches.get_flag("json");
 
     info!(
         event = "cli.diff_started",
         branch = branch,
         staged = staged,
-        stat = stat
+        stat = stat,
+        json_output = json_output
     );
 
     // 1. Look up the session
-    let session = helpers::require_session(branch, "cli.diff_failed")?;
+    let session = helpers::require_session_json(branch, "cli.diff_failed", json_output)?;
 
     // Handle --stat flag: show summary instead of full diff
     if stat {
-        let diff = get_diff_stats(&session.worktree_path)?;
-        println!(
-            "+{} -{} ({} files changed)",
-            diff.insertions, diff.deletions, diff.files_changed
-        );
+        let diff = get_diff_stats(&session.worktree_path).map_err(|e| {
+            error!(event = "cli.diff_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            if json_output {
+                helpers::print_json_error(&e, e.error_code())
+            } else {
+                e.into()
+            }
+        })?;
+        if json_output {
+            println!("{}", serde_json::to_string_pretty(&diff)?);
+        } else {
+            println!(
+                "+{} -{} ({} files changed)",
+                diff.insertions, diff.deletions, diff.files_changed
+            );
+        }
         info!(event = "cli.diff_completed", branch = branch, stat = true);
         return Ok(());
     }
@@ -55,3 +70,26 @@ pub(crate) fn handle_diff_command(matches: &ArgMatches) -> Result<(), Box<dyn st
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use kild_core::git::DiffStats;
+
+    #[test]
+    fn test_stat_json_shape() {
+        let stats = DiffStats {
+            insertions: 12,
+            deletions: 3,
+            files_changed: 2,
+        };
+        let value = serde_json::to_value(stats).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "insertions": 12,
+                "deletions": 3,
+                "files_changed": 2,
+            })
+        );
+    }
+}
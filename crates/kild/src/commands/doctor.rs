@@ -0,0 +1,71 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::doctor::{self, DiagnosticStatus};
+
+use super::helpers::load_config_with_warning;
+use crate::color;
+
+pub(crate) fn handle_doctor_command(
+    _matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    info!(event = "cli.doctor_started");
+
+    let config = load_config_with_warning();
+    let results = doctor::run_diagnostics(&config);
+
+    let name_width = results.iter().map(|r| r.name.len()).max().unwrap_or(0);
+    for result in &results {
+        let marker = match result.status {
+            DiagnosticStatus::Pass => color::aurora("✓"),
+            DiagnosticStatus::Warn => color::copper("!"),
+            DiagnosticStatus::Fail => color::error("✗"),
+        };
+        println!(
+            "{} {:<width$}  {}",
+            marker,
+            result.name,
+            color::muted(&result.detail),
+            width = name_width
+        );
+        if let Some(hint) = &result.hint {
+            println!(
+                "  {:<width$}  {}",
+                "",
+                color::hint(hint),
+                width = name_width
+            );
+        }
+    }
+
+    let failed = count(&results, DiagnosticStatus::Fail);
+    let warned = count(&results, DiagnosticStatus::Warn);
+
+    println!();
+    if failed == 0 && warned == 0 {
+        println!("{}", color::aurora("All checks passed."));
+    } else {
+        println!(
+            "{} passed, {} warning(s), {} failed.",
+            count(&results, DiagnosticStatus::Pass),
+            warned,
+            failed
+        );
+    }
+
+    if failed > 0 {
+        error!(
+            event = "cli.doctor_failed",
+            failed = failed,
+            warned = warned
+        );
+        return Err(format!("{} check(s) failed", failed).into());
+    }
+
+    info!(event = "cli.doctor_completed", warned = warned);
+    Ok(())
+}
+
+fn count(results: &[doctor::DiagnosticResult], status: DiagnosticStatus) -> usize {
+    results.iter().filter(|r| r.status == status).count()
+}
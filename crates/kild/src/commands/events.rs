@@ -0,0 +1,216 @@
+use std::io::Write;
+use std::time::Duration;
+
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events::{EventLog, RecordedEvent};
+use kild_core::state::Event;
+
+use super::helpers;
+use crate::color;
+
+/// How often `--follow` checks the event log for new events.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub(crate) fn handle_events_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let follow = matches.get_flag("follow");
+    let json_output = matches.get_flag("json");
+
+    info!(
+        event = "cli.events_started",
+        follow = follow,
+        json_output = json_output
+    );
+
+    let log = EventLog::open_default();
+    let mut out = std::io::stdout().lock();
+
+    if follow {
+        let mut subscription = log.subscribe();
+        // Streams until the user interrupts with Ctrl+C
+        loop {
+            let records = subscription.poll().map_err(|e| {
+                error!(event = "cli.events_failed", error = %e);
+                e
+            })?;
+            write_events(&records, json_output, &mut out)?;
+            std::thread::sleep(FOLLOW_POLL_INTERVAL);
+        }
+    }
+
+    let records = log.replay().poll().map_err(|e| {
+        eprintln!(
+            "{} {}: {}",
+            color::error("Could not read event log"),
+            helpers::shorten_home_path(log.path()),
+            e
+        );
+        error!(event = "cli.events_failed", error = %e);
+        e
+    })?;
+    if records.is_empty() && !json_output {
+        println!("No events recorded yet.");
+    }
+    write_events(&records, json_output, &mut out)?;
+
+    info!(event = "cli.events_completed", count = records.len());
+
+    Ok(())
+}
+
+/// Write one line per event: JSONL with `--json`, human-readable otherwise.
+fn write_events(
+    records: &[RecordedEvent],
+    json_output: bool,
+    out: &mut impl Write,
+) -> std::io::Result<()> {
+    for record in records {
+        if json_output {
+            serde_json::to_writer(&mut *out, record)?;
+            writeln!(out)?;
+        } else {
+            writeln!(out, "{}", format_event_line(record))?;
+        }
+    }
+    out.flush()
+}
+
+fn format_event_line(record: &RecordedEvent) -> String {
+    let time = chrono::DateTime::parse_from_rfc3339(&record.timestamp)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|_| record.timestamp.clone());
+    let (label, subject) = describe(&record.event);
+    format!("{}  {:<16} {}", color::muted(&time), label, subject)
+}
+
+/// Short label and subject for an event, e.g. `("created", "feature-auth (proj/feature-auth)")`.
+fn describe(event: &Event) -> (&'static str, String) {
+    match event {
+        Event::KildCreated { branch, session_id } => (
+            "created",
+            format!("{} ({})", color::ice(branch), session_id),
+        ),
+        Event::KildDestroyed { branch } => ("destroyed", color::ice(branch)),
+        Event::KildOpened { branch, agent } => {
+            ("opened", format!("{} ({})", color::ice(branch), agent))
+        }
+        Event::KildStopped { branch } => ("stopped", color::ice(branch)),
+        Event::KildCompleted { branch } => ("completed", color::ice(branch)),
+        Event::AgentStatusUpdated { branch, status } => (
+            "agent-status",
+            format!(
+                "{} {}",
+                color::ice(branch),
+                color::activity(&status.to_string())
+            ),
+        ),
+        Event::PrStatusRefreshed { branch } => ("pr-refreshed", color::ice(branch)),
+        Event::SessionsRefreshed => ("refreshed", String::new()),
+        Event::ProjectAdded { path, name } => (
+            "project-added",
+            format!("{} ({})", name, helpers::shorten_home_path(path)),
+        ),
+        Event::ProjectRemoved { path } => ("project-removed", helpers::shorten_home_path(path)),
+        Event::ActiveProjectChanged { path } => (
+            "project-selected",
+            path.as_deref()
+                .map(helpers::shorten_home_path)
+                .unwrap_or_else(|| "all projects".to_string()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(event: Event) -> RecordedEvent {
+        RecordedEvent {
+            timestamp: "2026-01-01T12:00:00Z".to_string(),
+            event,
+        }
+    }
+
+    #[test]
+    fn test_write_events_human_readable() {
+        let records = vec![
+            record(Event::KildCreated {
+                branch: "feature-auth".into(),
+                session_id: "proj/feature-auth".into(),
+            }),
+            record(Event::AgentStatusUpdated {
+                branch: "feature-auth".into(),
+                status: kild_core::AgentStatus::Working,
+            }),
+            record(Event::KildDestroyed {
+                branch: "feature-auth".into(),
+            }),
+        ];
+
+        let mut out = Vec::new();
+        write_events(&records, false, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("created"));
+        assert!(lines[0].contains("feature-auth"));
+        assert!(lines[0].contains("proj/feature-auth"));
+        assert!(lines[1].contains("agent-status"));
+        assert!(lines[1].contains("working"));
+        assert!(lines[2].contains("destroyed"));
+    }
+
+    #[test]
+    fn test_write_events_json_lines() {
+        let records = vec![
+            record(Event::KildStopped {
+                branch: "feature-auth".into(),
+            }),
+            record(Event::SessionsRefreshed),
+        ];
+
+        let mut out = Vec::new();
+        write_events(&records, true, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        let parsed: Vec<RecordedEvent> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed, records);
+    }
+
+    #[test]
+    fn test_published_events_reach_follower() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = EventLog::new(dir.path().join("events.jsonl"));
+        let mut subscription = log.subscribe();
+
+        log.publish(&Event::KildCreated {
+            branch: "synthetic".into(),
+            session_id: "proj/synthetic".into(),
+        })
+        .unwrap();
+        log.publish(&Event::KildStopped {
+            branch: "synthetic".into(),
+        })
+        .unwrap();
+
+        let mut out = Vec::new();
+        write_events(&subscription.poll().unwrap(), false, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("created") && lines[0].contains("synthetic"));
+        assert!(lines[1].contains("stopped") && lines[1].contains("synthetic"));
+    }
+}
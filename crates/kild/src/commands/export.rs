@@ -0,0 +1,45 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::session_ops;
+
+use crate::color;
+
+pub(crate) fn handle_export_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let output = matches.get_one::<String>("output");
+
+    info!(event = "cli.export_started", branch = branch);
+
+    let bundle = match session_ops::export_session(branch) {
+        Ok(bundle) => bundle,
+        Err(e) => {
+            eprintln!("{} '{}': {}", color::error("Could not export"), branch, e);
+            error!(event = "cli.export_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            return Err(e.into());
+        }
+    };
+
+    let json = serde_json::to_string_pretty(&bundle)?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, format!("{json}\n"))?;
+            eprintln!(
+                "{} {} to {}",
+                color::aurora("Exported."),
+                color::ice(branch),
+                path
+            );
+        }
+        None => println!("{json}"),
+    }
+
+    info!(event = "cli.export_completed", branch = branch);
+    Ok(())
+}
@@ -156,6 +156,39 @@ pub fn load_config_with_warning() -> KildConfig {
     }
 }
 
+/// Check an `--agent` value against built-in and config-defined agents.
+///
+/// Config agents are only known once config is loaded, so clap can't do this.
+/// Prints the error and returns it for unknown agents.
+pub fn validate_agent_arg(
+    config: &KildConfig,
+    agent: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if kild_core::agents::resolve_agent(config, agent).is_some() {
+        return Ok(());
+    }
+
+    let mut supported: Vec<&str> = kild_core::agents::valid_agent_names();
+    supported.extend(
+        config
+            .agents
+            .keys()
+            .map(String::as_str)
+            .filter(|name| kild_core::agents::resolve_agent(config, name).is_some()),
+    );
+    supported.sort();
+    supported.dedup();
+
+    let message = format!(
+        "Unknown agent '{}'. Supported: {}",
+        agent,
+        supported.join(", ")
+    );
+    eprintln!("{}", color::error(&message));
+    error!(event = "cli.agent_unknown", agent = agent);
+    Err(message.into())
+}
+
 /// Validate branch name to prevent injection attacks
 pub fn is_valid_branch_name(name: &str) -> bool {
     // Allow alphanumeric, hyphens, underscores, and forward slashes
@@ -198,6 +231,23 @@ pub fn shorten_home_path(path: &std::path::Path) -> String {
     path.display().to_string()
 }
 
+/// Print the files a rebase conflicted on, if `err` is a rebase conflict.
+pub fn print_rebase_conflicts(err: &kild_core::git::GitError) {
+    if let kild_core::git::GitError::RebaseConflict {
+        conflicted_files, ..
+    } = err
+    {
+        print_conflicted_files(conflicted_files);
+    }
+}
+
+/// Print conflicted paths indented under a kild's error line.
+pub fn print_conflicted_files(files: &[std::path::PathBuf]) {
+    for file in files {
+        eprintln!("  {} {}", color::muted("conflict:"), file.display());
+    }
+}
+
 /// Format partial failure error message for bulk operations.
 pub fn format_partial_failure_error(operation: &str, failed: usize, total: usize) -> String {
     format!(
@@ -570,4 +620,23 @@ mod tests {
         let mode = resolve_explicit_runtime_mode(false, false);
         assert_eq!(mode, None);
     }
+
+    #[test]
+    fn test_validate_agent_arg_accepts_builtin_and_config_agents() {
+        let mut config = KildConfig::default();
+        config.agents.insert(
+            "myagent".to_string(),
+            kild_config::AgentSettings {
+                binary: Some("my-agent".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert!(validate_agent_arg(&config, "claude").is_ok());
+        assert!(validate_agent_arg(&config, "myagent").is_ok());
+
+        let err = validate_agent_arg(&config, "nope").unwrap_err().to_string();
+        assert!(err.contains("Unknown agent 'nope'"));
+        assert!(err.contains("myagent"));
+    }
 }
@@ -66,7 +66,7 @@ pub(crate) fn handle_hide_command(matches: &ArgMatches) -> Result<(), Box<dyn st
 fn handle_hide_all() -> Result<(), Box<dyn std::error::Error>> {
     info!(event = "cli.hide_all_started");
 
-    let sessions = session_ops::list_sessions()?;
+    let sessions = session_ops::list_sessions(false)?;
     let active: Vec<_> = sessions
         .into_iter()
         .filter(|s| s.status == SessionStatus::Active)
@@ -0,0 +1,73 @@
+use std::io::Read;
+
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::session_ops::{self, SessionBundle};
+
+use super::helpers::shorten_home_path;
+use crate::color;
+
+pub(crate) fn handle_import_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file = matches
+        .get_one::<String>("file")
+        .ok_or("File argument is required")?;
+    let overwrite = matches.get_flag("overwrite");
+
+    info!(
+        event = "cli.import_started",
+        file = file,
+        overwrite = overwrite
+    );
+
+    let raw = if file == "-" {
+        let mut raw = String::new();
+        std::io::stdin().read_to_string(&mut raw)?;
+        raw
+    } else {
+        std::fs::read_to_string(file)?
+    };
+    let bundle: SessionBundle = match serde_json::from_str(&raw) {
+        Ok(bundle) => bundle,
+        Err(e) => {
+            eprintln!("{} {}: {}", color::error("Invalid bundle"), file, e);
+            error!(event = "cli.import_failed", file = file, error = %e);
+            return Err(e.into());
+        }
+    };
+
+    match session_ops::import_session(bundle, overwrite) {
+        Ok(session) => {
+            println!("{}", color::aurora("Kild imported."));
+            println!(
+                "  {}   {}",
+                color::muted("Branch:"),
+                color::ice(&session.branch)
+            );
+            println!(
+                "  {} {}",
+                color::muted("Worktree:"),
+                shorten_home_path(&session.worktree_path)
+            );
+            if !session.worktree_path.exists() {
+                println!(
+                    "  {}",
+                    color::hint(
+                        "Worktree does not exist on this machine; recreate it before opening."
+                    )
+                );
+            }
+            info!(event = "cli.import_completed", branch = %session.branch);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{} {}: {}", color::error("Could not import"), file, e);
+            error!(event = "cli.import_failed", file = file, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
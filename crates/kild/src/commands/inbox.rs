@@ -112,7 +112,7 @@ fn handle_single_inbox(
 fn handle_all_inbox(json_output: bool) -> Result<(), Box<dyn std::error::Error>> {
     info!(event = "cli.inbox_all_started");
 
-    let sessions = session_ops::list_sessions().map_err(|e| {
+    let sessions = session_ops::list_sessions(false).map_err(|e| {
         error!(event = "cli.inbox_all_failed", error = %e);
         let boxed: Box<dyn std::error::Error> = e.into();
         boxed
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use serde::Serialize;
 
 use kild_core::sessions::types::SessionStatus;
+use kild_teams::{TeamColor, TeamMember};
 
 /// Fleet-level summary metrics for list output.
 #[derive(Serialize)]
@@ -104,12 +105,47 @@ pub struct AgentStatusResponse {
     pub updated_at: String,
 }
 
+/// One pane in `kild teammates --json` output.
+#[derive(Debug, Serialize)]
+pub struct TeammateEntry {
+    pub name: String,
+    pub color: TeamColor,
+    /// Daemon session ID, if the pane is mapped to one.
+    pub session_id: Option<String>,
+    pub pane_id: String,
+    pub role: &'static str,
+    /// Live daemon status of the pane's session (`running`, `stopped`, ...).
+    pub agent_status: Option<String>,
+}
+
+impl TeammateEntry {
+    pub fn new(member: &TeamMember, status: Option<String>) -> Self {
+        Self {
+            name: member.name.clone(),
+            color: member.color,
+            session_id: member.daemon_session_id.clone(),
+            pane_id: member.pane_id.clone(),
+            role: if member.is_leader() {
+                "leader"
+            } else {
+                "teammate"
+            },
+            agent_status: status,
+        }
+    }
+}
+
 /// Enriched session data for JSON output (used by list and status commands).
 #[derive(Serialize)]
 pub struct EnrichedSession {
     #[serde(flatten)]
     pub session: kild_core::Session,
     pub process_status: kild_core::ProcessStatus,
+    /// Whether the worktree directory is still present on disk.
+    pub worktree_exists: bool,
+    /// Whether the kild is more than `git.stale_after_commits` behind its base.
+    /// `None` when branch health could not be collected.
+    pub stale: Option<bool>,
     pub git_stats: Option<kild_core::GitStats>,
     pub branch_health: Option<kild_core::BranchHealth>,
     pub merge_readiness: Option<kild_core::MergeReadiness>,
@@ -119,4 +155,105 @@ pub struct EnrichedSession {
     pub terminal_type: Option<String>,
     pub pr_info: Option<kild_core::PullRequest>,
     pub overlapping_files: Option<Vec<String>>,
+    pub agent_version: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(pane_id: &str, daemon_session_id: Option<&str>) -> TeamMember {
+        TeamMember {
+            name: "researcher".to_string(),
+            agent_id: None,
+            agent_type: None,
+            color: TeamColor::Purple,
+            pane_id: pane_id.to_string(),
+            daemon_session_id: daemon_session_id.map(str::to_string),
+            is_active: true,
+        }
+    }
+
+    #[test]
+    fn test_teammate_entry_mapped() {
+        let entry = TeammateEntry::new(
+            &member("%1", Some("myapp_feature_1")),
+            Some("running".to_string()),
+        );
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "researcher",
+                "color": "purple",
+                "session_id": "myapp_feature_1",
+                "pane_id": "%1",
+                "role": "teammate",
+                "agent_status": "running",
+            })
+        );
+    }
+
+    fn enriched(worktree_path: std::path::PathBuf) -> EnrichedSession {
+        let session = kild_core::Session::new(
+            "project_feature".into(),
+            "project".into(),
+            "feature".into(),
+            worktree_path,
+            "claude".to_string(),
+            SessionStatus::Active,
+            "2024-01-01T00:00:00Z".to_string(),
+            0,
+            0,
+            0,
+            None,
+            None,
+            None,
+            vec![],
+            None,
+            None,
+            None,
+        );
+        EnrichedSession {
+            worktree_exists: session.worktree_path.is_dir(),
+            stale: None,
+            session,
+            process_status: kild_core::ProcessStatus::Stopped,
+            git_stats: None,
+            branch_health: None,
+            merge_readiness: None,
+            agent_status: None,
+            agent_status_updated_at: None,
+            terminal_window_title: None,
+            terminal_type: None,
+            pr_info: None,
+            overlapping_files: None,
+            agent_version: None,
+        }
+    }
+
+    #[test]
+    fn test_enriched_session_worktree_exists() {
+        let dir = tempfile::tempdir().unwrap();
+        let present = serde_json::to_string(&enriched(dir.path().to_path_buf())).unwrap();
+        let absent = serde_json::to_string(&enriched(dir.path().join("missing"))).unwrap();
+
+        let present: serde_json::Value = serde_json::from_str(&present).unwrap();
+        let absent: serde_json::Value = serde_json::from_str(&absent).unwrap();
+
+        assert_eq!(present["worktree_exists"], true);
+        assert_eq!(absent["worktree_exists"], false);
+        assert_eq!(present["created_at"], "2024-01-01T00:00:00Z");
+        assert_eq!(present["process_status"], "stopped");
+    }
+
+    #[test]
+    fn test_teammate_entry_unmapped() {
+        let entry = TeammateEntry::new(&member("%0", None), None);
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["session_id"], serde_json::Value::Null);
+        assert_eq!(json["agent_status"], serde_json::Value::Null);
+        assert_eq!(json["role"], "leader");
+        assert_eq!(json["color"], "purple");
+    }
 }
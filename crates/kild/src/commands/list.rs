@@ -12,10 +12,27 @@ use crate::color;
 
 pub(crate) fn handle_list_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
     let json_output = matches.get_flag("json");
-
-    info!(event = "cli.list_started", json_output = json_output);
-
-    match session_ops::list_sessions() {
+    let filter_expr = matches.get_one::<String>("filter");
+    let include_archived = matches.get_flag("archived");
+
+    info!(
+        event = "cli.list_started",
+        json_output = json_output,
+        filter = ?filter_expr,
+        include_archived = include_archived
+    );
+
+    let result = match filter_expr {
+        Some(expr) => expr
+            .parse::<kild_core::sessions::SessionFilter>()
+            .and_then(|filter| {
+                session_ops::list_sessions_filtered(&filter, include_archived, true)
+            }),
+        None if include_archived => session_ops::list_all_sessions(true),
+        None => session_ops::list_sessions(true),
+    };
+
+    match result {
         Ok(mut sessions) => {
             // Sync daemon-managed sessions: if daemon says stopped, update JSON
             for session in &mut sessions {
@@ -25,7 +42,11 @@ pub(crate) fn handle_list_command(matches: &ArgMatches) -> Result<(), Box<dyn st
             let session_count = sessions.len();
 
             if sessions.is_empty() && !json_output {
-                println!("No active kilds found.");
+                if filter_expr.is_some() {
+                    println!("No kilds match the filter.");
+                } else {
+                    println!("No active kilds found.");
+                }
                 info!(event = "cli.list_completed", count = session_count);
                 return Ok(());
             }
@@ -102,7 +123,17 @@ pub(crate) fn handle_list_command(matches: &ArgMatches) -> Result<(), Box<dyn st
                                 .collect(),
                         );
 
+                        let agent_version =
+                            kild_core::agents::resolve_agent(&config, &session.agent)
+                                .and_then(|agent| agent.backend().version());
+
+                        let stale = branch_health
+                            .as_ref()
+                            .map(|h| h.drift.is_stale(config.git.stale_after_commits()));
+
                         EnrichedSession {
+                            worktree_exists: session.worktree_path.is_dir(),
+                            stale,
                             session,
                             process_status,
                             git_stats,
@@ -114,6 +145,7 @@ pub(crate) fn handle_list_command(matches: &ArgMatches) -> Result<(), Box<dyn st
                             terminal_type,
                             pr_info,
                             overlapping_files,
+                            agent_version,
                         }
                     })
                     .collect();
@@ -0,0 +1,147 @@
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::time::Duration;
+
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use super::helpers;
+
+/// How often `--follow` checks the log for new output.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub(crate) fn handle_logs_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let follow = matches.get_flag("follow");
+
+    info!(event = "cli.logs_started", branch = branch, follow = follow);
+
+    let session = helpers::require_session(branch, "cli.logs_failed")?;
+
+    let Some(daemon_session_id) = session.latest_agent().and_then(|a| a.daemon_session_id()) else {
+        let msg = format!(
+            "'{}' has no captured output. Only daemon sessions log agent output (kild create --daemon).",
+            branch
+        );
+        eprintln!("{}", msg);
+        error!(
+            event = "cli.logs_failed",
+            branch = branch,
+            error = msg.as_str()
+        );
+        return Err(msg.into());
+    };
+
+    let log_path = kild_config::Config::new()
+        .paths()
+        .agent_log_file(daemon_session_id);
+    let mut file = File::open(&log_path).map_err(|e| {
+        let msg = format!("No output log for '{}' at {}", branch, log_path.display());
+        eprintln!("{}", msg);
+        error!(
+            event = "cli.logs_failed",
+            branch = branch,
+            path = %log_path.display(),
+            error = %e
+        );
+        msg
+    })?;
+
+    let mut out = std::io::stdout().lock();
+    let mut offset = copy_new_output(&mut file, 0, &mut out)?;
+
+    if follow {
+        // Streams until the user interrupts with Ctrl+C
+        loop {
+            std::thread::sleep(FOLLOW_POLL_INTERVAL);
+            offset = copy_new_output(&mut file, offset, &mut out)?;
+        }
+    }
+
+    info!(
+        event = "cli.logs_completed",
+        branch = branch,
+        bytes = offset,
+        path = %helpers::shorten_home_path(&log_path)
+    );
+
+    Ok(())
+}
+
+/// Copy everything in `file` past `offset` to `out`, returning the new offset.
+///
+/// A file shorter than `offset` was truncated by a respawn, so reading
+/// restarts from the beginning.
+fn copy_new_output(file: &mut File, offset: u64, out: &mut impl Write) -> std::io::Result<u64> {
+    let len = file.metadata()?.len();
+    let start = if len < offset { 0 } else { offset };
+    if len == start {
+        return Ok(start);
+    }
+
+    file.seek(SeekFrom::Start(start))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+    out.write_all(&buf)?;
+    out.flush()?;
+    Ok(start + buf.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_new_output_reads_existing_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("myapp_feature_0.log");
+        std::fs::write(&path, "agent started\nworking...\n").unwrap();
+
+        let mut file = File::open(&path).unwrap();
+        let mut out = Vec::new();
+        let offset = copy_new_output(&mut file, 0, &mut out).unwrap();
+
+        assert_eq!(out, b"agent started\nworking...\n");
+        assert_eq!(offset, out.len() as u64);
+    }
+
+    #[test]
+    fn test_copy_new_output_only_returns_appended_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("agent.log");
+        std::fs::write(&path, "first\n").unwrap();
+
+        let mut file = File::open(&path).unwrap();
+        let mut out = Vec::new();
+        let offset = copy_new_output(&mut file, 0, &mut out).unwrap();
+
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"second\n")
+            .unwrap();
+
+        let mut appended = Vec::new();
+        copy_new_output(&mut file, offset, &mut appended).unwrap();
+        assert_eq!(appended, b"second\n");
+    }
+
+    #[test]
+    fn test_copy_new_output_restarts_after_truncation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("agent.log");
+        std::fs::write(&path, "old output from a previous run\n").unwrap();
+
+        let mut file = File::open(&path).unwrap();
+        let offset = copy_new_output(&mut file, 0, &mut Vec::new()).unwrap();
+
+        std::fs::write(&path, "new\n").unwrap();
+
+        let mut out = Vec::new();
+        assert_eq!(copy_new_output(&mut file, offset, &mut out).unwrap(), 4);
+        assert_eq!(out, b"new\n");
+    }
+}
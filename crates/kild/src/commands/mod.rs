@@ -1,12 +1,11 @@
 use clap::ArgMatches;
 use tracing::error;
 
-use kild_core::events;
-
 pub mod helpers;
 mod json_types;
 
 mod agent_status;
+mod archive;
 mod attach;
 mod cd;
 mod cleanup;
@@ -14,23 +13,32 @@ mod code;
 mod commits;
 mod complete;
 mod completions;
+mod config;
+mod copy_out;
 mod create;
 mod daemon;
 mod destroy;
 mod diff;
+mod doctor;
+mod events;
+mod export;
 mod focus;
 mod health;
 mod hide;
+mod import;
 mod inbox;
 mod init_hooks;
 mod inject;
 mod list;
+mod logs;
 mod open;
 mod overlaps;
 mod pr;
 mod prime;
 mod project;
 mod rebase;
+mod rename;
+mod restart;
 mod stats;
 mod status;
 mod stop;
@@ -38,17 +46,26 @@ mod sync;
 mod teammates;
 
 pub fn run_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
-    events::log_app_startup();
+    kild_core::events::log_app_startup();
 
     match matches.subcommand() {
         Some(("create", sub_matches)) => create::handle_create_command(sub_matches),
         Some(("list", sub_matches)) => list::handle_list_command(sub_matches),
         Some(("cd", sub_matches)) => cd::handle_cd_command(sub_matches),
+        Some(("logs", sub_matches)) => logs::handle_logs_command(sub_matches),
+        Some(("events", sub_matches)) => events::handle_events_command(sub_matches),
         Some(("destroy", sub_matches)) => destroy::handle_destroy_command(sub_matches),
         Some(("complete", sub_matches)) => complete::handle_complete_command(sub_matches),
         Some(("completions", sub_matches)) => completions::handle_completions_command(sub_matches),
         Some(("open", sub_matches)) => open::handle_open_command(sub_matches),
         Some(("stop", sub_matches)) => stop::handle_stop_command(sub_matches),
+        Some(("restart", sub_matches)) => restart::handle_restart_command(sub_matches),
+        Some(("rename", sub_matches)) => rename::handle_rename_command(sub_matches),
+        Some(("copy-out", sub_matches)) => copy_out::handle_copy_out_command(sub_matches),
+        Some(("archive", sub_matches)) => archive::handle_archive_command(sub_matches),
+        Some(("unarchive", sub_matches)) => archive::handle_unarchive_command(sub_matches),
+        Some(("export", sub_matches)) => export::handle_export_command(sub_matches),
+        Some(("import", sub_matches)) => import::handle_import_command(sub_matches),
         Some(("code", sub_matches)) => code::handle_code_command(sub_matches),
         Some(("focus", sub_matches)) => focus::handle_focus_command(sub_matches),
         Some(("hide", sub_matches)) => hide::handle_hide_command(sub_matches),
@@ -65,6 +82,7 @@ pub fn run_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error
         Some(("sync", sub_matches)) => sync::handle_sync_command(sub_matches),
         Some(("cleanup", sub_matches)) => cleanup::handle_cleanup_command(sub_matches),
         Some(("health", sub_matches)) => health::handle_health_command(sub_matches),
+        Some(("doctor", sub_matches)) => doctor::handle_doctor_command(sub_matches),
         Some(("daemon", sub_matches)) => daemon::handle_daemon_command(sub_matches),
         Some(("attach", sub_matches)) => attach::handle_attach_command(sub_matches),
         Some(("inbox", sub_matches)) => inbox::handle_inbox_command(sub_matches),
@@ -73,6 +91,7 @@ pub fn run_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error
         Some(("teammates", sub_matches)) => teammates::handle_teammates_command(sub_matches),
         Some(("init-hooks", sub_matches)) => init_hooks::handle_init_hooks_command(sub_matches),
         Some(("project", sub_matches)) => project::handle_project_command(sub_matches),
+        Some(("config", sub_matches)) => config::handle_config_command(sub_matches),
         _ => {
             error!(event = "cli.command_unknown");
             Err("Unknown command".into())
@@ -8,11 +8,14 @@ use kild_core::sessions::fleet;
 
 use super::helpers::{
     FailedOperation, OpenedKild, format_count, format_partial_failure_error,
-    resolve_explicit_runtime_mode, resolve_open_mode,
+    load_config_with_warning, resolve_explicit_runtime_mode, resolve_open_mode, validate_agent_arg,
 };
 
 pub(crate) fn handle_open_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
     let mode = resolve_open_mode(matches);
+    if let kild_core::OpenMode::Agent(agent) = &mode {
+        validate_agent_arg(&load_config_with_warning(), agent)?;
+    }
     let daemon_flag = matches.get_flag("daemon");
     let no_daemon_flag = matches.get_flag("no-daemon");
     let runtime_mode = resolve_explicit_runtime_mode(daemon_flag, no_daemon_flag);
@@ -116,7 +119,7 @@ fn handle_open_all(
 ) -> Result<(), Box<dyn std::error::Error>> {
     info!(event = "cli.open_all_started", mode = ?mode);
 
-    let sessions = session_ops::list_sessions()?;
+    let sessions = session_ops::list_sessions(true)?;
     let stopped: Vec<_> = sessions
         .into_iter()
         .filter(|s| s.status == SessionStatus::Stopped)
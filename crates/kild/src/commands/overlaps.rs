@@ -21,7 +21,7 @@ pub(crate) fn handle_overlaps_command(
         json_output = json_output
     );
 
-    let sessions = session_ops::list_sessions()?;
+    let sessions = session_ops::list_sessions(false)?;
 
     if sessions.is_empty() {
         if json_output {
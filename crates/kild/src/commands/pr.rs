@@ -1,6 +1,7 @@
 use clap::ArgMatches;
 use tracing::{error, info, warn};
 
+use kild_core::errors::KildError;
 use kild_core::session_ops;
 
 use super::helpers::{self, is_valid_branch_name};
@@ -11,6 +12,8 @@ pub(crate) fn handle_pr_command(matches: &ArgMatches) -> Result<(), Box<dyn std:
         .ok_or("Branch argument is required")?;
     let json_output = matches.get_flag("json");
     let refresh = matches.get_flag("refresh");
+    let create = matches.get_flag("create");
+    let draft = matches.get_flag("draft");
 
     if !is_valid_branch_name(branch) {
         if json_output {
@@ -28,7 +31,9 @@ pub(crate) fn handle_pr_command(matches: &ArgMatches) -> Result<(), Box<dyn std:
         event = "cli.pr_started",
         branch = branch,
         json_output = json_output,
-        refresh = refresh
+        refresh = refresh,
+        create = create,
+        draft = draft
     );
 
     // 1. Look up session
@@ -58,8 +63,33 @@ pub(crate) fn handle_pr_command(matches: &ArgMatches) -> Result<(), Box<dyn std:
 
     let kild_branch = kild_core::git::kild_branch_name(branch);
 
-    // 3. Get PR info: refresh or read from cache
-    let pr_info = if refresh || session_ops::read_pr_info(&session.id).is_none() {
+    // 3. Open a PR if requested and none exists yet
+    let created =
+        if create && session_ops::fetch_pr_info(&session.worktree_path, &kild_branch).is_none() {
+            match session_ops::create_pr(&session.worktree_path, &kild_branch, draft) {
+                Ok(url) => {
+                    info!(event = "cli.pr_created", branch = branch, draft = draft, url = %url);
+                    if !json_output {
+                        let kind = if draft { "draft PR" } else { "PR" };
+                        println!("Opened {} for 'kild/{}': {}", kind, branch, url);
+                    }
+                    true
+                }
+                Err(e) => {
+                    error!(event = "cli.pr_failed", branch = branch, error = %e);
+                    if json_output {
+                        return Err(super::helpers::print_json_error(&e, e.error_code()));
+                    }
+                    eprintln!("Failed to open PR: {}", e);
+                    return Err(e.into());
+                }
+            }
+        } else {
+            false
+        };
+
+    // 4. Get PR info: refresh or read from cache
+    let pr_info = if created || refresh || session_ops::read_pr_info(&session.id).is_none() {
         // Fetch from GitHub and write sidecar
         let fetched = session_ops::fetch_pr_info(&session.worktree_path, &kild_branch);
         if let Some(ref info) = fetched {
@@ -81,7 +111,7 @@ pub(crate) fn handle_pr_command(matches: &ArgMatches) -> Result<(), Box<dyn std:
         session_ops::read_pr_info(&session.id)
     };
 
-    // 4. Output
+    // 5. Output
     match pr_info {
         Some(info) => {
             if json_output {
@@ -59,7 +59,7 @@ fn handle_single_prime(
     info!(event = "cli.prime_started", branch = branch);
 
     let session = helpers::require_session_json(branch, "cli.prime_failed", json_output)?;
-    let all_sessions = session_ops::list_sessions().map_err(|e| {
+    let all_sessions = session_ops::list_sessions(false).map_err(|e| {
         error!(event = "cli.prime_failed", branch = branch, error = %e);
         Box::<dyn std::error::Error>::from(e)
     })?;
@@ -110,7 +110,7 @@ fn handle_all_prime(
 ) -> Result<(), Box<dyn std::error::Error>> {
     info!(event = "cli.prime_all_started");
 
-    let sessions = session_ops::list_sessions().map_err(|e| {
+    let sessions = session_ops::list_sessions(false).map_err(|e| {
         error!(event = "cli.prime_all_failed", error = %e);
         Box::<dyn std::error::Error>::from(e)
     })?;
@@ -52,6 +52,7 @@ pub(crate) fn handle_rebase_command(
         }
         Err(e) => {
             eprintln!("{}: {}", branch, e);
+            helpers::print_rebase_conflicts(&e);
             error!(
                 event = "cli.rebase_failed",
                 branch = branch,
@@ -73,7 +74,7 @@ fn handle_rebase_all(base_override: Option<String>) -> Result<(), Box<dyn std::e
         None => config.git.base_branch(),
     };
 
-    let sessions = session_ops::list_sessions()?;
+    let sessions = session_ops::list_sessions(false)?;
 
     if sessions.is_empty() {
         println!("No kilds to rebase.");
@@ -97,6 +98,7 @@ fn handle_rebase_all(base_override: Option<String>) -> Result<(), Box<dyn std::e
             }
             Err(e) => {
                 eprintln!("{}: {}", session.branch, e);
+                helpers::print_rebase_conflicts(&e);
                 error!(
                     event = "cli.rebase_failed",
                     branch = %session.branch,
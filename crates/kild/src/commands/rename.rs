@@ -0,0 +1,59 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::session_ops;
+
+use super::helpers::{self, is_valid_branch_name};
+use crate::color;
+
+pub(crate) fn handle_rename_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let old = matches
+        .get_one::<String>("old")
+        .ok_or("Current branch name is required")?;
+    let new = matches
+        .get_one::<String>("new")
+        .ok_or("New branch name is required")?;
+
+    if !is_valid_branch_name(new) {
+        eprintln!("{} {}", color::error("Invalid branch name:"), new);
+        error!(event = "cli.rename_invalid_branch", branch = new.as_str());
+        return Err("Invalid branch name".into());
+    }
+
+    info!(event = "cli.rename_started", old = old, new = new);
+
+    let session = helpers::require_session(old, "cli.rename_failed")?;
+
+    match session_ops::rename_session(&session.id, new.as_str().into()) {
+        Ok(renamed) => {
+            println!("{}", color::aurora("Kild renamed."));
+            println!(
+                "  {}   {} → {}",
+                color::muted("Branch:"),
+                old,
+                color::ice(&renamed.branch)
+            );
+            println!(
+                "  {} {}",
+                color::muted("Worktree:"),
+                helpers::shorten_home_path(&renamed.worktree_path)
+            );
+            info!(
+                event = "cli.rename_completed",
+                old = old,
+                new = %renamed.branch,
+                session_id = %renamed.id
+            );
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{} {}", color::error("Could not rename:"), e);
+            error!(event = "cli.rename_failed", old = old, new = new, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
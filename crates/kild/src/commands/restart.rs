@@ -0,0 +1,47 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::session_ops;
+
+use super::helpers::resolve_explicit_runtime_mode;
+
+pub(crate) fn handle_restart_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let runtime_mode =
+        resolve_explicit_runtime_mode(matches.get_flag("daemon"), matches.get_flag("no-daemon"));
+    let no_attach = matches.get_flag("no-attach");
+
+    info!(event = "cli.restart_started", branch = branch);
+
+    match session_ops::restart_session(branch, runtime_mode, no_attach) {
+        Ok(session) => {
+            println!("Restarted agent for '{}'.", branch);
+            let display_agent = session
+                .latest_agent()
+                .map(|a| a.agent().to_string())
+                .unwrap_or_else(|| session.agent.clone());
+            println!("  Agent: {}", display_agent);
+            if let Some(pid) = session.latest_agent().and_then(|a| a.process_id()) {
+                println!("  PID:   {}", pid);
+            }
+
+            info!(
+                event = "cli.restart_completed",
+                branch = branch,
+                session_id = %session.id
+            );
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("Could not restart '{}': {}", branch, e);
+            error!(event = "cli.restart_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
@@ -153,7 +153,7 @@ fn handle_all_stats(
         None => config.git.base_branch(),
     };
 
-    let sessions = session_ops::list_sessions()?;
+    let sessions = session_ops::list_sessions(false)?;
 
     if sessions.is_empty() {
         if json_output {
@@ -35,16 +35,19 @@ pub(crate) fn handle_status_command(
     let git_stats = kild_core::git::collect_git_stats(&session.worktree_path, branch, base_branch);
     let status_info = session_ops::read_agent_status(&session.id);
     let pr_info = session_ops::read_pr_info(&session.id);
+    let agent_version = kild_core::agents::get_agent_version(&config, &session.agent);
+
+    let stale_after = config.git.stale_after_commits();
+    let branch_health = kild_core::git::collect_branch_health(
+        &session.worktree_path,
+        &session.branch,
+        base_branch,
+        &session.created_at,
+    )
+    .ok();
 
     if json_output {
         let process_status = kild_core::sessions::info::determine_process_status(&session);
-        let branch_health = kild_core::git::collect_branch_health(
-            &session.worktree_path,
-            &session.branch,
-            base_branch,
-            &session.created_at,
-        )
-        .ok();
 
         let latest_agent = session.latest_agent();
         let terminal_window_title =
@@ -68,7 +71,12 @@ pub(crate) fn handle_status_command(
         let overlapping_files = compute_overlapping_files(&session, base_branch);
 
         let agent_count = session.agent_count();
+        let stale = branch_health
+            .as_ref()
+            .map(|h| h.drift.is_stale(stale_after));
         let enriched = EnrichedSession {
+            worktree_exists: session.worktree_path.is_dir(),
+            stale,
             session,
             process_status,
             git_stats,
@@ -80,6 +88,7 @@ pub(crate) fn handle_status_command(
             terminal_type,
             pr_info,
             overlapping_files,
+            agent_version,
         };
         println!("{}", serde_json::to_string_pretty(&enriched)?);
         info!(
@@ -131,13 +140,14 @@ pub(crate) fn handle_status_command(
             rows.push(("Uncommitted:", uncommitted_line));
         }
 
-        // Show base-branch drift
-        if let Some(ref drift) = stats.drift {
-            let commits_line = format!(
-                "{} ahead, {} behind {}",
-                drift.ahead, drift.behind, drift.base_branch
-            );
-            rows.push(("Commits:", commits_line));
+        // Show base-branch drift, preferring branch health (resolves the kild branch
+        // ref directly) over the HEAD-based stats
+        if let Some(drift) = branch_health
+            .as_ref()
+            .map(|h| &h.drift)
+            .or(stats.drift.as_ref())
+        {
+            rows.push(("Commits:", format_drift(drift, stale_after)));
         } else if stats.diff_vs_base.is_none() {
             rows.push((
                 "Commits:",
@@ -166,11 +176,27 @@ pub(crate) fn handle_status_command(
     // Agent rows
     let mut agent_rows: Vec<String> = Vec::new();
     if session.has_agents() {
+        // A single sample always reads 0% CPU; take a baseline first
+        let pids: Vec<u32> = session
+            .agents()
+            .iter()
+            .filter_map(|agent_proc| agent_proc.process_id())
+            .collect();
+        process::prime_cpu_sampling(&pids);
+
         rows.push(("Agents:", format!("{}", session.agent_count())));
         for (i, agent_proc) in session.agents().iter().enumerate() {
             let status = agent_proc.process_id().map_or("No PID".to_string(), |pid| {
                 match process::is_process_running(pid) {
-                    Ok(true) => format!("Running (PID: {})", pid),
+                    Ok(true) => match process::get_process_info(pid) {
+                        Ok(info) => format!(
+                            "Running (PID: {}, CPU: {:.1}%, Mem: {} MB)",
+                            pid,
+                            info.cpu_percent,
+                            info.memory_bytes / 1_024 / 1_024
+                        ),
+                        Err(_) => format!("Running (PID: {})", pid),
+                    },
                     Ok(false) => format!("Stopped (PID: {})", pid),
                     Err(e) => {
                         warn!(
@@ -189,6 +215,9 @@ pub(crate) fn handle_status_command(
         rows.push(("Agent:", session.agent.clone()));
         rows.push(("Process:", "No agents tracked".to_string()));
     }
+    if let Some(ref version) = agent_version {
+        rows.push(("Version:", version.clone()));
+    }
 
     // Compute max value width using display width for correct Unicode alignment
     let value_width = rows
@@ -247,6 +276,23 @@ pub(crate) fn handle_status_command(
     Ok(())
 }
 
+/// Appended to the Commits row when a kild is further behind base than the
+/// configured `git.stale_after_commits`.
+const STALE_SUFFIX: &str = "(stale)";
+
+/// Format ahead/behind drift, flagging the kild as stale past `stale_after` commits behind.
+fn format_drift(drift: &kild_core::git::BaseBranchDrift, stale_after: usize) -> String {
+    let line = format!(
+        "{} ahead, {} behind {}",
+        drift.ahead, drift.behind, drift.base_branch
+    );
+    if drift.is_stale(stale_after) {
+        format!("{} {}", line, STALE_SUFFIX)
+    } else {
+        line
+    }
+}
+
 /// Apply semantic coloring to a status detail value based on its label.
 fn colorize_status_value(label: &str, value: &str) -> String {
     match label {
@@ -255,6 +301,7 @@ fn colorize_status_value(label: &str, value: &str) -> String {
         "Activity:" => color::activity(value),
         "Agent:" => color::kiri(value),
         "Agents:" => value.to_string(),
+        "Commits:" if value.ends_with(STALE_SUFFIX) => color::ember(value),
         _ => value.to_string(),
     }
 }
@@ -264,7 +311,7 @@ fn compute_overlapping_files(
     session: &kild_core::Session,
     base_branch: &str,
 ) -> Option<Vec<String>> {
-    session_ops::list_sessions().ok().map(|all_sessions| {
+    session_ops::list_sessions(false).ok().map(|all_sessions| {
         let (overlap_report, overlap_errors) =
             kild_core::git::collect_file_overlaps(&all_sessions, base_branch);
         for (branch, err_msg) in &overlap_errors {
@@ -303,3 +350,27 @@ fn determine_remote_status(ws: &kild_core::git::types::WorktreeStatus) -> &'stat
 
     "Diverged"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kild_core::git::BaseBranchDrift;
+
+    fn drift(ahead: usize, behind: usize) -> BaseBranchDrift {
+        BaseBranchDrift {
+            ahead,
+            behind,
+            base_branch: "main".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_format_drift_flags_stale_past_threshold() {
+        assert_eq!(format_drift(&drift(2, 3), 20), "2 ahead, 3 behind main");
+        assert_eq!(format_drift(&drift(2, 20), 20), "2 ahead, 20 behind main");
+        assert_eq!(
+            format_drift(&drift(0, 21), 20),
+            "0 ahead, 21 behind main (stale)"
+        );
+    }
+}
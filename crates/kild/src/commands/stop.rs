@@ -137,7 +137,7 @@ fn handle_stop_all() -> Result<(), Box<dyn std::error::Error>> {
 
     let self_branch = super::helpers::resolve_self_branch();
 
-    let sessions = session_ops::list_sessions()?;
+    let sessions = session_ops::list_sessions(false)?;
     let mut active = Vec::new();
     let mut already_stopped = Vec::new();
     let mut skipped_self = false;
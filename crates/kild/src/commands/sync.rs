@@ -1,6 +1,7 @@
 use clap::ArgMatches;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
+use kild_core::git::SyncOutcome;
 use kild_core::session_ops;
 
 use super::helpers::{
@@ -73,6 +74,7 @@ pub(crate) fn handle_sync_command(matches: &ArgMatches) -> Result<(), Box<dyn st
         }
         Err(e) => {
             eprintln!("{}: {}", branch, e);
+            helpers::print_rebase_conflicts(&e);
             error!(
                 event = "cli.sync_failed",
                 branch = branch,
@@ -117,7 +119,7 @@ fn handle_sync_all(base_override: Option<String>) -> Result<(), Box<dyn std::err
         base = base_branch
     );
 
-    let sessions = session_ops::list_sessions()?;
+    let sessions = session_ops::list_sessions(false)?;
 
     if sessions.is_empty() {
         println!("No kilds to sync.");
@@ -126,11 +128,13 @@ fn handle_sync_all(base_override: Option<String>) -> Result<(), Box<dyn std::err
     }
 
     let mut synced: Vec<String> = Vec::new();
+    let mut skipped: Vec<String> = Vec::new();
     let mut errors: Vec<FailedOperation> = Vec::new();
 
+    // Conflicts and per-kild failures don't stop the batch; only the fetch above is fatal
     for session in &sessions {
-        match kild_core::git::remote::rebase_worktree(&session.worktree_path, base_branch) {
-            Ok(()) => {
+        match kild_core::git::sync_worktree(&session.worktree_path, base_branch) {
+            Ok(SyncOutcome::Synced) => {
                 println!("{}: rebased onto {}", session.branch, base_branch);
                 info!(
                     event = "cli.sync_completed",
@@ -139,6 +143,30 @@ fn handle_sync_all(base_override: Option<String>) -> Result<(), Box<dyn std::err
                 );
                 synced.push(session.branch.to_string());
             }
+            Ok(SyncOutcome::SkippedDirty) => {
+                println!("{}: skipped (uncommitted changes)", session.branch);
+                warn!(
+                    event = "cli.sync_skipped_dirty",
+                    branch = %session.branch,
+                    path = %session.worktree_path.display()
+                );
+                skipped.push(session.branch.to_string());
+            }
+            Ok(SyncOutcome::Conflict { files }) => {
+                eprintln!(
+                    "{}: conflicts with {} (rebase aborted, worktree unchanged)",
+                    session.branch, base_branch
+                );
+                helpers::print_conflicted_files(&files);
+                warn!(
+                    event = "cli.sync_conflict",
+                    branch = %session.branch,
+                    base = base_branch,
+                    path = %session.worktree_path.display(),
+                    files = files.len()
+                );
+                errors.push((session.branch.to_string(), "rebase conflict".to_string()));
+            }
             Err(e) => {
                 eprintln!("{}: {}", session.branch, e);
                 error!(
@@ -156,11 +184,12 @@ fn handle_sync_all(base_override: Option<String>) -> Result<(), Box<dyn std::err
     info!(
         event = "cli.sync_all_completed",
         synced = synced.len(),
+        skipped = skipped.len(),
         failed = errors.len()
     );
 
     if !errors.is_empty() {
-        let total = synced.len() + errors.len();
+        let total = sessions.len();
         return Err(format_partial_failure_error("sync", errors.len(), total).into());
     }
 
@@ -6,6 +6,7 @@ use kild_core::events;
 use kild_teams::discovery;
 
 use super::helpers;
+use super::json_types::TeammateEntry;
 use crate::color;
 
 pub(crate) fn handle_teammates_command(
@@ -78,16 +79,7 @@ pub(crate) fn handle_teammates_command(
     if json_output {
         let json: Vec<_> = enriched
             .iter()
-            .map(|(m, status)| {
-                let role = if m.is_leader() { "leader" } else { "teammate" };
-                serde_json::json!({
-                    "pane_id": m.pane_id,
-                    "name": m.name,
-                    "role": role,
-                    "daemon_session_id": m.daemon_session_id,
-                    "status": status.as_ref().map(|s| s.to_string()),
-                })
-            })
+            .map(|(m, status)| TeammateEntry::new(m, status.as_ref().map(|s| s.to_string())))
             .collect();
         println!("{}", serde_json::to_string_pretty(&json)?);
     } else {
@@ -18,12 +18,21 @@ fn main() {
     let quiet = !verbose;
     init_logging(quiet);
 
-    // Apply --remote override before any IPC operations.
+    // Apply --remote / --remote-alias override before any IPC operations.
+    let fingerprint = matches
+        .get_one::<String>("remote-fingerprint")
+        .map(|s| s.as_str());
     if let Some(remote) = matches.get_one::<String>("remote") {
-        let fingerprint = matches
-            .get_one::<String>("remote-fingerprint")
-            .map(|s| s.as_str());
         kild_core::daemon::set_remote_override(remote, fingerprint);
+    } else if let Some(alias) = matches.get_one::<String>("remote-alias") {
+        let resolved = kild_core::KildConfig::load_hierarchy().and_then(|config| {
+            kild_core::daemon::set_remote_override_from_alias(&config.daemon, alias, fingerprint)
+                .map_err(Into::into)
+        });
+        if let Err(e) = resolved {
+            eprintln!("{} {}", color::error("Error:"), e);
+            std::process::exit(1);
+        }
     }
 
     if let Err(e) = commands::run_command(&matches) {
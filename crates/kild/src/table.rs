@@ -158,14 +158,7 @@ impl TableFormatter {
                 }
             }
         }
-        let total = session.agent_count();
-        if total == 0 {
-            "No PID".to_string()
-        } else if errored > 0 {
-            format!("{}run,{}err/{}", running, errored, total)
-        } else {
-            format!("Run({}/{})", running, total)
-        }
+        process_label(running, errored, session.agent_count())
     }
 
     fn issue_border_segment(&self) -> String {
@@ -253,7 +246,7 @@ impl TableFormatter {
             color::activity(&pad(&activity_display, self.activity_width)),
             pad(&session.created_at, self.created_width),
             pad(&port_range, self.port_width),
-            pad(&process_status, self.process_width),
+            color_process_status(&pad(&process_status, self.process_width)),
             pad(&command, self.command_width),
             pad(&pr_display, self.pr_width),
             issue_cell,
@@ -358,10 +351,60 @@ pub(crate) fn pad(s: &str, min_width: usize) -> String {
     }
 }
 
+/// Process column label for a kild whose tracked agents have all exited.
+const DEAD_LABEL: &str = "Dead";
+
+/// Label for the Process column, from live PID/daemon checks.
+///
+/// Reflects actual liveness, not the stored `SessionStatus`: a kild whose
+/// tracked agents have all exited reads "Dead" even while still marked active.
+fn process_label(running: usize, errored: usize, total: usize) -> String {
+    if total == 0 {
+        "No PID".to_string()
+    } else if errored > 0 {
+        format!("{}run,{}err/{}", running, errored, total)
+    } else if running == 0 {
+        DEAD_LABEL.to_string()
+    } else {
+        format!("Run({}/{})", running, total)
+    }
+}
+
+/// Highlight dead agents; other process labels stay uncolored.
+fn color_process_status(padded: &str) -> String {
+    if padded.trim_end() == DEAD_LABEL {
+        color::ember(padded)
+    } else {
+        padded.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_process_label_live_pid() {
+        assert_eq!(process_label(1, 0, 1), "Run(1/1)");
+        assert_eq!(process_label(1, 0, 2), "Run(1/2)");
+    }
+
+    #[test]
+    fn test_process_label_dead_pid() {
+        assert_eq!(process_label(0, 0, 1), "Dead");
+        assert_eq!(process_label(0, 0, 3), "Dead");
+    }
+
+    #[test]
+    fn test_process_label_no_pid() {
+        assert_eq!(process_label(0, 0, 0), "No PID");
+    }
+
+    #[test]
+    fn test_process_label_check_errors() {
+        assert_eq!(process_label(0, 1, 1), "0run,1err/1");
+    }
+
     #[test]
     fn test_pad_shorter_than_width() {
         assert_eq!(pad("hi", 5), "hi   ");
@@ -0,0 +1,116 @@
+//! Integration tests for `kild cd` shell integration output.
+//!
+//! `cd "$(kild cd <branch> --print-path)"` relies on stdout carrying the
+//! worktree path and nothing else. Uses HOME env var override to isolate
+//! session state.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Create a temporary HOME directory with `.kild/sessions/` for test isolation.
+fn setup_test_home(test_name: &str) -> PathBuf {
+    let unique_id = format!(
+        "{}_{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    );
+    let temp_home = std::env::temp_dir().join(format!("kild_test_{}_{}", test_name, unique_id));
+    fs::create_dir_all(temp_home.join(".kild").join("sessions"))
+        .expect("Failed to create test sessions dir");
+    temp_home
+}
+
+/// Write a minimal session fixture for `branch` pointing at `worktree_path`.
+fn write_session(temp_home: &Path, branch: &str, worktree_path: &str) {
+    let id = format!("test/{}", branch);
+    let json = serde_json::json!({
+        "id": id,
+        "project_id": "test",
+        "branch": branch,
+        "worktree_path": worktree_path,
+        "agent": "claude",
+        "status": "Active",
+        "created_at": "2024-01-01T00:00:00Z",
+        "port_range_start": 3000,
+        "port_range_end": 3009,
+        "port_count": 10,
+        "agents": []
+    });
+    fs::write(
+        temp_home
+            .join(".kild")
+            .join("sessions")
+            .join(format!("{}.json", id.replace('/', "_"))),
+        serde_json::to_string_pretty(&json).unwrap(),
+    )
+    .expect("Failed to write session fixture");
+}
+
+fn run_cd(temp_home: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_kild"))
+        .arg("cd")
+        .args(args)
+        .env("HOME", temp_home)
+        .output()
+        .expect("Failed to execute 'kild cd'")
+}
+
+#[test]
+fn test_cd_print_path_stdout_is_exactly_path() {
+    let temp_home = setup_test_home("cd_print_path");
+    write_session(&temp_home, "feature-x", "/tmp/kild-test-worktree/feature-x");
+
+    let output = run_cd(&temp_home, &["feature-x", "--print-path"]);
+
+    assert!(
+        output.status.success(),
+        "kild cd --print-path failed. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "/tmp/kild-test-worktree/feature-x\n"
+    );
+
+    let _ = fs::remove_dir_all(&temp_home);
+}
+
+#[test]
+fn test_cd_without_flag_prints_same_path() {
+    let temp_home = setup_test_home("cd_default");
+    write_session(&temp_home, "feature-y", "/tmp/kild-test-worktree/feature-y");
+
+    let output = run_cd(&temp_home, &["feature-y"]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "/tmp/kild-test-worktree/feature-y\n"
+    );
+
+    let _ = fs::remove_dir_all(&temp_home);
+}
+
+#[test]
+fn test_cd_print_path_missing_session_fails_with_empty_stdout() {
+    let temp_home = setup_test_home("cd_missing");
+
+    let output = run_cd(&temp_home, &["no-such-kild", "--print-path"]);
+
+    assert!(
+        !output.status.success(),
+        "kild cd should exit non-zero for a missing session"
+    );
+    assert!(
+        output.stdout.is_empty(),
+        "stdout must stay empty so cd \"$(...)\" doesn't get garbage, got: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No kild found"));
+
+    let _ = fs::remove_dir_all(&temp_home);
+}
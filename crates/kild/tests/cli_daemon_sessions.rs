@@ -219,27 +219,27 @@ fn test_focus_daemon_session_errors_with_attach_hint() {
 }
 
 // =============================================================================
-// restart command removed
+// restart command
 // =============================================================================
 
-/// `kild restart` should be an unrecognized subcommand after removal.
+/// `kild restart` is a recognized subcommand that requires a branch.
 #[test]
-fn test_restart_command_removed() {
+fn test_restart_command_requires_branch() {
     let output = Command::new(env!("CARGO_BIN_EXE_kild"))
-        .args(["restart", "some-branch"])
+        .args(["restart"])
         .output()
         .expect("Failed to execute 'kild restart'");
 
     assert!(
         !output.status.success(),
-        "restart should not be a recognized subcommand"
+        "restart without a branch must fail"
     );
 
     let stderr = String::from_utf8_lossy(&output.stderr);
 
     assert!(
-        stderr.contains("unrecognized subcommand"),
-        "Should indicate restart is not valid, got stderr: {}",
+        !stderr.contains("unrecognized subcommand") && stderr.contains("<branch>"),
+        "Should ask for the branch argument, got stderr: {}",
         stderr
     );
 }